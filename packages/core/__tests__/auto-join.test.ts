@@ -1,5 +1,9 @@
 import { describe, it, expect } from "vitest";
-import { appendAutoJoinParam, hasAutoJoinParam } from "../src/auto-join.js";
+import {
+  appendAutoJoinParam,
+  hasAutoJoinParam,
+  getAutoClickOverride,
+} from "../src/auto-join.js";
 
 describe("auto-join url helpers", () => {
   it("should append auto-join param to meeting url", () => {
@@ -54,4 +58,21 @@ describe("auto-join url helpers", () => {
   it("should return false when url parsing fails", () => {
     expect(hasAutoJoinParam("http://[")).toBe(false);
   });
+
+  it("should encode a per-meeting auto-click override in the marker", () => {
+    const url = "https://meet.google.com/abc-defg-hij";
+
+    const navigateOnly = appendAutoJoinParam(url, false);
+    expect(hasAutoJoinParam(navigateOnly)).toBe(true);
+    expect(getAutoClickOverride(navigateOnly)).toBe(false);
+
+    expect(getAutoClickOverride(appendAutoJoinParam(url, true))).toBe(true);
+    expect(getAutoClickOverride(appendAutoJoinParam(url, null))).toBeNull();
+    expect(new URL(appendAutoJoinParam(url, null)).searchParams.get("meetcatAuto")).toBe("1");
+  });
+
+  it("should return no override without a marker or for invalid urls", () => {
+    expect(getAutoClickOverride("https://meet.google.com/abc-defg-hij")).toBeNull();
+    expect(getAutoClickOverride("http://[")).toBeNull();
+  });
 });
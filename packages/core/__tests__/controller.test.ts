@@ -4,6 +4,7 @@ import {
   findMediaButtons,
   isMuted,
   setMicState,
+  toggleMicState,
   setCameraState,
   applyMicState,
   applyCameraState,
@@ -12,9 +13,12 @@ import {
 import {
   findJoinButton,
   findLeaveButton,
+  findJoinRestriction,
   clickJoinButton,
   getMeetingCodeFromPath,
 } from "../src/controller/join-button.js";
+import { readCallPresence } from "../src/controller/call-presence.js";
+import { findBreakoutRoom } from "../src/controller/breakout.js";
 
 describe("Controller - Media Buttons", () => {
   let dom: JSDOM;
@@ -137,6 +141,29 @@ describe("Controller - Media Buttons", () => {
     });
   });
 
+  describe("toggleMicState", () => {
+    it("should unmute a muted mic and mute a live one", () => {
+      const micBtn = createMediaButton(true);
+      document.body.appendChild(micBtn);
+      document.body.appendChild(createMediaButton(true));
+      micBtn.addEventListener("click", () => {
+        micBtn.dataset.isMuted = String(micBtn.dataset.isMuted !== "true");
+      });
+
+      expect(toggleMicState(document)).toEqual({ success: true, changed: true });
+      expect(micBtn.dataset.isMuted).toBe("false");
+
+      expect(toggleMicState(document)).toEqual({ success: true, changed: true });
+      expect(micBtn.dataset.isMuted).toBe("true");
+    });
+
+    it("should return failure when muted state cannot be determined", () => {
+      document.body.appendChild(createUnknownMediaButton());
+
+      expect(toggleMicState(document)).toEqual({ success: false, changed: false });
+    });
+  });
+
   describe("setCameraState", () => {
     it("should click camera button to turn on when currently off", () => {
       const micBtn = createMediaButton(true);
@@ -452,6 +479,40 @@ describe("Controller - Join Button", () => {
     });
   });
 
+  describe("findJoinRestriction", () => {
+    function addHeading(text: string): void {
+      const heading = document.createElement("h1");
+      heading.textContent = text;
+      document.body.appendChild(heading);
+    }
+
+    it("should detect the restriction headline", () => {
+      addHeading("You can't join this video call");
+
+      expect(findJoinRestriction(document)).toEqual({
+        reason: "not_allowed",
+        matchedText: "You can't join this video call",
+      });
+    });
+
+    it("should report domain restrictions", () => {
+      addHeading("このビデオ通話に参加できません");
+      const detail = document.createElement("p");
+      detail.textContent = "この会議には、主催者の組織のユーザーのみが参加できます";
+      document.body.appendChild(detail);
+
+      expect(findJoinRestriction(document)?.reason).toBe("domain_restricted");
+    });
+
+    it("should ignore the phrase outside headings", () => {
+      const title = document.createElement("span");
+      title.textContent = "You can't join this video call";
+      document.body.appendChild(title);
+
+      expect(findJoinRestriction(document)).toBeNull();
+    });
+  });
+
   describe("clickJoinButton", () => {
     it("should click the join button", () => {
       const button = createJoinButton("Join now");
@@ -525,3 +586,102 @@ describe("Controller - Join Button", () => {
     });
   });
 });
+
+describe("Controller - Call Presence", () => {
+  let document: Document;
+
+  beforeEach(() => {
+    document = new JSDOM("<!DOCTYPE html><html><body></body></html>").window.document;
+  });
+
+  function addVideo(readyState: string): void {
+    const video = document.createElement("video");
+    Object.defineProperty(video, "srcObject", {
+      value: { getTracks: () => [{ readyState }] },
+    });
+    document.body.appendChild(video);
+  }
+
+  it("should report nothing on an empty page", () => {
+    expect(readCallPresence(document)).toEqual({
+      localMediaLive: false,
+      participantCount: null,
+      micMuted: null,
+      cameraMuted: null,
+    });
+  });
+
+  it("should see a green room preview without a participant count", () => {
+    addVideo("ended");
+    addVideo("live");
+
+    expect(readCallPresence(document)).toEqual({
+      localMediaLive: true,
+      participantCount: null,
+      micMuted: null,
+      cameraMuted: null,
+    });
+  });
+
+  it("should read the participant count from the people toggle", () => {
+    const toggle = document.createElement("button");
+    toggle.setAttribute("data-panel-id", "1");
+    toggle.innerHTML = "<i>people</i><div>5</div>";
+    document.body.appendChild(toggle);
+
+    expect(readCallPresence(document).participantCount).toBe(5);
+  });
+
+  it("should read the mic and camera state from the media buttons", () => {
+    for (const muted of ["false", "true"]) {
+      const button = document.createElement("div");
+      button.setAttribute("role", "button");
+      button.setAttribute("data-is-muted", muted);
+      document.body.appendChild(button);
+    }
+
+    const presence = readCallPresence(document);
+    expect(presence.micMuted).toBe(false);
+    expect(presence.cameraMuted).toBe(true);
+  });
+});
+
+describe("Controller - Breakout Rooms", () => {
+  let document: Document;
+
+  beforeEach(() => {
+    document = new JSDOM("<!DOCTYPE html><html><body></body></html>").window.document;
+  });
+
+  function addButton(label: string): void {
+    const button = document.createElement("button");
+    button.setAttribute("aria-label", label);
+    document.body.appendChild(button);
+  }
+
+  it("should not report a room in the main call", () => {
+    addButton("Leave call");
+    expect(findBreakoutRoom(document)).toBeNull();
+  });
+
+  it("should detect the return button and read the room name", () => {
+    addButton("Return to main call");
+    const status = document.createElement("div");
+    status.setAttribute("role", "status");
+    status.textContent = "Breakout room: Design review";
+    document.body.appendChild(status);
+
+    expect(findBreakoutRoom(document)).toEqual({
+      matchedText: "Return to main call",
+      roomName: "Design review",
+    });
+  });
+
+  it("should leave the room name out when no status line names it", () => {
+    addButton("返回主通话");
+    expect(findBreakoutRoom(document)).toEqual({
+      matchedText: "返回主通话",
+      roomName: null,
+    });
+  });
+});
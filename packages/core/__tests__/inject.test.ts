@@ -6,6 +6,7 @@ const parserMocks = vi.hoisted(() => ({
   getNextJoinableMeeting: vi.fn((meetings: unknown[]) =>
     meetings.length ? (meetings[0] as unknown) : null
   ),
+  runParserSelfTest: vi.fn(() => ({ passed: true, total: 6, failed: 0, cases: [] })),
 }));
 
 const controllerMocks = vi.hoisted(() => ({
@@ -15,7 +16,16 @@ const controllerMocks = vi.hoisted(() => ({
   getMeetingCodeFromPath: vi.fn(() => null),
   findJoinButton: vi.fn(() => ({ button: null, matchedText: null })),
   findLeaveButton: vi.fn(() => ({ button: null, matchedText: null })),
+  findJoinRestriction: vi.fn(() => null),
+  findBreakoutRoom: vi.fn(() => null),
   findMediaButtons: vi.fn(() => []),
+  readCallPresence: vi.fn(() => ({
+    localMediaLive: false,
+    participantCount: null,
+    micMuted: null,
+    cameraMuted: null,
+  })),
+  toggleMicState: vi.fn(() => ({ success: true, changed: true })),
 }));
 
 const uiMocks = vi.hoisted(() => ({
@@ -24,7 +34,9 @@ const uiMocks = vi.hoisted(() => ({
     setUpdateInfo: vi.fn(),
     destroy: vi.fn(),
   })),
-  createJoinCountdown: vi.fn(() => ({ update: vi.fn(), destroy: vi.fn() })),
+  createJoinCountdown: vi.fn(() => ({ update: vi.fn(), destroy: vi.fn(), start: vi.fn() })),
+  createWindowNotice: vi.fn(() => ({ destroy: vi.fn() })),
+  describeWindowActivity: vi.fn(() => null),
   ensureStyles: vi.fn(),
 }));
 
@@ -34,18 +46,35 @@ const tauriMocks = vi.hoisted(() => ({
   getSettings: vi.fn(),
   getJoinedMeetings: vi.fn().mockResolvedValue([]),
   getSuppressedMeetings: vi.fn().mockResolvedValue([]),
+  getNextTrigger: vi.fn(),
   onCheckMeetings: vi.fn(),
   onNavigateAndJoin: vi.fn(),
+  onNavigateToGreenRoom: vi.fn().mockResolvedValue(() => {}),
+  onGreenRoomJoin: vi.fn().mockResolvedValue(() => {}),
   onSettingsChanged: vi.fn(),
+  onSettingsDelta: vi.fn().mockResolvedValue(() => {}),
   onUpdateAvailable: vi.fn(),
   getUpdatePromptPreference: vi.fn(),
   onUpdatePromptPreferenceChanged: vi.fn(),
   getUpdateInfo: vi.fn(),
   openUpdateDialog: vi.fn(),
   reportJoined: vi.fn(),
+  reportJoinCompleted: vi.fn().mockResolvedValue(undefined),
+  reportJoinBlocked: vi.fn().mockResolvedValue(undefined),
+  reportJoinCountdownStarted: vi.fn().mockResolvedValue(undefined),
+  reportCallPresence: vi.fn().mockResolvedValue(undefined),
+  reportBreakoutEntered: vi.fn().mockResolvedValue(undefined),
+  reportBreakoutExited: vi.fn().mockResolvedValue(undefined),
   reportMeetingClosed: vi.fn().mockResolvedValue(undefined),
   logEvent: vi.fn().mockResolvedValue(undefined),
   requestNavigateHome: vi.fn().mockResolvedValue(undefined),
+  reportParserSelfTest: vi.fn().mockResolvedValue(undefined),
+  onScriptPing: vi.fn().mockResolvedValue(() => {}),
+  onWindowShown: vi.fn().mockResolvedValue(() => {}),
+  onToggleMic: vi.fn().mockResolvedValue(() => {}),
+  onLeaveMeeting: vi.fn().mockResolvedValue(() => {}),
+  onCopyMeetingLink: vi.fn().mockResolvedValue(() => {}),
+  reportScriptPong: vi.fn().mockResolvedValue(undefined),
 }));
 
 vi.mock("../src/parser/index.js", () => parserMocks);
@@ -54,6 +83,7 @@ vi.mock("../src/ui/index.js", () => uiMocks);
 vi.mock("../src/auto-join.js", () => ({
   appendAutoJoinParam: (url: string) => url,
   hasAutoJoinParam: () => false,
+  getAutoClickOverride: () => null,
 }));
 vi.mock("../src/tauri-bridge.js", () => tauriMocks);
 vi.mock("@meetcat/i18n", () => ({
@@ -141,8 +171,55 @@ describe("inject homepage checks", () => {
     clearIntervalSpy.mockRestore();
   });
 
-  it("uses maxMinutesAfterStart for overlay selection in Tauri", async () => {
+  it("shows the meeting the daemon triggers next in the overlay in Tauri", async () => {
     tauriMocks.isTauriEnvironment.mockReturnValue(true);
+    tauriMocks.getSettings.mockResolvedValue({ ...DEFAULT_SETTINGS });
+    tauriMocks.onCheckMeetings.mockResolvedValue(() => {});
+    tauriMocks.onNavigateAndJoin.mockResolvedValue(() => {});
+    tauriMocks.onSettingsChanged.mockResolvedValue(() => {});
+
+    const now = Date.now();
+    const meetingAt = (callId: string, offsetMinutes: number) => ({
+      callId,
+      url: `https://meet.google.com/${callId}`,
+      title: callId,
+      displayTime: "10:00 AM",
+      beginTime: new Date(now + offsetMinutes * 60 * 1000),
+      endTime: new Date(now + (offsetMinutes + 30) * 60 * 1000),
+      eventId: null,
+      startsInMinutes: offsetMinutes,
+    });
+    const first = meetingAt("first", 10);
+    const later = meetingAt("later", 20);
+    tauriMocks.getNextTrigger.mockResolvedValue({
+      next: { call_id: "later", reason: "scheduled" },
+      snoozed_until_ms: {},
+    });
+
+    const updateSpy = vi.fn();
+    uiMocks.createHomepageOverlay.mockReturnValue({
+      update: updateSpy,
+      setUpdateInfo: vi.fn(),
+      destroy: vi.fn(),
+    });
+    parserMocks.parseMeetingCards.mockReturnValue({
+      meetings: [first, later],
+      cardsFound: 2,
+    });
+
+    const module = await import("../src/inject.js");
+    await flushPromises();
+
+    expect(tauriMocks.reportMeetings).toHaveBeenCalledWith([first, later]);
+    expect(updateSpy).toHaveBeenLastCalledWith(later);
+    expect(parserMocks.getNextJoinableMeeting).not.toHaveBeenCalled();
+
+    module.cleanup();
+  });
+
+  it("falls back to maxMinutesAfterStart for overlay selection without a daemon schedule", async () => {
+    tauriMocks.isTauriEnvironment.mockReturnValue(true);
+    tauriMocks.getNextTrigger.mockRejectedValue(new Error("unavailable"));
     tauriMocks.getSettings.mockResolvedValue({
       ...DEFAULT_SETTINGS,
       maxMinutesAfterStart: 10,
@@ -221,6 +298,65 @@ describe("inject homepage checks", () => {
     module.cleanup();
   });
 
+  it("toggles the overlay only when a settings delta touches it", async () => {
+    const initial = { ...DEFAULT_SETTINGS, showCountdownOverlay: true };
+    tauriMocks.isTauriEnvironment.mockReturnValue(true);
+    tauriMocks.getSettings.mockResolvedValue(initial);
+    tauriMocks.onCheckMeetings.mockResolvedValue(() => {});
+    tauriMocks.onNavigateAndJoin.mockResolvedValue(() => {});
+
+    let onChanged: ((settings: unknown) => void) | undefined;
+    let onDelta: ((delta: unknown) => void) | undefined;
+    tauriMocks.onSettingsChanged.mockImplementation(async (handler) => {
+      onChanged = handler;
+      return () => {};
+    });
+    tauriMocks.onSettingsDelta.mockImplementation(async (handler) => {
+      onDelta = handler;
+      return () => {};
+    });
+    const destroy = vi.fn();
+    uiMocks.createHomepageOverlay.mockReturnValue({
+      update: vi.fn(),
+      setUpdateInfo: vi.fn(),
+      destroy,
+    });
+
+    const module = await import("../src/inject.js");
+    await flushPromises();
+
+    onChanged?.({ ...initial, showCountdownOverlay: false, joinBeforeMinutes: 5 });
+    onDelta?.({ changedKeys: ["joinBeforeMinutes"], changes: {} });
+    expect(destroy).not.toHaveBeenCalled();
+
+    onDelta?.({ changedKeys: ["showCountdownOverlay"], changes: {} });
+    expect(destroy).toHaveBeenCalledTimes(1);
+
+    module.cleanup();
+  });
+
+  it("answers script pings from the desktop app", async () => {
+    tauriMocks.isTauriEnvironment.mockReturnValue(true);
+    tauriMocks.getSettings.mockResolvedValue({ ...DEFAULT_SETTINGS });
+    tauriMocks.onCheckMeetings.mockResolvedValue(() => {});
+    tauriMocks.onNavigateAndJoin.mockResolvedValue(() => {});
+    tauriMocks.onSettingsChanged.mockResolvedValue(() => {});
+
+    let onPing: ((payload: { pingId: number }) => void) | undefined;
+    tauriMocks.onScriptPing.mockImplementation(async (handler) => {
+      onPing = handler;
+      return () => {};
+    });
+
+    const module = await import("../src/inject.js");
+    await flushPromises();
+
+    onPing?.({ pingId: 7 });
+    expect(tauriMocks.reportScriptPong).toHaveBeenCalledWith(7);
+
+    module.cleanup();
+  });
+
   it("does not log to disk when settings fail to load in Tauri", async () => {
     tauriMocks.isTauriEnvironment.mockReturnValue(true);
     tauriMocks.getSettings.mockRejectedValue(new Error("boom"));
@@ -340,6 +476,126 @@ describe("inject homepage checks", () => {
     module.cleanup();
   });
 
+  it("starts the join countdown in place on green-room-join for the open meeting", async () => {
+    tauriMocks.isTauriEnvironment.mockReturnValue(true);
+    tauriMocks.getSettings.mockResolvedValue({ ...DEFAULT_SETTINGS, autoClickJoin: false });
+    tauriMocks.onCheckMeetings.mockResolvedValue(() => {});
+    tauriMocks.onNavigateAndJoin.mockResolvedValue(() => {});
+    tauriMocks.onSettingsChanged.mockResolvedValue(() => {});
+
+    controllerMocks.getMeetingCodeFromPath.mockImplementation(
+      (path: string) => path.split("/")[1] || null
+    );
+    controllerMocks.findMediaButtons.mockReturnValue({
+      micButton: document.createElement("button"),
+      cameraButton: document.createElement("button"),
+    });
+
+    window.history.pushState({}, "", "/abc-defg-hij");
+
+    const module = await import("../src/inject.js");
+    await flushPromises();
+
+    expect(tauriMocks.onGreenRoomJoin).toHaveBeenCalled();
+    expect(uiMocks.createJoinCountdown).not.toHaveBeenCalled();
+    const handler = tauriMocks.onGreenRoomJoin.mock.calls[0][0] as (cmd: {
+      url: string;
+      settings: typeof DEFAULT_SETTINGS;
+    }) => void;
+
+    handler({
+      url: "https://meet.google.com/xyz-wxyz-xyz",
+      settings: { ...DEFAULT_SETTINGS, autoClickJoin: true },
+    });
+    expect(uiMocks.createJoinCountdown).not.toHaveBeenCalled();
+
+    handler({
+      url: "https://meet.google.com/abc-defg-hij",
+      settings: { ...DEFAULT_SETTINGS, autoClickJoin: true, joinCountdownSeconds: 10 },
+    });
+    expect(uiMocks.createJoinCountdown).toHaveBeenCalled();
+
+    module.cleanup();
+    controllerMocks.getMeetingCodeFromPath.mockReset();
+    controllerMocks.getMeetingCodeFromPath.mockReturnValue(null);
+  });
+
+  it("keeps a meeting moved to a breakout room open across room pages", async () => {
+    tauriMocks.isTauriEnvironment.mockReturnValue(true);
+    tauriMocks.getSettings.mockResolvedValue({ ...DEFAULT_SETTINGS });
+    tauriMocks.onCheckMeetings.mockResolvedValue(() => {});
+    tauriMocks.onNavigateAndJoin.mockResolvedValue(() => {});
+    tauriMocks.onSettingsChanged.mockResolvedValue(() => {});
+
+    controllerMocks.getMeetingCodeFromPath.mockImplementation(
+      (path: string) => path.split("/")[1] || null
+    );
+    controllerMocks.findMediaButtons.mockReturnValue({
+      micButton: document.createElement("button"),
+      cameraButton: document.createElement("button"),
+    });
+    const joinButton = document.createElement("button");
+    document.body.appendChild(joinButton);
+    controllerMocks.findJoinButton.mockReturnValue({ button: joinButton, matchedText: "Join" });
+    controllerMocks.findBreakoutRoom.mockReturnValue({
+      matchedText: "Return to main call",
+      roomName: "Room 1",
+    });
+    tauriMocks.reportJoined.mockResolvedValue(undefined);
+
+    window.history.pushState({}, "", "/abc-defg-hij");
+    const module = await import("../src/inject.js");
+    await flushPromises();
+
+    joinButton.click();
+    expect(tauriMocks.reportBreakoutEntered).toHaveBeenCalledWith("abc-defg-hij", "Room 1");
+
+    // Meet opens the room's own page, then the main call again
+    controllerMocks.findBreakoutRoom.mockReturnValue(null);
+    controllerMocks.findLeaveButton.mockReturnValue({
+      button: document.createElement("button"),
+      matchedText: "Leave call",
+    });
+    window.history.pushState({}, "", "/xyz-wxyz-xyz");
+    document.body.appendChild(document.createElement("div"));
+    await flushPromises();
+    await flushPromises();
+
+    expect(tauriMocks.reportMeetingClosed).not.toHaveBeenCalled();
+    expect(tauriMocks.reportBreakoutExited).toHaveBeenCalledWith("abc-defg-hij");
+    expect(tauriMocks.reportJoined).toHaveBeenCalledTimes(1);
+
+    module.cleanup();
+    expect(tauriMocks.reportMeetingClosed).toHaveBeenCalledWith(
+      "abc-defg-hij",
+      expect.any(Number)
+    );
+    controllerMocks.getMeetingCodeFromPath.mockReset();
+    controllerMocks.getMeetingCodeFromPath.mockReturnValue(null);
+    controllerMocks.findJoinButton.mockReturnValue({ button: null, matchedText: null });
+    controllerMocks.findLeaveButton.mockReturnValue({ button: null, matchedText: null });
+  });
+
+  it("runs the parser self-test and reports it for the request", async () => {
+    tauriMocks.isTauriEnvironment.mockReturnValue(true);
+    tauriMocks.getSettings.mockResolvedValue({ ...DEFAULT_SETTINGS });
+    tauriMocks.onCheckMeetings.mockResolvedValue(() => {});
+    tauriMocks.onNavigateAndJoin.mockResolvedValue(() => {});
+    tauriMocks.onSettingsChanged.mockResolvedValue(() => {});
+
+    const module = await import("../src/inject.js");
+    await flushPromises();
+
+    window.__meetcatParserSelfTest?.("selftest-1");
+
+    expect(parserMocks.runParserSelfTest).toHaveBeenCalledWith(document);
+    expect(tauriMocks.reportParserSelfTest).toHaveBeenCalledWith(
+      "selftest-1",
+      expect.objectContaining({ passed: true, total: 6 })
+    );
+
+    module.cleanup();
+  });
 });
 
 describe("safeNavigateHome behavior", () => {
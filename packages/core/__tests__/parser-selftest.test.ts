@@ -0,0 +1,58 @@
+import { describe, it, expect, beforeEach } from "vitest";
+import { JSDOM } from "jsdom";
+import {
+  runParserSelfTest,
+  PARSER_SELFTEST_SAMPLES,
+  type ParserSelfTestSample,
+} from "../src/parser/selftest.js";
+
+describe("Parser self-test", () => {
+  let document: Document;
+
+  beforeEach(() => {
+    const dom = new JSDOM("<!DOCTYPE html><html><body></body></html>");
+    document = dom.window.document;
+  });
+
+  it("passes every embedded sample", () => {
+    const report = runParserSelfTest(document, 1_700_000_000_000);
+
+    expect(report.cases.filter((c) => !c.passed)).toEqual([]);
+    expect(report.passed).toBe(true);
+    expect(report.total).toBe(PARSER_SELFTEST_SAMPLES.length);
+    expect(new Set(report.cases.map((c) => c.locale)).size).toBeGreaterThanOrEqual(5);
+  });
+
+  it("removes sample cards and counts live cards separately", () => {
+    document.body.innerHTML =
+      '<div data-call-id="abc-defg-hij" data-begin-time="1700000600000" data-end-time="1700002400000">Live</div>';
+
+    const report = runParserSelfTest(document, 1_700_000_000_000);
+
+    expect(report.liveCardsFound).toBe(1);
+    expect(report.liveMeetings).toBe(1);
+    expect(document.querySelectorAll("[data-meetcat-selftest]").length).toBe(0);
+    expect(document.querySelectorAll("[data-call-id]").length).toBe(1);
+  });
+
+  it("reports mismatches against the fixtures", () => {
+    const sample: ParserSelfTestSample = {
+      id: "broken",
+      locale: "en-US",
+      html: '<div data-call-id="aaa-bbbb-ccc" data-begin-time="{{begin:10}}" data-end-time="{{end:40}}"><div>Actual Title</div></div>',
+      expected: [
+        { callId: "aaa-bbbb-ccc", title: "Expected Title", startsInMinutes: 10 },
+        { callId: "ddd-eeee-fff", title: "Missing", startsInMinutes: 20 },
+      ],
+    };
+
+    const report = runParserSelfTest(document, 1_700_000_000_000, [sample]);
+
+    expect(report.passed).toBe(false);
+    expect(report.failed).toBe(1);
+    expect(report.cases[0].mismatches).toEqual([
+      "expected 2 meetings, parsed 1",
+      '#0 title: expected "Expected Title", got "Actual Title"',
+    ]);
+  });
+});
@@ -78,11 +78,29 @@ describe("Parser", () => {
       expect(meeting).toBeNull();
     });
 
-    it("should return null for card without begin-time", () => {
+    it("should keep a card without times with unknown accuracy", () => {
+      const now = new Date(2026, 2, 2, 9, 20).getTime();
       const card = document.createElement("div");
       card.setAttribute("data-call-id", "abc-defg-hij");
-      const meeting = parseMeetingCard(card);
-      expect(meeting).toBeNull();
+
+      const meeting = parseMeetingCard(card, now);
+
+      expect(meeting).not.toBeNull();
+      expect(meeting!.accuracy).toBe("unknown");
+      expect(meeting!.beginTime.getTime()).toBe(now);
+      expect(meeting!.endTime.getTime()).toBe(new Date(2026, 2, 3).getTime());
+    });
+
+    it("should infer a missing end time from the start", () => {
+      const now = Date.now();
+      const card = document.createElement("div");
+      card.setAttribute("data-call-id", "abc-defg-hij");
+      card.setAttribute("data-begin-time", (now + 10 * 60 * 1000).toString());
+
+      const meeting = parseMeetingCard(card, now);
+
+      expect(meeting!.accuracy).toBe("inferred");
+      expect(meeting!.endTime.getTime()).toBe(now + 40 * 60 * 1000);
     });
 
     it("should handle negative startsInMinutes for past meetings", () => {
@@ -99,7 +117,7 @@ describe("Parser", () => {
       expect(meeting!.startsInMinutes).toBe(-10);
     });
 
-    it("should return null for invalid begin/end timestamps", () => {
+    it("should start a card with an invalid begin time now", () => {
       const now = Date.now();
       const card = document.createElement("div");
       card.setAttribute("data-call-id", "abc-defg-hij");
@@ -108,7 +126,9 @@ describe("Parser", () => {
 
       const meeting = parseMeetingCard(card, now);
 
-      expect(meeting).toBeNull();
+      expect(meeting!.accuracy).toBe("inferred");
+      expect(meeting!.beginTime.getTime()).toBe(now);
+      expect(meeting!.startsInMinutes).toBe(0);
     });
 
     it("should use aria-label title when title element is missing", () => {
@@ -214,6 +234,7 @@ describe("Parser", () => {
       // Should be sorted by start time
       expect(result.meetings[0].title).toBe("Meeting 2"); // Earlier
       expect(result.meetings[1].title).toBe("Meeting 1"); // Later
+      expect(result.timeAccuracy).toEqual({ exact: 2, inferred: 0, unknown: 0 });
     });
 
     it("should return empty array when no cards found", () => {
@@ -311,6 +332,15 @@ describe("Parser", () => {
       expect(next!.callId).toBe("soon-meet-ing");
     });
 
+    it("should skip meetings with estimated times", () => {
+      const inferred = meetings.map((meeting) => ({
+        ...meeting,
+        accuracy: "inferred" as const,
+      }));
+
+      expect(getNextJoinableMeeting(inferred, { now })).toBeNull();
+    });
+
     it("should skip already joined meetings", () => {
       const recentlyStarted: Meeting = {
         callId: "just-star-ted",
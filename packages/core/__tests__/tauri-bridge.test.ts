@@ -5,10 +5,33 @@ import {
   listen,
   reportMeetings,
   getSettings,
+  getNextTrigger,
   reportJoined,
+  reportJoinCompleted,
+  reportJoinBlocked,
+  reportJoinCountdownStarted,
+  reportCallPresence,
+  reportBreakoutEntered,
+  reportBreakoutExited,
   onCheckMeetings,
   onNavigateAndJoin,
+  onNavigateToGreenRoom,
+  onGreenRoomJoin,
+  onJoinReminder,
+  onMeetingConflict,
+  chooseConflictMeeting,
+  onWindowShown,
+  getWindowActivity,
+  onToggleMic,
+  onLeaveMeeting,
+  onCopyMeetingLink,
+  getShortcutStatus,
+  checkMediaPermissions,
+  requestMediaPermissions,
+  openMediaPermissionSettings,
+  skipPendingJoin,
   onSettingsChanged,
+  onSettingsDelta,
   getUpdateInfo,
   onUpdateAvailable,
   openUpdateDialog,
@@ -16,6 +39,10 @@ import {
   getUpdatePromptPreference,
   setUpdatePromptPreference,
   onUpdatePromptPreferenceChanged,
+  reportParserSelfTest,
+  onScriptPing,
+  reportScriptPong,
+  getIpcSchema,
 } from "../src/tauri-bridge.js";
 import type { Meeting } from "../src/types.js";
 
@@ -145,6 +172,7 @@ describe("Tauri Bridge", () => {
             end_time: "2024-01-15T11:00:00.000Z",
             event_id: "event123",
             starts_in_minutes: 5,
+            accuracy: "exact",
           },
         ],
       });
@@ -181,6 +209,33 @@ describe("Tauri Bridge", () => {
     });
   });
 
+  describe("getNextTrigger", () => {
+    it("should call invoke with get_next_trigger command", async () => {
+      const schedule = {
+        next: {
+          call_id: "abc-defg-hij",
+          trigger_at_ms: 1_000,
+          delay_ms: 500,
+          reason: "scheduled",
+          countdown_seconds: 10,
+          busy_hold: null,
+          conflict: [],
+        },
+        paused_until_ms: null,
+        rate_capped: false,
+        suppressed: [],
+        cancelled: [],
+        snoozed_until_ms: {},
+      };
+      mockInvoke.mockResolvedValue(schedule);
+
+      const result = await getNextTrigger();
+
+      expect(mockInvoke).toHaveBeenCalledWith("get_next_trigger", undefined);
+      expect(result).toEqual(schedule);
+    });
+  });
+
   describe("reportJoined", () => {
     it("should call invoke with callId", async () => {
       mockInvoke.mockResolvedValue(undefined);
@@ -193,6 +248,94 @@ describe("Tauri Bridge", () => {
     });
   });
 
+  describe("reportJoinCompleted", () => {
+    it("should call invoke with join_completed command", async () => {
+      mockInvoke.mockResolvedValue(undefined);
+
+      await reportJoinCompleted("abc-defg-hij");
+
+      expect(mockInvoke).toHaveBeenCalledWith("join_completed", {
+        callId: "abc-defg-hij",
+      });
+    });
+  });
+
+  describe("reportJoinCountdownStarted", () => {
+    it("should call invoke with callId and seconds", async () => {
+      mockInvoke.mockResolvedValue(undefined);
+
+      await reportJoinCountdownStarted("abc-defg-hij", 10);
+
+      expect(mockInvoke).toHaveBeenCalledWith("join_countdown_started", {
+        callId: "abc-defg-hij",
+        seconds: 10,
+      });
+    });
+  });
+
+  describe("reportCallPresence", () => {
+    it("should call invoke with callId and presence", async () => {
+      mockInvoke.mockResolvedValue(undefined);
+
+      await reportCallPresence("abc-defg-hij", {
+        localMediaLive: true,
+        participantCount: 4,
+        micMuted: true,
+        cameraMuted: false,
+      });
+
+      expect(mockInvoke).toHaveBeenCalledWith("meeting_presence", {
+        callId: "abc-defg-hij",
+        presence: {
+          localMediaLive: true,
+          participantCount: 4,
+          micMuted: true,
+          cameraMuted: false,
+        },
+      });
+    });
+  });
+
+  describe("reportBreakoutEntered", () => {
+    it("should call invoke with callId and room name", async () => {
+      mockInvoke.mockResolvedValue(undefined);
+
+      await reportBreakoutEntered("abc-defg-hij", "Room 2");
+
+      expect(mockInvoke).toHaveBeenCalledWith("breakout_entered", {
+        callId: "abc-defg-hij",
+        roomName: "Room 2",
+      });
+    });
+  });
+
+  describe("reportBreakoutExited", () => {
+    it("should call invoke with callId", async () => {
+      mockInvoke.mockResolvedValue(undefined);
+
+      await reportBreakoutExited("abc-defg-hij");
+
+      expect(mockInvoke).toHaveBeenCalledWith("breakout_exited", { callId: "abc-defg-hij" });
+    });
+  });
+
+  describe("reportJoinBlocked", () => {
+    it("should call invoke with the restriction", async () => {
+      mockInvoke.mockResolvedValue(undefined);
+
+      await reportJoinBlocked("abc-defg-hij", {
+        reason: "domain_restricted",
+        matchedText: "You can't join this video call",
+      });
+
+      expect(mockInvoke).toHaveBeenCalledWith("meeting_join_blocked", {
+        callId: "abc-defg-hij",
+        reason: "domain_restricted",
+        matchedText: "You can't join this video call",
+      });
+    });
+  });
+
   describe("onCheckMeetings", () => {
     it("should listen for check-meetings event", async () => {
       const unlisten = vi.fn();
@@ -206,6 +349,28 @@ describe("Tauri Bridge", () => {
     });
   });
 
+  describe("onScriptPing", () => {
+    it("should listen for script-ping event", async () => {
+      const unlisten = vi.fn();
+      mockListen.mockResolvedValue(unlisten);
+
+      const result = await onScriptPing(vi.fn());
+
+      expect(mockListen).toHaveBeenCalledWith("script-ping", expect.any(Function));
+      expect(result).toBe(unlisten);
+    });
+  });
+
+  describe("reportScriptPong", () => {
+    it("should call invoke with script_pong command", async () => {
+      mockInvoke.mockResolvedValue(undefined);
+
+      await reportScriptPong(3);
+
+      expect(mockInvoke).toHaveBeenCalledWith("script_pong", { pingId: 3 });
+    });
+  });
+
   describe("onNavigateAndJoin", () => {
     it("should listen for navigate-and-join event", async () => {
       const unlisten = vi.fn();
@@ -222,6 +387,270 @@ describe("Tauri Bridge", () => {
     });
   });
 
+  describe("window targeting", () => {
+    it("should only pass commands targeting this window", async () => {
+      (window as unknown as { __TAURI_INTERNALS__: unknown }).__TAURI_INTERNALS__ = {
+        metadata: { currentWindow: { label: "profile-work" } },
+      };
+      mockListen.mockResolvedValue(vi.fn());
+      const handler = vi.fn();
+
+      await onNavigateAndJoin(handler);
+      const listener = mockListen.mock.calls[0][1] as (event: { payload: unknown }) => void;
+      const cmd = { url: "https://meet.google.com/abc-defg-hij", settings: {} };
+      listener({ payload: { ...cmd, window: "main" } });
+      listener({ payload: cmd });
+      expect(handler).not.toHaveBeenCalled();
+
+      listener({ payload: { ...cmd, window: "profile-work" } });
+      expect(handler).toHaveBeenCalledTimes(1);
+    });
+
+    it("should treat a window without a readable label as the main window", async () => {
+      mockListen.mockResolvedValue(vi.fn());
+      const handler = vi.fn();
+
+      await onGreenRoomJoin(handler);
+      const listener = mockListen.mock.calls[0][1] as (event: { payload: unknown }) => void;
+      const cmd = { url: "https://meet.google.com/abc-defg-hij", settings: {} };
+      listener({ payload: cmd });
+      listener({ payload: { ...cmd, window: "profile-work" } });
+      expect(handler).toHaveBeenCalledTimes(1);
+    });
+  });
+
+  describe("onNavigateToGreenRoom", () => {
+    it("should listen for navigate-to-green-room event", async () => {
+      const unlisten = vi.fn();
+      mockListen.mockResolvedValue(unlisten);
+      const handler = vi.fn();
+
+      const result = await onNavigateToGreenRoom(handler);
+
+      expect(mockListen).toHaveBeenCalledWith(
+        "navigate-to-green-room",
+        expect.any(Function)
+      );
+      expect(result).toBe(unlisten);
+    });
+  });
+
+  describe("onGreenRoomJoin", () => {
+    it("should listen for green-room-join event", async () => {
+      const unlisten = vi.fn();
+      mockListen.mockResolvedValue(unlisten);
+      const handler = vi.fn();
+
+      const result = await onGreenRoomJoin(handler);
+
+      expect(mockListen).toHaveBeenCalledWith(
+        "green-room-join",
+        expect.any(Function)
+      );
+      expect(result).toBe(unlisten);
+    });
+  });
+
+  describe("onJoinReminder", () => {
+    it("should listen for join-reminder event", async () => {
+      const unlisten = vi.fn();
+      mockListen.mockResolvedValue(unlisten);
+      const handler = vi.fn();
+
+      const result = await onJoinReminder(handler);
+
+      expect(mockListen).toHaveBeenCalledWith("join-reminder", expect.any(Function));
+      expect(result).toBe(unlisten);
+    });
+  });
+
+  describe("onMeetingConflict", () => {
+    it("should listen for meeting-conflict event", async () => {
+      const unlisten = vi.fn();
+      mockListen.mockResolvedValue(unlisten);
+      const handler = vi.fn();
+
+      const result = await onMeetingConflict(handler);
+
+      expect(mockListen).toHaveBeenCalledWith("meeting-conflict", expect.any(Function));
+      expect(result).toBe(unlisten);
+    });
+  });
+
+  describe("chooseConflictMeeting", () => {
+    it("should call invoke with choose_conflict_meeting command", async () => {
+      mockInvoke.mockResolvedValue(undefined);
+
+      await chooseConflictMeeting("abc-defg-hij");
+
+      expect(mockInvoke).toHaveBeenCalledWith("choose_conflict_meeting", {
+        callId: "abc-defg-hij",
+      });
+    });
+  });
+
+  describe("onWindowShown", () => {
+    it("should listen for window:shown event", async () => {
+      const unlisten = vi.fn();
+      mockListen.mockResolvedValue(unlisten);
+      const handler = vi.fn();
+
+      const result = await onWindowShown(handler);
+
+      expect(mockListen).toHaveBeenCalledWith("window:shown", expect.any(Function));
+      expect(result).toBe(unlisten);
+    });
+
+    it("should only pass shows of this window", async () => {
+      mockListen.mockResolvedValue(vi.fn());
+      const handler = vi.fn();
+
+      await onWindowShown(handler);
+      const listener = mockListen.mock.calls[0][1] as (event: { payload: unknown }) => void;
+      const activity = { atMs: 1_000, cause: "deep_link", callId: null, title: null };
+      listener({ payload: { ...activity, label: "settings" } });
+      expect(handler).not.toHaveBeenCalled();
+
+      listener({ payload: { ...activity, label: "main" } });
+      expect(handler).toHaveBeenCalledTimes(1);
+    });
+  });
+
+  describe("getWindowActivity", () => {
+    it("should call invoke with get_window_activity command", async () => {
+      const activity = [
+        {
+          atMs: 1_000,
+          label: "main",
+          cause: "auto_join",
+          callId: "abc-defg-hij",
+          title: "Standup",
+        },
+      ];
+      mockInvoke.mockResolvedValue(activity);
+
+      const result = await getWindowActivity(5);
+
+      expect(mockInvoke).toHaveBeenCalledWith("get_window_activity", { limit: 5 });
+      expect(result).toEqual(activity);
+    });
+  });
+
+  describe("onToggleMic", () => {
+    it("should only pass toggles aimed at this window", async () => {
+      mockListen.mockResolvedValue(vi.fn());
+      const handler = vi.fn();
+
+      await onToggleMic(handler);
+      expect(mockListen).toHaveBeenCalledWith("shortcut:toggle-mic", expect.any(Function));
+      const listener = mockListen.mock.calls[0][1] as (event: { payload: unknown }) => void;
+      listener({ payload: { window: "profile-work" } });
+      expect(handler).not.toHaveBeenCalled();
+
+      listener({ payload: { window: "main" } });
+      expect(handler).toHaveBeenCalledTimes(1);
+    });
+  });
+
+  describe("onLeaveMeeting", () => {
+    it("should only pass leave requests aimed at this window", async () => {
+      mockListen.mockResolvedValue(vi.fn());
+      const handler = vi.fn();
+
+      await onLeaveMeeting(handler);
+      expect(mockListen).toHaveBeenCalledWith("tray:leave-meeting", expect.any(Function));
+      const listener = mockListen.mock.calls[0][1] as (event: { payload: unknown }) => void;
+      listener({ payload: { window: "profile-work" } });
+      expect(handler).not.toHaveBeenCalled();
+
+      listener({ payload: { window: "main" } });
+      expect(handler).toHaveBeenCalledTimes(1);
+    });
+  });
+
+  describe("onCopyMeetingLink", () => {
+    it("should pass the link of copy requests aimed at this window", async () => {
+      mockListen.mockResolvedValue(vi.fn());
+      const handler = vi.fn();
+
+      await onCopyMeetingLink(handler);
+      expect(mockListen).toHaveBeenCalledWith("tray:copy-meeting-link", expect.any(Function));
+      const listener = mockListen.mock.calls[0][1] as (event: { payload: unknown }) => void;
+      listener({ payload: { window: "profile-work", url: "https://meet.google.com/abc" } });
+      expect(handler).not.toHaveBeenCalled();
+
+      listener({ payload: { window: "main", url: "https://meet.google.com/abc-defg-hij" } });
+      expect(handler).toHaveBeenCalledWith("https://meet.google.com/abc-defg-hij");
+    });
+  });
+
+  describe("getShortcutStatus", () => {
+    it("should call invoke with get_shortcut_status command", async () => {
+      const statuses = [
+        {
+          action: "toggle_mic",
+          accelerator: "CommandOrControl+Shift+M",
+          registered: false,
+          error: "HotKey already registered",
+        },
+      ];
+      mockInvoke.mockResolvedValue(statuses);
+
+      const result = await getShortcutStatus();
+
+      expect(mockInvoke).toHaveBeenCalledWith("get_shortcut_status", undefined);
+      expect(result).toEqual(statuses);
+    });
+  });
+
+  describe("checkMediaPermissions", () => {
+    it("should call invoke with check_media_permissions command", async () => {
+      const permissions = { camera: "denied", microphone: "authorized" };
+      mockInvoke.mockResolvedValue(permissions);
+
+      const result = await checkMediaPermissions();
+
+      expect(mockInvoke).toHaveBeenCalledWith("check_media_permissions", undefined);
+      expect(result).toEqual(permissions);
+    });
+  });
+
+  describe("requestMediaPermissions", () => {
+    it("should call invoke with request_media_permissions command", async () => {
+      const permissions = { camera: "authorized", microphone: "authorized" };
+      mockInvoke.mockResolvedValue(permissions);
+
+      const result = await requestMediaPermissions();
+
+      expect(mockInvoke).toHaveBeenCalledWith("request_media_permissions", undefined);
+      expect(result).toEqual(permissions);
+    });
+  });
+
+  describe("openMediaPermissionSettings", () => {
+    it("should call invoke with the device to open settings for", async () => {
+      mockInvoke.mockResolvedValue(undefined);
+
+      await openMediaPermissionSettings("microphone");
+
+      expect(mockInvoke).toHaveBeenCalledWith("open_media_permission_settings", {
+        device: "microphone",
+      });
+    });
+  });
+
+  describe("skipPendingJoin", () => {
+    it("should call invoke with cancel_pending_join command", async () => {
+      mockInvoke.mockResolvedValue("abc-defg-hij");
+
+      await skipPendingJoin("abc-defg-hij");
+
+      expect(mockInvoke).toHaveBeenCalledWith("cancel_pending_join", {
+        callId: "abc-defg-hij",
+      });
+    });
+  });
+
   describe("onSettingsChanged", () => {
     it("should listen for settings_changed event", async () => {
       const unlisten = vi.fn();
@@ -238,6 +667,27 @@ describe("Tauri Bridge", () => {
     });
   });
 
+  describe("onSettingsDelta", () => {
+    it("should listen for settings-delta event and pass the delta", async () => {
+      const unlisten = vi.fn();
+      mockListen.mockResolvedValue(unlisten);
+      const handler = vi.fn();
+
+      const result = await onSettingsDelta(handler);
+
+      expect(mockListen).toHaveBeenCalledWith("settings-delta", expect.any(Function));
+      expect(result).toBe(unlisten);
+
+      const delta = {
+        changedKeys: ["showCountdownOverlay"],
+        changes: { showCountdownOverlay: { from: true, to: false } },
+      };
+      const listener = mockListen.mock.calls[0][1] as (event: { payload: unknown }) => void;
+      listener({ payload: delta });
+      expect(handler).toHaveBeenCalledWith(delta);
+    });
+  });
+
   describe("getUpdateInfo", () => {
     it("should call invoke with get_update_info command", async () => {
       const payload = { version: "0.0.5", notes: "update notes" };
@@ -336,4 +786,42 @@ describe("Tauri Bridge", () => {
       expect(result).toBe(unlisten);
     });
   });
+
+  describe("getIpcSchema", () => {
+    it("should call invoke with get_ipc_schema command", async () => {
+      const schema = {
+        version: 1,
+        $defs: {},
+        events: { "script-ping": { type: "object" } },
+      };
+      mockInvoke.mockResolvedValue(schema);
+
+      const result = await getIpcSchema();
+
+      expect(mockInvoke).toHaveBeenCalledWith("get_ipc_schema", undefined);
+      expect(result).toEqual(schema);
+    });
+  });
+
+  describe("reportParserSelfTest", () => {
+    it("should call invoke with parser_selftest_result command", async () => {
+      mockInvoke.mockResolvedValue(undefined);
+      const report = {
+        passed: false,
+        total: 1,
+        failed: 1,
+        cases: [{ id: "en-basic", locale: "en-US", passed: false, mismatches: ["x"] }],
+        liveCardsFound: 0,
+        liveMeetings: 0,
+        ranAtMs: 0,
+      };
+
+      await reportParserSelfTest("req-1", report);
+
+      expect(mockInvoke).toHaveBeenCalledWith("parser_selftest_result", {
+        requestId: "req-1",
+        report,
+      });
+    });
+  });
 });
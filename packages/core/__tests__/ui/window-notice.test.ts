@@ -0,0 +1,84 @@
+import { describe, it, expect, beforeAll, beforeEach, afterEach, vi } from "vitest";
+import { JSDOM } from "jsdom";
+import { initI18n } from "@meetcat/i18n";
+import { createWindowNotice, describeWindowActivity } from "../../src/ui/window-notice.js";
+import type { WindowActivity } from "../../src/tauri-bridge.js";
+
+describe("Window Notice", () => {
+  let dom: JSDOM;
+  let document: Document;
+  let container: HTMLElement;
+
+  const activity = (overrides: Partial<WindowActivity> = {}): WindowActivity => ({
+    atMs: 1_000,
+    label: "main",
+    cause: "auto_join",
+    callId: "abc-defg-hij",
+    title: "Standup",
+    ...overrides,
+  });
+
+  beforeAll(async () => {
+    await initI18n("en");
+  });
+
+  beforeEach(() => {
+    vi.useFakeTimers();
+    dom = new JSDOM("<!DOCTYPE html><html><head></head><body></body></html>");
+    document = dom.window.document;
+    container = document.body;
+
+    // Mock global document for styles
+    (globalThis as unknown as { document: Document }).document = document;
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  describe("describeWindowActivity", () => {
+    it("should name the cause and the meeting", () => {
+      expect(describeWindowActivity(activity())).toBe("Shown by MeetCat: auto-join for Standup");
+    });
+
+    it("should leave out a missing title", () => {
+      expect(describeWindowActivity(activity({ cause: "deep_link", title: null }))).toBe(
+        "Shown by MeetCat: MeetCat link"
+      );
+    });
+
+    it("should return null for shows the user asked for", () => {
+      expect(describeWindowActivity(activity({ cause: "tray" }))).toBeNull();
+      expect(describeWindowActivity(activity({ cause: "reopen" }))).toBeNull();
+    });
+  });
+
+  describe("createWindowNotice", () => {
+    it("should show the text and remove itself after the duration", () => {
+      createWindowNotice(container, "Shown by MeetCat: green room", { durationMs: 3000 });
+
+      const notice = container.querySelector(".meetcat-window-notice");
+      expect(notice?.textContent).toContain("Shown by MeetCat: green room");
+
+      vi.advanceTimersByTime(2999);
+      expect(container.querySelector(".meetcat-window-notice")).not.toBeNull();
+      vi.advanceTimersByTime(1);
+      expect(container.querySelector(".meetcat-window-notice")).toBeNull();
+    });
+
+    it("should replace an earlier notice", () => {
+      createWindowNotice(container, "first");
+      createWindowNotice(container, "second");
+
+      const notices = container.querySelectorAll(".meetcat-window-notice");
+      expect(notices).toHaveLength(1);
+      expect(notices[0].textContent).toContain("second");
+    });
+
+    it("should remove the notice on destroy", () => {
+      const notice = createWindowNotice(container, "first");
+      notice.destroy();
+      expect(container.querySelector(".meetcat-window-notice")).toBeNull();
+    });
+  });
+});
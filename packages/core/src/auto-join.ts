@@ -1,17 +1,30 @@
 const AUTO_JOIN_PARAM = "meetcatAuto";
+const AUTO_CLICK_VALUE = "click";
+const NAVIGATE_ONLY_VALUE = "navigate";
+
+function autoJoinParamValue(autoClickJoin?: boolean | null): string {
+  if (autoClickJoin === true) return AUTO_CLICK_VALUE;
+  if (autoClickJoin === false) return NAVIGATE_ONLY_VALUE;
+  return "1";
+}
 
 /**
  * Append MeetCat auto-join marker to a meeting URL.
+ *
+ * A per-meeting auto-click override is encoded in the marker value so it
+ * survives the navigation; without one the meeting page follows the global
+ * `autoClickJoin` setting.
  */
-export function appendAutoJoinParam(url: string): string {
+export function appendAutoJoinParam(url: string, autoClickJoin?: boolean | null): string {
+  const value = autoJoinParamValue(autoClickJoin);
   try {
     const parsed = new URL(url);
-    parsed.searchParams.set(AUTO_JOIN_PARAM, "1");
+    parsed.searchParams.set(AUTO_JOIN_PARAM, value);
     return parsed.toString();
   } catch (error) {
     try {
       const parsed = new URL(url, "https://meet.google.com");
-      parsed.searchParams.set(AUTO_JOIN_PARAM, "1");
+      parsed.searchParams.set(AUTO_JOIN_PARAM, value);
       return parsed.toString();
     } catch (fallbackError) {
       return url;
@@ -30,3 +43,19 @@ export function hasAutoJoinParam(url: string): boolean {
     return false;
   }
 }
+
+/**
+ * Read the per-meeting auto-click override from the auto-join marker.
+ * Returns null when the marker is missing or carries no override.
+ */
+export function getAutoClickOverride(url: string): boolean | null {
+  try {
+    const parsed = new URL(url, "https://meet.google.com");
+    const value = parsed.searchParams.get(AUTO_JOIN_PARAM);
+    if (value === AUTO_CLICK_VALUE) return true;
+    if (value === NAVIGATE_ONLY_VALUE) return false;
+    return null;
+  } catch (error) {
+    return null;
+  }
+}
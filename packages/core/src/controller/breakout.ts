@@ -0,0 +1,83 @@
+import type { BreakoutRoomResult } from "../types.js";
+
+/**
+ * Labels of the button Google Meet shows inside a breakout room to go back
+ * to the main call
+ */
+export const BREAKOUT_RETURN_PATTERNS = [
+  // Chinese
+  "返回主通话",
+  "返回主通話",
+  // English
+  "Return to main call",
+  "Back to main call",
+  // Japanese
+  "メインの通話に戻る",
+  // Korean
+  "기본 통화로 돌아가기",
+];
+
+/**
+ * Phrases in the status line that names the current breakout room
+ */
+const BREAKOUT_ROOM_PATTERNS = [
+  // Chinese
+  "分组讨论室",
+  "分組討論室",
+  // English (matched lowercased)
+  "breakout room",
+  // Japanese
+  "ブレイクアウト ルーム",
+  // Korean
+  "소회의실",
+];
+
+const MAX_ROOM_NAME_LENGTH = 80;
+
+function getAccessibleText(element: Element): string {
+  return (
+    element.getAttribute("aria-label") ||
+    element.getAttribute("title") ||
+    element.textContent ||
+    ""
+  ).trim();
+}
+
+/**
+ * Read the room name from the status line, e.g. "Breakout room: Room 2"
+ * gives "Room 2"; null when no status line names the room
+ */
+function readBreakoutRoomName(container: Document | Element): string | null {
+  const candidates = Array.from(
+    container.querySelectorAll("h1, h2, [role='heading'], [role='status']")
+  );
+  for (const candidate of candidates) {
+    const text = (candidate.textContent || "").trim();
+    const lower = text.toLowerCase();
+    if (!BREAKOUT_ROOM_PATTERNS.some((pattern) => lower.includes(pattern))) continue;
+    const separator = text.search(/[:：]/);
+    const name = (separator >= 0 ? text.slice(separator + 1) : text).trim();
+    if (name) return name.slice(0, MAX_ROOM_NAME_LENGTH);
+  }
+  return null;
+}
+
+/**
+ * Detect that Meet moved this page into a breakout room.
+ *
+ * Only the "Return to main call" button counts, since Meet shows it in every
+ * breakout room and nowhere else; the room name is read when shown.
+ *
+ * @param container - The document or element to search within
+ */
+export function findBreakoutRoom(container: Document | Element): BreakoutRoomResult | null {
+  const buttons = Array.from(container.querySelectorAll("button"));
+  for (const button of buttons) {
+    const text = getAccessibleText(button);
+    const matchedText = BREAKOUT_RETURN_PATTERNS.find((pattern) => text.includes(pattern));
+    if (matchedText) {
+      return { matchedText, roomName: readBreakoutRoomName(container) };
+    }
+  }
+  return null;
+}
@@ -0,0 +1,47 @@
+import type { CallPresence } from "../types.js";
+import { findMediaButtons, isMuted } from "./media-buttons.js";
+
+/**
+ * Selector for the "People" panel toggle, whose badge shows the participant
+ * count once inside a call
+ */
+export const PARTICIPANT_COUNT_SELECTOR = '[data-panel-id="1"]';
+
+function hasLiveLocalTrack(container: Document | Element): boolean {
+  const videos = container.querySelectorAll("video");
+  for (const video of videos) {
+    const stream = (video as HTMLVideoElement).srcObject as MediaStream | null;
+    if (!stream || typeof stream.getTracks !== "function") continue;
+    if (stream.getTracks().some((track) => track.readyState === "live")) {
+      return true;
+    }
+  }
+  return false;
+}
+
+function readParticipantCount(container: Document | Element): number | null {
+  const toggle = container.querySelector(PARTICIPANT_COUNT_SELECTOR);
+  if (!toggle) return null;
+  const match = (toggle.textContent || "").match(/\d+/);
+  if (!match) return null;
+  const count = Number.parseInt(match[0], 10);
+  return Number.isFinite(count) ? count : null;
+}
+
+/**
+ * Read what the meeting page shows about the call.
+ *
+ * The green room preview also has live local tracks, so only a participant
+ * count tells that the call was actually entered.
+ *
+ * @param container - The document or element to search within
+ */
+export function readCallPresence(container: Document | Element): CallPresence {
+  const { micButton, cameraButton } = findMediaButtons(container);
+  return {
+    localMediaLive: hasLiveLocalTrack(container),
+    participantCount: readParticipantCount(container),
+    micMuted: isMuted(micButton),
+    cameraMuted: isMuted(cameraButton),
+  };
+}
@@ -2,6 +2,7 @@ export {
   findMediaButtons,
   isMuted,
   setMicState,
+  toggleMicState,
   setCameraState,
   applyMicState,
   applyCameraState,
@@ -11,8 +12,14 @@ export {
 export {
   findJoinButton,
   findLeaveButton,
+  findJoinRestriction,
   clickJoinButton,
   getMeetingCodeFromPath,
   JOIN_BUTTON_PATTERNS,
   LEAVE_BUTTON_PATTERNS,
+  JOIN_RESTRICTION_PATTERNS,
 } from "./join-button.js";
+
+export { readCallPresence, PARTICIPANT_COUNT_SELECTOR } from "./call-presence.js";
+
+export { findBreakoutRoom, BREAKOUT_RETURN_PATTERNS } from "./breakout.js";
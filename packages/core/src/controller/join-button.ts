@@ -1,4 +1,4 @@
-import type { JoinButtonResult } from "../types.js";
+import type { JoinButtonResult, JoinRestrictionResult } from "../types.js";
 
 /**
  * Join button text patterns for multiple languages
@@ -38,6 +38,34 @@ export const LEAVE_BUTTON_PATTERNS = [
   "通話を退出",
 ];
 
+/**
+ * Headlines Google Meet shows when the signed-in account may not join
+ */
+export const JOIN_RESTRICTION_PATTERNS = [
+  // Chinese
+  "无法加入此视频通话",
+  "無法加入這場視訊通話",
+  // English
+  "You can't join this video call",
+  "You can\u2019t join this video call",
+  // Japanese
+  "このビデオ通話に参加できません",
+  // Korean
+  "이 화상 통화에 참여할 수 없습니다",
+];
+
+/**
+ * Phrases that narrow a restriction down to the host's organization
+ */
+const DOMAIN_RESTRICTION_PATTERNS = [
+  "组织",
+  "organization",
+  "organisation",
+  "domain",
+  "組織",
+  "조직",
+];
+
 const PROMO_ANCHOR_ID = "w5gBed";
 
 function getButtonText(button: Element): string {
@@ -206,6 +234,33 @@ export function findLeaveButton(container: Document | Element): JoinButtonResult
   return { button: null, matchedText: null };
 }
 
+/**
+ * Detect the "You can't join this video call" page
+ *
+ * Only headings are matched so meeting titles or chat text quoting the phrase
+ * don't count. The reason is `domain_restricted` when the page also mentions
+ * the host's organization.
+ */
+export function findJoinRestriction(container: Document | Element): JoinRestrictionResult | null {
+  const headings = Array.from(container.querySelectorAll("h1, h2, [role='heading']"));
+  for (const heading of headings) {
+    const text = (heading.textContent || "").trim();
+    const matchedText = JOIN_RESTRICTION_PATTERNS.find((pattern) => text.includes(pattern));
+    if (!matchedText) continue;
+
+    const root = "body" in container ? container.body : container;
+    const pageText = (root?.textContent || "").toLowerCase();
+    const domainRestricted = DOMAIN_RESTRICTION_PATTERNS.some((pattern) =>
+      pageText.includes(pattern)
+    );
+    return {
+      reason: domainRestricted ? "domain_restricted" : "not_allowed",
+      matchedText,
+    };
+  }
+  return null;
+}
+
 /**
  * Click the join button
  *
@@ -72,6 +72,20 @@ export function setMicState(
   return { success: true, changed: false };
 }
 
+/**
+ * Mute the mic when it is live and unmute it when it is muted
+ *
+ * @param container - The document or element to search within
+ * @returns MediaStateResult; fails when no mic button with a known state is found
+ */
+export function toggleMicState(container: Document | Element): MediaStateResult {
+  const muted = isMuted(findMediaButtons(container).micButton);
+  if (muted === null) {
+    return { success: false, changed: false };
+  }
+  return setMicState(container, muted);
+}
+
 /**
  * Set the camera state
  *
@@ -1,12 +1,19 @@
 // Re-export all types
 export type {
   Meeting,
+  MeetingTimeAccuracy,
   ParseResult,
   MediaButtons,
   MediaStateResult,
   MediaApplyOptions,
   MediaApplyResult,
   JoinButtonResult,
+  JoinBlockedReason,
+  JoinRestrictionResult,
+  BreakoutRoomResult,
+  CallPresence,
+  MeetingPhase,
+  MeetingLifecycleEvent,
   SchedulerEventType,
   SchedulerEvent,
   SchedulerConfig,
@@ -18,6 +25,11 @@ export {
   parseMeetingCard,
   getNextJoinableMeeting,
   MEETING_CARD_SELECTOR,
+  runParserSelfTest,
+  PARSER_SELFTEST_SAMPLES,
+  type ParserSelfTestSample,
+  type ParserSelfTestCaseResult,
+  type ParserSelfTestReport,
 } from "./parser/index.js";
 
 // Re-export controller
@@ -31,10 +43,15 @@ export {
   MEDIA_BUTTON_SELECTOR,
   findJoinButton,
   findLeaveButton,
+  findJoinRestriction,
+  findBreakoutRoom,
+  readCallPresence,
   clickJoinButton,
   getMeetingCodeFromPath,
   JOIN_BUTTON_PATTERNS,
   LEAVE_BUTTON_PATTERNS,
+  JOIN_RESTRICTION_PATTERNS,
+  BREAKOUT_RETURN_PATTERNS,
 } from "./controller/index.js";
 
 // Re-export scheduler
@@ -56,7 +73,7 @@ export {
 } from "./ui/index.js";
 
 // Re-export auto-join helpers
-export { appendAutoJoinParam, hasAutoJoinParam } from "./auto-join.js";
+export { appendAutoJoinParam, hasAutoJoinParam, getAutoClickOverride } from "./auto-join.js";
 
 // Re-export utils
 export {
@@ -10,10 +10,16 @@
 declare global {
   interface Window {
     __meetcatInitialized?: string; // stores the path that was initialized
+    /** Entry point evaluated by the desktop app's `run_parser_selftest` */
+    __meetcatParserSelfTest?: (requestId: string) => void;
   }
 }
 
-import { parseMeetingCards, getNextJoinableMeeting } from "./parser/index.js";
+import {
+  parseMeetingCards,
+  getNextJoinableMeeting,
+  runParserSelfTest,
+} from "./parser/index.js";
 import {
   applyMicState,
   applyCameraState,
@@ -21,16 +27,22 @@ import {
   getMeetingCodeFromPath,
   findJoinButton,
   findLeaveButton,
+  findJoinRestriction,
+  findBreakoutRoom,
   findMediaButtons,
+  readCallPresence,
+  toggleMicState,
 } from "./controller/index.js";
 import {
   createHomepageOverlay,
   createJoinCountdown,
+  createWindowNotice,
+  describeWindowActivity,
   ensureStyles,
   type HomepageOverlay,
   type JoinCountdown,
 } from "./ui/index.js";
-import { appendAutoJoinParam, hasAutoJoinParam } from "./auto-join.js";
+import { appendAutoJoinParam, hasAutoJoinParam, getAutoClickOverride } from "./auto-join.js";
 import {
   isTauriEnvironment,
   reportMeetings,
@@ -39,24 +51,42 @@ import {
   getUpdatePromptPreference,
   onCheckMeetings,
   onNavigateAndJoin,
+  onNavigateToGreenRoom,
+  onGreenRoomJoin,
   onSettingsChanged,
+  onSettingsDelta,
   onUpdateAvailable,
   onUpdatePromptPreferenceChanged,
   openUpdateDialog,
   requestNavigateHome,
   reportJoined,
+  reportJoinCompleted,
+  reportJoinBlocked,
+  reportJoinCountdownStarted,
+  reportCallPresence,
+  reportBreakoutEntered,
+  reportBreakoutExited,
   reportMeetingClosed,
   getJoinedMeetings,
   getSuppressedMeetings,
+  getNextTrigger,
   logEvent,
+  reportParserSelfTest,
+  onScriptPing,
+  onWindowShown,
+  onToggleMic,
+  onLeaveMeeting,
+  onCopyMeetingLink,
+  reportScriptPong,
   type LogLevel,
   type CheckMeetingsPayload,
   type TauriSettings,
+  type SettingsDelta,
   type NavigateAndJoinCommand,
   type UpdateInfo,
   type UpdatePromptPreference,
 } from "./tauri-bridge.js";
-import type { Meeting } from "./types.js";
+import type { CallPresence, Meeting } from "./types.js";
 import { DEFAULT_SETTINGS as SETTINGS_DEFAULTS } from "@meetcat/settings";
 import { initI18n, changeLanguage, type LanguageSetting } from "@meetcat/i18n";
 import {
@@ -76,6 +106,7 @@ let lastMeetings: Meeting[] = [];
 let mediaApplied = false;
 let joinAttempted = false;
 let autoJoinBlocked = false;
+let joinRestrictionReported = false;
 let joinedMeetings: Set<string> = new Set();
 let suppressedMeetings: Set<string> = new Set();
 let unsubscribers: Array<() => void> = [];
@@ -83,10 +114,20 @@ let fallbackIntervalId: ReturnType<typeof setInterval> | null = null;
 let currentMeetingCallId: string | null = null;
 let homepageKeydownHandler: ((event: KeyboardEvent) => void) | null = null;
 let meetingEntryObserver: MutationObserver | null = null;
+let presenceIntervalId: ReturnType<typeof setInterval> | null = null;
+let lastPresenceKey: string | null = null;
+/**
+ * Joined call whose page Meet moved to a breakout room; kept across the
+ * navigations Meet makes between rooms
+ */
+let breakoutParentCallId: string | null = null;
+let breakoutRoomName: string | null = null;
 let homepageVisibilityHandler: (() => void) | null = null;
 let homepageBlurHandler: (() => void) | null = null;
 let lastHomepageRecoveryLogKey: string | null = null;
+let lastParseQualityKey = "";
 const WATCHDOG_STORAGE_KEY = "__meetcat_reload_watchdog";
+const PRESENCE_POLL_MS = 5_000;
 
 function restoreWatchdogState(): HomepageReloadPersistableState | undefined {
   try {
@@ -203,7 +244,8 @@ async function syncJoinedMeetings(): Promise<void> {
     const joined = await getJoinedMeetings();
     joinedMeetings = new Set([...joinedMeetings, ...joined]);
     const suppressed = await getSuppressedMeetings();
-    suppressedMeetings = new Set([...suppressedMeetings, ...suppressed]);
+    // Replaced rather than merged, so expired suppressions drop out
+    suppressedMeetings = new Set(suppressed.map((s) => s.call_id));
   } catch (e) {
     logToConsole("warn", "[MeetCat] Failed to load joined meetings", {
       error: e instanceof Error ? e.message : String(e),
@@ -218,6 +260,83 @@ function reportJoinedOnce(callId: string): void {
   logToDisk("debug", "meeting", "join.reported", "Meeting reported joined", {
     callId,
   });
+  startPresencePolling(callId);
+}
+
+/**
+ * Report media and participant indicators after joining, so the app can tell
+ * a page waiting in the green room from one that is in the call. Only
+ * changes are sent.
+ */
+function startPresencePolling(callId: string): void {
+  if (!isTauriEnvironment()) return;
+  stopPresencePolling();
+  const report = () => {
+    syncBreakoutState(callId);
+    const presence: CallPresence = readCallPresence(document);
+    const key = JSON.stringify(presence);
+    if (key === lastPresenceKey) return;
+    lastPresenceKey = key;
+    logToDisk("debug", "meeting", "join.presence", "Call presence changed", {
+      callId,
+      ...presence,
+    });
+    reportCallPresence(callId, presence).catch((e) =>
+      console.error("[MeetCat] Failed to report call presence:", e)
+    );
+  };
+  report();
+  presenceIntervalId = setInterval(report, PRESENCE_POLL_MS);
+}
+
+/**
+ * Report moves into, between and out of breakout rooms, so the app keeps the
+ * meeting joined instead of seeing it close
+ */
+function syncBreakoutState(callId: string): void {
+  const room = findBreakoutRoom(document);
+  if (room) {
+    if (breakoutParentCallId !== null && room.roomName === breakoutRoomName) return;
+    breakoutParentCallId = callId;
+    breakoutRoomName = room.roomName;
+    logToDisk("info", "meeting", "breakout.entered", "Moved to a breakout room", {
+      callId,
+      roomName: room.roomName,
+      matchedText: room.matchedText,
+    });
+    reportBreakoutEntered(callId, room.roomName).catch((e) =>
+      console.error("[MeetCat] Failed to report breakout room:", e)
+    );
+    return;
+  }
+  // Between rooms neither button is shown; only the main call has a leave
+  // button without the return button
+  if (breakoutParentCallId === callId && findLeaveButton(document).button) {
+    reportBreakoutLeft("main_call");
+  }
+}
+
+function reportBreakoutLeft(reason: string): void {
+  if (breakoutParentCallId === null) return;
+  const callId = breakoutParentCallId;
+  logToDisk("info", "meeting", "breakout.exited", "Left the breakout room", {
+    callId,
+    roomName: breakoutRoomName,
+    reason,
+  });
+  breakoutParentCallId = null;
+  breakoutRoomName = null;
+  reportBreakoutExited(callId).catch((e) =>
+    console.error("[MeetCat] Failed to report breakout exit:", e)
+  );
+}
+
+function stopPresencePolling(): void {
+  if (presenceIntervalId !== null) {
+    clearInterval(presenceIntervalId);
+    presenceIntervalId = null;
+  }
+  lastPresenceKey = null;
 }
 
 function logToConsole(
@@ -342,10 +461,39 @@ function detectEnteredMeeting(stage: string): boolean {
   return true;
 }
 
+/**
+ * Stop auto-join when Meet says this account can't join, and report it once
+ * so the call is not retried
+ */
+function detectJoinRestriction(stage: string): boolean {
+  const restriction = findJoinRestriction(document);
+  if (!restriction) return false;
+
+  autoJoinBlocked = true;
+  cleanupCountdown();
+  if (!joinRestrictionReported && currentMeetingCallId) {
+    joinRestrictionReported = true;
+    const context = {
+      stage,
+      reason: restriction.reason,
+      matchedText: restriction.matchedText,
+      callId: currentMeetingCallId,
+    };
+    logToConsole("warn", "[MeetCat] Google Meet refused to let this account join", context);
+    logToDisk("warn", "meeting", "join.restricted", "Join restricted by Google Meet", context);
+    if (isTauriEnvironment()) {
+      reportJoinBlocked(currentMeetingCallId, restriction).catch((e) =>
+        console.error("[MeetCat] Failed to report blocked join:", e)
+      );
+    }
+  }
+  return true;
+}
+
 function startMeetingEntryObserver(): void {
   if (meetingEntryObserver) return;
   meetingEntryObserver = new MutationObserver(() => {
-    if (detectEnteredMeeting("observer")) {
+    if (detectEnteredMeeting("observer") || detectJoinRestriction("observer")) {
       stopMeetingEntryObserver();
     }
   });
@@ -484,11 +632,6 @@ async function setupEventListeners(): Promise<void> {
         settings: newSettings,
       });
       settings = newSettings;
-      updateOverlayVisibility();
-      // Sync i18n language if changed
-      if (newSettings?.language) {
-        void changeLanguage(newSettings.language as LanguageSetting);
-      }
       logToDisk("info", "settings", "settings.changed", "Settings updated", {
         logCollectionEnabled: settings?.tauri?.logCollectionEnabled ?? false,
         logLevel: settings?.tauri?.logLevel ?? "info",
@@ -501,6 +644,74 @@ async function setupEventListeners(): Promise<void> {
     logToDisk("warn", "inject", "listener.settings_failed", "Settings listener failed");
   }
 
+  try {
+    const unsubSettingsDelta = await onSettingsDelta(handleSettingsDelta);
+    unsubscribers.push(unsubSettingsDelta);
+  } catch (e) {
+    console.warn("[MeetCat] Failed to listen for settings deltas:", e);
+  }
+
+  try {
+    const unsubPing = await onScriptPing(({ pingId }) => {
+      reportScriptPong(pingId).catch((e) => {
+        console.warn("[MeetCat] Failed to answer script ping:", e);
+      });
+    });
+    unsubscribers.push(unsubPing);
+  } catch (e) {
+    console.warn("[MeetCat] Failed to listen for script pings:", e);
+  }
+
+  try {
+    const unsubWindowShown = await onWindowShown((activity) => {
+      const text = describeWindowActivity(activity);
+      if (text && document.body) {
+        createWindowNotice(document.body, text, { iconUrl: ICON_URL });
+      }
+    });
+    unsubscribers.push(unsubWindowShown);
+  } catch (e) {
+    console.warn("[MeetCat] Failed to listen for window shows:", e);
+  }
+
+  try {
+    const unsubToggleMic = await onToggleMic(() => {
+      const result = toggleMicState(document);
+      logToDisk("info", "meeting", "shortcut.toggle_mic", "Mic toggled by shortcut", {
+        success: result.success,
+      });
+    });
+    unsubscribers.push(unsubToggleMic);
+  } catch (e) {
+    console.warn("[MeetCat] Failed to listen for the mute shortcut:", e);
+  }
+
+  try {
+    const unsubLeaveMeeting = await onLeaveMeeting(() => {
+      const { button } = findLeaveButton(document);
+      button?.click();
+      logToDisk("info", "meeting", "tray.leave_meeting", "Leave requested from the tray", {
+        callId: currentMeetingCallId,
+        found: Boolean(button),
+      });
+    });
+    unsubscribers.push(unsubLeaveMeeting);
+  } catch (e) {
+    console.warn("[MeetCat] Failed to listen for tray leave requests:", e);
+  }
+
+  try {
+    const unsubCopyLink = await onCopyMeetingLink((url) => {
+      navigator.clipboard.writeText(url).catch((e) => {
+        console.warn("[MeetCat] Failed to copy the meeting link:", e);
+        logToDisk("warn", "meeting", "tray.copy_link_failed", "Failed to copy the meeting link");
+      });
+    });
+    unsubscribers.push(unsubCopyLink);
+  } catch (e) {
+    console.warn("[MeetCat] Failed to listen for tray copy requests:", e);
+  }
+
   try {
     updatePromptPreference = await getUpdatePromptPreference();
     syncOverlayUpdateNotice();
@@ -595,6 +806,13 @@ async function initHomepage(): Promise<void> {
       console.warn("[MeetCat] Failed to listen for navigate-and-join:", e);
     }
 
+    try {
+      const unsubGreenRoom = await onNavigateToGreenRoom(handleNavigateToGreenRoom);
+      unsubscribers.push(unsubGreenRoom);
+    } catch (e) {
+      console.warn("[MeetCat] Failed to listen for navigate-to-green-room:", e);
+    }
+
     try {
       updatePromptPreference = await getUpdatePromptPreference();
       syncOverlayUpdateNotice();
@@ -618,6 +836,29 @@ async function initHomepage(): Promise<void> {
   }
 }
 
+/**
+ * Log which meetings had estimated times, once per change of that set
+ */
+function reportParseQuality(
+  meetings: Meeting[],
+  meta: { source?: string; checkId?: number }
+): void {
+  const inexact = meetings.filter((m) => m.accuracy && m.accuracy !== "exact");
+  const key = inexact.map((m) => `${m.callId}:${m.accuracy}`).join(",");
+  if (key === lastParseQualityKey) return;
+  lastParseQualityKey = key;
+  if (!inexact.length) return;
+
+  logToDisk("warn", "homepage", "parse.quality", "Meeting times not read exactly", {
+    source: meta.source ?? "unknown",
+    checkId: meta.checkId,
+    meetingsCount: meetings.length,
+    inferred: inexact.filter((m) => m.accuracy === "inferred").length,
+    unknown: inexact.filter((m) => m.accuracy === "unknown").length,
+    meetings: inexact.map((m) => ({ callId: m.callId, accuracy: m.accuracy })),
+  });
+}
+
 /**
  * Parse meetings from DOM and report to Rust backend
  */
@@ -646,23 +887,34 @@ async function checkAndReportMeetings(meta: {
     meetingsCount: result.meetings.length,
     hiddenCards: result.hiddenCards ?? 0,
     hiddenReasons: result.hiddenReasons ?? {},
+    timeAccuracy: result.timeAccuracy ?? {},
   });
+  reportParseQuality(result.meetings, meta);
 
   if (evaluateHomepageRecovery(meta.source ?? "unknown", result.meetings)) {
     return;
   }
 
-  // Update overlay with next meeting
-  if (overlay) {
-    await syncJoinedMeetings();
-    const nextMeeting = getNextJoinableMeeting(result.meetings, {
-      gracePeriodMinutes:
-        settings?.maxMinutesAfterStart ?? SETTINGS_DEFAULTS.maxMinutesAfterStart,
-      alreadyJoined: joinedMeetings,
-      suppressedMeetings,
-      joinBeforeMinutes: settings?.joinBeforeMinutes ?? SETTINGS_DEFAULTS.joinBeforeMinutes,
+  // Report to Rust backend
+  try {
+    await reportMeetings(result.meetings);
+    logToDisk("debug", "homepage", "meetings.reported", "Meetings reported", {
+      source: meta.source ?? "unknown",
+      checkId: meta.checkId,
+      meetingsCount: result.meetings.length,
+    });
+  } catch (error) {
+    console.error("[MeetCat] Failed to report meetings:", error);
+    logToDisk("error", "homepage", "meetings.report_failed", "Report failed", {
+      source: meta.source ?? "unknown",
+      checkId: meta.checkId,
     });
-    overlay.update(nextMeeting);
+  }
+
+  // Update overlay with next meeting, once the daemon has the new list
+  if (overlay) {
+    const nextMeeting = await resolveOverlayMeeting(result.meetings);
+    overlay?.update(nextMeeting);
     logToDisk("debug", "overlay", "overlay.update", "Overlay updated", {
       source: meta.source ?? "unknown",
       checkId: meta.checkId,
@@ -677,22 +929,31 @@ async function checkAndReportMeetings(meta: {
         settings?.maxMinutesAfterStart ?? SETTINGS_DEFAULTS.maxMinutesAfterStart,
     });
   }
+}
 
-  // Report to Rust backend
-  try {
-    await reportMeetings(result.meetings);
-    logToDisk("debug", "homepage", "meetings.reported", "Meetings reported", {
-      source: meta.source ?? "unknown",
-      checkId: meta.checkId,
-      meetingsCount: result.meetings.length,
-    });
-  } catch (error) {
-    console.error("[MeetCat] Failed to report meetings:", error);
-    logToDisk("error", "homepage", "meetings.report_failed", "Report failed", {
-      source: meta.source ?? "unknown",
-      checkId: meta.checkId,
-    });
+/**
+ * Meeting for the homepage overlay: in the desktop app, the one the daemon
+ * triggers next, so the countdown matches what it will do; otherwise, or when
+ * the daemon can't be asked, derived from the parsed list
+ */
+async function resolveOverlayMeeting(meetings: Meeting[]): Promise<Meeting | null> {
+  if (isTauriEnvironment()) {
+    try {
+      const { next } = await getNextTrigger();
+      return meetings.find((m) => m.callId === next?.call_id) ?? null;
+    } catch (error) {
+      logToConsole("warn", "[MeetCat] Failed to load next trigger", {
+        error: error instanceof Error ? error.message : String(error),
+      });
+    }
   }
+  await syncJoinedMeetings();
+  return getNextJoinableMeeting(meetings, {
+    gracePeriodMinutes: settings?.maxMinutesAfterStart ?? SETTINGS_DEFAULTS.maxMinutesAfterStart,
+    alreadyJoined: joinedMeetings,
+    suppressedMeetings,
+    joinBeforeMinutes: settings?.joinBeforeMinutes ?? SETTINGS_DEFAULTS.joinBeforeMinutes,
+  });
 }
 
 /**
@@ -720,14 +981,7 @@ function createOverlay(): void {
 
   // Update with current next meeting
   if (lastMeetings.length > 0) {
-    void syncJoinedMeetings().then(() => {
-      const nextMeeting = getNextJoinableMeeting(lastMeetings, {
-        gracePeriodMinutes:
-          settings?.maxMinutesAfterStart ?? SETTINGS_DEFAULTS.maxMinutesAfterStart,
-        alreadyJoined: joinedMeetings,
-        suppressedMeetings,
-        joinBeforeMinutes: settings?.joinBeforeMinutes ?? SETTINGS_DEFAULTS.joinBeforeMinutes,
-      });
+    void resolveOverlayMeeting(lastMeetings).then((nextMeeting) => {
       overlay?.update(nextMeeting);
     });
   }
@@ -953,6 +1207,22 @@ function updateOverlayVisibility(): void {
   }
 }
 
+/**
+ * Reload only the subsystems whose settings changed in the last save
+ */
+function handleSettingsDelta(delta: SettingsDelta): void {
+  const changed = new Set(delta.changedKeys);
+  if (changed.has("showCountdownOverlay")) {
+    updateOverlayVisibility();
+  }
+  if (changed.has("language") && settings?.language) {
+    void changeLanguage(settings.language as LanguageSetting);
+  }
+  logToDisk("debug", "settings", "settings.delta", "Settings delta applied", {
+    changedKeys: delta.changedKeys,
+  });
+}
+
 /**
  * Handle navigate-and-join command from Rust
  */
@@ -960,13 +1230,62 @@ function handleNavigateAndJoin(cmd: NavigateAndJoinCommand): void {
   logToConsole("info", "[MeetCat] Navigate and join:", { url: cmd.url });
   logToDisk("info", "meeting", "navigate_and_join", "Navigate and join", {
     url: cmd.url,
+    autoClickJoin: cmd.autoClickJoin ?? null,
+    joinId: cmd.joinId,
   });
 
   // Update settings with the ones from the command
   settings = cmd.settings;
 
-  // Navigate to meeting URL
-  location.href = appendAutoJoinParam(cmd.url);
+  // Navigate to meeting URL, carrying any per-meeting auto-click override
+  location.href = appendAutoJoinParam(cmd.url, cmd.autoClickJoin);
+}
+
+/**
+ * Handle navigate-to-green-room command from Rust: open the meeting's pre-join
+ * page without the auto-join marker so nothing is clicked yet
+ */
+function handleNavigateToGreenRoom(cmd: NavigateAndJoinCommand): void {
+  logToConsole("info", "[MeetCat] Navigate to green room:", { url: cmd.url });
+  logToDisk("info", "meeting", "navigate_to_green_room", "Navigate to green room", {
+    url: cmd.url,
+  });
+
+  settings = cmd.settings;
+  location.href = cmd.url;
+}
+
+/**
+ * Handle green-room-join command from Rust: the join phase reached a meeting
+ * page opened by the green-room phase, so start the countdown in place
+ */
+function handleGreenRoomJoin(cmd: NavigateAndJoinCommand): void {
+  let callId: string | null = null;
+  try {
+    callId = getMeetingCodeFromPath(new URL(cmd.url).pathname);
+  } catch {
+    callId = null;
+  }
+
+  if (!callId || callId !== currentMeetingCallId) {
+    logToDisk("info", "meeting", "green_room.join_skipped", "Green room join for another page", {
+      callId,
+      currentCallId: currentMeetingCallId,
+    });
+    return;
+  }
+
+  settings = cmd.settings;
+  const autoClickJoin = cmd.autoClickJoin ?? settings?.autoClickJoin;
+  logToDisk("info", "meeting", "green_room.join", "Joining from green room", {
+    callId,
+    autoClickJoin,
+    joinId: cmd.joinId,
+  });
+
+  if (autoClickJoin) {
+    startJoinCountdown();
+  }
 }
 
 /**
@@ -974,10 +1293,21 @@ function handleNavigateAndJoin(cmd: NavigateAndJoinCommand): void {
  */
 async function initMeetingPage(): Promise<void> {
   autoJoinBlocked = false;
+  joinRestrictionReported = false;
   const meetingCode = getMeetingCodeFromPath(location.pathname);
   logToConsole("info", "[MeetCat] Initializing meeting page:", {
     callId: meetingCode,
   });
+  if (breakoutParentCallId) {
+    // Meet opened another room of the call; keep following the joined meeting
+    currentMeetingCallId = breakoutParentCallId;
+    logToDisk("info", "meeting", "breakout.page_init", "Breakout room page init", {
+      callId: breakoutParentCallId,
+      roomCode: meetingCode,
+    });
+    startPresencePolling(breakoutParentCallId);
+    return;
+  }
   currentMeetingCallId = meetingCode;
   const isAutoJoinRequested = hasAutoJoinParam(location.href);
   logToDisk("info", "meeting", "meeting.init", "Meeting page init", {
@@ -985,9 +1315,21 @@ async function initMeetingPage(): Promise<void> {
     autoJoinRequested: isAutoJoinRequested,
   });
 
+  if (isTauriEnvironment()) {
+    try {
+      const unsubGreenRoomJoin = await onGreenRoomJoin(handleGreenRoomJoin);
+      unsubscribers.push(unsubGreenRoomJoin);
+    } catch (e) {
+      console.warn("[MeetCat] Failed to listen for green-room-join:", e);
+    }
+  }
+
   // Wait for media buttons to appear
   await waitForMediaButtons();
 
+  // The restriction page has no media buttons and must not be retried
+  if (detectJoinRestriction("meeting.init")) return;
+
   // Apply media settings
   await applyMediaSettings();
 
@@ -996,9 +1338,15 @@ async function initMeetingPage(): Promise<void> {
     return;
   }
 
-  // Start join countdown for auto-join (UI always shown on meeting page)
-  if (settings?.autoClickJoin) {
+  // Start join countdown for auto-join (UI always shown on meeting page);
+  // a per-meeting override from the marker wins over the global setting
+  const autoClickJoin = getAutoClickOverride(location.href) ?? settings?.autoClickJoin;
+  if (autoClickJoin) {
     startJoinCountdown();
+  } else {
+    logToDisk("info", "meeting", "auto_click.skipped", "Auto-click disabled, navigate only", {
+      callId: meetingCode,
+    });
   }
 }
 
@@ -1085,6 +1433,9 @@ function startJoinCountdown(): void {
   if (detectEnteredMeeting("countdown.precheck")) {
     return;
   }
+  if (detectJoinRestriction("countdown.precheck")) {
+    return;
+  }
 
   const seconds = settings.joinCountdownSeconds ?? SETTINGS_DEFAULTS.joinCountdownSeconds;
 
@@ -1097,6 +1448,11 @@ function startJoinCountdown(): void {
   logToDisk("info", "meeting", "join.countdown_start", "Join countdown started", {
     seconds,
   });
+  if (isTauriEnvironment()) {
+    reportJoinCountdownStarted(currentMeetingCallId, seconds).catch((e) =>
+      console.error("[MeetCat] Failed to report join countdown:", e)
+    );
+  }
 
   countdown = createJoinCountdown(document.body, {
     seconds,
@@ -1134,6 +1490,7 @@ function performJoin(): void {
   if (joinAttempted) return;
   if (autoJoinBlocked) return;
   if (detectEnteredMeeting("join.precheck")) return;
+  if (detectJoinRestriction("join.precheck")) return;
   joinAttempted = true;
 
   const success = clickJoinButton(document);
@@ -1146,6 +1503,9 @@ function performJoin(): void {
     const meetingCode = getMeetingCodeFromPath(location.pathname);
     if (meetingCode) {
       reportJoinedOnce(meetingCode);
+      reportJoinCompleted(meetingCode).catch((e) =>
+        console.error("[MeetCat] Failed to report join completion:", e)
+      );
     }
 
   }
@@ -1165,7 +1525,9 @@ function cleanupCountdown(): void {
 /**
  * Cleanup all resources
  */
-function cleanup(reason: "beforeunload" | "navigation" | "manual" = "manual"): void {
+function cleanup(
+  reason: "beforeunload" | "navigation" | "reinject" | "manual" = "manual"
+): void {
   logToConsole("info", "[MeetCat] Cleaning up", {
     reason,
     callId: currentMeetingCallId,
@@ -1175,7 +1537,17 @@ function cleanup(reason: "beforeunload" | "navigation" | "manual" = "manual"): v
     callId: currentMeetingCallId,
   });
 
-  if (currentMeetingCallId) {
+  // Meet moves a page between breakout rooms by navigating; the call goes on
+  const breakoutHop =
+    reason === "navigation" &&
+    breakoutParentCallId !== null &&
+    getMeetingCodeFromPath(location.pathname) !== null;
+  if (reason !== "reinject" && !breakoutHop) {
+    reportBreakoutLeft(reason);
+  }
+
+  // A re-injected script picks the call up again, so it stays open
+  if (currentMeetingCallId && reason !== "reinject" && !breakoutHop) {
     logToDisk("info", "meeting", "meeting.closed_report", "Reporting meeting closed", {
       callId: currentMeetingCallId,
       closedAtMs: Date.now(),
@@ -1194,6 +1566,7 @@ function cleanup(reason: "beforeunload" | "navigation" | "manual" = "manual"): v
   stopFallbackInterval();
   stopWakeDetector();
   stopMeetingEntryObserver();
+  stopPresencePolling();
 
   // Destroy overlays
   if (overlay) {
@@ -1222,6 +1595,24 @@ function cleanup(reason: "beforeunload" | "navigation" | "manual" = "manual"): v
   reloadInFlightSince = null;
 }
 
+/**
+ * Run the parser self-test and send the report to the desktop app
+ */
+function handleParserSelfTest(requestId: string): void {
+  const report = runParserSelfTest(document);
+  logToDisk("info", "parser", "selftest.ran", "Parser self-test ran", {
+    passed: report.passed,
+    failed: report.failed,
+    total: report.total,
+    liveCardsFound: report.liveCardsFound,
+  });
+  reportParserSelfTest(requestId, report).catch((e) => {
+    console.warn("[MeetCat] Failed to report parser self-test:", e);
+  });
+}
+
+window.__meetcatParserSelfTest = handleParserSelfTest;
+
 // Initialize on DOMContentLoaded or immediately if already loaded
 if (document.readyState === "loading") {
   document.addEventListener("DOMContentLoaded", init);
@@ -4,3 +4,10 @@ export {
   getNextJoinableMeeting,
   MEETING_CARD_SELECTOR,
 } from "./meeting-cards.js";
+export {
+  runParserSelfTest,
+  PARSER_SELFTEST_SAMPLES,
+  type ParserSelfTestSample,
+  type ParserSelfTestCaseResult,
+  type ParserSelfTestReport,
+} from "./selftest.js";
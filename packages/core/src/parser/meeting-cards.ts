@@ -1,10 +1,13 @@
-import type { Meeting, ParseResult } from "../types.js";
+import type { Meeting, MeetingTimeAccuracy, ParseResult } from "../types.js";
 
 /**
  * Selector for meeting cards on Google Meet homepage
  */
 export const MEETING_CARD_SELECTOR = "[data-call-id]";
 
+/** Length assumed for a meeting whose card has no end time */
+const INFERRED_DURATION_MS = 30 * 60 * 1000;
+
 
 /**
  * Parse a single meeting card element
@@ -18,13 +21,7 @@ export function parseMeetingCard(
   const callId = card.getAttribute("data-call-id");
   if (!callId) return null;
 
-  const beginTimeRaw = card.getAttribute("data-begin-time");
-  const endTimeRaw = card.getAttribute("data-end-time");
-  if (!beginTimeRaw || !endTimeRaw) return null;
-
-  const beginTime = parseInt(beginTimeRaw, 10);
-  const endTime = parseInt(endTimeRaw, 10);
-  if (isNaN(beginTime) || isNaN(endTime)) return null;
+  const { beginTime, endTime, accuracy } = readCardTimes(card, now);
 
   const eventId = card.getAttribute("data-event-id");
   const ariaLabel =
@@ -51,9 +48,46 @@ export function parseMeetingCard(
     endTime: new Date(endTime),
     eventId,
     startsInMinutes,
+    accuracy,
   };
 }
 
+function parseTimestamp(raw: string | null): number | null {
+  if (!raw) return null;
+  const value = parseInt(raw, 10);
+  return isNaN(value) ? null : value;
+}
+
+/**
+ * Read a card's start and end times. A missing end is estimated from the
+ * start, a missing start ("Now" cards) from the current time, and a card
+ * without either (all-day rows) is placed from now to the end of the day.
+ */
+function readCardTimes(
+  card: Element,
+  now: number
+): { beginTime: number; endTime: number; accuracy: MeetingTimeAccuracy } {
+  const begin = parseTimestamp(card.getAttribute("data-begin-time"));
+  const end = parseTimestamp(card.getAttribute("data-end-time"));
+
+  if (begin !== null && end !== null) {
+    return { beginTime: begin, endTime: end, accuracy: "exact" };
+  }
+  if (begin !== null) {
+    return {
+      beginTime: begin,
+      endTime: begin + INFERRED_DURATION_MS,
+      accuracy: "inferred",
+    };
+  }
+  if (end !== null) {
+    return { beginTime: Math.min(now, end), endTime: end, accuracy: "inferred" };
+  }
+  const endOfDay = new Date(now);
+  endOfDay.setHours(24, 0, 0, 0);
+  return { beginTime: now, endTime: endOfDay.getTime(), accuracy: "unknown" };
+}
+
 function extractMeetingTitle(card: Element, ariaLabel: string): string {
   const candidates = collectTextCandidates(card);
   if (!candidates.length) return fallbackTitleFromAria(ariaLabel);
@@ -168,6 +202,11 @@ export function parseMeetingCards(
 
   const meetings: Meeting[] = [];
   const hiddenReasons: Record<string, number> = {};
+  const timeAccuracy: Record<MeetingTimeAccuracy, number> = {
+    exact: 0,
+    inferred: 0,
+    unknown: 0,
+  };
   let hiddenCards = 0;
 
   for (const card of cards) {
@@ -180,6 +219,7 @@ export function parseMeetingCards(
     const meeting = parseMeetingCard(card, now);
     if (meeting) {
       meetings.push(meeting);
+      timeAccuracy[meeting.accuracy ?? "exact"] += 1;
     }
   }
 
@@ -191,6 +231,7 @@ export function parseMeetingCards(
     cardsFound: cards.length,
     hiddenCards,
     hiddenReasons,
+    timeAccuracy,
   };
 }
 
@@ -239,6 +280,9 @@ export function getNextJoinableMeeting(
     // Skip already joined only after meeting starts
     if (alreadyJoined.has(meeting.callId) && startTime <= now) continue;
 
+    // Never auto-join on estimated times
+    if (meeting.accuracy && meeting.accuracy !== "exact") continue;
+
     // Skip if doesn't match filter
     if (titleFilter && !meeting.title.includes(titleFilter)) continue;
 
@@ -0,0 +1,176 @@
+import { parseMeetingCards } from "./meeting-cards.js";
+
+/**
+ * A sample of Google Meet homepage markup with the meetings it should yield.
+ *
+ * `{{begin:N}}` / `{{end:N}}` placeholders are replaced with timestamps
+ * N minutes from the time the self-test runs.
+ */
+export interface ParserSelfTestSample {
+  id: string;
+  locale: string;
+  html: string;
+  expected: Array<{ callId: string; title: string; startsInMinutes: number }>;
+}
+
+export interface ParserSelfTestCaseResult {
+  id: string;
+  locale: string;
+  passed: boolean;
+  /** Human-readable differences between parsed and expected meetings */
+  mismatches: string[];
+}
+
+export interface ParserSelfTestReport {
+  passed: boolean;
+  total: number;
+  failed: number;
+  cases: ParserSelfTestCaseResult[];
+  /** Cards and meetings found on the live page, for comparison */
+  liveCardsFound: number;
+  liveMeetings: number;
+  ranAtMs: number;
+}
+
+function card(
+  callId: string,
+  startMinutes: number,
+  durationMinutes: number,
+  ariaLabel: string,
+  texts: string[],
+  attrs = ""
+): string {
+  const children = texts.map((text) => `<div>${text}</div>`).join("");
+  return (
+    `<div data-call-id="${callId}" data-begin-time="{{begin:${startMinutes}}}" ` +
+    `data-end-time="{{end:${startMinutes + durationMinutes}}}" ` +
+    `data-aria-label-static="${ariaLabel}" ${attrs}>${children}</div>`
+  );
+}
+
+/**
+ * Homepage card markup as rendered for several UI languages
+ */
+export const PARSER_SELFTEST_SAMPLES: ParserSelfTestSample[] = [
+  {
+    id: "en-basic",
+    locale: "en-US",
+    html: card("aaa-bbbb-ccc", 10, 30, "10:00 AM to 10:30 AM. Weekly Sync. Join with Google Meet.", [
+      "10:00 AM",
+      "Weekly Sync",
+    ]),
+    expected: [{ callId: "aaa-bbbb-ccc", title: "Weekly Sync", startsInMinutes: 10 }],
+  },
+  {
+    id: "zh-basic",
+    locale: "zh-CN",
+    html: card("ddd-eeee-fff", 5, 60, "上午10:00至上午11:00。产品评审。", ["上午10:00", "产品评审"]),
+    expected: [{ callId: "ddd-eeee-fff", title: "产品评审", startsInMinutes: 5 }],
+  },
+  {
+    id: "ja-basic",
+    locale: "ja-JP",
+    html: card("ggg-hhhh-iii", 45, 30, "10:00～10:30。デザインレビュー。", ["10:00", "デザインレビュー"]),
+    expected: [{ callId: "ggg-hhhh-iii", title: "デザインレビュー", startsInMinutes: 45 }],
+  },
+  {
+    id: "ko-basic",
+    locale: "ko-KR",
+    html: card("jjj-kkkk-lll", 20, 30, "오전 10:00~오전 10:30. 주간 회의.", ["오전 10:00", "주간 회의"]),
+    expected: [{ callId: "jjj-kkkk-lll", title: "주간 회의", startsInMinutes: 20 }],
+  },
+  {
+    id: "de-basic",
+    locale: "de-DE",
+    html: card("mmm-nnnn-ooo", 90, 45, "10:00 bis 10:45. Teambesprechung.", [
+      "10:00",
+      "Teambesprechung",
+    ]),
+    expected: [{ callId: "mmm-nnnn-ooo", title: "Teambesprechung", startsInMinutes: 90 }],
+  },
+  {
+    id: "en-ordering-hidden",
+    locale: "en-US",
+    html:
+      card("ppp-qqqq-rrr", 120, 30, "12:00 PM to 12:30 PM. Later Meeting.", ["12:00 PM", "Later Meeting"]) +
+      card("sss-tttt-uuu", -2, 30, "9:58 AM to 10:28 AM. Started Meeting.", ["9:58 AM", "Started Meeting"]) +
+      `<div hidden>${card("vvv-wwww-xxx", 30, 30, "Hidden.", ["Hidden Meeting"])}</div>`,
+    expected: [
+      { callId: "sss-tttt-uuu", title: "Started Meeting", startsInMinutes: -2 },
+      { callId: "ppp-qqqq-rrr", title: "Later Meeting", startsInMinutes: 120 },
+    ],
+  },
+];
+
+function renderSample(html: string, now: number): string {
+  return html.replace(/\{\{(begin|end):(-?\d+)\}\}/g, (_, _kind: string, minutes: string) =>
+    String(now + parseInt(minutes, 10) * 60_000)
+  );
+}
+
+function compareSample(
+  sample: ParserSelfTestSample,
+  container: Element,
+  now: number
+): ParserSelfTestCaseResult {
+  container.innerHTML = renderSample(sample.html, now);
+  const { meetings } = parseMeetingCards(container, now);
+  const mismatches: string[] = [];
+
+  if (meetings.length !== sample.expected.length) {
+    mismatches.push(`expected ${sample.expected.length} meetings, parsed ${meetings.length}`);
+  }
+  sample.expected.forEach((expected, index) => {
+    const actual = meetings[index];
+    if (!actual) return;
+    for (const key of ["callId", "title", "startsInMinutes"] as const) {
+      if (actual[key] !== expected[key]) {
+        mismatches.push(
+          `#${index} ${key}: expected ${JSON.stringify(expected[key])}, got ${JSON.stringify(actual[key])}`
+        );
+      }
+    }
+  });
+
+  return { id: sample.id, locale: sample.locale, passed: mismatches.length === 0, mismatches };
+}
+
+/**
+ * Run the meeting parser against embedded samples and compare with fixtures.
+ *
+ * Runs synchronously so the temporary sample cards are removed before any
+ * other script on the page (including MeetCat's own meeting checks) can see
+ * them. The container is kept on-screen-sized but off-viewport because the
+ * parser skips cards without layout boxes.
+ */
+export function runParserSelfTest(
+  doc: Document,
+  now: number = Date.now(),
+  samples: ParserSelfTestSample[] = PARSER_SELFTEST_SAMPLES
+): ParserSelfTestReport {
+  const live = parseMeetingCards(doc, now);
+
+  const container = doc.createElement("div");
+  container.setAttribute("data-meetcat-selftest", "");
+  container.style.cssText =
+    "position:fixed;left:-10000px;top:0;width:400px;opacity:0;pointer-events:none;";
+  (doc.body ?? doc.documentElement).appendChild(container);
+
+  let cases: ParserSelfTestCaseResult[];
+  try {
+    cases = samples.map((sample) => compareSample(sample, container, now));
+  } finally {
+    container.remove();
+  }
+
+  const failed = cases.filter((result) => !result.passed).length;
+  return {
+    passed: failed === 0,
+    total: cases.length,
+    failed,
+    cases,
+    liveCardsFound: live.cardsFound,
+    liveMeetings: live.meetings.length,
+    ranAtMs: now,
+  };
+}
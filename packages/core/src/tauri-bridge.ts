@@ -5,7 +5,8 @@
  * JavaScript code running in the WebView and the Rust backend.
  */
 
-import type { Meeting } from "./types.js";
+import type { CallPresence, JoinRestrictionResult, Meeting } from "./types.js";
+import type { ParserSelfTestReport } from "./parser/selftest.js";
 
 /**
  * Settings structure matching Rust Settings
@@ -48,6 +49,23 @@ export type CheckMeetingsPayload = {
   emittedAtMs: number;
 };
 
+/**
+ * Liveness ping sent after each check; unanswered pings make the desktop
+ * app inject the script again
+ */
+export type ScriptPingPayload = {
+  pingId: number;
+};
+
+/**
+ * Settings keys that changed in one save, e.g. "showCountdownOverlay" or
+ * "tauri.powerMode", with their old and new values
+ */
+export interface SettingsDelta {
+  changedKeys: string[];
+  changes: Record<string, { from: unknown; to: unknown }>;
+}
+
 export interface UpdateInfo {
   version: string;
   notes?: string | null;
@@ -59,12 +77,150 @@ export interface UpdatePromptPreference {
   remindUntilMs?: number;
 }
 
+/**
+ * Sent `reminderMinutesBefore` ahead of an auto-join; the meeting keeps the
+ * Rust field names
+ */
+export interface JoinReminderPayload {
+  meeting: {
+    call_id: string;
+    url: string;
+    title: string;
+    display_time: string;
+    begin_time: string;
+    end_time: string;
+  };
+  joinAtMs: number;
+  minutesBefore: number;
+}
+
+/**
+ * The join the daemon triggers next, from `get_next_trigger`
+ */
+export interface ScheduledTrigger {
+  call_id: string;
+  trigger_at_ms: number;
+  delay_ms: number;
+  reason: "scheduled" | "join_window" | "snoozed" | "busy_block";
+  countdown_seconds: number;
+  /** A "confirm" busy block asks before this join */
+  busy_hold: { block_title: string; block_end: string; policy: "delay" | "confirm" } | null;
+  /** Overlapping meetings the user picks from when it fires */
+  conflict: string[];
+}
+
+/**
+ * What the daemon intends to do, and what holds other joins back
+ */
+export interface TriggerSchedule {
+  /** null when no meeting will be joined */
+  next: ScheduledTrigger | null;
+  paused_until_ms: number | null;
+  rate_capped: boolean;
+  suppressed: string[];
+  cancelled: string[];
+  /** When each snoozed meeting may trigger again */
+  snoozed_until_ms: Record<string, number>;
+}
+
+/**
+ * Payload of the `meeting-conflict` event: overlapping meetings held until the
+ * user picks one, under the "promptUser" conflict policy
+ */
+export interface MeetingConflictPayload {
+  meetings: JoinReminderPayload["meeting"][];
+}
+
+/**
+ * What made MeetCat show or focus one of its windows
+ */
+export type WindowShowCause =
+  | "auto_join"
+  | "green_room"
+  | "background_join"
+  | "rate_capped"
+  | "tray"
+  | "reopen"
+  | "shortcut"
+  | "deep_link"
+  | "navigate_home";
+
+/**
+ * One recorded window show, from `get_window_activity` and the `window:shown` event
+ */
+export interface WindowActivity {
+  atMs: number;
+  label: string;
+  cause: WindowShowCause;
+  callId: string | null;
+  /** null in privacy mode */
+  title: string | null;
+}
+
+/**
+ * Payload of the `shortcut:toggle-mic` event: the window whose call to mute or unmute
+ */
+export interface ToggleMicCommand {
+  window: string;
+}
+
+/**
+ * Payload of the `tray:leave-meeting` event: the window whose call to leave
+ */
+export interface LeaveMeetingCommand {
+  window: string;
+}
+
+/**
+ * Payload of the `tray:copy-meeting-link` event: the window in the meeting and
+ * the link to copy
+ */
+export interface CopyMeetingLinkCommand {
+  window: string;
+  url: string;
+}
+
+/**
+ * Outcome of one configured global shortcut, from `get_shortcut_status`
+ */
+export interface ShortcutStatus {
+  action: "join_next" | "cancel_join" | "toggle_mic";
+  accelerator: string;
+  registered: boolean;
+  /** Why the shortcut is not active, e.g. invalid or taken by another app */
+  error: string | null;
+}
+
+/**
+ * Camera and microphone privacy permission status. Only macOS reports it;
+ * elsewhere both are "unknown".
+ */
+export type PermissionStatus =
+  | "authorized"
+  | "denied"
+  | "restricted"
+  | "undetermined"
+  | "unknown";
+
+export type MediaDevice = "camera" | "microphone";
+
+export interface MediaPermissions {
+  camera: PermissionStatus;
+  microphone: PermissionStatus;
+}
+
 /**
  * Navigation command from Rust
  */
 export interface NavigateAndJoinCommand {
   url: string;
   settings: TauriSettings;
+  /** Per-meeting override of `settings.autoClickJoin`; null follows the setting */
+  autoClickJoin?: boolean | null;
+  /** Correlation id shared by the log entries of this join attempt */
+  joinId?: string;
+  /** Label of the window that acts on the command, e.g. "main" or "profile-work" */
+  window?: string;
 }
 
 /**
@@ -82,6 +238,23 @@ function getTauriApi(): typeof window.__TAURI__ | null {
   return window.__TAURI__;
 }
 
+/**
+ * Label of the window this script runs in; null outside Tauri
+ */
+export function currentWindowLabel(): string | null {
+  if (!isTauriEnvironment()) return null;
+  return window.__TAURI_INTERNALS__?.metadata?.currentWindow?.label ?? null;
+}
+
+/**
+ * Whether a navigation command is meant for this window. Commands without a
+ * target, or a window whose label can't be read, are treated as the main window.
+ */
+function isCommandForThisWindow(cmd: NavigateAndJoinCommand): boolean {
+  const target = cmd.window ?? "main";
+  return target === (currentWindowLabel() ?? "main");
+}
+
 /**
  * Invoke a Tauri command
  */
@@ -121,6 +294,7 @@ export async function reportMeetings(meetings: Meeting[]): Promise<void> {
     end_time: m.endTime.toISOString(),
     event_id: m.eventId,
     starts_in_minutes: m.startsInMinutes,
+    accuracy: m.accuracy ?? "exact",
   }));
   await invoke("meetings_updated", { meetings: serializedMeetings });
 }
@@ -139,6 +313,52 @@ export async function reportJoined(callId: string): Promise<void> {
   await invoke("meeting_joined", { callId });
 }
 
+/**
+ * Report that auto-click pressed the join button, so a window that joined in
+ * the background is brought to front
+ */
+export async function reportJoinCompleted(callId: string): Promise<void> {
+  await invoke("join_completed", { callId });
+}
+
+/**
+ * Report what the meeting page shows about the call after joining
+ */
+export async function reportCallPresence(
+  callId: string,
+  presence: CallPresence
+): Promise<void> {
+  await invoke("meeting_presence", { callId, presence });
+}
+
+/**
+ * Report that Meet moved the page of a joined meeting into a breakout room;
+ * the meeting stays joined while in the room
+ */
+export async function reportBreakoutEntered(
+  callId: string,
+  roomName: string | null
+): Promise<void> {
+  await invoke("breakout_entered", { callId, roomName });
+}
+
+/**
+ * Report that the page returned from its breakout room to the main call
+ */
+export async function reportBreakoutExited(callId: string): Promise<void> {
+  await invoke("breakout_exited", { callId });
+}
+
+/**
+ * Report that the join countdown started, for screen reader announcements
+ */
+export async function reportJoinCountdownStarted(
+  callId: string | null,
+  seconds: number
+): Promise<void> {
+  await invoke("join_countdown_started", { callId, seconds });
+}
+
 /**
  * Report that a meeting page was closed
  */
@@ -146,6 +366,20 @@ export async function reportMeetingClosed(callId: string, closedAtMs: number): P
   await invoke("meeting_closed", { callId, closedAtMs });
 }
 
+/**
+ * Report that Google Meet refused to let this account join a meeting
+ */
+export async function reportJoinBlocked(
+  callId: string,
+  restriction: JoinRestrictionResult
+): Promise<void> {
+  await invoke("meeting_join_blocked", {
+    callId,
+    reason: restriction.reason,
+    matchedText: restriction.matchedText,
+  });
+}
+
 /**
  * Get joined meeting call IDs from Rust backend
  */
@@ -154,10 +388,36 @@ export async function getJoinedMeetings(): Promise<string[]> {
 }
 
 /**
- * Get suppressed meeting call IDs from Rust backend
+ * A suppressed meeting instance, from `get_suppressed_meetings`. It expires
+ * when the instance ends, so later instances of a recurring series still join.
+ */
+export interface Suppression {
+  call_id: string;
+  begin_time: string;
+  end_time: string;
+  suppressed_at_ms: number;
+  reason: "closed" | "cancelled" | "away" | "deep_link";
+}
+
+/**
+ * Get suppressed meetings from Rust backend
  */
-export async function getSuppressedMeetings(): Promise<string[]> {
-  return invoke<string[]>("get_suppressed_meetings");
+export async function getSuppressedMeetings(): Promise<Suppression[]> {
+  return invoke<Suppression[]>("get_suppressed_meetings");
+}
+
+/**
+ * Let auto-join pick a suppressed meeting again
+ */
+export async function clearSuppressed(callId: string): Promise<boolean> {
+  return invoke<boolean>("clear_suppressed", { callId });
+}
+
+/**
+ * Get the join the daemon triggers next, so countdowns match its schedule
+ */
+export async function getNextTrigger(): Promise<TriggerSchedule> {
+  return invoke<TriggerSchedule>("get_next_trigger");
 }
 
 /**
@@ -169,13 +429,158 @@ export async function onCheckMeetings(
   return listen<CheckMeetingsPayload>("check-meetings", handler);
 }
 
+/**
+ * Listen for liveness pings from Rust
+ */
+export async function onScriptPing(
+  handler: (payload: ScriptPingPayload) => void
+): Promise<() => void> {
+  return listen<ScriptPingPayload>("script-ping", handler);
+}
+
+/**
+ * Answer a liveness ping
+ */
+export async function reportScriptPong(pingId: number): Promise<void> {
+  await invoke("script_pong", { pingId });
+}
+
 /**
  * Listen for navigate-and-join command from Rust
  */
 export async function onNavigateAndJoin(
   handler: (cmd: NavigateAndJoinCommand) => void
 ): Promise<() => void> {
-  return listen<NavigateAndJoinCommand>("navigate-and-join", handler);
+  return listen<NavigateAndJoinCommand>("navigate-and-join", (cmd) => {
+    if (isCommandForThisWindow(cmd)) handler(cmd);
+  });
+}
+
+/**
+ * Listen for the green-room phase: open a meeting's pre-join page without joining
+ */
+export async function onNavigateToGreenRoom(
+  handler: (cmd: NavigateAndJoinCommand) => void
+): Promise<() => void> {
+  return listen<NavigateAndJoinCommand>("navigate-to-green-room", (cmd) => {
+    if (isCommandForThisWindow(cmd)) handler(cmd);
+  });
+}
+
+/**
+ * Listen for the join phase on a meeting page opened by the green-room phase
+ */
+export async function onGreenRoomJoin(
+  handler: (cmd: NavigateAndJoinCommand) => void
+): Promise<() => void> {
+  return listen<NavigateAndJoinCommand>("green-room-join", (cmd) => {
+    if (isCommandForThisWindow(cmd)) handler(cmd);
+  });
+}
+
+/**
+ * Listen for the reminder sent ahead of an auto-join
+ */
+export async function onJoinReminder(
+  handler: (payload: JoinReminderPayload) => void
+): Promise<() => void> {
+  return listen<JoinReminderPayload>("join-reminder", handler);
+}
+
+/**
+ * Listen for overlapping meetings that wait for the user to pick one
+ */
+export async function onMeetingConflict(
+  handler: (payload: MeetingConflictPayload) => void
+): Promise<() => void> {
+  return listen<MeetingConflictPayload>("meeting-conflict", handler);
+}
+
+/**
+ * Join the picked meeting of a `meeting-conflict` prompt; the others are skipped
+ */
+export async function chooseConflictMeeting(callId: string): Promise<void> {
+  await invoke("choose_conflict_meeting", { callId });
+}
+
+/**
+ * Listen for MeetCat bringing this window forward on its own
+ */
+export async function onWindowShown(
+  handler: (activity: WindowActivity) => void
+): Promise<() => void> {
+  return listen<WindowActivity>("window:shown", (activity) => {
+    if (activity.label === (currentWindowLabel() ?? "main")) handler(activity);
+  });
+}
+
+/**
+ * Why MeetCat recently showed its windows, newest first
+ */
+export async function getWindowActivity(limit?: number): Promise<WindowActivity[]> {
+  return invoke<WindowActivity[]>("get_window_activity", { limit });
+}
+
+/**
+ * Listen for the global mute shortcut aimed at this window
+ */
+export async function onToggleMic(handler: () => void): Promise<() => void> {
+  return listen<ToggleMicCommand>("shortcut:toggle-mic", (cmd) => {
+    if (cmd.window === (currentWindowLabel() ?? "main")) handler();
+  });
+}
+
+/**
+ * Listen for the tray's "Leave meeting" aimed at this window
+ */
+export async function onLeaveMeeting(handler: () => void): Promise<() => void> {
+  return listen<LeaveMeetingCommand>("tray:leave-meeting", (cmd) => {
+    if (cmd.window === (currentWindowLabel() ?? "main")) handler();
+  });
+}
+
+/**
+ * Listen for the tray's "Copy meeting link" aimed at this window
+ */
+export async function onCopyMeetingLink(handler: (url: string) => void): Promise<() => void> {
+  return listen<CopyMeetingLinkCommand>("tray:copy-meeting-link", (cmd) => {
+    if (cmd.window === (currentWindowLabel() ?? "main")) handler(cmd.url);
+  });
+}
+
+/**
+ * Which global shortcuts are active, and why the others are not
+ */
+export async function getShortcutStatus(): Promise<ShortcutStatus[]> {
+  return invoke<ShortcutStatus[]>("get_shortcut_status");
+}
+
+/**
+ * Camera and microphone permission status, without prompting
+ */
+export async function checkMediaPermissions(): Promise<MediaPermissions> {
+  return invoke<MediaPermissions>("check_media_permissions");
+}
+
+/**
+ * Prompt for camera and microphone access where the user was never asked
+ */
+export async function requestMediaPermissions(): Promise<MediaPermissions> {
+  return invoke<MediaPermissions>("request_media_permissions");
+}
+
+/**
+ * Open the System Settings pane where access to `device` is granted
+ */
+export async function openMediaPermissionSettings(device: MediaDevice): Promise<void> {
+  await invoke("open_media_permission_settings", { device });
+}
+
+/**
+ * Skip a scheduled auto-join, e.g. from a reminder banner
+ */
+export async function skipPendingJoin(callId: string): Promise<void> {
+  await invoke("cancel_pending_join", { callId });
 }
 
 /**
@@ -187,6 +592,15 @@ export async function onSettingsChanged(
   return listen<TauriSettings>("settings_changed", handler);
 }
 
+/**
+ * Listen for the keys changed by a settings save; follows `settings_changed`
+ */
+export async function onSettingsDelta(
+  handler: (delta: SettingsDelta) => void
+): Promise<() => void> {
+  return listen<SettingsDelta>("settings-delta", handler);
+}
+
 export async function getUpdateInfo(): Promise<UpdateInfo | null> {
   return invoke<UpdateInfo | null>("get_update_info");
 }
@@ -221,6 +635,30 @@ export async function onUpdatePromptPreferenceChanged(
   return listen<UpdatePromptPreference>("update:preference-changed", handler);
 }
 
+/**
+ * Send a parser self-test report back for the request that started it
+ */
+export async function reportParserSelfTest(
+  requestId: string,
+  report: ParserSelfTestReport
+): Promise<void> {
+  await invoke("parser_selftest_result", { requestId, report });
+}
+
+/**
+ * JSON Schema of every event the desktop app emits, keyed by event name;
+ * validate or generate the payload types above against it
+ */
+export interface IpcSchema {
+  version: number;
+  $defs: Record<string, unknown>;
+  events: Record<string, Record<string, unknown>>;
+}
+
+export async function getIpcSchema(): Promise<IpcSchema> {
+  return invoke<IpcSchema>("get_ipc_schema");
+}
+
 /**
  * Send log event to Rust backend
  */
@@ -243,5 +681,8 @@ declare global {
         emit(event: string, payload?: unknown): Promise<void>;
       };
     };
+    __TAURI_INTERNALS__?: {
+      metadata?: { currentWindow?: { label: string } };
+    };
   }
 }
@@ -1,3 +1,11 @@
+/**
+ * How reliably a meeting's times were read from its card:
+ * - exact: both start and end times were on the card
+ * - inferred: one of them was missing and has been estimated
+ * - unknown: neither was there, e.g. all-day rows; the times are placeholders
+ */
+export type MeetingTimeAccuracy = "exact" | "inferred" | "unknown";
+
 /**
  * Represents a Google Meet meeting parsed from the homepage
  */
@@ -18,6 +26,8 @@ export interface Meeting {
   eventId: string | null;
   /** Minutes until meeting starts (negative if started) */
   startsInMinutes: number;
+  /** How reliable the times are (default: exact); only exact ones are auto-joined */
+  accuracy?: MeetingTimeAccuracy;
 }
 
 /**
@@ -32,6 +42,8 @@ export interface ParseResult {
   hiddenCards?: number;
   /** Hidden reason counts */
   hiddenReasons?: Record<string, number>;
+  /** Meeting counts by time accuracy */
+  timeAccuracy?: Record<MeetingTimeAccuracy, number>;
 }
 
 /**
@@ -82,6 +94,68 @@ export interface JoinButtonResult {
   matchedText: string | null;
 }
 
+/**
+ * Why Google Meet refused to let the signed-in account join a call
+ */
+export type JoinBlockedReason = "not_allowed" | "domain_restricted";
+
+/**
+ * Join restriction detected on the meeting page
+ */
+export interface JoinRestrictionResult {
+  reason: JoinBlockedReason;
+  /** Headline text that matched */
+  matchedText: string;
+}
+
+/**
+ * Breakout room detected on the meeting page
+ */
+export interface BreakoutRoomResult {
+  /** Label of the "return to main call" button that matched */
+  matchedText: string;
+  /** Room name Meet shows, null when it could not be read */
+  roomName: string | null;
+}
+
+/**
+ * What the meeting page shows about the call after joining
+ */
+export interface CallPresence {
+  /** A local microphone or camera track is live */
+  localMediaLive: boolean;
+  /** Participant count Meet shows inside a call, null when not shown */
+  participantCount: number | null;
+  /** Whether the mic button shows muted, null when it is not found */
+  micMuted: boolean | null;
+  /** Whether the camera button shows off, null when it is not found */
+  cameraMuted: boolean | null;
+}
+
+/**
+ * Lifecycle phase of a meeting tracked by the desktop app
+ */
+export type MeetingPhase =
+  | "scheduled"
+  | "triggered"
+  | "navigating"
+  | "joined"
+  | "left"
+  | "suppressed"
+  | "failed"
+  | "expired";
+
+/**
+ * Payload of the `meeting:lifecycle` event, emitted on every phase change
+ */
+export interface MeetingLifecycleEvent {
+  call_id: string;
+  /** null when the meeting started being tracked */
+  from: MeetingPhase | null;
+  to: MeetingPhase;
+  at_ms: number;
+}
+
 /**
  * Scheduler event types
  */
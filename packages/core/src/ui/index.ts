@@ -11,6 +11,13 @@ export {
   type JoinCountdownOptions,
 } from "./join-countdown.js";
 
+export {
+  createWindowNotice,
+  describeWindowActivity,
+  type WindowNotice,
+  type WindowNoticeOptions,
+} from "./window-notice.js";
+
 export { attachOverlayHideButton, type OverlayHideOptions } from "./overlay-controls.js";
 
 export { ensureStyles, createOverlayStyles } from "./styles.js";
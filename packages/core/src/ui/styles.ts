@@ -37,6 +37,12 @@ export function createOverlayStyles(): HTMLStyleElement {
       transform: translateX(-50%);
     }
 
+    .meetcat-overlay-bottom-center {
+      bottom: 24px;
+      left: 50%;
+      transform: translateX(-50%);
+    }
+
     .meetcat-icon {
       font-size: 20px;
       line-height: 1;
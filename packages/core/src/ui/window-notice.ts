@@ -0,0 +1,98 @@
+import { t } from "@meetcat/i18n";
+import type { WindowActivity, WindowShowCause } from "../tauri-bridge.js";
+import { ensureStyles } from "./styles.js";
+
+export interface WindowNoticeOptions {
+  /** Milliseconds before the notice removes itself (default: 5000) */
+  durationMs?: number;
+  /** URL for the icon image (uses emoji fallback if not provided) */
+  iconUrl?: string;
+}
+
+export interface WindowNotice {
+  /** Remove the notice from DOM */
+  destroy(): void;
+}
+
+const DEFAULT_DURATION_MS = 5000;
+
+const CAUSE_KEYS: Partial<Record<WindowShowCause, string>> = {
+  auto_join: "overlay.causeAutoJoin",
+  green_room: "overlay.causeGreenRoom",
+  background_join: "overlay.causeBackgroundJoin",
+  rate_capped: "overlay.causeRateCapped",
+  deep_link: "overlay.causeDeepLink",
+  navigate_home: "overlay.causeNavigateHome",
+};
+
+/**
+ * Text explaining why MeetCat showed the window, e.g.
+ * "Shown by MeetCat: auto-join for Standup"; null for causes the user
+ * started themselves
+ */
+export function describeWindowActivity(activity: WindowActivity): string | null {
+  const key = CAUSE_KEYS[activity.cause];
+  if (!key) return null;
+  const reason = t(key);
+  return activity.title
+    ? t("overlay.shownByFor", { reason, title: activity.title })
+    : t("overlay.shownBy", { reason });
+}
+
+/**
+ * Create icon element (img or span with emoji)
+ */
+function createIconElement(doc: Document, iconUrl?: string): HTMLElement {
+  if (iconUrl) {
+    const img = doc.createElement("img");
+    img.className = "meetcat-icon";
+    img.src = iconUrl;
+    img.alt = "MeetCat";
+    return img;
+  }
+  const span = doc.createElement("span");
+  span.className = "meetcat-icon";
+  span.textContent = "\u{1F431}";
+  return span;
+}
+
+/**
+ * Show a short-lived notice saying why MeetCat brought this window forward
+ *
+ * @param container - The element to append the notice to (usually document.body)
+ * @param text - Text from `describeWindowActivity`
+ * @param options - Notice options
+ * @returns WindowNotice interface
+ */
+export function createWindowNotice(
+  container: Element,
+  text: string,
+  options: WindowNoticeOptions = {}
+): WindowNotice {
+  const { durationMs = DEFAULT_DURATION_MS, iconUrl } = options;
+  const doc = container.ownerDocument;
+  ensureStyles(doc);
+
+  container.querySelector(".meetcat-window-notice")?.remove();
+
+  const notice = doc.createElement("div");
+  notice.className = "meetcat-overlay meetcat-overlay-bottom-center meetcat-window-notice";
+  notice.setAttribute("role", "status");
+  notice.appendChild(createIconElement(doc, iconUrl));
+
+  const textDiv = doc.createElement("div");
+  textDiv.className = "meetcat-text meetcat-title";
+  textDiv.textContent = text;
+  notice.appendChild(textDiv);
+
+  container.appendChild(notice);
+
+  const timeoutId = setTimeout(() => notice.remove(), durationMs);
+
+  return {
+    destroy(): void {
+      clearTimeout(timeoutId);
+      notice.remove();
+    },
+  };
+}
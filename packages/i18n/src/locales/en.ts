@@ -26,6 +26,18 @@ const en = {
     enterFilterText: "Enter filter text...",
     add: "Add",
     skipMatchingTitles: "Skip meetings whose titles contain these keywords",
+    enterTestTitle: "Test a meeting title...",
+    test: "Test",
+    titleFilterJoins: "Would be auto-joined",
+    titleFilterSkips: "Would be skipped by the filters",
+    calendarSubscriptions: "Calendar subscriptions",
+    enterCalendarSubscription: "ICS URL or file path...",
+    calendarSubscriptionsHint:
+      "Merge Meet events from ICS calendars (URL, webcal:// or file path)",
+    calendarSubscriptionInvalid:
+      "Enter an http(s) or webcal:// URL, or an absolute file path",
+    removeCalendarSubscription: "Remove subscription",
+    settingsNotSaved: "Some settings were not saved:",
     defaultMicrophone: "Default microphone",
     muted: "Muted",
     unmuted: "Unmuted",
@@ -63,6 +75,14 @@ const en = {
     temporarilyHide: "Temporarily hide",
     autoJoiningIn: "Auto-joining in ",
     cancel: "Cancel",
+    shownBy: "Shown by MeetCat: {{reason}}",
+    shownByFor: "Shown by MeetCat: {{reason}} for {{title}}",
+    causeAutoJoin: "auto-join",
+    causeGreenRoom: "green room",
+    causeBackgroundJoin: "joined in the background",
+    causeRateCapped: "auto-join limit reached",
+    causeDeepLink: "MeetCat link",
+    causeNavigateHome: "back to the homepage",
   },
 
   update: {
@@ -91,6 +111,76 @@ const en = {
     checking: "Checking...",
   },
 
+  joinByCode: {
+    title: "Join a meeting by code",
+    inputLabel: "Meeting code or link",
+    placeholder: "e.g. abc-defg-hij",
+    join: "Join",
+    joining: "Joining...",
+    recent: "Recent",
+    closeDialog: "Close join by code dialog",
+  },
+
+  history: {
+    weekSummary: "You auto-joined {{count}} meetings this week",
+    noteTitle: "Add a note",
+    notePlaceholder: "e.g. decisions, follow-ups",
+    saveNote: "Save",
+    savingNote: "Saving...",
+    closeNoteDialog: "Close note dialog",
+  },
+
+  troubleshooter: {
+    run: "Troubleshoot...",
+    title: "Troubleshooter",
+    closeDialog: "Close troubleshooter",
+    running: "Running checks...",
+    progress: "Step {{current}} of {{total}}",
+    back: "Back",
+    next: "Next",
+    runAgain: "Run again",
+    allPassed: "No problems found.",
+    someFailed:
+      "Some checks failed. Follow the suggested fixes and run the troubleshooter again.",
+    steps: {
+      injection: "MeetCat script",
+      parser: "Meeting detection",
+      permissions: "Camera and microphone",
+      reachability: "Google Meet connection",
+      clock: "System clock",
+      logging: "Log collection",
+    },
+    status: {
+      pass: "Passed",
+      warn: "Needs attention",
+      fail: "Failed",
+      skipped: "Skipped",
+    },
+    fixes: {
+      open_meet_home:
+        "Open Google Meet home in the main window and sign in, then run the troubleshooter again.",
+      check_for_updates:
+        "Google Meet may have changed its page. Check for a newer version of MeetCat.",
+      allow_media_access:
+        "Allow camera and microphone access for MeetCat in your system privacy settings.",
+      check_network: "Check your network connection and proxy settings.",
+      sync_clock: "Turn on automatic date and time in your system settings.",
+      enable_logging:
+        "Turn on log collection in the developer settings so problems can be diagnosed.",
+    },
+    actions: {
+      open_meet_home: "Open Google Meet",
+      check_for_updates: "Check for updates",
+    },
+  },
+
+  presence: {
+    syncNow: "Sync Slack status",
+    syncing: "Syncing...",
+    synced: "Slack status is up to date",
+    syncFailed: "Couldn't sync Slack status: {{error}}",
+  },
+
   tray: {
     quitMeetCat: "Quit MeetCat",
     showWindow: "Show Window",
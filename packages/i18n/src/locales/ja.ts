@@ -26,6 +26,18 @@ const ja = {
     enterFilterText: "フィルターテキストを入力...",
     add: "追加",
     skipMatchingTitles: "タイトルにこれらのキーワードを含む会議をスキップ",
+    enterTestTitle: "会議タイトルをテスト...",
+    test: "テスト",
+    titleFilterJoins: "自動参加されます",
+    titleFilterSkips: "フィルターによりスキップされます",
+    calendarSubscriptions: "カレンダー購読",
+    enterCalendarSubscription: "ICS URL またはファイルパス...",
+    calendarSubscriptionsHint:
+      "ICS カレンダーの Meet 会議を統合（URL、webcal:// またはファイルパス）",
+    calendarSubscriptionInvalid:
+      "http(s) または webcal:// の URL、または絶対ファイルパスを入力してください",
+    removeCalendarSubscription: "購読を削除",
+    settingsNotSaved: "一部の設定は保存されませんでした：",
     defaultMicrophone: "デフォルトのマイク",
     muted: "ミュート",
     unmuted: "ミュート解除",
@@ -63,6 +75,14 @@ const ja = {
     temporarilyHide: "一時的に非表示",
     autoJoiningIn: "自動参加まで ",
     cancel: "キャンセル",
+    shownBy: "MeetCat が表示しました：{{reason}}",
+    shownByFor: "MeetCat が表示しました：{{title}} の{{reason}}",
+    causeAutoJoin: "自動参加",
+    causeGreenRoom: "事前入室",
+    causeBackgroundJoin: "バックグラウンドで参加済み",
+    causeRateCapped: "自動参加の上限に達しました",
+    causeDeepLink: "MeetCat リンク",
+    causeNavigateHome: "ホームに戻る",
   },
 
   update: {
@@ -91,6 +111,72 @@ const ja = {
     checking: "確認中...",
   },
 
+  joinByCode: {
+    title: "会議コードで参加",
+    inputLabel: "会議コードまたはリンク",
+    placeholder: "例: abc-defg-hij",
+    join: "参加",
+    joining: "参加中...",
+    recent: "最近",
+    closeDialog: "会議コードダイアログを閉じる",
+  },
+
+  history: {
+    weekSummary: "今週は {{count}} 件の会議に自動参加しました",
+    noteTitle: "メモを追加",
+    notePlaceholder: "例：決定事項、フォローアップ",
+    saveNote: "保存",
+    savingNote: "保存中...",
+    closeNoteDialog: "メモダイアログを閉じる",
+  },
+
+  troubleshooter: {
+    run: "トラブルシューティング...",
+    title: "トラブルシューティング",
+    closeDialog: "トラブルシューティングを閉じる",
+    running: "チェック中...",
+    progress: "ステップ {{current}} / {{total}}",
+    back: "戻る",
+    next: "次へ",
+    runAgain: "再実行",
+    allPassed: "問題は見つかりませんでした。",
+    someFailed: "一部のチェックに失敗しました。提案に従って修正し、もう一度実行してください。",
+    steps: {
+      injection: "MeetCat スクリプト",
+      parser: "会議の検出",
+      permissions: "カメラとマイク",
+      reachability: "Google Meet への接続",
+      clock: "システム時計",
+      logging: "ログ収集",
+    },
+    status: {
+      pass: "合格",
+      warn: "要確認",
+      fail: "失敗",
+      skipped: "スキップ",
+    },
+    fixes: {
+      open_meet_home: "メインウィンドウで Google Meet のホームを開いてサインインし、もう一度実行してください。",
+      check_for_updates:
+        "Google Meet のページが変更された可能性があります。MeetCat の新しいバージョンを確認してください。",
+      allow_media_access: "システムのプライバシー設定で MeetCat にカメラとマイクへのアクセスを許可してください。",
+      check_network: "ネットワーク接続とプロキシ設定を確認してください。",
+      sync_clock: "システム設定で日付と時刻の自動設定をオンにしてください。",
+      enable_logging: "問題を診断できるよう、開発者設定でログ収集をオンにしてください。",
+    },
+    actions: {
+      open_meet_home: "Google Meet を開く",
+      check_for_updates: "アップデートを確認",
+    },
+  },
+
+  presence: {
+    syncNow: "Slack ステータスを同期",
+    syncing: "同期中...",
+    synced: "Slack ステータスを更新しました",
+    syncFailed: "Slack ステータスを同期できませんでした: {{error}}",
+  },
+
   tray: {
     quitMeetCat: "MeetCat を終了",
     showWindow: "ウィンドウを表示",
@@ -26,6 +26,18 @@ const ko = {
     enterFilterText: "필터 텍스트 입력...",
     add: "추가",
     skipMatchingTitles: "제목에 이 키워드가 포함된 회의 건너뛰기",
+    enterTestTitle: "회의 제목 테스트...",
+    test: "테스트",
+    titleFilterJoins: "자동 참가됩니다",
+    titleFilterSkips: "필터에 의해 건너뜁니다",
+    calendarSubscriptions: "캘린더 구독",
+    enterCalendarSubscription: "ICS URL 또는 파일 경로...",
+    calendarSubscriptionsHint:
+      "ICS 캘린더의 Meet 회의 병합 (URL, webcal:// 또는 파일 경로)",
+    calendarSubscriptionInvalid:
+      "http(s) 또는 webcal:// URL, 또는 절대 파일 경로를 입력하세요",
+    removeCalendarSubscription: "구독 제거",
+    settingsNotSaved: "일부 설정이 저장되지 않았습니다:",
     defaultMicrophone: "기본 마이크",
     muted: "음소거",
     unmuted: "음소거 해제",
@@ -63,6 +75,14 @@ const ko = {
     temporarilyHide: "일시적으로 숨기기",
     autoJoiningIn: "자동 참가까지 ",
     cancel: "취소",
+    shownBy: "MeetCat이 표시함: {{reason}}",
+    shownByFor: "MeetCat이 표시함: {{title}} {{reason}}",
+    causeAutoJoin: "자동 참가",
+    causeGreenRoom: "미리 입장",
+    causeBackgroundJoin: "백그라운드에서 참가함",
+    causeRateCapped: "자동 참가 한도 도달",
+    causeDeepLink: "MeetCat 링크",
+    causeNavigateHome: "홈으로 돌아가기",
   },
 
   update: {
@@ -91,6 +111,71 @@ const ko = {
     checking: "확인 중...",
   },
 
+  joinByCode: {
+    title: "회의 코드로 참가",
+    inputLabel: "회의 코드 또는 링크",
+    placeholder: "예: abc-defg-hij",
+    join: "참가",
+    joining: "참가 중...",
+    recent: "최근",
+    closeDialog: "회의 코드 대화상자 닫기",
+  },
+
+  history: {
+    weekSummary: "이번 주에 {{count}}개의 회의에 자동 참가했습니다",
+    noteTitle: "메모 추가",
+    notePlaceholder: "예: 결정 사항, 후속 작업",
+    saveNote: "저장",
+    savingNote: "저장 중...",
+    closeNoteDialog: "메모 대화상자 닫기",
+  },
+
+  troubleshooter: {
+    run: "문제 해결...",
+    title: "문제 해결",
+    closeDialog: "문제 해결 닫기",
+    running: "확인 중...",
+    progress: "{{total}}단계 중 {{current}}단계",
+    back: "이전",
+    next: "다음",
+    runAgain: "다시 실행",
+    allPassed: "문제가 발견되지 않았습니다.",
+    someFailed: "일부 검사에 실패했습니다. 제안된 해결 방법을 따른 후 다시 실행하세요.",
+    steps: {
+      injection: "MeetCat 스크립트",
+      parser: "회의 감지",
+      permissions: "카메라 및 마이크",
+      reachability: "Google Meet 연결",
+      clock: "시스템 시계",
+      logging: "로그 수집",
+    },
+    status: {
+      pass: "통과",
+      warn: "확인 필요",
+      fail: "실패",
+      skipped: "건너뜀",
+    },
+    fixes: {
+      open_meet_home: "기본 창에서 Google Meet 홈을 열고 로그인한 후 다시 실행하세요.",
+      check_for_updates: "Google Meet 페이지가 변경되었을 수 있습니다. MeetCat의 새 버전을 확인하세요.",
+      allow_media_access: "시스템 개인 정보 설정에서 MeetCat의 카메라 및 마이크 접근을 허용하세요.",
+      check_network: "네트워크 연결과 프록시 설정을 확인하세요.",
+      sync_clock: "시스템 설정에서 날짜 및 시간 자동 설정을 켜세요.",
+      enable_logging: "문제를 진단할 수 있도록 개발자 설정에서 로그 수집을 켜세요.",
+    },
+    actions: {
+      open_meet_home: "Google Meet 열기",
+      check_for_updates: "업데이트 확인",
+    },
+  },
+
+  presence: {
+    syncNow: "Slack 상태 동기화",
+    syncing: "동기화 중...",
+    synced: "Slack 상태를 업데이트했습니다",
+    syncFailed: "Slack 상태를 동기화하지 못했습니다: {{error}}",
+  },
+
   tray: {
     quitMeetCat: "MeetCat 종료",
     showWindow: "창 표시",
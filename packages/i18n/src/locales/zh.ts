@@ -26,6 +26,18 @@ const zh = {
     enterFilterText: "输入过滤文本...",
     add: "添加",
     skipMatchingTitles: "跳过标题中包含这些文本的会议",
+    enterTestTitle: "测试会议标题...",
+    test: "测试",
+    titleFilterJoins: "将自动加入",
+    titleFilterSkips: "将被过滤器跳过",
+    calendarSubscriptions: "日历订阅",
+    enterCalendarSubscription: "ICS 链接或文件路径...",
+    calendarSubscriptionsHint:
+      "合并 ICS 日历中的 Meet 会议（链接、webcal:// 或文件路径）",
+    calendarSubscriptionInvalid:
+      "请输入 http(s) 或 webcal:// 链接，或绝对文件路径",
+    removeCalendarSubscription: "移除订阅",
+    settingsNotSaved: "部分设置未保存：",
     defaultMicrophone: "默认麦克风",
     muted: "静音",
     unmuted: "开启",
@@ -62,6 +74,14 @@ const zh = {
     temporarilyHide: "暂时隐藏",
     autoJoiningIn: "即将自动加入 ",
     cancel: "取消",
+    shownBy: "由 MeetCat 显示：{{reason}}",
+    shownByFor: "由 MeetCat 显示：{{title}}的{{reason}}",
+    causeAutoJoin: "自动加入",
+    causeGreenRoom: "提前进入会议",
+    causeBackgroundJoin: "已在后台加入",
+    causeRateCapped: "已达到自动加入上限",
+    causeDeepLink: "MeetCat 链接",
+    causeNavigateHome: "返回主页",
   },
 
   update: {
@@ -88,6 +108,71 @@ const zh = {
     checking: "检查中...",
   },
 
+  joinByCode: {
+    title: "通过会议代码加入",
+    inputLabel: "会议代码或链接",
+    placeholder: "例如 abc-defg-hij",
+    join: "加入",
+    joining: "正在加入...",
+    recent: "最近",
+    closeDialog: "关闭会议代码对话框",
+  },
+
+  history: {
+    weekSummary: "本周已自动加入 {{count}} 场会议",
+    noteTitle: "添加备注",
+    notePlaceholder: "例如：决定、待办事项",
+    saveNote: "保存",
+    savingNote: "正在保存...",
+    closeNoteDialog: "关闭备注对话框",
+  },
+
+  troubleshooter: {
+    run: "故障排查...",
+    title: "故障排查",
+    closeDialog: "关闭故障排查",
+    running: "正在检查...",
+    progress: "第 {{current}} 步，共 {{total}} 步",
+    back: "上一步",
+    next: "下一步",
+    runAgain: "重新检查",
+    allPassed: "未发现问题。",
+    someFailed: "部分检查未通过。请按建议修复后重新运行故障排查。",
+    steps: {
+      injection: "MeetCat 脚本",
+      parser: "会议识别",
+      permissions: "摄像头和麦克风",
+      reachability: "Google Meet 连接",
+      clock: "系统时钟",
+      logging: "日志收集",
+    },
+    status: {
+      pass: "通过",
+      warn: "需要注意",
+      fail: "未通过",
+      skipped: "已跳过",
+    },
+    fixes: {
+      open_meet_home: "在主窗口中打开 Google Meet 主页并登录，然后重新运行故障排查。",
+      check_for_updates: "Google Meet 的页面可能已更改。请检查 MeetCat 是否有新版本。",
+      allow_media_access: "请在系统隐私设置中允许 MeetCat 使用摄像头和麦克风。",
+      check_network: "请检查网络连接和代理设置。",
+      sync_clock: "请在系统设置中开启自动设置日期和时间。",
+      enable_logging: "请在开发者设置中开启日志收集，以便诊断问题。",
+    },
+    actions: {
+      open_meet_home: "打开 Google Meet",
+      check_for_updates: "检查更新",
+    },
+  },
+
+  presence: {
+    syncNow: "同步 Slack 状态",
+    syncing: "正在同步...",
+    synced: "Slack 状态已更新",
+    syncFailed: "无法同步 Slack 状态：{{error}}",
+  },
+
   tray: {
     quitMeetCat: "退出 MeetCat",
     showWindow: "显示窗口",
@@ -200,6 +200,43 @@ describe("SettingsView", () => {
     expect(nextSettings.joinCountdownSeconds).toBe(12);
   });
 
+  it("previews the title filters on a test title", async () => {
+    const onTestTitleFilter = vi.fn().mockResolvedValue({
+      autoJoin: false,
+      matchedKeywords: ["Standup"],
+      matchedExclude: [],
+      matchedInclude: [],
+      meetingRule: null,
+    });
+
+    render(
+      <SettingsView
+        settings={createSettings()}
+        loading={false}
+        saving={false}
+        showSavingIndicator={false}
+        headerTitle="MeetCat Settings"
+        headerIconSrc="/icon.png"
+        footerText="MeetCat"
+        capabilities={{}}
+        onSettingsChange={vi.fn()}
+        onTestTitleFilter={onTestTitleFilter}
+      />
+    );
+
+    fireEvent.change(screen.getByPlaceholderText("Test a meeting title..."), {
+      target: { value: "Daily Standup" },
+    });
+    fireEvent.click(screen.getByText("Test"));
+
+    await waitFor(() => {
+      expect(screen.getByRole("status").textContent).toBe(
+        "Would be skipped by the filters"
+      );
+    });
+    expect(onTestTitleFilter).toHaveBeenCalledWith("Daily Standup");
+  });
+
   it("updates max minutes after start", async () => {
     const settings = createSettings();
     const onSettingsChange = vi.fn();
@@ -404,6 +441,74 @@ describe("SettingsView", () => {
     expect(nextSettings.titleExcludeFilters).toEqual(["Daily"]);
   });
 
+  it("adds and removes calendar subscriptions", async () => {
+    const settings = createSettings();
+    const onSettingsChange = vi.fn();
+
+    const { rerender } = render(
+      <SettingsView
+        settings={settings}
+        loading={false}
+        saving={false}
+        showSavingIndicator={false}
+        headerTitle="MeetCat Settings"
+        headerIconSrc="/icon.png"
+        footerText="MeetCat"
+        capabilities={{ calendarSubscriptions: true }}
+        onSettingsChange={onSettingsChange}
+      />
+    );
+
+    const input = screen.getByPlaceholderText("ICS URL or file path...");
+    fireEvent.change(input, { target: { value: "team.ics" } });
+    expect(
+      screen.getByText(
+        "Enter an http(s) or webcal:// URL, or an absolute file path"
+      )
+    ).toBeTruthy();
+    fireEvent.keyDown(input, { key: "Enter" });
+    expect(onSettingsChange).not.toHaveBeenCalled();
+
+    fireEvent.change(input, {
+      target: { value: "webcal://calendar.example.com/team.ics" },
+    });
+    fireEvent.keyDown(input, { key: "Enter" });
+
+    await waitFor(() => {
+      expect(onSettingsChange).toHaveBeenCalled();
+    });
+
+    const addedSettings = onSettingsChange.mock.calls.at(-1)?.[0] as Settings;
+    expect(addedSettings.tauri?.icsSubscriptions).toEqual([
+      "webcal://calendar.example.com/team.ics",
+    ]);
+
+    onSettingsChange.mockClear();
+
+    rerender(
+      <SettingsView
+        settings={addedSettings}
+        loading={false}
+        saving={false}
+        showSavingIndicator={false}
+        headerTitle="MeetCat Settings"
+        headerIconSrc="/icon.png"
+        footerText="MeetCat"
+        capabilities={{ calendarSubscriptions: true }}
+        onSettingsChange={onSettingsChange}
+      />
+    );
+
+    fireEvent.click(screen.getByTitle("Remove subscription"));
+
+    await waitFor(() => {
+      expect(onSettingsChange).toHaveBeenCalled();
+    });
+
+    const removedSettings = onSettingsChange.mock.calls.at(-1)?.[0] as Settings;
+    expect(removedSettings.tauri?.icsSubscriptions).toEqual([]);
+  });
+
   it("resets invalid number input to default", async () => {
     const settings = createSettings();
     const onSettingsChange = vi.fn();
@@ -725,6 +830,50 @@ describe("SettingsContainer", () => {
     errorSpy.mockRestore();
   });
 
+  it("highlights fields rejected by the save", async () => {
+    const settings = createSettings();
+    const saveSettings = vi.fn().mockRejectedValue([
+      { field: "joinCountdownSeconds", message: "Must be between 0 and 60, got 90" },
+      { field: "tauri.mqttTopicPrefix", message: "Topic prefix must not be empty" },
+    ]);
+    const errorSpy = vi.spyOn(console, "error").mockImplementation(() => {});
+    const adapter = {
+      capabilities: {},
+      getDefaultSettings: () => settings,
+      loadSettings: vi.fn().mockResolvedValue(settings),
+      saveSettings,
+      resolveSettings: (loaded: Settings | null) => loaded ?? settings,
+    };
+
+    render(
+      <SettingsContainer
+        adapter={adapter}
+        headerIconSrc="/icon.png"
+        headerTitle="MeetCat Settings"
+        appName="MeetCat"
+      />
+    );
+
+    await waitFor(() => {
+      expect(screen.getByText("General")).toBeDefined();
+    });
+
+    await act(async () => {
+      fireEvent.click(screen.getByLabelText("Auto-click join"));
+    });
+
+    await waitFor(() => {
+      expect(screen.getByRole("alert").textContent).toContain(
+        "Topic prefix must not be empty"
+      );
+    });
+    const countdown = screen.getAllByRole("spinbutton")[1];
+    expect(countdown.getAttribute("aria-invalid")).toBe("true");
+    expect(screen.getAllByRole("spinbutton")[0].getAttribute("aria-invalid")).toBeNull();
+
+    errorSpy.mockRestore();
+  });
+
   it("uses adapter.updateStartAtLogin when available", async () => {
     const settings = createSettings({
       tauri: { ...DEFAULT_TAURI_SETTINGS, startAtLogin: false },
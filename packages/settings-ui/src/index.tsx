@@ -16,17 +16,48 @@ export type SettingsCapabilities = {
   tray?: boolean;
   showSavingIndicator?: boolean;
   developer?: boolean;
+  calendarSubscriptions?: boolean;
+};
+
+/** A setting the app refused to save, named by its path in `Settings` */
+export type SettingsFieldError = {
+  field: string;
+  message: string;
+};
+
+/**
+ * Field errors carried by a rejected save, or an empty list when the error
+ * is not a validation failure
+ */
+export function getSettingsFieldErrors(error: unknown): SettingsFieldError[] {
+  if (!Array.isArray(error)) return [];
+  return error.filter(
+    (item): item is SettingsFieldError =>
+      typeof item?.field === "string" && typeof item?.message === "string"
+  );
+}
+
+/** How the title filters decide on one meeting title */
+export type TitleFilterPreview = {
+  autoJoin: boolean;
+  matchedKeywords: string[];
+  matchedExclude: number[];
+  matchedInclude: number[];
+  meetingRule: number | null;
 };
 
 export type SettingsAdapter = {
   capabilities: SettingsCapabilities;
   getDefaultSettings: () => Settings;
   loadSettings: () => Promise<Settings | null>;
+  /** Rejects with a `SettingsFieldError[]` when validation fails */
   saveSettings: (settings: Settings) => Promise<void>;
   resolveSettings: (loaded: Settings | null) => Settings;
   subscribe?: (handler: (settings: Settings) => void) => () => void;
   updateStartAtLogin?: (enabled: boolean, settings: Settings) => Promise<Settings>;
   getVersion?: () => Promise<string | null> | string | null;
+  /** Preview the title filters of the current settings on `title` */
+  testTitleFilter?: (title: string) => Promise<TitleFilterPreview>;
 };
 
 export type SettingsContainerProps = {
@@ -45,6 +76,7 @@ export function SettingsContainer({
   const [settings, setSettings] = useState<Settings>(() => adapter.getDefaultSettings());
   const [loading, setLoading] = useState(true);
   const [saving, setSaving] = useState(false);
+  const [fieldErrors, setFieldErrors] = useState<SettingsFieldError[]>([]);
   const [version, setVersion] = useState<string | null>(null);
   const [i18nReady, setI18nReady] = useState(false);
 
@@ -114,8 +146,10 @@ export function SettingsContainer({
     setSaving(true);
     try {
       await adapter.saveSettings(nextSettings);
+      setFieldErrors([]);
     } catch (e) {
       console.error("Failed to save settings:", e);
+      setFieldErrors(getSettingsFieldErrors(e));
     } finally {
       setSaving(false);
     }
@@ -168,7 +202,9 @@ export function SettingsContainer({
       headerIconSrc={headerIconSrc}
       footerText={footerText}
       capabilities={adapter.capabilities}
+      fieldErrors={fieldErrors}
       onSettingsChange={handleSettingsChange}
+      onTestTitleFilter={adapter.testTitleFilter}
       onStartAtLoginChange={
         adapter.capabilities.startAtLogin ? handleStartAtLoginChange : undefined
       }
@@ -186,11 +222,27 @@ export type SettingsViewProps = {
   headerIconSrc: string;
   footerText?: string;
   capabilities: SettingsCapabilities;
+  /** Fields rejected by the last save, highlighted until a save succeeds */
+  fieldErrors?: SettingsFieldError[];
   onSettingsChange: (settings: Settings) => void;
+  onTestTitleFilter?: (title: string) => Promise<TitleFilterPreview>;
   onStartAtLoginChange?: (enabled: boolean) => void;
   onLanguageChange?: (lang: LanguageSetting) => void;
 };
 
+/**
+ * Whether `value` is an entry the app can subscribe to: an http(s) or webcal
+ * URL, a file URL, or an absolute path
+ */
+function isCalendarSubscription(value: string): boolean {
+  const entry = value.trim();
+  return (
+    /^(https?|webcals?|file):\/\/\S+$/i.test(entry) ||
+    entry.startsWith("/") ||
+    /^[a-z]:[\\/]/i.test(entry)
+  );
+}
+
 function NumberInput({
   value,
   defaultValue,
@@ -198,6 +250,7 @@ function NumberInput({
   max,
   prefix,
   suffix,
+  error,
   onChange,
 }: {
   value: number;
@@ -206,6 +259,7 @@ function NumberInput({
   max: number;
   prefix?: string;
   suffix: string;
+  error?: string;
   onChange: (value: number) => void;
 }) {
   const [localValue, setLocalValue] = useState(value.toString());
@@ -225,25 +279,29 @@ function NumberInput({
   };
 
   return (
-    <div className="input-with-suffix">
-      {prefix && <span className="input-prefix">{prefix}</span>}
-      <input
-        type="number"
-        className="form-input"
-        min={min}
-        max={max}
-        placeholder={defaultValue.toString()}
-        value={localValue}
-        onChange={(e) => setLocalValue(e.target.value)}
-        onBlur={handleBlur}
-        onKeyDown={(e) => {
-          if (e.key === "Enter") {
-            e.currentTarget.blur();
-          }
-        }}
-      />
-      <span className="input-suffix">{suffix}</span>
-    </div>
+    <>
+      <div className="input-with-suffix">
+        {prefix && <span className="input-prefix">{prefix}</span>}
+        <input
+          type="number"
+          className={error ? "form-input form-input-invalid" : "form-input"}
+          aria-invalid={error ? true : undefined}
+          min={min}
+          max={max}
+          placeholder={defaultValue.toString()}
+          value={localValue}
+          onChange={(e) => setLocalValue(e.target.value)}
+          onBlur={handleBlur}
+          onKeyDown={(e) => {
+            if (e.key === "Enter") {
+              e.currentTarget.blur();
+            }
+          }}
+        />
+        <span className="input-suffix">{suffix}</span>
+      </div>
+      {error && <p className="form-error">{error}</p>}
+    </>
   );
 }
 
@@ -256,12 +314,19 @@ export function SettingsView({
   headerIconSrc,
   footerText,
   capabilities,
+  fieldErrors = [],
   onSettingsChange,
+  onTestTitleFilter,
   onStartAtLoginChange,
   onLanguageChange,
 }: SettingsViewProps) {
   const { t } = useTranslation();
+  const fieldError = (field: string) =>
+    fieldErrors.find((error) => error.field === field)?.message;
   const [filterInput, setFilterInput] = useState("");
+  const [subscriptionInput, setSubscriptionInput] = useState("");
+  const [testTitle, setTestTitle] = useState("");
+  const [titlePreview, setTitlePreview] = useState<TitleFilterPreview | null>(null);
 
   const updateSettings = (updates: Partial<Settings>) => {
     onSettingsChange({ ...settings, ...updates });
@@ -303,6 +368,41 @@ export function SettingsView({
     });
   };
 
+  const runTitleTest = async () => {
+    const title = testTitle.trim();
+    if (!title || !onTestTitleFilter) return;
+    try {
+      setTitlePreview(await onTestTitleFilter(title));
+    } catch (e) {
+      console.error("Failed to test title filters:", e);
+      setTitlePreview(null);
+    }
+  };
+
+  const icsSubscriptions =
+    settings.tauri?.icsSubscriptions ?? DEFAULT_TAURI_SETTINGS.icsSubscriptions;
+  const subscriptionInvalid =
+    subscriptionInput.trim() !== "" && !isCalendarSubscription(subscriptionInput);
+
+  const addSubscription = () => {
+    const subscription = subscriptionInput.trim();
+    if (
+      isCalendarSubscription(subscription) &&
+      !icsSubscriptions.includes(subscription)
+    ) {
+      updateTauriSettings({
+        icsSubscriptions: [...icsSubscriptions, subscription],
+      });
+      setSubscriptionInput("");
+    }
+  };
+
+  const removeSubscription = (subscription: string) => {
+    updateTauriSettings({
+      icsSubscriptions: icsSubscriptions.filter((s) => s !== subscription),
+    });
+  };
+
   if (loading) {
     return (
       <div className="settings-window">
@@ -319,6 +419,19 @@ export function SettingsView({
       </header>
 
       <main className="settings-content">
+        {fieldErrors.length > 0 && (
+          <div className="settings-errors" role="alert">
+            <p>{t("settings.settingsNotSaved")}</p>
+            <ul>
+              {fieldErrors.map((error) => (
+                <li key={`${error.field}:${error.message}`}>
+                  <code>{error.field}</code> {error.message}
+                </li>
+              ))}
+            </ul>
+          </div>
+        )}
+
         <section className="settings-section">
           <h2>{t("settings.general")}</h2>
 
@@ -413,6 +526,7 @@ export function SettingsView({
               max={30}
               prefix={t("settings.beforeMeetingStarts")}
               suffix={t("settings.minutes")}
+              error={fieldError("joinBeforeMinutes")}
               onChange={(value) => updateSettings({ joinBeforeMinutes: value })}
             />
           </div>
@@ -426,6 +540,7 @@ export function SettingsView({
               max={60}
               prefix={t("settings.beforeAutoJoin")}
               suffix={t("settings.seconds")}
+              error={fieldError("joinCountdownSeconds")}
               onChange={(value) => updateSettings({ joinCountdownSeconds: value })}
             />
           </div>
@@ -439,6 +554,7 @@ export function SettingsView({
               max={30}
               prefix={t("settings.afterMeetingStarts")}
               suffix={t("settings.minutes")}
+              error={fieldError("maxMinutesAfterStart")}
               onChange={(value) => updateSettings({ maxMinutesAfterStart: value })}
             />
           </div>
@@ -479,8 +595,79 @@ export function SettingsView({
                 ))}
               </div>
             )}
+            {onTestTitleFilter && (
+              <>
+                <div className="filter-input-row">
+                  <input
+                    type="text"
+                    className="form-input"
+                    placeholder={t("settings.enterTestTitle")}
+                    value={testTitle}
+                    onChange={(e) => {
+                      setTestTitle(e.target.value);
+                      setTitlePreview(null);
+                    }}
+                    onKeyDown={(e) => e.key === "Enter" && void runTitleTest()}
+                  />
+                  <button className="btn btn-secondary" onClick={() => void runTitleTest()}>
+                    {t("settings.test")}
+                  </button>
+                </div>
+                {titlePreview && (
+                  <p className="form-hint" role="status">
+                    {titlePreview.autoJoin
+                      ? t("settings.titleFilterJoins")
+                      : t("settings.titleFilterSkips")}
+                  </p>
+                )}
+              </>
+            )}
           </div>
 
+          {capabilities.calendarSubscriptions && (
+            <div className="form-group">
+              <label className="form-label">{t("settings.calendarSubscriptions")}</label>
+              <div className="filter-input-row">
+                <input
+                  type="text"
+                  className="form-input"
+                  placeholder={t("settings.enterCalendarSubscription")}
+                  value={subscriptionInput}
+                  onChange={(e) => setSubscriptionInput(e.target.value)}
+                  onKeyDown={(e) => e.key === "Enter" && addSubscription()}
+                />
+                <button
+                  className="btn btn-secondary"
+                  onClick={addSubscription}
+                  disabled={subscriptionInvalid}
+                >
+                  {t("settings.add")}
+                </button>
+              </div>
+              <p className="form-hint">
+                {subscriptionInvalid
+                  ? t("settings.calendarSubscriptionInvalid")
+                  : t("settings.calendarSubscriptionsHint")}
+              </p>
+              {icsSubscriptions.length > 0 && (
+                <div className="filter-list">
+                  {icsSubscriptions.map((subscription) => (
+                    <div key={subscription} className="filter-item">
+                      <span className="filter-text">{subscription}</span>
+                      <button
+                        className="filter-remove"
+                        onClick={() => removeSubscription(subscription)}
+                        title={t("settings.removeCalendarSubscription")}
+                      >
+                        ×
+                      </button>
+                    </div>
+                  ))}
+                </div>
+              )}
+            </div>
+          )}
+
           <div className="form-group">
             <label className="form-label">{t("settings.defaultMicrophone")}</label>
             <select
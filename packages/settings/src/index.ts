@@ -5,10 +5,30 @@ export {
   MediaStateSchema,
   LanguageSchema,
   LogLevelSchema,
+  MeetingSourceSchema,
+  ConflictPolicySchema,
+  ProxySettingsSchema,
+  MeetingRuleSchema,
+  TitleFilterSchema,
+  BusyBlockRuleSchema,
+  DndWindowSchema,
+  AutoJoinHoursSchema,
+  ProfileSchema,
+  SoakTestSettingsSchema,
+  GlobalShortcutsSchema,
+  MaintenanceWindowSchema,
   type Settings,
   type ExtensionSettings,
   type TauriSettings,
   type MediaState,
+  type MeetingRule,
+  type TitleFilter,
+  type BusyBlockRule,
+  type DndWindow,
+  type AutoJoinHours,
+  type Profile,
+  type GlobalShortcuts,
+  type MaintenanceWindow,
 } from "./schema.js";
 
 export {
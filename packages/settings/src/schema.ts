@@ -22,6 +22,87 @@ type DefaultsJson = {
     trayShowMeetingTitle: boolean;
     logCollectionEnabled: boolean;
     logLevel: "error" | "warn" | "info" | "debug" | "trace";
+    meetingSourcePriority: ("webview" | "ics" | "api")[];
+    captureScreenshotOnJoinFailure: boolean;
+    pauseDuringOutOfOffice: boolean;
+    maxAutoJoinsPerHour: number;
+    privacyMode: boolean;
+    greenRoomMinutes: number;
+    powerMode: "auto" | "normal" | "lowPower";
+    proxy: { host: string; port: number; username?: string; password?: string } | null;
+    allowConcurrentMeetings: boolean;
+    deferWhileMicBusy: boolean;
+    startupUrl: string | null;
+    meetingRules: {
+      pattern: string;
+      matchMode?: "contains" | "regex";
+      autoJoin?: boolean;
+      joinBeforeMinutes?: number;
+      micState?: "muted" | "unmuted";
+      cameraState?: "muted" | "unmuted";
+      autoClickJoin?: boolean;
+      countdownSeconds?: number;
+    }[];
+    rememberRecentCodes: boolean;
+    showNotifications: boolean;
+    busyBlockRules: {
+      pattern: string;
+      matchMode?: "contains" | "regex";
+      policy?: "delay" | "confirm";
+    }[];
+    soakTest: { clockFactor?: number; meetingIntervalMinutes?: number } | null;
+    accessibilityAnnouncements: boolean;
+    returnHomeAfterMeeting: number | null;
+    dndWindows: {
+      weekday: "Mon" | "Tue" | "Wed" | "Thu" | "Fri" | "Sat" | "Sun";
+      start: string;
+      end: string;
+    }[];
+    profiles: { id: string; name: string }[];
+    staleRefreshMinutes: number;
+    icsSubscriptions: string[];
+    showMeetingSummary: boolean;
+    widgetDataEnabled: boolean;
+    widgetDataPath: string | null;
+    autoJoinHours: {
+      start: string;
+      end: string;
+      weekdays: ("Mon" | "Tue" | "Wed" | "Thu" | "Fri" | "Sat" | "Sun")[];
+    } | null;
+    reminderMinutesBefore: number | null;
+    joinInBackground: boolean;
+    conflictPolicy: "firstScheduled" | "shortest" | "promptUser";
+    showWindowCause: boolean;
+    globalShortcuts: {
+      joinNext: string | null;
+      cancelJoin: string | null;
+      toggleMic: string | null;
+    } | null;
+    maintenanceWindow: { start: string; end: string } | null;
+    lastMinuteMeetingMinutes: number;
+    demoMode: boolean;
+    trayIconTemplate: boolean;
+    apiServerEnabled: boolean;
+    apiServerPort: number;
+    apiServerToken: string | null;
+    mqttEnabled: boolean;
+    mqttHost: string | null;
+    mqttPort: number;
+    mqttUsername: string | null;
+    mqttPassword: string | null;
+    mqttTopicPrefix: string;
+    webhookUrls: string[];
+    webhookEvents: ("scheduled" | "triggerFired" | "joined" | "closed" | "suppressed")[];
+    webhookSecret: string | null;
+    slackStatusEnabled: boolean;
+    slackToken: string | null;
+    skipJoinWhenIdleMinutes: number | null;
+    staleDataMinutes: number;
+    skipJoinWhenStale: boolean;
+    excludeTitleFilters: { kind: "contains" | "regex" | "exact"; pattern: string; caseSensitive?: boolean }[];
+    includeTitleFilters: { kind: "contains" | "regex" | "exact"; pattern: string; caseSensitive?: boolean }[];
+    mqttTls: boolean;
+    holdJoinsWhenOffline: boolean;
   };
 };
 
@@ -41,6 +122,11 @@ export const TrayDisplayModeSchema = z.enum([
   "iconWithCountdown",
 ]);
 
+/**
+ * Power mode options
+ */
+export const PowerModeSchema = z.enum(["auto", "normal", "lowPower"]);
+
 /**
  * Language options
  */
@@ -51,6 +137,133 @@ export const LanguageSchema = z.enum(["auto", "en", "zh", "ja", "ko"]);
  */
 export const LogLevelSchema = z.enum(["error", "warn", "info", "debug", "trace"]);
 
+/**
+ * Meeting source options
+ */
+export const MeetingSourceSchema = z.enum(["webview", "ics", "api"]);
+
+/**
+ * Overlapping meeting resolution options
+ */
+export const ConflictPolicySchema = z.enum(["firstScheduled", "shortest", "promptUser"]);
+
+/**
+ * Meeting lifecycle events that can be sent to webhooks
+ */
+export const WebhookEventSchema = z.enum([
+  "scheduled",
+  "triggerFired",
+  "joined",
+  "closed",
+  "suppressed",
+]);
+
+/**
+ * Explicit HTTP proxy for network features, overriding system settings
+ */
+export const ProxySettingsSchema = z.object({
+  host: z.string().min(1),
+  port: z.number().int().min(1).max(65535),
+  username: z.string().optional(),
+  /** Moved to the OS keychain when saved; use the `set_secret` command instead */
+  password: z.string().optional(),
+});
+
+/**
+ * Meeting title filter: `contains` a substring, matches a `regex`, or is the
+ * `exact` title; case is ignored unless `caseSensitive` is set
+ */
+export const TitleFilterSchema = z.object({
+  kind: z.enum(["contains", "regex", "exact"]),
+  pattern: z.string().min(1),
+  caseSensitive: z.boolean().default(false),
+});
+
+/**
+ * Per-meeting overrides for meetings whose title matches `pattern`
+ */
+export const MeetingRuleSchema = z.object({
+  pattern: z.string().min(1),
+  matchMode: z.enum(["contains", "regex"]).default("contains"),
+  autoJoin: z.boolean().optional(),
+  joinBeforeMinutes: z.number().min(0).max(30).optional(),
+  micState: MediaStateSchema.optional(),
+  cameraState: MediaStateSchema.optional(),
+  /** `false` only opens the meeting, leaving Join to the user */
+  autoClickJoin: z.boolean().optional(),
+  /** Replaces `joinCountdownSeconds`, e.g. 0 for standups */
+  countdownSeconds: z.number().min(0).max(60).optional(),
+});
+
+/**
+ * Policy for non-Meet busy calendar blocks (Focus time, Lunch) whose title
+ * matches `pattern`: wait until the block ends, or ask before joining
+ */
+export const BusyBlockRuleSchema = z.object({
+  pattern: z.string().min(1),
+  matchMode: z.enum(["contains", "regex"]).default("contains"),
+  policy: z.enum(["delay", "confirm"]).default("delay"),
+});
+
+/**
+ * Weekly Do-Not-Disturb window in local time (`HH:MM`); an `end` not after
+ * `start` runs past midnight into the next day
+ */
+export const DndWindowSchema = z.object({
+  weekday: z.enum(["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]),
+  start: z.string().regex(/^([01]\d|2[0-3]):[0-5]\d$/),
+  end: z.string().regex(/^([01]\d|2[0-3]):[0-5]\d$/),
+});
+
+/**
+ * Local hours (`HH:MM`) and weekdays in which meetings may start to be
+ * auto-joined; an `end` not after `start` runs past midnight
+ */
+export const AutoJoinHoursSchema = z.object({
+  start: z.string().regex(/^([01]\d|2[0-3]):[0-5]\d$/),
+  end: z.string().regex(/^([01]\d|2[0-3]):[0-5]\d$/),
+  weekdays: z
+    .array(z.enum(["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]))
+    .min(1),
+});
+
+/**
+ * Extra Google account opened in its own window with a separate session;
+ * `id` names the window and its data directory
+ */
+export const ProfileSchema = z.object({
+  id: z.string().regex(/^[a-z0-9-]{1,32}$/),
+  name: z.string().min(1),
+});
+
+/**
+ * Developer-only soak-test instrumentation: synthetic meetings on an
+ * accelerated clock with scheduler invariant checks. Not shown in the UI.
+ */
+export const SoakTestSettingsSchema = z.object({
+  clockFactor: z.number().int().min(1).max(3600).default(60),
+  meetingIntervalMinutes: z.number().int().min(5).default(15),
+});
+
+/**
+ * System-wide keyboard shortcuts as accelerators (`CommandOrControl+Shift+J`);
+ * null leaves an action unbound
+ */
+export const GlobalShortcutsSchema = z.object({
+  joinNext: z.string().min(1).nullable().default("CommandOrControl+Shift+J"),
+  cancelJoin: z.string().min(1).nullable().default("CommandOrControl+Shift+K"),
+  toggleMic: z.string().min(1).nullable().default("CommandOrControl+Shift+M"),
+});
+
+/**
+ * Local time range (`HH:MM`) for update checks, log digests and history
+ * pruning; an `end` not after `start` runs past midnight
+ */
+export const MaintenanceWindowSchema = z.object({
+  start: z.string().regex(/^([01]\d|2[0-3]):[0-5]\d$/),
+  end: z.string().regex(/^([01]\d|2[0-3]):[0-5]\d$/),
+});
+
 /**
  * Extension-specific settings
  */
@@ -77,6 +290,199 @@ export const TauriSettingsSchema = z.object({
     .default(DEFAULTS.tauri.logCollectionEnabled),
   /** Log level for collection (default: info) */
   logLevel: LogLevelSchema.default(DEFAULTS.tauri.logLevel),
+  /** Source priority used when merging meetings from multiple sources */
+  meetingSourcePriority: z
+    .array(MeetingSourceSchema)
+    .default([...DEFAULTS.tauri.meetingSourcePriority]),
+  /** Save a webview screenshot when auto-join verification fails (default: false) */
+  captureScreenshotOnJoinFailure: z
+    .boolean()
+    .default(DEFAULTS.tauri.captureScreenshotOnJoinFailure),
+  /** Pause auto-join while an out-of-office event covers today (default: false) */
+  pauseDuringOutOfOffice: z
+    .boolean()
+    .default(DEFAULTS.tauri.pauseDuringOutOfOffice),
+  /** Cap on auto-join actions per rolling hour, 0 disables the cap (default: 6) */
+  maxAutoJoinsPerHour: z
+    .number()
+    .min(0)
+    .max(60)
+    .default(DEFAULTS.tauri.maxAutoJoinsPerHour),
+  /** Hide meeting titles in the tray title, menu and tooltip (default: false) */
+  privacyMode: z.boolean().default(DEFAULTS.tauri.privacyMode),
+  /** Minutes before start to open the meeting's pre-join page without joining, 0 disables (default: 0) */
+  greenRoomMinutes: z
+    .number()
+    .min(0)
+    .max(30)
+    .default(DEFAULTS.tauri.greenRoomMinutes),
+  /** Background work level; auto follows the system battery saver */
+  powerMode: PowerModeSchema.default(DEFAULTS.tauri.powerMode),
+  /** Explicit proxy for network features; null uses the environment or manually configured system proxy (default: null) */
+  proxy: ProxySettingsSchema.nullable().default(DEFAULTS.tauri.proxy),
+  /** Let a join proceed while another call is active in any window (default: false) */
+  allowConcurrentMeetings: z.boolean().default(DEFAULTS.tauri.allowConcurrentMeetings),
+  /** Hold auto-join while another app is using the microphone (default: false) */
+  deferWhileMicBusy: z.boolean().default(DEFAULTS.tauri.deferWhileMicBusy),
+  /** Page the main window opens at startup, on Google Meet or Google Calendar; null opens the Meet homepage (default: null) */
+  startupUrl: z.string().nullable().default(DEFAULTS.tauri.startupUrl),
+  /** Per-meeting overrides; the first rule matching a title wins (default: []) */
+  meetingRules: z
+    .array(MeetingRuleSchema)
+    .default([...DEFAULTS.tauri.meetingRules]),
+  /** Remember meeting codes joined from the tray (default: true) */
+  rememberRecentCodes: z.boolean().default(DEFAULTS.tauri.rememberRecentCodes),
+  /** Post a desktop notification with a cancel button before auto-joining (default: true) */
+  showNotifications: z.boolean().default(DEFAULTS.tauri.showNotifications),
+  /** Policies for busy calendar blocks overlapping a join (default: []) */
+  busyBlockRules: z
+    .array(BusyBlockRuleSchema)
+    .default([...DEFAULTS.tauri.busyBlockRules]),
+  /** Developer-only soak-test instrumentation, not shown in the settings window; null disables it (default: null) */
+  soakTest: SoakTestSettingsSchema.nullable().default(DEFAULTS.tauri.soakTest),
+  /** Speak screen reader announcements when a join countdown starts or an auto-join fires (default: true) */
+  accessibilityAnnouncements: z.boolean().default(DEFAULTS.tauri.accessibilityAnnouncements),
+  /** Seconds after a meeting page closes before the main window goes back to the Meet homepage; 0 returns immediately, null stays on the page (default: null) */
+  returnHomeAfterMeeting: z
+    .number()
+    .min(0)
+    .max(600)
+    .nullable()
+    .default(DEFAULTS.tauri.returnHomeAfterMeeting),
+  /** Weekly Do-Not-Disturb windows in local time; auto-joins that would fire inside one are skipped (default: []) */
+  dndWindows: z
+    .array(DndWindowSchema)
+    .default([...DEFAULTS.tauri.dndWindows]),
+  /** Extra Google accounts, each opened in its own window with a separate session; the main window keeps the default account (default: []) */
+  profiles: z
+    .array(ProfileSchema)
+    .default([...DEFAULTS.tauri.profiles]),
+  /** Minutes the main window may stay hidden before Meet home is reloaded to refresh its meeting list, 0 disables (default: 30) */
+  staleRefreshMinutes: z
+    .number()
+    .int()
+    .min(0)
+    .max(720)
+    .default(DEFAULTS.tauri.staleRefreshMinutes),
+  /** ICS calendars (http(s)/webcal URLs or absolute file paths) whose Meet events are merged into the meeting list (default: []) */
+  icsSubscriptions: z
+    .array(z.string().min(1))
+    .default([...DEFAULTS.tauri.icsSubscriptions]),
+  /** Notify with the time spent after leaving a joined meeting, with an action to add a note to its history entry; never shown in privacy mode (default: false) */
+  showMeetingSummary: z.boolean().default(DEFAULTS.tauri.showMeetingSummary),
+  /** Keep the next-meeting data file for desktop widgets up to date (default: false) */
+  widgetDataEnabled: z.boolean().default(DEFAULTS.tauri.widgetDataEnabled),
+  /** Absolute path of the widget data file; null writes widget.json in the data directory (default: null) */
+  widgetDataPath: z
+    .string()
+    .min(1)
+    .nullable()
+    .default(DEFAULTS.tauri.widgetDataPath),
+  /** Local hours and weekdays in which meetings may start to be auto-joined; null auto-joins at any time (default: null) */
+  autoJoinHours: AutoJoinHoursSchema.nullable().default(DEFAULTS.tauri.autoJoinHours),
+  /** Minutes before an auto-join fires to emit `join-reminder` and, with notifications on, post a reminder that can skip the join; null sends none (default: null) */
+  reminderMinutesBefore: z
+    .number()
+    .int()
+    .min(1)
+    .max(60)
+    .nullable()
+    .default(DEFAULTS.tauri.reminderMinutesBefore),
+  /** Open auto-joined meetings without raising the window; it comes to front once the join button was clicked (default: false) */
+  joinInBackground: z.boolean().default(DEFAULTS.tauri.joinInBackground),
+  /** Which meeting to auto-join when overlapping meetings trigger together: the one that starts first, the shortest, or ask via `meeting-conflict` (default: firstScheduled) */
+  conflictPolicy: ConflictPolicySchema.default(DEFAULTS.tauri.conflictPolicy),
+  /** Show a short in-window notice saying why MeetCat brought a window forward on its own (default: true) */
+  showWindowCause: z.boolean().default(DEFAULTS.tauri.showWindowCause),
+  /** System-wide shortcuts to join the next meeting, cancel the pending join and toggle the mic; null turns them off (default: null) */
+  globalShortcuts: GlobalShortcutsSchema.nullable().default(DEFAULTS.tauri.globalShortcuts),
+  /** Local time range for update checks, log digests and history pruning, kept out of auto-join hours; null runs them when due (default: null) */
+  maintenanceWindow: MaintenanceWindowSchema.nullable().default(DEFAULTS.tauri.maintenanceWindow),
+  /** Meetings first seen this many minutes or less before they start are announced with a notification and scheduled right away, 0 disables (default: 5) */
+  lastMinuteMeetingMinutes: z
+    .number()
+    .int()
+    .min(0)
+    .max(60)
+    .default(DEFAULTS.tauri.lastMinuteMeetingMinutes),
+  /** Show sample meetings instead of the real calendar and never join or navigate, for demos and bug screenshots; real meeting sources are paused meanwhile (default: false) */
+  demoMode: z.boolean().default(DEFAULTS.tauri.demoMode),
+  /** Use monochrome tray icons that macOS tints to match the menu bar; ignored elsewhere (default: false) */
+  trayIconTemplate: z.boolean().default(DEFAULTS.tauri.trayIconTemplate),
+  /** Serve the local control API on 127.0.0.1; needs `apiServerToken` (default: false) */
+  apiServerEnabled: z.boolean().default(DEFAULTS.tauri.apiServerEnabled),
+  /** Port of the local control API (default: 47625) */
+  apiServerPort: z.number().int().min(1024).max(65535).default(DEFAULTS.tauri.apiServerPort),
+  /**
+   * Bearer token of the local control API. Moved to the OS credential store
+   * once saved, so it reads back as null (default: null)
+   */
+  apiServerToken: z.string().nullable().default(DEFAULTS.tauri.apiServerToken),
+  /** Publish meeting and mute state to an MQTT broker; needs `mqttHost` (default: false) */
+  mqttEnabled: z.boolean().default(DEFAULTS.tauri.mqttEnabled),
+  /** Host name or address of the MQTT broker (default: null) */
+  mqttHost: z.string().nullable().default(DEFAULTS.tauri.mqttHost),
+  /** Port of the MQTT broker (default: 1883) */
+  mqttPort: z.number().int().min(1).max(65535).default(DEFAULTS.tauri.mqttPort),
+  /** User name for the MQTT broker; null connects anonymously (default: null) */
+  mqttUsername: z.string().nullable().default(DEFAULTS.tauri.mqttUsername),
+  /**
+   * Password for the MQTT broker. Moved to the OS credential store once
+   * saved, so it reads back as null (default: null)
+   */
+  mqttPassword: z.string().nullable().default(DEFAULTS.tauri.mqttPassword),
+  /** Prefix of the published topics, e.g. `meetcat/state` (default: "meetcat") */
+  mqttTopicPrefix: z.string().min(1).default(DEFAULTS.tauri.mqttTopicPrefix),
+  /** http(s) URLs that receive a signed POST for each lifecycle event in `webhookEvents` (default: []) */
+  webhookUrls: z
+    .array(z.string().url())
+    .default([...DEFAULTS.tauri.webhookUrls]),
+  /** Lifecycle events sent to `webhookUrls` (default: ["joined", "closed"]) */
+  webhookEvents: z
+    .array(WebhookEventSchema)
+    .default([...DEFAULTS.tauri.webhookEvents]),
+  /**
+   * Key of the `X-MeetCat-Signature` HMAC sent with webhooks; unsigned when
+   * null. Moved to the OS credential store once saved, so it reads back as
+   * null (default: null)
+   */
+  webhookSecret: z.string().nullable().default(DEFAULTS.tauri.webhookSecret),
+  /** Set the Slack status while in a meeting; needs `slackToken` (default: false) */
+  slackStatusEnabled: z.boolean().default(DEFAULTS.tauri.slackStatusEnabled),
+  /**
+   * Slack user token with the `users.profile:write` scope. Moved to the OS
+   * credential store once saved, so it reads back as null (default: null)
+   */
+  slackToken: z.string().nullable().default(DEFAULTS.tauri.slackToken),
+  /** Skip an auto-join when there has been no keyboard or mouse input for this many minutes, or the screen is locked; null always joins (default: null) */
+  skipJoinWhenIdleMinutes: z
+    .number()
+    .int()
+    .min(1)
+    .max(240)
+    .nullable()
+    .default(DEFAULTS.tauri.skipJoinWhenIdleMinutes),
+  /** Minutes without a meeting list from the main window before it counts as stale, is reported as such and Meet home is reloaded, 0 disables (default: 15) */
+  staleDataMinutes: z
+    .number()
+    .int()
+    .min(0)
+    .max(720)
+    .default(DEFAULTS.tauri.staleDataMinutes),
+  /** Hold auto-joins while the meeting list is stale (see `staleDataMinutes`) until the page reports again (default: false) */
+  skipJoinWhenStale: z.boolean().default(DEFAULTS.tauri.skipJoinWhenStale),
+  /** Skip auto-join for titles matching any of these filters; unlike `titleExcludeFilters` they ignore case unless `caseSensitive` is set (default: []) */
+  excludeTitleFilters: z
+    .array(TitleFilterSchema)
+    .default([...DEFAULTS.tauri.excludeTitleFilters]),
+  /** Only auto-join titles matching one of these filters; empty auto-joins any title not excluded (default: []) */
+  includeTitleFilters: z
+    .array(TitleFilterSchema)
+    .default([...DEFAULTS.tauri.includeTitleFilters]),
+  /** Connect to the MQTT broker over TLS, usually on port 8883; needed to send a user name and password to a broker on another machine (default: false) */
+  mqttTls: z.boolean().default(DEFAULTS.tauri.mqttTls),
+  /** Hold auto-joins while meet.google.com can't be reached, joining once it answers again if the meeting is still in its join window (default: true) */
+  holdJoinsWhenOffline: z.boolean().default(DEFAULTS.tauri.holdJoinsWhenOffline),
 });
 
 /**
@@ -157,3 +563,16 @@ export type TauriSettings = z.infer<typeof TauriSettingsSchema>;
  * Media state type
  */
 export type MediaState = z.infer<typeof MediaStateSchema>;
+
+/**
+ * Per-meeting override rule type
+ */
+export type MeetingRule = z.infer<typeof MeetingRuleSchema>;
+export type BusyBlockRule = z.infer<typeof BusyBlockRuleSchema>;
+export type DndWindow = z.infer<typeof DndWindowSchema>;
+export type AutoJoinHours = z.infer<typeof AutoJoinHoursSchema>;
+export type Profile = z.infer<typeof ProfileSchema>;
+export type GlobalShortcuts = z.infer<typeof GlobalShortcutsSchema>;
+export type MaintenanceWindow = z.infer<typeof MaintenanceWindowSchema>;
+export type WebhookEvent = z.infer<typeof WebhookEventSchema>;
+export type TitleFilter = z.infer<typeof TitleFilterSchema>;
@@ -864,4 +864,110 @@ describe("App", () => {
       });
     });
   });
+
+  it("should walk through troubleshooter steps", async () => {
+    const report = {
+      ranAtMs: 0,
+      passed: false,
+      steps: [
+        { id: "injection", status: "fail", detail: "boom", fix: "open_meet_home" },
+        { id: "logging", status: "pass", detail: null, fix: null },
+      ],
+    };
+    const baseInvoke = mockInvoke.getMockImplementation();
+    mockInvoke.mockImplementation((cmd, args) => {
+      if (cmd === "run_troubleshooter") {
+        return Promise.resolve(report);
+      }
+      return baseInvoke?.(cmd, args);
+    });
+
+    render(<App />);
+
+    fireEvent.click(await screen.findByText("Troubleshoot..."));
+
+    await waitFor(() => {
+      expect(screen.getByText("MeetCat script")).toBeDefined();
+      expect(screen.getByText("Failed")).toBeDefined();
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("run_troubleshooter");
+
+    fireEvent.click(screen.getByText("Open Google Meet"));
+    expect(mockInvoke).toHaveBeenCalledWith("navigate_home");
+
+    fireEvent.click(screen.getByText("Next"));
+    fireEvent.click(screen.getByText("Next"));
+
+    await waitFor(() => {
+      expect(
+        screen.getByText(
+          "Some checks failed. Follow the suggested fixes and run the troubleshooter again."
+        )
+      ).toBeDefined();
+    });
+  });
+
+  it("should only offer Slack status sync when it is enabled", async () => {
+    render(<App />);
+
+    await waitFor(() => {
+      expect(screen.getByText("Troubleshoot...")).toBeDefined();
+    });
+    expect(screen.queryByText("Sync Slack status")).toBeNull();
+  });
+
+  it("should report the result of a manual Slack status sync", async () => {
+    mockInvokeWithSettings({
+      ...defaultSettings,
+      tauri: { ...DEFAULT_TAURI_SETTINGS, slackStatusEnabled: true },
+    });
+    const baseInvoke = mockInvoke.getMockImplementation();
+    mockInvoke.mockImplementation((cmd, args) => {
+      if (cmd === "sync_presence_now") {
+        return Promise.reject("slack: Slack error: invalid_auth");
+      }
+      return baseInvoke?.(cmd, args);
+    });
+
+    render(<App />);
+
+    fireEvent.click(await screen.findByText("Sync Slack status"));
+
+    await waitFor(() => {
+      expect(
+        screen.getByText("Couldn't sync Slack status: slack: Slack error: invalid_auth")
+      ).toBeDefined();
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("sync_presence_now");
+  });
+
+  it("should save a note for the meeting requested by the summary", async () => {
+    const baseInvoke = mockInvoke.getMockImplementation();
+    mockInvoke.mockImplementation((cmd, args) => {
+      if (cmd === "consume_history_note_request") {
+        return Promise.resolve({
+          callId: "abc-defg-hij",
+          recordedAtMs: 1_700_000_000_000,
+          title: "Standup",
+        });
+      }
+      return baseInvoke?.(cmd, args);
+    });
+
+    render(<App />);
+
+    const input = await screen.findByPlaceholderText("e.g. decisions, follow-ups");
+    expect(screen.getByText("Standup")).toBeDefined();
+    fireEvent.change(input, { target: { value: "Ship on Friday" } });
+    fireEvent.click(screen.getByText("Save"));
+
+    await waitFor(() => {
+      expect(mockInvoke).toHaveBeenCalledWith("set_history_note", {
+        callId: "abc-defg-hij",
+        recordedAtMs: 1_700_000_000_000,
+        note: "Ship on Friday",
+      });
+      expect(screen.queryByText("Add a note")).toBeNull();
+    });
+  });
 });
@@ -0,0 +1,128 @@
+//! Baseline benchmarks for the join decision engine and tray formatting.
+//!
+//! Run with `cargo bench --bench decision_engine`.
+
+use chrono::Utc;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use meetcat_lib::daemon::DaemonState;
+use meetcat_lib::filters::TitleFilters;
+use meetcat_lib::fixtures::{synthetic_meetings, synthetic_title_filters};
+use meetcat_lib::i18n::Language;
+use meetcat_lib::settings::{Settings, TauriSettings, TrayDisplayMode};
+use meetcat_lib::tray::{build_tray_title, build_tray_tooltip};
+use std::collections::HashMap;
+
+fn bench_calculate_next_trigger(c: &mut Criterion) {
+    let mut group = c.benchmark_group("calculate_next_trigger");
+    let settings = Settings::default();
+    let title_filters = TitleFilters::from_settings(&settings);
+    for count in [10usize, 100, 500] {
+        let mut state = DaemonState::default();
+        state.update_meetings(synthetic_meetings(count, Utc::now()));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &state, |b, state| {
+            b.iter(|| state.calculate_next_trigger(black_box(&settings), &title_filters))
+        });
+    }
+    group.finish();
+}
+
+fn bench_title_filters(c: &mut Criterion) {
+    let mut group = c.benchmark_group("title_filters");
+    let mut state = DaemonState::default();
+    state.update_meetings(synthetic_meetings(200, Utc::now()));
+    for filter_count in [0usize, 10, 100] {
+        let settings = Settings {
+            title_exclude_filters: synthetic_title_filters(filter_count),
+            ..Settings::default()
+        };
+        let title_filters = TitleFilters::from_settings(&settings);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(filter_count),
+            &settings,
+            |b, settings| {
+                b.iter(|| state.calculate_next_trigger(black_box(settings), &title_filters))
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_tray_title(c: &mut Criterion) {
+    let mut group = c.benchmark_group("build_tray_title");
+    let now = Utc::now();
+    let meetings = synthetic_meetings(1, now);
+    let meeting = &meetings[0];
+    let lang = Language::En;
+    for (name, mode) in [
+        ("icon_only", TrayDisplayMode::IconOnly),
+        ("time", TrayDisplayMode::IconWithTime),
+        ("countdown", TrayDisplayMode::IconWithCountdown),
+    ] {
+        let settings = TauriSettings {
+            tray_display_mode: mode,
+            tray_show_meeting_title: true,
+            ..TauriSettings::default()
+        };
+        group.bench_function(name, |b| {
+            b.iter(|| build_tray_title(black_box(Some(meeting)), &settings, &lang, now))
+        });
+    }
+    group.finish();
+}
+
+fn bench_meeting_snapshot(c: &mut Criterion) {
+    let mut group = c.benchmark_group("meeting_snapshot");
+    for count in [10usize, 100, 500] {
+        let mut state = DaemonState::default();
+        state.update_meetings(synthetic_meetings(count, Utc::now()));
+        group.bench_with_input(BenchmarkId::new("vec_clone", count), &state, |b, state| {
+            b.iter(|| black_box(state.get_meetings().to_vec()))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("arc_snapshot", count),
+            &state,
+            |b, state| b.iter(|| black_box(state.get_meetings())),
+        );
+    }
+    group.finish();
+}
+
+fn bench_tray_tooltip(c: &mut Criterion) {
+    let mut group = c.benchmark_group("build_tray_tooltip");
+    let settings = TauriSettings::default();
+    let origins = HashMap::new();
+    let lang = Language::En;
+    for count in [10usize, 100] {
+        let now = Utc::now();
+        let mut state = DaemonState::default();
+        state.update_meetings(synthetic_meetings(count, now));
+        let upcoming = state.upcoming_meetings_at(now);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(count),
+            &upcoming,
+            |b, upcoming| {
+                b.iter(|| {
+                    build_tray_tooltip(
+                        black_box(upcoming.as_slice()),
+                        &[],
+                        &[],
+                        &origins,
+                        &settings,
+                        &lang,
+                    )
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_calculate_next_trigger,
+    bench_title_filters,
+    bench_tray_title,
+    bench_meeting_snapshot,
+    bench_tray_tooltip
+);
+criterion_main!(benches);
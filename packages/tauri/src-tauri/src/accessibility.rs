@@ -0,0 +1,47 @@
+//! Screen reader announcements
+//!
+//! An auto-join switches the user's context without any non-visual cue, so
+//! the join countdown and the join itself are announced to VoiceOver. Other
+//! platforms rely on the desktop notification, which screen readers read.
+
+use tauri::AppHandle;
+
+/// Ask the screen reader to speak `message` with high priority
+#[cfg(target_os = "macos")]
+pub fn announce(app: &AppHandle, message: &str) -> Result<(), String> {
+    let message = message.to_string();
+    app.run_on_main_thread(move || {
+        use objc2::runtime::AnyObject;
+        use objc2_app_kit::{
+            NSAccessibilityAnnouncementKey, NSAccessibilityAnnouncementRequestedNotification,
+            NSAccessibilityPostNotificationWithUserInfo, NSAccessibilityPriorityKey,
+            NSAccessibilityPriorityLevel, NSApplication,
+        };
+        use objc2_foundation::{MainThreadMarker, NSDictionary, NSNumber, NSString};
+
+        let Some(mtm) = MainThreadMarker::new() else {
+            return;
+        };
+        let application = NSApplication::sharedApplication(mtm);
+        let element: &AnyObject = application.as_ref();
+        let text = NSString::from_str(&message);
+        let priority = NSNumber::new_isize(NSAccessibilityPriorityLevel::High.0);
+        unsafe {
+            let user_info = NSDictionary::<NSString, AnyObject>::from_slices(
+                &[NSAccessibilityAnnouncementKey, NSAccessibilityPriorityKey],
+                &[text.as_ref(), priority.as_ref()],
+            );
+            NSAccessibilityPostNotificationWithUserInfo(
+                element,
+                NSAccessibilityAnnouncementRequestedNotification,
+                Some(&user_info),
+            );
+        }
+    })
+    .map_err(|e| e.to_string())
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn announce(_app: &AppHandle, _message: &str) -> Result<(), String> {
+    Err("Screen reader announcements are only supported on macOS".to_string())
+}
@@ -0,0 +1,646 @@
+//! Local control API for automation
+//!
+//! An opt-in HTTP server on 127.0.0.1 lets Stream Deck, Raycast or
+//! home-automation setups drive MeetCat without touching the webview:
+//!
+//! - `GET /status` — the `AppStatus` the tray and settings window use
+//! - `POST /join/{call_id}` — join a meeting, like `meetcat://join/<code>`
+//! - `POST /pause?minutes=<n>` (or `{"minutes": n}`) — pause auto-join
+//! - `POST /resume` — resume auto-join
+//! - `GET /events` — WebSocket stream of meeting events
+//!
+//! Every request needs `Authorization: Bearer <token>`; only the `/events`
+//! upgrade also takes `?token=`, for WebSocket clients that can't set
+//! headers. Requests whose `Host` isn't local are refused so a web page
+//! can't reach the server through DNS rebinding. HTTP is served by hyper,
+//! one request per connection, and `/events` by tungstenite.
+
+use http_body_util::{BodyExt, Full, Limited};
+use hyper::body::{Bytes, Incoming};
+use hyper::header::{self, HeaderMap, HeaderValue};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::{TokioIo, TokioTimer};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::convert::Infallible;
+use std::net::{Ipv4Addr, TcpListener as StdTcpListener};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::Url;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, watch};
+use tokio_tungstenite::tungstenite::handshake::derive_accept_key;
+use tokio_tungstenite::tungstenite::protocol::{Message, Role, WebSocketConfig};
+use tokio_tungstenite::WebSocketStream;
+
+use crate::ipc::event;
+use crate::locks::LockExt;
+use crate::url_norm;
+use crate::url_scheme::MAX_PAUSE_MINUTES;
+
+/// App events forwarded to `/events` clients. `navigate-and-join` and
+/// `settings_changed` are left out since they carry the settings.
+pub const STREAMED_EVENTS: &[&str] = &[
+    event::MEETING_LIFECYCLE,
+    event::MEETING_CONFLICT,
+    event::JOIN_BLOCKED,
+    event::JOIN_RATE_CAPPED,
+];
+
+/// Shortest token accepted, so it can't be guessed by trying
+pub const MIN_TOKEN_LEN: usize = 16;
+const MAX_BODY_BYTES: usize = 4 * 1024;
+const MAX_FRAME_BYTES: usize = 64 * 1024;
+/// How long a client may take to send its request head and body
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Check the configured port before binding it
+pub fn validate_port(port: u16) -> Result<(), String> {
+    if port < 1024 {
+        return Err(format!(
+            "API server port must be between 1024 and 65535, got {}",
+            port
+        ));
+    }
+    Ok(())
+}
+
+/// Check a token before it's stored
+pub fn validate_token(token: &str) -> Result<(), String> {
+    if token.chars().count() < MIN_TOKEN_LEN {
+        return Err(format!(
+            "API server token must be at least {} characters",
+            MIN_TOKEN_LEN
+        ));
+    }
+    Ok(())
+}
+
+/// What a request asks for, once routed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiRoute {
+    Status,
+    /// `code` is a path as in `DeepLinkAction::JoinMeeting`
+    Join {
+        code: String,
+    },
+    Pause {
+        minutes: u32,
+    },
+    Resume,
+    Events,
+}
+
+/// Error answered with its HTTP status
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ApiError {
+    #[serde(skip)]
+    pub status: u16,
+    pub error: String,
+}
+
+impl ApiError {
+    pub fn new(status: u16, error: impl Into<String>) -> Self {
+        Self {
+            status,
+            error: error.into(),
+        }
+    }
+}
+
+/// Runs routed requests; called off the async runtime, so it may block
+pub type ApiHandler = Arc<dyn Fn(ApiRoute) -> Result<Value, ApiError> + Send + Sync>;
+
+/// A running server; dropping it stops the server and its event streams
+pub struct ApiServerHandle {
+    port: u16,
+    token: Arc<Mutex<String>>,
+    shutdown: watch::Sender<bool>,
+}
+
+impl ApiServerHandle {
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Accept `token` from now on, without giving up the port
+    pub fn set_token(&self, token: &str) -> Result<(), String> {
+        validate_token(token)?;
+        *self.token.lock_or_recover() = token.to_string();
+        Ok(())
+    }
+}
+
+impl Drop for ApiServerHandle {
+    fn drop(&mut self) {
+        let _ = self.shutdown.send(true);
+    }
+}
+
+/// Bind `port` on 127.0.0.1 and serve until the handle is dropped
+pub fn start(
+    port: u16,
+    token: &str,
+    handler: ApiHandler,
+    events: broadcast::Sender<String>,
+) -> Result<ApiServerHandle, String> {
+    validate_port(port)?;
+    validate_token(token)?;
+    let listener = StdTcpListener::bind((Ipv4Addr::LOCALHOST, port))
+        .map_err(|e| format!("Failed to listen on 127.0.0.1:{}: {}", port, e))?;
+    listener.set_nonblocking(true).map_err(|e| e.to_string())?;
+
+    let (shutdown, mut stopped) = watch::channel(false);
+    let token = Arc::new(Mutex::new(token.to_string()));
+    let connection = Arc::new(Connection {
+        token: Arc::clone(&token),
+        handler,
+        events,
+        stopped: stopped.clone(),
+    });
+    tauri::async_runtime::spawn(async move {
+        let Ok(listener) = TcpListener::from_std(listener) else {
+            return;
+        };
+        loop {
+            tokio::select! {
+                _ = stopped.changed() => break,
+                accepted = listener.accept() => {
+                    let Ok((stream, _)) = accepted else {
+                        continue;
+                    };
+                    tauri::async_runtime::spawn(Arc::clone(&connection).serve(stream));
+                }
+            }
+        }
+    });
+    Ok(ApiServerHandle {
+        port,
+        token,
+        shutdown,
+    })
+}
+
+/// The message sent to `/events` clients for an app event
+pub fn event_message(event: &str, payload: &str) -> String {
+    json!({
+        "event": event,
+        "payload": serde_json::from_str::<Value>(payload).unwrap_or(Value::Null),
+    })
+    .to_string()
+}
+
+/// What every connection of one server shares
+struct Connection {
+    token: Arc<Mutex<String>>,
+    handler: ApiHandler,
+    events: broadcast::Sender<String>,
+    stopped: watch::Receiver<bool>,
+}
+
+type ApiResponse = Response<Full<Bytes>>;
+
+impl Connection {
+    async fn serve(self: Arc<Self>, stream: TcpStream) {
+        let mut stopped = self.stopped.clone();
+        let service = service_fn(move |request| {
+            let connection = Arc::clone(&self);
+            async move { Ok::<_, Infallible>(connection.respond(request).await) }
+        });
+        // A client that stalls while sending its request head is dropped
+        let serving = http1::Builder::new()
+            .timer(TokioTimer::new())
+            .header_read_timeout(READ_TIMEOUT)
+            .serve_connection(TokioIo::new(stream), service)
+            .with_upgrades();
+        tokio::select! {
+            _ = serving => {}
+            _ = stopped.changed() => {}
+        }
+    }
+
+    async fn respond(&self, request: Request<Incoming>) -> ApiResponse {
+        self.handle(request)
+            .await
+            .unwrap_or_else(|e| json_response(e.status, &json!(e)))
+    }
+
+    async fn handle(&self, mut request: Request<Incoming>) -> Result<ApiResponse, ApiError> {
+        let url = request_url(&request)?;
+        self.authorize(request.headers(), &url)?;
+        let upgrade = hyper::upgrade::on(&mut request);
+        let (parts, body) = request.into_parts();
+
+        let body = tokio::time::timeout(READ_TIMEOUT, Limited::new(body, MAX_BODY_BYTES).collect())
+            .await
+            .map_err(|_| ApiError::new(408, "Timed out reading the request"))?
+            .map_err(|_| ApiError::new(413, "Request body too large"))?
+            .to_bytes();
+        let body = if body.is_empty() {
+            None
+        } else {
+            Some(
+                serde_json::from_slice::<Value>(&body)
+                    .map_err(|_| ApiError::new(400, "Body must be JSON"))?,
+            )
+        };
+        let route = route_request(parts.method.as_str(), &url, body.as_ref())?;
+
+        if route == ApiRoute::Events {
+            let key = parts
+                .headers
+                .get(header::SEC_WEBSOCKET_KEY)
+                .filter(|_| is_websocket_upgrade(&parts.headers))
+                .ok_or_else(|| ApiError::new(426, "WebSocket upgrade required"))?;
+            let accept = derive_accept_key(key.as_bytes());
+            let events = self.events.subscribe();
+            let stopped = self.stopped.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Ok(upgraded) = upgrade.await {
+                    let config = WebSocketConfig::default()
+                        .max_message_size(Some(MAX_FRAME_BYTES))
+                        .max_frame_size(Some(MAX_FRAME_BYTES));
+                    let socket = WebSocketStream::from_raw_socket(
+                        TokioIo::new(upgraded),
+                        Role::Server,
+                        Some(config),
+                    )
+                    .await;
+                    stream_events(socket, events, stopped).await;
+                }
+            });
+            return Response::builder()
+                .status(StatusCode::SWITCHING_PROTOCOLS)
+                .header(header::CONNECTION, "upgrade")
+                .header(header::UPGRADE, "websocket")
+                .header(header::SEC_WEBSOCKET_ACCEPT, accept)
+                .body(Full::default())
+                .map_err(|e| ApiError::new(500, e.to_string()));
+        }
+
+        let handler = Arc::clone(&self.handler);
+        let body = tokio::task::spawn_blocking(move || handler(route))
+            .await
+            .unwrap_or_else(|_| Err(ApiError::new(500, "Request handler failed")))?;
+        Ok(json_response(200, &body))
+    }
+
+    /// The token comes in the `Authorization` header; only the `/events`
+    /// upgrade may pass it as `?token=` instead
+    fn authorize(&self, headers: &HeaderMap, url: &Url) -> Result<(), ApiError> {
+        let value = |name| {
+            headers
+                .get(name)
+                .and_then(|v: &HeaderValue| v.to_str().ok())
+        };
+        if !is_local_host(value(header::HOST)) {
+            return Err(ApiError::new(403, "Host not allowed"));
+        }
+        let bearer = value(header::AUTHORIZATION)
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim);
+        let query = is_events_path(url)
+            .then(|| {
+                url.query_pairs()
+                    .find(|(key, _)| key == "token")
+                    .map(|(_, value)| value.into_owned())
+            })
+            .flatten();
+        let given = bearer.or(query.as_deref()).unwrap_or_default();
+        let token = self.token.lock_or_recover().clone();
+        if !constant_time_eq(given.as_bytes(), token.as_bytes()) {
+            return Err(ApiError::new(401, "Missing or wrong token"));
+        }
+        Ok(())
+    }
+}
+
+/// The request target resolved against the server, so dot segments are gone
+/// before routing
+fn request_url<B>(request: &Request<B>) -> Result<Url, ApiError> {
+    let target = request
+        .uri()
+        .path_and_query()
+        .map(|target| target.as_str())
+        .unwrap_or("/");
+    if !target.starts_with('/') {
+        return Err(ApiError::new(400, "Malformed request target"));
+    }
+    Url::parse(&format!("http://localhost{}", target))
+        .map_err(|_| ApiError::new(400, "Malformed request target"))
+}
+
+fn is_events_path(url: &Url) -> bool {
+    url.path().trim_matches('/') == "events"
+}
+
+fn is_websocket_upgrade(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::UPGRADE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.eq_ignore_ascii_case("websocket"))
+}
+
+/// Map a method, URL and JSON body to what they ask for
+fn route_request(method: &str, url: &Url, body: Option<&Value>) -> Result<ApiRoute, ApiError> {
+    let segments: Vec<&str> = url
+        .path()
+        .trim_matches('/')
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    let (route, allowed) = match segments.as_slice() {
+        ["status"] => (Some(ApiRoute::Status), "GET"),
+        ["events"] => (Some(ApiRoute::Events), "GET"),
+        ["resume"] => (Some(ApiRoute::Resume), "POST"),
+        ["pause"] => (None, "POST"),
+        ["join", ..] => (None, "POST"),
+        _ => return Err(ApiError::new(404, "Not found")),
+    };
+    if method != allowed {
+        return Err(ApiError::new(
+            405,
+            format!("Use {} for /{}", allowed, segments.join("/")),
+        ));
+    }
+    if let Some(route) = route {
+        return Ok(route);
+    }
+
+    match segments.as_slice() {
+        ["pause"] => {
+            let from_query = url
+                .query_pairs()
+                .find(|(key, _)| key == "minutes")
+                .map(|(_, value)| value.trim().parse::<u64>().ok());
+            let from_body = body.and_then(|b| b.get("minutes")).map(Value::as_u64);
+            let minutes = from_query
+                .or(from_body)
+                .flatten()
+                .filter(|m| (1..=MAX_PAUSE_MINUTES as u64).contains(m))
+                .ok_or_else(|| {
+                    ApiError::new(
+                        400,
+                        format!("minutes must be between 1 and {}", MAX_PAUSE_MINUTES),
+                    )
+                })?;
+            Ok(ApiRoute::Pause {
+                minutes: minutes as u32,
+            })
+        }
+        ["join", rest @ ..] => {
+            let target = url_norm::parse_target_path(&rest.join("/"))
+                .ok_or_else(|| ApiError::new(400, "Not a meeting code"))?;
+            Ok(ApiRoute::Join {
+                code: target.path(),
+            })
+        }
+        _ => Err(ApiError::new(404, "Not found")),
+    }
+}
+
+/// `localhost` or a loopback address, with any port
+fn is_local_host(host: Option<&str>) -> bool {
+    let Some(host) = host else {
+        return false;
+    };
+    let name = match host.strip_prefix('[') {
+        Some(rest) => rest.split(']').next().unwrap_or_default(),
+        None => host.split(':').next().unwrap_or_default(),
+    };
+    matches!(
+        name.to_ascii_lowercase().as_str(),
+        "localhost" | "127.0.0.1" | "::1"
+    )
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn json_response(status: u16, body: &Value) -> ApiResponse {
+    let mut response = Response::new(Full::new(Bytes::from(body.to_string())));
+    *response.status_mut() =
+        StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    let headers = response.headers_mut();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json"),
+    );
+    // One request per connection
+    headers.insert(header::CONNECTION, HeaderValue::from_static("close"));
+    response
+}
+
+/// Send events until either side stops. Client messages are read only to
+/// notice a close; tungstenite answers pings on its own.
+async fn stream_events<S>(
+    socket: WebSocketStream<S>,
+    mut events: broadcast::Receiver<String>,
+    mut stopped: watch::Receiver<bool>,
+) where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    use futures_util::{SinkExt, StreamExt};
+
+    let (mut sink, mut client) = socket.split();
+    loop {
+        tokio::select! {
+            _ = stopped.changed() => {
+                let _ = sink.send(Message::Close(None)).await;
+                return;
+            }
+            message = client.next() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => {
+                    let _ = sink.close().await;
+                    return;
+                }
+                Some(Ok(_)) => {}
+            },
+            event = events.recv() => match event {
+                Ok(message) => {
+                    if sink.send(Message::text(message)).await.is_err() {
+                        return;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(_)) => {}
+                Err(broadcast::error::RecvError::Closed) => return,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(target: &str) -> Url {
+        Url::parse(&format!("http://localhost{}", target)).unwrap()
+    }
+
+    #[test]
+    fn test_route_request() {
+        assert_eq!(
+            route_request("GET", &url("/status"), None),
+            Ok(ApiRoute::Status)
+        );
+        assert_eq!(
+            route_request("POST", &url("/join/ABC-DEFG-HIJ"), None),
+            Ok(ApiRoute::Join {
+                code: "abc-defg-hij".to_string(),
+            })
+        );
+        assert_eq!(
+            route_request("POST", &url("/join/lookup/ab_cd"), None),
+            Ok(ApiRoute::Join {
+                code: "lookup/ab_cd".to_string(),
+            })
+        );
+        assert_eq!(
+            route_request("POST", &url("/pause?minutes=30"), None),
+            Ok(ApiRoute::Pause { minutes: 30 })
+        );
+        assert_eq!(
+            route_request("POST", &url("/pause"), Some(&json!({ "minutes": 15 }))),
+            Ok(ApiRoute::Pause { minutes: 15 })
+        );
+        assert_eq!(
+            route_request("POST", &url("/resume/"), None),
+            Ok(ApiRoute::Resume)
+        );
+        assert_eq!(
+            route_request("GET", &url("/events?token=x"), None),
+            Ok(ApiRoute::Events)
+        );
+    }
+
+    #[test]
+    fn test_route_request_errors() {
+        let status = |method, target: &str, body: Option<Value>| {
+            route_request(method, &url(target), body.as_ref())
+                .unwrap_err()
+                .status
+        };
+        assert_eq!(status("GET", "/", None), 404);
+        assert_eq!(status("GET", "/settings", None), 404);
+        assert_eq!(status("POST", "/status", None), 405);
+        assert_eq!(status("GET", "/join/abc-defg-hij", None), 405);
+        assert_eq!(status("POST", "/join/nope", None), 400);
+        // Dot segments are resolved before routing
+        assert_eq!(status("POST", "/join/../status", None), 405);
+        assert_eq!(status("POST", "/pause", None), 400);
+        assert_eq!(status("POST", "/pause?minutes=0", None), 400);
+        assert_eq!(
+            status("POST", "/pause", Some(json!({ "minutes": 5000 }))),
+            400
+        );
+    }
+
+    #[test]
+    fn test_request_url_and_auth_inputs() {
+        let request = |target: &str| Request::builder().uri(target).body(()).unwrap();
+        let url = request_url(&request("/pause?minutes=5&token=t")).unwrap();
+        assert_eq!(url.path(), "/pause");
+        assert!(!is_events_path(&url));
+        assert!(is_events_path(
+            &request_url(&request("/events/?token=t")).unwrap()
+        ));
+
+        assert!(is_local_host(Some("127.0.0.1:47625")));
+        assert!(is_local_host(Some("localhost")));
+        assert!(is_local_host(Some("[::1]:47625")));
+        assert!(!is_local_host(Some("attacker.example:47625")));
+        assert!(!is_local_host(None));
+
+        assert!(constant_time_eq(b"secret-token", b"secret-token"));
+        assert!(!constant_time_eq(b"secret-token", b"secret-tokem"));
+        assert!(!constant_time_eq(b"secret", b"secret-token"));
+    }
+
+    #[test]
+    fn test_validate_settings() {
+        assert!(validate_port(47625).is_ok());
+        assert!(validate_port(80).is_err());
+        assert!(validate_token("0123456789abcdef").is_ok());
+        assert!(validate_token("short").is_err());
+    }
+
+    /// A free port for a test server; racy, but only within one test run
+    fn free_port() -> u16 {
+        loop {
+            let listener = StdTcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+            let port = listener.local_addr().unwrap().port();
+            if validate_port(port).is_ok() {
+                return port;
+            }
+        }
+    }
+
+    async fn http(port: u16, head: &str) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let mut stream = TcpStream::connect((Ipv4Addr::LOCALHOST, port))
+            .await
+            .unwrap();
+        stream
+            .write_all(format!("{}\r\nHost: 127.0.0.1\r\n\r\n", head).as_bytes())
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn test_serves_requests_and_events() {
+        use futures_util::StreamExt;
+
+        let token = "0123456789abcdef";
+        let port = free_port();
+        let (events, _) = broadcast::channel(4);
+        let handler: ApiHandler = Arc::new(|route| Ok(json!({ "route": format!("{:?}", route) })));
+        let _server = start(port, token, handler, events.clone()).unwrap();
+
+        let ok = http(
+            port,
+            &format!("GET /status HTTP/1.1\r\nAuthorization: Bearer {}", token),
+        )
+        .await;
+        assert!(ok.starts_with("HTTP/1.1 200"), "{}", ok);
+        assert!(ok.ends_with(r#"{"route":"Status"}"#), "{}", ok);
+
+        // The query token is only for the WebSocket upgrade
+        let query = http(port, &format!("GET /status?token={} HTTP/1.1", token)).await;
+        assert!(query.starts_with("HTTP/1.1 401"), "{}", query);
+        let plain = http(
+            port,
+            &format!("GET /events HTTP/1.1\r\nAuthorization: Bearer {}", token),
+        )
+        .await;
+        assert!(plain.starts_with("HTTP/1.1 426"), "{}", plain);
+
+        let stream = TcpStream::connect((Ipv4Addr::LOCALHOST, port))
+            .await
+            .unwrap();
+        let (mut socket, _) = tokio_tungstenite::client_async(
+            format!("ws://127.0.0.1:{}/events?token={}", port, token),
+            stream,
+        )
+        .await
+        .unwrap();
+        events.send("hello".to_string()).unwrap();
+        let message = socket.next().await.unwrap().unwrap();
+        assert_eq!(message, Message::text("hello"));
+    }
+
+    #[test]
+    fn test_event_message() {
+        let message = event_message("join:blocked", r#"{"callId":"abc-defg-hij"}"#);
+        assert_eq!(
+            serde_json::from_str::<Value>(&message).unwrap(),
+            json!({ "event": "join:blocked", "payload": { "callId": "abc-defg-hij" } })
+        );
+    }
+}
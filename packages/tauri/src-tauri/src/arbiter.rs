@@ -0,0 +1,121 @@
+//! Serializes active calls across MeetCat windows
+//!
+//! Each webview window reports when it joins or leaves a call. Before a join
+//! trigger opens another meeting, the arbiter is asked whether a different
+//! call is still active anywhere; if so the join is held until that call ends,
+//! unless concurrent meetings are allowed.
+
+use serde::Serialize;
+
+/// A call that a window reported as joined
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveCall {
+    pub window: String,
+    pub call_id: String,
+    pub joined_at_ms: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct MeetingArbiter {
+    active: Vec<ActiveCall>,
+    /// Join currently held back by an active call, to log it only once
+    held_call_id: Option<String>,
+}
+
+impl MeetingArbiter {
+    /// Record that `window` joined `call_id`; a window is in one call at a time
+    pub fn on_joined(&mut self, window: &str, call_id: &str, now_ms: u64) {
+        self.active.retain(|call| call.window != window);
+        self.active.push(ActiveCall {
+            window: window.to_string(),
+            call_id: call_id.to_string(),
+            joined_at_ms: now_ms,
+        });
+        if self.held_call_id.as_deref() == Some(call_id) {
+            self.held_call_id = None;
+        }
+    }
+
+    /// Record that `window` left `call_id`
+    pub fn on_left(&mut self, window: &str, call_id: &str) {
+        self.active
+            .retain(|call| !(call.window == window && call.call_id == call_id));
+    }
+
+    /// Drop calls whose window closed or no longer shows them
+    pub fn retain_live(&mut self, is_live: impl Fn(&ActiveCall) -> bool) {
+        self.active.retain(|call| is_live(call));
+    }
+
+    pub fn active_calls(&self) -> &[ActiveCall] {
+        &self.active
+    }
+
+    /// The active call that prevents joining `call_id`, if any
+    pub fn blocking_call(&self, call_id: &str, allow_concurrent: bool) -> Option<&ActiveCall> {
+        if allow_concurrent {
+            return None;
+        }
+        self.active.iter().find(|call| call.call_id != call_id)
+    }
+
+    /// Mark `call_id` as held; returns true the first time it is held
+    pub fn hold(&mut self, call_id: &str) -> bool {
+        if self.held_call_id.as_deref() == Some(call_id) {
+            return false;
+        }
+        self.held_call_id = Some(call_id.to_string());
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks_other_calls_until_left() {
+        let mut arbiter = MeetingArbiter::default();
+        arbiter.on_joined("main", "aaa", 0);
+
+        assert_eq!(
+            arbiter
+                .blocking_call("bbb", false)
+                .map(|c| c.call_id.as_str()),
+            Some("aaa")
+        );
+        assert!(arbiter.blocking_call("aaa", false).is_none());
+        assert!(arbiter.blocking_call("bbb", true).is_none());
+
+        arbiter.on_left("main", "aaa");
+        assert!(arbiter.blocking_call("bbb", false).is_none());
+    }
+
+    #[test]
+    fn test_window_holds_one_call_and_pruning() {
+        let mut arbiter = MeetingArbiter::default();
+        arbiter.on_joined("main", "aaa", 0);
+        arbiter.on_joined("main", "bbb", 10);
+        arbiter.on_joined("profile-2", "ccc", 20);
+        assert_eq!(arbiter.active_calls().len(), 2);
+
+        // Leaving a call the window is no longer in changes nothing
+        arbiter.on_left("main", "aaa");
+        assert_eq!(arbiter.active_calls().len(), 2);
+
+        arbiter.retain_live(|call| call.window != "profile-2");
+        assert_eq!(arbiter.active_calls().len(), 1);
+        arbiter.retain_live(|call| call.call_id != "bbb");
+        assert!(arbiter.active_calls().is_empty());
+    }
+
+    #[test]
+    fn test_hold_reported_once_per_call() {
+        let mut arbiter = MeetingArbiter::default();
+        assert!(arbiter.hold("bbb"));
+        assert!(!arbiter.hold("bbb"));
+        arbiter.on_joined("main", "bbb", 0);
+        assert!(arbiter.hold("bbb"));
+    }
+}
@@ -0,0 +1,151 @@
+//! Detection of the default microphone being in use
+//!
+//! Used to hold an auto-join while another app (e.g. a Zoom call) is
+//! recording. macOS asks CoreAudio whether the default input device is running
+//! in any process; Linux checks ALSA capture substreams. Other platforms report
+//! `None` and never hold a join.
+
+use std::fs;
+use std::path::Path;
+
+/// Whether the default input device is currently recording somewhere.
+///
+/// `None` when the state can't be determined on this platform.
+pub fn input_device_in_use() -> Option<bool> {
+    #[cfg(target_os = "macos")]
+    {
+        coreaudio::default_input_running()
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        if cfg!(target_os = "linux") {
+            alsa_capture_running(Path::new("/proc/asound"))
+        } else {
+            None
+        }
+    }
+}
+
+/// Scan `card*/pcm*c/sub*/status` under `asound_dir` for a running capture
+fn alsa_capture_running(asound_dir: &Path) -> Option<bool> {
+    let cards = fs::read_dir(asound_dir).ok()?;
+    let mut found_capture = false;
+    for card in cards.flatten() {
+        if !card.file_name().to_string_lossy().starts_with("card") {
+            continue;
+        }
+        let Ok(pcms) = fs::read_dir(card.path()) else {
+            continue;
+        };
+        for pcm in pcms.flatten() {
+            let name = pcm.file_name().to_string_lossy().into_owned();
+            if !(name.starts_with("pcm") && name.ends_with('c')) {
+                continue;
+            }
+            found_capture = true;
+            let Ok(subs) = fs::read_dir(pcm.path()) else {
+                continue;
+            };
+            for sub in subs.flatten() {
+                let status = fs::read_to_string(sub.path().join("status")).unwrap_or_default();
+                if status.lines().any(|line| line.trim() == "state: RUNNING") {
+                    return Some(true);
+                }
+            }
+        }
+    }
+    found_capture.then_some(false)
+}
+
+#[cfg(target_os = "macos")]
+mod coreaudio {
+    use std::ffi::c_void;
+
+    #[repr(C)]
+    struct AudioObjectPropertyAddress {
+        selector: u32,
+        scope: u32,
+        element: u32,
+    }
+
+    #[link(name = "CoreAudio", kind = "framework")]
+    extern "C" {
+        fn AudioObjectGetPropertyData(
+            object_id: u32,
+            address: *const AudioObjectPropertyAddress,
+            qualifier_size: u32,
+            qualifier: *const c_void,
+            data_size: *mut u32,
+            data: *mut c_void,
+        ) -> i32;
+    }
+
+    const SYSTEM_OBJECT: u32 = 1;
+    /// 'dIn '
+    const DEFAULT_INPUT_DEVICE: u32 = 0x6449_6E20;
+    /// 'gone'
+    const DEVICE_IS_RUNNING_SOMEWHERE: u32 = 0x676F_6E65;
+    /// 'glob'
+    const SCOPE_GLOBAL: u32 = 0x676C_6F62;
+    const ELEMENT_MAIN: u32 = 0;
+
+    fn get_u32(object_id: u32, selector: u32) -> Option<u32> {
+        let address = AudioObjectPropertyAddress {
+            selector,
+            scope: SCOPE_GLOBAL,
+            element: ELEMENT_MAIN,
+        };
+        let mut value: u32 = 0;
+        let mut size = std::mem::size_of::<u32>() as u32;
+        // SAFETY: `address` and `value` outlive the call and `size` matches
+        // the buffer; both properties are documented as UInt32-sized.
+        let status = unsafe {
+            AudioObjectGetPropertyData(
+                object_id,
+                &address,
+                0,
+                std::ptr::null(),
+                &mut size,
+                &mut value as *mut u32 as *mut c_void,
+            )
+        };
+        (status == 0).then_some(value)
+    }
+
+    pub fn default_input_running() -> Option<bool> {
+        let device = get_u32(SYSTEM_OBJECT, DEFAULT_INPUT_DEVICE).filter(|id| *id != 0)?;
+        get_u32(device, DEVICE_IS_RUNNING_SOMEWHERE).map(|running| running != 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_status(root: &Path, pcm: &str, state: &str) {
+        let dir = root.join("card0").join(pcm).join("sub0");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("status"), state).unwrap();
+    }
+
+    #[test]
+    fn test_alsa_capture_running() {
+        let root = std::env::temp_dir().join(format!("meetcat-asound-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        assert_eq!(alsa_capture_running(&root), None);
+
+        fs::create_dir_all(&root).unwrap();
+        // Playback streams don't count
+        write_status(&root, "pcm0p", "state: RUNNING\n");
+        assert_eq!(alsa_capture_running(&root), None);
+
+        write_status(&root, "pcm0c", "closed\n");
+        assert_eq!(alsa_capture_running(&root), Some(false));
+
+        write_status(&root, "pcm0c", "state: RUNNING\nowner_pid   : 4242\n");
+        assert_eq!(alsa_capture_running(&root), Some(true));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
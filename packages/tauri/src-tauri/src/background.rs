@@ -0,0 +1,613 @@
+//! Coalesced scheduling of background tasks
+//!
+//! The URL poller, meeting checks, tray refresh, update checks, log digests,
+//! ICS refreshes, history pruning and connectivity probes share a single
+//! timer. In low-power mode
+//! intervals are widened, tasks that come due close together run on the
+//! same wakeup, and non-essential work is suspended. The tray refresh ticks
+//! every second in the last two minutes before a join trigger or meeting
+//! start so its countdown title visibly counts down.
+//!
+//! Maintenance work (update checks, log digests, history pruning) follows
+//! `tauri.maintenanceWindow` when one is set: it runs inside the window, is
+//! never started during auto-join hours, and otherwise waits for the window
+//! unless it has been held back for a full day.
+
+use crate::settings::{AutoJoinHours, MaintenanceWindow};
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// First update check after launch
+const UPDATE_CHECK_STARTUP_DELAY_MS: u64 = 3_000;
+/// First log digest after launch, once startup work has settled
+const LOG_DIGEST_STARTUP_DELAY_MS: u64 = 60_000;
+/// First ICS refresh after launch, after the webview's first meeting check
+const ICS_REFRESH_STARTUP_DELAY_MS: u64 = 10_000;
+/// First history prune after launch
+const HISTORY_PRUNE_STARTUP_DELAY_MS: u64 = 120_000;
+/// First connectivity probe after launch, once the main window is loading
+const CONNECTIVITY_CHECK_STARTUP_DELAY_MS: u64 = 5_000;
+/// How often held-back maintenance work checks whether it may run
+const MAINTENANCE_RECHECK_MS: u64 = 5 * 60 * 1000;
+/// Held-back maintenance work runs outside the window after this long
+const MAINTENANCE_MAX_DEFER_MS: u64 = 24 * 60 * 60 * 1000;
+/// Within this time of the tray countdown target the tray refreshes every second
+const TRAY_COUNTDOWN_WINDOW_MS: u64 = 2 * 60 * 1000;
+const TRAY_COUNTDOWN_TICK_MS: u64 = 1_000;
+/// In low-power mode, tasks due within this time of a wakeup run with it
+const LOW_POWER_COALESCE_MS: u64 = 2_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BackgroundTask {
+    /// Watch the main window URL to re-inject scripts
+    UrlPoll,
+    /// Emit `check-meetings` to the webview
+    MeetingCheck,
+    /// Refresh the tray countdown text
+    TrayRefresh,
+    /// Poll for app updates
+    UpdateCheck,
+    /// Fold completed log days into weekly digests and prune raw logs
+    LogDigest,
+    /// Re-read subscribed ICS calendars
+    IcsRefresh,
+    /// Drop meeting history past its retention period
+    HistoryPrune,
+    /// Probe whether the network is reachable
+    ConnectivityCheck,
+}
+
+impl BackgroundTask {
+    /// Heavier work that follows the maintenance window
+    pub fn is_maintenance(self) -> bool {
+        matches!(
+            self,
+            BackgroundTask::UpdateCheck | BackgroundTask::LogDigest | BackgroundTask::HistoryPrune
+        )
+    }
+}
+
+const ALL_TASKS: [BackgroundTask; 8] = [
+    BackgroundTask::UrlPoll,
+    BackgroundTask::MeetingCheck,
+    BackgroundTask::TrayRefresh,
+    BackgroundTask::UpdateCheck,
+    BackgroundTask::LogDigest,
+    BackgroundTask::IcsRefresh,
+    BackgroundTask::HistoryPrune,
+    BackgroundTask::ConnectivityCheck,
+];
+
+/// Power mode currently in effect, after resolving `auto`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ActivePowerMode {
+    #[default]
+    Normal,
+    LowPower,
+}
+
+/// Where the local clock stands relative to the maintenance window
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MaintenanceState {
+    /// No maintenance window is set, so maintenance work runs when due
+    #[default]
+    Unrestricted,
+    InWindow,
+    /// Outside the window and outside auto-join hours
+    OffHours,
+    /// Inside auto-join hours; maintenance work never starts
+    WorkingHours,
+}
+
+impl MaintenanceState {
+    /// Resolve the state at `local`; auto-join hours stand in for working
+    /// hours, and the window wins where the two overlap
+    pub fn resolve(
+        window: Option<&MaintenanceWindow>,
+        working_hours: Option<&AutoJoinHours>,
+        local: NaiveDateTime,
+    ) -> Self {
+        let Some(window) = window else {
+            return MaintenanceState::Unrestricted;
+        };
+        if window.contains(local.time()) {
+            MaintenanceState::InWindow
+        } else if working_hours.is_some_and(|hours| hours.contains(local)) {
+            MaintenanceState::WorkingHours
+        } else {
+            MaintenanceState::OffHours
+        }
+    }
+
+    /// Whether maintenance work held back for `deferred_ms` may run now
+    fn allows(self, deferred_ms: u64) -> bool {
+        match self {
+            MaintenanceState::Unrestricted | MaintenanceState::InWindow => true,
+            MaintenanceState::OffHours => deferred_ms >= MAINTENANCE_MAX_DEFER_MS,
+            MaintenanceState::WorkingHours => false,
+        }
+    }
+}
+
+/// Tasks picked by one wakeup
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DueTasks {
+    pub run: Vec<BackgroundTask>,
+    /// Maintenance tasks that just started waiting for the window
+    pub deferred: Vec<BackgroundTask>,
+    /// How long each maintenance task in `run` was held back
+    pub waited_ms: BTreeMap<BackgroundTask, u64>,
+}
+
+/// Counters for the background timer
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackgroundMetrics {
+    pub mode: ActivePowerMode,
+    /// Times the shared timer woke up and ran at least one task
+    pub wakeups: u64,
+    pub runs: BTreeMap<BackgroundTask, u64>,
+    /// Tasks not running in the current mode
+    pub suspended: Vec<BackgroundTask>,
+    /// Current interval per running task
+    pub intervals_ms: BTreeMap<BackgroundTask, u64>,
+    pub maintenance: MaintenanceState,
+    /// Maintenance tasks waiting for the window, with when they came due
+    pub deferred_since_ms: BTreeMap<BackgroundTask, u64>,
+    pub last_run_ms: BTreeMap<BackgroundTask, u64>,
+}
+
+#[derive(Debug)]
+pub struct TaskCoordinator {
+    mode: ActivePowerMode,
+    check_interval_ms: u64,
+    next_due_ms: BTreeMap<BackgroundTask, u64>,
+    wakeups: u64,
+    runs: BTreeMap<BackgroundTask, u64>,
+    maintenance: MaintenanceState,
+    deferred_since_ms: BTreeMap<BackgroundTask, u64>,
+    last_run_ms: BTreeMap<BackgroundTask, u64>,
+    tray_countdown_ms: Option<u64>,
+}
+
+impl TaskCoordinator {
+    pub fn new(now_ms: u64, check_interval_seconds: u32) -> Self {
+        let check_interval_ms = check_interval_seconds.max(1) as u64 * 1000;
+        let mut coordinator = Self {
+            mode: ActivePowerMode::Normal,
+            check_interval_ms,
+            next_due_ms: BTreeMap::new(),
+            wakeups: 0,
+            runs: BTreeMap::new(),
+            maintenance: MaintenanceState::Unrestricted,
+            deferred_since_ms: BTreeMap::new(),
+            last_run_ms: BTreeMap::new(),
+            tray_countdown_ms: None,
+        };
+        for task in ALL_TASKS {
+            let due = match task {
+                BackgroundTask::UrlPoll | BackgroundTask::MeetingCheck => now_ms,
+                BackgroundTask::UpdateCheck => now_ms + UPDATE_CHECK_STARTUP_DELAY_MS,
+                BackgroundTask::LogDigest => now_ms + LOG_DIGEST_STARTUP_DELAY_MS,
+                BackgroundTask::IcsRefresh => now_ms + ICS_REFRESH_STARTUP_DELAY_MS,
+                BackgroundTask::HistoryPrune => now_ms + HISTORY_PRUNE_STARTUP_DELAY_MS,
+                BackgroundTask::ConnectivityCheck => now_ms + CONNECTIVITY_CHECK_STARTUP_DELAY_MS,
+                BackgroundTask::TrayRefresh => {
+                    now_ms + coordinator.interval_ms(task).unwrap_or_default()
+                }
+            };
+            coordinator.next_due_ms.insert(task, due);
+        }
+        coordinator
+    }
+
+    pub fn mode(&self) -> ActivePowerMode {
+        self.mode
+    }
+
+    /// Interval for `task` in the current mode; `None` while suspended
+    pub fn interval_ms(&self, task: BackgroundTask) -> Option<u64> {
+        let low_power = self.mode == ActivePowerMode::LowPower;
+        match task {
+            BackgroundTask::UrlPoll => Some(if low_power { 2_000 } else { 500 }),
+            BackgroundTask::MeetingCheck => Some(if low_power {
+                self.check_interval_ms * 2
+            } else {
+                self.check_interval_ms
+            }),
+            BackgroundTask::TrayRefresh => Some(if low_power { 300_000 } else { 30_000 }),
+            BackgroundTask::UpdateCheck => (!low_power).then_some(24 * 60 * 60 * 1000),
+            // Daily, since raw logs are kept for fewer days than a week
+            BackgroundTask::LogDigest | BackgroundTask::HistoryPrune => Some(24 * 60 * 60 * 1000),
+            BackgroundTask::IcsRefresh => Some(if low_power {
+                60 * 60 * 1000
+            } else {
+                15 * 60 * 1000
+            }),
+            BackgroundTask::ConnectivityCheck => Some(if low_power { 120_000 } else { 30_000 }),
+        }
+    }
+
+    /// Switch modes; returns whether the mode changed.
+    ///
+    /// Pending tasks are pulled in so a shorter interval applies right away,
+    /// while a longer one takes effect from the next run.
+    pub fn set_mode(&mut self, mode: ActivePowerMode, now_ms: u64) -> bool {
+        if self.mode == mode {
+            return false;
+        }
+        self.mode = mode;
+        self.clamp_due(now_ms);
+        true
+    }
+
+    pub fn maintenance(&self) -> MaintenanceState {
+        self.maintenance
+    }
+
+    /// Update the maintenance state; returns whether it changed.
+    ///
+    /// Held-back tasks are rechecked on their next wakeup, so opening the
+    /// window starts them within a few minutes.
+    pub fn set_maintenance(&mut self, maintenance: MaintenanceState) -> bool {
+        if self.maintenance == maintenance {
+            return false;
+        }
+        self.maintenance = maintenance;
+        true
+    }
+
+    /// Set what the tray counts down to: the next join trigger or meeting
+    /// start, whichever comes first.
+    ///
+    /// The tray refreshes every second once the target is two minutes away,
+    /// so the countdown title visibly ticks. Low-power mode keeps its
+    /// regular interval.
+    pub fn set_tray_countdown(&mut self, target_ms: Option<u64>, now_ms: u64) {
+        self.tray_countdown_ms = target_ms;
+        let next = self.next_tray_refresh_ms(now_ms);
+        if let Some(due) = self.next_due_ms.get_mut(&BackgroundTask::TrayRefresh) {
+            *due = (*due).min(next);
+        }
+    }
+
+    pub fn set_check_interval(&mut self, check_interval_seconds: u32, now_ms: u64) {
+        let check_interval_ms = check_interval_seconds.max(1) as u64 * 1000;
+        if self.check_interval_ms != check_interval_ms {
+            self.check_interval_ms = check_interval_ms;
+            self.clamp_due(now_ms);
+        }
+    }
+
+    /// Make `task` due at `now_ms`, e.g. after its settings changed
+    pub fn run_soon(&mut self, task: BackgroundTask, now_ms: u64) {
+        self.next_due_ms.insert(task, now_ms);
+    }
+
+    /// When the timer should wake up next
+    pub fn next_wakeup_ms(&self) -> Option<u64> {
+        self.next_due_ms
+            .iter()
+            .filter(|(task, _)| self.interval_ms(**task).is_some())
+            .map(|(_, due)| *due)
+            .min()
+    }
+
+    /// Tasks to run at `now_ms`, rescheduling each from now.
+    ///
+    /// Suspended tasks keep their due time, so an overdue update check runs
+    /// once when low-power mode ends. Maintenance tasks the current state
+    /// holds back are rechecked every few minutes until they may run.
+    pub fn take_due(&mut self, now_ms: u64) -> DueTasks {
+        let slack = match self.mode {
+            ActivePowerMode::Normal => 0,
+            ActivePowerMode::LowPower => LOW_POWER_COALESCE_MS,
+        };
+        let due: Vec<BackgroundTask> = ALL_TASKS
+            .into_iter()
+            .filter(|task| self.interval_ms(*task).is_some())
+            .filter(|task| {
+                self.next_due_ms
+                    .get(task)
+                    .is_some_and(|d| *d <= now_ms + slack)
+            })
+            .collect();
+        let mut tasks = DueTasks::default();
+        for task in due {
+            if task.is_maintenance() {
+                let since = self.deferred_since_ms.get(&task).copied();
+                let waited_ms = since.map_or(0, |since| now_ms.saturating_sub(since));
+                if !self.maintenance.allows(waited_ms) {
+                    if since.is_none() {
+                        self.deferred_since_ms.insert(task, now_ms);
+                        tasks.deferred.push(task);
+                    }
+                    self.next_due_ms
+                        .insert(task, now_ms + MAINTENANCE_RECHECK_MS);
+                    continue;
+                }
+                if self.deferred_since_ms.remove(&task).is_some() {
+                    tasks.waited_ms.insert(task, waited_ms);
+                }
+            }
+            let next = match task {
+                BackgroundTask::TrayRefresh => self.next_tray_refresh_ms(now_ms),
+                _ => now_ms + self.interval_ms(task).unwrap_or_default(),
+            };
+            self.next_due_ms.insert(task, next);
+            *self.runs.entry(task).or_default() += 1;
+            self.last_run_ms.insert(task, now_ms);
+            tasks.run.push(task);
+        }
+        if !tasks.run.is_empty() {
+            self.wakeups += 1;
+        }
+        tasks
+    }
+
+    pub fn metrics(&self) -> BackgroundMetrics {
+        BackgroundMetrics {
+            mode: self.mode,
+            wakeups: self.wakeups,
+            runs: self.runs.clone(),
+            suspended: ALL_TASKS
+                .into_iter()
+                .filter(|task| self.interval_ms(*task).is_none())
+                .collect(),
+            intervals_ms: ALL_TASKS
+                .into_iter()
+                .filter_map(|task| self.interval_ms(task).map(|ms| (task, ms)))
+                .collect(),
+            maintenance: self.maintenance,
+            deferred_since_ms: self.deferred_since_ms.clone(),
+            last_run_ms: self.last_run_ms.clone(),
+        }
+    }
+
+    /// Next tray refresh after `now_ms`, ticking on whole seconds before
+    /// the countdown target once it is close
+    fn next_tray_refresh_ms(&self, now_ms: u64) -> u64 {
+        let regular = now_ms
+            + self
+                .interval_ms(BackgroundTask::TrayRefresh)
+                .unwrap_or_default();
+        let Some(target) = self
+            .tray_countdown_ms
+            .filter(|target| *target > now_ms && self.mode == ActivePowerMode::Normal)
+        else {
+            return regular;
+        };
+        let window_start = target.saturating_sub(TRAY_COUNTDOWN_WINDOW_MS);
+        if now_ms < window_start {
+            return regular.min(window_start);
+        }
+        now_ms + (target - now_ms - 1) % TRAY_COUNTDOWN_TICK_MS + 1
+    }
+
+    fn clamp_due(&mut self, now_ms: u64) {
+        for task in ALL_TASKS {
+            if let (Some(interval), Some(due)) =
+                (self.interval_ms(task), self.next_due_ms.get_mut(&task))
+            {
+                *due = (*due).min(now_ms + interval);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_startup_schedule() {
+        let mut coordinator = TaskCoordinator::new(0, 30);
+        assert_eq!(
+            coordinator.take_due(0).run,
+            vec![BackgroundTask::UrlPoll, BackgroundTask::MeetingCheck]
+        );
+        assert_eq!(coordinator.next_wakeup_ms(), Some(500));
+        assert_eq!(
+            coordinator.take_due(3_000).run,
+            vec![BackgroundTask::UrlPoll, BackgroundTask::UpdateCheck]
+        );
+        assert_eq!(
+            coordinator.take_due(5_000).run,
+            vec![BackgroundTask::UrlPoll, BackgroundTask::ConnectivityCheck]
+        );
+        assert_eq!(
+            coordinator.take_due(30_000).run,
+            vec![
+                BackgroundTask::UrlPoll,
+                BackgroundTask::MeetingCheck,
+                BackgroundTask::TrayRefresh,
+                BackgroundTask::IcsRefresh
+            ]
+        );
+        assert!(coordinator
+            .take_due(60_000)
+            .run
+            .contains(&BackgroundTask::LogDigest));
+
+        coordinator.run_soon(BackgroundTask::IcsRefresh, 61_000);
+        assert_eq!(coordinator.next_wakeup_ms(), Some(60_500));
+        assert_eq!(
+            coordinator.take_due(61_000).run,
+            vec![BackgroundTask::UrlPoll, BackgroundTask::IcsRefresh]
+        );
+    }
+
+    #[test]
+    fn test_low_power_widens_coalesces_and_suspends() {
+        let mut coordinator = TaskCoordinator::new(0, 30);
+        coordinator.take_due(0);
+        assert!(coordinator.set_mode(ActivePowerMode::LowPower, 100));
+        assert!(!coordinator.set_mode(ActivePowerMode::LowPower, 100));
+
+        let metrics = coordinator.metrics();
+        assert_eq!(metrics.suspended, vec![BackgroundTask::UpdateCheck]);
+        assert_eq!(metrics.intervals_ms[&BackgroundTask::MeetingCheck], 60_000);
+
+        // The startup update check is held back while suspended, and the
+        // first connectivity probe, due at 5s, runs with the poll tick
+        assert_eq!(
+            coordinator.take_due(3_000).run,
+            vec![BackgroundTask::UrlPoll, BackgroundTask::ConnectivityCheck]
+        );
+        // A meeting check and tray refresh due at 30s run with the poll
+        // tick at 29s
+        coordinator.take_due(27_000);
+        assert_eq!(
+            coordinator.take_due(29_000).run,
+            vec![
+                BackgroundTask::UrlPoll,
+                BackgroundTask::MeetingCheck,
+                BackgroundTask::TrayRefresh
+            ]
+        );
+        assert_eq!(coordinator.next_wakeup_ms(), Some(31_000));
+
+        coordinator.set_mode(ActivePowerMode::Normal, 30_000);
+        assert!(coordinator
+            .take_due(30_500)
+            .run
+            .contains(&BackgroundTask::UpdateCheck));
+    }
+
+    #[test]
+    fn test_shorter_interval_applies_immediately() {
+        let mut coordinator = TaskCoordinator::new(0, 300);
+        coordinator.take_due(0);
+        coordinator.set_check_interval(10, 1_000);
+        assert!(coordinator
+            .take_due(11_000)
+            .run
+            .contains(&BackgroundTask::MeetingCheck));
+    }
+
+    #[test]
+    fn test_maintenance_waits_for_window() {
+        let mut coordinator = TaskCoordinator::new(0, 30);
+        coordinator.take_due(0);
+        coordinator.set_maintenance(MaintenanceState::WorkingHours);
+        let due = coordinator.take_due(3_000);
+        assert_eq!(due.run, vec![BackgroundTask::UrlPoll]);
+        assert_eq!(due.deferred, vec![BackgroundTask::UpdateCheck]);
+        assert_eq!(
+            coordinator.metrics().deferred_since_ms[&BackgroundTask::UpdateCheck],
+            3_000
+        );
+
+        // Rechecked every few minutes without logging the deferral again
+        let recheck = 3_000 + MAINTENANCE_RECHECK_MS;
+        let due = coordinator.take_due(recheck);
+        assert!(!due.run.contains(&BackgroundTask::UpdateCheck));
+        assert!(!due.deferred.contains(&BackgroundTask::UpdateCheck));
+
+        // Outside working hours the window is still awaited
+        coordinator.set_maintenance(MaintenanceState::OffHours);
+        let recheck = recheck + MAINTENANCE_RECHECK_MS;
+        assert!(!coordinator
+            .take_due(recheck)
+            .run
+            .contains(&BackgroundTask::UpdateCheck));
+
+        assert!(coordinator.set_maintenance(MaintenanceState::InWindow));
+        let recheck = recheck + MAINTENANCE_RECHECK_MS;
+        let due = coordinator.take_due(recheck);
+        assert!(due.run.contains(&BackgroundTask::UpdateCheck));
+        assert_eq!(due.waited_ms[&BackgroundTask::UpdateCheck], recheck - 3_000);
+        let metrics = coordinator.metrics();
+        assert!(!metrics
+            .deferred_since_ms
+            .contains_key(&BackgroundTask::UpdateCheck));
+        assert_eq!(metrics.last_run_ms[&BackgroundTask::UpdateCheck], recheck);
+    }
+
+    #[test]
+    fn test_maintenance_runs_off_hours_after_a_day() {
+        let mut coordinator = TaskCoordinator::new(0, 30);
+        coordinator.set_maintenance(MaintenanceState::OffHours);
+        assert!(coordinator
+            .take_due(3_000)
+            .deferred
+            .contains(&BackgroundTask::UpdateCheck));
+
+        let late = 3_000 + MAINTENANCE_MAX_DEFER_MS;
+        assert!(coordinator
+            .take_due(late)
+            .run
+            .contains(&BackgroundTask::UpdateCheck));
+
+        // Working hours hold it back no matter how long it waited
+        let mut coordinator = TaskCoordinator::new(0, 30);
+        coordinator.set_maintenance(MaintenanceState::WorkingHours);
+        coordinator.take_due(3_000);
+        assert!(!coordinator
+            .take_due(3_000 + 2 * MAINTENANCE_MAX_DEFER_MS)
+            .run
+            .contains(&BackgroundTask::UpdateCheck));
+    }
+
+    #[test]
+    fn test_resolve_maintenance_state() {
+        // 2026-10-12 is a Monday
+        let at = |time: &str| {
+            chrono::NaiveDate::from_ymd_opt(2026, 10, 12)
+                .unwrap()
+                .and_time(chrono::NaiveTime::parse_from_str(time, "%H:%M").unwrap())
+        };
+        let window = MaintenanceWindow {
+            start: "02:00".to_string(),
+            end: "04:00".to_string(),
+        };
+        let hours = AutoJoinHours {
+            start: "09:00".to_string(),
+            end: "18:00".to_string(),
+            weekdays: vec![chrono::Weekday::Mon],
+        };
+        let resolve = |time| MaintenanceState::resolve(Some(&window), Some(&hours), at(time));
+
+        assert_eq!(
+            MaintenanceState::resolve(None, Some(&hours), at("10:00")),
+            MaintenanceState::Unrestricted
+        );
+        assert_eq!(resolve("03:00"), MaintenanceState::InWindow);
+        assert_eq!(resolve("10:00"), MaintenanceState::WorkingHours);
+        assert_eq!(resolve("20:00"), MaintenanceState::OffHours);
+    }
+
+    #[test]
+    fn test_tray_ticks_every_second_near_countdown_target() {
+        let mut coordinator = TaskCoordinator::new(0, 30);
+        coordinator.take_due(0);
+        let tray_due = |c: &TaskCoordinator| c.next_due_ms[&BackgroundTask::TrayRefresh];
+        assert_eq!(tray_due(&coordinator), 30_000);
+
+        // Far away: the regular refresh is pulled in to the window start
+        coordinator.set_tray_countdown(Some(130_500), 1_000);
+        assert_eq!(tray_due(&coordinator), 10_500);
+        assert!(coordinator
+            .take_due(10_500)
+            .run
+            .contains(&BackgroundTask::TrayRefresh));
+        // Inside the window it ticks on whole seconds before the target
+        assert_eq!(tray_due(&coordinator), 11_500);
+        coordinator.take_due(11_500);
+        assert_eq!(tray_due(&coordinator), 12_500);
+
+        // Back to the regular interval once the target has passed
+        coordinator.take_due(130_500);
+        assert_eq!(tray_due(&coordinator), 160_500);
+
+        // Low-power mode doesn't tick
+        coordinator.set_mode(ActivePowerMode::LowPower, 131_000);
+        coordinator.set_tray_countdown(Some(200_000), 131_000);
+        coordinator.take_due(160_500);
+        assert_eq!(tray_due(&coordinator), 460_500);
+    }
+}
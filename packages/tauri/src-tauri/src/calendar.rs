@@ -0,0 +1,106 @@
+//! Calendar-level interpretation of meetings reported by sources
+
+use crate::daemon::Meeting;
+use chrono::{DateTime, Utc};
+
+/// Title markers that identify out-of-office events (matched case-insensitively)
+const OUT_OF_OFFICE_MARKERS: &[&str] = &[
+    "ooo",
+    "out of office",
+    "out-of-office",
+    "vacation",
+    "pto",
+    "休假",
+    "请假",
+    "休暇",
+    "不在",
+    "휴가",
+];
+
+/// Whether the event title marks an out-of-office block
+pub fn is_out_of_office(meeting: &Meeting) -> bool {
+    let title = meeting.title.to_lowercase();
+    OUT_OF_OFFICE_MARKERS.iter().any(|marker| {
+        if marker.is_ascii() {
+            contains_word(&title, marker)
+        } else {
+            title.contains(marker)
+        }
+    })
+}
+
+/// The out-of-office event covering `now` that ends last, if any
+pub fn out_of_office_covering(meetings: &[Meeting], now: DateTime<Utc>) -> Option<&Meeting> {
+    meetings
+        .iter()
+        .filter(|m| m.begin_time <= now && now < m.end_time)
+        .filter(|m| is_out_of_office(m))
+        .max_by_key(|m| m.end_time)
+}
+
+/// ASCII markers must appear as whole words so "Zoom" or "Scoop" don't match "ooo".
+fn contains_word(haystack: &str, word: &str) -> bool {
+    haystack.match_indices(word).any(|(idx, _)| {
+        let before = haystack[..idx].chars().next_back();
+        let after = haystack[idx + word.len()..].chars().next();
+        !before.is_some_and(|c| c.is_alphanumeric()) && !after.is_some_and(|c| c.is_alphanumeric())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::meeting_at;
+
+    #[test]
+    fn test_is_out_of_office_markers() {
+        let now = Utc::now();
+        for title in [
+            "OOO",
+            "Out of Office - Alice",
+            "Vacation 🌴",
+            "PTO",
+            "休假",
+            "휴가",
+        ] {
+            assert!(
+                is_out_of_office(&meeting_at("ooo", title, now, 0, 60)),
+                "{}",
+                title
+            );
+        }
+        for title in [
+            "Zoom sync",
+            "Scoop planning",
+            "Team Standup",
+            "Photo review",
+        ] {
+            assert!(
+                !is_out_of_office(&meeting_at("x", title, now, 0, 60)),
+                "{}",
+                title
+            );
+        }
+    }
+
+    #[test]
+    fn test_out_of_office_covering_now() {
+        let now = Utc::now();
+        let meetings = vec![
+            meeting_at("standup", "Standup", now, -5, 30),
+            meeting_at("ooo-short", "OOO", now, -60, 120),
+            meeting_at("ooo-day", "Out of office", now, -600, 24 * 60),
+            meeting_at("ooo-later", "Vacation", now, 60 * 24, 24 * 60),
+        ];
+
+        let covering = out_of_office_covering(&meetings, now).unwrap();
+        assert_eq!(covering.call_id, "ooo-day");
+    }
+
+    #[test]
+    fn test_out_of_office_covering_none_after_end() {
+        let now = Utc::now();
+        let meetings = vec![meeting_at("ooo", "OOO", now, -120, 60)];
+        assert!(out_of_office_covering(&meetings, now).is_none());
+    }
+}
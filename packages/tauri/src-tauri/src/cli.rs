@@ -0,0 +1,417 @@
+//! Command-line control of a running MeetCat
+//!
+//! `meetcat --join <code>`, `meetcat --pause <minutes>` and
+//! `meetcat --status [--json]` are sent to the instance that owns the same
+//! data directory and print its answer, without opening any window. The
+//! running instance listens on a loopback port published, with a random
+//! token, in `cli-endpoint.json` inside the data directory, so instances with
+//! their own `--data-dir` stay separate.
+//!
+//! When nothing is running, `--status` fails and `--join` / `--pause` start
+//! MeetCat normally and apply the command once it is up.
+//!
+//! On Windows and Linux a link opened while MeetCat runs starts a second
+//! process with the URL as an argument; that process forwards the URL the
+//! same way and exits, so the running instance handles it.
+//!
+//! Release builds on Windows have no console; the output is only visible when
+//! redirected, e.g. `meetcat --status --json > status.json`.
+
+use crate::daemon::Meeting;
+use crate::paths;
+use crate::url_norm;
+use crate::url_scheme::{DeepLinkAction, MAX_PAUSE_MINUTES};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::time::Duration;
+
+/// Published address of the running instance, inside the data directory
+const ENDPOINT_FILE: &str = "cli-endpoint.json";
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+/// Long enough for the running instance to navigate before answering
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "camelCase")]
+pub enum CliCommand {
+    /// Join a meeting; `code` is a path as in `DeepLinkAction::JoinMeeting`
+    Join { code: String },
+    /// Pause auto-join for the given number of minutes
+    Pause { minutes: u32 },
+    /// Report `AppStatus`
+    Status,
+    /// Handle a link another process was launched with, as if it had been
+    /// opened in this one
+    OpenUrl { url: String },
+}
+
+impl CliCommand {
+    /// The URL Scheme action doing the same thing, if any
+    pub fn deep_link_action(&self) -> Option<DeepLinkAction> {
+        match self {
+            CliCommand::Join { code } => Some(DeepLinkAction::JoinMeeting { code: code.clone() }),
+            CliCommand::Pause { minutes } => Some(DeepLinkAction::Pause { minutes: *minutes }),
+            CliCommand::Status | CliCommand::OpenUrl { .. } => None,
+        }
+    }
+}
+
+/// A command given on the command line, and how to print its result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliInvocation {
+    pub command: CliCommand,
+    /// Print the raw JSON result instead of a summary
+    pub json: bool,
+}
+
+/// Find the CLI command among the launch arguments.
+///
+/// Arguments that aren't CLI flags (`--data-dir`, deep links, ...) are left
+/// to their own handling. `Ok(None)` means a normal launch.
+pub fn parse_args<I>(args: I) -> Result<Option<CliInvocation>, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut command = None;
+    let mut json = false;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => {
+                (flag.to_string(), Some(value.to_string()))
+            }
+            _ => (arg, None),
+        };
+        let parsed = match flag.as_str() {
+            "--json" => {
+                json = true;
+                continue;
+            }
+            "--status" => CliCommand::Status,
+            "--join" => {
+                let value = inline_value
+                    .or_else(|| args.next())
+                    .ok_or("--join needs a meeting code or link")?;
+                let url = url_norm::meeting_input_url(&value)
+                    .map_err(|e| format!("--join {}: {}", value, e))?;
+                let code = url_norm::parse_target_path(url.path())
+                    .ok_or_else(|| format!("--join {}: not a meeting code", value))?
+                    .path();
+                CliCommand::Join { code }
+            }
+            "--pause" => {
+                let value = inline_value
+                    .or_else(|| args.next())
+                    .ok_or("--pause needs a number of minutes")?;
+                let minutes = value
+                    .trim()
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|m| (1..=MAX_PAUSE_MINUTES).contains(m))
+                    .ok_or_else(|| {
+                        format!(
+                            "--pause takes 1 to {} minutes, got {}",
+                            MAX_PAUSE_MINUTES, value
+                        )
+                    })?;
+                CliCommand::Pause { minutes }
+            }
+            _ => continue,
+        };
+        if command.replace(parsed).is_some() {
+            return Err("Use only one of --join, --pause and --status".to_string());
+        }
+    }
+    Ok(command.map(|command| CliInvocation { command, json }))
+}
+
+/// Why a command could not be sent to the running instance
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SendError {
+    NotRunning,
+    Failed(String),
+}
+
+#[derive(Serialize, Deserialize)]
+struct Endpoint {
+    port: u16,
+    token: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Request {
+    token: String,
+    #[serde(flatten)]
+    command: CliCommand,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Response {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Accept commands from other processes, answering each with `handler`.
+///
+/// Runs on its own thread for the life of the app.
+pub fn listen<F>(handler: F) -> Result<(), String>
+where
+    F: Fn(CliCommand) -> Result<Value, String> + Send + 'static,
+{
+    let path = paths::state_file(ENDPOINT_FILE).ok_or("No data directory")?;
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).map_err(|e| e.to_string())?;
+    let endpoint = Endpoint {
+        port: listener.local_addr().map_err(|e| e.to_string())?.port(),
+        token: uuid::Uuid::new_v4().to_string(),
+    };
+    write_private(
+        &path,
+        &serde_json::to_vec(&endpoint).map_err(|e| e.to_string())?,
+    )?;
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let _ = serve(stream, &endpoint.token, &handler);
+        }
+    });
+    Ok(())
+}
+
+fn serve<F>(stream: TcpStream, token: &str, handler: &F) -> std::io::Result<()>
+where
+    F: Fn(CliCommand) -> Result<Value, String>,
+{
+    stream.set_read_timeout(Some(CONNECT_TIMEOUT))?;
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line)?;
+    let response = match serde_json::from_str::<Request>(&line) {
+        Ok(request) if request.token == token => match handler(request.command) {
+            Ok(result) => Response {
+                result: Some(result),
+                error: None,
+            },
+            Err(error) => Response {
+                result: None,
+                error: Some(error),
+            },
+        },
+        // Not from a MeetCat sharing this data directory
+        _ => return Ok(()),
+    };
+    let mut stream = stream;
+    serde_json::to_writer(&mut stream, &response)?;
+    stream.write_all(b"\n")
+}
+
+/// Run `command` in the instance that owns this data directory
+pub fn send(command: &CliCommand) -> Result<Value, SendError> {
+    let path = paths::state_file(ENDPOINT_FILE).ok_or(SendError::NotRunning)?;
+    let endpoint: Endpoint = match fs::read(&path) {
+        Ok(bytes) => serde_json::from_slice(&bytes).map_err(|_| SendError::NotRunning)?,
+        Err(_) => return Err(SendError::NotRunning),
+    };
+    let address = SocketAddr::from((Ipv4Addr::LOCALHOST, endpoint.port));
+    let mut stream = TcpStream::connect_timeout(&address, CONNECT_TIMEOUT).map_err(|e| {
+        // A stale file from an instance that has quit
+        match e.kind() {
+            ErrorKind::ConnectionRefused | ErrorKind::TimedOut => SendError::NotRunning,
+            _ => SendError::Failed(e.to_string()),
+        }
+    })?;
+    let failed = |e: std::io::Error| SendError::Failed(e.to_string());
+    stream
+        .set_read_timeout(Some(RESPONSE_TIMEOUT))
+        .map_err(failed)?;
+    let request = Request {
+        token: endpoint.token,
+        command: command.clone(),
+    };
+    serde_json::to_writer(&mut stream, &request).map_err(|e| SendError::Failed(e.to_string()))?;
+    stream.write_all(b"\n").map_err(failed)?;
+
+    let mut line = String::new();
+    BufReader::new(&stream)
+        .read_line(&mut line)
+        .map_err(failed)?;
+    if line.trim().is_empty() {
+        // Another process took the port of an instance that has quit
+        return Err(SendError::NotRunning);
+    }
+    let response: Response =
+        serde_json::from_str(&line).map_err(|e| SendError::Failed(e.to_string()))?;
+    match (response.result, response.error) {
+        (_, Some(error)) => Err(SendError::Failed(error)),
+        (result, None) => Ok(result.unwrap_or(Value::Null)),
+    }
+}
+
+/// Write a file only the current user can read, as it holds the token
+fn write_private(path: &std::path::Path, contents: &[u8]) -> Result<(), String> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path).map_err(|e| e.to_string())?;
+    file.write_all(contents).map_err(|e| e.to_string())
+}
+
+/// Human-readable output for a command's result
+pub fn format_result(
+    command: &CliCommand,
+    result: &Value,
+    now: chrono::DateTime<chrono::Utc>,
+) -> String {
+    match command {
+        CliCommand::Join { code } => format!("Joining {}", code),
+        CliCommand::Pause { .. } => match result.get("pausedUntil").and_then(Value::as_str) {
+            Some(until) => format!("Auto-join paused until {}", until),
+            None => "Auto-join paused".to_string(),
+        },
+        CliCommand::Status => format_status(result, now),
+        CliCommand::OpenUrl { url } => format!("Opened {}", url),
+    }
+}
+
+/// Summary of an `AppStatus`
+fn format_status(status: &Value, now: chrono::DateTime<chrono::Utc>) -> String {
+    let enabled = status
+        .get("enabled")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let meetings = status
+        .get("meetings")
+        .and_then(Value::as_array)
+        .map_or(0, Vec::len);
+    let next = status
+        .get("next_meeting")
+        .cloned()
+        .and_then(|m| serde_json::from_value::<Meeting>(m).ok());
+
+    let mut lines = vec![
+        format!("Auto-join: {}", if enabled { "on" } else { "off" }),
+        format!("Meetings: {}", meetings),
+    ];
+    lines.push(match next {
+        Some(meeting) => {
+            let minutes = meeting.starts_in_minutes_at(now);
+            let when = if minutes > 0 {
+                format!("in {} min", minutes)
+            } else {
+                "now".to_string()
+            };
+            format!(
+                "Next meeting: {} ({}) {}",
+                meeting.title, meeting.call_id, when
+            )
+        }
+        None => "Next meeting: none".to_string(),
+    });
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn parse(args: &[&str]) -> Result<Option<CliInvocation>, String> {
+        parse_args(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse(&[]), Ok(None));
+        assert_eq!(parse(&["--data-dir", "/tmp/x", "meetcat://home"]), Ok(None));
+        assert_eq!(
+            parse(&["--status", "--json"]),
+            Ok(Some(CliInvocation {
+                command: CliCommand::Status,
+                json: true,
+            }))
+        );
+        assert_eq!(
+            parse(&["--join", "ABCDEFGHIJ"]).unwrap().unwrap().command,
+            CliCommand::Join {
+                code: "abc-defg-hij".to_string(),
+            }
+        );
+        assert_eq!(
+            parse(&["--join=https://meet.google.com/lookup/ab_cd?hs=1"])
+                .unwrap()
+                .unwrap()
+                .command,
+            CliCommand::Join {
+                code: "lookup/ab_cd".to_string(),
+            }
+        );
+        assert_eq!(
+            parse(&["--portable", "--pause", "60"]).unwrap().unwrap(),
+            CliInvocation {
+                command: CliCommand::Pause { minutes: 60 },
+                json: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_args_rejects_bad_values() {
+        assert!(parse(&["--join"]).is_err());
+        assert!(parse(&["--join", "https://example.com/abc-defg-hij"]).is_err());
+        assert!(parse(&["--pause", "0"]).is_err());
+        assert!(parse(&["--pause", "1441"]).is_err());
+        assert!(parse(&["--pause", "soon"]).is_err());
+        assert!(parse(&["--status", "--pause", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_request_round_trip() {
+        let request = Request {
+            token: "t".to_string(),
+            command: CliCommand::Pause { minutes: 5 },
+        };
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "token": "t", "command": "pause", "minutes": 5 })
+        );
+        let parsed: Request = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.command, CliCommand::Pause { minutes: 5 });
+
+        let forwarded = CliCommand::OpenUrl {
+            url: "meetcat://home".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_value(&forwarded).unwrap(),
+            serde_json::json!({ "command": "openUrl", "url": "meetcat://home" })
+        );
+    }
+
+    #[test]
+    fn test_format_status() {
+        let now = chrono::Utc::now();
+        let next = fixtures::meeting_at("abc-defg-hij", "Standup", now, 12, 30);
+        let status = serde_json::json!({
+            "enabled": true,
+            "next_meeting": next,
+            "meetings": [next],
+        });
+        assert_eq!(
+            format_result(&CliCommand::Status, &status, now),
+            "Auto-join: on\nMeetings: 1\nNext meeting: Standup (abc-defg-hij) in 12 min"
+        );
+
+        let idle = serde_json::json!({ "enabled": false, "next_meeting": null, "meetings": [] });
+        assert_eq!(
+            format_result(&CliCommand::Status, &idle, now),
+            "Auto-join: off\nMeetings: 0\nNext meeting: none"
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! Network reachability
+//!
+//! A background task requests meet.google.com itself, so a network that
+//! reaches other sites but not Meet still counts as offline. Any answer that
+//! ends on a google.com host counts as reachable; a captive portal redirects
+//! to its login page instead and counts as offline. Two failed probes in a
+//! row mark the network offline, so one dropped request doesn't hold joins,
+//! and the first answered probe marks it online again.
+//!
+//! While offline, auto-joins wait when `holdJoinsWhenOffline` is on, since
+//! the meeting page would only fail to load, and the join trigger is
+//! re-evaluated once the network is back, so a meeting still within
+//! `maxMinutesAfterStart` joins then.
+
+use crate::http_client;
+use crate::settings::Settings;
+use serde::Serialize;
+use std::time::Duration;
+use tauri::Url;
+
+/// The service joins need; its landing page redirects within google.com
+pub const PROBE_URL: &str = "https://meet.google.com/";
+/// Shorter than the request timeout, so a stalled network is noticed quickly
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Failed probes in a row before the network counts as offline
+pub const FAILURES_BEFORE_OFFLINE: u32 = 2;
+
+/// What the probes found so far
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Connectivity {
+    /// When the network went offline (ms); `None` while online
+    pub offline_since_ms: Option<u64>,
+    /// Failed probes since the last answered one
+    pub consecutive_failures: u32,
+    /// Why the last probe failed, while it is failing
+    pub last_error: Option<String>,
+}
+
+/// Change reported by [`Connectivity::apply`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectivityChange {
+    WentOffline,
+    /// Back online after `offline_ms`
+    CameOnline {
+        offline_ms: u64,
+    },
+}
+
+impl Connectivity {
+    /// Whether auto-joins should wait for the network
+    pub fn is_offline(&self) -> bool {
+        self.offline_since_ms.is_some()
+    }
+
+    /// Record a probe result; returns the change when the state flipped
+    pub fn apply(
+        &mut self,
+        result: &Result<(), String>,
+        now_ms: u64,
+    ) -> Option<ConnectivityChange> {
+        self.last_error = result.as_ref().err().cloned();
+        if result.is_ok() {
+            self.consecutive_failures = 0;
+            let since_ms = self.offline_since_ms.take()?;
+            return Some(ConnectivityChange::CameOnline {
+                offline_ms: now_ms.saturating_sub(since_ms),
+            });
+        }
+        self.consecutive_failures += 1;
+        if self.is_offline() || self.consecutive_failures < FAILURES_BEFORE_OFFLINE {
+            return None;
+        }
+        self.offline_since_ms = Some(now_ms);
+        Some(ConnectivityChange::WentOffline)
+    }
+}
+
+/// Request the probe endpoint through the configured proxy
+pub async fn probe(settings: &Settings) -> Result<(), String> {
+    let url = Url::parse(PROBE_URL).map_err(|e| e.to_string())?;
+    let client = http_client::client(settings, &url)?;
+    let response = tokio::time::timeout(PROBE_TIMEOUT, client.get(url).send())
+        .await
+        .map_err(|_| "timed out".to_string())?
+        .map_err(|e| e.to_string())?;
+    check_answer(response.url(), response.status())
+}
+
+/// Whether the probe reached Google rather than a portal or an error page
+fn check_answer(url: &Url, status: reqwest::StatusCode) -> Result<(), String> {
+    let host = url.host_str().unwrap_or("");
+    if host != "google.com" && !host.ends_with(".google.com") {
+        return Err(format!("redirected to {}", host));
+    }
+    if status.is_server_error() {
+        return Err(format!("unexpected status {}", status));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offline_after_repeated_failures() {
+        let ok = Ok(());
+        let failed = Err("timed out".to_string());
+        let mut connectivity = Connectivity::default();
+        assert_eq!(connectivity.apply(&ok, 0), None);
+
+        // A single dropped probe doesn't count
+        assert_eq!(connectivity.apply(&failed, 30_000), None);
+        assert!(!connectivity.is_offline());
+        assert_eq!(connectivity.apply(&ok, 60_000), None);
+        assert_eq!(connectivity.consecutive_failures, 0);
+
+        assert_eq!(connectivity.apply(&failed, 90_000), None);
+        assert_eq!(
+            connectivity.apply(&failed, 120_000),
+            Some(ConnectivityChange::WentOffline)
+        );
+        assert!(connectivity.is_offline());
+        assert_eq!(connectivity.last_error.as_deref(), Some("timed out"));
+        assert_eq!(connectivity.apply(&failed, 150_000), None);
+        assert_eq!(connectivity.offline_since_ms, Some(120_000));
+
+        assert_eq!(
+            connectivity.apply(&ok, 180_000),
+            Some(ConnectivityChange::CameOnline { offline_ms: 60_000 })
+        );
+        assert!(!connectivity.is_offline());
+        assert_eq!(connectivity.last_error, None);
+        assert_eq!(connectivity.apply(&ok, 210_000), None);
+    }
+
+    #[test]
+    fn test_answer_must_come_from_google() {
+        let url = |s: &str| Url::parse(s).unwrap();
+        let ok = reqwest::StatusCode::OK;
+        assert!(check_answer(&url(PROBE_URL), ok).is_ok());
+        assert!(check_answer(&url("https://workspace.google.com/products/meet/"), ok).is_ok());
+        assert!(check_answer(
+            &url("https://meet.google.com/"),
+            reqwest::StatusCode::FORBIDDEN
+        )
+        .is_ok());
+        // Captive portal login page
+        assert!(check_answer(&url("http://login.hotel-wifi.example/"), ok).is_err());
+        assert!(check_answer(&url("https://notgoogle.com/"), ok).is_err());
+        assert!(check_answer(&url(PROBE_URL), reqwest::StatusCode::BAD_GATEWAY).is_err());
+    }
+}
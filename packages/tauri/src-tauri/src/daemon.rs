@@ -1,9 +1,34 @@
 //! Background daemon for meeting scheduling
 
-use crate::settings::Settings;
-use chrono::{DateTime, Utc};
+use crate::calendar::out_of_office_covering;
+use crate::filters::{TitleFilterPreview, TitleFilters};
+use crate::lifecycle::{
+    BreakoutRoom, CallPresence, JoinVerification, LifecycleEvent, LifecycleTracker,
+    MeetingLifecycle, MeetingPhase, OverdueJoin, TransitionError,
+};
+use crate::merge::{merge_meetings, FieldOrigins, SourcedMeeting};
+use crate::persistence::VersionedState;
+use crate::settings::{
+    self, BusyBlockPolicy, ConflictPolicy, MeetingRule, MeetingSource, Settings,
+};
+use crate::url_norm::{self, UrlRejection};
+use chrono::{DateTime, Duration, Local, NaiveDate, Offset, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+/// How reliably a meeting's times were read from its card
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeAccuracy {
+    /// Start and end both came from the card
+    #[default]
+    Exact,
+    /// One of them was missing and has been estimated
+    Inferred,
+    /// Neither was there, e.g. all-day rows; the times are placeholders
+    Unknown,
+}
 
 /// Represents a Google Meet meeting
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,6 +42,57 @@ pub struct Meeting {
     pub end_time: DateTime<Utc>,
     pub event_id: Option<String>,
     pub starts_in_minutes: i64,
+    /// Profile whose window reported the meeting; `None` for the main window
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// Only meetings with exact times are joined automatically
+    #[serde(default)]
+    pub accuracy: TimeAccuracy,
+}
+
+impl Meeting {
+    /// Whole minutes until the meeting starts, floored like the webview parser.
+    ///
+    /// Always derived from `begin_time` so the value stays correct across
+    /// daylight-saving transitions, unlike the cached `starts_in_minutes`.
+    pub fn starts_in_minutes_at(&self, now: DateTime<Utc>) -> i64 {
+        (self.begin_time.timestamp_millis() - now.timestamp_millis()).div_euclid(60_000)
+    }
+}
+
+/// A non-Meet calendar event that marks time as busy, e.g. Focus time or Lunch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct BusyBlock {
+    pub title: String,
+    pub begin_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+}
+
+/// A busy block that holds back a join
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct BusyHold {
+    pub block_title: String,
+    pub block_end: DateTime<Utc>,
+    pub policy: BusyBlockPolicy,
+}
+
+/// Why the join engine treats a meeting the way it does
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct JoinExplanation {
+    pub call_id: String,
+    /// Why the meeting is not joined at all, if it isn't
+    pub skipped: Option<ConflictDecision>,
+    /// Trigger time from `join_before_minutes` alone
+    pub scheduled_at_ms: i64,
+    /// Trigger time after snoozes and busy blocks; `None` when the meeting
+    /// won't trigger
+    pub trigger_at_ms: Option<i64>,
+    pub snoozed_until_ms: Option<i64>,
+    pub busy_hold: Option<BusyHold>,
+    pub awaiting_confirmation: bool,
 }
 
 /// Result of calculating the next join trigger
@@ -26,15 +102,347 @@ pub struct NextJoinTrigger {
     pub meeting: Meeting,
     /// Milliseconds until we should trigger the join
     pub delay_ms: u64,
+    /// Join countdown length for this meeting, after per-meeting rules
+    pub countdown_seconds: u32,
+    /// Per-meeting auto-click override; `None` follows `auto_click_join`
+    pub auto_click_join: Option<bool>,
+    /// Milliseconds until the green-room phase opens the pre-join page, when
+    /// that phase is enabled and comes before the join
+    pub green_room_delay_ms: Option<u64>,
+    /// Busy block the join waited for, or that needs the user to confirm
+    pub busy_hold: Option<BusyHold>,
+    /// Wall-clock time the join is due, to re-check when the timer wakes
+    pub fire_at_ms: i64,
+    /// Overlapping meetings the user picks from instead of joining, under the
+    /// "promptUser" conflict policy; empty otherwise
+    pub conflict: Vec<Meeting>,
+}
+
+/// Why the next join fires when it does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriggerReason {
+    /// `join_before_minutes` ahead of the start
+    Scheduled,
+    /// The trigger time passed but the join window is still open, so it fires
+    /// right away
+    JoinWindow,
+    /// Postponed to the end of a snooze
+    Snoozed,
+    /// Postponed to the end of a busy block
+    BusyBlock,
+}
+
+/// The join the daemon triggers next
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ScheduledTrigger {
+    pub call_id: String,
+    pub trigger_at_ms: i64,
+    pub delay_ms: u64,
+    pub reason: TriggerReason,
+    pub countdown_seconds: u32,
+    /// A "confirm" busy block asks before this join
+    pub busy_hold: Option<BusyHold>,
+    /// Overlapping meetings the user picks from when it fires
+    pub conflict: Vec<String>,
+}
+
+/// What the daemon intends to do, so the frontend doesn't re-derive it
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct TriggerSchedule {
+    /// `None` when no meeting will be joined
+    pub next: Option<ScheduledTrigger>,
+    /// Auto-join is paused by the user or an out-of-office event until then
+    pub paused_until_ms: Option<i64>,
+    /// Auto-join stopped after hitting the hourly cap
+    pub rate_capped: bool,
+    /// Meetings closed by the user after their trigger time
+    pub suppressed: Vec<String>,
+    /// Meetings whose pending join the user cancelled
+    pub cancelled: Vec<String>,
+    /// Active snoozes: when each snoozed meeting may trigger again
+    pub snoozed_until_ms: BTreeMap<String, i64>,
+}
+
+/// What a woken join timer should do, judged by the wall clock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerRecheck {
+    Fire,
+    /// The wall clock hasn't reached the due time, e.g. after it jumped back
+    Early {
+        remaining_ms: i64,
+    },
+    /// The join window closed, e.g. after the clock jumped past the meeting
+    Expired,
+}
+
+/// What the join engine will do with one meeting of a conflict group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictDecision {
+    /// Earliest trigger in the group; this is the meeting MeetCat joins
+    Planned,
+    /// Eligible, but triggers after the planned meeting
+    Queued,
+    /// Skipped by the title exclude filters
+    Excluded,
+    /// Already joined
+    Joined,
+    /// Closed by the user after its trigger time
+    Suppressed,
+    /// Waits for the user to pick among overlapping meetings
+    AwaitingChoice,
+    /// The user picked another of the overlapping meetings
+    NotChosen,
+    /// Past `max_minutes_after_start`; no longer joined automatically
+    Missed,
+    /// The start time was estimated by the parser, so it isn't joined
+    /// automatically
+    InexactTime,
+    /// Google Meet refused the join for the signed-in account
+    Blocked,
+}
+
+/// Why Google Meet refused to let the signed-in account join a call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JoinBlockedReason {
+    /// "You can't join this video call" without a more specific cause
+    NotAllowed,
+    /// The meeting only admits accounts from the host's organization
+    DomainRestricted,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Why a meeting was suppressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuppressionReason {
+    /// The user closed the meeting page after its trigger time
+    Closed,
+    /// The user cancelled the pending auto-join
+    Cancelled,
+    /// The join was skipped because the user was away
+    Away,
+    /// A join deep link opened the meeting instead
+    DeepLink,
+}
+
+/// A suppressed meeting instance.
+///
+/// Meet reuses the code of a recurring series, so a suppression covers only
+/// the instance it was recorded for and expires when that instance ends.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Suppression {
+    pub call_id: String,
+    pub begin_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    pub suppressed_at_ms: i64,
+    pub reason: SuppressionReason,
+}
+
+/// A meeting within a conflict group
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ConflictMember {
+    pub meeting: Meeting,
+    pub decision: ConflictDecision,
+    /// Time this meeting shares with other members of its group
+    pub overlap_ms: i64,
+}
+
+/// Meetings whose time ranges overlap, directly or through a chain
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ConflictGroup {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// Total time during which at least two members run at once
+    pub overlap_ms: i64,
+    /// Members ordered by start time
+    pub members: Vec<ConflictMember>,
+}
+
+/// Section of the agenda a meeting is listed under, in display order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgendaGroup {
+    /// Ended today
+    Past,
+    InProgress,
+    /// The first meeting that hasn't started
+    Next,
+    LaterToday,
+    Tomorrow,
+}
+
+/// A meeting in the agenda
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AgendaItem {
+    pub meeting: Meeting,
+    pub group: AgendaGroup,
+    /// What the join engine does with the meeting; `Planned` marks the
+    /// meeting it triggers next
+    pub decision: ConflictDecision,
+}
+
+/// Today's and tomorrow's meetings, grouped and sorted for display
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AgendaView {
+    pub generated_at: DateTime<Utc>,
+    /// Position of the "now" marker in `items`: the number of meetings that
+    /// have started
+    pub now_index: usize,
+    /// Items ordered by group, then by start time
+    pub items: Vec<AgendaItem>,
+}
+
+/// The latest meeting list reported by one source
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceSnapshotInfo {
+    pub source: MeetingSource,
+    pub meeting_count: usize,
+    pub observed_at_ms: i64,
 }
 
 /// Daemon state
 #[derive(Debug, Default)]
 pub struct DaemonState {
     running: bool,
-    meetings: Vec<Meeting>,
-    joined_meetings: HashSet<String>,
-    suppressed_meetings: HashMap<String, i64>,
+    /// Shared with status snapshots, so reading the list doesn't copy it
+    meetings: Arc<[Meeting]>,
+    /// Lifecycle phase of every tracked meeting
+    lifecycle: LifecycleTracker,
+    /// Latest meeting list reported by each source, with its observation time
+    source_snapshots: HashMap<MeetingSource, (i64, Vec<Meeting>)>,
+    /// Which source won each field of the merged meetings
+    meeting_origins: Arc<HashMap<String, FieldOrigins>>,
+    /// Latest meeting list reported by each profile window
+    profile_meetings: BTreeMap<String, Vec<Meeting>>,
+    /// Auto-join is paused until this instant because of an out-of-office event
+    out_of_office_until: Option<DateTime<Utc>>,
+    /// Timestamps of recent auto-join actions, for the hourly cap
+    auto_join_history_ms: Vec<i64>,
+    /// Auto-join stays stopped until the user confirms after hitting the cap
+    join_rate_capped: bool,
+    /// Auto-join is paused by the user until this instant
+    paused_until: Option<DateTime<Utc>>,
+    /// Meetings whose pre-join page was already opened by the green-room phase
+    green_room_opened: HashSet<String>,
+    /// Meetings Google Meet refused to join; never retried automatically
+    blocked_meetings: HashMap<String, JoinBlockedReason>,
+    /// Auto-join of these meetings is postponed until the given instant (ms)
+    snoozed_until: HashMap<String, i64>,
+    /// Suppressed meeting instances, until each instance ends
+    suppressions: HashMap<String, Suppression>,
+    /// Meetings whose pending auto-join the user cancelled
+    cancelled_joins: HashSet<String>,
+    /// Busy blocks reported by each calendar source
+    busy_blocks: HashMap<MeetingSource, Vec<BusyBlock>>,
+    /// Joins held by a "confirm" busy block: `false` while the user hasn't
+    /// answered, `true` once they confirmed
+    busy_confirmations: HashMap<String, bool>,
+    /// Overlapping meetings held for the user's pick: `None` while they
+    /// haven't answered, then the meeting they chose
+    conflict_choices: HashMap<String, Option<String>>,
+    /// Meetings a source reported for the first time since the last
+    /// [`DaemonState::take_last_minute_meetings`]
+    newly_seen: Vec<String>,
+    /// Meetings already announced as last-minute with when they end, so a
+    /// source reporting them again doesn't announce them twice
+    announced_last_minute: HashMap<String, DateTime<Utc>>,
+    /// Sample meetings listed instead of the sources' while demo mode is on
+    demo_meetings: Option<Vec<Meeting>>,
+    /// When the main window last reported its meeting list (ms)
+    last_updated_ms: Option<i64>,
+    /// When the page was last reloaded because that list went stale (ms)
+    stale_recovery_at_ms: Option<i64>,
+    /// Persisted state changed since the last snapshot was taken
+    dirty: bool,
+}
+
+/// Daemon state saved across restarts.
+///
+/// Only what keeps a restart from re-joining or re-opening a meeting is kept;
+/// pauses, caps and source snapshots start fresh.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PersistedDaemonState {
+    pub saved_at_ms: i64,
+    pub meetings: Vec<Meeting>,
+    pub lifecycles: HashMap<String, MeetingLifecycle>,
+    pub green_room_opened: Vec<String>,
+    pub blocked_meetings: HashMap<String, JoinBlockedReason>,
+    #[serde(default)]
+    pub cancelled_joins: Vec<String>,
+    #[serde(default)]
+    pub suppressions: HashMap<String, Suppression>,
+}
+
+impl VersionedState for PersistedDaemonState {
+    const CURRENT_VERSION: u32 = 1;
+
+    fn migrate(from_version: u32, _data: serde_json::Value) -> Result<serde_json::Value, String> {
+        Err(format!(
+            "no daemon state format before version {}",
+            from_version + 1
+        ))
+    }
+}
+
+/// Saved daemon state older than this is ignored on restore
+pub const PERSISTED_STATE_MAX_AGE_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// Rolling window used by the auto-join cap
+pub const AUTO_JOIN_WINDOW_MS: i64 = 60 * 60 * 1000;
+
+/// How long a suppression lasts when its meeting isn't in the list
+const UNLISTED_SUPPRESSION_MS: i64 = 60 * 60 * 1000;
+
+/// Timers may wake slightly before the wall clock reaches the due time
+const TRIGGER_EARLY_TOLERANCE_MS: i64 = 1000;
+
+/// Normalize meeting URLs, splitting off meetings whose URL fails validation
+fn normalize_meeting_urls(meetings: Vec<Meeting>) -> (Vec<Meeting>, Vec<RejectedMeetingUrl>) {
+    let mut rejected = Vec::new();
+    let meetings = meetings
+        .into_iter()
+        .filter_map(|mut meeting| match url_norm::normalize(&meeting.url) {
+            Ok(url) => {
+                meeting.url = url.to_string();
+                Some(meeting)
+            }
+            Err(reason) => {
+                rejected.push(RejectedMeetingUrl {
+                    call_id: meeting.call_id,
+                    reason,
+                });
+                None
+            }
+        })
+        .collect();
+    (meetings, rejected)
+}
+
+/// A reported meeting dropped because its URL failed validation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectedMeetingUrl {
+    pub call_id: String,
+    pub reason: UrlRejection,
+}
+
+/// Change in the out-of-office pause after a refresh
+#[derive(Debug, Clone, PartialEq)]
+pub enum OutOfOfficeChange {
+    Paused { until: DateTime<Utc> },
+    Resumed,
 }
 
 impl DaemonState {
@@ -57,132 +465,651 @@ impl DaemonState {
 
     /// Update meetings list
     pub fn update_meetings(&mut self, meetings: Vec<Meeting>) {
-        self.meetings = meetings;
+        let now_ms = Utc::now().timestamp_millis();
+        for meeting in &meetings {
+            self.lifecycle.track(&meeting.call_id, now_ms);
+        }
+        if !same_schedule(&self.meetings, &meetings) {
+            self.dirty = true;
+        }
+        self.meetings = meetings.into();
         self.prune_state();
     }
 
-    /// Get all meetings
-    pub fn get_meetings(&self) -> Vec<Meeting> {
-        self.meetings.clone()
+    /// Rebuild state saved by a previous run.
+    ///
+    /// Nothing is restored when the snapshot is older than
+    /// [`PERSISTED_STATE_MAX_AGE_MS`]; otherwise ended meetings, meetings
+    /// whose URL no longer validates and records for meetings no longer in
+    /// the list are dropped. Restored phases don't produce lifecycle events.
+    pub fn restore(persisted: PersistedDaemonState, now: DateTime<Utc>) -> Self {
+        let mut state = Self::default();
+        let age_ms = now.timestamp_millis() - persisted.saved_at_ms;
+        if !(0..=PERSISTED_STATE_MAX_AGE_MS).contains(&age_ms) {
+            return state;
+        }
+
+        state.meetings = persisted
+            .meetings
+            .into_iter()
+            .filter(|m| m.end_time > now && url_norm::normalize(&m.url).is_ok())
+            .collect();
+        let active_ids: HashSet<&str> = state.meetings.iter().map(|m| m.call_id.as_str()).collect();
+        state.lifecycle.restore(
+            persisted
+                .lifecycles
+                .into_iter()
+                .filter(|(id, _)| active_ids.contains(id.as_str()))
+                .collect(),
+        );
+        state.green_room_opened = persisted
+            .green_room_opened
+            .into_iter()
+            .filter(|id| active_ids.contains(id.as_str()))
+            .collect();
+        state.blocked_meetings = persisted
+            .blocked_meetings
+            .into_iter()
+            .filter(|(id, _)| active_ids.contains(id.as_str()))
+            .collect();
+        state.cancelled_joins = persisted
+            .cancelled_joins
+            .into_iter()
+            .filter(|id| active_ids.contains(id.as_str()))
+            .collect();
+        state.suppressions = persisted
+            .suppressions
+            .into_iter()
+            .filter(|(id, s)| active_ids.contains(id.as_str()) && s.end_time > now)
+            .collect();
+        // Saved before suppressions recorded their instance
+        let unrecorded: Vec<(String, i64)> = state
+            .lifecycle
+            .all()
+            .iter()
+            .filter(|(id, _)| !state.suppressions.contains_key(id.as_str()))
+            .filter_map(|(id, record)| {
+                let suppressed = record
+                    .transitions
+                    .iter()
+                    .find(|t| t.to == MeetingPhase::Suppressed)?;
+                Some((id.clone(), suppressed.at_ms))
+            })
+            .collect();
+        for (call_id, at_ms) in unrecorded {
+            let reason = if state.cancelled_joins.contains(&call_id) {
+                SuppressionReason::Cancelled
+            } else {
+                SuppressionReason::Closed
+            };
+            let suppression = state.suppression_for(&call_id, reason, at_ms);
+            state.suppressions.insert(call_id, suppression);
+        }
+        state
+    }
+
+    /// Snapshot the persisted state if it changed since the last snapshot
+    pub fn take_snapshot_if_dirty(&mut self, now_ms: i64) -> Option<PersistedDaemonState> {
+        // Demo meetings must not outlive demo mode
+        if self.demo_meetings.is_some() {
+            return None;
+        }
+        if !self.dirty && !self.lifecycle.has_pending_events() {
+            return None;
+        }
+        self.dirty = false;
+        Some(PersistedDaemonState {
+            saved_at_ms: now_ms,
+            meetings: self.meetings.to_vec(),
+            lifecycles: self.lifecycle.all().clone(),
+            green_room_opened: self.green_room_opened.iter().cloned().collect(),
+            blocked_meetings: self.blocked_meetings.clone(),
+            cancelled_joins: self.cancelled_joins.iter().cloned().collect(),
+            suppressions: self.suppressions.clone(),
+        })
     }
 
-    /// Get the next meeting to join
-    pub fn get_next_meeting(&self, settings: &Settings) -> Option<Meeting> {
-        let now = Utc::now();
-        let join_before_ms = (settings.join_before_minutes as i64) * 60 * 1000;
-        let now_ms = now.timestamp_millis();
+    /// Replace the meetings reported by one source and re-merge all sources.
+    ///
+    /// Meeting URLs are normalized first; meetings whose URL fails validation
+    /// are dropped and returned so the caller can report them.
+    pub fn update_meetings_from_source(
+        &mut self,
+        source: MeetingSource,
+        meetings: Vec<Meeting>,
+        observed_at_ms: i64,
+        priority: &[MeetingSource],
+    ) -> Vec<RejectedMeetingUrl> {
+        let (meetings, rejected) = normalize_meeting_urls(meetings);
+        if source == MeetingSource::Webview {
+            self.last_updated_ms = Some(observed_at_ms);
+            self.stale_recovery_at_ms = None;
+        }
+        let first_report = self
+            .source_snapshots
+            .insert(source, (observed_at_ms, meetings))
+            .is_none();
+        self.rebuild_meetings_noting_new(priority, first_report);
+        rejected
+    }
 
-        self.meetings
+    /// Replace the meetings reported by a profile window.
+    ///
+    /// The meetings are tagged with the profile so joins open in its window.
+    /// A meeting also known to the main window or an earlier profile keeps
+    /// that owner.
+    pub fn update_profile_meetings(
+        &mut self,
+        profile: &str,
+        meetings: Vec<Meeting>,
+        priority: &[MeetingSource],
+    ) -> Vec<RejectedMeetingUrl> {
+        let (mut meetings, rejected) = normalize_meeting_urls(meetings);
+        for meeting in &mut meetings {
+            meeting.profile = Some(profile.to_string());
+        }
+        let first_report = self
+            .profile_meetings
+            .insert(profile.to_string(), meetings)
+            .is_none();
+        self.rebuild_meetings_noting_new(priority, first_report);
+        rejected
+    }
+
+    /// Forget meetings of profiles that are no longer configured
+    pub fn retain_profiles(&mut self, profiles: &[String], priority: &[MeetingSource]) {
+        let before = self.profile_meetings.len();
+        self.profile_meetings.retain(|id, _| profiles.contains(id));
+        if self.profile_meetings.len() != before {
+            self.rebuild_meetings(priority);
+        }
+    }
+
+    /// Re-merge after a source reported, noting the meetings it added.
+    ///
+    /// A source's first report after launch only establishes what it
+    /// already knew about, so none of its meetings count as new.
+    fn rebuild_meetings_noting_new(&mut self, priority: &[MeetingSource], first_report: bool) {
+        let known: HashSet<String> = self.meetings.iter().map(|m| m.call_id.clone()).collect();
+        self.rebuild_meetings(priority);
+        if first_report {
+            return;
+        }
+        for meeting in self.meetings.iter() {
+            if !known.contains(&meeting.call_id) && !self.newly_seen.contains(&meeting.call_id) {
+                self.newly_seen.push(meeting.call_id.clone());
+            }
+        }
+    }
+
+    /// Meetings first seen shortly before they start, each returned once.
+    ///
+    /// A newly reported meeting is last-minute when it starts within
+    /// `tauri.lastMinuteMeetingMinutes`, or started recently enough to still
+    /// be auto-joined. Meetings seen since the previous call that don't
+    /// qualify are forgotten.
+    pub fn take_last_minute_meetings(
+        &mut self,
+        settings: &Settings,
+        now: DateTime<Utc>,
+    ) -> Vec<Meeting> {
+        let newly_seen = std::mem::take(&mut self.newly_seen);
+        let window_minutes = settings.tauri_or_default().last_minute_meeting_minutes;
+        if window_minutes == 0 {
+            return Vec::new();
+        }
+        let latest_start = now + Duration::minutes(i64::from(window_minutes));
+        let earliest_start = now - Duration::minutes(i64::from(settings.max_minutes_after_start));
+        let mut meetings = Vec::new();
+        for call_id in newly_seen {
+            let Some(meeting) = self.meetings.iter().find(|m| m.call_id == call_id) else {
+                continue;
+            };
+            if meeting.begin_time <= earliest_start
+                || meeting.begin_time > latest_start
+                || meeting.end_time <= now
+            {
+                continue;
+            }
+            if self
+                .announced_last_minute
+                .insert(call_id, meeting.end_time)
+                .is_none()
+            {
+                meetings.push(meeting.clone());
+            }
+        }
+        meetings
+    }
+
+    /// Whether `call_id` was announced as a last-minute meeting
+    pub fn was_announced_last_minute(&self, call_id: &str) -> bool {
+        self.announced_last_minute.contains_key(call_id)
+    }
+
+    /// List `meetings` instead of what the sources report, or go back to the
+    /// sources with `None`.
+    ///
+    /// Sources keep their snapshots meanwhile, so leaving demo mode restores
+    /// the last real schedule right away. Auto-join is blocked while demo
+    /// meetings are listed.
+    pub fn set_demo_meetings(
+        &mut self,
+        meetings: Option<Vec<Meeting>>,
+        priority: &[MeetingSource],
+    ) {
+        self.demo_meetings = meetings;
+        self.rebuild_meetings(priority);
+    }
+
+    /// Whether demo meetings are listed instead of the real ones
+    pub fn in_demo_mode(&self) -> bool {
+        self.demo_meetings.is_some()
+    }
+
+    /// How long ago the main window last reported its meeting list; `None`
+    /// until it first does
+    pub fn data_age_ms(&self, now_ms: i64) -> Option<i64> {
+        self.last_updated_ms
+            .map(|updated_ms| now_ms.saturating_sub(updated_ms).max(0))
+    }
+
+    /// Whether the main window's meeting list is older than `threshold_ms`.
+    ///
+    /// A threshold of 0 disables the check. Nothing is stale before the first
+    /// report or while demo meetings are listed.
+    pub fn is_data_stale(&self, now_ms: i64, threshold_ms: i64) -> bool {
+        threshold_ms > 0
+            && self.demo_meetings.is_none()
+            && self
+                .data_age_ms(now_ms)
+                .is_some_and(|age_ms| age_ms >= threshold_ms)
+    }
+
+    /// Whether to reload the page to recover a stale meeting list. True at
+    /// most once per `threshold_ms` while the list stays stale.
+    pub fn take_stale_recovery(&mut self, now_ms: i64, threshold_ms: i64) -> bool {
+        if !self.is_data_stale(now_ms, threshold_ms) {
+            return false;
+        }
+        if self
+            .stale_recovery_at_ms
+            .is_some_and(|at_ms| now_ms - at_ms < threshold_ms)
+        {
+            return false;
+        }
+        self.stale_recovery_at_ms = Some(now_ms);
+        true
+    }
+
+    /// Merge all sources, then add profile meetings not already listed
+    fn rebuild_meetings(&mut self, priority: &[MeetingSource]) {
+        if let Some(demo_meetings) = &self.demo_meetings {
+            let meetings = demo_meetings.clone();
+            self.meeting_origins = Arc::default();
+            self.update_meetings(meetings);
+            return;
+        }
+        let inputs: Vec<SourcedMeeting> = self
+            .source_snapshots
             .iter()
-            .filter(|m| m.end_time > now)
-            .filter(|m| {
-                let start_time_ms = m.begin_time.timestamp_millis();
-                let trigger_at_ms = start_time_ms - join_before_ms;
+            .flat_map(|(source, (observed_at_ms, meetings))| {
+                meetings.iter().map(move |meeting| SourcedMeeting {
+                    meeting: meeting.clone(),
+                    source: source.clone(),
+                    observed_at_ms: *observed_at_ms,
+                })
+            })
+            .collect();
 
-                if self.suppressed_meetings.contains_key(&m.call_id) && now_ms >= trigger_at_ms {
-                    return false;
+        let merged = merge_meetings(&inputs, priority);
+        self.meeting_origins = Arc::new(
+            merged
+                .iter()
+                .map(|m| (m.meeting.call_id.clone(), m.origins.clone()))
+                .collect(),
+        );
+        let mut meetings: Vec<Meeting> = merged.into_iter().map(|m| m.meeting).collect();
+        if !self.profile_meetings.is_empty() {
+            for meeting in self.profile_meetings.values().flatten() {
+                if !meetings.iter().any(|m| m.call_id == meeting.call_id) {
+                    meetings.push(meeting.clone());
                 }
+            }
+            meetings.sort_by(|a, b| {
+                a.begin_time
+                    .cmp(&b.begin_time)
+                    .then_with(|| a.call_id.cmp(&b.call_id))
+            });
+        }
+        self.update_meetings(meetings);
+    }
 
-                if self.joined_meetings.contains(&m.call_id) && m.begin_time <= now {
-                    return false;
-                }
+    /// Field provenance of every merged meeting, by call id
+    pub fn meeting_origins(&self) -> &Arc<HashMap<String, FieldOrigins>> {
+        &self.meeting_origins
+    }
 
-                true
+    /// What each source last reported, ordered by source
+    pub fn source_snapshots(&self) -> Vec<SourceSnapshotInfo> {
+        let mut snapshots: Vec<SourceSnapshotInfo> = self
+            .source_snapshots
+            .iter()
+            .map(|(source, (observed_at_ms, meetings))| SourceSnapshotInfo {
+                source: source.clone(),
+                meeting_count: meetings.len(),
+                observed_at_ms: *observed_at_ms,
             })
-            .filter(|m| m.begin_time > now - chrono::Duration::minutes(5))
-            .min_by_key(|m| m.begin_time)
-            .cloned()
+            .collect();
+        snapshots.sort_by(|a, b| a.source.cmp(&b.source));
+        snapshots
     }
 
-    /// Mark a meeting as joined
-    pub fn mark_joined(&mut self, call_id: &str) {
-        self.joined_meetings.insert(call_id.to_string());
-    }
+    /// Re-evaluate the out-of-office pause against the current meetings.
+    ///
+    /// A pause stays in effect until the covering event ends, even if a later
+    /// meeting list no longer contains the event.
+    pub fn refresh_out_of_office(
+        &mut self,
+        enabled: bool,
+        now: DateTime<Utc>,
+    ) -> Option<OutOfOfficeChange> {
+        let previous = self.out_of_office_until;
+        let next = if enabled {
+            out_of_office_covering(&self.meetings, now)
+                .map(|m| m.end_time)
+                .max(self.out_of_office_until(now))
+        } else {
+            None
+        };
+        self.out_of_office_until = next;
 
-    /// Mark a meeting as suppressed
-    pub fn mark_suppressed(&mut self, call_id: &str, closed_at_ms: i64) {
-        self.suppressed_meetings
-            .insert(call_id.to_string(), closed_at_ms);
+        match (previous, next) {
+            (_, Some(until)) if previous != next => Some(OutOfOfficeChange::Paused { until }),
+            (Some(_), None) => Some(OutOfOfficeChange::Resumed),
+            _ => None,
+        }
     }
 
-    /// Clear joined history
-    pub fn clear_joined(&mut self) {
-        self.joined_meetings.clear();
+    /// End of the current out-of-office pause, if one is active at `now`
+    pub fn out_of_office_until(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.out_of_office_until.filter(|until| *until > now)
     }
 
-    /// Get joined meeting call IDs
-    pub fn get_joined_meetings(&self) -> Vec<String> {
-        self.joined_meetings.iter().cloned().collect()
+    /// Record an auto-join attempt at `now_ms`.
+    ///
+    /// Returns `false` without recording when `max_per_hour` joins already
+    /// happened in the last hour; the daemon then stays capped until
+    /// `reset_join_rate_cap` is called. A cap of 0 disables the check.
+    pub fn try_record_auto_join(&mut self, now_ms: i64, max_per_hour: u32) -> bool {
+        if self.join_rate_capped {
+            return false;
+        }
+        self.auto_join_history_ms
+            .retain(|ts| now_ms - ts < AUTO_JOIN_WINDOW_MS);
+        if max_per_hour > 0 && self.auto_join_history_ms.len() >= max_per_hour as usize {
+            self.join_rate_capped = true;
+            return false;
+        }
+        self.auto_join_history_ms.push(now_ms);
+        true
     }
 
-    /// Get suppressed meeting call IDs
-    pub fn get_suppressed_meetings(&self) -> Vec<String> {
-        self.suppressed_meetings.keys().cloned().collect()
+    /// Whether auto-join is stopped by the hourly cap
+    pub fn is_join_rate_capped(&self) -> bool {
+        self.join_rate_capped
     }
 
-    fn prune_state(&mut self) {
-        let now = Utc::now();
-        let active_ids: HashSet<String> = self
-            .meetings
+    /// Number of auto-joins recorded in the hour before `now_ms`
+    pub fn auto_joins_in_window(&self, now_ms: i64) -> usize {
+        self.auto_join_history_ms
             .iter()
-            .filter(|m| m.end_time > now)
-            .map(|m| m.call_id.clone())
+            .filter(|ts| now_ms - **ts < AUTO_JOIN_WINDOW_MS)
+            .count()
+    }
+
+    /// Manually confirm and resume auto-join after the cap was hit
+    pub fn reset_join_rate_cap(&mut self) {
+        self.join_rate_capped = false;
+        self.auto_join_history_ms.clear();
+    }
+
+    /// Pause auto-join until `until`
+    pub fn pause_until(&mut self, until: DateTime<Utc>) {
+        self.paused_until = Some(until);
+    }
+
+    /// Lift a manual pause; returns whether one was active at `now`
+    pub fn resume(&mut self, now: DateTime<Utc>) -> bool {
+        self.paused_until.take().is_some_and(|until| until > now)
+    }
+
+    /// End of the manual pause, if one is active at `now`
+    pub fn paused_until(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.paused_until.filter(|until| *until > now)
+    }
+
+    /// Postpone auto-joining `call_id` until `until_ms`, replacing any earlier snooze
+    pub fn snooze(&mut self, call_id: &str, until_ms: i64) {
+        self.snoozed_until.insert(call_id.to_string(), until_ms);
+    }
+
+    /// End of the snooze on `call_id`, if one is active at `now_ms`
+    pub fn snoozed_until(&self, call_id: &str, now_ms: i64) -> Option<i64> {
+        self.snoozed_until
+            .get(call_id)
+            .copied()
+            .filter(|until_ms| *until_ms > now_ms)
+    }
+
+    /// Replace the busy blocks reported by one calendar source
+    pub fn update_busy_blocks(&mut self, source: MeetingSource, blocks: Vec<BusyBlock>) {
+        let blocks = blocks
+            .into_iter()
+            .filter(|b| b.end_time > b.begin_time)
             .collect();
+        self.busy_blocks.insert(source, blocks);
+    }
 
-        self.joined_meetings.retain(|id| active_ids.contains(id));
-        self.suppressed_meetings
-            .retain(|id, _| active_ids.contains(id));
+    /// Hold the join of `call_id` until the user confirms it
+    pub fn request_busy_confirmation(&mut self, call_id: &str) {
+        self.busy_confirmations
+            .entry(call_id.to_string())
+            .or_insert(false);
     }
 
-    /// Check if any meeting should be joined now based on settings
-    pub fn should_join_now(&self, settings: &Settings) -> Option<Meeting> {
-        let join_threshold = settings.join_before_minutes as i64;
-        let max_after_start = settings.max_minutes_after_start as i64;
-        let now = Utc::now();
-        let join_before_ms = join_threshold * 60 * 1000;
-        let now_ms = now.timestamp_millis();
+    /// Let a join held by a "confirm" busy block go ahead
+    pub fn confirm_busy_join(&mut self, call_id: &str) {
+        self.busy_confirmations.insert(call_id.to_string(), true);
+    }
 
-        self.meetings
-            .iter()
-            .filter(|m| m.end_time > now)
-            .filter(|m| {
-                let start_time_ms = m.begin_time.timestamp_millis();
-                let trigger_at_ms = start_time_ms - join_before_ms;
+    /// Whether `call_id` waits for the user to confirm joining during a busy block
+    pub fn is_awaiting_busy_confirmation(&self, call_id: &str) -> bool {
+        self.busy_confirmations.get(call_id) == Some(&false)
+    }
 
-                if self.suppressed_meetings.contains_key(&m.call_id) && now_ms >= trigger_at_ms {
-                    return false;
-                }
+    /// Hold the joins of overlapping meetings until the user picks one
+    pub fn request_conflict_choice(&mut self, call_ids: &[String]) {
+        for call_id in call_ids {
+            self.conflict_choices.entry(call_id.clone()).or_insert(None);
+        }
+    }
 
-                if self.joined_meetings.contains(&m.call_id) && m.begin_time <= now {
-                    return false;
-                }
+    /// Record the user's pick among the meetings held with `chosen`; the others
+    /// are no longer joined automatically. Returns whether `chosen` was held.
+    pub fn choose_conflict_winner(&mut self, chosen: &str) -> bool {
+        if self.conflict_choices.get(chosen) != Some(&None) {
+            return false;
+        }
+        for choice in self.conflict_choices.values_mut() {
+            if choice.is_none() {
+                *choice = Some(chosen.to_string());
+            }
+        }
+        true
+    }
 
-                true
-            })
-            .filter(|m| {
-                // Filter by title exclude list
-                !settings
-                    .title_exclude_filters
-                    .iter()
-                    .any(|f| m.title.contains(f))
-            })
-            .filter(|m| {
-                // Within join window: from join_threshold before start to max_after_start after
-                // Use <= so joinBeforeMinutes=1 triggers at 1:xx (when starts_in_minutes = 1)
-                m.starts_in_minutes <= join_threshold && m.starts_in_minutes >= -max_after_start
-            })
-            .min_by_key(|m| m.starts_in_minutes.abs())
-            .cloned()
+    /// Whether `call_id` waits for the user to pick among overlapping meetings
+    pub fn is_awaiting_conflict_choice(&self, call_id: &str) -> bool {
+        self.conflict_choices.get(call_id) == Some(&None)
     }
 
-    /// Calculate the next precise join trigger time
+    /// Whether the user picked another meeting over `call_id`
+    fn lost_conflict(&self, call_id: &str) -> bool {
+        matches!(
+            self.conflict_choices.get(call_id),
+            Some(Some(chosen)) if chosen != call_id
+        )
+    }
+
+    /// Busy block covering a join of `meeting` triggered at `trigger_ms`.
     ///
-    /// This returns the meeting and the delay in milliseconds until we should trigger.
-    /// Unlike `should_join_now` which checks if it's time RIGHT NOW, this calculates
-    /// when we SHOULD trigger in the future.
-    pub fn calculate_next_trigger(&self, settings: &Settings) -> Option<NextJoinTrigger> {
-        let join_before_ms = (settings.join_before_minutes as i64) * 60 * 1000;
+    /// "Delay" blocks push the trigger to their end, following back-to-back
+    /// blocks; a "confirm" block met on the way wins.
+    fn busy_hold(
+        &self,
+        meeting: &Meeting,
+        trigger_ms: i64,
+        settings: &Settings,
+        title_filters: &TitleFilters,
+    ) -> Option<BusyHold> {
+        let rules = settings
+            .tauri
+            .as_ref()
+            .map(|t| t.busy_block_rules.as_slice())?;
+        if rules.is_empty() || self.busy_confirmations.get(&meeting.call_id) == Some(&true) {
+            return None;
+        }
+
+        let mut hold: Option<BusyHold> = None;
+        let mut at_ms = trigger_ms;
+        // Bounded so overlapping blocks can't loop forever
+        for _ in 0..16 {
+            let covering = self
+                .busy_blocks
+                .values()
+                .flatten()
+                .filter(|b| {
+                    b.begin_time.timestamp_millis() <= at_ms
+                        && at_ms < b.end_time.timestamp_millis()
+                })
+                .filter_map(|b| {
+                    let rule = rules.get(title_filters.busy_block_rule(&b.title)?)?;
+                    Some(BusyHold {
+                        block_title: b.title.clone(),
+                        block_end: b.end_time,
+                        policy: rule.policy,
+                    })
+                })
+                .max_by_key(|h| (h.policy == BusyBlockPolicy::Confirm, h.block_end));
+            match covering {
+                Some(found) if found.policy == BusyBlockPolicy::Confirm => return Some(found),
+                Some(found) => {
+                    at_ms = found.block_end.timestamp_millis();
+                    hold = Some(found);
+                }
+                None => break,
+            }
+        }
+        hold
+    }
+
+    /// Trigger time of `meeting` after snoozes and busy blocks, with the busy
+    /// block involved; `None` when those push it past the join window
+    fn adjusted_trigger_ms(
+        &self,
+        meeting: &Meeting,
+        settings: &Settings,
+        title_filters: &TitleFilters,
+        now_ms: i64,
+    ) -> Option<(i64, Option<BusyHold>)> {
+        let start_time_ms = meeting.begin_time.timestamp_millis();
         let max_after_start_ms = (settings.max_minutes_after_start as i64) * 60 * 1000;
+
+        // Calculate when we should trigger (joinBeforeMinutes before start),
+        // postponed to the end of an active snooze
+        let scheduled_ms = start_time_ms - join_before_ms(meeting, settings, title_filters);
+        let trigger_time_ms = match self.snoozed_until(&meeting.call_id, now_ms) {
+            // Snoozed past the join window, so it never triggers
+            Some(until_ms) if until_ms - start_time_ms >= max_after_start_ms => {
+                return None;
+            }
+            Some(until_ms) => scheduled_ms.max(until_ms),
+            None => scheduled_ms,
+        };
+
+        let busy_hold = self.busy_hold(
+            meeting,
+            trigger_time_ms.max(now_ms),
+            settings,
+            title_filters,
+        );
+        match &busy_hold {
+            Some(hold) if hold.policy == BusyBlockPolicy::Delay => {
+                let until_ms = hold.block_end.timestamp_millis();
+                // Busy until past the join window, so it never triggers
+                if until_ms - start_time_ms >= max_after_start_ms {
+                    return None;
+                }
+                Some((trigger_time_ms.max(until_ms), busy_hold))
+            }
+            _ => Some((trigger_time_ms, busy_hold)),
+        }
+    }
+
+    /// Explain how the join engine treats `call_id` at `now`
+    pub fn explain_join_at(
+        &self,
+        call_id: &str,
+        settings: &Settings,
+        title_filters: &TitleFilters,
+        now: DateTime<Utc>,
+    ) -> Option<JoinExplanation> {
+        let meeting = self.meetings.iter().find(|m| m.call_id == call_id)?;
+        let now_ms = now.timestamp_millis();
+        let skipped = self.conflict_skip_reason(meeting, settings, title_filters, now);
+        let scheduled_at_ms = meeting.begin_time.timestamp_millis()
+            - join_before_ms(meeting, settings, title_filters);
+        let trigger_at_ms = self
+            .adjusted_trigger_ms(meeting, settings, title_filters, now_ms)
+            .filter(|_| skipped.is_none())
+            .map(|(trigger_ms, _)| trigger_ms);
+        Some(JoinExplanation {
+            call_id: call_id.to_string(),
+            skipped,
+            scheduled_at_ms,
+            trigger_at_ms,
+            snoozed_until_ms: self.snoozed_until(call_id, now_ms),
+            busy_hold: self.busy_hold(
+                meeting,
+                scheduled_at_ms.max(now_ms),
+                settings,
+                title_filters,
+            ),
+            awaiting_confirmation: self.is_awaiting_busy_confirmation(call_id),
+        })
+    }
+
+    /// Whether a manual pause, an out-of-office pause, the rate cap or demo
+    /// mode blocks auto-join
+    fn auto_join_blocked(&self, now: DateTime<Utc>) -> bool {
+        self.in_demo_mode()
+            || self.join_rate_capped
+            || self.paused_until(now).is_some()
+            || self.out_of_office_until(now).is_some()
+    }
+
+    /// Get all meetings; the snapshot shares the list instead of copying it
+    pub fn get_meetings(&self) -> Arc<[Meeting]> {
+        Arc::clone(&self.meetings)
+    }
+
+    /// Get the next meeting to join
+    pub fn get_next_meeting(
+        &self,
+        settings: &Settings,
+        title_filters: &TitleFilters,
+    ) -> Option<Meeting> {
         let now = Utc::now();
         let now_ms = now.timestamp_millis();
 
@@ -191,31 +1118,688 @@ impl DaemonState {
             .filter(|m| m.end_time > now)
             .filter(|m| {
                 let start_time_ms = m.begin_time.timestamp_millis();
-                let trigger_at_ms = start_time_ms - join_before_ms;
+                let trigger_at_ms = start_time_ms - join_before_ms(m, settings, title_filters);
 
-                if self.suppressed_meetings.contains_key(&m.call_id) && now_ms >= trigger_at_ms {
+                if self.was_suppressed(m) && now_ms >= trigger_at_ms {
                     return false;
                 }
 
-                if self.joined_meetings.contains(&m.call_id) && m.begin_time <= now {
+                if self.cancelled_joins.contains(&m.call_id) {
                     return false;
                 }
 
-                true
+                if self.was_joined(&m.call_id) && m.begin_time <= now {
+                    return false;
+                }
+
+                !self.blocked_meetings.contains_key(&m.call_id)
             })
-            .filter(|m| {
-                // Filter by title exclude list
-                !settings
-                    .title_exclude_filters
-                    .iter()
-                    .any(|f| m.title.contains(f))
+            .filter(|m| m.begin_time > now - chrono::Duration::minutes(5))
+            .min_by_key(|m| m.begin_time)
+            .cloned()
+    }
+
+    /// Meetings that have not ended and started at most 5 minutes before `now`,
+    /// ordered by start time
+    pub fn upcoming_meetings_at(&self, now: DateTime<Utc>) -> Vec<&Meeting> {
+        let mut upcoming: Vec<&Meeting> = self
+            .meetings
+            .iter()
+            .filter(|m| m.end_time > now)
+            .filter(|m| m.begin_time > now - Duration::minutes(5))
+            .collect();
+        upcoming.sort_by_key(|m| m.begin_time);
+        upcoming
+    }
+
+    /// Overlapping meetings among those that haven't ended and start within
+    /// `range` of `now`, with the decision the join engine makes for each.
+    ///
+    /// Decisions ignore manual and out-of-office pauses, which hold back every
+    /// meeting alike.
+    pub fn find_conflicts_at(
+        &self,
+        settings: &Settings,
+        title_filters: &TitleFilters,
+        now: DateTime<Utc>,
+        range: Duration,
+    ) -> Vec<ConflictGroup> {
+        let mut meetings: Vec<&Meeting> = self
+            .meetings
+            .iter()
+            .filter(|m| m.end_time > now && m.begin_time < now + range)
+            .filter(|m| m.end_time > m.begin_time)
+            .filter(|m| m.accuracy != TimeAccuracy::Unknown)
+            .collect();
+        meetings.sort_by_key(|m| (m.begin_time, m.end_time));
+
+        let mut clusters: Vec<Vec<&Meeting>> = Vec::new();
+        let mut cluster_end = None;
+        for meeting in meetings {
+            match (clusters.last_mut(), cluster_end) {
+                (Some(cluster), Some(end)) if meeting.begin_time < end => {
+                    cluster.push(meeting);
+                    cluster_end = Some(end.max(meeting.end_time));
+                }
+                _ => {
+                    clusters.push(vec![meeting]);
+                    cluster_end = Some(meeting.end_time);
+                }
+            }
+        }
+
+        clusters
+            .into_iter()
+            .filter(|cluster| cluster.len() > 1)
+            .map(|cluster| self.conflict_group(&cluster, settings, title_filters, now))
+            .collect()
+    }
+
+    fn conflict_group(
+        &self,
+        cluster: &[&Meeting],
+        settings: &Settings,
+        title_filters: &TitleFilters,
+        now: DateTime<Utc>,
+    ) -> ConflictGroup {
+        let mut decisions: Vec<ConflictDecision> = cluster
+            .iter()
+            .map(|m| {
+                self.conflict_skip_reason(m, settings, title_filters, now)
+                    .unwrap_or(ConflictDecision::Queued)
             })
+            .collect();
+        // Same pick as calculate_next_trigger_at, assuming every meeting in
+        // the group shares the same join_before offset
+        let queued: Vec<usize> = (0..cluster.len())
+            .filter(|&i| decisions[i] == ConflictDecision::Queued)
+            .collect();
+        let policy = settings
+            .tauri
+            .as_ref()
+            .map(|t| t.conflict_policy)
+            .unwrap_or_default();
+        let planned = match policy {
+            ConflictPolicy::FirstScheduled => queued.first().copied(),
+            ConflictPolicy::Shortest => queued
+                .iter()
+                .copied()
+                .min_by_key(|&i| cluster[i].end_time - cluster[i].begin_time),
+            // Nothing is planned until the user picks
+            ConflictPolicy::PromptUser => (queued.len() == 1).then(|| queued[0]),
+        };
+        if let Some(planned) = planned {
+            decisions[planned] = ConflictDecision::Planned;
+        }
+
+        let members = cluster
+            .iter()
+            .zip(decisions)
+            .map(|(m, decision)| ConflictMember {
+                meeting: (*m).clone(),
+                decision,
+                overlap_ms: shared_ms(m, cluster),
+            })
+            .collect();
+
+        ConflictGroup {
+            start: cluster[0].begin_time,
+            end: cluster
+                .iter()
+                .map(|m| m.end_time)
+                .max()
+                .unwrap_or(cluster[0].end_time),
+            overlap_ms: multi_booked_ms(cluster),
+            members,
+        }
+    }
+
+    /// Why the join engine would not trigger `meeting`, mirroring the filters
+    /// of `calculate_next_trigger_at`
+    fn conflict_skip_reason(
+        &self,
+        meeting: &Meeting,
+        settings: &Settings,
+        title_filters: &TitleFilters,
+        now: DateTime<Utc>,
+    ) -> Option<ConflictDecision> {
+        let now_ms = now.timestamp_millis();
+        let start_ms = meeting.begin_time.timestamp_millis();
+        let trigger_at_ms = start_ms - join_before_ms(meeting, settings, title_filters);
+        let max_after_start_ms = (settings.max_minutes_after_start as i64) * 60 * 1000;
+
+        if self.blocked_meetings.contains_key(&meeting.call_id) {
+            Some(ConflictDecision::Blocked)
+        } else if (self.was_suppressed(meeting) && now_ms >= trigger_at_ms)
+            || self.cancelled_joins.contains(&meeting.call_id)
+        {
+            Some(ConflictDecision::Suppressed)
+        } else if self.was_joined(&meeting.call_id) && meeting.begin_time <= now {
+            Some(ConflictDecision::Joined)
+        } else if self.is_awaiting_conflict_choice(&meeting.call_id) {
+            Some(ConflictDecision::AwaitingChoice)
+        } else if self.lost_conflict(&meeting.call_id) {
+            Some(ConflictDecision::NotChosen)
+        } else if meeting.accuracy != TimeAccuracy::Exact {
+            Some(ConflictDecision::InexactTime)
+        } else if is_excluded_by_title(meeting, settings, title_filters) {
+            Some(ConflictDecision::Excluded)
+        } else if trigger_at_ms <= now_ms && now_ms - start_ms >= max_after_start_ms {
+            Some(ConflictDecision::Missed)
+        } else {
+            None
+        }
+    }
+
+    /// Meetings that end today or start tomorrow in `tz`, grouped around
+    /// `now` with the decision the join engine makes for each.
+    ///
+    /// Unlike `find_conflicts_at`, pauses count: while auto-join is paused
+    /// no meeting is `Planned`.
+    pub fn agenda_view_at<Tz: TimeZone>(
+        &self,
+        settings: &Settings,
+        title_filters: &TitleFilters,
+        now: DateTime<Utc>,
+        tz: &Tz,
+    ) -> AgendaView {
+        let today = now.with_timezone(tz).date_naive();
+        let tomorrow = today.succ_opt();
+        let planned = self
+            .calculate_next_trigger_at(settings, title_filters, now)
+            .map(|trigger| trigger.meeting.call_id);
+
+        let mut meetings: Vec<&Meeting> = self.meetings.iter().collect();
+        meetings.sort_by_key(|m| (m.begin_time, m.end_time));
+
+        let mut has_next = false;
+        let mut items: Vec<AgendaItem> = meetings
+            .into_iter()
             .filter_map(|m| {
-                let start_time_ms = m.begin_time.timestamp_millis();
-                let now_ms = now.timestamp_millis();
+                let starts_on = m.begin_time.with_timezone(tz).date_naive();
+                let group = if m.end_time <= now {
+                    (m.end_time.with_timezone(tz).date_naive() == today)
+                        .then_some(AgendaGroup::Past)?
+                } else if m.begin_time <= now {
+                    AgendaGroup::InProgress
+                } else if starts_on != today && Some(starts_on) != tomorrow {
+                    return None;
+                } else if !has_next {
+                    has_next = true;
+                    AgendaGroup::Next
+                } else if starts_on == today {
+                    AgendaGroup::LaterToday
+                } else {
+                    AgendaGroup::Tomorrow
+                };
+                let decision = match self.conflict_skip_reason(m, settings, title_filters, now) {
+                    Some(reason) => reason,
+                    None if planned.as_deref() == Some(m.call_id.as_str()) => {
+                        ConflictDecision::Planned
+                    }
+                    None => ConflictDecision::Queued,
+                };
+                Some(AgendaItem {
+                    meeting: m.clone(),
+                    group,
+                    decision,
+                })
+            })
+            .collect();
+        items.sort_by_key(|item| item.group);
+
+        AgendaView {
+            generated_at: now,
+            now_index: items
+                .iter()
+                .take_while(|item| item.group <= AgendaGroup::InProgress)
+                .count(),
+            items,
+        }
+    }
+
+    /// Move a meeting to `phase`, validating the transition
+    pub fn transition(
+        &mut self,
+        call_id: &str,
+        phase: MeetingPhase,
+        at_ms: i64,
+    ) -> Result<(), TransitionError> {
+        self.lifecycle.transition(call_id, phase, at_ms)
+    }
+
+    /// Mark a meeting as joined
+    pub fn mark_joined(&mut self, call_id: &str, at_ms: i64) -> Result<(), TransitionError> {
+        self.transition(call_id, MeetingPhase::Joined, at_ms)
+    }
+
+    /// Record that the meeting page was closed.
+    ///
+    /// Closing at or after the trigger time suppresses the meeting; closing
+    /// earlier only marks an open meeting as left. A page in a breakout room
+    /// closes when Meet moves it between rooms, so the meeting stays joined.
+    pub fn mark_closed(
+        &mut self,
+        call_id: &str,
+        closed_at_ms: i64,
+        trigger_at_ms: i64,
+    ) -> Result<(), TransitionError> {
+        if self.lifecycle.in_breakout(call_id) {
+            return Ok(());
+        }
+        if closed_at_ms >= trigger_at_ms {
+            return self.mark_suppressed(call_id, SuppressionReason::Closed, closed_at_ms);
+        }
+        match self.lifecycle.phase(call_id) {
+            Some(MeetingPhase::Triggered | MeetingPhase::Navigating | MeetingPhase::Joined) => {
+                self.transition(call_id, MeetingPhase::Left, closed_at_ms)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Record what the page reported about a joined call, returning the
+    /// verification when it changed
+    pub fn record_call_presence(
+        &mut self,
+        call_id: &str,
+        presence: CallPresence,
+        at_ms: i64,
+    ) -> Option<JoinVerification> {
+        let changed = self.lifecycle.record_presence(call_id, presence, at_ms);
+        if changed.is_some() {
+            self.dirty = true;
+        }
+        changed
+    }
+
+    /// Joined meetings whose page missed the verification deadline, each
+    /// returned once
+    pub fn take_overdue_verifications(&mut self, now_ms: i64) -> Vec<OverdueJoin> {
+        let overdue = self.lifecycle.take_overdue_verifications(now_ms);
+        if !overdue.is_empty() {
+            self.dirty = true;
+        }
+        overdue
+    }
+
+    /// Reload overdue joined meeting `call_id`, counting the retry
+    pub fn retry_join(&mut self, call_id: &str, at_ms: i64) -> Result<(), TransitionError> {
+        self.dirty = true;
+        self.lifecycle.retry_join(call_id, at_ms)
+    }
+
+    /// Record that the page of joined meeting `call_id` moved to a breakout
+    /// room; returns false when nothing changed
+    pub fn enter_breakout(&mut self, call_id: &str, name: Option<String>, at_ms: i64) -> bool {
+        let changed = self.lifecycle.enter_breakout(call_id, name, at_ms);
+        if changed {
+            self.dirty = true;
+        }
+        changed
+    }
+
+    /// Record that the page of `call_id` returned to the main call
+    pub fn exit_breakout(&mut self, call_id: &str) -> Option<BreakoutRoom> {
+        let room = self.lifecycle.exit_breakout(call_id);
+        if room.is_some() {
+            self.dirty = true;
+        }
+        room
+    }
+
+    /// Whether the page of `call_id` is in a breakout room
+    pub fn in_breakout(&self, call_id: &str) -> bool {
+        self.lifecycle.in_breakout(call_id)
+    }
+
+    /// Meetings whose page is in a breakout room
+    pub fn breakout_calls(&self) -> Vec<String> {
+        self.lifecycle
+            .all()
+            .iter()
+            .filter(|(_, record)| record.breakout.is_some())
+            .map(|(call_id, _)| call_id.clone())
+            .collect()
+    }
+
+    /// Current lifecycle phase of a meeting
+    pub fn meeting_phase(&self, call_id: &str) -> Option<MeetingPhase> {
+        self.lifecycle.phase(call_id)
+    }
+
+    /// Latest page report of each joined meeting; `None` for meetings whose
+    /// page hasn't reported since joining
+    pub fn joined_presences(&self) -> Vec<Option<CallPresence>> {
+        self.lifecycle
+            .all()
+            .values()
+            .filter(|record| record.phase == MeetingPhase::Joined)
+            .map(|record| record.presence)
+            .collect()
+    }
+
+    /// Call IDs of the meetings currently joined
+    pub fn joined_call_ids(&self) -> Vec<String> {
+        self.lifecycle
+            .all()
+            .iter()
+            .filter(|(_, record)| record.phase == MeetingPhase::Joined)
+            .map(|(call_id, _)| call_id.clone())
+            .collect()
+    }
+
+    /// Lifecycle of every tracked meeting
+    pub fn get_lifecycles(&self) -> HashMap<String, MeetingLifecycle> {
+        self.lifecycle.all().clone()
+    }
+
+    /// Take the lifecycle changes recorded since the last call
+    pub fn drain_lifecycle_events(&mut self) -> Vec<LifecycleEvent> {
+        let events = self.lifecycle.drain_events();
+        if !events.is_empty() {
+            self.dirty = true;
+        }
+        events
+    }
+
+    /// Triggered or joined at some point; such meetings aren't auto-joined
+    /// again once they've started
+    fn was_joined(&self, call_id: &str) -> bool {
+        self.lifecycle
+            .has_entered(call_id, &[MeetingPhase::Triggered, MeetingPhase::Joined])
+    }
+
+    /// Whether `meeting` is the instance, or an earlier one, of a suppressed
+    /// call; later instances of a recurring series are joined as usual
+    fn was_suppressed(&self, meeting: &Meeting) -> bool {
+        self.suppressions
+            .get(&meeting.call_id)
+            .is_some_and(|s| meeting.begin_time < s.end_time)
+    }
+
+    /// Mark a meeting's green-room phase as done
+    pub fn mark_green_room_opened(&mut self, call_id: &str) {
+        if self.green_room_opened.insert(call_id.to_string()) {
+            self.dirty = true;
+        }
+    }
+
+    /// Whether the green-room phase already opened this meeting
+    pub fn is_green_room_opened(&self, call_id: &str) -> bool {
+        self.green_room_opened.contains(call_id)
+    }
+
+    /// Cancel the pending auto-join of `call_id`: suppress the meeting and
+    /// keep it from being scheduled again, even before its trigger time
+    pub fn cancel_join(&mut self, call_id: &str, at_ms: i64) -> Result<(), TransitionError> {
+        self.mark_suppressed(call_id, SuppressionReason::Cancelled, at_ms)?;
+        if self.cancelled_joins.insert(call_id.to_string()) {
+            self.dirty = true;
+        }
+        Ok(())
+    }
+
+    /// Whether the user cancelled the auto-join of `call_id`
+    pub fn is_join_cancelled(&self, call_id: &str) -> bool {
+        self.cancelled_joins.contains(call_id)
+    }
+
+    /// Suppress the current instance of a meeting until it ends. A meeting
+    /// already suppressed keeps its first reason.
+    pub fn mark_suppressed(
+        &mut self,
+        call_id: &str,
+        reason: SuppressionReason,
+        at_ms: i64,
+    ) -> Result<(), TransitionError> {
+        self.transition(call_id, MeetingPhase::Suppressed, at_ms)?;
+        if !self.suppressions.contains_key(call_id) {
+            let suppression = self.suppression_for(call_id, reason, at_ms);
+            self.suppressions.insert(call_id.to_string(), suppression);
+            self.dirty = true;
+        }
+        Ok(())
+    }
+
+    /// Suppression of the listed instance of `call_id` that hasn't ended at
+    /// `at_ms`, or of the next hour when none is listed
+    fn suppression_for(&self, call_id: &str, reason: SuppressionReason, at_ms: i64) -> Suppression {
+        let at = DateTime::from_timestamp_millis(at_ms).unwrap_or_else(Utc::now);
+        let (begin_time, end_time) = self
+            .meetings
+            .iter()
+            .filter(|m| m.call_id == call_id && m.end_time > at)
+            .min_by_key(|m| m.begin_time)
+            .map_or(
+                (at, at + Duration::milliseconds(UNLISTED_SUPPRESSION_MS)),
+                |m| (m.begin_time, m.end_time),
+            );
+        Suppression {
+            call_id: call_id.to_string(),
+            begin_time,
+            end_time,
+            suppressed_at_ms: at_ms,
+            reason,
+        }
+    }
+
+    /// Lift the suppression of `call_id` so auto-join may pick it again,
+    /// cancelled joins included; returns false when it wasn't suppressed
+    pub fn clear_suppressed(&mut self, call_id: &str, at_ms: i64) -> bool {
+        if self.suppressions.remove(call_id).is_none() {
+            return false;
+        }
+        self.cancelled_joins.remove(call_id);
+        self.dirty = true;
+        // Suppressed can't move back to Triggered, so start the record over
+        if self.lifecycle.phase(call_id) == Some(MeetingPhase::Suppressed) {
+            self.lifecycle.expire_unless(|id| id != call_id, at_ms);
+            self.lifecycle.track(call_id, at_ms);
+        }
+        true
+    }
+
+    /// Record that Google Meet refused the join; returns true the first time
+    pub fn mark_join_blocked(
+        &mut self,
+        call_id: &str,
+        reason: JoinBlockedReason,
+        at_ms: i64,
+    ) -> bool {
+        let first_time = self
+            .blocked_meetings
+            .insert(call_id.to_string(), reason)
+            .is_none();
+        self.dirty = true;
+        // Every live phase may move to Failed, so this is never rejected
+        let _ = self.transition(call_id, MeetingPhase::Failed, at_ms);
+        first_time
+    }
+
+    /// Get call IDs Google Meet refused to join
+    pub fn get_blocked_meetings(&self) -> Vec<String> {
+        self.blocked_meetings.keys().cloned().collect()
+    }
+
+    /// Why joining `call_id` was refused, if it was
+    pub fn join_blocked_reason(&self, call_id: &str) -> Option<JoinBlockedReason> {
+        self.blocked_meetings.get(call_id).copied()
+    }
 
-                // Calculate when we should trigger (joinBeforeMinutes before start)
-                let trigger_time_ms = start_time_ms - join_before_ms;
+    /// Get joined meeting call IDs
+    pub fn get_joined_meetings(&self) -> Vec<String> {
+        self.lifecycle
+            .ids_entered(&[MeetingPhase::Triggered, MeetingPhase::Joined])
+    }
+
+    /// Suppressed meetings, oldest suppression first
+    pub fn get_suppressed_meetings(&self) -> Vec<Suppression> {
+        let mut suppressions: Vec<Suppression> = self.suppressions.values().cloned().collect();
+        suppressions.sort_by(|a, b| {
+            (a.suppressed_at_ms, &a.call_id).cmp(&(b.suppressed_at_ms, &b.call_id))
+        });
+        suppressions
+    }
+
+    fn prune_state(&mut self) {
+        let now = Utc::now();
+        let active_ids: HashSet<String> = self
+            .meetings
+            .iter()
+            .filter(|m| m.end_time > now)
+            .map(|m| m.call_id.clone())
+            .collect();
+
+        // A suppression ends with its instance; the next instance of a
+        // recurring series starts over with a fresh lifecycle
+        let ended: HashSet<String> = self
+            .suppressions
+            .values()
+            .filter(|s| s.end_time <= now)
+            .map(|s| s.call_id.clone())
+            .collect();
+        if !ended.is_empty() {
+            self.dirty = true;
+        }
+        self.suppressions
+            .retain(|id, _| active_ids.contains(id) && !ended.contains(id));
+        self.lifecycle.expire_unless(
+            |id| active_ids.contains(id) && !ended.contains(id),
+            now.timestamp_millis(),
+        );
+        for call_id in ended.iter().filter(|id| active_ids.contains(*id)) {
+            self.lifecycle.track(call_id, now.timestamp_millis());
+        }
+        self.green_room_opened.retain(|id| active_ids.contains(id));
+        self.blocked_meetings
+            .retain(|id, _| active_ids.contains(id));
+        self.snoozed_until.retain(|id, _| active_ids.contains(id));
+        self.cancelled_joins
+            .retain(|id| active_ids.contains(id) && !ended.contains(id));
+        self.busy_confirmations
+            .retain(|id, _| active_ids.contains(id));
+        self.conflict_choices
+            .retain(|id, _| active_ids.contains(id));
+        self.announced_last_minute
+            .retain(|_, end_time| *end_time > now);
+        for blocks in self.busy_blocks.values_mut() {
+            blocks.retain(|b| b.end_time > now);
+        }
+    }
+
+    /// Whether the join engine may fire `meeting` at `at`, the checks shared
+    /// by `should_join_now_at` and `calculate_next_trigger_at`
+    fn is_eligible(
+        &self,
+        meeting: &Meeting,
+        at: DateTime<Utc>,
+        settings: &Settings,
+        title_filters: &TitleFilters,
+    ) -> bool {
+        let at_ms = at.timestamp_millis();
+        let trigger_at_ms = meeting.begin_time.timestamp_millis()
+            - join_before_ms(meeting, settings, title_filters);
+        let delayed = self
+            .busy_hold(meeting, at_ms, settings, title_filters)
+            .is_some_and(|hold| hold.policy == BusyBlockPolicy::Delay);
+
+        if meeting.end_time <= at
+            || (self.was_suppressed(meeting) && at_ms >= trigger_at_ms)
+            || (self.was_joined(&meeting.call_id) && meeting.begin_time <= at)
+        {
+            return false;
+        }
+
+        !self.cancelled_joins.contains(&meeting.call_id)
+            && !self.blocked_meetings.contains_key(&meeting.call_id)
+            && meeting.accuracy == TimeAccuracy::Exact
+            && !is_excluded_by_title(meeting, settings, title_filters)
+            && !outside_auto_join_hours(meeting, settings)
+            && self.snoozed_until(&meeting.call_id, at_ms).is_none()
+            && !delayed
+            && !self.is_awaiting_busy_confirmation(&meeting.call_id)
+            && !self.is_awaiting_conflict_choice(&meeting.call_id)
+            && !self.lost_conflict(&meeting.call_id)
+            && !in_dnd_window(settings, at)
+    }
+
+    /// Check if any meeting should be joined now based on settings
+    pub fn should_join_now(
+        &self,
+        settings: &Settings,
+        title_filters: &TitleFilters,
+    ) -> Option<Meeting> {
+        self.should_join_now_at(settings, title_filters, Utc::now())
+    }
+
+    /// Same as `should_join_now`, evaluated at an explicit instant
+    pub fn should_join_now_at(
+        &self,
+        settings: &Settings,
+        title_filters: &TitleFilters,
+        now: DateTime<Utc>,
+    ) -> Option<Meeting> {
+        if self.auto_join_blocked(now) {
+            return None;
+        }
+        let max_after_start = settings.max_minutes_after_start as i64;
+        let now_ms = now.timestamp_millis();
+
+        self.meetings
+            .iter()
+            .filter(|m| self.is_eligible(m, now, settings, title_filters))
+            // A confirm block waits for the answer only the scheduled
+            // trigger asks for
+            .filter(|m| self.busy_hold(m, now_ms, settings, title_filters).is_none())
+            .filter(|m| {
+                // Within join window: from join_threshold before start to max_after_start after
+                // Use <= so joinBeforeMinutes=1 triggers at 1:xx (when starts_in_minutes = 1)
+                let join_threshold = join_before_minutes(m, settings, title_filters) as i64;
+                let starts_in_minutes = m.starts_in_minutes_at(now);
+                starts_in_minutes <= join_threshold && starts_in_minutes >= -max_after_start
+            })
+            .min_by_key(|m| m.starts_in_minutes_at(now).abs())
+            .cloned()
+    }
+
+    /// Calculate the next precise join trigger time
+    ///
+    /// This returns the meeting and the delay in milliseconds until we should trigger.
+    /// Unlike `should_join_now` which checks if it's time RIGHT NOW, this calculates
+    /// when we SHOULD trigger in the future.
+    pub fn calculate_next_trigger(
+        &self,
+        settings: &Settings,
+        title_filters: &TitleFilters,
+    ) -> Option<NextJoinTrigger> {
+        self.calculate_next_trigger_at(settings, title_filters, Utc::now())
+    }
+
+    /// Same as `calculate_next_trigger`, evaluated at an explicit instant
+    pub fn calculate_next_trigger_at(
+        &self,
+        settings: &Settings,
+        title_filters: &TitleFilters,
+        now: DateTime<Utc>,
+    ) -> Option<NextJoinTrigger> {
+        if self.auto_join_blocked(now) {
+            return None;
+        }
+        let max_after_start_ms = (settings.max_minutes_after_start as i64) * 60 * 1000;
+        let now_ms = now.timestamp_millis();
+
+        let mut candidates = self
+            .meetings
+            .iter()
+            .filter_map(|m| {
+                let start_time_ms = m.begin_time.timestamp_millis();
+                let (trigger_time_ms, busy_hold) =
+                    self.adjusted_trigger_ms(m, settings, title_filters, now_ms)?;
+                let fires_at = DateTime::from_timestamp_millis(trigger_time_ms.max(now_ms))?;
+                if !self.is_eligible(m, fires_at, settings, title_filters) {
+                    return None;
+                }
 
                 // Calculate delay from now
                 let delay_ms = trigger_time_ms - now_ms;
@@ -227,335 +1811,2583 @@ impl DaemonState {
 
                 if delay_ms > 0 {
                     // Trigger is in the future
-                    Some((m, delay_ms as u64))
+                    Some((m, delay_ms as u64, busy_hold))
                 } else if time_since_start < max_after_start_ms {
                     // Already past trigger time but still within join window - trigger immediately
-                    Some((m, 0))
+                    Some((m, 0, busy_hold))
                 } else {
                     // Past the join window, skip
                     None
                 }
             })
-            // Get the one with the smallest delay (earliest trigger)
-            .min_by_key(|(_, delay)| *delay)
-            .map(|(m, delay_ms)| NextJoinTrigger {
-                meeting: m.clone(),
-                delay_ms,
-            })
+            .collect::<Vec<_>>();
+
+        // The earliest trigger, unless it overlaps meetings the conflict
+        // policy prefers
+        let first = candidates
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, (_, delay, _))| *delay)
+            .map(|(index, _)| index)?;
+        let rivals: Vec<usize> = (0..candidates.len())
+            .filter(|&i| overlaps(candidates[i].0, candidates[first].0))
+            .collect();
+        let policy = settings
+            .tauri
+            .as_ref()
+            .map(|t| t.conflict_policy)
+            .unwrap_or_default();
+        let (picked, conflict) = match policy {
+            ConflictPolicy::FirstScheduled => (first, Vec::new()),
+            ConflictPolicy::Shortest => {
+                let shortest = rivals.iter().copied().min_by_key(|&i| {
+                    let (m, delay, _) = &candidates[i];
+                    (m.end_time - m.begin_time, *delay)
+                });
+                (shortest.unwrap_or(first), Vec::new())
+            }
+            ConflictPolicy::PromptUser => {
+                let resolved = self
+                    .conflict_choices
+                    .contains_key(&candidates[first].0.call_id);
+                let conflict = if rivals.len() > 1 && !resolved {
+                    rivals.iter().map(|&i| candidates[i].0.clone()).collect()
+                } else {
+                    Vec::new()
+                };
+                (first, conflict)
+            }
+        };
+
+        let (m, delay_ms, busy_hold) = candidates.swap_remove(picked);
+        Some(NextJoinTrigger {
+            meeting: m.clone(),
+            delay_ms,
+            countdown_seconds: resolve_countdown_seconds(m, settings, title_filters),
+            auto_click_join: resolve_auto_click_join(m, settings, title_filters),
+            green_room_delay_ms: self.green_room_delay_ms(m, settings, now_ms, delay_ms),
+            busy_hold,
+            fire_at_ms: now_ms + delay_ms as i64,
+            conflict,
+        })
+    }
+
+    /// The next join and what holds other joins back, evaluated at `now`
+    pub fn trigger_schedule_at(
+        &self,
+        settings: &Settings,
+        title_filters: &TitleFilters,
+        now: DateTime<Utc>,
+    ) -> TriggerSchedule {
+        let now_ms = now.timestamp_millis();
+        let next = self
+            .calculate_next_trigger_at(settings, title_filters, now)
+            .map(|trigger| self.scheduled_trigger(trigger, settings, title_filters, now_ms));
+
+        let mut cancelled: Vec<String> = self.cancelled_joins.iter().cloned().collect();
+        cancelled.sort();
+        TriggerSchedule {
+            next,
+            paused_until_ms: self
+                .paused_until(now)
+                .into_iter()
+                .chain(self.out_of_office_until(now))
+                .max()
+                .map(|until| until.timestamp_millis()),
+            rate_capped: self.join_rate_capped,
+            suppressed: self
+                .get_suppressed_meetings()
+                .into_iter()
+                .map(|s| s.call_id)
+                .collect(),
+            cancelled,
+            snoozed_until_ms: self
+                .snoozed_until
+                .iter()
+                .filter(|(_, until)| **until > now_ms)
+                .map(|(call_id, until)| (call_id.clone(), *until))
+                .collect(),
+        }
+    }
+
+    /// Frontend view of `trigger`, with why it fires when it does
+    fn scheduled_trigger(
+        &self,
+        trigger: NextJoinTrigger,
+        settings: &Settings,
+        title_filters: &TitleFilters,
+        now_ms: i64,
+    ) -> ScheduledTrigger {
+        let meeting = &trigger.meeting;
+        let scheduled_ms = meeting.begin_time.timestamp_millis()
+            - join_before_ms(meeting, settings, title_filters);
+        let snoozed_until = self.snoozed_until(&meeting.call_id, now_ms);
+        let reason = match &trigger.busy_hold {
+            Some(hold)
+                if hold.policy == BusyBlockPolicy::Delay
+                    && hold.block_end.timestamp_millis() == trigger.fire_at_ms =>
+            {
+                TriggerReason::BusyBlock
+            }
+            _ if snoozed_until.is_some_and(|until| until > scheduled_ms) => TriggerReason::Snoozed,
+            _ if scheduled_ms < now_ms => TriggerReason::JoinWindow,
+            _ => TriggerReason::Scheduled,
+        };
+        ScheduledTrigger {
+            call_id: meeting.call_id.clone(),
+            trigger_at_ms: trigger.fire_at_ms,
+            delay_ms: trigger.delay_ms,
+            reason,
+            countdown_seconds: trigger.countdown_seconds,
+            busy_hold: trigger
+                .busy_hold
+                .filter(|hold| hold.policy == BusyBlockPolicy::Confirm),
+            conflict: trigger.conflict.into_iter().map(|m| m.call_id).collect(),
+        }
+    }
+
+    /// Delay until the green-room phase for `meeting`, if it still has to run
+    /// before the join phase at `join_delay_ms`
+    fn green_room_delay_ms(
+        &self,
+        meeting: &Meeting,
+        settings: &Settings,
+        now_ms: i64,
+        join_delay_ms: u64,
+    ) -> Option<u64> {
+        let minutes = settings
+            .tauri
+            .as_ref()
+            .map(|t| t.green_room_minutes)
+            .unwrap_or(0);
+        if minutes == 0 || self.green_room_opened.contains(&meeting.call_id) {
+            return None;
+        }
+        let open_at_ms = meeting.begin_time.timestamp_millis() - (minutes as i64) * 60 * 1000;
+        let delay_ms = (open_at_ms - now_ms).max(0) as u64;
+        (delay_ms < join_delay_ms).then_some(delay_ms)
+    }
+}
+
+/// Whether two meetings run at the same time for a while
+fn overlaps(a: &Meeting, b: &Meeting) -> bool {
+    a.begin_time < b.end_time && b.begin_time < a.end_time
+}
+
+/// Time `meeting` shares with any other of `meetings`
+fn shared_ms(meeting: &Meeting, meetings: &[&Meeting]) -> i64 {
+    let mut shared: Vec<(DateTime<Utc>, DateTime<Utc>)> = meetings
+        .iter()
+        .filter(|other| !std::ptr::eq(**other, meeting))
+        .map(|other| {
+            (
+                meeting.begin_time.max(other.begin_time),
+                meeting.end_time.min(other.end_time),
+            )
+        })
+        .filter(|(start, end)| start < end)
+        .collect();
+    shared.sort();
+
+    let mut total_ms = 0;
+    let mut covered_until: Option<DateTime<Utc>> = None;
+    for (start, end) in shared {
+        let start = covered_until.map_or(start, |until| start.max(until));
+        if end > start {
+            total_ms += (end - start).num_milliseconds();
+            covered_until = Some(end);
+        }
+    }
+    total_ms
+}
+
+/// Total time during which at least two of `meetings` run at once
+fn multi_booked_ms(meetings: &[&Meeting]) -> i64 {
+    let mut edges: Vec<(DateTime<Utc>, i32)> = meetings
+        .iter()
+        .flat_map(|m| [(m.begin_time, 1), (m.end_time, -1)])
+        .collect();
+    // Ends sort before starts at the same instant so back-to-back meetings
+    // don't count as overlapping
+    edges.sort();
+
+    let mut running = 0;
+    let mut since: Option<DateTime<Utc>> = None;
+    let mut total_ms = 0;
+    for (at, delta) in edges {
+        running += delta;
+        match (running >= 2, since) {
+            (true, None) => since = Some(at),
+            (false, Some(start)) => {
+                total_ms += (at - start).num_milliseconds();
+                since = None;
+            }
+            _ => {}
+        }
+    }
+    total_ms
+}
+
+/// Whether two meeting lists describe the same schedule, ignoring the
+/// minute-by-minute `starts_in_minutes`
+fn same_schedule(a: &[Meeting], b: &[Meeting]) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b).all(|(a, b)| {
+            a.call_id == b.call_id
+                && a.url == b.url
+                && a.title == b.title
+                && a.begin_time == b.begin_time
+                && a.end_time == b.end_time
+                && a.event_id == b.event_id
+        })
+}
+
+/// First `meeting_rules` entry whose pattern matches the meeting's title
+pub fn matching_meeting_rule<'a>(
+    meeting: &Meeting,
+    settings: &'a Settings,
+    title_filters: &TitleFilters,
+) -> Option<&'a MeetingRule> {
+    let index = title_filters.meeting_rule(&meeting.title)?;
+    settings.tauri.as_ref()?.meeting_rules.get(index)
+}
+
+/// How many minutes before start `meeting` is joined, after its meeting rule
+pub fn join_before_minutes(
+    meeting: &Meeting,
+    settings: &Settings,
+    title_filters: &TitleFilters,
+) -> u32 {
+    matching_meeting_rule(meeting, settings, title_filters)
+        .and_then(|rule| rule.join_before_minutes)
+        .unwrap_or(settings.join_before_minutes)
+}
+
+fn join_before_ms(meeting: &Meeting, settings: &Settings, title_filters: &TitleFilters) -> i64 {
+    (join_before_minutes(meeting, settings, title_filters) as i64) * 60 * 1000
+}
+
+/// Whether `at` falls inside a Do-Not-Disturb window, in local time
+fn in_dnd_window(settings: &Settings, at: DateTime<Utc>) -> bool {
+    settings.tauri.as_ref().is_some_and(|t| {
+        settings::in_dnd_window(&t.dnd_windows, at.with_timezone(&Local).naive_local())
+    })
+}
+
+/// Whether `meeting` starts outside the configured auto-join hours, in local
+/// time
+pub fn outside_auto_join_hours(meeting: &Meeting, settings: &Settings) -> bool {
+    settings
+        .tauri
+        .as_ref()
+        .and_then(|t| t.auto_join_hours.as_ref())
+        .is_some_and(|hours| {
+            !hours.contains(meeting.begin_time.with_timezone(&Local).naive_local())
+        })
+}
+
+/// Whether auto-join skips `meeting` because of its title.
+///
+/// A matching meeting rule with `auto_join` set decides on its own; otherwise
+/// the title filters apply.
+fn is_excluded_by_title(
+    meeting: &Meeting,
+    settings: &Settings,
+    title_filters: &TitleFilters,
+) -> bool {
+    match matching_meeting_rule(meeting, settings, title_filters).and_then(|rule| rule.auto_join) {
+        Some(auto_join) => !auto_join,
+        None => !title_filters.allows(&meeting.title),
+    }
+}
+
+/// How the title filters and meeting rules decide on a meeting titled
+/// `title`, for previewing them in the settings window
+pub fn preview_title_filters(
+    title: &str,
+    settings: &Settings,
+    title_filters: &TitleFilters,
+) -> TitleFilterPreview {
+    let mut preview = title_filters.preview(title);
+    let deciding_rule = title_filters.meeting_rule(title).and_then(|i| {
+        let rule = settings.tauri.as_ref()?.meeting_rules.get(i)?;
+        rule.auto_join.map(|auto_join| (i, auto_join))
+    });
+    if let Some((i, auto_join)) = deciding_rule {
+        preview.auto_join = auto_join;
+        preview.meeting_rule = Some(i);
+    }
+    preview
+}
+
+/// Settings to join `meeting` with: its meeting rule's join lead, countdown
+/// and media states replace the global ones
+pub fn settings_for_meeting(
+    meeting: &Meeting,
+    settings: &Settings,
+    title_filters: &TitleFilters,
+) -> Settings {
+    let mut resolved = settings.clone();
+    if let Some(rule) = matching_meeting_rule(meeting, settings, title_filters) {
+        if let Some(minutes) = rule.join_before_minutes {
+            resolved.join_before_minutes = minutes;
+        }
+        if let Some(seconds) = rule.countdown_seconds {
+            resolved.join_countdown_seconds = seconds;
+        }
+        if let Some(mic_state) = &rule.mic_state {
+            resolved.default_mic_state = mic_state.clone();
+        }
+        if let Some(camera_state) = &rule.camera_state {
+            resolved.default_camera_state = camera_state.clone();
+        }
+    }
+    resolved
+}
+
+/// Resolve the join countdown for a meeting: its rule's `countdown_seconds`,
+/// otherwise the global `join_countdown_seconds`
+pub fn resolve_countdown_seconds(
+    meeting: &Meeting,
+    settings: &Settings,
+    title_filters: &TitleFilters,
+) -> u32 {
+    matching_meeting_rule(meeting, settings, title_filters)
+        .and_then(|rule| rule.countdown_seconds)
+        .unwrap_or(settings.join_countdown_seconds)
+}
+
+/// Resolve the per-meeting auto-click override from the meeting's rule.
+///
+/// `None` means no rule sets one and the global `auto_click_join` setting
+/// applies.
+pub fn resolve_auto_click_join(
+    meeting: &Meeting,
+    settings: &Settings,
+    title_filters: &TitleFilters,
+) -> Option<bool> {
+    matching_meeting_rule(meeting, settings, title_filters).and_then(|rule| rule.auto_click_join)
+}
+
+/// Re-check a join due at `fire_at_ms` against the wall clock `now`.
+///
+/// Join timers sleep on the monotonic clock, so when the system clock jumps
+/// while they wait they wake before the join is due or after its window has
+/// closed.
+pub fn recheck_trigger_at(
+    meeting: &Meeting,
+    fire_at_ms: i64,
+    settings: &Settings,
+    now: DateTime<Utc>,
+) -> TriggerRecheck {
+    let now_ms = now.timestamp_millis();
+    let window_end_ms = meeting.begin_time.timestamp_millis()
+        + (settings.max_minutes_after_start as i64) * 60 * 1000;
+    if meeting.end_time <= now || now_ms >= window_end_ms {
+        TriggerRecheck::Expired
+    } else if fire_at_ms - now_ms > TRIGGER_EARLY_TOLERANCE_MS {
+        TriggerRecheck::Early {
+            remaining_ms: fire_at_ms - now_ms,
+        }
+    } else {
+        TriggerRecheck::Fire
+    }
+}
+
+/// Time the injected script has to answer a ping sent after `check-meetings`
+pub const PING_REPLY_TIMEOUT_MS: u64 = 10_000;
+/// Unanswered pings in a row before the watchdog steps in
+pub const PING_MISSES_BEFORE_RECOVERY: u32 = 3;
+
+/// Recovery step the script watchdog asks for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchdogAction {
+    /// Answered in time, or not enough misses yet
+    None,
+    /// Inject the script again
+    Reinject,
+    /// Re-injecting didn't help; reload the page
+    Reload,
+    /// Reloading didn't help either, e.g. because the webview's content
+    /// process died; replace the main window
+    Recreate,
+}
+
+/// Liveness of the script injected into the main window.
+///
+/// Every ping must be answered within [`PING_REPLY_TIMEOUT_MS`]. After
+/// [`PING_MISSES_BEFORE_RECOVERY`] misses in a row the script is injected
+/// again; if the next run of misses follows without any answer in between,
+/// the page is reloaded, and after another such run the window is recreated.
+#[derive(Debug, Default)]
+pub struct ScriptWatchdog {
+    /// Id and send time of the ping awaiting an answer
+    pending: Option<(u64, u64)>,
+    next_ping_id: u64,
+    consecutive_misses: u32,
+    /// The script was re-injected and hasn't answered since
+    reinjected: bool,
+    /// The page was reloaded and the script hasn't answered since
+    reloaded: bool,
+}
+
+impl ScriptWatchdog {
+    /// Record a ping and return its id
+    pub fn on_ping_sent(&mut self, now_ms: u64) -> u64 {
+        self.next_ping_id += 1;
+        self.pending = Some((self.next_ping_id, now_ms));
+        self.next_ping_id
+    }
+
+    /// Record an answer; returns how many pings were missed before it, or
+    /// `None` for an unknown or late ping
+    pub fn on_pong(&mut self, ping_id: u64) -> Option<u32> {
+        let (pending_id, _) = self.pending?;
+        if pending_id != ping_id {
+            return None;
+        }
+        self.pending = None;
+        self.reinjected = false;
+        self.reloaded = false;
+        Some(std::mem::take(&mut self.consecutive_misses))
+    }
+
+    /// Count ping `ping_id` as missed if it is still unanswered
+    pub fn check_timeout(&mut self, ping_id: u64, now_ms: u64) -> WatchdogAction {
+        let Some((pending_id, sent_ms)) = self.pending else {
+            return WatchdogAction::None;
+        };
+        if pending_id != ping_id || now_ms.saturating_sub(sent_ms) < PING_REPLY_TIMEOUT_MS {
+            return WatchdogAction::None;
+        }
+        self.pending = None;
+        self.consecutive_misses += 1;
+        if self.consecutive_misses < PING_MISSES_BEFORE_RECOVERY {
+            return WatchdogAction::None;
+        }
+        self.consecutive_misses = 0;
+        if std::mem::take(&mut self.reloaded) {
+            WatchdogAction::Recreate
+        } else if std::mem::take(&mut self.reinjected) {
+            self.reloaded = true;
+            WatchdogAction::Reload
+        } else {
+            self.reinjected = true;
+            WatchdogAction::Reinject
+        }
+    }
+
+    /// Unanswered pings in a row
+    pub fn consecutive_misses(&self) -> u32 {
+        self.consecutive_misses
+    }
+}
+
+/// Local UTC offsets (in seconds) at the start and end of `date` when they differ.
+///
+/// Returns `None` on ordinary days. Used to log a `time.dst_transition` marker so
+/// support can correlate scheduling reports with daylight-saving changes.
+pub fn offset_change_on<Tz: TimeZone>(tz: &Tz, date: NaiveDate) -> Option<(i32, i32)> {
+    let start_local = date.and_hms_opt(0, 0, 0)?;
+    let start_utc = tz
+        .from_local_datetime(&start_local)
+        .earliest()?
+        .with_timezone(&Utc);
+    let end_utc = start_utc + Duration::hours(24);
+
+    let start_offset = tz.offset_from_utc_datetime(&start_utc.naive_utc()).fix();
+    let end_offset = tz.offset_from_utc_datetime(&end_utc.naive_utc()).fix();
+    let (from, to) = (start_offset.local_minus_utc(), end_offset.local_minus_utc());
+    (from != to).then_some((from, to))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{MediaState, TitleMatchMode};
+    use chrono::FixedOffset;
+
+    fn create_test_meeting(call_id: &str, title: &str, starts_in_minutes: i64) -> Meeting {
+        let now = Utc::now();
+        Meeting {
+            call_id: call_id.to_string(),
+            url: format!("https://meet.google.com/{}", call_id),
+            title: title.to_string(),
+            display_time: "10:00 AM".to_string(),
+            begin_time: now + Duration::minutes(starts_in_minutes),
+            end_time: now + Duration::minutes(starts_in_minutes + 60),
+            event_id: Some("event123".to_string()),
+            starts_in_minutes,
+            profile: None,
+            accuracy: TimeAccuracy::Exact,
+        }
+    }
+
+    #[test]
+    fn test_daemon_state() {
+        let mut state = DaemonState::default();
+        assert!(!state.is_running());
+
+        state.start();
+        assert!(state.is_running());
+
+        state.stop();
+        assert!(!state.is_running());
+    }
+
+    #[test]
+    fn test_joined_tracking() {
+        let mut state = DaemonState::default();
+
+        state.mark_joined("abc-defg-hij", 100).unwrap();
+        assert_eq!(
+            state.get_joined_meetings(),
+            vec!["abc-defg-hij".to_string()]
+        );
+        assert_eq!(
+            state.meeting_phase("abc-defg-hij"),
+            Some(MeetingPhase::Joined)
+        );
+
+        // Not in the meeting list, so the next update expires it
+        state.update_meetings(Vec::new());
+        assert!(state.get_joined_meetings().is_empty());
+        assert_eq!(state.meeting_phase("abc-defg-hij"), None);
+    }
+
+    #[test]
+    fn test_persisted_state_round_trip() {
+        let now = Utc::now();
+        let mut state = DaemonState::default();
+        state.update_meetings(vec![
+            create_test_meeting("abc-defg-hij", "Standup", -5),
+            create_test_meeting("xyz-uvwx-rst", "Review", 30),
+        ]);
+        state.mark_joined("abc-defg-hij", 100).unwrap();
+        state
+            .mark_suppressed("xyz-uvwx-rst", SuppressionReason::Closed, 200)
+            .unwrap();
+        state.drain_lifecycle_events();
+
+        let mut snapshot = state
+            .take_snapshot_if_dirty(now.timestamp_millis())
+            .unwrap();
+        assert!(state
+            .take_snapshot_if_dirty(now.timestamp_millis())
+            .is_none());
+
+        // An ended meeting and its records are dropped on restore
+        let mut ended = create_test_meeting("old-meet-ing", "Ended", -120);
+        ended.end_time = now - Duration::minutes(1);
+        snapshot.meetings.push(ended);
+        snapshot
+            .blocked_meetings
+            .insert("old-meet-ing".to_string(), JoinBlockedReason::NotAllowed);
+
+        let mut restored = DaemonState::restore(snapshot.clone(), now);
+        assert_eq!(restored.get_meetings().len(), 2);
+        assert_eq!(
+            restored.meeting_phase("abc-defg-hij"),
+            Some(MeetingPhase::Joined)
+        );
+        let suppressed = restored.get_suppressed_meetings();
+        assert_eq!(suppressed.len(), 1);
+        assert_eq!(suppressed[0].call_id, "xyz-uvwx-rst");
+        assert_eq!(suppressed[0].reason, SuppressionReason::Closed);
+        assert!(restored.get_blocked_meetings().is_empty());
+        assert!(restored.drain_lifecycle_events().is_empty());
+
+        let stale = now + Duration::milliseconds(PERSISTED_STATE_MAX_AGE_MS + 1);
+        assert!(DaemonState::restore(snapshot, stale)
+            .get_meetings()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_update_meetings() {
+        let mut state = DaemonState::default();
+        assert!(state.get_meetings().is_empty());
+
+        let meetings = vec![
+            create_test_meeting("abc-defg-hij", "Team Standup", 5),
+            create_test_meeting("xyz-uvwx-rst", "1:1 Meeting", 30),
+        ];
+        state.update_meetings(meetings);
+
+        assert_eq!(state.get_meetings().len(), 2);
+    }
+
+    #[test]
+    fn test_update_meetings_from_source_records_origins() {
+        let mut state = DaemonState::default();
+        let priority = vec![MeetingSource::Webview, MeetingSource::Ics];
+
+        let mut ics_meeting = create_test_meeting("abc-defg-hij", "Team Standup (Weekly)", 10);
+        ics_meeting.url = "https://meet.google.com/abc-defg-hij?authuser=1".to_string();
+        state.update_meetings_from_source(MeetingSource::Ics, vec![ics_meeting], 200, &priority);
+        state.update_meetings_from_source(
+            MeetingSource::Webview,
+            vec![create_test_meeting("abc-defg-hij", "Team Standup", 5)],
+            100,
+            &priority,
+        );
+
+        let meetings = state.get_meetings();
+        assert_eq!(meetings.len(), 1);
+        assert_eq!(meetings[0].title, "Team Standup (Weekly)");
+        assert_eq!(meetings[0].url, "https://meet.google.com/abc-defg-hij");
+
+        let origins = &state.meeting_origins()["abc-defg-hij"];
+        assert_eq!(origins.title, MeetingSource::Ics);
+        assert_eq!(origins.times, MeetingSource::Ics);
+        assert_eq!(origins.url, MeetingSource::Webview);
+    }
+
+    #[test]
+    fn test_sources_do_not_clobber_each_other() {
+        let mut state = DaemonState::default();
+        let priority = vec![MeetingSource::Webview, MeetingSource::Ics];
+
+        state.update_meetings_from_source(
+            MeetingSource::Ics,
+            vec![create_test_meeting("ics-only-mtg", "Vendor Call", 20)],
+            100,
+            &priority,
+        );
+        state.update_meetings_from_source(
+            MeetingSource::Webview,
+            vec![create_test_meeting("abc-defg-hij", "Team Standup", 5)],
+            200,
+            &priority,
+        );
+        assert_eq!(state.get_meetings().len(), 2);
+
+        // A new webview list replaces only the webview's own meetings
+        state.update_meetings_from_source(MeetingSource::Webview, Vec::new(), 300, &priority);
+        let meetings = state.get_meetings();
+        assert_eq!(meetings.len(), 1);
+        assert_eq!(meetings[0].call_id, "ics-only-mtg");
+        assert_eq!(
+            state.meeting_origins()["ics-only-mtg"].sources,
+            vec![MeetingSource::Ics]
+        );
+        assert_eq!(
+            state.source_snapshots(),
+            vec![
+                SourceSnapshotInfo {
+                    source: MeetingSource::Webview,
+                    meeting_count: 0,
+                    observed_at_ms: 300,
+                },
+                SourceSnapshotInfo {
+                    source: MeetingSource::Ics,
+                    meeting_count: 1,
+                    observed_at_ms: 100,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_update_meetings_from_source_rejects_invalid_urls() {
+        let mut state = DaemonState::default();
+        let priority = vec![MeetingSource::Webview];
+
+        let mut tracked = create_test_meeting("abc-defg-hij", "Standup", 10);
+        tracked.url = "http://meet.google.com/ABC-DEFG-HIJ?utm_source=calendar".to_string();
+        let mut spoofed = create_test_meeting("xyz-wxyz-xyz", "Spoofed", 5);
+        spoofed.url = "https://meet.google.com.evil.example/xyz-wxyz-xyz".to_string();
+
+        let rejected = state.update_meetings_from_source(
+            MeetingSource::Webview,
+            vec![tracked, spoofed],
+            100,
+            &priority,
+        );
+
+        assert_eq!(
+            rejected,
+            vec![RejectedMeetingUrl {
+                call_id: "xyz-wxyz-xyz".to_string(),
+                reason: UrlRejection::Host,
+            }]
+        );
+        let meetings = state.get_meetings();
+        assert_eq!(meetings.len(), 1);
+        assert_eq!(meetings[0].url, "https://meet.google.com/abc-defg-hij");
+    }
+
+    #[test]
+    fn test_stale_data_tracks_webview_reports() {
+        let mut state = DaemonState::default();
+        let priority = vec![MeetingSource::Webview, MeetingSource::Ics];
+        let threshold_ms = 15 * 60_000;
+
+        // Nothing reported yet
+        assert!(!state.is_data_stale(threshold_ms, threshold_ms));
+
+        state.update_meetings_from_source(MeetingSource::Webview, Vec::new(), 1_000, &priority);
+        assert_eq!(state.data_age_ms(61_000), Some(60_000));
+        assert!(!state.take_stale_recovery(61_000, threshold_ms));
+
+        // Other sources don't refresh the page's list
+        let stale_at = 1_000 + threshold_ms;
+        state.update_meetings_from_source(MeetingSource::Ics, Vec::new(), stale_at, &priority);
+        assert!(state.is_data_stale(stale_at, threshold_ms));
+        assert!(!state.is_data_stale(stale_at, 0));
+
+        // One reload per threshold while it stays stale
+        assert!(state.take_stale_recovery(stale_at, threshold_ms));
+        assert!(!state.take_stale_recovery(stale_at + 60_000, threshold_ms));
+        assert!(state.take_stale_recovery(stale_at + threshold_ms, threshold_ms));
+
+        state.update_meetings_from_source(
+            MeetingSource::Webview,
+            Vec::new(),
+            stale_at + threshold_ms,
+            &priority,
+        );
+        assert!(!state.is_data_stale(stale_at + threshold_ms, threshold_ms));
+    }
+
+    #[test]
+    fn test_profile_meetings_are_tagged_and_merged() {
+        let mut state = DaemonState::default();
+        let priority = vec![MeetingSource::Webview];
+
+        state.update_meetings_from_source(
+            MeetingSource::Webview,
+            vec![create_test_meeting("abc-defg-hij", "Standup", 20)],
+            100,
+            &priority,
+        );
+        state.update_profile_meetings(
+            "work",
+            vec![
+                create_test_meeting("abc-defg-hij", "Standup", 20),
+                create_test_meeting("xyz-wxyz-xyz", "Client Sync", 5),
+            ],
+            &priority,
+        );
+
+        let meetings = state.get_meetings();
+        assert_eq!(meetings.len(), 2);
+        assert_eq!(meetings[0].call_id, "xyz-wxyz-xyz");
+        assert_eq!(meetings[0].profile.as_deref(), Some("work"));
+        // Also listed by the main window, which keeps it
+        assert_eq!(meetings[1].profile, None);
+
+        // A later main-window report keeps the profile's meetings
+        state.update_meetings_from_source(MeetingSource::Webview, vec![], 200, &priority);
+        assert_eq!(state.get_meetings().len(), 2);
+
+        state.retain_profiles(&[], &priority);
+        assert!(state.get_meetings().is_empty());
+    }
+
+    #[test]
+    fn test_last_minute_meetings_are_announced_once() {
+        let mut state = DaemonState::default();
+        let priority = vec![MeetingSource::Webview, MeetingSource::Ics];
+        let mut settings = Settings::default();
+        let now = Utc::now();
+
+        // A source's first report isn't news
+        state.update_meetings_from_source(
+            MeetingSource::Webview,
+            vec![create_test_meeting("abc-defg-hij", "Standup", 2)],
+            100,
+            &priority,
+        );
+        assert!(state.take_last_minute_meetings(&settings, now).is_empty());
+
+        state.update_meetings_from_source(
+            MeetingSource::Webview,
+            vec![
+                create_test_meeting("abc-defg-hij", "Standup", 2),
+                create_test_meeting("qck-sync-now", "Quick sync", 2),
+                create_test_meeting("xyz-wxyz-xyz", "Planning", 30),
+            ],
+            200,
+            &priority,
+        );
+        let announced = state.take_last_minute_meetings(&settings, now);
+        assert_eq!(announced.len(), 1);
+        assert_eq!(announced[0].call_id, "qck-sync-now");
+        assert!(state.was_announced_last_minute("qck-sync-now"));
+
+        // Reported again by another source after it dropped out of the list
+        state.update_meetings_from_source(MeetingSource::Webview, vec![], 300, &priority);
+        state.update_meetings_from_source(MeetingSource::Ics, vec![], 400, &priority);
+        state.update_meetings_from_source(
+            MeetingSource::Ics,
+            vec![
+                create_test_meeting("qck-sync-now", "Quick sync", 2),
+                create_test_meeting("new-call-now", "Escalation", 1),
+            ],
+            500,
+            &priority,
+        );
+        let announced = state.take_last_minute_meetings(&settings, now);
+        assert_eq!(announced.len(), 1);
+        assert_eq!(announced[0].call_id, "new-call-now");
+
+        settings.tauri.as_mut().unwrap().last_minute_meeting_minutes = 0;
+        state.update_meetings_from_source(
+            MeetingSource::Ics,
+            vec![create_test_meeting("one-more-now", "Handover", 1)],
+            600,
+            &priority,
+        );
+        assert!(state.take_last_minute_meetings(&settings, now).is_empty());
+    }
+
+    #[test]
+    fn test_get_next_meeting_returns_earliest() {
+        let mut state = DaemonState::default();
+        let meetings = vec![
+            create_test_meeting("later", "Later Meeting", 30),
+            create_test_meeting("soon", "Soon Meeting", 5),
+            create_test_meeting("soonest", "Soonest Meeting", 2),
+        ];
+        state.update_meetings(meetings);
+
+        let next = state.get_next_meeting(
+            &Settings::default(),
+            &TitleFilters::from_settings(&Settings::default()),
+        );
+        assert!(next.is_some());
+        assert_eq!(next.unwrap().call_id, "soonest");
+    }
+
+    #[test]
+    fn test_get_next_meeting_excludes_joined() {
+        let mut state = DaemonState::default();
+        let meetings = vec![
+            create_test_meeting("first", "First Meeting", -2),
+            create_test_meeting("second", "Second Meeting", 5),
+        ];
+        state.update_meetings(meetings);
+        state
+            .mark_joined("first", Utc::now().timestamp_millis())
+            .unwrap();
+
+        let next = state.get_next_meeting(
+            &Settings::default(),
+            &TitleFilters::from_settings(&Settings::default()),
+        );
+        assert!(next.is_some());
+        assert_eq!(next.unwrap().call_id, "second");
+    }
+
+    #[test]
+    fn test_get_next_meeting_allows_joined_before_start() {
+        let mut state = DaemonState::default();
+        let meetings = vec![create_test_meeting("first", "First Meeting", 5)];
+        state.update_meetings(meetings);
+        state
+            .mark_joined("first", Utc::now().timestamp_millis())
+            .unwrap();
+
+        let next = state.get_next_meeting(
+            &Settings::default(),
+            &TitleFilters::from_settings(&Settings::default()),
+        );
+        assert!(next.is_some());
+        assert_eq!(next.unwrap().call_id, "first");
+    }
+
+    #[test]
+    fn test_get_next_meeting_skips_suppressed_after_trigger() {
+        let mut state = DaemonState::default();
+        let meetings = vec![create_test_meeting("first", "First Meeting", 1)];
+        state.update_meetings(meetings);
+        state
+            .mark_suppressed(
+                "first",
+                SuppressionReason::Closed,
+                Utc::now().timestamp_millis(),
+            )
+            .unwrap();
+
+        let settings = Settings {
+            join_before_minutes: 2,
+            ..Settings::default()
+        };
+
+        let next = state.get_next_meeting(&settings, &TitleFilters::from_settings(&settings));
+        assert!(next.is_none());
+    }
+
+    #[test]
+    fn test_mark_closed_leaves_or_suppresses() {
+        let mut state = DaemonState::default();
+        state.update_meetings(vec![create_test_meeting("early", "Early Chat", 10)]);
+        let trigger_at_ms = Utc::now().timestamp_millis() + 9 * 60 * 1000;
+
+        state.mark_joined("early", 100).unwrap();
+        state.mark_closed("early", 200, trigger_at_ms).unwrap();
+        assert_eq!(state.meeting_phase("early"), Some(MeetingPhase::Left));
+        assert!(state.get_suppressed_meetings().is_empty());
+
+        state
+            .mark_closed("early", trigger_at_ms, trigger_at_ms)
+            .unwrap();
+        assert_eq!(state.meeting_phase("early"), Some(MeetingPhase::Suppressed));
+        let suppressed = state.get_suppressed_meetings();
+        assert_eq!(suppressed.len(), 1);
+        assert_eq!(suppressed[0].call_id, "early");
+        assert_eq!(suppressed[0].suppressed_at_ms, trigger_at_ms);
+
+        let phases: Vec<MeetingPhase> = state
+            .drain_lifecycle_events()
+            .into_iter()
+            .map(|e| e.transition.to)
+            .collect();
+        assert_eq!(
+            phases,
+            vec![
+                MeetingPhase::Scheduled,
+                MeetingPhase::Joined,
+                MeetingPhase::Left,
+                MeetingPhase::Suppressed,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mark_closed_keeps_breakout_joined() {
+        let mut state = DaemonState::default();
+        state.update_meetings(vec![create_test_meeting("main", "All Hands", 10)]);
+        let trigger_at_ms = Utc::now().timestamp_millis() + 9 * 60 * 1000;
+        state.mark_joined("main", 100).unwrap();
+
+        assert!(state.enter_breakout("main", Some("Room 1".to_string()), 200));
+        assert_eq!(state.breakout_calls(), vec!["main".to_string()]);
+        state
+            .mark_closed("main", trigger_at_ms, trigger_at_ms)
+            .unwrap();
+        assert_eq!(state.meeting_phase("main"), Some(MeetingPhase::Joined));
+
+        assert!(state.exit_breakout("main").is_some());
+        state
+            .mark_closed("main", trigger_at_ms, trigger_at_ms)
+            .unwrap();
+        assert_eq!(state.meeting_phase("main"), Some(MeetingPhase::Suppressed));
+        assert!(state.breakout_calls().is_empty());
+    }
+
+    #[test]
+    fn test_suppressed_meeting_does_not_trigger() {
+        let mut state = DaemonState::default();
+        let meetings = vec![create_test_meeting("first", "First Meeting", 1)];
+        state.update_meetings(meetings);
+        state
+            .mark_suppressed(
+                "first",
+                SuppressionReason::Closed,
+                Utc::now().timestamp_millis(),
+            )
+            .unwrap();
+
+        let settings = Settings {
+            join_before_minutes: 2,
+            ..Settings::default()
+        };
+
+        let trigger =
+            state.calculate_next_trigger(&settings, &TitleFilters::from_settings(&settings));
+        assert!(trigger.is_none());
+    }
+
+    #[test]
+    fn test_suppressed_before_trigger_is_not_scheduled() {
+        let now = Utc::now();
+        let mut state = DaemonState::default();
+        state.update_meetings(vec![create_test_meeting("later", "Later Sync", 10)]);
+        state
+            .mark_suppressed("later", SuppressionReason::Closed, now.timestamp_millis())
+            .unwrap();
+
+        let settings = Settings {
+            join_before_minutes: 1,
+            ..Settings::default()
+        };
+        let title_filters = TitleFilters::from_settings(&settings);
+
+        // Both paths judge the meeting at its trigger, so neither fires it
+        assert!(state
+            .calculate_next_trigger_at(&settings, &title_filters, now)
+            .is_none());
+        assert!(state
+            .should_join_now_at(&settings, &title_filters, now + Duration::minutes(9))
+            .is_none());
+    }
+
+    #[test]
+    fn test_suppression_expires_with_its_instance() {
+        let now = Utc::now();
+        let mut state = DaemonState::default();
+        let earlier = create_test_meeting("standup", "Standup", -90);
+        state.update_meetings(vec![earlier.clone()]);
+        let suppressed_at_ms = (now - Duration::minutes(80)).timestamp_millis();
+        state
+            .mark_suppressed("standup", SuppressionReason::Closed, suppressed_at_ms)
+            .unwrap();
+        let suppression = state.get_suppressed_meetings().remove(0);
+        assert_eq!(suppression.begin_time, earlier.begin_time);
+        assert_eq!(suppression.end_time, earlier.end_time);
+        state.drain_lifecycle_events();
+
+        // Next week's standup reuses the code
+        state.update_meetings(vec![create_test_meeting("standup", "Standup", 1)]);
+        assert!(state.get_suppressed_meetings().is_empty());
+        assert_eq!(
+            state.meeting_phase("standup"),
+            Some(MeetingPhase::Scheduled)
+        );
+        let phases: Vec<MeetingPhase> = state
+            .drain_lifecycle_events()
+            .into_iter()
+            .map(|e| e.transition.to)
+            .collect();
+        assert_eq!(phases, vec![MeetingPhase::Expired, MeetingPhase::Scheduled]);
+
+        let settings = Settings {
+            join_before_minutes: 2,
+            ..Settings::default()
+        };
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.meeting.call_id, "standup");
+    }
+
+    #[test]
+    fn test_clear_suppressed() {
+        let now = Utc::now();
+        let mut state = DaemonState::default();
+        let meeting = create_test_meeting("abc-defg-hij", "Standup", 1);
+        state.update_meetings(vec![meeting.clone()]);
+        let settings = Settings {
+            join_before_minutes: 2,
+            ..Settings::default()
+        };
+
+        state
+            .cancel_join("abc-defg-hij", now.timestamp_millis())
+            .unwrap();
+        assert!(state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .is_none());
+        let suppressed = state.get_suppressed_meetings();
+        assert_eq!(suppressed.len(), 1);
+        assert_eq!(suppressed[0].reason, SuppressionReason::Cancelled);
+        assert_eq!(suppressed[0].begin_time, meeting.begin_time);
+        assert_eq!(suppressed[0].suppressed_at_ms, now.timestamp_millis());
+
+        assert!(state.clear_suppressed("abc-defg-hij", now.timestamp_millis()));
+        assert!(!state.is_join_cancelled("abc-defg-hij"));
+        assert_eq!(
+            state.meeting_phase("abc-defg-hij"),
+            Some(MeetingPhase::Scheduled)
+        );
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.meeting.call_id, "abc-defg-hij");
+        assert!(!state.clear_suppressed("abc-defg-hij", now.timestamp_millis()));
+    }
+
+    #[test]
+    fn test_get_next_meeting_excludes_old_meetings() {
+        let mut state = DaemonState::default();
+        // Meeting that started 10 minutes ago (beyond the 5-minute grace period)
+        let meetings = vec![create_test_meeting("old", "Old Meeting", -10)];
+        state.update_meetings(meetings);
+
+        let next = state.get_next_meeting(
+            &Settings::default(),
+            &TitleFilters::from_settings(&Settings::default()),
+        );
+        assert!(next.is_none());
+    }
+
+    #[test]
+    fn test_should_join_now_within_window() {
+        let mut state = DaemonState::default();
+        // Meeting starting in 1 minute, with joinBeforeMinutes = 1
+        let meetings = vec![create_test_meeting("abc", "Test Meeting", 1)];
+        state.update_meetings(meetings);
+
+        let settings = Settings {
+            join_before_minutes: 1,
+            ..Settings::default()
+        };
+
+        let should_join = state.should_join_now(&settings, &TitleFilters::from_settings(&settings));
+        assert!(should_join.is_some());
+        assert_eq!(should_join.unwrap().call_id, "abc");
+    }
+
+    #[test]
+    fn test_should_join_now_not_yet() {
+        let mut state = DaemonState::default();
+        // Meeting starting in 10 minutes, with joinBeforeMinutes = 1
+        let meetings = vec![create_test_meeting("abc", "Test Meeting", 10)];
+        state.update_meetings(meetings);
+
+        let settings = Settings {
+            join_before_minutes: 1,
+            ..Settings::default()
+        };
+
+        let should_join = state.should_join_now(&settings, &TitleFilters::from_settings(&settings));
+        assert!(should_join.is_none());
+    }
+
+    #[test]
+    fn test_should_join_now_respects_exclude_filters() {
+        let mut state = DaemonState::default();
+        let meetings = vec![
+            create_test_meeting("skip", "1:1 with Manager", 1),
+            create_test_meeting("join", "Team Standup", 2),
+        ];
+        state.update_meetings(meetings);
+
+        let settings = Settings {
+            join_before_minutes: 5,
+            title_exclude_filters: vec!["1:1".to_string()],
+            ..Settings::default()
+        };
+
+        let should_join = state.should_join_now(&settings, &TitleFilters::from_settings(&settings));
+        assert!(should_join.is_some());
+        assert_eq!(should_join.unwrap().call_id, "join");
+    }
+
+    #[test]
+    fn test_should_join_now_after_start_within_grace() {
+        let mut state = DaemonState::default();
+        // Meeting that started 5 minutes ago (within grace period)
+        let meetings = vec![create_test_meeting("abc", "Test Meeting", -5)];
+        state.update_meetings(meetings);
+
+        let settings = Settings {
+            join_before_minutes: 1,
+            ..Settings::default()
+        };
+
+        let should_join = state.should_join_now(&settings, &TitleFilters::from_settings(&settings));
+        assert!(should_join.is_some());
+    }
+
+    #[test]
+    fn test_should_join_now_too_late() {
+        let mut state = DaemonState::default();
+        // Meeting that started 35 minutes ago (beyond grace period)
+        let meetings = vec![create_test_meeting("abc", "Test Meeting", -35)];
+        state.update_meetings(meetings);
+
+        let settings = Settings::default();
+
+        let should_join = state.should_join_now(&settings, &TitleFilters::from_settings(&settings));
+        assert!(should_join.is_none());
+    }
+
+    #[test]
+    fn test_should_join_now_respects_max_after_start() {
+        let mut state = DaemonState::default();
+        let meetings = vec![create_test_meeting("abc", "Test Meeting", -5)];
+        state.update_meetings(meetings);
+
+        let settings = Settings {
+            max_minutes_after_start: 3,
+            ..Settings::default()
+        };
+
+        let should_join = state.should_join_now(&settings, &TitleFilters::from_settings(&settings));
+        assert!(should_join.is_none());
+    }
+
+    #[test]
+    fn test_calculate_next_trigger_future_meeting() {
+        let mut state = DaemonState::default();
+        // Meeting starting in 10 minutes
+        let meetings = vec![create_test_meeting("abc", "Test Meeting", 10)];
+        state.update_meetings(meetings);
+
+        let settings = Settings {
+            join_before_minutes: 1,
+            ..Settings::default()
+        };
+
+        let trigger =
+            state.calculate_next_trigger(&settings, &TitleFilters::from_settings(&settings));
+        assert!(trigger.is_some());
+        let trigger = trigger.unwrap();
+        assert_eq!(trigger.meeting.call_id, "abc");
+        // Should trigger in about 9 minutes (10 - 1 = 9 minutes before)
+        assert!(trigger.delay_ms > 8 * 60 * 1000); // > 8 minutes
+        assert!(trigger.delay_ms < 10 * 60 * 1000); // < 10 minutes
+    }
+
+    #[test]
+    fn test_snooze_postpones_trigger() {
+        let now = Utc::now();
+        let now_ms = now.timestamp_millis();
+        let mut state = DaemonState::default();
+        state.update_meetings(vec![
+            create_test_meeting("abc", "Standup", 0),
+            create_test_meeting("xyz", "Review", 30),
+        ]);
+        let settings = Settings {
+            join_before_minutes: 1,
+            max_minutes_after_start: 10,
+            ..Settings::default()
+        };
+        assert_eq!(
+            state
+                .should_join_now_at(&settings, &TitleFilters::from_settings(&settings), now)
+                .unwrap()
+                .call_id,
+            "abc"
+        );
+
+        state.snooze("abc", now_ms + 5 * 60 * 1000);
+        assert!(state
+            .should_join_now_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .is_none());
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.meeting.call_id, "abc");
+        assert_eq!(trigger.delay_ms, 5 * 60 * 1000);
+
+        // Snoozing past the join window skips the meeting
+        state.snooze("abc", now_ms + 15 * 60 * 1000);
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.meeting.call_id, "xyz");
+
+        // Once the snooze expires the meeting is eligible again
+        let later = now + Duration::minutes(16);
+        assert_eq!(state.snoozed_until("abc", later.timestamp_millis()), None);
+    }
+
+    #[test]
+    fn test_recheck_trigger_after_clock_jumps() {
+        let now = Utc::now();
+        let mut state = DaemonState::default();
+        state.update_meetings(vec![create_test_meeting("abc", "Standup", 10)]);
+        let settings = Settings {
+            join_before_minutes: 1,
+            max_minutes_after_start: 5,
+            ..Settings::default()
+        };
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        let meeting = &trigger.meeting;
+        let due = now + Duration::milliseconds(trigger.delay_ms as i64);
+        assert_eq!(trigger.fire_at_ms, due.timestamp_millis());
+
+        // Timer woke on time
+        assert_eq!(
+            recheck_trigger_at(meeting, trigger.fire_at_ms, &settings, due),
+            TriggerRecheck::Fire
+        );
+
+        // Clock jumped back 3 minutes while the timer slept
+        assert_eq!(
+            recheck_trigger_at(
+                meeting,
+                trigger.fire_at_ms,
+                &settings,
+                due - Duration::minutes(3)
+            ),
+            TriggerRecheck::Early {
+                remaining_ms: 3 * 60 * 1000
+            }
+        );
+
+        // Clock jumped forward past the join window, then past the meeting
+        assert_eq!(
+            recheck_trigger_at(
+                meeting,
+                trigger.fire_at_ms,
+                &settings,
+                meeting.begin_time + Duration::minutes(6)
+            ),
+            TriggerRecheck::Expired
+        );
+        assert_eq!(
+            recheck_trigger_at(meeting, trigger.fire_at_ms, &settings, meeting.end_time),
+            TriggerRecheck::Expired
+        );
+        // Rescheduling from there finds nothing to join
+        assert!(state
+            .calculate_next_trigger_at(
+                &settings,
+                &TitleFilters::from_settings(&settings),
+                meeting.begin_time + Duration::minutes(6)
+            )
+            .is_none());
+
+        // A small forward jump that stays inside the window still joins
+        assert_eq!(
+            recheck_trigger_at(
+                meeting,
+                trigger.fire_at_ms,
+                &settings,
+                meeting.begin_time + Duration::minutes(2)
+            ),
+            TriggerRecheck::Fire
+        );
+    }
+
+    #[test]
+    fn test_script_watchdog_escalates() {
+        let mut watchdog = ScriptWatchdog::default();
+        let miss = |watchdog: &mut ScriptWatchdog, now_ms: u64| {
+            let id = watchdog.on_ping_sent(now_ms);
+            // Too early to count
+            assert_eq!(watchdog.check_timeout(id, now_ms + 1), WatchdogAction::None);
+            watchdog.check_timeout(id, now_ms + PING_REPLY_TIMEOUT_MS)
+        };
+
+        assert_eq!(miss(&mut watchdog, 0), WatchdogAction::None);
+        assert_eq!(miss(&mut watchdog, 60_000), WatchdogAction::None);
+        assert_eq!(watchdog.consecutive_misses(), 2);
+        assert_eq!(miss(&mut watchdog, 120_000), WatchdogAction::Reinject);
+
+        // Still silent after re-injecting: reload
+        assert_eq!(miss(&mut watchdog, 180_000), WatchdogAction::None);
+        assert_eq!(miss(&mut watchdog, 240_000), WatchdogAction::None);
+        assert_eq!(miss(&mut watchdog, 300_000), WatchdogAction::Reload);
+
+        // Still silent after reloading: recreate the window, then start over
+        assert_eq!(miss(&mut watchdog, 360_000), WatchdogAction::None);
+        assert_eq!(miss(&mut watchdog, 420_000), WatchdogAction::None);
+        assert_eq!(miss(&mut watchdog, 480_000), WatchdogAction::Recreate);
+        assert_eq!(miss(&mut watchdog, 540_000), WatchdogAction::None);
+        assert_eq!(miss(&mut watchdog, 600_000), WatchdogAction::None);
+        assert_eq!(miss(&mut watchdog, 660_000), WatchdogAction::Reinject);
+
+        // An answer resets the count and the escalation
+        assert_eq!(miss(&mut watchdog, 720_000), WatchdogAction::None);
+        let id = watchdog.on_ping_sent(780_000);
+        assert_eq!(watchdog.on_pong(id + 1), None);
+        assert_eq!(watchdog.on_pong(id), Some(1));
+        assert_eq!(watchdog.check_timeout(id, 860_000), WatchdogAction::None);
+        assert_eq!(miss(&mut watchdog, 900_000), WatchdogAction::None);
+        assert_eq!(miss(&mut watchdog, 960_000), WatchdogAction::None);
+        assert_eq!(miss(&mut watchdog, 1_020_000), WatchdogAction::Reinject);
+    }
+
+    #[test]
+    fn test_dnd_window_skips_trigger() {
+        use crate::settings::{DndWindow, TauriSettings};
+        use chrono::Datelike;
+
+        let now = Utc::now();
+        let mut state = DaemonState::default();
+        state.update_meetings(vec![
+            create_test_meeting("abc", "Standup", 10),
+            create_test_meeting("xyz", "Review", 60),
+        ]);
+        // Ten minutes around the Standup trigger, 9 minutes from now
+        let from = (now + Duration::minutes(4)).with_timezone(&Local);
+        let to = (now + Duration::minutes(14)).with_timezone(&Local);
+        let mut settings = Settings {
+            join_before_minutes: 1,
+            max_minutes_after_start: 2,
+            tauri: Some(TauriSettings {
+                dnd_windows: vec![DndWindow {
+                    weekday: from.weekday(),
+                    start: from.format("%H:%M").to_string(),
+                    end: to.format("%H:%M").to_string(),
+                }],
+                ..TauriSettings::default()
+            }),
+            ..Settings::default()
+        };
+
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.meeting.call_id, "xyz");
+        assert!(state
+            .should_join_now_at(
+                &settings,
+                &TitleFilters::from_settings(&settings),
+                now + Duration::minutes(9)
+            )
+            .is_none());
+
+        settings.tauri.as_mut().unwrap().dnd_windows.clear();
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.meeting.call_id, "abc");
+    }
+
+    #[test]
+    fn test_auto_join_hours_skip_meetings_outside() {
+        use crate::settings::{AutoJoinHours, TauriSettings};
+        use chrono::Weekday;
+
+        let now = Utc::now();
+        let mut state = DaemonState::default();
+        state.update_meetings(vec![
+            create_test_meeting("abc", "Early sync", 10),
+            create_test_meeting("xyz", "Review", 60),
+        ]);
+        // Hours from 30 to 90 minutes from now, every day
+        let from = (now + Duration::minutes(30)).with_timezone(&Local);
+        let to = (now + Duration::minutes(90)).with_timezone(&Local);
+        let mut settings = Settings {
+            join_before_minutes: 1,
+            max_minutes_after_start: 2,
+            tauri: Some(TauriSettings {
+                auto_join_hours: Some(AutoJoinHours {
+                    start: from.format("%H:%M").to_string(),
+                    end: to.format("%H:%M").to_string(),
+                    weekdays: vec![
+                        Weekday::Mon,
+                        Weekday::Tue,
+                        Weekday::Wed,
+                        Weekday::Thu,
+                        Weekday::Fri,
+                        Weekday::Sat,
+                        Weekday::Sun,
+                    ],
+                }),
+                ..TauriSettings::default()
+            }),
+            ..Settings::default()
+        };
+
+        let meetings = state.get_meetings();
+        let early = meetings.iter().find(|m| m.call_id == "abc").unwrap();
+        assert!(outside_auto_join_hours(early, &settings));
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.meeting.call_id, "xyz");
+        assert!(state
+            .should_join_now_at(
+                &settings,
+                &TitleFilters::from_settings(&settings),
+                now + Duration::minutes(9)
+            )
+            .is_none());
+
+        settings.tauri.as_mut().unwrap().auto_join_hours = None;
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.meeting.call_id, "abc");
+        assert!(!outside_auto_join_hours(early, &settings));
+    }
+
+    #[test]
+    fn test_busy_blocks_delay_or_hold_joins() {
+        use crate::settings::{BusyBlockRule, TauriSettings, TitleMatchMode};
+
+        let now = Utc::now();
+        let now_ms = now.timestamp_millis();
+        let mut state = DaemonState::default();
+        state.update_meetings(vec![create_test_meeting("abc-defg-hij", "Standup", 10)]);
+        let block = |title: &str, from: i64, to: i64| BusyBlock {
+            title: title.to_string(),
+            begin_time: now + Duration::minutes(from),
+            end_time: now + Duration::minutes(to),
+        };
+        state.update_busy_blocks(
+            MeetingSource::Ics,
+            vec![block("Focus time", 0, 10), block("Focus time", 10, 12)],
+        );
+        let mut settings = Settings {
+            join_before_minutes: 1,
+            max_minutes_after_start: 10,
+            tauri: Some(TauriSettings {
+                busy_block_rules: vec![BusyBlockRule {
+                    pattern: "Focus".to_string(),
+                    match_mode: TitleMatchMode::Contains,
+                    policy: BusyBlockPolicy::Delay,
+                }],
+                ..TauriSettings::default()
+            }),
+            ..Settings::default()
+        };
+
+        // Back-to-back blocks push the join to the end of the second one
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.delay_ms, 12 * 60 * 1000);
+        let hold = trigger.busy_hold.unwrap();
+        assert_eq!(hold.block_end, now + Duration::minutes(12));
+        let explanation = state
+            .explain_join_at(
+                "abc-defg-hij",
+                &settings,
+                &TitleFilters::from_settings(&settings),
+                now,
+            )
+            .unwrap();
+        assert_eq!(explanation.scheduled_at_ms, now_ms + 9 * 60 * 1000);
+        assert_eq!(explanation.trigger_at_ms, Some(now_ms + 12 * 60 * 1000));
+        assert_eq!(
+            explanation.busy_hold.unwrap().policy,
+            BusyBlockPolicy::Delay
+        );
+
+        // A block running past the join window skips the meeting
+        state.update_busy_blocks(MeetingSource::Ics, vec![block("Focus time", 0, 30)]);
+        assert!(state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .is_none());
+
+        // "Confirm" keeps the trigger time and waits for the user once fired
+        settings.tauri.as_mut().unwrap().busy_block_rules[0].policy = BusyBlockPolicy::Confirm;
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.delay_ms, 9 * 60 * 1000);
+        assert_eq!(trigger.busy_hold.unwrap().policy, BusyBlockPolicy::Confirm);
+        state.request_busy_confirmation("abc-defg-hij");
+        assert!(state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .is_none());
+        state.confirm_busy_join("abc-defg-hij");
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert!(trigger.busy_hold.is_none());
+    }
+
+    #[test]
+    fn test_cancel_join_before_trigger_time() {
+        let now = Utc::now();
+        let mut state = DaemonState::default();
+        state.update_meetings(vec![
+            create_test_meeting("abc-defg-hij", "Standup", 10),
+            create_test_meeting("xyz-uvwx-rst", "Review", 30),
+        ]);
+        let settings = Settings {
+            join_before_minutes: 1,
+            ..Settings::default()
+        };
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.meeting.call_id, "abc-defg-hij");
+
+        state
+            .cancel_join("abc-defg-hij", now.timestamp_millis())
+            .unwrap();
+        assert!(state.is_join_cancelled("abc-defg-hij"));
+        assert_eq!(
+            state.meeting_phase("abc-defg-hij"),
+            Some(MeetingPhase::Suppressed)
+        );
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.meeting.call_id, "xyz-uvwx-rst");
+        assert_eq!(
+            state
+                .get_next_meeting(&settings, &TitleFilters::from_settings(&settings))
+                .unwrap()
+                .call_id,
+            "xyz-uvwx-rst"
+        );
+
+        let snapshot = state
+            .take_snapshot_if_dirty(now.timestamp_millis())
+            .unwrap();
+        let restored = DaemonState::restore(snapshot, now);
+        assert!(restored.is_join_cancelled("abc-defg-hij"));
+    }
+
+    #[test]
+    fn test_calculate_next_trigger_immediate() {
+        let mut state = DaemonState::default();
+        // Meeting that started 5 minutes ago
+        let meetings = vec![create_test_meeting("abc", "Test Meeting", -5)];
+        state.update_meetings(meetings);
+
+        let settings = Settings {
+            join_before_minutes: 1,
+            ..Settings::default()
+        };
+
+        let trigger =
+            state.calculate_next_trigger(&settings, &TitleFilters::from_settings(&settings));
+        assert!(trigger.is_some());
+        // Should trigger immediately
+        assert_eq!(trigger.unwrap().delay_ms, 0);
+    }
+
+    #[test]
+    fn test_calculate_next_trigger_excludes_joined() {
+        let mut state = DaemonState::default();
+        let meetings = vec![
+            create_test_meeting("joined", "Already Joined", 5),
+            create_test_meeting("pending", "Pending Meeting", 10),
+        ];
+        state.update_meetings(meetings);
+        state
+            .mark_joined("joined", Utc::now().timestamp_millis())
+            .unwrap();
+
+        let settings = Settings::default();
+
+        let trigger =
+            state.calculate_next_trigger(&settings, &TitleFilters::from_settings(&settings));
+        assert!(trigger.is_some());
+        assert_eq!(trigger.unwrap().meeting.call_id, "joined");
+    }
+
+    #[test]
+    fn test_calculate_next_trigger_respects_exclude_filters() {
+        let mut state = DaemonState::default();
+        let meetings = vec![
+            create_test_meeting("optional", "Optional: Team Sync", 5),
+            create_test_meeting("required", "Sprint Planning", 10),
+        ];
+        state.update_meetings(meetings);
+
+        let settings = Settings {
+            title_exclude_filters: vec!["Optional".to_string()],
+            ..Settings::default()
+        };
+
+        let trigger =
+            state.calculate_next_trigger(&settings, &TitleFilters::from_settings(&settings));
+        assert!(trigger.is_some());
+        assert_eq!(trigger.unwrap().meeting.call_id, "required");
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use chrono::Duration;
+    fn at(rfc3339: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(rfc3339)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
 
-    fn create_test_meeting(call_id: &str, title: &str, starts_in_minutes: i64) -> Meeting {
-        let now = Utc::now();
+    fn meeting_between(call_id: &str, begin: &str, end: &str, now: DateTime<Utc>) -> Meeting {
+        let begin_time = at(begin);
         Meeting {
             call_id: call_id.to_string(),
             url: format!("https://meet.google.com/{}", call_id),
-            title: title.to_string(),
-            display_time: "10:00 AM".to_string(),
-            begin_time: now + Duration::minutes(starts_in_minutes),
-            end_time: now + Duration::minutes(starts_in_minutes + 60),
-            event_id: Some("event123".to_string()),
-            starts_in_minutes,
+            title: "DST Meeting".to_string(),
+            display_time: "3:30 AM".to_string(),
+            begin_time,
+            end_time: at(end),
+            event_id: None,
+            starts_in_minutes: (begin_time - now).num_minutes(),
+            profile: None,
+            accuracy: TimeAccuracy::Exact,
         }
     }
 
     #[test]
-    fn test_daemon_state() {
-        let mut state = DaemonState::default();
-        assert!(!state.is_running());
+    fn test_calculate_next_trigger_across_spring_forward() {
+        // US spring forward: 01:50 EST is 40 real minutes before 03:30 EDT,
+        // although the wall clock shows 100 minutes apart.
+        let now = at("2024-03-10T01:50:00-05:00");
+        let state = DaemonState {
+            meetings: vec![meeting_between(
+                "dst",
+                "2024-03-10T03:30:00-04:00",
+                "2024-03-10T04:00:00-04:00",
+                now,
+            )]
+            .into(),
+            ..DaemonState::default()
+        };
 
-        state.start();
-        assert!(state.is_running());
+        let settings = Settings {
+            join_before_minutes: 1,
+            ..Settings::default()
+        };
 
-        state.stop();
-        assert!(!state.is_running());
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.delay_ms, 39 * 60 * 1000);
     }
 
     #[test]
-    fn test_joined_tracking() {
-        let mut state = DaemonState::default();
+    fn test_calculate_next_trigger_across_fall_back() {
+        // US fall back: 01:50 EDT is 10 real minutes before the repeated 01:00 EST,
+        // although the wall clock moves backwards.
+        let now = at("2024-11-03T01:50:00-04:00");
+        let state = DaemonState {
+            meetings: vec![meeting_between(
+                "dst",
+                "2024-11-03T01:00:00-05:00",
+                "2024-11-03T01:30:00-05:00",
+                now,
+            )]
+            .into(),
+            ..DaemonState::default()
+        };
 
-        state.mark_joined("abc-defg-hij");
-        assert!(state.joined_meetings.contains("abc-defg-hij"));
+        let settings = Settings {
+            join_before_minutes: 1,
+            ..Settings::default()
+        };
 
-        state.clear_joined();
-        assert!(state.joined_meetings.is_empty());
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.delay_ms, 9 * 60 * 1000);
+        assert!(state
+            .should_join_now_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .is_none());
     }
 
     #[test]
-    fn test_update_meetings() {
-        let mut state = DaemonState::default();
-        assert!(state.get_meetings().is_empty());
+    fn test_should_join_now_ignores_stale_starts_in_minutes() {
+        let now = at("2024-03-10T03:29:30-04:00");
+        let mut meeting = meeting_between(
+            "dst",
+            "2024-03-10T03:30:00-04:00",
+            "2024-03-10T04:00:00-04:00",
+            now,
+        );
+        // Cached value computed an hour earlier on the other side of the transition
+        meeting.starts_in_minutes = 100;
+        let state = DaemonState {
+            meetings: vec![meeting].into(),
+            ..DaemonState::default()
+        };
 
-        let meetings = vec![
-            create_test_meeting("abc-defg-hij", "Team Standup", 5),
-            create_test_meeting("xyz-uvwx-rst", "1:1 Meeting", 30),
-        ];
-        state.update_meetings(meetings);
+        let settings = Settings {
+            join_before_minutes: 1,
+            ..Settings::default()
+        };
 
-        assert_eq!(state.get_meetings().len(), 2);
+        assert!(state
+            .should_join_now_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .is_some());
     }
 
     #[test]
-    fn test_get_next_meeting_returns_earliest() {
-        let mut state = DaemonState::default();
-        let meetings = vec![
-            create_test_meeting("later", "Later Meeting", 30),
-            create_test_meeting("soon", "Soon Meeting", 5),
-            create_test_meeting("soonest", "Soonest Meeting", 2),
-        ];
-        state.update_meetings(meetings);
+    fn test_starts_in_minutes_at_floors_like_webview() {
+        let now = at("2024-03-10T01:50:00-05:00");
+        let meeting = meeting_between(
+            "dst",
+            "2024-03-10T03:30:00-04:00",
+            "2024-03-10T04:00:00-04:00",
+            now,
+        );
+        assert_eq!(meeting.starts_in_minutes_at(now), 40);
+        assert_eq!(
+            meeting.starts_in_minutes_at(now + Duration::seconds(90)),
+            38
+        );
+        assert_eq!(
+            meeting.starts_in_minutes_at(at("2024-03-10T03:30:30-04:00")),
+            -1
+        );
+    }
 
-        let next = state.get_next_meeting(&Settings::default());
-        assert!(next.is_some());
-        assert_eq!(next.unwrap().call_id, "soonest");
+    #[test]
+    fn test_offset_change_on_fixed_offset_is_none() {
+        let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        assert_eq!(offset_change_on(&tz, date), None);
+        assert_eq!(offset_change_on(&Utc, date), None);
     }
 
     #[test]
-    fn test_get_next_meeting_excludes_joined() {
+    fn test_calculate_next_trigger_applies_meeting_rule_countdown() {
         let mut state = DaemonState::default();
-        let meetings = vec![
-            create_test_meeting("first", "First Meeting", -2),
-            create_test_meeting("second", "Second Meeting", 5),
-        ];
-        state.update_meetings(meetings);
-        state.mark_joined("first");
+        state.update_meetings(vec![create_test_meeting("abc", "Daily Standup", 10)]);
 
-        let next = state.get_next_meeting(&Settings::default());
-        assert!(next.is_some());
-        assert_eq!(next.unwrap().call_id, "second");
+        let mut settings = Settings {
+            join_countdown_seconds: 20,
+            ..Settings::default()
+        };
+        assert_eq!(
+            state
+                .calculate_next_trigger(&settings, &TitleFilters::from_settings(&settings))
+                .unwrap()
+                .countdown_seconds,
+            20
+        );
+
+        settings.tauri.as_mut().unwrap().meeting_rules = vec![
+            MeetingRule {
+                pattern: "Standup".to_string(),
+                match_mode: TitleMatchMode::Contains,
+                auto_join: None,
+                join_before_minutes: None,
+                mic_state: None,
+                camera_state: None,
+                auto_click_join: None,
+                countdown_seconds: Some(0),
+            },
+            MeetingRule {
+                pattern: "Daily".to_string(),
+                match_mode: TitleMatchMode::Contains,
+                auto_join: None,
+                join_before_minutes: None,
+                mic_state: None,
+                camera_state: None,
+                auto_click_join: None,
+                countdown_seconds: Some(30),
+            },
+        ];
+        assert_eq!(
+            state
+                .calculate_next_trigger(&settings, &TitleFilters::from_settings(&settings))
+                .unwrap()
+                .countdown_seconds,
+            0
+        );
     }
 
     #[test]
-    fn test_get_next_meeting_allows_joined_before_start() {
+    fn test_calculate_next_trigger_applies_meeting_rule_auto_click() {
         let mut state = DaemonState::default();
-        let meetings = vec![create_test_meeting("first", "First Meeting", 5)];
-        state.update_meetings(meetings);
-        state.mark_joined("first");
+        state.update_meetings(vec![create_test_meeting("abc", "Panel Interview", 10)]);
 
-        let next = state.get_next_meeting(&Settings::default());
-        assert!(next.is_some());
-        assert_eq!(next.unwrap().call_id, "first");
+        let mut settings = Settings::default();
+        assert_eq!(
+            state
+                .calculate_next_trigger(&settings, &TitleFilters::from_settings(&settings))
+                .unwrap()
+                .auto_click_join,
+            None
+        );
+
+        settings.tauri.as_mut().unwrap().meeting_rules = vec![
+            MeetingRule {
+                pattern: "Panel".to_string(),
+                match_mode: TitleMatchMode::Contains,
+                auto_join: None,
+                join_before_minutes: None,
+                mic_state: None,
+                camera_state: None,
+                auto_click_join: Some(false),
+                countdown_seconds: None,
+            },
+            MeetingRule {
+                pattern: "Interview".to_string(),
+                match_mode: TitleMatchMode::Contains,
+                auto_join: None,
+                join_before_minutes: None,
+                mic_state: None,
+                camera_state: None,
+                auto_click_join: Some(true),
+                countdown_seconds: None,
+            },
+        ];
+        assert_eq!(
+            state
+                .calculate_next_trigger(&settings, &TitleFilters::from_settings(&settings))
+                .unwrap()
+                .auto_click_join,
+            Some(false)
+        );
     }
 
     #[test]
-    fn test_get_next_meeting_skips_suppressed_after_trigger() {
+    fn test_meeting_rules_override_join_and_media() {
+        let now = Utc::now();
         let mut state = DaemonState::default();
-        let meetings = vec![create_test_meeting("first", "First Meeting", 1)];
-        state.update_meetings(meetings);
-        state.mark_suppressed("first", Utc::now().timestamp_millis());
+        state.update_meetings(vec![
+            create_test_meeting("one", "1:1 with Sam", 3),
+            create_test_meeting("standup", "Daily Standup", 8),
+        ]);
 
-        let settings = Settings {
-            join_before_minutes: 2,
+        let mut settings = Settings {
+            join_before_minutes: 1,
+            title_exclude_filters: vec!["Standup".to_string()],
             ..Settings::default()
         };
+        settings.tauri.as_mut().unwrap().meeting_rules = vec![
+            MeetingRule {
+                pattern: "1:1".to_string(),
+                match_mode: TitleMatchMode::Contains,
+                auto_join: Some(false),
+                join_before_minutes: None,
+                mic_state: None,
+                camera_state: None,
+                auto_click_join: None,
+                countdown_seconds: None,
+            },
+            MeetingRule {
+                pattern: "^Daily Standup$".to_string(),
+                match_mode: TitleMatchMode::Regex,
+                auto_join: Some(true),
+                join_before_minutes: Some(10),
+                mic_state: Some(MediaState::Unmuted),
+                camera_state: None,
+                auto_click_join: None,
+                countdown_seconds: None,
+            },
+        ];
 
-        let next = state.get_next_meeting(&settings);
-        assert!(next.is_none());
+        // The 1:1 is never auto-joined; the standup overrides the exclude
+        // filter and is joined 10 minutes early
+        let joinable = state
+            .should_join_now_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(joinable.call_id, "standup");
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.meeting.call_id, "standup");
+        assert_eq!(trigger.delay_ms, 0);
+
+        let join_settings = settings_for_meeting(
+            &trigger.meeting,
+            &settings,
+            &TitleFilters::from_settings(&settings),
+        );
+        assert_eq!(join_settings.join_before_minutes, 10);
+        assert_eq!(join_settings.default_mic_state, MediaState::Unmuted);
+        assert_eq!(join_settings.default_camera_state, MediaState::Muted);
+
+        // The preview reports the keyword and the rule that overrides it
+        let preview = preview_title_filters(
+            "Daily Standup",
+            &settings,
+            &TitleFilters::from_settings(&settings),
+        );
+        assert!(preview.auto_join);
+        assert_eq!(preview.matched_keywords, vec!["Standup".to_string()]);
+        assert_eq!(preview.meeting_rule, Some(1));
+        assert!(
+            !preview_title_filters(
+                "1:1 with Sam",
+                &settings,
+                &TitleFilters::from_settings(&settings)
+            )
+            .auto_join
+        );
     }
 
     #[test]
-    fn test_suppressed_meeting_does_not_trigger() {
+    fn test_out_of_office_pauses_triggers() {
+        let now = Utc::now();
         let mut state = DaemonState::default();
-        let meetings = vec![create_test_meeting("first", "First Meeting", 1)];
-        state.update_meetings(meetings);
-        state.mark_suppressed("first", Utc::now().timestamp_millis());
+        let mut ooo = create_test_meeting("ooo", "OOO - Vacation", -120);
+        ooo.end_time = now + Duration::hours(12);
+        state.update_meetings(vec![ooo, create_test_meeting("abc", "Team Standup", 5)]);
 
-        let settings = Settings {
-            join_before_minutes: 2,
-            ..Settings::default()
-        };
+        assert_eq!(state.refresh_out_of_office(false, now), None);
+        assert!(state
+            .calculate_next_trigger_at(
+                &Settings::default(),
+                &TitleFilters::from_settings(&Settings::default()),
+                now
+            )
+            .is_some());
 
-        let trigger = state.calculate_next_trigger(&settings);
-        assert!(trigger.is_none());
+        assert_eq!(
+            state.refresh_out_of_office(true, now),
+            Some(OutOfOfficeChange::Paused {
+                until: now + Duration::hours(12)
+            })
+        );
+        assert!(state
+            .calculate_next_trigger_at(
+                &Settings::default(),
+                &TitleFilters::from_settings(&Settings::default()),
+                now
+            )
+            .is_none());
+        assert!(state
+            .should_join_now_at(
+                &Settings::default(),
+                &TitleFilters::from_settings(&Settings::default()),
+                now
+            )
+            .is_none());
+
+        // Unchanged on repeated refresh, even if the OOO event disappears from the list
+        state.update_meetings(vec![create_test_meeting("abc", "Team Standup", 5)]);
+        assert_eq!(state.refresh_out_of_office(true, now), None);
+        assert!(state.out_of_office_until(now).is_some());
+
+        // Resumes once the pause expires
+        let later = now + Duration::hours(13);
+        assert_eq!(
+            state.refresh_out_of_office(true, later),
+            Some(OutOfOfficeChange::Resumed)
+        );
     }
 
     #[test]
-    fn test_get_next_meeting_excludes_old_meetings() {
+    fn test_auto_join_rate_cap() {
+        let now = Utc::now();
+        let now_ms = now.timestamp_millis();
         let mut state = DaemonState::default();
-        // Meeting that started 10 minutes ago (beyond the 5-minute grace period)
-        let meetings = vec![create_test_meeting("old", "Old Meeting", -10)];
-        state.update_meetings(meetings);
+        state.update_meetings(vec![create_test_meeting("abc", "Team Standup", 0)]);
 
-        let next = state.get_next_meeting(&Settings::default());
-        assert!(next.is_none());
+        assert!(state.try_record_auto_join(now_ms, 2));
+        assert!(state.try_record_auto_join(now_ms + 1_000, 2));
+        assert!(!state.try_record_auto_join(now_ms + 2_000, 2));
+        assert!(state.is_join_rate_capped());
+        assert!(state
+            .calculate_next_trigger_at(
+                &Settings::default(),
+                &TitleFilters::from_settings(&Settings::default()),
+                now
+            )
+            .is_none());
+
+        // Stays capped after the window slides until the user confirms
+        assert!(!state.try_record_auto_join(now_ms + AUTO_JOIN_WINDOW_MS * 2, 2));
+
+        state.reset_join_rate_cap();
+        assert!(!state.is_join_rate_capped());
+        assert!(state.try_record_auto_join(now_ms + 3_000, 2));
+        assert!(state
+            .calculate_next_trigger_at(
+                &Settings::default(),
+                &TitleFilters::from_settings(&Settings::default()),
+                now
+            )
+            .is_some());
     }
 
     #[test]
-    fn test_should_join_now_within_window() {
+    fn test_auto_join_rate_cap_rolling_window() {
         let mut state = DaemonState::default();
-        // Meeting starting in 1 minute, with joinBeforeMinutes = 1
-        let meetings = vec![create_test_meeting("abc", "Test Meeting", 1)];
-        state.update_meetings(meetings);
+        assert!(state.try_record_auto_join(0, 1));
+        assert_eq!(state.auto_joins_in_window(1), 1);
+        assert!(state.try_record_auto_join(AUTO_JOIN_WINDOW_MS, 1));
+        assert!(!state.is_join_rate_capped());
 
-        let settings = Settings {
-            join_before_minutes: 1,
-            ..Settings::default()
-        };
+        // 0 disables the cap
+        for i in 0..100 {
+            assert!(state.try_record_auto_join(AUTO_JOIN_WINDOW_MS + i, 0));
+        }
+    }
 
-        let should_join = state.should_join_now(&settings);
-        assert!(should_join.is_some());
-        assert_eq!(should_join.unwrap().call_id, "abc");
+    #[test]
+    fn test_upcoming_meetings_sorted_and_filtered() {
+        let now = Utc::now();
+        let mut state = DaemonState::default();
+        state.update_meetings(vec![
+            create_test_meeting("later", "Later", 60),
+            create_test_meeting("started", "Started long ago", -30),
+            create_test_meeting("soon", "Soon", 5),
+            create_test_meeting("just-started", "Just started", -2),
+        ]);
+
+        let ids: Vec<&str> = state
+            .upcoming_meetings_at(now)
+            .into_iter()
+            .map(|m| m.call_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["just-started", "soon", "later"]);
     }
 
     #[test]
-    fn test_should_join_now_not_yet() {
+    fn test_find_conflicts_groups_chained_overlaps() {
+        let now = at("2026-03-02T09:00:00Z");
         let mut state = DaemonState::default();
-        // Meeting starting in 10 minutes, with joinBeforeMinutes = 1
-        let meetings = vec![create_test_meeting("abc", "Test Meeting", 10)];
-        state.update_meetings(meetings);
+        state.update_meetings(vec![
+            meeting_between("c", "2026-03-02T11:15:00Z", "2026-03-02T12:00:00Z", now),
+            meeting_between("a", "2026-03-02T10:00:00Z", "2026-03-02T11:00:00Z", now),
+            meeting_between("b", "2026-03-02T10:30:00Z", "2026-03-02T11:30:00Z", now),
+            meeting_between("alone", "2026-03-02T13:00:00Z", "2026-03-02T13:30:00Z", now),
+            meeting_between("e", "2026-03-02T14:00:00Z", "2026-03-02T14:30:00Z", now),
+            meeting_between("f", "2026-03-02T14:30:00Z", "2026-03-02T15:00:00Z", now),
+        ]);
 
-        let settings = Settings {
-            join_before_minutes: 1,
+        let groups = state.find_conflicts_at(
+            &Settings::default(),
+            &TitleFilters::from_settings(&Settings::default()),
+            now,
+            Duration::hours(24),
+        );
+        assert_eq!(groups.len(), 1);
+        let group = &groups[0];
+        assert_eq!(group.start, at("2026-03-02T10:00:00Z"));
+        assert_eq!(group.end, at("2026-03-02T12:00:00Z"));
+        assert_eq!(group.overlap_ms, 45 * 60 * 1000);
+
+        let members: Vec<(&str, ConflictDecision, i64)> = group
+            .members
+            .iter()
+            .map(|m| {
+                (
+                    m.meeting.call_id.as_str(),
+                    m.decision,
+                    m.overlap_ms / 60_000,
+                )
+            })
+            .collect();
+        assert_eq!(
+            members,
+            vec![
+                ("a", ConflictDecision::Planned, 30),
+                ("b", ConflictDecision::Queued, 45),
+                ("c", ConflictDecision::Queued, 15),
+            ]
+        );
+
+        assert!(state
+            .find_conflicts_at(
+                &Settings::default(),
+                &TitleFilters::from_settings(&Settings::default()),
+                now,
+                Duration::minutes(45)
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn test_trigger_schedule_reports_reason_and_holds() {
+        let now = at("2026-03-02T09:00:00Z");
+        let now_ms = now.timestamp_millis();
+        let mut state = DaemonState::default();
+        state.update_meetings(vec![
+            meeting_between("late", "2026-03-02T08:55:00Z", "2026-03-02T09:30:00Z", now),
+            meeting_between("next", "2026-03-02T10:00:00Z", "2026-03-02T10:30:00Z", now),
+        ]);
+        let settings = Settings::default();
+
+        let next = state
+            .trigger_schedule_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .next
+            .unwrap();
+        assert_eq!(next.call_id, "late");
+        assert_eq!(next.reason, TriggerReason::JoinWindow);
+        assert_eq!((next.trigger_at_ms, next.delay_ms), (now_ms, 0));
+
+        state.cancel_join("late", now_ms).unwrap();
+        let schedule =
+            state.trigger_schedule_at(&settings, &TitleFilters::from_settings(&settings), now);
+        let next = schedule.next.unwrap();
+        assert_eq!(next.call_id, "next");
+        assert_eq!(next.reason, TriggerReason::Scheduled);
+        assert_eq!(
+            next.trigger_at_ms,
+            at("2026-03-02T09:59:00Z").timestamp_millis()
+        );
+        assert_eq!(schedule.cancelled, vec!["late"]);
+        assert_eq!(schedule.suppressed, vec!["late"]);
+
+        let snooze_end_ms = at("2026-03-02T10:03:00Z").timestamp_millis();
+        state.snooze("next", snooze_end_ms);
+        let schedule =
+            state.trigger_schedule_at(&settings, &TitleFilters::from_settings(&settings), now);
+        let next = schedule.next.unwrap();
+        assert_eq!(next.reason, TriggerReason::Snoozed);
+        assert_eq!(next.trigger_at_ms, snooze_end_ms);
+        assert_eq!(
+            schedule.snoozed_until_ms,
+            BTreeMap::from([("next".to_string(), snooze_end_ms)])
+        );
+
+        let paused_until = at("2026-03-02T09:30:00Z");
+        state.pause_until(paused_until);
+        let schedule =
+            state.trigger_schedule_at(&settings, &TitleFilters::from_settings(&settings), now);
+        assert!(schedule.next.is_none());
+        assert_eq!(
+            schedule.paused_until_ms,
+            Some(paused_until.timestamp_millis())
+        );
+    }
+
+    #[test]
+    fn test_conflict_policy_picks_among_overlapping_triggers() {
+        use crate::settings::TauriSettings;
+
+        let now = at("2026-03-02T09:00:00Z");
+        let mut state = DaemonState::default();
+        state.update_meetings(vec![
+            meeting_between("long", "2026-03-02T10:00:00Z", "2026-03-02T11:00:00Z", now),
+            meeting_between("short", "2026-03-02T10:05:00Z", "2026-03-02T10:30:00Z", now),
+            meeting_between("later", "2026-03-02T12:00:00Z", "2026-03-02T12:30:00Z", now),
+        ]);
+        let with_policy = |conflict_policy| Settings {
+            tauri: Some(TauriSettings {
+                conflict_policy,
+                ..TauriSettings::default()
+            }),
             ..Settings::default()
         };
+        let next = |state: &DaemonState, settings: &Settings| {
+            let trigger = state
+                .calculate_next_trigger_at(settings, &TitleFilters::from_settings(settings), now)
+                .unwrap();
+            let conflict: Vec<String> = trigger.conflict.into_iter().map(|m| m.call_id).collect();
+            (trigger.meeting.call_id, conflict)
+        };
 
-        let should_join = state.should_join_now(&settings);
-        assert!(should_join.is_none());
+        let first = with_policy(ConflictPolicy::FirstScheduled);
+        assert_eq!(next(&state, &first), ("long".to_string(), vec![]));
+        let shortest = with_policy(ConflictPolicy::Shortest);
+        assert_eq!(next(&state, &shortest), ("short".to_string(), vec![]));
+        let planned: Vec<String> = state.find_conflicts_at(
+            &shortest,
+            &TitleFilters::from_settings(&shortest),
+            now,
+            Duration::hours(4),
+        )[0]
+        .members
+        .iter()
+        .filter(|m| m.decision == ConflictDecision::Planned)
+        .map(|m| m.meeting.call_id.clone())
+        .collect();
+        assert_eq!(planned, vec!["short"]);
+
+        let prompt = with_policy(ConflictPolicy::PromptUser);
+        let (call_id, conflict) = next(&state, &prompt);
+        assert_eq!(call_id, "long");
+        assert_eq!(conflict, vec!["long", "short"]);
+
+        // While the user hasn't picked, neither meeting is joined
+        state.request_conflict_choice(&conflict);
+        assert_eq!(next(&state, &prompt), ("later".to_string(), vec![]));
+        assert!(!state.choose_conflict_winner("later"));
+
+        assert!(state.choose_conflict_winner("short"));
+        assert_eq!(next(&state, &prompt), ("short".to_string(), vec![]));
+        let groups = state.find_conflicts_at(
+            &prompt,
+            &TitleFilters::from_settings(&prompt),
+            now,
+            Duration::hours(4),
+        );
+        let decisions: Vec<ConflictDecision> =
+            groups[0].members.iter().map(|m| m.decision).collect();
+        assert_eq!(
+            decisions,
+            vec![ConflictDecision::NotChosen, ConflictDecision::Planned]
+        );
     }
 
     #[test]
-    fn test_should_join_now_respects_exclude_filters() {
+    fn test_find_conflicts_decisions_follow_join_filters() {
+        let now = at("2026-03-02T10:40:00Z");
         let mut state = DaemonState::default();
-        let meetings = vec![
-            create_test_meeting("skip", "1:1 with Manager", 1),
-            create_test_meeting("join", "Team Standup", 2),
-        ];
-        state.update_meetings(meetings);
-
+        let mut excluded =
+            meeting_between("a", "2026-03-02T10:00:00Z", "2026-03-02T11:00:00Z", now);
+        excluded.title = "Team standup".to_string();
+        state.update_meetings(vec![
+            excluded,
+            meeting_between("b", "2026-03-02T10:30:00Z", "2026-03-02T11:30:00Z", now),
+            meeting_between("c", "2026-03-02T10:45:00Z", "2026-03-02T11:15:00Z", now),
+        ]);
+        state
+            .mark_joined("b", Utc::now().timestamp_millis())
+            .unwrap();
         let settings = Settings {
-            join_before_minutes: 5,
-            title_exclude_filters: vec!["1:1".to_string()],
+            title_exclude_filters: vec!["standup".to_string()],
             ..Settings::default()
         };
 
-        let should_join = state.should_join_now(&settings);
-        assert!(should_join.is_some());
-        assert_eq!(should_join.unwrap().call_id, "join");
+        let groups = state.find_conflicts_at(
+            &settings,
+            &TitleFilters::from_settings(&settings),
+            now,
+            Duration::hours(1),
+        );
+        let decisions: Vec<ConflictDecision> =
+            groups[0].members.iter().map(|m| m.decision).collect();
+        assert_eq!(
+            decisions,
+            vec![
+                ConflictDecision::Excluded,
+                ConflictDecision::Joined,
+                ConflictDecision::Planned,
+            ]
+        );
     }
 
     #[test]
-    fn test_should_join_now_after_start_within_grace() {
+    fn test_agenda_view_groups_around_now() {
+        let now = at("2026-03-02T10:40:00Z");
         let mut state = DaemonState::default();
-        // Meeting that started 5 minutes ago (within grace period)
-        let meetings = vec![create_test_meeting("abc", "Test Meeting", -5)];
-        state.update_meetings(meetings);
-
+        let mut excluded =
+            meeting_between("e", "2026-03-02T15:00:00Z", "2026-03-02T15:30:00Z", now);
+        excluded.title = "Team standup".to_string();
+        state.update_meetings(vec![
+            meeting_between("tmrw", "2026-03-03T09:00:00Z", "2026-03-03T09:30:00Z", now),
+            meeting_between("later", "2026-03-02T14:00:00Z", "2026-03-02T14:30:00Z", now),
+            meeting_between("next", "2026-03-02T11:00:00Z", "2026-03-02T11:30:00Z", now),
+            excluded,
+            meeting_between("long", "2026-03-02T08:00:00Z", "2026-03-02T12:00:00Z", now),
+            meeting_between("past", "2026-03-02T09:00:00Z", "2026-03-02T09:30:00Z", now),
+            meeting_between("old", "2026-03-01T09:00:00Z", "2026-03-01T09:30:00Z", now),
+            meeting_between("week", "2026-03-05T09:00:00Z", "2026-03-05T09:30:00Z", now),
+        ]);
         let settings = Settings {
-            join_before_minutes: 1,
+            title_exclude_filters: vec!["standup".to_string()],
             ..Settings::default()
         };
 
-        let should_join = state.should_join_now(&settings);
-        assert!(should_join.is_some());
+        let view = state.agenda_view_at(
+            &settings,
+            &TitleFilters::from_settings(&settings),
+            now,
+            &Utc,
+        );
+        let items: Vec<(&str, AgendaGroup, ConflictDecision)> = view
+            .items
+            .iter()
+            .map(|item| (item.meeting.call_id.as_str(), item.group, item.decision))
+            .collect();
+        assert_eq!(
+            items,
+            vec![
+                ("past", AgendaGroup::Past, ConflictDecision::Missed),
+                ("long", AgendaGroup::InProgress, ConflictDecision::Missed),
+                ("next", AgendaGroup::Next, ConflictDecision::Planned),
+                ("later", AgendaGroup::LaterToday, ConflictDecision::Queued),
+                ("e", AgendaGroup::LaterToday, ConflictDecision::Excluded),
+                ("tmrw", AgendaGroup::Tomorrow, ConflictDecision::Queued),
+            ]
+        );
+        assert_eq!(view.now_index, 2);
+
+        state.pause_until(now + Duration::hours(1));
+        let paused = state.agenda_view_at(
+            &settings,
+            &TitleFilters::from_settings(&settings),
+            now,
+            &Utc,
+        );
+        assert!(paused
+            .items
+            .iter()
+            .all(|item| item.decision != ConflictDecision::Planned));
     }
 
     #[test]
-    fn test_should_join_now_too_late() {
+    fn test_inexact_times_are_never_auto_joined() {
+        let now = at("2026-03-02T10:40:00Z");
         let mut state = DaemonState::default();
-        // Meeting that started 35 minutes ago (beyond grace period)
-        let meetings = vec![create_test_meeting("abc", "Test Meeting", -35)];
-        state.update_meetings(meetings);
-
+        let mut inferred =
+            meeting_between("a", "2026-03-02T10:41:00Z", "2026-03-02T11:00:00Z", now);
+        inferred.accuracy = TimeAccuracy::Inferred;
+        let mut unknown = meeting_between("b", "2026-03-02T10:40:00Z", "2026-03-03T00:00:00Z", now);
+        unknown.accuracy = TimeAccuracy::Unknown;
+        state.update_meetings(vec![inferred, unknown]);
         let settings = Settings::default();
 
-        let should_join = state.should_join_now(&settings);
-        assert!(should_join.is_none());
+        assert!(state
+            .should_join_now_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .is_none());
+        assert!(state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .is_none());
+        assert!(state
+            .find_conflicts_at(
+                &settings,
+                &TitleFilters::from_settings(&settings),
+                now,
+                Duration::hours(1)
+            )
+            .is_empty());
+        let view = state.agenda_view_at(
+            &settings,
+            &TitleFilters::from_settings(&settings),
+            now,
+            &Utc,
+        );
+        assert!(view
+            .items
+            .iter()
+            .all(|item| item.decision == ConflictDecision::InexactTime));
+        assert_eq!(view.items.len(), 2);
     }
 
     #[test]
-    fn test_should_join_now_respects_max_after_start() {
+    fn test_manual_pause() {
+        let now = Utc::now();
         let mut state = DaemonState::default();
-        let meetings = vec![create_test_meeting("abc", "Test Meeting", -5)];
-        state.update_meetings(meetings);
+        state.update_meetings(vec![create_test_meeting("abc", "Team Standup", 0)]);
 
-        let settings = Settings {
-            max_minutes_after_start: 3,
-            ..Settings::default()
-        };
+        state.pause_until(now + Duration::minutes(30));
+        assert_eq!(state.paused_until(now), Some(now + Duration::minutes(30)));
+        assert!(state
+            .calculate_next_trigger_at(
+                &Settings::default(),
+                &TitleFilters::from_settings(&Settings::default()),
+                now
+            )
+            .is_none());
 
-        let should_join = state.should_join_now(&settings);
-        assert!(should_join.is_none());
+        // Expires on its own
+        let later = now + Duration::minutes(31);
+        assert_eq!(state.paused_until(later), None);
+        assert!(!state.resume(later));
+
+        state.pause_until(now + Duration::minutes(30));
+        assert!(state.resume(now));
+        assert!(state
+            .calculate_next_trigger_at(
+                &Settings::default(),
+                &TitleFilters::from_settings(&Settings::default()),
+                now
+            )
+            .is_some());
     }
 
     #[test]
-    fn test_calculate_next_trigger_future_meeting() {
+    fn test_demo_meetings_replace_sources_until_cleared() {
+        let now = Utc::now();
         let mut state = DaemonState::default();
-        // Meeting starting in 10 minutes
-        let meetings = vec![create_test_meeting("abc", "Test Meeting", 10)];
-        state.update_meetings(meetings);
+        let priority = vec![MeetingSource::Webview, MeetingSource::Ics];
+        state.update_meetings_from_source(
+            MeetingSource::Webview,
+            vec![create_test_meeting("abc-defg-hij", "Real Meeting", 10)],
+            100,
+            &priority,
+        );
+        state.take_snapshot_if_dirty(100);
 
-        let settings = Settings {
-            join_before_minutes: 1,
-            ..Settings::default()
-        };
+        state.set_demo_meetings(Some(crate::fixtures::demo_meetings(now)), &priority);
+        assert!(state.in_demo_mode());
+        assert!(state
+            .get_meetings()
+            .iter()
+            .all(|m| m.title != "Real Meeting"));
+        assert!(state
+            .calculate_next_trigger_at(
+                &Settings::default(),
+                &TitleFilters::from_settings(&Settings::default()),
+                now
+            )
+            .is_none());
+        assert!(state.take_snapshot_if_dirty(200).is_none());
 
-        let trigger = state.calculate_next_trigger(&settings);
-        assert!(trigger.is_some());
-        let trigger = trigger.unwrap();
-        assert_eq!(trigger.meeting.call_id, "abc");
-        // Should trigger in about 9 minutes (10 - 1 = 9 minutes before)
-        assert!(trigger.delay_ms > 8 * 60 * 1000); // > 8 minutes
-        assert!(trigger.delay_ms < 10 * 60 * 1000); // < 10 minutes
+        // A source reporting meanwhile doesn't bring the real meetings back
+        state.update_meetings_from_source(
+            MeetingSource::Webview,
+            vec![create_test_meeting("abc-defg-hij", "Real Meeting", 10)],
+            300,
+            &priority,
+        );
+        assert!(state
+            .get_meetings()
+            .iter()
+            .all(|m| m.title != "Real Meeting"));
+
+        state.set_demo_meetings(None, &priority);
+        assert!(!state.in_demo_mode());
+        let meetings = state.get_meetings();
+        assert_eq!(meetings.len(), 1);
+        assert_eq!(meetings[0].title, "Real Meeting");
+        assert!(state
+            .calculate_next_trigger_at(
+                &Settings::default(),
+                &TitleFilters::from_settings(&Settings::default()),
+                now
+            )
+            .is_some());
     }
 
     #[test]
-    fn test_calculate_next_trigger_immediate() {
+    fn test_blocked_meeting_is_not_retried() {
+        let now = Utc::now();
         let mut state = DaemonState::default();
-        // Meeting that started 5 minutes ago
-        let meetings = vec![create_test_meeting("abc", "Test Meeting", -5)];
-        state.update_meetings(meetings);
-
-        let settings = Settings {
-            join_before_minutes: 1,
-            ..Settings::default()
-        };
+        state.update_meetings(vec![
+            create_test_meeting("abc", "Partner Sync", 0),
+            create_test_meeting("xyz", "Team Standup", 20),
+        ]);
+        let settings = Settings::default();
 
-        let trigger = state.calculate_next_trigger(&settings);
-        assert!(trigger.is_some());
-        // Should trigger immediately
-        assert_eq!(trigger.unwrap().delay_ms, 0);
+        assert!(state.mark_join_blocked("abc", JoinBlockedReason::DomainRestricted, 100));
+        assert!(!state.mark_join_blocked("abc", JoinBlockedReason::NotAllowed, 200));
+        assert_eq!(state.meeting_phase("abc"), Some(MeetingPhase::Failed));
+        assert_eq!(
+            state.join_blocked_reason("abc"),
+            Some(JoinBlockedReason::NotAllowed)
+        );
+        assert!(state
+            .should_join_now_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .is_none());
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.meeting.call_id, "xyz");
+        assert_eq!(
+            serde_json::from_str::<JoinBlockedReason>("\"something_new\"").unwrap(),
+            JoinBlockedReason::Unknown
+        );
     }
 
     #[test]
-    fn test_calculate_next_trigger_excludes_joined() {
+    fn test_green_room_phase_precedes_join() {
+        let now = Utc::now();
         let mut state = DaemonState::default();
-        let meetings = vec![
-            create_test_meeting("joined", "Already Joined", 5),
-            create_test_meeting("pending", "Pending Meeting", 10),
-        ];
-        state.update_meetings(meetings);
-        state.mark_joined("joined");
+        let mut meeting = create_test_meeting("abc", "Team Standup", 10);
+        meeting.begin_time = now + Duration::minutes(10);
+        state.update_meetings(vec![meeting]);
 
-        let settings = Settings::default();
+        let mut settings = Settings {
+            join_before_minutes: 1,
+            ..Settings::default()
+        };
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.green_room_delay_ms, None);
 
-        let trigger = state.calculate_next_trigger(&settings);
-        assert!(trigger.is_some());
-        assert_eq!(trigger.unwrap().meeting.call_id, "joined");
+        settings
+            .tauri
+            .get_or_insert_with(Default::default)
+            .green_room_minutes = 5;
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.delay_ms, 9 * 60 * 1000);
+        assert_eq!(trigger.green_room_delay_ms, Some(5 * 60 * 1000));
+
+        // Inside the green-room window the page opens right away
+        let later = now + Duration::minutes(7);
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), later)
+            .unwrap();
+        assert_eq!(trigger.green_room_delay_ms, Some(0));
+
+        // Only once per meeting
+        state.mark_green_room_opened("abc");
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), later)
+            .unwrap();
+        assert_eq!(trigger.green_room_delay_ms, None);
+        assert_eq!(trigger.delay_ms, 2 * 60 * 1000);
     }
 
     #[test]
-    fn test_calculate_next_trigger_respects_exclude_filters() {
+    fn test_green_room_not_before_join_window() {
+        let now = Utc::now();
         let mut state = DaemonState::default();
-        let meetings = vec![
-            create_test_meeting("optional", "Optional: Team Sync", 5),
-            create_test_meeting("required", "Sprint Planning", 10),
-        ];
-        state.update_meetings(meetings);
+        let mut meeting = create_test_meeting("abc", "Team Standup", 10);
+        meeting.begin_time = now + Duration::minutes(10);
+        state.update_meetings(vec![meeting]);
 
-        let settings = Settings {
-            title_exclude_filters: vec!["Optional".to_string()],
+        let mut settings = Settings {
+            join_before_minutes: 3,
             ..Settings::default()
         };
+        settings
+            .tauri
+            .get_or_insert_with(Default::default)
+            .green_room_minutes = 2;
 
-        let trigger = state.calculate_next_trigger(&settings);
-        assert!(trigger.is_some());
-        assert_eq!(trigger.unwrap().meeting.call_id, "required");
+        let trigger = state
+            .calculate_next_trigger_at(&settings, &TitleFilters::from_settings(&settings), now)
+            .unwrap();
+        assert_eq!(trigger.green_room_delay_ms, None);
     }
 
     #[test]
@@ -0,0 +1,180 @@
+//! Best-effort desktop notifications
+//!
+//! Uses `osascript` on macOS and `notify-send` on Linux. Other platforms (and
+//! systems without those tools) silently show nothing, so callers must not
+//! rely on the notification being seen.
+//!
+//! macOS notifications posted by `osascript` can't carry buttons, so
+//! [`show_with_action`] falls back to a dialog that closes itself there.
+
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// Action name reported by `notify-send --wait`
+const NOTIFY_SEND_ACTION: &str = "action";
+
+/// Post a desktop notification without waiting for it
+pub fn show(title: &str, body: &str) {
+    let _ = if cfg!(target_os = "macos") {
+        Command::new("osascript")
+            .arg("-e")
+            .arg(format!(
+                "display notification {} with title {}",
+                applescript_string(body),
+                applescript_string(title)
+            ))
+            .spawn()
+    } else if cfg!(target_os = "linux") {
+        Command::new("notify-send").arg(title).arg(body).spawn()
+    } else {
+        return;
+    };
+}
+
+/// Whether [`show_with_action`] and [`ask`] can show a button here
+pub fn supports_actions() -> bool {
+    cfg!(any(target_os = "macos", target_os = "linux"))
+}
+
+/// Post a notification with one action button, calling `on_action` from a
+/// background thread if the user clicks it within `timeout`
+pub fn show_with_action(
+    title: &str,
+    body: &str,
+    action_label: &str,
+    timeout: Duration,
+    on_action: impl FnOnce() + Send + 'static,
+) {
+    prompt(title, body, action_label, "OK", timeout, move |clicked| {
+        if clicked {
+            on_action();
+        }
+    });
+}
+
+/// Ask the user to allow something, calling `on_answer` from a background
+/// thread with whether they clicked `action_label` within `timeout`. Nothing
+/// is called where [`supports_actions`] is false.
+pub fn ask(
+    title: &str,
+    body: &str,
+    action_label: &str,
+    timeout: Duration,
+    on_answer: impl FnOnce(bool) + Send + 'static,
+) {
+    prompt(title, body, action_label, "Cancel", timeout, on_answer);
+}
+
+/// `dismiss_label` is the default button of the macOS dialog
+fn prompt(
+    title: &str,
+    body: &str,
+    action_label: &str,
+    dismiss_label: &str,
+    timeout: Duration,
+    on_answer: impl FnOnce(bool) + Send + 'static,
+) {
+    let child = if cfg!(target_os = "macos") {
+        Command::new("osascript")
+            .arg("-e")
+            .arg(format!(
+                "display dialog {} with title {} buttons {{{}, {}}} default button {} giving up after {}",
+                applescript_string(body),
+                applescript_string(title),
+                applescript_string(action_label),
+                applescript_string(dismiss_label),
+                applescript_string(dismiss_label),
+                timeout.as_secs().max(1)
+            ))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+    } else if cfg!(target_os = "linux") {
+        Command::new("notify-send")
+            .arg(format!("--action={}={}", NOTIFY_SEND_ACTION, action_label))
+            .arg(format!("--expire-time={}", timeout.as_millis()))
+            .arg("--wait")
+            .arg(title)
+            .arg(body)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+    } else {
+        return;
+    };
+    let Ok(child) = child else {
+        on_answer(false);
+        return;
+    };
+
+    let title = title.to_string();
+    let body = body.to_string();
+    let action_label = action_label.to_string();
+    std::thread::spawn(move || {
+        let Ok(output) = child.wait_with_output() else {
+            on_answer(false);
+            return;
+        };
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if !output.status.success() {
+            // notify-send before 0.7.9 has no --action; show it without the button
+            if cfg!(target_os = "linux") {
+                show(&title, &body);
+            }
+            on_answer(false);
+        } else {
+            on_answer(action_clicked(&stdout, &action_label));
+        }
+    });
+}
+
+/// Whether the output of the notification process reports the action button
+fn action_clicked(stdout: &str, action_label: &str) -> bool {
+    let stdout = stdout.trim();
+    if cfg!(target_os = "macos") {
+        dialog_button_clicked(stdout, action_label)
+    } else {
+        stdout == NOTIFY_SEND_ACTION
+    }
+}
+
+/// Parse `button returned:<label>, gave up:false` printed by `display dialog`
+fn dialog_button_clicked(stdout: &str, label: &str) -> bool {
+    stdout
+        .strip_prefix("button returned:")
+        .and_then(|rest| rest.rsplit_once(", gave up:"))
+        .is_some_and(|(button, gave_up)| button == label && gave_up == "false")
+}
+
+fn applescript_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_applescript_string_escapes_quotes() {
+        assert_eq!(applescript_string(r#"a "b""#), r#""a \"b\"""#);
+        assert_eq!(applescript_string(r"c:\d"), r#""c:\\d""#);
+    }
+
+    #[test]
+    fn test_dialog_button_clicked() {
+        let label = "Cancel Join";
+        assert!(dialog_button_clicked(
+            "button returned:Cancel Join, gave up:false",
+            label
+        ));
+        assert!(!dialog_button_clicked(
+            "button returned:OK, gave up:false",
+            label
+        ));
+        assert!(!dialog_button_clicked(
+            "button returned:, gave up:true",
+            label
+        ));
+        assert!(!dialog_button_clicked("", label));
+    }
+}
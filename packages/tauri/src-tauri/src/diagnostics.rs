@@ -0,0 +1,154 @@
+//! Diagnostics artifacts (webview screenshots, health reports) for MeetCat
+
+use crate::health::HealthReport;
+use crate::logging::now_ms;
+use crate::paths;
+use crate::settings::Settings;
+use std::fs;
+use std::path::PathBuf;
+use tauri::WebviewWindow;
+
+/// Logged when a join didn't reach the call by the verification deadline
+pub const JOIN_VERIFY_FAILED: &str = "join.verify_failed";
+
+/// Whether logging `event` should capture the main webview, so the page the
+/// failed join was stuck on can be seen later
+pub fn captures_screenshot(settings: &Settings, event: &str) -> bool {
+    event == JOIN_VERIFY_FAILED
+        && settings
+            .tauri_or_default()
+            .capture_screenshot_on_join_failure
+}
+
+/// Directory where diagnostics artifacts are written
+pub fn diagnostics_dir() -> PathBuf {
+    paths::diagnostics_dir()
+}
+
+/// File name for a screenshot taken for `reason` at `ts_ms`
+pub fn screenshot_file_name(reason: &str, ts_ms: u64) -> String {
+    let reason: String = reason
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    format!("screenshot-{}-{}.png", ts_ms, reason)
+}
+
+/// Path of the self-healing report inside the diagnostics folder
+pub fn health_report_path() -> PathBuf {
+    diagnostics_dir().join("health-report.json")
+}
+
+/// Write the latest health report to the diagnostics folder
+pub fn write_health_report(report: &HealthReport) -> Result<PathBuf, String> {
+    let dir = diagnostics_dir();
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let path = health_report_path();
+    let json = serde_json::to_string_pretty(report).map_err(|e| e.to_string())?;
+    fs::write(&path, json).map_err(|e| e.to_string())?;
+    Ok(path)
+}
+
+/// Capture the given webview as PNG and save it to the diagnostics folder.
+///
+/// Returns the path of the written file.
+pub async fn capture_webview_png(window: &WebviewWindow, reason: &str) -> Result<PathBuf, String> {
+    let png = snapshot_png(window).await?;
+    let dir = diagnostics_dir();
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let path = dir.join(screenshot_file_name(reason, now_ms()));
+    fs::write(&path, png).map_err(|e| e.to_string())?;
+    Ok(path)
+}
+
+#[cfg(target_os = "macos")]
+async fn snapshot_png(window: &WebviewWindow) -> Result<Vec<u8>, String> {
+    use block2::RcBlock;
+    use objc2_app_kit::{NSBitmapImageFileType, NSBitmapImageRep, NSImage};
+    use objc2_foundation::{NSDictionary, NSError};
+    use objc2_web_kit::WKWebView;
+    use std::sync::Mutex;
+
+    let (tx, rx) = tokio::sync::oneshot::channel::<Result<Vec<u8>, String>>();
+    let tx = Mutex::new(Some(tx));
+
+    window
+        .with_webview(move |webview| unsafe {
+            let wk_webview: &WKWebView = &*webview.inner().cast();
+            let handler = RcBlock::new(move |image: *mut NSImage, error: *mut NSError| {
+                let result = if let Some(image) = image.as_ref() {
+                    image
+                        .TIFFRepresentation()
+                        .and_then(|tiff| NSBitmapImageRep::imageRepWithData(&tiff))
+                        .and_then(|rep| {
+                            rep.representationUsingType_properties(
+                                NSBitmapImageFileType::PNG,
+                                &NSDictionary::new(),
+                            )
+                        })
+                        .map(|data| data.to_vec())
+                        .ok_or_else(|| "Failed to encode snapshot as PNG".to_string())
+                } else if let Some(error) = error.as_ref() {
+                    Err(error.localizedDescription().to_string())
+                } else {
+                    Err("Snapshot returned no image".to_string())
+                };
+                if let Some(tx) = tx.lock().ok().and_then(|mut tx| tx.take()) {
+                    let _ = tx.send(result);
+                }
+            });
+            wk_webview.takeSnapshotWithConfiguration_completionHandler(None, &handler);
+        })
+        .map_err(|e| e.to_string())?;
+
+    rx.await.map_err(|_| "Snapshot was cancelled".to_string())?
+}
+
+#[cfg(not(target_os = "macos"))]
+async fn snapshot_png(_window: &WebviewWindow) -> Result<Vec<u8>, String> {
+    Err("Webview screenshots are only supported on macOS".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::DaemonState;
+    use crate::lifecycle::{CallPresence, JoinVerification, VERIFY_DEADLINE_MS};
+
+    #[test]
+    fn test_screenshot_file_name_sanitizes_reason() {
+        assert_eq!(
+            screenshot_file_name("join.verify_failed", 42),
+            "screenshot-42-join-verify-failed.png"
+        );
+        assert_eq!(screenshot_file_name("manual", 1), "screenshot-1-manual.png");
+    }
+
+    #[test]
+    fn test_join_stuck_in_green_room_captures_screenshot() {
+        let mut state = DaemonState::default();
+        state.mark_joined("abc-defg-hij", 0).unwrap();
+        let waiting = CallPresence {
+            local_media_live: true,
+            participant_count: None,
+            mic_muted: Some(false),
+            camera_muted: Some(true),
+        };
+        state.record_call_presence("abc-defg-hij", waiting, 5_000);
+
+        // Still in the green room at the deadline: reported, not reloaded
+        let overdue = state.take_overdue_verifications(VERIFY_DEADLINE_MS);
+        assert_eq!(overdue.len(), 1);
+        assert_eq!(overdue[0].verification, JoinVerification::GreenRoom);
+        assert!(!overdue[0].should_retry());
+
+        let mut settings = Settings::default();
+        assert!(!captures_screenshot(&settings, JOIN_VERIFY_FAILED));
+        settings
+            .tauri
+            .get_or_insert_with(Default::default)
+            .capture_screenshot_on_join_failure = true;
+        assert!(captures_screenshot(&settings, JOIN_VERIFY_FAILED));
+        assert!(!captures_screenshot(&settings, "join.blocked"));
+    }
+}
@@ -0,0 +1,297 @@
+//! Meeting title filters
+//!
+//! Title filters decide from a meeting's title whether auto-join may pick
+//! it. A title matching any exclude filter is skipped; when include filters
+//! are set, only titles matching one of them are joined. Filters ignore case
+//! unless `caseSensitive` is set. The plain `titleExcludeFilters` keywords
+//! shared with the extension still apply as case-sensitive substrings.
+//!
+//! [`TitleFilters`] also compiles the title patterns of the meeting rules and
+//! busy block rules, so one settings snapshot is compiled once.
+
+use crate::settings::Settings;
+use serde::{Deserialize, Serialize};
+
+/// One title filter, e.g. `{ "kind": "regex", "pattern": "^1:1\\b" }`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum TitleFilter {
+    /// The title contains `pattern`
+    Contains {
+        pattern: String,
+        #[serde(default, rename = "caseSensitive")]
+        case_sensitive: bool,
+    },
+    /// `pattern` is a regular expression matching somewhere in the title
+    Regex {
+        pattern: String,
+        #[serde(default, rename = "caseSensitive")]
+        case_sensitive: bool,
+    },
+    /// The whole title, ignoring surrounding whitespace, is `pattern`
+    Exact {
+        pattern: String,
+        #[serde(default, rename = "caseSensitive")]
+        case_sensitive: bool,
+    },
+}
+
+impl TitleFilter {
+    pub fn pattern(&self) -> &str {
+        match self {
+            TitleFilter::Contains { pattern, .. }
+            | TitleFilter::Regex { pattern, .. }
+            | TitleFilter::Exact { pattern, .. } => pattern,
+        }
+    }
+
+    fn case_sensitive(&self) -> bool {
+        match self {
+            TitleFilter::Contains { case_sensitive, .. }
+            | TitleFilter::Regex { case_sensitive, .. }
+            | TitleFilter::Exact { case_sensitive, .. } => *case_sensitive,
+        }
+    }
+
+    /// Whether the filter matches `title`; an empty pattern or an invalid
+    /// regex never matches. Compiles a regex on every call, so filters used
+    /// more than once go through [`TitleFilters`].
+    pub fn matches(&self, title: &str) -> bool {
+        CompiledFilter::new(self).matches(title)
+    }
+
+    /// Check that the pattern is set and, for a regex, compiles
+    pub fn validate(&self) -> Result<(), String> {
+        let pattern = self.pattern();
+        if pattern.trim().is_empty() {
+            return Err("Title filter pattern must not be empty".to_string());
+        }
+        if let TitleFilter::Regex { .. } = self {
+            regex::Regex::new(pattern)
+                .map_err(|e| format!("Invalid title filter regex \"{}\": {}", pattern, e))?;
+        }
+        Ok(())
+    }
+}
+
+/// A filter with its regex compiled once
+#[derive(Debug)]
+struct CompiledFilter {
+    filter: TitleFilter,
+    /// `None` for other kinds and for a regex that doesn't compile
+    regex: Option<regex::Regex>,
+}
+
+impl CompiledFilter {
+    fn new(filter: &TitleFilter) -> Self {
+        let regex = match filter {
+            TitleFilter::Regex {
+                pattern,
+                case_sensitive,
+            } if !pattern.is_empty() => regex::RegexBuilder::new(pattern)
+                .case_insensitive(!case_sensitive)
+                .build()
+                .ok(),
+            _ => None,
+        };
+        Self {
+            filter: filter.clone(),
+            regex,
+        }
+    }
+
+    fn matches(&self, title: &str) -> bool {
+        let pattern = self.filter.pattern();
+        if pattern.is_empty() {
+            return false;
+        }
+        let case_sensitive = self.filter.case_sensitive();
+        match &self.filter {
+            TitleFilter::Contains { .. } if case_sensitive => title.contains(pattern),
+            TitleFilter::Contains { .. } => title.to_lowercase().contains(&pattern.to_lowercase()),
+            TitleFilter::Regex { .. } => self.regex.as_ref().is_some_and(|re| re.is_match(title)),
+            TitleFilter::Exact { .. } if case_sensitive => title.trim() == pattern.trim(),
+            TitleFilter::Exact { .. } => {
+                title.trim().to_lowercase() == pattern.trim().to_lowercase()
+            }
+        }
+    }
+}
+
+/// The title filters and rule patterns of one settings snapshot, compiled
+/// when the settings are loaded or saved so the join engine doesn't rebuild
+/// a regex on every pass over the meeting list
+#[derive(Debug, Default)]
+pub struct TitleFilters {
+    keywords: Vec<String>,
+    exclude: Vec<CompiledFilter>,
+    include: Vec<CompiledFilter>,
+    /// Patterns of `meetingRules`, in order
+    meeting_rules: Vec<CompiledFilter>,
+    /// Patterns of `busyBlockRules`, in order
+    busy_block_rules: Vec<CompiledFilter>,
+}
+
+impl TitleFilters {
+    pub fn from_settings(settings: &Settings) -> Self {
+        let mut filters = Self {
+            keywords: settings.title_exclude_filters.clone(),
+            ..Self::default()
+        };
+        if let Some(tauri) = &settings.tauri {
+            let compile =
+                |filters: &[TitleFilter]| filters.iter().map(CompiledFilter::new).collect();
+            filters.exclude = compile(&tauri.exclude_title_filters);
+            filters.include = compile(&tauri.include_title_filters);
+            filters.meeting_rules = tauri
+                .meeting_rules
+                .iter()
+                .map(|rule| CompiledFilter::new(&rule.match_mode.filter(&rule.pattern)))
+                .collect();
+            filters.busy_block_rules = tauri
+                .busy_block_rules
+                .iter()
+                .map(|rule| CompiledFilter::new(&rule.match_mode.filter(&rule.pattern)))
+                .collect();
+        }
+        filters
+    }
+
+    /// Whether the filters let a meeting titled `title` be auto-joined
+    pub fn allows(&self, title: &str) -> bool {
+        !self.keywords.iter().any(|keyword| title.contains(keyword))
+            && !self.exclude.iter().any(|filter| filter.matches(title))
+            && (self.include.is_empty() || self.include.iter().any(|f| f.matches(title)))
+    }
+
+    /// Index of the first meeting rule whose pattern matches `title`
+    pub fn meeting_rule(&self, title: &str) -> Option<usize> {
+        self.meeting_rules
+            .iter()
+            .position(|rule| rule.matches(title))
+    }
+
+    /// Index of the first busy block rule whose pattern matches `title`
+    pub fn busy_block_rule(&self, title: &str) -> Option<usize> {
+        self.busy_block_rules
+            .iter()
+            .position(|rule| rule.matches(title))
+    }
+
+    /// Which filters match `title`, for the settings window's preview
+    pub fn preview(&self, title: &str) -> TitleFilterPreview {
+        let matching = |filters: &[CompiledFilter]| -> Vec<usize> {
+            filters
+                .iter()
+                .enumerate()
+                .filter(|(_, filter)| filter.matches(title))
+                .map(|(i, _)| i)
+                .collect()
+        };
+        TitleFilterPreview {
+            auto_join: self.allows(title),
+            matched_keywords: self
+                .keywords
+                .iter()
+                .filter(|keyword| title.contains(keyword.as_str()))
+                .cloned()
+                .collect(),
+            matched_exclude: matching(&self.exclude),
+            matched_include: matching(&self.include),
+            meeting_rule: None,
+        }
+    }
+}
+
+/// Result of the `test_title_filter` command
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TitleFilterPreview {
+    /// Whether a meeting with this title may be auto-joined
+    pub auto_join: bool,
+    /// `titleExcludeFilters` keywords found in the title
+    pub matched_keywords: Vec<String>,
+    /// Indexes of the matching `excludeTitleFilters`
+    pub matched_exclude: Vec<usize>,
+    /// Indexes of the matching `includeTitleFilters`
+    pub matched_include: Vec<usize>,
+    /// Index of the meeting rule whose `autoJoin` overrides the filters
+    pub meeting_rule: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::TauriSettings;
+
+    fn contains(pattern: &str) -> TitleFilter {
+        TitleFilter::Contains {
+            pattern: pattern.to_string(),
+            case_sensitive: false,
+        }
+    }
+
+    #[test]
+    fn test_filter_kinds_and_case() {
+        assert!(contains("standup").matches("Daily Standup"));
+        let sensitive = TitleFilter::Contains {
+            pattern: "standup".to_string(),
+            case_sensitive: true,
+        };
+        assert!(!sensitive.matches("Daily Standup"));
+
+        let regex: TitleFilter =
+            serde_json::from_str(r#"{ "kind": "regex", "pattern": "^1:1\\b" }"#).unwrap();
+        assert!(regex.matches("1:1 with Alex"));
+        assert!(!regex.matches("Team 1:1"));
+
+        let exact: TitleFilter =
+            serde_json::from_str(r#"{ "kind": "exact", "pattern": "Lunch" }"#).unwrap();
+        assert!(exact.matches("  lunch "));
+        assert!(!exact.matches("Lunch and learn"));
+
+        assert!(!contains("").matches("Anything"));
+        assert!(contains("").validate().is_err());
+        let broken = TitleFilter::Regex {
+            pattern: "(".to_string(),
+            case_sensitive: false,
+        };
+        assert!(!broken.matches("("));
+        assert!(broken.validate().is_err());
+    }
+
+    #[test]
+    fn test_exclude_and_include_lists() {
+        let settings = Settings {
+            title_exclude_filters: vec!["Optional".to_string()],
+            tauri: Some(TauriSettings {
+                exclude_title_filters: vec![contains("canceled")],
+                include_title_filters: vec![contains("sync"), contains("review")],
+                ..TauriSettings::default()
+            }),
+            ..Settings::default()
+        };
+        let filters = TitleFilters::from_settings(&settings);
+        assert!(filters.allows("Weekly Sync"));
+        assert!(!filters.allows("Lunch"));
+        assert!(!filters.allows("Canceled: Weekly Sync"));
+        // Legacy keywords stay case-sensitive
+        assert!(!filters.allows("Optional sync"));
+        assert!(filters.allows("optional sync"));
+
+        let preview = filters.preview("Optional: Canceled Design Review");
+        assert_eq!(
+            preview,
+            TitleFilterPreview {
+                auto_join: false,
+                matched_keywords: vec!["Optional".to_string()],
+                matched_exclude: vec![0],
+                matched_include: vec![1],
+                meeting_rule: None,
+            }
+        );
+
+        let unfiltered = Settings::default();
+        assert!(TitleFilters::from_settings(&unfiltered).allows("Anything"));
+    }
+}
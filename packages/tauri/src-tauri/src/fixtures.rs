@@ -0,0 +1,138 @@
+//! Synthetic meeting data shared by unit tests, benchmarks and demo mode
+
+use crate::daemon::{Meeting, TimeAccuracy};
+use chrono::{DateTime, Duration, Utc};
+
+const TITLES: &[&str] = &[
+    "Team Standup",
+    "1:1 with Manager",
+    "Sprint Planning",
+    "Design Review",
+    "Optional: Coffee Chat",
+    "Customer Call",
+    "周会",
+    "Interview Loop",
+];
+
+/// Sample day shown in demo mode: call ID, title, minutes from now to the
+/// start, and duration in minutes
+const DEMO_MEETINGS: &[(&str, &str, i64, i64)] = &[
+    ("qpr-xmtw-bka", "Team Standup", -10, 30),
+    ("vhn-cdje-rzo", "Design Review", 4, 30),
+    ("kwe-fsly-pum", "1:1 with Alex", 45, 30),
+    ("tzb-gqoa-hix", "Customer Onboarding Call", 120, 60),
+    ("mle-dnru-wcy", "Sprint Planning", 240, 45),
+    ("ajo-vkpt-esf", "Optional: Coffee Chat", 330, 15),
+];
+
+/// Build a single meeting starting `starts_in_minutes` from `now`, lasting `duration_minutes`.
+pub fn meeting_at(
+    call_id: &str,
+    title: &str,
+    now: DateTime<Utc>,
+    starts_in_minutes: i64,
+    duration_minutes: i64,
+) -> Meeting {
+    let begin_time = now + Duration::minutes(starts_in_minutes);
+    Meeting {
+        call_id: call_id.to_string(),
+        url: format!("https://meet.google.com/{}", call_id),
+        title: title.to_string(),
+        display_time: begin_time.format("%H:%M").to_string(),
+        begin_time,
+        end_time: begin_time + Duration::minutes(duration_minutes),
+        event_id: Some(format!("event-{}", call_id)),
+        starts_in_minutes,
+        profile: None,
+        accuracy: TimeAccuracy::Exact,
+    }
+}
+
+/// The sample meetings of demo mode, timed relative to `now`
+pub fn demo_meetings(now: DateTime<Utc>) -> Vec<Meeting> {
+    DEMO_MEETINGS
+        .iter()
+        .map(|(call_id, title, starts_in_minutes, duration_minutes)| {
+            meeting_at(call_id, title, now, *starts_in_minutes, *duration_minutes)
+        })
+        .collect()
+}
+
+/// Generate `count` meetings resembling a busy calendar.
+///
+/// Output is deterministic for a given `now`: meetings start every 15 minutes beginning
+/// one hour in the past, durations cycle between 15 and 60 minutes, and titles rotate
+/// through a fixed pool so title filters have something to match.
+pub fn synthetic_meetings(count: usize, now: DateTime<Utc>) -> Vec<Meeting> {
+    (0..count)
+        .map(|i| {
+            let call_id = synthetic_call_id(i);
+            let title = format!("{} #{}", TITLES[i % TITLES.len()], i);
+            let starts_in_minutes = (i as i64) * 15 - 60;
+            let duration_minutes = 15 + ((i as i64) % 4) * 15;
+            meeting_at(&call_id, &title, now, starts_in_minutes, duration_minutes)
+        })
+        .collect()
+}
+
+/// Generate `count` title exclude filters, only a few of which match synthetic titles.
+pub fn synthetic_title_filters(count: usize) -> Vec<String> {
+    (0..count)
+        .map(|i| match i % 8 {
+            0 => "Optional".to_string(),
+            1 => "1:1".to_string(),
+            _ => format!("No Match Pattern {}", i),
+        })
+        .collect()
+}
+
+/// Meet-style `xxx-xxxx-xxx` code derived from an index.
+pub fn synthetic_call_id(index: usize) -> String {
+    const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+    let mut n = index;
+    let mut chars = [b'a'; 10];
+    for slot in chars.iter_mut().rev() {
+        *slot = ALPHABET[n % ALPHABET.len()];
+        n /= ALPHABET.len();
+    }
+    let s = String::from_utf8_lossy(&chars).to_string();
+    format!("{}-{}-{}", &s[0..3], &s[3..7], &s[7..10])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_synthetic_meetings_are_deterministic() {
+        let now = Utc::now();
+        let a = synthetic_meetings(50, now);
+        let b = synthetic_meetings(50, now);
+        assert_eq!(a.len(), 50);
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert_eq!(x.call_id, y.call_id);
+            assert_eq!(x.begin_time, y.begin_time);
+        }
+    }
+
+    #[test]
+    fn test_demo_meetings_are_valid_and_upcoming() {
+        let now = Utc::now();
+        let meetings = demo_meetings(now);
+        assert_eq!(meetings.len(), DEMO_MEETINGS.len());
+        assert!(meetings
+            .iter()
+            .all(|m| crate::url_norm::normalize(&m.url).is_ok() && m.end_time > now));
+        assert!(meetings
+            .windows(2)
+            .all(|w| w[0].begin_time < w[1].begin_time));
+    }
+
+    #[test]
+    fn test_synthetic_call_ids_are_unique_meet_codes() {
+        let meetings = synthetic_meetings(500, Utc::now());
+        let ids: std::collections::HashSet<_> = meetings.iter().map(|m| &m.call_id).collect();
+        assert_eq!(ids.len(), 500);
+        assert!(meetings.iter().all(|m| m.call_id.len() == 12));
+    }
+}
@@ -0,0 +1,176 @@
+//! Locale-aware formatting for tray text and notifications
+//!
+//! Relative times and counts are phrased per language rather than composed
+//! from translated fragments, so word order reads naturally ("in 5 min",
+//! "5分後", "还有5分钟"). Meeting subjects go through [`meeting_subject`] so
+//! privacy mode never leaks a title into the tray or a notification.
+
+use crate::i18n::{self, keys, Language};
+
+/// Relative start time like "in 5 min" / "now" / "5 min ago"
+pub fn relative_time(lang: &Language, starts_in_minutes: i64) -> String {
+    if starts_in_minutes == 0 {
+        return i18n::tr(lang, keys::NOW).to_string();
+    }
+    let minutes = group_digits(starts_in_minutes.unsigned_abs());
+    if starts_in_minutes > 0 {
+        match lang {
+            Language::En => format!("in {} min", minutes),
+            Language::Zh => format!("还有{}分钟", minutes),
+            Language::Ja => format!("{}分後", minutes),
+            Language::Ko => format!("{}분 후", minutes),
+        }
+    } else {
+        match lang {
+            Language::En => format!("{} min ago", minutes),
+            Language::Zh => format!("{}分钟前", minutes),
+            Language::Ja => format!("{}分前", minutes),
+            Language::Ko => format!("{}분 전", minutes),
+        }
+    }
+}
+
+/// Compact relative start time for the tray title like "in 5m" / "now" / "3m ago"
+pub fn relative_time_short(lang: &Language, starts_in_minutes: i64) -> String {
+    if starts_in_minutes == 0 {
+        return i18n::tr(lang, keys::NOW).to_string();
+    }
+    let minutes = group_digits(starts_in_minutes.unsigned_abs());
+    if starts_in_minutes > 0 {
+        match lang {
+            Language::En => format!("in {}m", minutes),
+            Language::Zh => format!("{}分后", minutes),
+            Language::Ja => format!("{}分後", minutes),
+            Language::Ko => format!("{}분 후", minutes),
+        }
+    } else {
+        match lang {
+            Language::En => format!("{}m ago", minutes),
+            Language::Zh => format!("{}分前", minutes),
+            Language::Ja => format!("{}分前", minutes),
+            Language::Ko => format!("{}분 전", minutes),
+        }
+    }
+}
+
+/// Second-level countdown for the tray title like "in 1:05", used in the
+/// last minutes before a meeting starts
+pub fn countdown_seconds_short(lang: &Language, starts_in_seconds: i64) -> String {
+    if starts_in_seconds <= 0 {
+        return i18n::tr(lang, keys::NOW).to_string();
+    }
+    let clock = format!("{}:{:02}", starts_in_seconds / 60, starts_in_seconds % 60);
+    match lang {
+        Language::En => format!("in {}", clock),
+        Language::Zh => format!("{}后", clock),
+        Language::Ja => format!("{}後", clock),
+        Language::Ko => format!("{} 후", clock),
+    }
+}
+
+/// How to refer to a meeting in user-facing text.
+///
+/// In privacy mode the title is replaced by its display time, or a generic
+/// "Next meeting" when no time is known.
+pub fn meeting_subject(
+    lang: &Language,
+    title: &str,
+    display_time: &str,
+    privacy_mode: bool,
+) -> String {
+    let title = title.trim();
+    let display_time = display_time.trim();
+    if !privacy_mode && !title.is_empty() {
+        return title.to_string();
+    }
+    if !display_time.is_empty() {
+        return display_time.to_string();
+    }
+    i18n::tr(lang, keys::NEXT_MEETING).to_string()
+}
+
+/// Digits grouped in threes; every supported language uses `,`
+fn group_digits(value: u64) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_time_across_locales() {
+        let cases = [
+            (Language::En, "in 5 min", "now", "3 min ago"),
+            (Language::Zh, "还有5分钟", "现在", "3分钟前"),
+            (Language::Ja, "5分後", "間もなく", "3分前"),
+            (Language::Ko, "5분 후", "지금", "3분 전"),
+        ];
+        for (lang, future, now, past) in cases {
+            assert_eq!(relative_time(&lang, 5), future);
+            assert_eq!(relative_time(&lang, 0), now);
+            assert_eq!(relative_time(&lang, -3), past);
+        }
+    }
+
+    #[test]
+    fn test_relative_time_short_across_locales() {
+        let cases = [
+            (Language::En, "in 5m", "3m ago"),
+            (Language::Zh, "5分后", "3分前"),
+            (Language::Ja, "5分後", "3分前"),
+            (Language::Ko, "5분 후", "3분 전"),
+        ];
+        for (lang, future, past) in cases {
+            assert_eq!(relative_time_short(&lang, 5), future);
+            assert_eq!(relative_time_short(&lang, -3), past);
+        }
+    }
+
+    #[test]
+    fn test_countdown_seconds_short_across_locales() {
+        let cases = [
+            (Language::En, "in 1:05"),
+            (Language::Zh, "1:05后"),
+            (Language::Ja, "1:05後"),
+            (Language::Ko, "1:05 후"),
+        ];
+        for (lang, future) in cases {
+            assert_eq!(countdown_seconds_short(&lang, 65), future);
+        }
+        assert_eq!(countdown_seconds_short(&Language::En, 9), "in 0:09");
+        assert_eq!(countdown_seconds_short(&Language::En, 0), "now");
+    }
+
+    #[test]
+    fn test_large_values_are_grouped() {
+        assert_eq!(group_digits(0), "0");
+        assert_eq!(group_digits(999), "999");
+        assert_eq!(group_digits(1_440), "1,440");
+        assert_eq!(group_digits(1_234_567), "1,234,567");
+        assert_eq!(relative_time(&Language::Zh, 1_440), "还有1,440分钟");
+    }
+
+    #[test]
+    fn test_meeting_subject_respects_privacy_mode() {
+        let lang = Language::En;
+        assert_eq!(
+            meeting_subject(&lang, " Standup ", "10:00 AM", false),
+            "Standup"
+        );
+        assert_eq!(
+            meeting_subject(&lang, "Standup", "10:00 AM", true),
+            "10:00 AM"
+        );
+        assert_eq!(meeting_subject(&lang, "Standup", "", true), "Next meeting");
+        assert_eq!(meeting_subject(&Language::Ja, "", "", false), "次の会議");
+    }
+}
@@ -0,0 +1,282 @@
+//! Error budget tracking and self-healing reports
+//!
+//! Two success rates are tracked over a rolling window:
+//! - joins: triggers fired vs joins confirmed by the webview
+//! - parser: `check-meetings` events emitted vs `meetings_updated` replies
+//!
+//! Pending items that are not confirmed within their timeout count as
+//! failures. When a rate with enough samples drops below its threshold the
+//! budget is breached and the caller runs the self-healing sequence.
+
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+
+/// Rolling window over which success rates are computed
+pub const HEALTH_WINDOW_MS: u64 = 60 * 60 * 1000;
+/// A fired join not confirmed within this time counts as failed
+pub const JOIN_VERIFY_TIMEOUT_MS: u64 = 5 * 60 * 1000;
+/// A check not answered by a meeting report within this time counts as failed
+pub const CHECK_REPLY_TIMEOUT_MS: u64 = 60 * 1000;
+/// How long debug logging stays on after self-healing
+pub const DEBUG_BOOST_MS: u64 = 60 * 60 * 1000;
+/// Minimum time between two self-healing runs
+pub const HEALING_COOLDOWN_MS: u64 = 60 * 60 * 1000;
+
+const JOIN_MIN_SAMPLES: u32 = 3;
+const JOIN_MIN_RATE: f64 = 0.5;
+const PARSER_MIN_SAMPLES: u32 = 10;
+const PARSER_MIN_RATE: f64 = 0.5;
+
+/// Which error budget was breached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthBudget {
+    Joins,
+    Parser,
+}
+
+/// Success rate over the rolling window
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessRate {
+    pub attempts: u32,
+    pub successes: u32,
+    /// `None` until there is at least one attempt
+    pub rate: Option<f64>,
+}
+
+impl SuccessRate {
+    fn from_outcomes(outcomes: &VecDeque<(u64, bool)>) -> Self {
+        let attempts = outcomes.len() as u32;
+        let successes = outcomes.iter().filter(|(_, ok)| *ok).count() as u32;
+        let rate = (attempts > 0).then(|| successes as f64 / attempts as f64);
+        Self {
+            attempts,
+            successes,
+            rate,
+        }
+    }
+
+    fn is_below(&self, min_samples: u32, min_rate: f64) -> bool {
+        self.attempts >= min_samples && self.rate.is_some_and(|rate| rate < min_rate)
+    }
+}
+
+/// Record of one self-healing run
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealingRecord {
+    pub at_ms: u64,
+    pub breached: Vec<HealthBudget>,
+    pub joins: SuccessRate,
+    pub parser: SuccessRate,
+    /// Recovery steps taken, in order
+    pub actions: Vec<String>,
+    pub debug_logging_until_ms: u64,
+}
+
+/// Current health plus the most recent self-healing run
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthReport {
+    pub generated_at_ms: u64,
+    pub joins: SuccessRate,
+    pub parser: SuccessRate,
+    pub breached: Vec<HealthBudget>,
+    pub last_healing: Option<HealingRecord>,
+}
+
+#[derive(Debug, Default)]
+pub struct HealthMonitor {
+    join_outcomes: VecDeque<(u64, bool)>,
+    /// Fired joins waiting for confirmation, by call id
+    pending_joins: HashMap<String, u64>,
+    check_outcomes: VecDeque<(u64, bool)>,
+    /// Emission times of checks waiting for a meeting report
+    pending_checks: VecDeque<u64>,
+    last_healing: Option<HealingRecord>,
+}
+
+impl HealthMonitor {
+    pub fn on_join_fired(&mut self, call_id: &str, now_ms: u64) {
+        self.pending_joins.insert(call_id.to_string(), now_ms);
+    }
+
+    /// Confirm a fired join; joins MeetCat did not fire are ignored
+    pub fn on_join_verified(&mut self, call_id: &str, now_ms: u64) {
+        if self.pending_joins.remove(call_id).is_some() {
+            self.join_outcomes.push_back((now_ms, true));
+        }
+    }
+
+    pub fn on_check_emitted(&mut self, now_ms: u64) {
+        self.pending_checks.push_back(now_ms);
+    }
+
+    /// A meeting report answers the oldest pending check
+    pub fn on_meetings_reported(&mut self, now_ms: u64) {
+        if self.pending_checks.pop_front().is_some() {
+            self.check_outcomes.push_back((now_ms, true));
+        }
+    }
+
+    /// Resolve timed-out items and return the budgets currently breached.
+    ///
+    /// Returns an empty list during the cooldown after a self-healing run.
+    pub fn evaluate(&mut self, now_ms: u64) -> Vec<HealthBudget> {
+        self.expire(now_ms);
+        let in_cooldown = self
+            .last_healing
+            .as_ref()
+            .is_some_and(|h| now_ms.saturating_sub(h.at_ms) < HEALING_COOLDOWN_MS);
+        if in_cooldown {
+            return Vec::new();
+        }
+        self.breached()
+    }
+
+    /// Store a self-healing run; clears the windows so recovery is measured afresh
+    pub fn record_healing(&mut self, record: HealingRecord) {
+        self.join_outcomes.clear();
+        self.check_outcomes.clear();
+        self.pending_checks.clear();
+        self.last_healing = Some(record);
+    }
+
+    pub fn join_rate(&self) -> SuccessRate {
+        SuccessRate::from_outcomes(&self.join_outcomes)
+    }
+
+    pub fn parser_rate(&self) -> SuccessRate {
+        SuccessRate::from_outcomes(&self.check_outcomes)
+    }
+
+    pub fn report(&mut self, now_ms: u64) -> HealthReport {
+        self.expire(now_ms);
+        HealthReport {
+            generated_at_ms: now_ms,
+            joins: self.join_rate(),
+            parser: self.parser_rate(),
+            breached: self.breached(),
+            last_healing: self.last_healing.clone(),
+        }
+    }
+
+    fn breached(&self) -> Vec<HealthBudget> {
+        let mut breached = Vec::new();
+        if self.join_rate().is_below(JOIN_MIN_SAMPLES, JOIN_MIN_RATE) {
+            breached.push(HealthBudget::Joins);
+        }
+        if self
+            .parser_rate()
+            .is_below(PARSER_MIN_SAMPLES, PARSER_MIN_RATE)
+        {
+            breached.push(HealthBudget::Parser);
+        }
+        breached
+    }
+
+    fn expire(&mut self, now_ms: u64) {
+        let timed_out: Vec<String> = self
+            .pending_joins
+            .iter()
+            .filter(|(_, fired)| now_ms.saturating_sub(**fired) >= JOIN_VERIFY_TIMEOUT_MS)
+            .map(|(id, _)| id.clone())
+            .collect();
+        for id in timed_out {
+            if let Some(fired) = self.pending_joins.remove(&id) {
+                self.join_outcomes
+                    .push_back((fired + JOIN_VERIFY_TIMEOUT_MS, false));
+            }
+        }
+
+        while let Some(&emitted) = self.pending_checks.front() {
+            if now_ms.saturating_sub(emitted) < CHECK_REPLY_TIMEOUT_MS {
+                break;
+            }
+            self.pending_checks.pop_front();
+            self.check_outcomes
+                .push_back((emitted + CHECK_REPLY_TIMEOUT_MS, false));
+        }
+
+        for outcomes in [&mut self.join_outcomes, &mut self.check_outcomes] {
+            outcomes.retain(|(ts, _)| now_ms.saturating_sub(*ts) < HEALTH_WINDOW_MS);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_budget_breached_by_unverified_joins() {
+        let mut monitor = HealthMonitor::default();
+        monitor.on_join_fired("a", 0);
+        monitor.on_join_verified("a", 1_000);
+        monitor.on_join_fired("b", 10_000);
+        monitor.on_join_fired("c", 20_000);
+
+        assert!(monitor.evaluate(30_000).is_empty());
+
+        let now = 20_000 + JOIN_VERIFY_TIMEOUT_MS;
+        assert_eq!(monitor.evaluate(now), vec![HealthBudget::Joins]);
+        let rate = monitor.join_rate();
+        assert_eq!((rate.attempts, rate.successes), (3, 1));
+    }
+
+    #[test]
+    fn test_parser_budget_and_unsolicited_reports() {
+        let mut monitor = HealthMonitor::default();
+        // Reports without a pending check don't count
+        monitor.on_meetings_reported(0);
+        for i in 0..10 {
+            monitor.on_check_emitted(i * 5_000);
+        }
+        monitor.on_meetings_reported(1_000);
+        monitor.on_meetings_reported(6_000);
+
+        let now = 45_000 + CHECK_REPLY_TIMEOUT_MS;
+        assert_eq!(monitor.evaluate(now), vec![HealthBudget::Parser]);
+        let rate = monitor.parser_rate();
+        assert_eq!((rate.attempts, rate.successes), (10, 2));
+        assert_eq!(rate.rate, Some(0.2));
+    }
+
+    #[test]
+    fn test_healing_cooldown_and_reset() {
+        let mut monitor = HealthMonitor::default();
+        for i in 0..3 {
+            monitor.on_join_fired(&format!("m{}", i), 0);
+        }
+        let now = JOIN_VERIFY_TIMEOUT_MS;
+        assert_eq!(monitor.evaluate(now), vec![HealthBudget::Joins]);
+
+        monitor.record_healing(HealingRecord {
+            at_ms: now,
+            breached: vec![HealthBudget::Joins],
+            joins: monitor.join_rate(),
+            parser: monitor.parser_rate(),
+            actions: vec!["debug_logging".to_string()],
+            debug_logging_until_ms: now + DEBUG_BOOST_MS,
+        });
+        assert_eq!(monitor.join_rate().attempts, 0);
+
+        for i in 3..6 {
+            monitor.on_join_fired(&format!("m{}", i), now);
+        }
+        let later = now + JOIN_VERIFY_TIMEOUT_MS;
+        assert!(monitor.evaluate(later).is_empty());
+        assert_eq!(monitor.report(later).breached, vec![HealthBudget::Joins]);
+        assert!(!monitor.evaluate(now + HEALING_COOLDOWN_MS).is_empty());
+    }
+
+    #[test]
+    fn test_outcomes_leave_window() {
+        let mut monitor = HealthMonitor::default();
+        monitor.on_check_emitted(0);
+        monitor.on_meetings_reported(10);
+        assert_eq!(monitor.report(100).parser.attempts, 1);
+        assert_eq!(monitor.report(10 + HEALTH_WINDOW_MS).parser.attempts, 0);
+    }
+}
@@ -0,0 +1,594 @@
+//! History of meetings MeetCat handled
+//!
+//! Lifecycle events are folded into one entry per meeting outcome: joined
+//! (with the time spent in the meeting), suppressed by the user, refused by
+//! Google Meet, or skipped because its join window passed. Joined entries
+//! also record whether the call was entered or the page stayed in the green
+//! room, and the breakout rooms the page spent time in. Entries are appended
+//! to a JSONL file and never rewritten, except to drop entries past the
+//! retention period or attach a note the user wrote. Those rewrites go
+//! through a temporary file, and every file operation holds [`FILE_LOCK`],
+//! so an entry appended while the file is rewritten isn't lost.
+
+use crate::daemon::Meeting;
+use crate::lifecycle::{JoinVerification, LifecycleEvent, MeetingPhase};
+use crate::locks::LockExt;
+use crate::persistence;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Entries older than this are dropped by the daily history prune
+pub const HISTORY_RETENTION_DAYS: i64 = 180;
+
+/// Held for every read or write of the history file
+static FILE_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HistoryOutcome {
+    Joined,
+    /// Closed by the user at or after its trigger time
+    Suppressed,
+    /// Google Meet refused the join
+    Failed,
+    /// Started and ended without MeetCat joining it
+    Skipped,
+}
+
+/// Time a joined meeting's page spent in one breakout room
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakoutSegment {
+    pub room_name: Option<String>,
+    pub entered_at_ms: i64,
+    pub left_at_ms: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEntry {
+    pub call_id: String,
+    pub title: String,
+    pub outcome: HistoryOutcome,
+    /// Calendar start of the meeting
+    pub scheduled_at_ms: i64,
+    /// When the webview reported the meeting as joined
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joined_at_ms: Option<i64>,
+    /// Time between joining and leaving the meeting page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<i64>,
+    /// How far a joined meeting got; missing in entries written before the
+    /// page reported it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<JoinVerification>,
+    /// Breakout rooms visited during the meeting, in order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub breakouts: Vec<BreakoutSegment>,
+    pub recorded_at_ms: i64,
+    /// Written by the user, e.g. from the end-of-meeting summary
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Time range for history queries; open ends are unbounded
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryRange {
+    #[serde(default)]
+    pub from_ms: Option<i64>,
+    #[serde(default)]
+    pub to_ms: Option<i64>,
+}
+
+impl HistoryRange {
+    /// Whether a meeting scheduled at `at_ms` falls in the range
+    pub fn contains(&self, at_ms: i64) -> bool {
+        self.from_ms.is_none_or(|from| at_ms >= from) && self.to_ms.is_none_or(|to| at_ms < to)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistorySummary {
+    pub joined: u32,
+    pub suppressed: u32,
+    pub failed: u32,
+    pub skipped: u32,
+    /// Joined meetings whose page never got past the green room
+    pub not_admitted: u32,
+    /// Total time spent in joined meetings
+    pub in_meeting_ms: i64,
+}
+
+impl HistorySummary {
+    pub fn of(entries: &[HistoryEntry]) -> Self {
+        let mut summary = Self::default();
+        for entry in entries {
+            match entry.outcome {
+                HistoryOutcome::Joined => summary.joined += 1,
+                HistoryOutcome::Suppressed => summary.suppressed += 1,
+                HistoryOutcome::Failed => summary.failed += 1,
+                HistoryOutcome::Skipped => summary.skipped += 1,
+            }
+            if entry.verification == Some(JoinVerification::GreenRoom) {
+                summary.not_admitted += 1;
+            }
+            summary.in_meeting_ms += entry.duration_ms.unwrap_or(0);
+        }
+        summary
+    }
+}
+
+/// Response of the `get_meeting_history` command
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingHistory {
+    pub entries: Vec<HistoryEntry>,
+    pub summary: HistorySummary,
+}
+
+/// Entry the note dialog edits, identified the way `set_note` matches it
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryNoteTarget {
+    pub call_id: String,
+    pub recorded_at_ms: i64,
+    pub title: String,
+}
+
+/// A meeting whose outcome isn't settled yet
+#[derive(Debug, Clone)]
+struct OpenMeeting {
+    title: String,
+    scheduled_at_ms: i64,
+    joined_at_ms: Option<i64>,
+    verification: JoinVerification,
+    /// Breakout rooms left since the meeting was last joined
+    breakouts: Vec<BreakoutSegment>,
+    /// Breakout room the page is in, with its name and when it was entered
+    breakout: Option<(Option<String>, i64)>,
+    /// An entry was written since the meeting was last joined
+    recorded: bool,
+}
+
+/// Turns lifecycle events into history entries
+#[derive(Debug, Default)]
+pub struct HistoryRecorder {
+    meetings: HashMap<String, OpenMeeting>,
+}
+
+impl HistoryRecorder {
+    /// Remember titles and start times of scheduled meetings; lifecycle
+    /// events only carry the call ID, and expired meetings are already gone
+    /// from the schedule when their event arrives
+    pub fn observe(&mut self, meetings: &[Meeting]) {
+        for meeting in meetings {
+            let open = self
+                .meetings
+                .entry(meeting.call_id.clone())
+                .or_insert_with(|| OpenMeeting {
+                    title: String::new(),
+                    scheduled_at_ms: 0,
+                    joined_at_ms: None,
+                    verification: JoinVerification::Unverified,
+                    breakouts: Vec::new(),
+                    breakout: None,
+                    recorded: false,
+                });
+            open.title.clone_from(&meeting.title);
+            open.scheduled_at_ms = meeting.begin_time.timestamp_millis();
+        }
+    }
+
+    /// Update how far the current join of `call_id` got
+    pub fn verify(&mut self, call_id: &str, verification: JoinVerification) {
+        if let Some(open) = self.meetings.get_mut(call_id) {
+            open.verification = verification;
+        }
+    }
+
+    /// Start a breakout segment for `call_id`, closing the one it moved from
+    pub fn enter_breakout(&mut self, call_id: &str, room_name: Option<String>, at_ms: i64) {
+        self.exit_breakout(call_id, at_ms);
+        if let Some(open) = self.meetings.get_mut(call_id) {
+            open.breakout = Some((room_name, at_ms));
+        }
+    }
+
+    /// Close the breakout segment of `call_id`, if one is open
+    pub fn exit_breakout(&mut self, call_id: &str, at_ms: i64) {
+        if let Some(open) = self.meetings.get_mut(call_id) {
+            if let Some((room_name, entered_at_ms)) = open.breakout.take() {
+                open.breakouts.push(BreakoutSegment {
+                    room_name,
+                    entered_at_ms,
+                    left_at_ms: at_ms,
+                });
+            }
+        }
+    }
+
+    /// Fold one lifecycle event, returning the entry it completes
+    pub fn record(&mut self, event: &LifecycleEvent) -> Option<HistoryEntry> {
+        let at_ms = event.transition.at_ms;
+        let to = event.transition.to;
+        if to == MeetingPhase::Expired {
+            let open = self.meetings.remove(&event.call_id)?;
+            return match open.joined_at_ms {
+                Some(_) if !open.recorded => Some(entry(&event.call_id, &open, at_ms)),
+                None if !open.recorded && open.scheduled_at_ms <= at_ms => Some(HistoryEntry {
+                    outcome: HistoryOutcome::Skipped,
+                    ..entry(&event.call_id, &open, at_ms)
+                }),
+                _ => None,
+            };
+        }
+
+        let open = self.meetings.get_mut(&event.call_id)?;
+        match to {
+            MeetingPhase::Joined => {
+                open.joined_at_ms = Some(at_ms);
+                open.verification = JoinVerification::Unverified;
+                open.breakouts.clear();
+                open.breakout = None;
+                open.recorded = false;
+                None
+            }
+            MeetingPhase::Left | MeetingPhase::Suppressed | MeetingPhase::Failed
+                if open.joined_at_ms.is_some() && !open.recorded =>
+            {
+                open.recorded = true;
+                Some(entry(&event.call_id, open, at_ms))
+            }
+            MeetingPhase::Suppressed | MeetingPhase::Failed if !open.recorded => {
+                open.recorded = true;
+                Some(HistoryEntry {
+                    outcome: if to == MeetingPhase::Failed {
+                        HistoryOutcome::Failed
+                    } else {
+                        HistoryOutcome::Suppressed
+                    },
+                    ..entry(&event.call_id, open, at_ms)
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Entry for `open` as of `at_ms`: joined when it was joined, with the time
+/// spent in the meeting and its breakout rooms up to `at_ms`
+fn entry(call_id: &str, open: &OpenMeeting, at_ms: i64) -> HistoryEntry {
+    let mut breakouts = open.breakouts.clone();
+    if let Some((room_name, entered_at_ms)) = &open.breakout {
+        breakouts.push(BreakoutSegment {
+            room_name: room_name.clone(),
+            entered_at_ms: *entered_at_ms,
+            left_at_ms: at_ms,
+        });
+    }
+    HistoryEntry {
+        call_id: call_id.to_string(),
+        title: open.title.clone(),
+        outcome: HistoryOutcome::Joined,
+        scheduled_at_ms: open.scheduled_at_ms,
+        joined_at_ms: open.joined_at_ms,
+        duration_ms: open.joined_at_ms.map(|joined| (at_ms - joined).max(0)),
+        verification: open.joined_at_ms.map(|_| open.verification),
+        breakouts,
+        recorded_at_ms: at_ms,
+        note: None,
+    }
+}
+
+/// Append `entry` to the history file at `path`
+pub fn append(path: &Path, entry: &HistoryEntry) -> Result<(), String> {
+    let mut line = serde_json::to_string(entry).map_err(|e| e.to_string())?;
+    line.push('\n');
+    let _guard = FILE_LOCK.lock_or_recover();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| e.to_string())?;
+    file.write_all(line.as_bytes()).map_err(|e| e.to_string())
+}
+
+/// Entries in `range`, oldest first. Unreadable lines are skipped.
+pub fn read(path: &Path, range: HistoryRange) -> Result<Vec<HistoryEntry>, String> {
+    let _guard = FILE_LOCK.lock_or_recover();
+    let file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.to_string()),
+    };
+    let mut entries: Vec<HistoryEntry> = BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str::<HistoryEntry>(&line).ok())
+        .filter(|entry| range.contains(entry.scheduled_at_ms))
+        .collect();
+    entries.sort_by_key(|entry| entry.scheduled_at_ms);
+    Ok(entries)
+}
+
+/// Set the note of the latest entry for `call_id` recorded at
+/// `recorded_at_ms`; a blank note removes it. Returns whether an entry matched.
+pub fn set_note(
+    path: &Path,
+    call_id: &str,
+    recorded_at_ms: i64,
+    note: &str,
+) -> Result<bool, String> {
+    let _guard = FILE_LOCK.lock_or_recover();
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e.to_string()),
+    };
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let matched = lines.iter().rposition(|line| {
+        serde_json::from_str::<HistoryEntry>(line)
+            .is_ok_and(|e| e.call_id == call_id && e.recorded_at_ms == recorded_at_ms)
+    });
+    let Some(index) = matched else {
+        return Ok(false);
+    };
+    let mut entry: HistoryEntry = serde_json::from_str(&lines[index]).map_err(|e| e.to_string())?;
+    let note = note.trim();
+    entry.note = (!note.is_empty()).then(|| note.to_string());
+    lines[index] = serde_json::to_string(&entry).map_err(|e| e.to_string())?;
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    persistence::write_atomically(path, &updated).map_err(|e| e.to_string())?;
+    Ok(true)
+}
+
+/// Drop entries scheduled before `cutoff_ms`. Returns how many were dropped.
+pub fn prune(path: &Path, cutoff_ms: i64) -> Result<usize, String> {
+    let _guard = FILE_LOCK.lock_or_recover();
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e.to_string()),
+    };
+    let mut kept = String::with_capacity(content.len());
+    let mut dropped = 0;
+    for line in content.lines() {
+        match serde_json::from_str::<HistoryEntry>(line) {
+            Ok(entry) if entry.scheduled_at_ms >= cutoff_ms => {
+                kept.push_str(line);
+                kept.push('\n');
+            }
+            _ => dropped += 1,
+        }
+    }
+    if dropped > 0 {
+        persistence::write_atomically(path, &kept).map_err(|e| e.to_string())?;
+    }
+    Ok(dropped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::lifecycle::PhaseTransition;
+    use chrono::Utc;
+    use std::path::PathBuf;
+
+    fn event(call_id: &str, to: MeetingPhase, at_ms: i64) -> LifecycleEvent {
+        LifecycleEvent {
+            call_id: call_id.to_string(),
+            transition: PhaseTransition {
+                from: None,
+                to,
+                at_ms,
+            },
+        }
+    }
+
+    #[test]
+    fn test_recorder_folds_lifecycle_into_outcomes() {
+        let now = Utc::now();
+        let start_ms = now.timestamp_millis();
+        let meetings = vec![
+            fixtures::meeting_at("aaa-aaaa-aaa", "Standup", now, 0, 30),
+            fixtures::meeting_at("bbb-bbbb-bbb", "Planning", now, 0, 30),
+            fixtures::meeting_at("ccc-cccc-ccc", "Optional", now, 0, 30),
+            fixtures::meeting_at("ddd-dddd-ddd", "Next Week", now, 60 * 24 * 7, 30),
+        ];
+        let mut recorder = HistoryRecorder::default();
+        recorder.observe(&meetings);
+
+        assert!(recorder
+            .record(&event("aaa-aaaa-aaa", MeetingPhase::Triggered, start_ms))
+            .is_none());
+        assert!(recorder
+            .record(&event(
+                "aaa-aaaa-aaa",
+                MeetingPhase::Joined,
+                start_ms + 1000
+            ))
+            .is_none());
+        recorder.verify("aaa-aaaa-aaa", JoinVerification::InCall);
+        let joined = recorder
+            .record(&event(
+                "aaa-aaaa-aaa",
+                MeetingPhase::Suppressed,
+                start_ms + 61_000,
+            ))
+            .unwrap();
+        assert_eq!(joined.outcome, HistoryOutcome::Joined);
+        assert_eq!(joined.title, "Standup");
+        assert_eq!(joined.duration_ms, Some(60_000));
+        assert_eq!(joined.verification, Some(JoinVerification::InCall));
+        // Already recorded when it expires
+        assert!(recorder
+            .record(&event(
+                "aaa-aaaa-aaa",
+                MeetingPhase::Expired,
+                start_ms + 90_000
+            ))
+            .is_none());
+
+        let suppressed = recorder
+            .record(&event("bbb-bbbb-bbb", MeetingPhase::Suppressed, start_ms))
+            .unwrap();
+        assert_eq!(suppressed.outcome, HistoryOutcome::Suppressed);
+        assert!(suppressed.joined_at_ms.is_none());
+        assert!(suppressed.verification.is_none());
+
+        let skipped = recorder
+            .record(&event(
+                "ccc-cccc-ccc",
+                MeetingPhase::Expired,
+                start_ms + 90_000,
+            ))
+            .unwrap();
+        assert_eq!(skipped.outcome, HistoryOutcome::Skipped);
+
+        // Removed from the calendar before it started
+        assert!(recorder
+            .record(&event("ddd-dddd-ddd", MeetingPhase::Expired, start_ms))
+            .is_none());
+    }
+
+    #[test]
+    fn test_recorder_keeps_breakout_segments() {
+        let now = Utc::now();
+        let start_ms = now.timestamp_millis();
+        let mut recorder = HistoryRecorder::default();
+        recorder.observe(&[fixtures::meeting_at(
+            "aaa-aaaa-aaa",
+            "All Hands",
+            now,
+            0,
+            60,
+        )]);
+        recorder.record(&event("aaa-aaaa-aaa", MeetingPhase::Joined, start_ms));
+
+        recorder.enter_breakout("aaa-aaaa-aaa", Some("Room 1".to_string()), start_ms + 100);
+        recorder.enter_breakout("aaa-aaaa-aaa", Some("Room 2".to_string()), start_ms + 200);
+        recorder.exit_breakout("aaa-aaaa-aaa", start_ms + 300);
+        recorder.enter_breakout("aaa-aaaa-aaa", None, start_ms + 400);
+        let entry = recorder
+            .record(&event("aaa-aaaa-aaa", MeetingPhase::Left, start_ms + 500))
+            .unwrap();
+
+        let segments: Vec<_> = entry
+            .breakouts
+            .iter()
+            .map(|s| {
+                (
+                    s.room_name.as_deref(),
+                    s.entered_at_ms - start_ms,
+                    s.left_at_ms - start_ms,
+                )
+            })
+            .collect();
+        assert_eq!(
+            segments,
+            vec![
+                (Some("Room 1"), 100, 200),
+                (Some("Room 2"), 200, 300),
+                (None, 400, 500),
+            ]
+        );
+        assert_eq!(entry.duration_ms, Some(500));
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("meetcat-history-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir.join("meeting-history.jsonl")
+    }
+
+    fn stored(call_id: &str, outcome: HistoryOutcome, scheduled_at_ms: i64) -> HistoryEntry {
+        HistoryEntry {
+            call_id: call_id.to_string(),
+            title: format!("Meeting {}", call_id),
+            outcome,
+            scheduled_at_ms,
+            joined_at_ms: (outcome == HistoryOutcome::Joined).then_some(scheduled_at_ms),
+            duration_ms: (outcome == HistoryOutcome::Joined).then_some(30 * 60 * 1000),
+            verification: (outcome == HistoryOutcome::Joined).then_some(JoinVerification::InCall),
+            breakouts: Vec::new(),
+            recorded_at_ms: scheduled_at_ms,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn test_read_range_summary_and_prune() {
+        let path = temp_path("range");
+        append(&path, &stored("a", HistoryOutcome::Joined, 1_000)).unwrap();
+        append(&path, &stored("b", HistoryOutcome::Skipped, 2_000)).unwrap();
+        fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"{not json\n")
+            .unwrap();
+        append(&path, &stored("c", HistoryOutcome::Joined, 3_000)).unwrap();
+        append(
+            &path,
+            &HistoryEntry {
+                verification: Some(JoinVerification::GreenRoom),
+                ..stored("d", HistoryOutcome::Joined, 4_000)
+            },
+        )
+        .unwrap();
+
+        let week = read(
+            &path,
+            HistoryRange {
+                from_ms: Some(1_500),
+                to_ms: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            week.iter().map(|e| e.call_id.as_str()).collect::<Vec<_>>(),
+            ["b", "c", "d"]
+        );
+        let summary = HistorySummary::of(&week);
+        assert_eq!(summary.joined, 2);
+        assert_eq!(summary.skipped, 1);
+        assert_eq!(summary.not_admitted, 1);
+        assert_eq!(summary.in_meeting_ms, 2 * 30 * 60 * 1000);
+
+        // The old entry and the broken line go
+        assert_eq!(prune(&path, 1_500).unwrap(), 2);
+        assert_eq!(read(&path, HistoryRange::default()).unwrap().len(), 3);
+        assert!(read(&temp_path("missing"), HistoryRange::default())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_set_note_updates_matching_entry() {
+        let path = temp_path("note");
+        append(&path, &stored("a", HistoryOutcome::Joined, 1_000)).unwrap();
+        append(&path, &stored("b", HistoryOutcome::Joined, 2_000)).unwrap();
+
+        assert!(set_note(&path, "a", 1_000, "  Follow up on budget ").unwrap());
+        assert!(!set_note(&path, "a", 9_000, "Wrong entry").unwrap());
+        let entries = read(&path, HistoryRange::default()).unwrap();
+        assert_eq!(entries[0].note.as_deref(), Some("Follow up on budget"));
+        assert_eq!(entries[1].note, None);
+
+        assert!(set_note(&path, "a", 1_000, " ").unwrap());
+        let entries = read(&path, HistoryRange::default()).unwrap();
+        assert_eq!(entries[0].note, None);
+        assert!(!set_note(&temp_path("missing-note"), "a", 1_000, "x").unwrap());
+    }
+}
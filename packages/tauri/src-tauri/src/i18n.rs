@@ -44,15 +44,52 @@ impl Language {
 pub mod keys {
     pub const QUIT_MEETCAT: &str = "tray.quitMeetCat";
     pub const SHOW_WINDOW: &str = "tray.showWindow";
+    pub const ACCOUNTS: &str = "tray.accounts";
+    pub const MAIN_ACCOUNT: &str = "tray.mainAccount";
     pub const BACK_TO_GOOGLE_MEET_HOME: &str = "tray.backToGoogleMeetHome";
+    pub const BACK_TO_STARTUP_PAGE: &str = "tray.backToStartupPage";
     pub const SETTINGS: &str = "tray.settings";
     pub const CHECK_FOR_UPDATES: &str = "tray.checkForUpdates";
     pub const NO_UPCOMING_MEETINGS: &str = "tray.noUpcomingMeetings";
     pub const TOOLTIP: &str = "tray.tooltip";
     pub const NOW: &str = "tray.now";
+    pub const PAUSED_OUT_OF_OFFICE: &str = "tray.pausedOutOfOffice";
+    pub const JOIN_RATE_CAPPED: &str = "tray.joinRateCapped";
+    pub const DATA_STALE: &str = "tray.dataStale";
+    pub const OFFLINE: &str = "tray.offline";
+    pub const PAUSED: &str = "tray.paused";
+    pub const NEXT_MEETING: &str = "tray.nextMeeting";
+    pub const JOIN_BLOCKED: &str = "tray.joinBlocked";
+    pub const OUTSIDE_AUTO_JOIN_HOURS: &str = "tray.outsideAutoJoinHours";
+    pub const SNOOZE_NEXT_JOIN: &str = "tray.snoozeNextJoin";
+    pub const JOIN_BY_CODE: &str = "tray.joinByCode";
+    pub const CANCEL_NEXT_JOIN: &str = "tray.cancelNextJoin";
+    pub const SKIP_MEETING: &str = "tray.skipMeeting";
+    pub const LEAVE_MEETING: &str = "tray.leaveMeeting";
+    pub const COPY_MEETING_LINK: &str = "tray.copyMeetingLink";
+    pub const PAUSE_FOR_HOUR: &str = "tray.pauseForHour";
+    pub const RESUME_AUTO_JOIN: &str = "tray.resumeAutoJoin";
+
+    // Notification keys
+    pub const NOTIFY_MIC_BUSY_TITLE: &str = "notify.micBusyTitle";
+    pub const NOTIFY_SKIPPED_AWAY_TITLE: &str = "notify.skippedAwayTitle";
+    pub const NOTIFY_JOIN_BLOCKED_TITLE: &str = "notify.joinBlockedTitle";
+    pub const NOTIFY_JOIN_PENDING_TITLE: &str = "notify.joinPendingTitle";
+    pub const NOTIFY_CANCEL_JOIN: &str = "notify.cancelJoin";
+    pub const NOTIFY_BUSY_CONFIRM_TITLE: &str = "notify.busyConfirmTitle";
+    pub const NOTIFY_JOIN_NOW: &str = "notify.joinNow";
+    pub const NOTIFY_MEETING_ENDED_TITLE: &str = "notify.meetingEndedTitle";
+    pub const NOTIFY_ADD_NOTE: &str = "notify.addNote";
+    pub const NOTIFY_JOIN_REMINDER_TITLE: &str = "notify.joinReminderTitle";
+    pub const NOTIFY_SKIP_JOIN: &str = "notify.skipJoin";
+    pub const NOTIFY_LAST_MINUTE_TITLE: &str = "notify.lastMinuteTitle";
+    pub const BUSY_BLOCK: &str = "notify.busyBlock";
+    pub const NOTIFY_LINK_CONFIRM_TITLE: &str = "notify.linkConfirmTitle";
+    pub const NOTIFY_ALLOW: &str = "notify.allow";
 
     // App menu keys
     pub const MENU_REFRESH_HOME: &str = "menu.refreshHome";
+    pub const MENU_FILE: &str = "menu.file";
     pub const MENU_EDIT: &str = "menu.edit";
     pub const MENU_VIEW: &str = "menu.view";
     pub const MENU_WINDOW: &str = "menu.window";
@@ -97,8 +134,14 @@ fn translations() -> &'static TranslationMap {
             en: "Quit MeetCat", zh: "退出 MeetCat", ja: "MeetCat を終了", ko: "MeetCat 종료");
         tr!(keys::SHOW_WINDOW,
             en: "Show Window", zh: "显示窗口", ja: "ウィンドウを表示", ko: "창 표시");
+        tr!(keys::ACCOUNTS,
+            en: "Accounts", zh: "账号", ja: "アカウント", ko: "계정");
+        tr!(keys::MAIN_ACCOUNT,
+            en: "Main Account", zh: "主账号", ja: "メインアカウント", ko: "기본 계정");
         tr!(keys::BACK_TO_GOOGLE_MEET_HOME,
             en: "Back to Google Meet Home", zh: "返回 Google Meet 主页", ja: "Google Meet ホームに戻る", ko: "Google Meet 홈으로 돌아가기");
+        tr!(keys::BACK_TO_STARTUP_PAGE,
+            en: "Back to Startup Page", zh: "返回启动页面", ja: "起動ページに戻る", ko: "시작 페이지로 돌아가기");
         tr!(keys::SETTINGS,
             en: "Settings...", zh: "设置...", ja: "設定...", ko: "설정...");
         tr!(keys::CHECK_FOR_UPDATES,
@@ -109,10 +152,106 @@ fn translations() -> &'static TranslationMap {
             en: "MeetCat - Auto-join Google Meet", zh: "MeetCat - 自动加入 Google Meet", ja: "MeetCat - Google Meet に自動参加", ko: "MeetCat - Google Meet 자동 참가");
         tr!(keys::NOW,
             en: "now", zh: "现在", ja: "間もなく", ko: "지금");
+        tr!(keys::PAUSED_OUT_OF_OFFICE,
+            en: "Paused: OOO", zh: "已暂停：休假中", ja: "一時停止中：不在", ko: "일시 중지됨: 부재 중");
+        tr!(keys::PAUSED,
+            en: "Auto-join paused", zh: "自动加入已暂停", ja: "自動参加を一時停止中", ko: "자동 참가 일시 중지됨");
+        tr!(keys::JOIN_RATE_CAPPED,
+            en: "Too many auto-joins - click to resume",
+            zh: "自动加入过于频繁 - 点击恢复",
+            ja: "自動参加が多すぎます - クリックで再開",
+            ko: "자동 참가가 너무 많습니다 - 클릭하여 재개");
+        tr!(keys::DATA_STALE,
+            en: "Meeting list is out of date",
+            zh: "会议列表已过期",
+            ja: "会議リストが古くなっています",
+            ko: "회의 목록이 오래되었습니다");
+        tr!(keys::OFFLINE,
+            en: "Offline - joins wait for the network",
+            zh: "网络已断开 - 恢复后再加入",
+            ja: "オフライン - ネットワークの復帰まで参加を保留",
+            ko: "오프라인 - 네트워크가 복구되면 참가");
+        tr!(keys::NEXT_MEETING,
+            en: "Next meeting", zh: "下一个会议", ja: "次の会議", ko: "다음 회의");
+        tr!(keys::JOIN_BLOCKED,
+            en: "can't join", zh: "无法加入", ja: "参加できません", ko: "참가 불가");
+        tr!(keys::OUTSIDE_AUTO_JOIN_HOURS,
+            en: "Outside auto-join hours", zh: "不在自动加入时段", ja: "自動参加の時間外", ko: "자동 참가 시간 외");
+        tr!(keys::SNOOZE_NEXT_JOIN,
+            en: "Snooze Next Join", zh: "推迟下一次加入", ja: "次の参加をスヌーズ", ko: "다음 참가 미루기");
+        tr!(keys::CANCEL_NEXT_JOIN,
+            en: "Cancel Next Auto-Join", zh: "取消下一次自动加入", ja: "次の自動参加をキャンセル", ko: "다음 자동 참가 취소");
+        tr!(keys::SKIP_MEETING,
+            en: "Skip Auto-Join", zh: "跳过自动加入", ja: "自動参加をスキップ", ko: "자동 참가 건너뛰기");
+        tr!(keys::LEAVE_MEETING,
+            en: "Leave Meeting", zh: "离开会议", ja: "会議から退出", ko: "회의 나가기");
+        tr!(keys::COPY_MEETING_LINK,
+            en: "Copy Meeting Link", zh: "复制会议链接", ja: "会議のリンクをコピー", ko: "회의 링크 복사");
+        tr!(keys::PAUSE_FOR_HOUR,
+            en: "Pause Auto-Join for 1 Hour", zh: "暂停自动加入 1 小时", ja: "自動参加を 1 時間停止", ko: "1시간 동안 자동 참가 일시 중지");
+        tr!(keys::RESUME_AUTO_JOIN,
+            en: "Resume Auto-Join", zh: "恢复自动加入", ja: "自動参加を再開", ko: "자동 참가 재개");
+        tr!(keys::JOIN_BY_CODE,
+            en: "Join a Meeting by Code...", zh: "通过会议代码加入...", ja: "会議コードで参加...", ko: "회의 코드로 참가...");
+
+        // Notifications
+        tr!(keys::NOTIFY_MIC_BUSY_TITLE,
+            en: "MeetCat is waiting to join",
+            zh: "MeetCat 正在等待加入",
+            ja: "MeetCat は参加を待機中です",
+            ko: "MeetCat이 참가를 기다리는 중");
+        tr!(keys::NOTIFY_SKIPPED_AWAY_TITLE,
+            en: "MeetCat didn't join while you were away",
+            zh: "你不在时 MeetCat 没有加入会议",
+            ja: "離席中のため MeetCat は参加しませんでした",
+            ko: "자리를 비운 동안 MeetCat이 참가하지 않았습니다");
+        tr!(keys::NOTIFY_JOIN_BLOCKED_TITLE,
+            en: "Google Meet didn't let this account join",
+            zh: "Google Meet 不允许此帐号加入",
+            ja: "このアカウントでは Google Meet に参加できません",
+            ko: "이 계정으로는 Google Meet에 참가할 수 없습니다");
+        tr!(keys::NOTIFY_JOIN_PENDING_TITLE,
+            en: "MeetCat is about to join",
+            zh: "MeetCat 即将加入会议",
+            ja: "MeetCat がまもなく参加します",
+            ko: "MeetCat이 곧 참가합니다");
+        tr!(keys::NOTIFY_CANCEL_JOIN,
+            en: "Cancel Join", zh: "取消加入", ja: "参加をキャンセル", ko: "참가 취소");
+        tr!(keys::NOTIFY_BUSY_CONFIRM_TITLE,
+            en: "Join during a busy block?",
+            zh: "要在忙碌时段加入会议吗？",
+            ja: "予定ありの時間帯に参加しますか？",
+            ko: "바쁜 시간대에 참가할까요?");
+        tr!(keys::NOTIFY_JOIN_NOW,
+            en: "Join Now", zh: "立即加入", ja: "今すぐ参加", ko: "지금 참가");
+        tr!(keys::NOTIFY_MEETING_ENDED_TITLE,
+            en: "Meeting ended", zh: "会议已结束", ja: "会議が終了しました", ko: "회의가 종료되었습니다");
+        tr!(keys::NOTIFY_ADD_NOTE,
+            en: "Add Note", zh: "添加备注", ja: "メモを追加", ko: "메모 추가");
+        tr!(keys::NOTIFY_JOIN_REMINDER_TITLE,
+            en: "Joining soon", zh: "即将加入会议", ja: "まもなく参加します", ko: "곧 참가합니다");
+        tr!(keys::NOTIFY_SKIP_JOIN,
+            en: "Skip", zh: "跳过", ja: "スキップ", ko: "건너뛰기");
+        tr!(keys::NOTIFY_LAST_MINUTE_TITLE,
+            en: "New meeting detected starting soon",
+            zh: "检测到即将开始的新会议",
+            ja: "まもなく始まる新しい会議を検出しました",
+            ko: "곧 시작하는 새 회의가 감지되었습니다");
+        tr!(keys::BUSY_BLOCK,
+            en: "a busy block", zh: "忙碌时段", ja: "予定ありの時間帯", ko: "바쁜 시간대");
+        tr!(keys::NOTIFY_LINK_CONFIRM_TITLE,
+            en: "Allow this MeetCat link?",
+            zh: "允许这个 MeetCat 链接吗？",
+            ja: "この MeetCat リンクを許可しますか？",
+            ko: "이 MeetCat 링크를 허용할까요?");
+        tr!(keys::NOTIFY_ALLOW,
+            en: "Allow", zh: "允许", ja: "許可", ko: "허용");
 
         // App menu
         tr!(keys::MENU_REFRESH_HOME,
             en: "Refresh Home", zh: "刷新主页", ja: "ホームを更新", ko: "홈 새로고침");
+        tr!(keys::MENU_FILE,
+            en: "File", zh: "文件", ja: "ファイル", ko: "파일");
         tr!(keys::MENU_EDIT,
             en: "Edit", zh: "编辑", ja: "編集", ko: "편집");
         tr!(keys::MENU_VIEW,
@@ -208,16 +347,6 @@ pub fn tr_next_meeting(lang: &Language, title: &str, status: &str) -> String {
     }
 }
 
-/// Format "MeetCat - Next: {title} ({status})" for the given language
-pub fn tr_tooltip_with_meeting(lang: &Language, title: &str, status: &str) -> String {
-    match lang {
-        Language::En => format!("MeetCat - Next: {} ({})", title, status),
-        Language::Zh => format!("MeetCat - 下一个：{}（{}）", title, status),
-        Language::Ja => format!("MeetCat - 次：{}（{}）", title, status),
-        Language::Ko => format!("MeetCat - 다음: {} ({})", title, status),
-    }
-}
-
 /// Format "MeetCat - No upcoming meetings" for the given language
 pub fn tr_tooltip_no_meetings(lang: &Language) -> String {
     match lang {
@@ -228,44 +357,186 @@ pub fn tr_tooltip_no_meetings(lang: &Language) -> String {
     }
 }
 
-/// Format time status like "in 5 min" / "now" / "3 min ago"
-pub fn tr_time_status(lang: &Language, starts_in_minutes: i64) -> String {
-    if starts_in_minutes > 0 {
-        match lang {
-            Language::En => format!("in {} min", starts_in_minutes),
-            Language::Zh => format!("{} 分钟后", starts_in_minutes),
-            Language::Ja => format!("{} 分後", starts_in_minutes),
-            Language::Ko => format!("{}분 후", starts_in_minutes),
-        }
-    } else if starts_in_minutes == 0 {
-        tr(lang, keys::NOW).to_string()
-    } else {
-        match lang {
-            Language::En => format!("{} min ago", -starts_in_minutes),
-            Language::Zh => format!("{} 分钟前", -starts_in_minutes),
-            Language::Ja => format!("{} 分前", -starts_in_minutes),
-            Language::Ko => format!("{}분 전", -starts_in_minutes),
-        }
+/// Format "{label} ({status})" for one meeting line in the tray tooltip
+pub fn tr_meeting_line(lang: &Language, label: &str, status: &str) -> String {
+    match lang {
+        Language::En | Language::Ko => format!("{} ({})", label, status),
+        Language::Zh | Language::Ja => format!("{}（{}）", label, status),
     }
 }
 
-/// Format countdown for tray title like "in 5m" / "now" / "3m ago"
-pub fn tr_countdown_short(lang: &Language, starts_in_minutes: i64) -> String {
-    if starts_in_minutes > 0 {
-        match lang {
-            Language::En => format!("in {}m", starts_in_minutes),
-            Language::Zh => format!("{}分后", starts_in_minutes),
-            Language::Ja => format!("{}分後", starts_in_minutes),
-            Language::Ko => format!("{}분 후", starts_in_minutes),
-        }
-    } else if starts_in_minutes == 0 {
-        tr(lang, keys::NOW).to_string()
-    } else {
-        match lang {
-            Language::En => format!("{}m ago", -starts_in_minutes),
-            Language::Zh => format!("{}分前", -starts_in_minutes),
-            Language::Ja => format!("{}分前", -starts_in_minutes),
-            Language::Ko => format!("{}분 전", -starts_in_minutes),
-        }
+/// Format "+{count} more" for meetings left out of the tray tooltip
+pub fn tr_more_meetings(lang: &Language, count: usize) -> String {
+    match lang {
+        Language::En => format!("+{} more", count),
+        Language::Zh => format!("还有 {} 个会议", count),
+        Language::Ja => format!("他 {} 件", count),
+        Language::Ko => format!("외 {}개", count),
+    }
+}
+
+/// Format "{minutes} minutes" for the snooze submenu
+pub fn tr_snooze_minutes(lang: &Language, minutes: u32) -> String {
+    match lang {
+        Language::En => format!("{} minutes", minutes),
+        Language::Zh => format!("{} 分钟", minutes),
+        Language::Ja => format!("{} 分", minutes),
+        Language::Ko => format!("{}분", minutes),
+    }
+}
+
+/// Format a tray item that joins an upcoming meeting, like
+/// "10:30 AM Design Sync — Join now"
+pub fn tr_join_meeting_now(lang: &Language, label: &str) -> String {
+    match lang {
+        Language::En => format!("{} — Join now", label),
+        Language::Zh => format!("{} — 立即加入", label),
+        Language::Ja => format!("{} — 今すぐ参加", label),
+        Language::Ko => format!("{} — 지금 참가", label),
+    }
+}
+
+/// Watermark `tooltip` as showing demo mode's sample meetings
+pub fn tr_demo_tooltip(lang: &Language, tooltip: &str) -> String {
+    match lang {
+        Language::En => format!("[DEMO · sample meetings]\n{}", tooltip),
+        Language::Zh => format!("[演示 · 示例会议]\n{}", tooltip),
+        Language::Ja => format!("[デモ · サンプルの会議]\n{}", tooltip),
+        Language::Ko => format!("[데모 · 샘플 회의]\n{}", tooltip),
+    }
+}
+
+/// Format the tray status line while in a meeting, naming it unless `title`
+/// is empty
+pub fn tr_in_meeting(lang: &Language, title: &str) -> String {
+    match (lang, title.is_empty()) {
+        (Language::En, true) => "In a meeting".to_string(),
+        (Language::En, false) => format!("In meeting: {}", title),
+        (Language::Zh, true) => "会议中".to_string(),
+        (Language::Zh, false) => format!("会议中：{}", title),
+        (Language::Ja, true) => "会議中".to_string(),
+        (Language::Ja, false) => format!("会議中：{}", title),
+        (Language::Ko, true) => "회의 중".to_string(),
+        (Language::Ko, false) => format!("회의 중: {}", title),
+    }
+}
+
+/// Format the chat status shown while in a meeting, with its end time if known
+pub fn tr_presence_in_meeting(lang: &Language, until: Option<&str>) -> String {
+    match (lang, until) {
+        (Language::En, None) => "In a meeting".to_string(),
+        (Language::En, Some(time)) => format!("In a meeting until {}", time),
+        (Language::Zh, None) => "会议中".to_string(),
+        (Language::Zh, Some(time)) => format!("会议中，{} 结束", time),
+        (Language::Ja, None) => "会議中".to_string(),
+        (Language::Ja, Some(time)) => format!("{}まで会議中", time),
+        (Language::Ko, None) => "회의 중".to_string(),
+        (Language::Ko, Some(time)) => format!("{}까지 회의 중", time),
+    }
+}
+
+/// Format the body of the notification shown while a join waits for the microphone
+pub fn tr_mic_busy_body(lang: &Language, subject: &str, status: &str) -> String {
+    let line = tr_meeting_line(lang, subject, status);
+    match lang {
+        Language::En => format!("{}\nMeetCat will join once your microphone is free.", line),
+        Language::Zh => format!("{}\n麦克风空闲后 MeetCat 将自动加入。", line),
+        Language::Ja => format!("{}\nマイクが空き次第 MeetCat が参加します。", line),
+        Language::Ko => format!("{}\n마이크를 사용할 수 있게 되면 MeetCat이 참가합니다.", line),
+    }
+}
+
+/// Format the body of the notification shown when an auto-join is skipped
+/// because the user is away
+pub fn tr_skipped_away_body(lang: &Language, subject: &str) -> String {
+    match lang {
+        Language::En => format!(
+            "{}\nJoin it from the MeetCat menu once you're back.",
+            subject
+        ),
+        Language::Zh => format!("{}\n回来后可从 MeetCat 菜单加入。", subject),
+        Language::Ja => format!("{}\n戻ったら MeetCat のメニューから参加できます。", subject),
+        Language::Ko => format!("{}\n돌아오면 MeetCat 메뉴에서 참가하세요.", subject),
+    }
+}
+
+/// Format the body of the notification posted shortly before an auto-join
+pub fn tr_join_pending_body(lang: &Language, subject: &str, seconds: u64) -> String {
+    match lang {
+        Language::En => format!("Joining {} in {} seconds", subject, seconds),
+        Language::Zh => format!("{} 秒后加入 {}", seconds, subject),
+        Language::Ja => format!("{} 秒後に {} に参加します", seconds, subject),
+        Language::Ko => format!("{}초 후 {}에 참가합니다", seconds, subject),
+    }
+}
+
+/// Format the screen reader announcement made when an auto-join fires
+pub fn tr_join_firing_announcement(lang: &Language, subject: &str) -> String {
+    match lang {
+        Language::En => format!("MeetCat is joining {} now", subject),
+        Language::Zh => format!("MeetCat 正在加入 {}", subject),
+        Language::Ja => format!("MeetCat が {} に参加しています", subject),
+        Language::Ko => format!("MeetCat이 지금 {}에 참가합니다", subject),
+    }
+}
+
+/// Format the body of the notification asking to join over a busy block
+pub fn tr_busy_confirm_body(lang: &Language, subject: &str, block: &str) -> String {
+    match lang {
+        Language::En => format!("{} overlaps {}.", subject, block),
+        Language::Zh => format!("{} 与「{}」时间重叠。", subject, block),
+        Language::Ja => format!("{} は「{}」と重なっています。", subject, block),
+        Language::Ko => format!("{}이(가) '{}'와(과) 겹칩니다.", subject, block),
+    }
+}
+
+/// Format the body of the prompt confirming a `meetcat://` link
+pub fn tr_link_confirm_body(lang: &Language, link: &str) -> String {
+    match lang {
+        Language::En => format!("An app or web page opened {}.", link),
+        Language::Zh => format!("某个应用或网页打开了 {}。", link),
+        Language::Ja => format!("アプリまたはウェブページが {} を開きました。", link),
+        Language::Ko => format!("앱 또는 웹 페이지가 {}을(를) 열었습니다.", link),
+    }
+}
+
+/// Format the body of the end-of-meeting summary, e.g. "Standup ended — 27 minutes"
+pub fn tr_meeting_summary_body(lang: &Language, subject: &str, minutes: u64) -> String {
+    match lang {
+        Language::En if minutes == 1 => format!("{} ended — 1 minute", subject),
+        Language::En => format!("{} ended — {} minutes", subject, minutes),
+        Language::Zh => format!("{} 已结束 — {} 分钟", subject, minutes),
+        Language::Ja => format!("{} が終了しました — {} 分", subject, minutes),
+        Language::Ko => format!("{} 종료 — {}분", subject, minutes),
+    }
+}
+
+/// Format the body of the pre-join reminder, e.g. "Joining Standup in 5 minutes"
+pub fn tr_join_reminder_body(lang: &Language, subject: &str, minutes: u32) -> String {
+    match lang {
+        Language::En if minutes == 1 => format!("Joining {} in 1 minute", subject),
+        Language::En => format!("Joining {} in {} minutes", subject, minutes),
+        Language::Zh => format!("{} 分钟后加入 {}", minutes, subject),
+        Language::Ja => format!("{} 分後に {} に参加します", minutes, subject),
+        Language::Ko => format!("{}분 후 {}에 참가합니다", minutes, subject),
+    }
+}
+
+/// Format the body of the notification shown when Google Meet refuses a join
+pub fn tr_join_blocked_body(lang: &Language, subject: &str) -> String {
+    match lang {
+        Language::En => format!(
+            "{}\nMeetCat won't retry. Join from your browser with an account that has access.",
+            subject
+        ),
+        Language::Zh => format!("{}\nMeetCat 不会重试。请在浏览器中使用有权限的帐号加入。", subject),
+        Language::Ja => format!(
+            "{}\nMeetCat は再試行しません。アクセス権のあるアカウントでブラウザから参加してください。",
+            subject
+        ),
+        Language::Ko => format!(
+            "{}\nMeetCat은 다시 시도하지 않습니다. 권한이 있는 계정으로 브라우저에서 참가하세요.",
+            subject
+        ),
     }
 }
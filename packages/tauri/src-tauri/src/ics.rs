@@ -0,0 +1,684 @@
+//! ICS calendar subscriptions as a secondary meeting source
+//!
+//! Calendars outside Google (Outlook, iCloud, Fastmail, ...) often carry
+//! meet.google.com links. Each entry of `tauri.icsSubscriptions` is an
+//! http(s) or `webcal://` URL, or a local file path. VEVENTs with a Meet link
+//! in their URL, X-GOOGLE-CONFERENCE, LOCATION or DESCRIPTION become meetings
+//! reported as the `ics` source, which the daemon merges with the webview's
+//! meetings by call id. Remote calendars share the `ics` request budget of
+//! [`crate::source_quota`], so a throttled server is backed off rather than
+//! polled harder.
+//!
+//! Only the next [`ICS_HORIZON_HOURS`] are reported. Recurring events are
+//! expanded for DAILY, WEEKLY (with BYDAY) and MONTHLY (same day of month)
+//! rules, honouring INTERVAL, COUNT, UNTIL and EXDATE; other rules only yield
+//! their first occurrence. Times with a TZID are read as local time, since
+//! time zone definitions aren't bundled.
+
+use crate::daemon::{Meeting, TimeAccuracy};
+use crate::http_client;
+use crate::locks::LockExt;
+use crate::logging::now_ms;
+use crate::settings::{MeetingSource, Settings};
+use crate::source_quota::{self, Deferral, RequestOutcome, RequestScheduler};
+use crate::url_norm;
+use chrono::{
+    DateTime, Datelike, Duration, Local, Months, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday,
+};
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use tauri::Url;
+
+/// How far ahead subscriptions contribute meetings
+pub const ICS_HORIZON_HOURS: i64 = 24;
+/// Occurrences examined per recurring event before giving up
+const MAX_RECURRENCE_STEPS: usize = 5000;
+
+/// Where a subscription is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IcsLocation {
+    Remote(Url),
+    File(PathBuf),
+}
+
+impl IcsLocation {
+    /// Parse a subscription entry; `webcal://` is fetched over https
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let raw = raw.trim();
+        if let Ok(url) = Url::parse(raw) {
+            match url.scheme() {
+                "http" | "https" => return Ok(IcsLocation::Remote(url)),
+                "webcal" | "webcals" => {
+                    let https = format!("https{}", &raw[url.scheme().len()..]);
+                    return Url::parse(&https)
+                        .map(IcsLocation::Remote)
+                        .map_err(|e| e.to_string());
+                }
+                "file" => {
+                    return url
+                        .to_file_path()
+                        .map(IcsLocation::File)
+                        .map_err(|_| format!("Invalid file URL \"{}\"", raw));
+                }
+                // Windows paths such as C:\cal.ics parse with a one-letter scheme
+                scheme if scheme.len() > 1 => {
+                    return Err(format!("Unsupported calendar URL \"{}\"", raw));
+                }
+                _ => {}
+            }
+        }
+        let path = PathBuf::from(raw);
+        if path.is_absolute() {
+            Ok(IcsLocation::File(path))
+        } else {
+            Err(format!(
+                "Calendar subscription \"{}\" must be a URL or an absolute file path",
+                raw
+            ))
+        }
+    }
+}
+
+/// Why a subscription couldn't be read
+#[derive(Debug)]
+pub enum FetchError {
+    /// Remote calendars are out of request budget or backing off
+    Deferred(Deferral),
+    Failed {
+        message: String,
+        /// Set when this failure backed off all remote calendars
+        backoff_until_ms: Option<u64>,
+    },
+}
+
+/// Read the calendar text of a subscription. Remote reads draw from the
+/// `ics` source's request budget in `scheduler`.
+pub async fn fetch(
+    location: &IcsLocation,
+    settings: &Settings,
+    scheduler: &Mutex<RequestScheduler>,
+) -> Result<String, FetchError> {
+    let url = match location {
+        IcsLocation::Remote(url) => url,
+        IcsLocation::File(path) => {
+            return tokio::fs::read_to_string(path)
+                .await
+                .map_err(|e| FetchError::Failed {
+                    message: e.to_string(),
+                    backoff_until_ms: None,
+                });
+        }
+    };
+
+    scheduler
+        .lock_or_recover()
+        .acquire(&MeetingSource::Ics, now_ms())
+        .map_err(FetchError::Deferred)?;
+    let result = request(url, settings).await;
+    let outcome = match &result {
+        Ok(_) => RequestOutcome::Success,
+        Err((_, outcome)) => *outcome,
+    };
+    let backoff_until_ms = scheduler.lock_or_recover().record(
+        &MeetingSource::Ics,
+        outcome,
+        now_ms(),
+        source_quota::random_jitter(),
+    );
+    result.map_err(|(message, _)| FetchError::Failed {
+        message,
+        backoff_until_ms,
+    })
+}
+
+async fn request(url: &Url, settings: &Settings) -> Result<String, (String, RequestOutcome)> {
+    let client = http_client::client(settings, url).map_err(|e| (e, RequestOutcome::Failed))?;
+    // Errors drop the URL, which may embed a private calendar token
+    let response = client
+        .get(url.clone())
+        .send()
+        .await
+        .map_err(|e| (e.without_url().to_string(), RequestOutcome::Failed))?;
+    let retry_after = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok());
+    let outcome = RequestOutcome::from_status(response.status().as_u16(), retry_after);
+    if outcome != RequestOutcome::Success {
+        return Err((format!("HTTP {}", response.status()), outcome));
+    }
+    response
+        .text()
+        .await
+        .map_err(|e| (e.without_url().to_string(), RequestOutcome::Failed))
+}
+
+/// One VEVENT, with only the properties meetings are built from
+#[derive(Debug, Default)]
+struct Event {
+    uid: Option<String>,
+    summary: Option<String>,
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+    duration: Option<Duration>,
+    all_day: bool,
+    cancelled: bool,
+    rrule: Option<String>,
+    exdates: Vec<DateTime<Utc>>,
+    recurrence_id: Option<DateTime<Utc>>,
+    /// Candidate texts for the Meet link, in lookup order
+    link_texts: Vec<(u8, String)>,
+}
+
+/// Meetings with a Meet link in `calendar` that haven't ended and start
+/// within [`ICS_HORIZON_HOURS`] of `now`, one per call id, sorted by start
+pub fn parse_meetings(calendar: &str, now: DateTime<Utc>) -> Vec<Meeting> {
+    let events = parse_events(calendar);
+    let horizon = now + Duration::hours(ICS_HORIZON_HOURS);
+
+    // Occurrences replaced by a RECURRENCE-ID override, per UID
+    let mut overridden: HashMap<&str, Vec<DateTime<Utc>>> = HashMap::new();
+    for event in &events {
+        if let (Some(uid), Some(recurrence_id)) = (&event.uid, event.recurrence_id) {
+            overridden.entry(uid).or_default().push(recurrence_id);
+        }
+    }
+
+    let mut meetings: Vec<Meeting> = Vec::new();
+    for event in &events {
+        if event.cancelled || event.all_day {
+            continue;
+        }
+        let Some(start) = event.start else {
+            continue;
+        };
+        let Some((url, call_id)) = meet_link(event) else {
+            continue;
+        };
+        let length = match (event.end, event.duration) {
+            (Some(end), _) => end - start,
+            (None, Some(duration)) => duration,
+            (None, None) => Duration::zero(),
+        };
+        let mut skipped = event.exdates.clone();
+        if event.recurrence_id.is_none() {
+            if let Some(moved) = event.uid.as_deref().and_then(|uid| overridden.get(uid)) {
+                skipped.extend(moved);
+            }
+        }
+        let starts = match (&event.rrule, event.recurrence_id) {
+            (Some(rrule), None) => expand_rrule(start, rrule, horizon),
+            _ => vec![start],
+        };
+
+        let next = starts
+            .into_iter()
+            .filter(|begin| !skipped.contains(begin))
+            .map(|begin| (begin, begin + length))
+            .find(|(begin, end)| *begin < horizon && (*end > now || *begin > now));
+        if let Some((begin_time, end_time)) = next {
+            meetings.push(Meeting {
+                call_id,
+                url,
+                title: event.summary.clone().unwrap_or_default(),
+                display_time: begin_time.with_timezone(&Local).format("%H:%M").to_string(),
+                begin_time,
+                end_time,
+                event_id: event.uid.clone(),
+                starts_in_minutes: (begin_time.timestamp_millis() - now.timestamp_millis())
+                    .div_euclid(60_000),
+                profile: None,
+                accuracy: TimeAccuracy::Exact,
+            });
+        }
+    }
+
+    meetings.sort_by(|a, b| {
+        a.begin_time
+            .cmp(&b.begin_time)
+            .then_with(|| a.call_id.cmp(&b.call_id))
+    });
+    let mut seen = Vec::new();
+    meetings.retain(|meeting| {
+        let first = !seen.contains(&meeting.call_id);
+        seen.push(meeting.call_id.clone());
+        first
+    });
+    meetings
+}
+
+/// Normalized Meet URL and call id of the event's first Meet link
+fn meet_link(event: &Event) -> Option<(String, String)> {
+    static MEET_LINK: OnceLock<Regex> = OnceLock::new();
+    let pattern = MEET_LINK.get_or_init(|| {
+        Regex::new(r"(?i)(?:https?://)?meet\.google\.com/[A-Za-z0-9/_\-]+(?:\?[^\s<>]*)?")
+            .expect("valid regex")
+    });
+    let mut texts: Vec<&(u8, String)> = event.link_texts.iter().collect();
+    texts.sort_by_key(|(rank, _)| *rank);
+    texts.into_iter().find_map(|(_, text)| {
+        pattern.find_iter(text).find_map(|found| {
+            let url = url_norm::normalize(found.as_str()).ok()?;
+            let target = url_norm::parse_target_path(url.path())?;
+            Some((url.to_string(), target.path()))
+        })
+    })
+}
+
+fn parse_events(calendar: &str) -> Vec<Event> {
+    let mut events = Vec::new();
+    let mut current: Option<Event> = None;
+    // Components nested in the current VEVENT, e.g. VALARM
+    let mut nested = 0usize;
+
+    for line in unfold(calendar) {
+        let Some((name, params, value)) = split_property(&line) else {
+            continue;
+        };
+        match (name.as_str(), value.to_ascii_uppercase().as_str()) {
+            ("BEGIN", "VEVENT") if current.is_none() => {
+                current = Some(Event::default());
+                continue;
+            }
+            ("END", "VEVENT") if nested == 0 => {
+                events.extend(current.take());
+                continue;
+            }
+            ("BEGIN", _) if current.is_some() => {
+                nested += 1;
+                continue;
+            }
+            ("END", _) if current.is_some() => {
+                nested = nested.saturating_sub(1);
+                continue;
+            }
+            _ => {}
+        }
+        let Some(event) = current.as_mut().filter(|_| nested == 0) else {
+            continue;
+        };
+        let is_date = params.iter().any(|(k, v)| k == "VALUE" && v == "DATE");
+        match name.as_str() {
+            "UID" => event.uid = Some(value),
+            "SUMMARY" => event.summary = Some(unescape(&value)),
+            "DTSTART" => {
+                event.all_day = is_date || value.len() == 8;
+                event.start = parse_date_time(&value);
+            }
+            "DTEND" => event.end = parse_date_time(&value),
+            "DURATION" => event.duration = parse_duration(&value),
+            "STATUS" => event.cancelled = value.eq_ignore_ascii_case("CANCELLED"),
+            "RRULE" => event.rrule = Some(value),
+            "EXDATE" => event
+                .exdates
+                .extend(value.split(',').filter_map(parse_date_time)),
+            "RECURRENCE-ID" => event.recurrence_id = parse_date_time(&value),
+            "URL" => event.link_texts.push((0, value)),
+            "X-GOOGLE-CONFERENCE" => event.link_texts.push((1, value)),
+            "LOCATION" => event.link_texts.push((2, unescape(&value))),
+            "DESCRIPTION" => event.link_texts.push((3, unescape(&value))),
+            _ => {}
+        }
+    }
+    events
+}
+
+/// Join folded lines (continuations start with a space or tab)
+fn unfold(calendar: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in calendar.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+    lines
+}
+
+/// Upper-cased `PARAM=VALUE` pairs of a content line
+type Params = Vec<(String, String)>;
+
+/// Split `NAME;PARAM=VALUE:value` into the upper-cased name, upper-cased
+/// parameters and the raw value
+fn split_property(line: &str) -> Option<(String, Params, String)> {
+    let mut in_quotes = false;
+    let colon = line.char_indices().find_map(|(i, c)| match c {
+        '"' => {
+            in_quotes = !in_quotes;
+            None
+        }
+        ':' if !in_quotes => Some(i),
+        _ => None,
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let mut parts = head.split(';');
+    let name = parts.next()?.trim().to_ascii_uppercase();
+    let params = parts
+        .filter_map(|param| param.split_once('='))
+        .map(|(k, v)| (k.trim().to_ascii_uppercase(), v.trim().to_ascii_uppercase()))
+        .collect();
+    Some((name, params, value.trim().to_string()))
+}
+
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+/// `20260102T090000Z` (UTC) or `20260102T090000` (local); dates mean local midnight
+fn parse_date_time(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Some(utc) = value.strip_suffix(['Z', 'z']) {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(Utc.from_utc_datetime(&naive));
+    }
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y%m%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })?;
+    local_to_utc(naive)
+}
+
+fn local_to_utc(naive: NaiveDateTime) -> Option<DateTime<Utc>> {
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|local| local.with_timezone(&Utc))
+}
+
+/// `PT1H30M`, `P1D`, `P1W`; negative durations are ignored
+fn parse_duration(value: &str) -> Option<Duration> {
+    let rest = value.trim().strip_prefix('+').unwrap_or(value.trim());
+    let rest = rest.strip_prefix('P')?;
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    for c in rest.chars() {
+        match c {
+            'T' => {}
+            '0'..='9' => number.push(c),
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += match unit {
+                    'W' => Duration::weeks(n),
+                    'D' => Duration::days(n),
+                    'H' => Duration::hours(n),
+                    'M' => Duration::minutes(n),
+                    'S' => Duration::seconds(n),
+                    _ => return None,
+                };
+            }
+        }
+    }
+    number.is_empty().then_some(total)
+}
+
+/// Start times of a recurring event up to `until`, in order.
+///
+/// Occurrences keep the local wall-clock time of the first one across
+/// daylight-saving changes.
+fn expand_rrule(start: DateTime<Utc>, rrule: &str, until: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+    let mut freq = None;
+    let mut interval: u32 = 1;
+    let mut count: Option<usize> = None;
+    let mut rule_until: Option<DateTime<Utc>> = None;
+    let mut by_day: Vec<Weekday> = Vec::new();
+    let mut other_by_rule = false;
+    for part in rrule.split(';') {
+        let Some((key, value)) = part.split_once('=') else {
+            continue;
+        };
+        match key.trim().to_ascii_uppercase().as_str() {
+            "FREQ" => freq = Some(value.trim().to_ascii_uppercase()),
+            "INTERVAL" => interval = value.trim().parse().unwrap_or(1).max(1),
+            "COUNT" => count = value.trim().parse().ok(),
+            "UNTIL" => rule_until = parse_date_time(value),
+            "BYDAY" => {
+                for day in value.split(',') {
+                    // Ordinal prefixes like 2TU only make sense for MONTHLY
+                    let code: String = day.chars().filter(|c| c.is_ascii_alphabetic()).collect();
+                    if code.len() != day.trim().len() {
+                        other_by_rule = true;
+                    }
+                    match weekday(&code) {
+                        Some(day) => by_day.push(day),
+                        None => other_by_rule = true,
+                    }
+                }
+            }
+            "WKST" => {}
+            _ => other_by_rule = true,
+        }
+    }
+
+    let local_start = start.with_timezone(&Local).naive_local();
+    let (date, time) = (local_start.date(), local_start.time());
+    let candidates: Box<dyn Iterator<Item = NaiveDate>> = match freq.as_deref() {
+        Some("DAILY") if by_day.is_empty() && !other_by_rule => {
+            Box::new((0..).map_while(move |i: u64| {
+                date.checked_add_days(chrono::Days::new(i * interval as u64))
+            }))
+        }
+        Some("WEEKLY") if !other_by_rule => {
+            if by_day.is_empty() {
+                by_day.push(date.weekday());
+            }
+            by_day.sort_by_key(|day| day.num_days_from_monday());
+            by_day.dedup();
+            let week_start = date - Duration::days(date.weekday().num_days_from_monday() as i64);
+            Box::new(
+                (0..)
+                    .map_while(move |i: u64| {
+                        week_start.checked_add_days(chrono::Days::new(i * 7 * interval as u64))
+                    })
+                    .flat_map(move |week| {
+                        by_day.clone().into_iter().map(move |day| {
+                            week + Duration::days(day.num_days_from_monday() as i64)
+                        })
+                    })
+                    .filter(move |day| *day >= date),
+            )
+        }
+        Some("MONTHLY") if by_day.is_empty() && !other_by_rule => Box::new(
+            (0..)
+                .map_while(move |i: u32| date.checked_add_months(Months::new(i * interval)))
+                // Months without the day are skipped, not clamped
+                .filter(move |day| day.day() == date.day()),
+        ),
+        _ => return vec![start],
+    };
+
+    let mut starts = Vec::new();
+    for (index, day) in candidates.take(MAX_RECURRENCE_STEPS).enumerate() {
+        if count.is_some_and(|count| index >= count) {
+            break;
+        }
+        let Some(occurrence) = local_to_utc(day.and_time(time)) else {
+            continue;
+        };
+        if occurrence > until || rule_until.is_some_and(|limit| occurrence > limit) {
+            break;
+        }
+        starts.push(occurrence);
+    }
+    starts
+}
+
+fn weekday(code: &str) -> Option<Weekday> {
+    Some(match code.to_ascii_uppercase().as_str() {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(raw: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(raw)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn calendar(events: &[&str]) -> String {
+        let mut text = String::from("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n");
+        for event in events {
+            text.push_str("BEGIN:VEVENT\r\n");
+            text.push_str(event);
+            text.push_str("END:VEVENT\r\n");
+        }
+        text.push_str("END:VCALENDAR\r\n");
+        text
+    }
+
+    #[test]
+    fn test_location_parsing() {
+        assert_eq!(
+            IcsLocation::parse("webcal://example.com/cal.ics"),
+            Ok(IcsLocation::Remote(
+                Url::parse("https://example.com/cal.ics").unwrap()
+            ))
+        );
+        assert!(matches!(
+            IcsLocation::parse("https://example.com/cal.ics"),
+            Ok(IcsLocation::Remote(_))
+        ));
+        assert!(IcsLocation::parse("ftp://example.com/cal.ics").is_err());
+        assert!(IcsLocation::parse("relative/cal.ics").is_err());
+        #[cfg(unix)]
+        {
+            assert_eq!(
+                IcsLocation::parse("/home/me/cal.ics"),
+                Ok(IcsLocation::File(PathBuf::from("/home/me/cal.ics")))
+            );
+            assert_eq!(
+                IcsLocation::parse("file:///home/me/cal.ics"),
+                Ok(IcsLocation::File(PathBuf::from("/home/me/cal.ics")))
+            );
+        }
+    }
+
+    #[test]
+    fn test_events_with_meet_links_become_meetings() {
+        let now = utc("2026-03-02T08:00:00Z");
+        let text = calendar(&[
+            "UID:standup\r\nSUMMARY:Standup\\, daily\r\nDTSTART:20260302T090000Z\r\n\
+             DTEND:20260302T091500Z\r\nDESCRIPTION:Join: https://meet.google.com/abc-\r\n defg-hij?hs=1\r\n",
+            // Already over
+            "UID:done\r\nSUMMARY:Done\r\nDTSTART:20260302T060000Z\r\nDTEND:20260302T070000Z\r\n\
+             LOCATION:https://meet.google.com/zzz-zzzz-zzz\r\n",
+            // No Meet link
+            "UID:zoom\r\nSUMMARY:Zoom\r\nDTSTART:20260302T100000Z\r\nDTEND:20260302T110000Z\r\n\
+             LOCATION:https://zoom.us/j/1\r\n",
+            // Cancelled
+            "UID:off\r\nSUMMARY:Off\r\nSTATUS:CANCELLED\r\nDTSTART:20260302T100000Z\r\n\
+             DURATION:PT30M\r\nURL:https://meet.google.com/kkk-kkkk-kkk\r\n",
+            // Beyond the horizon
+            "UID:later\r\nSUMMARY:Later\r\nDTSTART:20260305T100000Z\r\nDURATION:PT30M\r\n\
+             URL:https://meet.google.com/lll-llll-lll\r\n",
+            // Ignores links inside alarms
+            "UID:review\r\nSUMMARY:Review\r\nDTSTART:20260302T120000Z\r\nDURATION:PT1H\r\n\
+             BEGIN:VALARM\r\nDESCRIPTION:https://meet.google.com/aaa-aaaa-aaa\r\nEND:VALARM\r\n\
+             X-GOOGLE-CONFERENCE:https://meet.google.com/rev-revi-rev\r\n",
+        ]);
+
+        let meetings = parse_meetings(&text, now);
+        let ids: Vec<&str> = meetings.iter().map(|m| m.call_id.as_str()).collect();
+        assert_eq!(ids, vec!["abc-defg-hij", "rev-revi-rev"]);
+
+        let standup = &meetings[0];
+        assert_eq!(standup.title, "Standup, daily");
+        assert_eq!(standup.url, "https://meet.google.com/abc-defg-hij");
+        assert_eq!(standup.begin_time, utc("2026-03-02T09:00:00Z"));
+        assert_eq!(standup.end_time, utc("2026-03-02T09:15:00Z"));
+        assert_eq!(standup.starts_in_minutes, 60);
+        assert_eq!(standup.event_id.as_deref(), Some("standup"));
+        assert_eq!(meetings[1].end_time, utc("2026-03-02T13:00:00Z"));
+    }
+
+    #[test]
+    fn test_recurring_events_yield_next_occurrence() {
+        // Monday
+        let now = utc("2026-03-02T12:00:00Z");
+        let text = calendar(&[
+            // Weekly on Monday and Wednesday; today's occurrence is over
+            "UID:sync\r\nSUMMARY:Sync\r\nDTSTART:20260216T090000Z\r\nDURATION:PT30M\r\n\
+             RRULE:FREQ=WEEKLY;BYDAY=MO,WE\r\nURL:https://meet.google.com/syn-sync-syn\r\n",
+            // Daily, but tomorrow's occurrence is excluded and today's moved
+            "UID:daily\r\nSUMMARY:Daily\r\nDTSTART:20260201T070000Z\r\nDURATION:PT15M\r\n\
+             RRULE:FREQ=DAILY\r\nEXDATE:20260303T070000Z\r\n\
+             URL:https://meet.google.com/day-dayd-day\r\n",
+            "UID:daily\r\nSUMMARY:Daily (moved)\r\nRECURRENCE-ID:20260302T070000Z\r\n\
+             DTSTART:20260302T150000Z\r\nDURATION:PT15M\r\n\
+             URL:https://meet.google.com/day-dayd-day\r\n",
+            // Ended by COUNT
+            "UID:short\r\nSUMMARY:Short\r\nDTSTART:20260226T130000Z\r\nDURATION:PT15M\r\n\
+             RRULE:FREQ=DAILY;COUNT=3\r\nURL:https://meet.google.com/sho-rtsh-ort\r\n",
+        ]);
+
+        let meetings = parse_meetings(&text, now);
+        let found: Vec<(&str, &str, DateTime<Utc>)> = meetings
+            .iter()
+            .map(|m| (m.call_id.as_str(), m.title.as_str(), m.begin_time))
+            .collect();
+        assert_eq!(
+            found,
+            vec![("day-dayd-day", "Daily (moved)", utc("2026-03-02T15:00:00Z"))]
+        );
+
+        let later = parse_meetings(&text, utc("2026-03-03T12:00:00Z"));
+        let found: Vec<(&str, DateTime<Utc>)> = later
+            .iter()
+            .map(|m| (m.call_id.as_str(), m.begin_time))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("day-dayd-day", utc("2026-03-04T07:00:00Z")),
+                ("syn-sync-syn", utc("2026-03-04T09:00:00Z")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_value_parsing() {
+        assert_eq!(parse_duration("PT1H30M"), Some(Duration::minutes(90)));
+        assert_eq!(parse_duration("P1DT2H"), Some(Duration::hours(26)));
+        assert_eq!(parse_duration("-PT5M"), None);
+        assert_eq!(
+            parse_date_time("20260302T090000Z"),
+            Some(utc("2026-03-02T09:00:00Z"))
+        );
+        assert_eq!(unescape("a\\nb\\;c\\\\"), "a\nb;c\\");
+        let (name, params, value) =
+            split_property("DTSTART;TZID=\"Europe/Berlin:x\":20260302T090000").unwrap();
+        assert_eq!(name, "DTSTART");
+        assert_eq!(params[0].0, "TZID");
+        assert_eq!(value, "20260302T090000");
+    }
+}
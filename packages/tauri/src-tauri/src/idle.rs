@@ -0,0 +1,252 @@
+//! Detection of the user being away from the computer
+//!
+//! Used to skip an auto-join that would otherwise open a meeting with nobody
+//! at the microphone. macOS asks Quartz for the time since the last input
+//! event and whether the screen is locked; Windows reads the last input tick
+//! and the lock state [`crate::power`] receives from the session;
+//! Linux reads the idle and lock hints logind keeps for the session, which
+//! most desktop environments report. Elsewhere the state is unknown and joins
+//! go ahead.
+
+use serde::Serialize;
+
+/// How long the user has been away, as far as the OS can tell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdleState {
+    /// Seconds since the last keyboard or mouse input
+    pub idle_seconds: u64,
+    /// Whether the screen is locked; `false` where it can't be told
+    pub locked: bool,
+}
+
+impl IdleState {
+    /// Whether the user counts as away with a threshold of `minutes`
+    pub fn is_away(&self, minutes: u32) -> bool {
+        self.locked || self.idle_seconds >= u64::from(minutes) * 60
+    }
+}
+
+/// Current idle state; `None` when this platform can't tell
+pub fn current() -> Option<IdleState> {
+    #[cfg(target_os = "macos")]
+    {
+        quartz::idle_seconds().map(|idle_seconds| IdleState {
+            idle_seconds,
+            locked: quartz::screen_locked(),
+        })
+    }
+    #[cfg(target_os = "windows")]
+    {
+        win32::idle_seconds().map(|idle_seconds| IdleState {
+            idle_seconds,
+            locked: crate::power::screen_locked(),
+        })
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        if cfg!(target_os = "linux") {
+            logind_idle_state()
+        } else {
+            None
+        }
+    }
+}
+
+/// Ask logind for the idle and lock hints of this session
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn logind_idle_state() -> Option<IdleState> {
+    let session = std::env::var("XDG_SESSION_ID").unwrap_or_else(|_| "auto".to_string());
+    let output = std::process::Command::new("loginctl")
+        .args(["show-session", &session])
+        .args(["-p", "IdleHint", "-p", "IdleSinceHint", "-p", "LockedHint"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let now_usec = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?
+        .as_micros() as u64;
+    parse_logind_session(&String::from_utf8_lossy(&output.stdout), now_usec)
+}
+
+/// Parse `loginctl show-session` properties. `IdleSinceHint` is the wall
+/// clock time in microseconds when the session went idle.
+#[cfg_attr(any(target_os = "macos", target_os = "windows"), allow(dead_code))]
+fn parse_logind_session(output: &str, now_usec: u64) -> Option<IdleState> {
+    let mut idle = None;
+    let mut idle_since_usec = None;
+    let mut locked = false;
+    for line in output.lines() {
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        match key.trim() {
+            "IdleHint" => idle = Some(value.trim() == "yes"),
+            "IdleSinceHint" => idle_since_usec = value.trim().parse::<u64>().ok(),
+            "LockedHint" => locked = value.trim() == "yes",
+            _ => {}
+        }
+    }
+    let idle_seconds = match (idle?, idle_since_usec) {
+        (true, Some(since)) if since > 0 => now_usec.saturating_sub(since) / 1_000_000,
+        _ => 0,
+    };
+    Some(IdleState {
+        idle_seconds,
+        locked,
+    })
+}
+
+#[cfg(target_os = "macos")]
+mod quartz {
+    use std::ffi::{c_char, c_void};
+
+    type CFTypeRef = *const c_void;
+
+    #[link(name = "CoreGraphics", kind = "framework")]
+    extern "C" {
+        fn CGEventSourceSecondsSinceLastEventType(state_id: i32, event_type: u32) -> f64;
+        fn CGSessionCopyCurrentDictionary() -> CFTypeRef;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        fn CFStringCreateWithCString(
+            allocator: CFTypeRef,
+            c_str: *const c_char,
+            encoding: u32,
+        ) -> CFTypeRef;
+        fn CFDictionaryGetValue(dictionary: CFTypeRef, key: CFTypeRef) -> CFTypeRef;
+        fn CFGetTypeID(value: CFTypeRef) -> usize;
+        fn CFBooleanGetTypeID() -> usize;
+        fn CFBooleanGetValue(boolean: CFTypeRef) -> u8;
+        fn CFRelease(value: CFTypeRef);
+    }
+
+    /// kCGEventSourceStateCombinedSessionState
+    const COMBINED_SESSION_STATE: i32 = 0;
+    /// kCGAnyInputEventType
+    const ANY_INPUT_EVENT: u32 = u32::MAX;
+    /// kCFStringEncodingUTF8
+    const ENCODING_UTF8: u32 = 0x0800_0100;
+
+    pub fn idle_seconds() -> Option<u64> {
+        // SAFETY: plain value call with documented constants
+        let seconds = unsafe {
+            CGEventSourceSecondsSinceLastEventType(COMBINED_SESSION_STATE, ANY_INPUT_EVENT)
+        };
+        (seconds.is_finite() && seconds >= 0.0).then_some(seconds as u64)
+    }
+
+    pub fn screen_locked() -> bool {
+        // SAFETY: every object created or copied here is released before
+        // returning, and the dictionary value is only read while the
+        // dictionary is alive.
+        unsafe {
+            let session = CGSessionCopyCurrentDictionary();
+            if session.is_null() {
+                return false;
+            }
+            let key = CFStringCreateWithCString(
+                std::ptr::null(),
+                b"CGSSessionScreenIsLocked\0".as_ptr() as *const c_char,
+                ENCODING_UTF8,
+            );
+            let mut locked = false;
+            if !key.is_null() {
+                let value = CFDictionaryGetValue(session, key);
+                locked = !value.is_null()
+                    && CFGetTypeID(value) == CFBooleanGetTypeID()
+                    && CFBooleanGetValue(value) != 0;
+                CFRelease(key);
+            }
+            CFRelease(session);
+            locked
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod win32 {
+    #[repr(C)]
+    struct LastInputInfo {
+        size: u32,
+        time: u32,
+    }
+
+    #[link(name = "user32")]
+    extern "system" {
+        fn GetLastInputInfo(info: *mut LastInputInfo) -> i32;
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetTickCount() -> u32;
+    }
+
+    pub fn idle_seconds() -> Option<u64> {
+        let mut info = LastInputInfo {
+            size: std::mem::size_of::<LastInputInfo>() as u32,
+            time: 0,
+        };
+        // SAFETY: `info` outlives the call and its size field is set as the
+        // API requires.
+        let ok = unsafe { GetLastInputInfo(&mut info) } != 0;
+        if !ok {
+            return None;
+        }
+        // Both tick counts wrap after 49.7 days
+        let now = unsafe { GetTickCount() };
+        Some(u64::from(now.wrapping_sub(info.time)) / 1000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_away() {
+        let active = IdleState {
+            idle_seconds: 30,
+            locked: false,
+        };
+        assert!(!active.is_away(5));
+        assert!(IdleState {
+            idle_seconds: 300,
+            ..active
+        }
+        .is_away(5));
+        assert!(IdleState {
+            locked: true,
+            ..active
+        }
+        .is_away(5));
+    }
+
+    #[test]
+    fn test_parse_logind_session() {
+        let now = 1_000_000_000_000;
+        let idle = "IdleHint=yes\nIdleSinceHint=999400000000\nLockedHint=no\n";
+        assert_eq!(
+            parse_logind_session(idle, now),
+            Some(IdleState {
+                idle_seconds: 600,
+                locked: false,
+            })
+        );
+
+        let active = "IdleHint=no\nIdleSinceHint=0\nLockedHint=yes\n";
+        assert_eq!(
+            parse_logind_session(active, now),
+            Some(IdleState {
+                idle_seconds: 0,
+                locked: true,
+            })
+        );
+
+        // Sessions without the hints can't tell
+        assert_eq!(parse_logind_session("", now), None);
+    }
+}
@@ -0,0 +1,720 @@
+//! Events emitted to the webviews, with their payloads
+//!
+//! Every event name is defined in [`event`], and every payload either has a
+//! struct here or is a type owned by the module that produces it. [`schema`]
+//! describes each event's payload as JSON Schema; `get_ipc_schema` returns it
+//! so the types in `tauri-bridge.ts` can be validated or generated from what
+//! Rust actually sends instead of being kept in step by hand.
+
+use crate::daemon::{JoinBlockedReason, Meeting};
+use crate::settings::Settings;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::path::PathBuf;
+
+/// Bumped when a payload changes incompatibly
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Event names
+pub mod event {
+    pub const CHECK_MEETINGS: &str = "check-meetings";
+    pub const SCRIPT_PING: &str = "script-ping";
+    pub const NAVIGATE_AND_JOIN: &str = "navigate-and-join";
+    pub const NAVIGATE_TO_GREEN_ROOM: &str = "navigate-to-green-room";
+    pub const GREEN_ROOM_JOIN: &str = "green-room-join";
+    pub const JOIN_REMINDER: &str = "join-reminder";
+    pub const MEETING_CONFLICT: &str = "meeting-conflict";
+    pub const MEETING_LIFECYCLE: &str = "meeting:lifecycle";
+    pub const MEETING_VERIFIED: &str = "meeting:verified";
+    pub const JOIN_BLOCKED: &str = "join:blocked";
+    pub const JOIN_RATE_CAPPED: &str = "join:rate-capped";
+    pub const WINDOW_SHOWN: &str = "window:shown";
+    pub const TOGGLE_MIC: &str = "shortcut:toggle-mic";
+    pub const LEAVE_MEETING: &str = "tray:leave-meeting";
+    pub const COPY_MEETING_LINK: &str = "tray:copy-meeting-link";
+    pub const SETTINGS_CHANGED: &str = "settings_changed";
+    pub const SETTINGS_DELTA: &str = "settings-delta";
+    pub const SETTINGS_RECOVERED: &str = "settings:recovered";
+    pub const HISTORY_NOTE_OPEN_DIALOG: &str = "history-note:open-dialog";
+    pub const JOIN_BY_CODE_OPEN_DIALOG: &str = "join-by-code:open-dialog";
+    pub const PERSISTENCE_QUARANTINED: &str = "persistence:quarantined";
+    pub const UPDATE_AVAILABLE: &str = "update:available";
+    pub const UPDATE_PREFERENCE_CHANGED: &str = "update:preference-changed";
+    pub const UPDATE_OPEN_DIALOG: &str = "update:open-dialog";
+    pub const UPDATE_MANUAL_CHECK: &str = "update:manual-check";
+    pub const UPDATE_DOWNLOAD_PROGRESS: &str = "update:download-progress";
+    pub const UPDATE_DOWNLOAD_FINISH: &str = "update:download-finish";
+}
+
+/// Payload of `check-meetings`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckMeetingsPayload {
+    pub check_id: u64,
+    pub interval_seconds: u32,
+    pub emitted_at_ms: u64,
+}
+
+/// Payload of `script-ping`, answered with `script_pong`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptPing {
+    pub ping_id: u64,
+}
+
+/// Payload of `navigate-and-join`, `navigate-to-green-room` and
+/// `green-room-join`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NavigateAndJoinCommand {
+    pub url: String,
+    pub settings: Settings,
+    /// Per-meeting override of `settings.auto_click_join`; `Some(false)` only
+    /// navigates and leaves clicking Join to the user
+    pub auto_click_join: Option<bool>,
+    /// Correlation id shared by every log entry of this join attempt
+    pub join_id: String,
+    /// Label of the window that acts on the command; the other Meet windows
+    /// ignore it
+    pub window: String,
+}
+
+/// Payload of `join-reminder`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JoinReminderPayload {
+    pub meeting: Meeting,
+    pub join_at_ms: i64,
+    pub minutes_before: u32,
+}
+
+/// Payload of `meeting-conflict`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingConflictPayload {
+    pub meetings: Vec<Meeting>,
+}
+
+/// Payload of `meeting:verified`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingVerified {
+    pub call_id: String,
+    pub verification: crate::lifecycle::JoinVerification,
+}
+
+/// What the user can do instead after Google Meet refused a join
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum JoinBlockedSuggestion {
+    /// Open the meeting in the default browser, where another account may be
+    /// signed in
+    #[serde(rename_all = "camelCase")]
+    OpenInBrowser { url: String },
+}
+
+/// Payload of `join:blocked`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JoinBlockedNotice {
+    pub call_id: String,
+    pub reason: JoinBlockedReason,
+    pub suggestion: JoinBlockedSuggestion,
+}
+
+/// Payload of `shortcut:toggle-mic`: the window whose call is muted or
+/// unmuted
+#[derive(Debug, Clone, Serialize)]
+pub struct ToggleMicCommand {
+    pub window: String,
+}
+
+/// Payload of `tray:leave-meeting`: the window whose call to leave
+#[derive(Debug, Clone, Serialize)]
+pub struct LeaveMeetingCommand {
+    pub window: String,
+}
+
+/// Payload of `tray:copy-meeting-link`: the window in the meeting and the
+/// link to copy
+#[derive(Debug, Clone, Serialize)]
+pub struct CopyMeetingLinkCommand {
+    pub window: String,
+    pub url: String,
+}
+
+/// Payload of `settings-delta`: the keys changed by one save, with their old
+/// and new values under `changes`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsDelta {
+    pub changed_keys: Vec<String>,
+    pub changes: Value,
+}
+
+/// Payload of `settings:recovered`: the settings file could not be read and
+/// was restored from the last good copy
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsRecovered {
+    pub restored_from: PathBuf,
+    pub quarantined_to: PathBuf,
+    pub reason: String,
+}
+
+/// Payload of `update:download-progress`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDownloadProgress {
+    pub downloaded: u64,
+    pub total: Option<u64>,
+    pub percent: Option<f64>,
+}
+
+/// JSON Schema of every event's payload, keyed by event name
+pub fn schema() -> Value {
+    let events: Map<String, Value> = events()
+        .into_iter()
+        .map(|(name, payload)| (name.to_string(), payload))
+        .collect();
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "MeetCat IPC events",
+        "version": SCHEMA_VERSION,
+        "$defs": {
+            "Meeting": object(
+                &[
+                    ("call_id", string()),
+                    ("url", string()),
+                    ("title", string()),
+                    ("display_time", string()),
+                    ("begin_time", date_time()),
+                    ("end_time", date_time()),
+                    ("event_id", nullable(string())),
+                    ("starts_in_minutes", integer()),
+                    ("profile", string()),
+                    ("accuracy", string_enum(&["exact", "inferred", "unknown"])),
+                ],
+                &["profile"],
+            ),
+            "MeetingPhase": string_enum(&[
+                "scheduled",
+                "triggered",
+                "navigating",
+                "joined",
+                "left",
+                "suppressed",
+                "failed",
+                "expired",
+            ]),
+            // Validated by the zod schema of the settings package
+            "Settings": {
+                "type": "object",
+                "description": "Settings as saved; see packages/settings/src/schema.ts",
+            },
+        },
+        "events": events,
+    })
+}
+
+/// Every event with the schema of its payload
+fn events() -> Vec<(&'static str, Value)> {
+    let navigate = object(
+        &[
+            ("url", string()),
+            ("settings", reference("Settings")),
+            ("autoClickJoin", nullable(boolean())),
+            ("joinId", string()),
+            ("window", string()),
+        ],
+        &[],
+    );
+    let window_command = object(&[("window", string())], &[]);
+    vec![
+        (
+            event::CHECK_MEETINGS,
+            object(
+                &[
+                    ("checkId", integer()),
+                    ("intervalSeconds", integer()),
+                    ("emittedAtMs", integer()),
+                ],
+                &[],
+            ),
+        ),
+        (event::SCRIPT_PING, object(&[("pingId", integer())], &[])),
+        (event::NAVIGATE_AND_JOIN, navigate.clone()),
+        (event::NAVIGATE_TO_GREEN_ROOM, navigate.clone()),
+        (event::GREEN_ROOM_JOIN, navigate),
+        (
+            event::JOIN_REMINDER,
+            object(
+                &[
+                    ("meeting", reference("Meeting")),
+                    ("joinAtMs", integer()),
+                    ("minutesBefore", integer()),
+                ],
+                &[],
+            ),
+        ),
+        (
+            event::MEETING_CONFLICT,
+            object(&[("meetings", array(reference("Meeting")))], &[]),
+        ),
+        (
+            event::MEETING_LIFECYCLE,
+            object(
+                &[
+                    ("call_id", string()),
+                    ("from", nullable(reference("MeetingPhase"))),
+                    ("to", reference("MeetingPhase")),
+                    ("at_ms", integer()),
+                ],
+                &[],
+            ),
+        ),
+        (
+            event::MEETING_VERIFIED,
+            object(
+                &[
+                    ("callId", string()),
+                    (
+                        "verification",
+                        string_enum(&["unverified", "green_room", "in_call"]),
+                    ),
+                ],
+                &[],
+            ),
+        ),
+        (
+            event::JOIN_BLOCKED,
+            object(
+                &[
+                    ("callId", string()),
+                    (
+                        "reason",
+                        string_enum(&["not_allowed", "domain_restricted", "unknown"]),
+                    ),
+                    (
+                        "suggestion",
+                        object(
+                            &[("kind", string_enum(&["openInBrowser"])), ("url", string())],
+                            &[],
+                        ),
+                    ),
+                ],
+                &[],
+            ),
+        ),
+        (event::JOIN_RATE_CAPPED, null_type()),
+        (
+            event::WINDOW_SHOWN,
+            object(
+                &[
+                    ("atMs", integer()),
+                    ("label", string()),
+                    (
+                        "cause",
+                        string_enum(&[
+                            "auto_join",
+                            "green_room",
+                            "background_join",
+                            "rate_capped",
+                            "tray",
+                            "reopen",
+                            "shortcut",
+                            "deep_link",
+                            "navigate_home",
+                        ]),
+                    ),
+                    ("callId", nullable(string())),
+                    ("title", nullable(string())),
+                ],
+                &[],
+            ),
+        ),
+        (event::TOGGLE_MIC, window_command.clone()),
+        (event::LEAVE_MEETING, window_command),
+        (
+            event::COPY_MEETING_LINK,
+            object(&[("window", string()), ("url", string())], &[]),
+        ),
+        (event::SETTINGS_CHANGED, reference("Settings")),
+        (
+            event::SETTINGS_DELTA,
+            object(
+                &[
+                    ("changedKeys", array(string())),
+                    (
+                        "changes",
+                        json!({
+                            "type": "object",
+                            "additionalProperties": object(&[("from", any()), ("to", any())], &[]),
+                        }),
+                    ),
+                ],
+                &[],
+            ),
+        ),
+        (
+            event::SETTINGS_RECOVERED,
+            object(
+                &[
+                    ("restoredFrom", string()),
+                    ("quarantinedTo", string()),
+                    ("reason", string()),
+                ],
+                &[],
+            ),
+        ),
+        (
+            event::HISTORY_NOTE_OPEN_DIALOG,
+            object(
+                &[
+                    ("callId", string()),
+                    ("recordedAtMs", integer()),
+                    ("title", string()),
+                ],
+                &[],
+            ),
+        ),
+        (event::JOIN_BY_CODE_OPEN_DIALOG, null_type()),
+        (
+            event::PERSISTENCE_QUARANTINED,
+            object(
+                &[
+                    ("original", string()),
+                    ("movedTo", string()),
+                    ("reason", string()),
+                ],
+                &[],
+            ),
+        ),
+        (
+            event::UPDATE_AVAILABLE,
+            nullable(object(
+                &[("version", string()), ("notes", nullable(string()))],
+                &[],
+            )),
+        ),
+        (
+            event::UPDATE_PREFERENCE_CHANGED,
+            object(
+                &[
+                    ("skippedVersion", string()),
+                    ("remindVersion", string()),
+                    ("remindUntilMs", integer()),
+                ],
+                &["skippedVersion", "remindVersion", "remindUntilMs"],
+            ),
+        ),
+        (event::UPDATE_OPEN_DIALOG, null_type()),
+        (event::UPDATE_MANUAL_CHECK, null_type()),
+        (
+            event::UPDATE_DOWNLOAD_PROGRESS,
+            object(
+                &[
+                    ("downloaded", integer()),
+                    ("total", nullable(integer())),
+                    ("percent", nullable(number())),
+                ],
+                &[],
+            ),
+        ),
+        (event::UPDATE_DOWNLOAD_FINISH, null_type()),
+    ]
+}
+
+/// A closed object; every property not listed in `optional` is required
+fn object(properties: &[(&str, Value)], optional: &[&str]) -> Value {
+    let required: Vec<&str> = properties
+        .iter()
+        .map(|(name, _)| *name)
+        .filter(|name| !optional.contains(name))
+        .collect();
+    let properties: Map<String, Value> = properties
+        .iter()
+        .map(|(name, schema)| (name.to_string(), schema.clone()))
+        .collect();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+fn string() -> Value {
+    json!({ "type": "string" })
+}
+
+fn date_time() -> Value {
+    json!({ "type": "string", "format": "date-time" })
+}
+
+fn integer() -> Value {
+    json!({ "type": "integer" })
+}
+
+fn number() -> Value {
+    json!({ "type": "number" })
+}
+
+fn boolean() -> Value {
+    json!({ "type": "boolean" })
+}
+
+fn null_type() -> Value {
+    json!({ "type": "null" })
+}
+
+fn any() -> Value {
+    json!({})
+}
+
+fn array(items: Value) -> Value {
+    json!({ "type": "array", "items": items })
+}
+
+fn string_enum(values: &[&str]) -> Value {
+    json!({ "type": "string", "enum": values })
+}
+
+fn nullable(schema: Value) -> Value {
+    json!({ "anyOf": [schema, null_type()] })
+}
+
+fn reference(name: &str) -> Value {
+    json!({ "$ref": format!("#/$defs/{}", name) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::lifecycle::{JoinVerification, LifecycleEvent, MeetingPhase, PhaseTransition};
+    use crate::window_activity::{ShowCause, WindowActivity};
+    use chrono::{TimeZone, Utc};
+    use std::collections::HashSet;
+
+    /// Check `value` against the subset of JSON Schema that [`schema`] uses
+    fn conforms(value: &Value, schema: &Value, defs: &Value) -> Result<(), String> {
+        if let Some(target) = schema.get("$ref").and_then(Value::as_str) {
+            let name = target.trim_start_matches("#/$defs/");
+            return conforms(value, &defs[name], defs);
+        }
+        if let Some(options) = schema.get("anyOf").and_then(Value::as_array) {
+            return options
+                .iter()
+                .any(|option| conforms(value, option, defs).is_ok())
+                .then_some(())
+                .ok_or_else(|| format!("{} matches no option", value));
+        }
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            if !values.contains(value) {
+                return Err(format!("{} is not one of {:?}", value, values));
+            }
+        }
+        let type_ok = match schema.get("type").and_then(Value::as_str) {
+            None => true,
+            Some("object") => value.is_object(),
+            Some("array") => value.is_array(),
+            Some("string") => value.is_string(),
+            Some("integer") => value.is_i64() || value.is_u64(),
+            Some("number") => value.is_number(),
+            Some("boolean") => value.is_boolean(),
+            Some("null") => value.is_null(),
+            Some(other) => return Err(format!("unsupported type {}", other)),
+        };
+        if !type_ok {
+            return Err(format!("{} is not {}", value, schema["type"]));
+        }
+        if let Some(object) = value.as_object() {
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for name in schema["required"].as_array().into_iter().flatten() {
+                let name = name.as_str().unwrap_or_default();
+                if !object.contains_key(name) {
+                    return Err(format!("missing {}", name));
+                }
+            }
+            for (name, field) in object {
+                match properties.and_then(|properties| properties.get(name)) {
+                    Some(field_schema) => conforms(field, field_schema, defs)
+                        .map_err(|e| format!("{}: {}", name, e))?,
+                    None => match schema.get("additionalProperties") {
+                        Some(Value::Bool(false)) => return Err(format!("unexpected {}", name)),
+                        Some(extra) => conforms(field, extra, defs)?,
+                        None => {}
+                    },
+                }
+            }
+        }
+        if let (Some(items), Some(schema)) = (value.as_array(), schema.get("items")) {
+            for item in items {
+                conforms(item, schema, defs)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn meeting() -> Meeting {
+        let now = Utc.with_ymd_and_hms(2026, 3, 2, 9, 55, 0).unwrap();
+        fixtures::meeting_at("abc-defg-hij", "Standup", now, 5, 30)
+    }
+
+    fn to_value<T: Serialize>(payload: &T) -> Value {
+        serde_json::to_value(payload).expect("payload serializes")
+    }
+
+    /// A sample payload of every event type
+    fn samples() -> Vec<(&'static str, Value)> {
+        let navigate = NavigateAndJoinCommand {
+            url: meeting().url,
+            settings: Settings::default(),
+            auto_click_join: None,
+            join_id: "join-1".to_string(),
+            window: "main".to_string(),
+        };
+        let mut changes = Map::new();
+        changes.insert(
+            "tauri.powerMode".to_string(),
+            json!({ "from": "auto", "to": "eco" }),
+        );
+        vec![
+            (
+                event::CHECK_MEETINGS,
+                to_value(&CheckMeetingsPayload {
+                    check_id: 1,
+                    interval_seconds: 30,
+                    emitted_at_ms: 1_000,
+                }),
+            ),
+            (event::SCRIPT_PING, to_value(&ScriptPing { ping_id: 7 })),
+            (event::NAVIGATE_AND_JOIN, to_value(&navigate)),
+            (event::SETTINGS_CHANGED, to_value(&navigate.settings)),
+            (
+                event::JOIN_REMINDER,
+                to_value(&JoinReminderPayload {
+                    meeting: Meeting {
+                        profile: Some("work".to_string()),
+                        event_id: Some("evt".to_string()),
+                        ..meeting()
+                    },
+                    join_at_ms: 1_000,
+                    minutes_before: 2,
+                }),
+            ),
+            (
+                event::MEETING_CONFLICT,
+                to_value(&MeetingConflictPayload {
+                    meetings: vec![meeting()],
+                }),
+            ),
+            (
+                event::MEETING_LIFECYCLE,
+                to_value(&LifecycleEvent {
+                    call_id: "abc-defg-hij".to_string(),
+                    transition: PhaseTransition {
+                        from: Some(MeetingPhase::Scheduled),
+                        to: MeetingPhase::Triggered,
+                        at_ms: 1_000,
+                    },
+                }),
+            ),
+            (
+                event::MEETING_VERIFIED,
+                to_value(&MeetingVerified {
+                    call_id: "abc-defg-hij".to_string(),
+                    verification: JoinVerification::InCall,
+                }),
+            ),
+            (
+                event::JOIN_BLOCKED,
+                to_value(&JoinBlockedNotice {
+                    call_id: "abc-defg-hij".to_string(),
+                    reason: JoinBlockedReason::DomainRestricted,
+                    suggestion: JoinBlockedSuggestion::OpenInBrowser { url: meeting().url },
+                }),
+            ),
+            (event::JOIN_RATE_CAPPED, to_value(&())),
+            (
+                event::WINDOW_SHOWN,
+                to_value(&WindowActivity {
+                    at_ms: 1_000,
+                    label: "main".to_string(),
+                    cause: ShowCause::DeepLink,
+                    call_id: None,
+                    title: None,
+                }),
+            ),
+            (
+                event::TOGGLE_MIC,
+                to_value(&ToggleMicCommand {
+                    window: "main".to_string(),
+                }),
+            ),
+            (
+                event::LEAVE_MEETING,
+                to_value(&LeaveMeetingCommand {
+                    window: "main".to_string(),
+                }),
+            ),
+            (
+                event::COPY_MEETING_LINK,
+                to_value(&CopyMeetingLinkCommand {
+                    window: "main".to_string(),
+                    url: meeting().url,
+                }),
+            ),
+            (
+                event::SETTINGS_DELTA,
+                to_value(&SettingsDelta {
+                    changed_keys: vec!["tauri.powerMode".to_string()],
+                    changes: Value::Object(changes),
+                }),
+            ),
+            (
+                event::SETTINGS_RECOVERED,
+                to_value(&SettingsRecovered {
+                    restored_from: PathBuf::from("settings.json.bak"),
+                    quarantined_to: PathBuf::from("settings.json.corrupt-1"),
+                    reason: "EOF while parsing".to_string(),
+                }),
+            ),
+            (
+                event::UPDATE_DOWNLOAD_PROGRESS,
+                to_value(&UpdateDownloadProgress {
+                    downloaded: 10,
+                    total: Some(100),
+                    percent: Some(10.0),
+                }),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_event_names_are_unique() {
+        let names: Vec<&str> = events().into_iter().map(|(name, _)| name).collect();
+        let unique: HashSet<&str> = names.iter().copied().collect();
+        assert_eq!(unique.len(), names.len());
+        for streamed in crate::api_server::STREAMED_EVENTS {
+            assert!(unique.contains(streamed), "{} has no schema", streamed);
+        }
+    }
+
+    #[test]
+    fn test_payloads_match_schema() {
+        let schema = schema();
+        let defs = &schema["$defs"];
+        for (name, payload) in samples() {
+            let event_schema = &schema["events"][name];
+            assert!(!event_schema.is_null(), "{} has no schema", name);
+            if let Err(e) = conforms(&payload, event_schema, defs) {
+                panic!("{} payload drifted from its schema: {}", name, e);
+            }
+        }
+        // Extra fields are reported
+        let extra = json!({ "pingId": 1, "sentAtMs": 2 });
+        assert!(conforms(&extra, &schema["events"][event::SCRIPT_PING], defs).is_err());
+    }
+}
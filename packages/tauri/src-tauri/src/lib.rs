@@ -3,26 +3,102 @@
 //! Main application logic with WebView script injection, IPC communication,
 //! and background daemon for meeting scheduling.
 
-mod daemon;
+mod accessibility;
+mod api_server;
+mod arbiter;
+mod audio_input;
+mod background;
+mod calendar;
+mod cli;
+mod connectivity;
+pub mod daemon;
+mod desktop_notify;
+mod diagnostics;
+pub mod filters;
+mod format;
+#[doc(hidden)]
+pub mod fixtures;
+mod health;
+mod history;
+mod http_client;
 pub mod i18n;
+mod ics;
+mod idle;
+mod ipc;
+pub mod lifecycle;
+mod locks;
+mod log_digest;
 mod logging;
-mod settings;
-mod tray;
+pub mod merge;
+mod migrations;
+mod mqtt;
+mod paths;
+mod permissions;
+mod persistence;
+mod power;
+mod presence;
+mod profiles;
+mod recent_codes;
+mod secrets;
+pub mod settings;
+mod settings_save;
+mod settings_window;
+mod shortcuts;
+mod soak;
+mod source_quota;
+pub mod tray;
+mod trigger_actor;
+mod troubleshooter;
+pub mod url_norm;
 mod url_scheme;
-
-use daemon::{DaemonState, Meeting};
-use logging::{now_ms, LogEventInput, LogManager};
+mod webhooks;
+mod webview_health;
+mod widget;
+mod window_activity;
+
+use arbiter::{ActiveCall, MeetingArbiter};
+use background::{
+    ActivePowerMode, BackgroundMetrics, BackgroundTask, MaintenanceState, TaskCoordinator,
+};
+use daemon::{
+    DaemonState, JoinBlockedReason, Meeting, OutOfOfficeChange, PersistedDaemonState,
+    ScriptWatchdog, SourceSnapshotInfo, Suppression, SuppressionReason, WatchdogAction,
+};
+use health::{HealingRecord, HealthBudget, HealthMonitor, HealthReport};
+use history::{
+    HistoryEntry, HistoryNoteTarget, HistoryOutcome, HistoryRange, HistoryRecorder, HistorySummary,
+    MeetingHistory,
+};
+use ipc::{
+    event, CheckMeetingsPayload, JoinBlockedNotice, JoinBlockedSuggestion, JoinReminderPayload,
+    MeetingConflictPayload, MeetingVerified, NavigateAndJoinCommand, ScriptPing, SettingsDelta,
+    ToggleMicCommand, UpdateDownloadProgress,
+};
+use lifecycle::{
+    CallPresence, JoinVerification, LifecycleEvent, MeetingLifecycle, MeetingPhase, OverdueJoin,
+    TransitionError,
+};
+use locks::LockExt;
+use logging::{now_ms, LogEntry, LogEventInput, LogManager, LogQuery, LoggerHandle};
+use merge::FieldOrigins;
+use persistence::{LoadedState, QuarantinedFile, VersionedState};
+use recent_codes::RecentMeetingCodes;
+use secrets::{Keychain, SecretName, SecretStore};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use settings::{LogLevel, Settings, TAURI_DEFAULT_CHECK_INTERVAL_SECONDS};
+use settings::{
+    FieldError, FieldErrors, LogLevel, MeetingSource, PowerMode, Settings, UnknownLogLevel,
+    TAURI_DEFAULT_CHECK_INTERVAL_SECONDS,
+};
+use source_quota::{RequestScheduler, SourceRequestMetrics};
+use std::collections::{BTreeMap, HashMap};
 use std::error::Error as StdError;
 use std::fs;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tauri::async_runtime::JoinHandle;
-#[cfg(target_os = "macos")]
 use tauri::menu::{AboutMetadata, MenuBuilder, MenuItem, SubmenuBuilder};
 use tauri::webview::PageLoadEvent;
 use tauri::{
@@ -30,21 +106,69 @@ use tauri::{
     WebviewWindowBuilder,
 };
 use tauri_plugin_opener::OpenerExt;
-use tauri_plugin_updater::UpdaterExt;
+use tokio::task::AbortHandle;
 
-use url_scheme::DeepLinkAction;
+use settings_save::SaveDebouncer;
+use settings_window::SettingsWindowError;
+use shortcuts::{ShortcutAction, ShortcutRegistry, ShortcutStatus};
+use url_scheme::{CallbackError, DeepLinkAction, XCallback};
+use webview_health::{HiddenTracker, LoadOutcome, LoadTracker, LOAD_TIMEOUT_MS};
+use window_activity::{ShowCause, WindowActivity, WindowActivityLog};
 
 const MEET_HOME_URL: &str = "https://meet.google.com/";
 const MEETCAT_AUTO_JOIN_PARAM: &str = "meetcatAuto";
-const UPDATE_CHECK_INTERVAL_SECONDS: u64 = 24 * 60 * 60;
 const UPDATE_PROMPT_PREFERENCE_FILE: &str = "update-prompt-preference.json";
-
-/// Application state shared across commands
+const DAEMON_STATE_FILE: &str = "daemon-state.json";
+const RECENT_CODES_FILE: &str = "recent-meeting-codes.json";
+const MEETING_HISTORY_FILE: &str = "meeting-history.jsonl";
+/// Length of the pause started from the tray
+const TRAY_PAUSE_MINUTES: i64 = 60;
+/// How long before an auto-join the cancellable notification is posted
+const JOIN_NOTICE_SECONDS: u64 = 10;
+/// Longest single timer wait of a join trigger, so a suspend delays it by at
+/// most this much past its wall-clock time
+const WALL_CLOCK_RECHECK: Duration = Duration::from_secs(30);
+/// How long the end-of-meeting summary offers to add a note
+const MEETING_SUMMARY_TIMEOUT: Duration = Duration::from_secs(60);
+/// How long a link waits for the user to allow it before it is declined
+const DEEP_LINK_CONFIRM_TIMEOUT: Duration = Duration::from_secs(60);
+/// Checks, and the time between them, for a destroyed main window to
+/// release its label before it is recreated
+const WEBVIEW_RELEASE_POLLS: u32 = 50;
+const WEBVIEW_RELEASE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Time a recreated main window gets to load and inject its scripts before
+/// `check-meetings` is replayed
+const WEBVIEW_RECOVERY_SETTLE: Duration = Duration::from_secs(5);
+const PARSER_SELFTEST_TIMEOUT_MS: u64 = 10_000;
+/// The troubleshooter's page probe includes a request to meet.google.com
+const TROUBLESHOOT_PROBE_TIMEOUT_MS: u64 = 15_000;
+/// Real time between soak-test engine steps
+const SOAK_TICK_MS: u64 = 1000;
+/// Soak-test ticks between progress log entries
+const SOAK_PROGRESS_TICKS: u64 = 300;
+/// Window shows returned by `get_window_activity` without a limit
+const DEFAULT_WINDOW_ACTIVITY_LIMIT: usize = 20;
+/// Why joins and meeting navigation are refused while demo mode is on
+const DEMO_MODE_ERROR: &str = "Demo mode is on; meetings can't be joined";
+
+/// Application state shared across commands.
+///
+/// Only join trigger scheduling goes through an actor (`join_trigger`);
+/// everything else is locked in place. A caller needing both `settings` and
+/// `daemon` locks `settings` first, and neither is held while emitting
+/// events, updating the tray or rescheduling the trigger.
 pub struct AppState {
     pub settings: Mutex<Settings>,
+    /// Title filters compiled from `settings`, replaced while holding the
+    /// `settings` lock whenever they change
+    pub title_filters: Mutex<Arc<filters::TitleFilters>>,
+    /// Pending debounced write of `settings`
+    pub settings_save: Mutex<SaveDebouncer>,
     pub daemon: Mutex<DaemonState>,
-    /// Handle to cancel the current join trigger timer
-    pub join_trigger_handle: Mutex<Option<JoinHandle<()>>>,
+    /// Owns the join trigger timer and its reminder, and reschedules them
+    pub join_trigger: trigger_actor::TriggerActor,
+    /// Call ID and window label of a join opened without raising its window
+    pub background_join: Mutex<Option<(String, String)>>,
     pub update_checking: Mutex<bool>,
     pub update_info: Mutex<Option<UpdateInfo>>,
     pub update_prompt_preference: Mutex<UpdatePromptPreference>,
@@ -57,32 +181,193 @@ pub struct AppState {
     /// dispatched immediately, to avoid racing with the cold-start initial
     /// load (which intermittently swallows our `webview.navigate(...)`).
     pub main_first_load_done: AtomicBool,
-    pub pending_deep_link: Mutex<Option<DeepLinkAction>>,
-    pub logger: Mutex<LogManager>,
-    #[cfg(target_os = "macos")]
+    pub pending_deep_link: Mutex<Option<(DeepLinkAction, XCallback)>>,
+    pub logger: LoggerHandle,
+    /// Page-load failure tracking for the main webview
+    pub webview_health: Mutex<LoadTracker>,
+    /// How long the main window has been hidden, to refresh a stale page
+    pub main_hidden: Mutex<HiddenTracker>,
+    /// Join and parser success rates for the self-healing error budget
+    pub health: Mutex<HealthMonitor>,
+    /// Pings to the script injected into the main window
+    pub script_watchdog: Mutex<ScriptWatchdog>,
+    /// Shared timer for URL polling, meeting checks, tray refresh and updates
+    pub background: Mutex<TaskCoordinator>,
+    /// Wakes the background timer early, e.g. after settings change
+    pub background_wake: tokio::sync::Notify,
+    /// Parser self-test runs waiting for the webview's report, by request id
+    pub parser_selftests: Mutex<HashMap<String, tokio::sync::oneshot::Sender<serde_json::Value>>>,
+    /// Troubleshooter page probes waiting for the webview's report, by request id
+    pub troubleshoot_probes:
+        Mutex<HashMap<String, tokio::sync::oneshot::Sender<serde_json::Value>>>,
+    /// Last calendar text read from each ICS subscription, kept for failed refreshes
+    pub ics_calendars: Mutex<HashMap<String, String>>,
+    /// Request budgets of the sources that poll remote services
+    pub request_scheduler: Mutex<RequestScheduler>,
+    /// Calls active in each window, to keep joins from overlapping
+    pub arbiter: Mutex<MeetingArbiter>,
+    /// State files moved aside at startup, reported once the app is running
+    pub quarantined_files: Mutex<Vec<QuarantinedFile>>,
+    /// Set when the settings file was unreadable at startup and restored
+    /// from its last good copy
+    pub settings_recovered: Mutex<Option<ipc::SettingsRecovered>>,
+    /// Meeting codes joined from the tray, most recent first
+    pub recent_codes: Mutex<RecentMeetingCodes>,
+    pub join_by_code_dialog_requested: Mutex<bool>,
+    /// Last widget data write error, so a failing path is logged once
+    pub widget_write_error: Mutex<Option<String>>,
+    /// History entry the settings window should offer to annotate
+    pub history_note_request: Mutex<Option<HistoryNoteTarget>>,
+    /// Folds lifecycle events into meeting history entries
+    pub history: Mutex<HistoryRecorder>,
+    /// Set while the soak-test shadow engine is running
+    pub soak_running: AtomicBool,
+    /// Why MeetCat recently showed its windows
+    pub window_activity: Mutex<WindowActivityLog>,
+    /// Registered global shortcuts and the outcome of each binding
+    pub shortcuts: Mutex<ShortcutRegistry>,
+    /// Whether the main window shows Meet home, which enables "Refresh Home"
     pub homepage_active: Mutex<Option<bool>>,
+    /// Local control API, while enabled and listening
+    pub api_server: Mutex<Option<api_server::ApiServerHandle>>,
+    /// Daemon events fanned out to the control API's WebSocket clients
+    pub api_events: tokio::sync::broadcast::Sender<String>,
+    /// MQTT publisher, while enabled
+    pub mqtt: Mutex<Option<mqtt::MqttHandle>>,
+    /// Meeting the chat status providers currently show
+    pub presence: Mutex<presence::PresenceSync>,
+    /// Screen lock and sleep state; joins wait while it holds them
+    pub power: Mutex<power::PowerState>,
+    /// Network reachability; joins wait while offline
+    pub connectivity: Mutex<connectivity::Connectivity>,
 }
 
 impl Default for AppState {
     fn default() -> Self {
-        let settings = Settings::load().unwrap_or_default();
-        let logger = LogManager::new(&settings);
-        let update_prompt_preference = load_update_prompt_preference();
+        let loaded_settings = Settings::load().ok();
+        let settings_migration = loaded_settings.as_ref().and_then(|loaded| {
+            loaded
+                .migrated_from
+                .map(|from| (from, loaded.migrations.clone(), loaded.backup.clone()))
+        });
+        if let Some((from, names, backup)) = &settings_migration {
+            println!(
+                "[MeetCat] Upgraded settings from schema {} ({})",
+                from,
+                names.join(", ")
+            );
+            if let Some(backup) = backup {
+                println!("[MeetCat] Previous settings kept at {}", backup.display());
+            }
+        }
+        let (mut settings, settings_quarantined, settings_recovered) = match loaded_settings {
+            Some(loaded) => {
+                let recovered = loaded.recovered_from.zip(loaded.quarantined.as_ref()).map(
+                    |(restored_from, quarantined)| ipc::SettingsRecovered {
+                        restored_from,
+                        quarantined_to: quarantined.moved_to.clone(),
+                        reason: quarantined.reason.clone(),
+                    },
+                );
+                (loaded.settings, loaded.quarantined, recovered)
+            }
+            None => (Settings::default(), None, None),
+        };
+        let secrets_migration = secrets::migrate_plaintext(&mut settings, &Keychain);
+        if !secrets_migration.migrated.is_empty() {
+            match settings.save() {
+                Ok(()) => println!(
+                    "[MeetCat] Moved {} secret(s) from settings to the OS keychain",
+                    secrets_migration.migrated.len()
+                ),
+                Err(e) => eprintln!("[MeetCat] Failed to save migrated settings: {}", e),
+            }
+        }
+        for (name, e) in &secrets_migration.failed {
+            eprintln!(
+                "[MeetCat] Keeping {:?} in settings, OS keychain unavailable: {}",
+                name, e
+            );
+        }
+        let mut logger = LogManager::new(&settings);
+        if let Some((from, names, backup)) = settings_migration {
+            logger.log_internal(
+                LogLevel::Info,
+                "settings",
+                "settings.migrated",
+                None,
+                Some(serde_json::json!({
+                    "fromVersion": from,
+                    "toVersion": migrations::current_version(),
+                    "migrations": names,
+                    "backup": backup,
+                })),
+            );
+        }
+        let background = TaskCoordinator::new(now_ms(), settings.check_interval_seconds);
+        let update_prompt_outcome = load_update_prompt_preference();
+        if let Some(version) = update_prompt_outcome.migrated_from {
+            println!(
+                "[MeetCat] Upgraded update prompt preference from version {}",
+                version
+            );
+        }
+        let daemon_outcome = load_daemon_state();
+        let recent_codes_outcome = load_recent_codes();
+        let quarantined_files = settings_quarantined
+            .into_iter()
+            .chain(update_prompt_outcome.quarantined)
+            .chain(daemon_outcome.quarantined)
+            .chain(recent_codes_outcome.quarantined)
+            .collect();
+        let title_filters = Arc::new(filters::TitleFilters::from_settings(&settings));
         Self {
             settings: Mutex::new(settings),
-            daemon: Mutex::new(DaemonState::default()),
-            join_trigger_handle: Mutex::new(None),
+            title_filters: Mutex::new(title_filters),
+            settings_save: Mutex::new(SaveDebouncer::default()),
+            daemon: Mutex::new(DaemonState::restore(
+                daemon_outcome.state,
+                chrono::Utc::now(),
+            )),
+            join_trigger: trigger_actor::TriggerActor::default(),
+            background_join: Mutex::new(None),
             update_checking: Mutex::new(false),
             update_info: Mutex::new(None),
-            update_prompt_preference: Mutex::new(update_prompt_preference),
+            update_prompt_preference: Mutex::new(update_prompt_outcome.state),
             update_dialog_requested: Mutex::new(false),
             update_manual_check_requested: Mutex::new(false),
             suppress_reopen_focus_until_ms: Mutex::new(0),
             main_first_load_done: AtomicBool::new(false),
             pending_deep_link: Mutex::new(None),
-            logger: Mutex::new(logger),
-            #[cfg(target_os = "macos")]
+            logger: LoggerHandle::new(logger),
+            webview_health: Mutex::new(LoadTracker::default()),
+            main_hidden: Mutex::new(HiddenTracker::default()),
+            health: Mutex::new(HealthMonitor::default()),
+            script_watchdog: Mutex::new(ScriptWatchdog::default()),
+            background: Mutex::new(background),
+            background_wake: tokio::sync::Notify::new(),
+            parser_selftests: Mutex::new(HashMap::new()),
+            troubleshoot_probes: Mutex::new(HashMap::new()),
+            ics_calendars: Mutex::new(HashMap::new()),
+            request_scheduler: Mutex::new(RequestScheduler::default()),
+            arbiter: Mutex::new(MeetingArbiter::default()),
+            quarantined_files: Mutex::new(quarantined_files),
+            settings_recovered: Mutex::new(settings_recovered),
+            recent_codes: Mutex::new(recent_codes_outcome.state),
+            join_by_code_dialog_requested: Mutex::new(false),
+            widget_write_error: Mutex::new(None),
+            history_note_request: Mutex::new(None),
+            history: Mutex::new(HistoryRecorder::default()),
+            soak_running: AtomicBool::new(false),
+            window_activity: Mutex::new(WindowActivityLog::default()),
+            shortcuts: Mutex::new(ShortcutRegistry::default()),
             homepage_active: Mutex::new(None),
+            api_server: Mutex::new(None),
+            api_events: tokio::sync::broadcast::channel(64).0,
+            mqtt: Mutex::new(None),
+            presence: Mutex::new(presence::PresenceSync::default()),
+            power: Mutex::new(power::PowerState::default()),
+            connectivity: Mutex::new(connectivity::Connectivity::default()),
         }
     }
 }
@@ -92,7 +377,16 @@ impl Default for AppState {
 pub struct AppStatus {
     enabled: bool,
     next_meeting: Option<Meeting>,
-    meetings: Vec<Meeting>,
+    meetings: Arc<[Meeting]>,
+    /// Which sources reported each meeting and won its fields, by call id
+    meeting_origins: Arc<HashMap<String, FieldOrigins>>,
+    /// What each source last reported
+    sources: Vec<SourceSnapshotInfo>,
+    power_mode: ActivePowerMode,
+    /// Time since the main window last reported its meeting list
+    data_age_ms: Option<i64>,
+    /// That list is older than `staleDataMinutes`
+    data_stale: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -113,12 +407,16 @@ pub struct UpdatePromptPreference {
     pub remind_until_ms: Option<u64>,
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-struct UpdateDownloadProgress {
-    downloaded: u64,
-    total: Option<u64>,
-    percent: Option<f64>,
+impl VersionedState for UpdatePromptPreference {
+    const CURRENT_VERSION: u32 = 1;
+
+    fn migrate(from_version: u32, data: serde_json::Value) -> Result<serde_json::Value, String> {
+        match from_version {
+            // Before the envelope the preference was stored as a bare object
+            0 => Ok(data),
+            _ => Err(format!("unknown version {}", from_version)),
+        }
+    }
 }
 
 // =============================================================================
@@ -128,46 +426,278 @@ struct UpdateDownloadProgress {
 /// Get current application status
 #[tauri::command]
 fn get_status(state: State<AppState>) -> AppStatus {
-    let daemon = state.daemon.lock().unwrap();
-    let settings = state.settings.lock().unwrap();
+    app_status(&state)
+}
+
+fn app_status(state: &AppState) -> AppStatus {
+    let settings = state.settings.lock_or_recover();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+    let daemon = state.daemon.lock_or_recover();
+    let now_ms = now_ms() as i64;
     AppStatus {
         enabled: daemon.is_running(),
-        next_meeting: daemon.get_next_meeting(&settings),
+        next_meeting: daemon.get_next_meeting(&settings, &title_filters),
         meetings: daemon.get_meetings(),
+        meeting_origins: Arc::clone(daemon.meeting_origins()),
+        sources: daemon.source_snapshots(),
+        power_mode: state.background.lock_or_recover().mode(),
+        data_age_ms: daemon.data_age_ms(now_ms),
+        data_stale: daemon.is_data_stale(now_ms, stale_data_ms(&settings)),
     }
 }
 
+/// JSON Schema of every event the app emits to the webviews
+#[tauri::command]
+fn get_ipc_schema() -> serde_json::Value {
+    ipc::schema()
+}
+
+/// Background timer counters and the active power mode
+#[tauri::command]
+fn get_background_metrics(state: State<AppState>) -> BackgroundMetrics {
+    state.background.lock_or_recover().metrics()
+}
+
+/// Request, deferral and backoff counters of the remote meeting sources
+#[tauri::command]
+fn get_source_request_metrics(
+    state: State<AppState>,
+) -> BTreeMap<MeetingSource, SourceRequestMetrics> {
+    state.request_scheduler.lock_or_recover().metrics(now_ms())
+}
+
 /// Get joined meeting call IDs
 #[tauri::command]
 fn get_joined_meetings(state: State<AppState>) -> Vec<String> {
-    let daemon = state.daemon.lock().unwrap();
+    let daemon = state.daemon.lock_or_recover();
     daemon.get_joined_meetings()
 }
 
+/// Get the lifecycle phase and transition history of every tracked meeting
+#[tauri::command]
+fn get_meeting_lifecycles(state: State<AppState>) -> HashMap<String, MeetingLifecycle> {
+    state.daemon.lock_or_recover().get_lifecycles()
+}
+
 /// Get current settings
 #[tauri::command]
 fn get_settings(state: State<AppState>) -> Settings {
-    state.settings.lock().unwrap().clone()
+    state.settings.lock_or_recover().clone()
 }
 
-/// Save settings
+/// Which title filters and meeting rules match `title`, and whether a
+/// meeting with it would be auto-joined under the current settings
 #[tauri::command]
-fn save_settings(app: AppHandle, state: State<AppState>, settings: Settings) -> Result<(), String> {
-    let previous_settings = state.settings.lock().unwrap().clone();
+fn test_title_filter(state: State<AppState>, title: String) -> filters::TitleFilterPreview {
+    let settings = state.settings.lock_or_recover();
+    let title_filters = state.title_filters.lock_or_recover();
+    daemon::preview_title_filters(&title, &settings, &title_filters)
+}
 
-    {
-        let mut current = state.settings.lock().unwrap();
-        *current = settings.clone();
-        current.save().map_err(|e| e.to_string())?;
+/// Apply settings in memory right away and persist them once changes settle
+#[tauri::command]
+fn save_settings(
+    app: AppHandle,
+    state: State<AppState>,
+    mut settings: Settings,
+) -> Result<(), Vec<FieldError>> {
+    let mut errors = FieldErrors(settings.validate());
+    if let Some(raw) = configured_startup_url(&settings) {
+        errors.check(
+            "tauri.startupUrl",
+            url_norm::normalize_startup_url(raw)
+                .map(|_| ())
+                .map_err(|e| format!("Invalid startup URL: {}", e)),
+        );
+    }
+    if let Some(tauri) = &settings.tauri {
+        for (i, subscription) in tauri.ics_subscriptions.iter().enumerate() {
+            errors.check(
+                &format!("tauri.icsSubscriptions.{}", i),
+                ics::IcsLocation::parse(subscription).map(|_| ()),
+            );
+        }
+        if let Some(path) = &tauri.widget_data_path {
+            errors.check("tauri.widgetDataPath", widget::validate_path(path));
+        }
+        errors.check(
+            "tauri.apiServerPort",
+            api_server::validate_port(tauri.api_server_port),
+        );
+        if let Some(token) = &tauri.api_server_token {
+            errors.check("tauri.apiServerToken", api_server::validate_token(token));
+        }
+        errors.check(
+            "tauri.mqttTopicPrefix",
+            mqtt::validate_topic_prefix(&tauri.mqtt_topic_prefix),
+        );
+        let mqtt_host = tauri.mqtt_host.as_deref().map(str::trim).unwrap_or("");
+        if tauri.mqtt_enabled
+            && !mqtt_host.is_empty()
+            && tauri
+                .mqtt_username
+                .as_deref()
+                .is_some_and(|u| !u.is_empty())
+        {
+            errors.check(
+                "tauri.mqttTls",
+                mqtt::validate_credentials_transport(mqtt_host, tauri.mqtt_tls),
+            );
+        }
+        for (i, url) in tauri.webhook_urls.iter().enumerate() {
+            errors.check(
+                &format!("tauri.webhookUrls.{}", i),
+                webhooks::validate_url(url),
+            );
+        }
+    }
+    if !errors.0.is_empty() {
+        log_app_event(
+            &app,
+            LogLevel::Warn,
+            "settings",
+            "settings.rejected",
+            None,
+            Some(json!({ "errors": errors.0 })),
+        );
+        return Err(errors.0);
+    }
+    let migration = secrets::migrate_plaintext(&mut settings, &Keychain);
+    for name in &migration.migrated {
+        log_app_event(
+            &app,
+            LogLevel::Info,
+            "secrets",
+            "secret.stored",
+            None,
+            Some(json!({ "name": name, "source": "settings" })),
+        );
+    }
+    for (name, e) in &migration.failed {
+        log_app_event(
+            &app,
+            LogLevel::Warn,
+            "secrets",
+            "secret.store_failed",
+            Some(e.clone()),
+            Some(json!({ "name": name })),
+        );
+    }
+
+    let title_filters = Arc::new(filters::TitleFilters::from_settings(&settings));
+    let generation = {
+        let mut current = state.settings.lock_or_recover();
+        let generation = state.settings_save.lock_or_recover().record(&current);
+        *current = settings;
+        *state.title_filters.lock_or_recover() = title_filters;
+        generation
+    };
+
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(settings_save::SAVE_DEBOUNCE_MS)).await;
+        let Some(state) = app_handle.try_state::<AppState>() else {
+            return;
+        };
+        if !state.settings_save.lock_or_recover().is_latest(generation) {
+            return;
+        }
+        if let Err(e) = flush_pending_settings(&app_handle) {
+            eprintln!("[MeetCat] Failed to save settings: {}", e);
+        }
+    });
+
+    Ok(())
+}
+
+/// Store a secret in the OS keychain
+#[tauri::command]
+fn set_secret(app: AppHandle, name: SecretName, value: String) -> Result<(), String> {
+    if value.is_empty() {
+        return clear_secret(app, name);
+    }
+    Keychain.set(name, &value)?;
+    log_app_event(
+        &app,
+        LogLevel::Info,
+        "secrets",
+        "secret.stored",
+        None,
+        Some(json!({ "name": name, "source": "command" })),
+    );
+    Ok(())
+}
+
+/// Remove a secret from the OS keychain
+#[tauri::command]
+fn clear_secret(app: AppHandle, name: SecretName) -> Result<(), String> {
+    Keychain.clear(name)?;
+    log_app_event(
+        &app,
+        LogLevel::Info,
+        "secrets",
+        "secret.cleared",
+        None,
+        Some(json!({ "name": name })),
+    );
+    Ok(())
+}
+
+/// Whether a secret is stored, without revealing it
+#[tauri::command]
+fn has_secret(name: SecretName) -> Result<bool, String> {
+    Keychain.get(name).map(|value| value.is_some())
+}
+
+/// Persist settings still waiting for the debounce, e.g. from a "Save" button
+#[tauri::command]
+fn flush_settings(app: AppHandle) -> Result<(), String> {
+    flush_pending_settings(&app).map(|_| ())
+}
+
+/// Write pending settings to disk and apply their side effects once.
+///
+/// Returns false when nothing was pending.
+fn flush_pending_settings(app: &AppHandle) -> Result<bool, String> {
+    let Some(state) = app.try_state::<AppState>() else {
+        return Ok(false);
+    };
+    let Some(previous_settings) = state.settings_save.lock_or_recover().take() else {
+        return Ok(false);
+    };
+    let settings = state.settings.lock_or_recover().clone();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+
+    if let Err(e) = settings.save() {
+        log_app_event(
+            app,
+            LogLevel::Error,
+            "settings",
+            "settings.save_failed",
+            Some(e.to_string()),
+            None,
+        );
+        return Err(e.to_string());
     }
 
     // Notify WebView of settings change
-    app.emit("settings_changed", &settings)
+    app.emit(event::SETTINGS_CHANGED, &settings)
         .map_err(|e| e.to_string())?;
 
+    // Changed keys only, so listeners can skip reloading untouched subsystems
+    let (changed_keys, changes) = build_settings_change_summary(&previous_settings, &settings);
+    if !changed_keys.is_empty() {
+        let _ = app.emit(
+            event::SETTINGS_DELTA,
+            SettingsDelta {
+                changed_keys: changed_keys.clone(),
+                changes: changes.clone(),
+            },
+        );
+    }
+
     {
-        let (changed_keys, changes) = build_settings_change_summary(&previous_settings, &settings);
-        let mut logger = state.logger.lock().unwrap();
+        let mut logger = state.logger.lock();
         logger.configure(&settings);
         logger.log_internal(
             LogLevel::Info,
@@ -183,97 +713,571 @@ fn save_settings(app: AppHandle, state: State<AppState>, settings: Settings) ->
                 "logLevel": settings
                     .tauri
                     .as_ref()
-                    .map(|t| format!("{:?}", t.log_level).to_lowercase())
-                    .unwrap_or("info".to_string()),
+                    .map(|t| t.log_level.as_str())
+                    .unwrap_or(LogLevel::Info.as_str()),
                 "changedKeys": changed_keys,
                 "changes": changes,
             })),
         );
     }
 
-    // Refresh tray display with new settings
-    let settings = state.settings.lock().unwrap().clone();
-    let next_meeting = state.daemon.lock().unwrap().get_next_meeting(&settings);
-    tray::update_tray_status(&app, next_meeting.as_ref());
+    if changed_keys
+        .iter()
+        .any(|key| key == "tauri.icsSubscriptions")
+    {
+        state
+            .background
+            .lock_or_recover()
+            .run_soon(BackgroundTask::IcsRefresh, now_ms());
+    }
+
+    // Apply power mode, check interval and maintenance window changes to
+    // the background timer
+    refresh_power_mode(app);
+    refresh_maintenance_state(app);
+    state.background_wake.notify_one();
 
-    Ok(())
+    // Add or remove the tray icon; no-op when visibility didn't change
+    let show_tray_icon = settings.tauri.clone().unwrap_or_default().show_tray_icon;
+    tray::set_tray_visible(app, show_tray_icon);
+
+    if changed_keys
+        .iter()
+        .any(|key| key == "tauri.globalShortcuts")
+    {
+        apply_global_shortcuts(app);
+    }
+
+    if changed_keys.iter().any(|key| key == "tauri.demoMode") {
+        apply_demo_mode(app);
+    }
+
+    // The token isn't in the change summary, so this always runs; it only
+    // restarts the server when it's toggled or the port changes
+    apply_api_server(app);
+    // Same for the MQTT password
+    apply_mqtt(app);
+
+    if changed_keys.iter().any(|key| key == "tauri.profiles") {
+        // Window creation must not block the command that saved the settings
+        let app_handle = app.clone();
+        let _ = app.run_on_main_thread(move || sync_profile_windows(&app_handle));
+    }
+
+    if changed_keys
+        .iter()
+        .any(|key| key == "tauri.widgetDataEnabled" || key == "tauri.widgetDataPath")
+    {
+        remove_stale_widget_data(app, &previous_settings, &settings);
+    }
+
+    // Refresh tray display and widget data with new settings
+    let next_meeting = state
+        .daemon
+        .lock_or_recover()
+        .get_next_meeting(&settings, &title_filters);
+    tray::update_tray_status(app, &settings, next_meeting.as_ref());
+    write_widget_data(app, &settings, next_meeting.as_ref());
+
+    start_soak_test(app);
+
+    Ok(true)
+}
+
+/// Whether `tauri.demoMode` replaced the real meetings with sample ones
+fn demo_mode_active(app: &AppHandle) -> bool {
+    app.try_state::<AppState>()
+        .is_some_and(|state| state.daemon.lock_or_recover().in_demo_mode())
+}
+
+/// List the sample meetings instead of the real ones, or switch back, to
+/// match `tauri.demoMode`.
+///
+/// Joins and meeting navigation are refused and meeting sources are
+/// ignored while demo mode is on. Leaving it restores the last real
+/// schedule and asks the sources for a fresh one.
+fn apply_demo_mode(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let tauri_settings = state
+        .settings
+        .lock_or_recover()
+        .tauri_or_default()
+        .into_owned();
+    let enabled = tauri_settings.demo_mode;
+    {
+        let mut daemon = state.daemon.lock_or_recover();
+        if daemon.in_demo_mode() == enabled {
+            return;
+        }
+        let meetings = enabled.then(|| fixtures::demo_meetings(chrono::Utc::now()));
+        daemon.set_demo_meetings(meetings, &tauri_settings.meeting_source_priority);
+    }
+    log_app_event(
+        app,
+        LogLevel::Info,
+        "daemon",
+        if enabled {
+            "demo.entered"
+        } else {
+            "demo.exited"
+        },
+        None,
+        None,
+    );
+    emit_lifecycle_events(app);
+
+    if !enabled {
+        let mut background = state.background.lock_or_recover();
+        background.run_soon(BackgroundTask::MeetingCheck, now_ms());
+        background.run_soon(BackgroundTask::IcsRefresh, now_ms());
+        drop(background);
+        state.background_wake.notify_one();
+    }
+
+    schedule_join_trigger(&state);
+    refresh_tray_status(app);
+}
+
+fn soak_test_config(settings: &Settings) -> Option<settings::SoakTestSettings> {
+    settings.tauri.as_ref().and_then(|t| t.soak_test.clone())
+}
+
+/// Start the soak-test shadow engine if `tauri.soakTest` is set and it isn't
+/// running yet. The run stops, or restarts, when the setting changes.
+fn start_soak_test(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let Some(config) = soak_test_config(&state.settings.lock_or_recover()) else {
+        return;
+    };
+    if state.soak_running.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    eprintln!(
+        "[MeetCat] Soak test running: clock x{}, a meeting every {} virtual minutes",
+        config.clock_factor, config.meeting_interval_minutes
+    );
+    log_app_event(
+        app,
+        LogLevel::Warn,
+        "soak",
+        "soak.started",
+        None,
+        Some(json!({
+            "clockFactor": config.clock_factor,
+            "meetingIntervalMinutes": config.meeting_interval_minutes,
+        })),
+    );
+
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut run = soak::SoakRun::new(config, chrono::Utc::now());
+        let mut ticks: u64 = 0;
+        loop {
+            tokio::time::sleep(Duration::from_millis(SOAK_TICK_MS)).await;
+            let Some(state) = app_handle.try_state::<AppState>() else {
+                return;
+            };
+            let current = soak_test_config(&state.settings.lock_or_recover());
+            if current.as_ref() != Some(run.config()) {
+                state.soak_running.store(false, Ordering::SeqCst);
+                log_app_event(
+                    &app_handle,
+                    LogLevel::Warn,
+                    "soak",
+                    "soak.stopped",
+                    None,
+                    serde_json::to_value(run.stats()).ok(),
+                );
+                start_soak_test(&app_handle);
+                return;
+            }
+
+            for violation in run.advance_to(chrono::Utc::now()) {
+                eprintln!(
+                    "[MeetCat] SOAK VIOLATION {:?} for {}: {}",
+                    violation.kind, violation.call_id, violation.detail
+                );
+                log_app_event(
+                    &app_handle,
+                    LogLevel::Error,
+                    "soak",
+                    "soak.violation",
+                    Some(violation.detail.clone()),
+                    serde_json::to_value(&violation).ok(),
+                );
+            }
+
+            ticks += 1;
+            if ticks.is_multiple_of(SOAK_PROGRESS_TICKS) {
+                log_app_event(
+                    &app_handle,
+                    LogLevel::Info,
+                    "soak",
+                    "soak.progress",
+                    None,
+                    serde_json::to_value(run.stats()).ok(),
+                );
+            }
+        }
+    });
 }
 
 /// Start the auto-join daemon
 #[tauri::command]
 fn start_daemon(state: State<AppState>) {
-    let mut daemon = state.daemon.lock().unwrap();
+    let mut daemon = state.daemon.lock_or_recover();
     daemon.start();
 
-    let mut logger = state.logger.lock().unwrap();
+    let mut logger = state.logger.lock();
     logger.log_internal(LogLevel::Info, "daemon", "daemon.start", None, None);
 }
 
 /// Stop the auto-join daemon
 #[tauri::command]
 fn stop_daemon(state: State<AppState>) {
-    let mut daemon = state.daemon.lock().unwrap();
+    let mut daemon = state.daemon.lock_or_recover();
     daemon.stop();
 
-    let mut logger = state.logger.lock().unwrap();
+    let mut logger = state.logger.lock();
     logger.log_internal(LogLevel::Info, "daemon", "daemon.stop", None, None);
 }
 
 /// Log event from WebView
 #[tauri::command]
-fn log_event(app: AppHandle, state: State<AppState>, input: LogEventInput) {
-    #[cfg(target_os = "macos")]
+fn log_event(
+    app: AppHandle,
+    state: State<AppState>,
+    input: LogEventInput,
+) -> Result<(), UnknownLogLevel> {
     let input_context = input.context.clone();
-    #[cfg(target_os = "macos")]
     let is_page_detected = input.module == "inject" && input.event == "init.page_detected";
 
-    if let Ok(mut logger) = state.logger.lock() {
-        logger.log_from_input(input, "webview");
+    {
+        let mut logger = state.logger.lock();
+        if let Err(err) = logger.log_from_input(input, "webview") {
+            logger.log_internal(
+                LogLevel::Warn,
+                "logging",
+                "log.unknown_level",
+                Some(err.to_string()),
+                Some(json!({ "level": err.level })),
+            );
+            return Err(err);
+        }
     }
 
-    #[cfg(target_os = "macos")]
-    {
-        if is_page_detected {
-            if let Some(context) = input_context.as_ref() {
-                if let Some(is_homepage) = context.get("homepage").and_then(|v| v.as_bool()) {
-                    update_refresh_menu_state(&app, &state, is_homepage);
-                }
+    if is_page_detected {
+        if let Some(context) = input_context.as_ref() {
+            if let Some(is_homepage) = context.get("homepage").and_then(|v| v.as_bool()) {
+                update_refresh_menu_state(&app, &state, is_homepage);
             }
         }
     }
+
+    Ok(())
 }
 
-/// Schedule a precise join trigger for the next meeting
-fn schedule_join_trigger(app: &AppHandle, state: &State<AppState>) {
-    let settings = state.settings.lock().unwrap().clone();
-    let daemon = state.daemon.lock().unwrap();
-    let joined_count = daemon.get_joined_meetings().len();
-    let suppressed_count = daemon.get_suppressed_meetings().len();
+/// Run the meeting parser against built-in sample cards in the main webview.
+///
+/// Returns the webview's pass/fail report, which also counts the cards on
+/// the live page so a Google UI change can be told apart from a settings
+/// problem.
+#[tauri::command]
+async fn run_parser_selftest(app: AppHandle) -> Result<serde_json::Value, String> {
+    let window = app
+        .get_webview_window("main")
+        .ok_or_else(|| "Main window not found".to_string())?;
+    let state = app
+        .try_state::<AppState>()
+        .ok_or_else(|| "App state unavailable".to_string())?;
+
+    let request_id = format!("selftest-{}", now_ms());
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    state
+        .parser_selftests
+        .lock_or_recover()
+        .insert(request_id.clone(), tx);
+
+    let script = format!(
+        "window.__meetcatParserSelfTest && window.__meetcatParserSelfTest({});",
+        serde_json::to_string(&request_id).map_err(|e| e.to_string())?
+    );
+    if let Err(e) = window.eval(&script) {
+        state.parser_selftests.lock_or_recover().remove(&request_id);
+        return Err(e.to_string());
+    }
 
-    // Cancel any existing trigger
-    {
-        let mut handle = state.join_trigger_handle.lock().unwrap();
-        if let Some(h) = handle.take() {
-            h.abort();
-            println!("[MeetCat] Cancelled previous join trigger");
+    let result =
+        tokio::time::timeout(Duration::from_millis(PARSER_SELFTEST_TIMEOUT_MS), rx).await;
+    state.parser_selftests.lock_or_recover().remove(&request_id);
+    let report = match result {
+        Ok(Ok(report)) => report,
+        _ => {
             log_app_event(
-                app,
-                LogLevel::Debug,
-                "join",
-                "trigger.cancelled",
+                &app,
+                LogLevel::Warn,
+                "parser",
+                "selftest.timeout",
                 None,
-                Some(json!({ "reason": "reschedule" })),
+                Some(json!({ "timeoutMs": PARSER_SELFTEST_TIMEOUT_MS })),
+            );
+            return Err(
+                "The parser self-test did not respond. Make sure Google Meet is loaded in the main window."
+                    .to_string(),
+            );
+        }
+    };
+
+    log_app_event(
+        &app,
+        LogLevel::Info,
+        "parser",
+        "selftest.completed",
+        None,
+        Some(json!({
+            "passed": report.get("passed"),
+            "failed": report.get("failed"),
+            "total": report.get("total"),
+            "liveCardsFound": report.get("liveCardsFound"),
+        })),
+    );
+    Ok(report)
+}
+
+/// Receive a parser self-test report from the webview
+#[tauri::command]
+fn parser_selftest_result(state: State<AppState>, request_id: String, report: serde_json::Value) {
+    if let Some(tx) = state.parser_selftests.lock_or_recover().remove(&request_id) {
+        let _ = tx.send(report);
+    }
+}
+
+/// Run every diagnostic in turn and return the troubleshooting wizard's steps
+#[tauri::command]
+async fn run_troubleshooter(app: AppHandle) -> Result<troubleshooter::TroubleshootReport, String> {
+    let state = app
+        .try_state::<AppState>()
+        .ok_or_else(|| "App state unavailable".to_string())?;
+    let window = app.get_webview_window("main");
+    let on_meet = window
+        .as_ref()
+        .and_then(|window| window.url().ok())
+        .is_some_and(|url| url_norm::is_allowed_host(&url));
+
+    let probe = match window.as_ref().filter(|_| on_meet) {
+        Some(window) => probe_main_page(window, &state).await,
+        None => None,
+    };
+    let parser = if on_meet {
+        run_parser_selftest(app.clone()).await
+    } else {
+        Err("Google Meet is not open in the main window".to_string())
+    };
+
+    let settings = state.settings.lock_or_recover().clone();
+    let input = troubleshooter::TroubleshootInput::from_settings(&settings, on_meet, probe, parser);
+    let report = troubleshooter::build_report(&input, now_ms());
+    log_app_event(
+        &app,
+        LogLevel::Info,
+        "diagnostics",
+        "troubleshooter.ran",
+        None,
+        serde_json::to_value(&report).ok(),
+    );
+    Ok(report)
+}
+
+/// Evaluate the troubleshooter's probe script and wait for its report
+async fn probe_main_page(
+    window: &WebviewWindow,
+    state: &AppState,
+) -> Option<troubleshooter::PageProbe> {
+    let request_id = format!("probe-{}", now_ms());
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    state
+        .troubleshoot_probes
+        .lock_or_recover()
+        .insert(request_id.clone(), tx);
+
+    let script = troubleshooter::PROBE_SCRIPT
+        .replace("__REQUEST_ID__", &serde_json::to_string(&request_id).ok()?);
+    let result = match window.eval(&script) {
+        Ok(()) => tokio::time::timeout(Duration::from_millis(TROUBLESHOOT_PROBE_TIMEOUT_MS), rx)
+            .await
+            .ok()
+            .and_then(Result::ok),
+        Err(e) => {
+            eprintln!("Failed to run troubleshooter probe: {}", e);
+            None
+        }
+    };
+    state
+        .troubleshoot_probes
+        .lock_or_recover()
+        .remove(&request_id);
+    result.and_then(|report| serde_json::from_value(report).ok())
+}
+
+/// Receive the troubleshooter's page probe report from the webview
+#[tauri::command]
+fn troubleshoot_probe_result(
+    state: State<AppState>,
+    request_id: String,
+    report: serde_json::Value,
+) {
+    if let Some(tx) = state
+        .troubleshoot_probes
+        .lock_or_recover()
+        .remove(&request_id)
+    {
+        let _ = tx.send(report);
+    }
+}
+
+/// Answer from the injected script to a `script-ping`
+#[tauri::command]
+fn script_pong(app: AppHandle, window: tauri::WebviewWindow, state: State<AppState>, ping_id: u64) {
+    if window.label() != "main" {
+        return;
+    }
+    let recovered = state.script_watchdog.lock_or_recover().on_pong(ping_id);
+    if let Some(misses) = recovered.filter(|misses| *misses > 0) {
+        log_app_event(
+            &app,
+            LogLevel::Info,
+            "inject",
+            "inject.watchdog_recovered",
+            None,
+            Some(json!({ "pingId": ping_id, "misses": misses })),
+        );
+    }
+}
+
+/// Capture a PNG of the main webview into the diagnostics folder
+#[tauri::command]
+async fn capture_webview_screenshot(app: AppHandle) -> Result<String, String> {
+    capture_main_webview(&app, "manual")
+        .await
+        .map(|path| path.to_string_lossy().to_string())
+}
+
+async fn capture_main_webview(app: &AppHandle, reason: &str) -> Result<PathBuf, String> {
+    let window = app
+        .get_webview_window("main")
+        .ok_or_else(|| "Main window not found".to_string())?;
+    let result = diagnostics::capture_webview_png(&window, reason).await;
+    match &result {
+        Ok(path) => log_app_event(
+            app,
+            LogLevel::Info,
+            "diagnostics",
+            "screenshot.saved",
+            None,
+            Some(json!({
+                "reason": reason,
+                "file": path.file_name().map(|n| n.to_string_lossy().to_string()),
+            })),
+        ),
+        Err(e) => {
+            eprintln!("[MeetCat] Failed to capture webview screenshot: {}", e);
+            log_app_event(
+                app,
+                LogLevel::Warn,
+                "diagnostics",
+                "screenshot.failed",
+                Some(e.clone()),
+                Some(json!({ "reason": reason })),
             );
         }
     }
+    result
+}
+
+/// Replace the join trigger with one for the next meeting.
+///
+/// The trigger actor does the work after the caller's locks are released, so
+/// concurrent requests can't leave two triggers running.
+fn schedule_join_trigger(state: &AppState) {
+    state.join_trigger.reschedule();
+}
+
+/// Schedule a precise join trigger for the next meeting and return its tasks.
+///
+/// Only called by the trigger actor, which has already aborted the previous
+/// trigger; `aborted` tells whether it was still pending.
+fn schedule_next_join_trigger(app: &AppHandle, aborted: bool) -> Vec<AbortHandle> {
+    let state = app.state::<AppState>();
+    let mut tasks = Vec::new();
+
+    if aborted {
+        println!("[MeetCat] Cancelled previous join trigger");
+        log_app_event(
+            app,
+            LogLevel::Debug,
+            "join",
+            "trigger.cancelled",
+            None,
+            Some(json!({ "reason": "reschedule" })),
+        );
+    }
+
+    // Settings stay locked while the daemon is read, so the trigger comes
+    // from meetings and settings as they were at one moment
+    let settings_guard = state.settings.lock_or_recover();
+    let settings = settings_guard.clone();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+    let daemon = state.daemon.lock_or_recover();
+    let joined_count = daemon.get_joined_meetings().len();
+    let suppressed_count = daemon.get_suppressed_meetings().len();
 
     // Calculate next trigger time
-    if let Some(trigger) = daemon.calculate_next_trigger(&settings) {
+    let next_trigger = daemon.calculate_next_trigger(&settings, &title_filters);
+
+    // Let the tray count down every second as the trigger or the next
+    // meeting's start draws near
+    let now = now_ms();
+    let countdown_target = next_trigger
+        .as_ref()
+        .map(|trigger| trigger.fire_at_ms)
+        .into_iter()
+        .chain(
+            daemon
+                .get_next_meeting(&settings, &title_filters)
+                .map(|meeting| meeting.begin_time.timestamp_millis()),
+        )
+        .filter_map(|at_ms| u64::try_from(at_ms).ok())
+        .filter(|at_ms| *at_ms > now)
+        .min();
+    drop(daemon);
+    drop(settings_guard);
+    state
+        .background
+        .lock_or_recover()
+        .set_tray_countdown(countdown_target, now);
+    state.background_wake.notify_one();
+
+    if let Some(trigger) = next_trigger {
         let meeting = trigger.meeting.clone();
         let delay_ms = trigger.delay_ms;
         let app_handle = app.clone();
-        let settings_for_join = settings.clone();
+        let settings_for_join = daemon::settings_for_meeting(&meeting, &settings, &title_filters);
         let call_id = meeting.call_id.clone();
+        let conflict = trigger.conflict.clone();
+        // No pre-join page before the user picked which meeting to join
+        let green_room_delay_ms = trigger.green_room_delay_ms.filter(|_| conflict.is_empty());
+        let auto_click_join = trigger.auto_click_join;
+        let busy_confirmation = trigger
+            .busy_hold
+            .clone()
+            .filter(|hold| hold.policy == settings::BusyBlockPolicy::Confirm);
+        let join_id = state.logger.lock().begin_join(&call_id);
+        let fire_at_ms = trigger.fire_at_ms;
 
         println!(
             "[MeetCat] Scheduling join for \"{}\" in {}ms ({:.1} minutes)",
@@ -292,21 +1296,90 @@ fn schedule_join_trigger(app: &AppHandle, state: &State<AppState>) {
                 "title": meeting.title,
                 "delayMs": delay_ms,
                 "startsInMinutes": meeting.starts_in_minutes,
+                "countdownSeconds": trigger.countdown_seconds,
+                "autoClickJoin": auto_click_join,
+                "greenRoomDelayMs": green_room_delay_ms,
+                "busyPolicy": trigger.busy_hold.as_ref().map(|hold| hold.policy),
+                "busyUntilMs": trigger.busy_hold.as_ref().map(|hold| hold.block_end.timestamp_millis()),
+                "conflictCount": conflict.len(),
                 "joinedCount": joined_count,
                 "suppressedCount": suppressed_count,
             })),
         );
 
+        tasks.extend(
+            schedule_join_reminder(app, &meeting, &settings_for_join, delay_ms)
+                .map(|reminder| reminder.inner().abort_handle()),
+        );
+
         // Spawn a task to trigger the join at the exact time
+        // Captured once so the task doesn't look up AppState for every entry
+        let logger = state.logger.clone();
         let join_handle = tauri::async_runtime::spawn(async move {
+            let scheduled_at_ms = now_ms();
+            let join_at_ms = scheduled_at_ms + delay_ms;
+
+            // Green-room phase: open the pre-join page early without joining
+            if let Some(green_room_delay_ms) = green_room_delay_ms {
+                sleep_until_wall_clock(scheduled_at_ms + green_room_delay_ms).await;
+                open_green_room(&app_handle, &meeting, &settings_for_join, auto_click_join);
+            }
+
+            // Give the user a few seconds to call the join off
+            let show_notifications = settings_for_join
+                .tauri
+                .as_ref()
+                .map(|t| t.show_notifications)
+                .unwrap_or_else(|| settings::TauriSettings::default().show_notifications);
+            if show_notifications && busy_confirmation.is_none() && conflict.is_empty() {
+                sleep_until_wall_clock(join_at_ms.saturating_sub(JOIN_NOTICE_SECONDS * 1000)).await;
+                if join_was_cancelled(&app_handle, &call_id) {
+                    return;
+                }
+                let remaining = Duration::from_millis(join_at_ms.saturating_sub(now_ms()));
+                if remaining >= Duration::from_secs(1)
+                    && !was_announced_last_minute(&app_handle, &call_id)
+                {
+                    notify_join_pending(&app_handle, &meeting, &settings_for_join, remaining);
+                }
+            }
+
             // Wait for the precise time
-            if delay_ms > 0 {
-                tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+            sleep_until_wall_clock(join_at_ms).await;
+            if join_was_cancelled(&app_handle, &call_id) {
+                return;
+            }
+
+            // The timer follows the monotonic clock; reschedule instead of
+            // joining when the wall clock jumped while it slept
+            let recheck = daemon::recheck_trigger_at(
+                &meeting,
+                fire_at_ms,
+                &settings_for_join,
+                chrono::Utc::now(),
+            );
+            if recheck != daemon::TriggerRecheck::Fire {
+                logger.log(
+                    LogLevel::Warn,
+                    "join",
+                    "trigger.clock_jump",
+                    None,
+                    Some(json!({
+                        "callId": call_id,
+                        "scheduledAtMs": fire_at_ms,
+                        "nowMs": now_ms(),
+                        "expired": recheck == daemon::TriggerRecheck::Expired,
+                    })),
+                );
+                logger.lock().end_join(&call_id);
+                if let Some(state) = app_handle.try_state::<AppState>() {
+                    schedule_join_trigger(&state);
+                }
+                return;
             }
 
             println!("[MeetCat] Triggering join for: {}", meeting.title);
-            log_app_event(
-                &app_handle,
+            logger.log(
                 LogLevel::Info,
                 "join",
                 "trigger.fired",
@@ -317,14 +1390,86 @@ fn schedule_join_trigger(app: &AppHandle, state: &State<AppState>) {
                 })),
             );
 
+            if hold_while_locked(&app_handle, &call_id)
+                || hold_while_offline(&app_handle, &call_id)
+                || hold_while_stale(&app_handle, &call_id, &settings_for_join)
+                || hold_for_active_call(&app_handle, &call_id, &settings_for_join)
+                || hold_while_mic_busy(&app_handle, &meeting, &settings_for_join)
+                || skip_while_away(&app_handle, &meeting, &settings_for_join)
+            {
+                return;
+            }
+
+            if !conflict.is_empty() {
+                ask_conflict_choice(&app_handle, conflict);
+                logger.lock().end_join(&call_id);
+                return;
+            }
+
+            if let Some(hold) = busy_confirmation {
+                ask_busy_confirmation(&app_handle, &meeting, &settings_for_join, &hold);
+                logger.lock().end_join(&call_id);
+                return;
+            }
+
             // Mark the meeting as "triggered" BEFORE navigating
             // This prevents re-triggering if user cancels and goes back to homepage
             if let Some(state) = app_handle.try_state::<AppState>() {
-                let mut daemon = state.daemon.lock().unwrap();
-                daemon.mark_joined(&call_id);
+                let mut daemon = state.daemon.lock_or_recover();
+                let max_per_hour = settings_for_join
+                    .tauri
+                    .as_ref()
+                    .map(|t| t.max_auto_joins_per_hour)
+                    .unwrap_or_else(|| settings::TauriSettings::default().max_auto_joins_per_hour);
+                let fired_at_ms = now_ms() as i64;
+                if !daemon.try_record_auto_join(fired_at_ms, max_per_hour) {
+                    let joins_in_last_hour = daemon.auto_joins_in_window(fired_at_ms);
+                    let now = chrono::Utc::now();
+                    let meetings: Vec<serde_json::Value> = daemon
+                        .get_meetings()
+                        .iter()
+                        .filter(|m| m.end_time > now)
+                        .map(|m| {
+                            json!({
+                                "callId": m.call_id,
+                                "title": m.title,
+                                "beginTimeMs": m.begin_time.timestamp_millis(),
+                                "startsInMinutes": m.starts_in_minutes_at(now),
+                            })
+                        })
+                        .collect();
+                    drop(daemon);
+
+                    eprintln!(
+                        "[MeetCat] Auto-join cap reached ({} per hour), not joining: {}",
+                        max_per_hour, meeting.title
+                    );
+                    logger.log(
+                        LogLevel::Warn,
+                        "join",
+                        "join.rate_capped",
+                        None,
+                        Some(json!({
+                            "callId": meeting.call_id,
+                            "title": meeting.title,
+                            "maxPerHour": max_per_hour,
+                            "joinsInLastHour": joins_in_last_hour,
+                            "meetings": meetings,
+                        })),
+                    );
+                    notify_join_rate_capped(&app_handle);
+                    state.logger.lock().end_join(&call_id);
+                    return;
+                }
+                let triggered = daemon.transition(&call_id, MeetingPhase::Triggered, fired_at_ms);
+                drop(daemon);
+                report_transition(&app_handle, triggered);
+                state
+                    .health
+                    .lock_or_recover()
+                    .on_join_fired(&call_id, now_ms());
                 println!("[MeetCat] Marked meeting as triggered: {}", call_id);
-                log_app_event(
-                    &app_handle,
+                logger.log(
                     LogLevel::Debug,
                     "join",
                     "meeting.marked_joined",
@@ -333,174 +1478,2770 @@ fn schedule_join_trigger(app: &AppHandle, state: &State<AppState>) {
                 );
             }
 
-            if let Some(window) = app_handle.get_webview_window("main") {
-                let _ = window.show();
-                let _ = window.unminimize();
-                let _ = window.set_focus();
+            let lang = i18n::Language::from_setting(&settings_for_join.language);
+            let privacy_mode = settings_for_join
+                .tauri
+                .as_ref()
+                .is_some_and(|t| t.privacy_mode);
+            let subject =
+                format::meeting_subject(&lang, &meeting.title, &meeting.display_time, privacy_mode);
+            announce_for_accessibility(
+                &app_handle,
+                &settings_for_join,
+                &i18n::tr_join_firing_announcement(&lang, &subject),
+            );
+
+            let background = joins_in_background(&settings_for_join, auto_click_join);
+            let window = show_meeting_window(&app_handle, &meeting, !background);
+            if background {
+                if let Some(state) = app_handle.try_state::<AppState>() {
+                    *state.background_join.lock_or_recover() =
+                        Some((call_id.clone(), window.clone()));
+                }
+            } else {
+                record_window_shown(&app_handle, &window, ShowCause::AutoJoin, Some(&call_id));
             }
 
-            // Emit navigate-and-join command to WebView
+            // Emit navigate-and-join command to WebView, or join in place when
+            // the green-room phase already left the window on this meeting
+            let green_room_opened = app_handle.try_state::<AppState>().is_some_and(|state| {
+                state
+                    .daemon
+                    .lock_or_recover()
+                    .is_green_room_opened(&call_id)
+            });
+            let on_meeting = is_window_on_meeting(&app_handle, &window, &call_id);
             let cmd = NavigateAndJoinCommand {
                 url: meeting.url.clone(),
                 settings: settings_for_join,
+                auto_click_join,
+                join_id,
+                window,
+            };
+            let event = if green_room_opened && on_meeting {
+                event::GREEN_ROOM_JOIN
+            } else {
+                event::NAVIGATE_AND_JOIN
             };
 
-            if let Err(e) = app_handle.emit("navigate-and-join", &cmd) {
-                eprintln!("[MeetCat] Failed to emit navigate-and-join: {}", e);
+            if let Err(e) = app_handle.emit(event, &cmd) {
+                eprintln!("[MeetCat] Failed to emit {}: {}", event, e);
             }
+            transition_meeting(&app_handle, &call_id, MeetingPhase::Navigating);
+            logger.log(
+                LogLevel::Debug,
+                "join",
+                "navigate.emitted",
+                None,
+                Some(json!({
+                    "callId": call_id,
+                    "event": event,
+                    "window": cmd.window,
+                    "background": background,
+                })),
+            );
         });
 
-        // Store the handle so we can cancel it later
-        let mut handle = state.join_trigger_handle.lock().unwrap();
-        *handle = Some(join_handle);
+        tasks.push(join_handle.inner().abort_handle());
     } else {
         println!("[MeetCat] No meeting to schedule trigger for");
         log_app_event(app, LogLevel::Debug, "join", "trigger.none", None, None);
     }
+    tasks
 }
 
-/// Receive meetings from WebView
-#[tauri::command]
-fn meetings_updated(app: AppHandle, state: State<AppState>, meetings: Vec<Meeting>) {
-    let meeting_count = meetings.len();
-    let first_meeting = meetings.first().cloned();
-    {
-        let mut daemon = state.daemon.lock().unwrap();
-        daemon.update_meetings(meetings);
+/// Hold a join that overlaps a "confirm" busy block and ask the user whether
+/// to join anyway
+fn ask_busy_confirmation(
+    app: &AppHandle,
+    meeting: &Meeting,
+    settings: &Settings,
+    hold: &daemon::BusyHold,
+) {
+    if let Some(state) = app.try_state::<AppState>() {
+        state
+            .daemon
+            .lock_or_recover()
+            .request_busy_confirmation(&meeting.call_id);
     }
-
+    println!(
+        "[MeetCat] Join for \"{}\" waits for confirmation during a busy block",
+        meeting.title
+    );
     log_app_event(
-        &app,
-        LogLevel::Debug,
-        "meetings",
-        "meetings.updated",
+        app,
+        LogLevel::Info,
+        "join",
+        "join.awaiting_busy_confirmation",
         None,
         Some(json!({
-            "count": meeting_count,
-            "firstMeeting": first_meeting.as_ref().map(|m| {
-                json!({
-                    "callId": m.call_id,
-                    "title": m.title,
-                    "startsInMinutes": m.starts_in_minutes,
-                })
-            }),
+            "callId": meeting.call_id,
+            "busyUntilMs": hold.block_end.timestamp_millis(),
         })),
     );
 
-    // Schedule precise join trigger (this will cancel any existing trigger)
-    schedule_join_trigger(&app, &state);
+    let lang = i18n::Language::from_setting(&settings.language);
+    let privacy_mode = settings.tauri.as_ref().is_some_and(|t| t.privacy_mode);
+    let subject =
+        format::meeting_subject(&lang, &meeting.title, &meeting.display_time, privacy_mode);
+    let block = if privacy_mode {
+        i18n::tr(&lang, i18n::keys::BUSY_BLOCK).to_string()
+    } else {
+        hold.block_title.clone()
+    };
+    // Offer the join for as long as the meeting can still be auto-joined
+    let window_end_ms = meeting.begin_time.timestamp_millis()
+        + (settings.max_minutes_after_start as i64) * 60 * 1000;
+    let timeout = Duration::from_millis((window_end_ms - now_ms() as i64).max(1000) as u64);
 
-    // Update tray with next meeting info
-    let settings = state.settings.lock().unwrap().clone();
-    let next_meeting = state.daemon.lock().unwrap().get_next_meeting(&settings);
-    tray::update_tray_status(&app, next_meeting.as_ref());
+    let app_handle = app.clone();
+    let call_id = meeting.call_id.clone();
+    desktop_notify::show_with_action(
+        i18n::tr(&lang, i18n::keys::NOTIFY_BUSY_CONFIRM_TITLE),
+        &i18n::tr_busy_confirm_body(&lang, &subject, &block),
+        i18n::tr(&lang, i18n::keys::NOTIFY_JOIN_NOW),
+        timeout,
+        move || confirm_busy_join_for(&app_handle, &call_id, "notification"),
+    );
+    refresh_tray_status(app);
 }
 
-/// Mark a meeting as joined
+/// Let a join held by a "confirm" busy block go ahead
 #[tauri::command]
-fn meeting_joined(app: AppHandle, state: State<AppState>, call_id: String) {
-    {
-        let mut daemon = state.daemon.lock().unwrap();
-        daemon.mark_joined(&call_id);
-    }
+fn confirm_busy_join(app: AppHandle, call_id: String) {
+    confirm_busy_join_for(&app, &call_id, "command");
+}
 
+fn confirm_busy_join_for(app: &AppHandle, call_id: &str, source: &str) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    state.daemon.lock_or_recover().confirm_busy_join(call_id);
     log_app_event(
-        &app,
+        app,
         LogLevel::Info,
-        "meetings",
-        "meeting.joined",
+        "join",
+        "join.busy_confirmed",
         None,
-        Some(json!({ "callId": call_id })),
+        Some(json!({ "callId": call_id, "source": source })),
     );
-
-    // Re-schedule trigger for the next meeting
-    schedule_join_trigger(&app, &state);
+    schedule_join_trigger(&state);
+    refresh_tray_status(app);
 }
 
-/// Mark a meeting as closed
-#[tauri::command]
-fn meeting_closed(app: AppHandle, state: State<AppState>, call_id: String, closed_at_ms: i64) {
-    let settings = state.settings.lock().unwrap().clone();
-    let mut matched = false;
-    let mut trigger_at_ms: Option<i64> = None;
-    {
-        let mut daemon = state.daemon.lock().unwrap();
-        if let Some(meeting) = daemon.get_meetings().iter().find(|m| m.call_id == call_id) {
-            matched = true;
-            let computed_trigger_at_ms = meeting.begin_time.timestamp_millis()
-                - (settings.join_before_minutes as i64) * 60 * 1000;
-            trigger_at_ms = Some(computed_trigger_at_ms);
-            if closed_at_ms >= computed_trigger_at_ms {
-                daemon.mark_suppressed(&call_id, closed_at_ms);
-            }
-        }
+/// Hold the joins of overlapping meetings that trigger together and ask the
+/// frontend which one to join
+fn ask_conflict_choice(app: &AppHandle, meetings: Vec<Meeting>) {
+    let call_ids: Vec<String> = meetings.iter().map(|m| m.call_id.clone()).collect();
+    if let Some(state) = app.try_state::<AppState>() {
+        state
+            .daemon
+            .lock_or_recover()
+            .request_conflict_choice(&call_ids);
     }
-
     log_app_event(
-        &app,
+        app,
         LogLevel::Info,
-        "meetings",
-        "meeting.closed",
+        "join",
+        "join.awaiting_conflict_choice",
         None,
-        Some(json!({
-            "callId": call_id,
-            "closedAtMs": closed_at_ms,
-            "matched": matched,
-            "triggerAtMs": trigger_at_ms,
-            "joinBeforeMinutes": settings.join_before_minutes,
-        })),
+        Some(json!({ "callIds": call_ids })),
     );
-
-    // Re-schedule trigger for the next meeting
-    schedule_join_trigger(&app, &state);
-
-    let next_meeting = state.daemon.lock().unwrap().get_next_meeting(&settings);
-    tray::update_tray_status(&app, next_meeting.as_ref());
-}
-
-/// Get suppressed meeting call IDs
-#[tauri::command]
-fn get_suppressed_meetings(state: State<AppState>) -> Vec<String> {
-    let daemon = state.daemon.lock().unwrap();
-    daemon.get_suppressed_meetings()
-}
-
-#[tauri::command]
-fn get_update_info(state: State<AppState>) -> Option<UpdateInfo> {
-    state.update_info.lock().unwrap().clone()
-}
-
-#[tauri::command]
-fn get_update_prompt_preference(state: State<AppState>) -> UpdatePromptPreference {
-    state.update_prompt_preference.lock().unwrap().clone()
+    let _ = app.emit(
+        event::MEETING_CONFLICT,
+        &MeetingConflictPayload { meetings },
+    );
+    refresh_tray_status(app);
 }
 
+/// Join `call_id` out of the overlapping meetings held by a `meeting-conflict`
+/// prompt; the others are skipped
 #[tauri::command]
-fn set_update_prompt_preference(
+fn choose_conflict_meeting(
     app: AppHandle,
     state: State<AppState>,
-    preference: UpdatePromptPreference,
+    call_id: String,
 ) -> Result<(), String> {
+    if !state
+        .daemon
+        .lock_or_recover()
+        .choose_conflict_winner(&call_id)
     {
-        let mut current = state.update_prompt_preference.lock().unwrap();
-        *current = preference.clone();
+        return Err(format!("No meeting conflict waits for {call_id}"));
     }
-
-    save_update_prompt_preference(&preference)?;
-
-    app.emit("update:preference-changed", preference)
-        .map_err(|e| e.to_string())?;
+    log_app_event(
+        &app,
+        LogLevel::Info,
+        "join",
+        "join.conflict_chosen",
+        None,
+        Some(json!({ "callId": call_id })),
+    );
+    schedule_join_trigger(&state);
+    refresh_tray_status(&app);
     Ok(())
 }
 
-#[tauri::command]
-async fn check_for_update_manual(app: AppHandle) -> Result<Option<UpdateInfo>, String> {
-    check_for_update_with_source(app, "manual").await
+/// Speak `message` to screen reader users, if announcements are enabled
+fn announce_for_accessibility(app: &AppHandle, settings: &Settings, message: &str) {
+    let enabled = settings
+        .tauri
+        .as_ref()
+        .map(|t| t.accessibility_announcements)
+        .unwrap_or_else(|| settings::TauriSettings::default().accessibility_announcements);
+    if !enabled {
+        return;
+    }
+    if let Err(e) = accessibility::announce(app, message) {
+        log_app_event(
+            app,
+            LogLevel::Debug,
+            "accessibility",
+            "announcement.failed",
+            Some(e),
+            None,
+        );
+    }
 }
 
+/// The meeting page started its join countdown
 #[tauri::command]
-async fn download_and_install_update(app: AppHandle, auto_restart: bool) -> Result<bool, String> {
-    let updater = app.updater_builder().build().map_err(|e| e.to_string())?;
+fn join_countdown_started(
+    app: AppHandle,
+    state: State<AppState>,
+    call_id: Option<String>,
+    seconds: u64,
+) {
+    let settings = state.settings.lock_or_recover().clone();
+    let meeting = call_id.as_deref().and_then(|call_id| {
+        state
+            .daemon
+            .lock_or_recover()
+            .get_meetings()
+            .iter()
+            .find(|m| m.call_id == call_id)
+            .cloned()
+    });
+    let lang = i18n::Language::from_setting(&settings.language);
+    let privacy_mode = settings.tauri.as_ref().is_some_and(|t| t.privacy_mode);
+    let subject = match &meeting {
+        Some(m) => format::meeting_subject(&lang, &m.title, &m.display_time, privacy_mode),
+        None => format::meeting_subject(&lang, "", "", privacy_mode),
+    };
+    announce_for_accessibility(
+        &app,
+        &settings,
+        &i18n::tr_join_pending_body(&lang, &subject, seconds),
+    );
+}
+
+/// Emit `join-reminder`, and post a notification that can skip the join,
+/// `reminderMinutesBefore` ahead of an auto-join due in `join_delay_ms`.
+///
+/// Joins due sooner than that get no reminder.
+fn schedule_join_reminder(
+    app: &AppHandle,
+    meeting: &Meeting,
+    settings: &Settings,
+    join_delay_ms: u64,
+) -> Option<JoinHandle<()>> {
+    let minutes_before = settings
+        .tauri
+        .as_ref()
+        .and_then(|t| t.reminder_minutes_before)
+        .filter(|minutes| *minutes > 0)?;
+    let lead_ms = u64::from(minutes_before) * 60 * 1000;
+    let remind_in_ms = join_delay_ms.checked_sub(lead_ms)?;
+
+    let app_handle = app.clone();
+    let settings = settings.clone();
+    let payload = JoinReminderPayload {
+        meeting: meeting.clone(),
+        join_at_ms: now_ms() as i64 + join_delay_ms as i64,
+        minutes_before,
+    };
+    let handle = tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(remind_in_ms)).await;
+        let call_id = payload.meeting.call_id.clone();
+        if join_was_cancelled(&app_handle, &call_id) {
+            return;
+        }
+        let _ = app_handle.emit(event::JOIN_REMINDER, &payload);
+        log_app_event(
+            &app_handle,
+            LogLevel::Info,
+            "join",
+            "reminder.sent",
+            None,
+            Some(json!({ "callId": call_id, "minutesBefore": minutes_before })),
+        );
+
+        let show_notifications = settings
+            .tauri
+            .as_ref()
+            .map(|t| t.show_notifications)
+            .unwrap_or_else(|| settings::TauriSettings::default().show_notifications);
+        if !show_notifications {
+            return;
+        }
+        let lang = i18n::Language::from_setting(&settings.language);
+        let privacy_mode = settings.tauri.as_ref().is_some_and(|t| t.privacy_mode);
+        let meeting = &payload.meeting;
+        let subject =
+            format::meeting_subject(&lang, &meeting.title, &meeting.display_time, privacy_mode);
+        let notify_handle = app_handle.clone();
+        desktop_notify::show_with_action(
+            i18n::tr(&lang, i18n::keys::NOTIFY_JOIN_REMINDER_TITLE),
+            &i18n::tr_join_reminder_body(&lang, &subject, minutes_before),
+            i18n::tr(&lang, i18n::keys::NOTIFY_SKIP_JOIN),
+            Duration::from_millis(lead_ms),
+            move || {
+                let _ = cancel_join_trigger(&notify_handle, &call_id, "reminder");
+            },
+        );
+    });
+    Some(handle)
+}
+
+/// Announce meetings that were first seen shortly before they start.
+///
+/// Their join is scheduled from the same meeting list, so one that fires
+/// before the next meeting check goes ahead without waiting for it. The
+/// announcement can skip the join and stands in for the join-pending
+/// notice, so a last-minute meeting posts a single notification however
+/// many sources report it.
+fn announce_last_minute_meetings(app: &AppHandle, settings: &Settings) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let now = chrono::Utc::now();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+    let (meetings, trigger) = {
+        let mut daemon = state.daemon.lock_or_recover();
+        let meetings = daemon.take_last_minute_meetings(settings, now);
+        if meetings.is_empty() {
+            return;
+        }
+        (
+            meetings,
+            daemon.calculate_next_trigger_at(settings, &title_filters, now),
+        )
+    };
+    let check_interval_ms = state
+        .background
+        .lock_or_recover()
+        .interval_ms(BackgroundTask::MeetingCheck)
+        .unwrap_or_default();
+    let tauri_settings = settings.tauri_or_default();
+    let lang = i18n::Language::from_setting(&settings.language);
+
+    for meeting in meetings {
+        let join_delay_ms = trigger
+            .as_ref()
+            .filter(|trigger| trigger.meeting.call_id == meeting.call_id)
+            .map(|trigger| trigger.delay_ms);
+        let starts_in_minutes = meeting.starts_in_minutes_at(now);
+        log_app_event(
+            app,
+            LogLevel::Info,
+            "meetings",
+            "meeting.last_minute",
+            None,
+            Some(json!({
+                "callId": meeting.call_id,
+                "title": meeting.title,
+                "startsInMinutes": starts_in_minutes,
+                "joinInMs": join_delay_ms,
+                "fastPath": join_delay_ms.is_some_and(|delay_ms| delay_ms <= check_interval_ms),
+            })),
+        );
+        if !tauri_settings.show_notifications {
+            continue;
+        }
+
+        let subject = format::meeting_subject(
+            &lang,
+            &meeting.title,
+            &meeting.display_time,
+            tauri_settings.privacy_mode,
+        );
+        let title = i18n::tr(&lang, i18n::keys::NOTIFY_LAST_MINUTE_TITLE);
+        let body = i18n::tr_meeting_line(
+            &lang,
+            &subject,
+            &format::relative_time(&lang, starts_in_minutes),
+        );
+        match join_delay_ms.filter(|delay_ms| *delay_ms >= 1000) {
+            Some(delay_ms) => {
+                let app_handle = app.clone();
+                let call_id = meeting.call_id.clone();
+                desktop_notify::show_with_action(
+                    title,
+                    &body,
+                    i18n::tr(&lang, i18n::keys::NOTIFY_SKIP_JOIN),
+                    Duration::from_millis(delay_ms),
+                    move || {
+                        let _ = cancel_join_trigger(&app_handle, &call_id, "last_minute");
+                    },
+                );
+            }
+            None => desktop_notify::show(title, &body),
+        }
+    }
+}
+
+fn was_announced_last_minute(app: &AppHandle, call_id: &str) -> bool {
+    app.try_state::<AppState>().is_some_and(|state| {
+        state
+            .daemon
+            .lock_or_recover()
+            .was_announced_last_minute(call_id)
+    })
+}
+
+/// Post "Joining <title> in N seconds" with a button that cancels the join
+fn notify_join_pending(
+    app: &AppHandle,
+    meeting: &Meeting,
+    settings: &Settings,
+    remaining: Duration,
+) {
+    let lang = i18n::Language::from_setting(&settings.language);
+    let privacy_mode = settings.tauri.as_ref().is_some_and(|t| t.privacy_mode);
+    let subject =
+        format::meeting_subject(&lang, &meeting.title, &meeting.display_time, privacy_mode);
+    let seconds = remaining.as_millis().div_ceil(1000) as u64;
+
+    let app_handle = app.clone();
+    let call_id = meeting.call_id.clone();
+    desktop_notify::show_with_action(
+        i18n::tr(&lang, i18n::keys::NOTIFY_JOIN_PENDING_TITLE),
+        &i18n::tr_join_pending_body(&lang, &subject, seconds),
+        i18n::tr(&lang, i18n::keys::NOTIFY_CANCEL_JOIN),
+        remaining,
+        move || {
+            let _ = cancel_join_trigger(&app_handle, &call_id, "notification");
+        },
+    );
+    log_app_event(
+        app,
+        LogLevel::Debug,
+        "join",
+        "notice.shown",
+        None,
+        Some(json!({ "callId": meeting.call_id, "seconds": seconds })),
+    );
+}
+
+/// Cancel a scheduled auto-join, the next one when `call_id` is None.
+/// Returns the call ID that was cancelled.
+#[tauri::command]
+fn cancel_pending_join(app: AppHandle, call_id: Option<String>) -> Result<String, String> {
+    cancel_next_join(&app, call_id, "command")
+}
+
+pub(crate) fn cancel_next_join(
+    app: &AppHandle,
+    call_id: Option<String>,
+    source: &str,
+) -> Result<String, String> {
+    let Some(state) = app.try_state::<AppState>() else {
+        return Err("App state unavailable".to_string());
+    };
+    let call_id = match call_id {
+        Some(call_id) => call_id,
+        None => {
+            let settings = state.settings.lock_or_recover().clone();
+            let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+            state
+                .daemon
+                .lock_or_recover()
+                .calculate_next_trigger(&settings, &title_filters)
+                .map(|trigger| trigger.meeting.call_id)
+                .ok_or_else(|| "No join is scheduled".to_string())?
+        }
+    };
+    cancel_join_trigger(app, &call_id, source)?;
+    Ok(call_id)
+}
+
+/// Register the configured global shortcuts and log the bindings that failed
+fn apply_global_shortcuts(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let bindings = {
+        let settings = state.settings.lock_or_recover();
+        settings
+            .tauri
+            .as_ref()
+            .and_then(|t| t.global_shortcuts.clone())
+    };
+    let registry = shortcuts::apply(app, bindings.as_ref());
+    for status in registry.statuses().iter().filter(|s| !s.registered) {
+        log_app_event(
+            app,
+            LogLevel::Warn,
+            "shortcuts",
+            "shortcut.unavailable",
+            status.error.clone(),
+            Some(json!({ "action": status.action, "accelerator": status.accelerator })),
+        );
+    }
+    *state.shortcuts.lock_or_recover() = registry;
+}
+
+/// Run the action bound to a pressed global shortcut
+pub(crate) fn run_shortcut_action(app: &AppHandle, action: ShortcutAction) {
+    log_app_event(
+        app,
+        LogLevel::Info,
+        "shortcuts",
+        "shortcut.pressed",
+        None,
+        Some(json!({ "action": action })),
+    );
+    let result = match action {
+        ShortcutAction::JoinNext => dispatch_join_next(app)
+            .map(|_| record_window_shown(app, profiles::MAIN_WINDOW, ShowCause::Shortcut, None))
+            .map_err(|e| e.message),
+        ShortcutAction::CancelJoin => cancel_next_join(app, None, "shortcut").map(|_| ()),
+        ShortcutAction::ToggleMic => {
+            let window = app
+                .try_state::<AppState>()
+                .and_then(|state| {
+                    let arbiter = state.arbiter.lock_or_recover();
+                    arbiter
+                        .active_calls()
+                        .last()
+                        .map(|call| call.window.clone())
+                })
+                .unwrap_or_else(|| profiles::MAIN_WINDOW.to_string());
+            app.emit(event::TOGGLE_MIC, &ToggleMicCommand { window })
+                .map_err(|e| e.to_string())
+        }
+    };
+    if let Err(e) = result {
+        log_app_event(
+            app,
+            LogLevel::Info,
+            "shortcuts",
+            "shortcut.failed",
+            Some(e),
+            Some(json!({ "action": action })),
+        );
+    }
+}
+
+/// Abort the scheduled join for `call_id` and suppress the meeting so it
+/// isn't scheduled again. Fails once the join is already under way.
+fn cancel_join_trigger(app: &AppHandle, call_id: &str, source: &str) -> Result<(), String> {
+    let Some(state) = app.try_state::<AppState>() else {
+        return Err("App state unavailable".to_string());
+    };
+    // Checked and cancelled under one lock so the trigger can't fire between
+    let (phase, cancelled) = {
+        let mut daemon = state.daemon.lock_or_recover();
+        let phase = daemon.meeting_phase(call_id);
+        let too_late = matches!(
+            phase,
+            Some(MeetingPhase::Triggered | MeetingPhase::Navigating | MeetingPhase::Joined)
+        );
+        let cancelled = (!too_late).then(|| daemon.cancel_join(call_id, now_ms() as i64));
+        (phase, cancelled)
+    };
+    let Some(cancelled) = cancelled else {
+        log_app_event(
+            app,
+            LogLevel::Info,
+            "join",
+            "join.cancel_too_late",
+            None,
+            Some(json!({ "callId": call_id, "source": source, "phase": phase })),
+        );
+        return Err("The join has already started".to_string());
+    };
+
+    report_transition(app, cancelled);
+    state.logger.lock().end_join(call_id);
+
+    println!("[MeetCat] Cancelled pending join: {}", call_id);
+    log_app_event(
+        app,
+        LogLevel::Info,
+        "join",
+        "join.cancelled",
+        None,
+        Some(json!({ "callId": call_id, "source": source })),
+    );
+    schedule_join_trigger(&state);
+    refresh_tray_status(app);
+    Ok(())
+}
+
+/// Sleep until the wall clock reaches `at_ms`.
+///
+/// Tokio timers follow the monotonic clock, which stops while the machine is
+/// suspended, so one long sleep would fire late after a wake. Waking up every
+/// [`WALL_CLOCK_RECHECK`] keeps the wait on wall-clock time.
+async fn sleep_until_wall_clock(at_ms: u64) {
+    loop {
+        let remaining_ms = at_ms.saturating_sub(now_ms());
+        if remaining_ms == 0 {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(remaining_ms).min(WALL_CLOCK_RECHECK)).await;
+    }
+}
+
+/// Whether the pending join for `call_id` was cancelled after it was scheduled
+fn join_was_cancelled(app: &AppHandle, call_id: &str) -> bool {
+    app.try_state::<AppState>()
+        .is_some_and(|state| state.daemon.lock_or_recover().is_join_cancelled(call_id))
+}
+
+/// Green-room phase of a join trigger: ask the homepage to open the meeting's
+/// pre-join page without the auto-join marker, so the camera preview is up
+/// before the join phase starts the countdown.
+fn open_green_room(
+    app: &AppHandle,
+    meeting: &Meeting,
+    settings: &Settings,
+    auto_click_join: Option<bool>,
+) {
+    if let Some(active) = active_call_blocking_join(app, &meeting.call_id, settings) {
+        log_app_event(
+            app,
+            LogLevel::Debug,
+            "join",
+            "green_room.skipped_active_call",
+            None,
+            Some(json!({
+                "callId": meeting.call_id,
+                "activeCallId": active.call_id,
+                "activeWindow": active.window,
+            })),
+        );
+        return;
+    }
+
+    if let Some(state) = app.try_state::<AppState>() {
+        state
+            .daemon
+            .lock_or_recover()
+            .mark_green_room_opened(&meeting.call_id);
+        persist_daemon_state(app);
+    }
+
+    println!("[MeetCat] Opening green room for: {}", meeting.title);
+    log_app_event(
+        app,
+        LogLevel::Info,
+        "join",
+        "green_room.opened",
+        None,
+        Some(json!({
+            "callId": meeting.call_id,
+            "title": meeting.title,
+            "startsInMinutes": meeting.starts_in_minutes_at(chrono::Utc::now()),
+        })),
+    );
+
+    let raise = !joins_in_background(settings, auto_click_join);
+    let window = show_meeting_window(app, meeting, raise);
+    if raise {
+        record_window_shown(app, &window, ShowCause::GreenRoom, Some(&meeting.call_id));
+    }
+    let cmd = NavigateAndJoinCommand {
+        url: meeting.url.clone(),
+        settings: settings.clone(),
+        auto_click_join,
+        join_id: app
+            .try_state::<AppState>()
+            .map(|state| state.logger.lock().begin_join(&meeting.call_id))
+            .unwrap_or_default(),
+        window,
+    };
+    if let Err(e) = app.emit(event::NAVIGATE_TO_GREEN_ROOM, &cmd) {
+        eprintln!("[MeetCat] Failed to emit navigate-to-green-room: {}", e);
+    }
+}
+
+/// The call in another window or meeting that a join of `call_id` would
+/// overlap with, after dropping calls whose window no longer shows them.
+/// A call in a breakout room stays active while its window shows the room.
+fn active_call_blocking_join(
+    app: &AppHandle,
+    call_id: &str,
+    settings: &Settings,
+) -> Option<ActiveCall> {
+    let state = app.try_state::<AppState>()?;
+    let allow_concurrent = settings
+        .tauri
+        .as_ref()
+        .is_some_and(|t| t.allow_concurrent_meetings);
+    let breakout_calls = state.daemon.lock_or_recover().breakout_calls();
+    let mut arbiter = state.arbiter.lock_or_recover();
+    arbiter.retain_live(|call| {
+        let shown = app
+            .get_webview_window(&call.window)
+            .and_then(|window| window.url().ok());
+        shown.is_some_and(|url| {
+            url.path().trim_matches('/') == call.call_id || breakout_calls.contains(&call.call_id)
+        })
+    });
+    arbiter.blocking_call(call_id, allow_concurrent).cloned()
+}
+
+/// Hold a join while another call is active; it is retried on the next
+/// reschedule, e.g. when that call ends
+fn hold_for_active_call(app: &AppHandle, call_id: &str, settings: &Settings) -> bool {
+    let Some(active) = active_call_blocking_join(app, call_id, settings) else {
+        return false;
+    };
+    let first_hold = app
+        .try_state::<AppState>()
+        .is_some_and(|state| state.arbiter.lock_or_recover().hold(call_id));
+    if first_hold {
+        println!(
+            "[MeetCat] Holding join for {} while {} is active in {}",
+            call_id, active.call_id, active.window
+        );
+        log_app_event(
+            app,
+            LogLevel::Info,
+            "join",
+            "join.held_for_active_call",
+            None,
+            Some(json!({
+                "callId": call_id,
+                "activeCallId": active.call_id,
+                "activeWindow": active.window,
+                "activeSinceMs": active.joined_at_ms,
+            })),
+        );
+    }
+    true
+}
+
+/// Hold a join while the screen is locked or the system or display sleeps.
+///
+/// The trigger is re-evaluated once that ends; see [`on_power_event`].
+fn hold_while_locked(app: &AppHandle, call_id: &str) -> bool {
+    let Some(state) = app.try_state::<AppState>() else {
+        return false;
+    };
+    let power = *state.power.lock_or_recover();
+    if !power.holds_joins() {
+        return false;
+    }
+    if state.arbiter.lock_or_recover().hold(call_id) {
+        println!(
+            "[MeetCat] Holding join for {} while the screen is locked or asleep",
+            call_id
+        );
+        log_app_event(
+            app,
+            LogLevel::Info,
+            "join",
+            "join.held_locked",
+            None,
+            Some(json!({ "callId": call_id, "power": power })),
+        );
+    }
+    true
+}
+
+/// Hold a join while the network is offline, so the meeting page doesn't
+/// fail to load and lose it.
+///
+/// The trigger is re-evaluated once the network is back; see
+/// [`check_connectivity`].
+fn hold_while_offline(app: &AppHandle, call_id: &str) -> bool {
+    let Some(state) = app.try_state::<AppState>() else {
+        return false;
+    };
+    let hold = state
+        .settings
+        .lock_or_recover()
+        .tauri_or_default()
+        .hold_joins_when_offline;
+    let connectivity = state.connectivity.lock_or_recover().clone();
+    if !hold || !connectivity.is_offline() {
+        return false;
+    }
+    if state.arbiter.lock_or_recover().hold(call_id) {
+        let reason = format!(
+            "{} did not answer ({}); holding the join until it does. Turn off holdJoinsWhenOffline to join anyway.",
+            connectivity::PROBE_URL,
+            connectivity.last_error.as_deref().unwrap_or("unknown error")
+        );
+        println!("[MeetCat] Join for {}: {}", call_id, reason);
+        log_app_event(
+            app,
+            LogLevel::Warn,
+            "join",
+            "join.held_offline",
+            Some(reason),
+            Some(json!({
+                "callId": call_id,
+                "probeUrl": connectivity::PROBE_URL,
+                "connectivity": connectivity,
+            })),
+        );
+    }
+    true
+}
+
+/// Probe the network and act when it drops or comes back. Joins wait while
+/// offline; on reconnect the meeting list is refreshed and the join trigger
+/// re-evaluated from the wall clock, so a join held meanwhile still fires if
+/// the meeting is within its join window.
+async fn check_connectivity(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let settings = state.settings.lock_or_recover().clone();
+    let result = connectivity::probe(&settings).await;
+    let change = state
+        .connectivity
+        .lock_or_recover()
+        .apply(&result, now_ms());
+    match change {
+        None => {
+            if let Err(e) = result {
+                log_app_event(
+                    app,
+                    LogLevel::Debug,
+                    "network",
+                    "network.probe_failed",
+                    Some(e),
+                    None,
+                );
+            }
+            return;
+        }
+        Some(connectivity::ConnectivityChange::WentOffline) => {
+            println!("[MeetCat] Network offline; holding auto-joins");
+            log_app_event(
+                app,
+                LogLevel::Warn,
+                "network",
+                "network.offline",
+                result.err(),
+                Some(json!({
+                    "probeUrl": connectivity::PROBE_URL,
+                    "failures": connectivity::FAILURES_BEFORE_OFFLINE,
+                })),
+            );
+        }
+        Some(connectivity::ConnectivityChange::CameOnline { offline_ms }) => {
+            println!("[MeetCat] Network back online");
+            log_app_event(
+                app,
+                LogLevel::Info,
+                "network",
+                "network.online",
+                None,
+                Some(json!({ "offlineMs": offline_ms })),
+            );
+            state
+                .background
+                .lock_or_recover()
+                .run_soon(BackgroundTask::MeetingCheck, now_ms());
+            state.background_wake.notify_one();
+            schedule_join_trigger(&state);
+        }
+    }
+    refresh_tray_status(app);
+}
+
+/// Hold the join while the meeting list is stale and `skipJoinWhenStale` is
+/// on; the next report from the page reschedules the trigger
+fn hold_while_stale(app: &AppHandle, call_id: &str, settings: &Settings) -> bool {
+    if !settings.tauri_or_default().skip_join_when_stale {
+        return false;
+    }
+    let Some(state) = app.try_state::<AppState>() else {
+        return false;
+    };
+    let now_ms = now_ms() as i64;
+    let data_age_ms = {
+        let daemon = state.daemon.lock_or_recover();
+        if !daemon.is_data_stale(now_ms, stale_data_ms(settings)) {
+            return false;
+        }
+        daemon.data_age_ms(now_ms)
+    };
+    if state.arbiter.lock_or_recover().hold(call_id) {
+        println!(
+            "[MeetCat] Holding join for {} while the meeting list is stale",
+            call_id
+        );
+        log_app_event(
+            app,
+            LogLevel::Info,
+            "join",
+            "join.held_stale",
+            None,
+            Some(json!({ "callId": call_id, "dataAgeMs": data_age_ms })),
+        );
+    }
+    true
+}
+
+/// Track screen lock and sleep. The join trigger is re-evaluated from the wall
+/// clock when joins are no longer held or the system wakes, and a wake also
+/// runs a meeting check right away to replace stale meeting data.
+fn on_power_event(app: &AppHandle, event: power::PowerEvent) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let (reschedule, power) = {
+        let mut power = state.power.lock_or_recover();
+        let reschedule = power.apply(event);
+        (reschedule, *power)
+    };
+    log_app_event(
+        app,
+        LogLevel::Info,
+        "power",
+        "power.changed",
+        None,
+        Some(json!({ "event": event, "state": power })),
+    );
+    if event == power::PowerEvent::Wake {
+        // The meeting list may have changed while asleep, and the network
+        // may not have come back with the system
+        let mut background = state.background.lock_or_recover();
+        background.run_soon(BackgroundTask::MeetingCheck, now_ms());
+        background.run_soon(BackgroundTask::ConnectivityCheck, now_ms());
+        drop(background);
+        state.background_wake.notify_one();
+    }
+    if reschedule {
+        schedule_join_trigger(&state);
+        refresh_tray_status(app);
+    }
+}
+
+/// Hold a join while another app records from the default microphone.
+///
+/// Skipped while MeetCat itself is in a meeting, since the mic is then ours.
+/// The join is retried on the next reschedule within the join window.
+fn hold_while_mic_busy(app: &AppHandle, meeting: &Meeting, settings: &Settings) -> bool {
+    let Some(tauri_settings) = settings.tauri.as_ref() else {
+        return false;
+    };
+    if !tauri_settings.defer_while_mic_busy || is_main_window_in_meeting(app) {
+        return false;
+    }
+    if audio_input::input_device_in_use() != Some(true) {
+        return false;
+    }
+    let call_id = &meeting.call_id;
+    let first_hold = app
+        .try_state::<AppState>()
+        .is_some_and(|state| state.arbiter.lock_or_recover().hold(call_id));
+    if first_hold {
+        println!(
+            "[MeetCat] Holding join for {} while the microphone is in use",
+            call_id
+        );
+        log_app_event(
+            app,
+            LogLevel::Info,
+            "join",
+            "join.held_mic_busy",
+            None,
+            Some(json!({
+                "callId": call_id,
+                "title": meeting.title,
+            })),
+        );
+        let lang = i18n::Language::from_setting(&settings.language);
+        let subject = format::meeting_subject(
+            &lang,
+            &meeting.title,
+            &meeting.display_time,
+            tauri_settings.privacy_mode,
+        );
+        let status =
+            format::relative_time(&lang, meeting.starts_in_minutes_at(chrono::Utc::now()));
+        desktop_notify::show(
+            i18n::tr(&lang, i18n::keys::NOTIFY_MIC_BUSY_TITLE),
+            &i18n::tr_mic_busy_body(&lang, &subject, &status),
+        );
+    }
+    true
+}
+
+/// Skip a join while the user is away from the computer, per
+/// `skipJoinWhenIdleMinutes`.
+///
+/// The meeting is suppressed rather than joined with nobody at the mic, so it
+/// stays available from the tray, and a notification says so.
+fn skip_while_away(app: &AppHandle, meeting: &Meeting, settings: &Settings) -> bool {
+    let Some(tauri_settings) = settings.tauri.as_ref() else {
+        return false;
+    };
+    let Some(minutes) = tauri_settings
+        .skip_join_when_idle_minutes
+        .filter(|minutes| *minutes > 0)
+    else {
+        return false;
+    };
+    let call_id = &meeting.call_id;
+    let Some(idle) = idle::current() else {
+        log_app_event(
+            app,
+            LogLevel::Debug,
+            "join",
+            "join.idle_unknown",
+            None,
+            Some(json!({ "callId": call_id })),
+        );
+        return false;
+    };
+    let away = idle.is_away(minutes);
+    let (level, event) = if away {
+        (LogLevel::Info, "join.skipped_away")
+    } else {
+        (LogLevel::Debug, "join.idle_checked")
+    };
+    log_app_event(
+        app,
+        level,
+        "join",
+        event,
+        None,
+        Some(json!({
+            "callId": call_id,
+            "title": meeting.title,
+            "idle": idle,
+            "thresholdMinutes": minutes,
+        })),
+    );
+    if !away {
+        return false;
+    }
+    let Some(state) = app.try_state::<AppState>() else {
+        return true;
+    };
+
+    println!(
+        "[MeetCat] Skipping join for {} while the user is away",
+        call_id
+    );
+    let suppressed = state.daemon.lock_or_recover().mark_suppressed(
+        call_id,
+        SuppressionReason::Away,
+        now_ms() as i64,
+    );
+    report_transition(app, suppressed);
+    state.logger.lock().end_join(call_id);
+
+    let lang = i18n::Language::from_setting(&settings.language);
+    let subject = format::meeting_subject(
+        &lang,
+        &meeting.title,
+        &meeting.display_time,
+        tauri_settings.privacy_mode,
+    );
+    desktop_notify::show(
+        i18n::tr(&lang, i18n::keys::NOTIFY_SKIPPED_AWAY_TITLE),
+        &i18n::tr_skipped_away_body(&lang, &subject),
+    );
+
+    schedule_join_trigger(&state);
+    refresh_tray_status(app);
+    true
+}
+
+/// Move a meeting to `phase` and publish the change
+fn transition_meeting(app: &AppHandle, call_id: &str, phase: MeetingPhase) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let result = state
+        .daemon
+        .lock_or_recover()
+        .transition(call_id, phase, now_ms() as i64);
+    report_transition(app, result);
+}
+
+/// Log a rejected lifecycle transition, then publish the queued changes
+fn report_transition(app: &AppHandle, result: Result<(), TransitionError>) {
+    if let Err(e) = result {
+        log_app_event(
+            app,
+            LogLevel::Warn,
+            "lifecycle",
+            "lifecycle.invalid_transition",
+            Some(e.to_string()),
+            Some(json!({ "callId": e.call_id, "from": e.from, "to": e.to })),
+        );
+    }
+    emit_lifecycle_events(app);
+}
+
+/// POST the lifecycle changes selected in `webhookEvents` to every webhook
+fn send_webhooks(app: &AppHandle, events: &[LifecycleEvent], meetings: &[Meeting]) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let settings = state.settings.lock_or_recover().clone();
+    let tauri_settings = settings.tauri_or_default();
+    let selected: Vec<(settings::WebhookEvent, &LifecycleEvent)> = events
+        .iter()
+        .filter_map(|event| webhooks::event_for(event).map(|kind| (kind, event)))
+        .filter(|(kind, _)| tauri_settings.webhook_events.contains(kind))
+        .collect();
+    if tauri_settings.webhook_urls.is_empty() || selected.is_empty() {
+        return;
+    }
+
+    let secret = secrets::webhook_secret(&settings, &Keychain);
+    let lang = i18n::Language::from_setting(&settings.language);
+    for url in &tauri_settings.webhook_urls {
+        let Ok(url) = Url::parse(url.trim()) else {
+            continue;
+        };
+        let client = match http_client::client(&settings, &url) {
+            Ok(client) => client,
+            Err(e) => {
+                log_app_event(
+                    app,
+                    LogLevel::Warn,
+                    "webhooks",
+                    "webhook.failed",
+                    Some(e),
+                    Some(json!({ "host": webhooks::display_host(&url) })),
+                );
+                continue;
+            }
+        };
+        for (kind, event) in &selected {
+            let meeting = meetings.iter().find(|m| m.call_id == event.call_id);
+            let payload = webhooks::WebhookPayload::build(
+                *kind,
+                event,
+                meeting,
+                tauri_settings.privacy_mode,
+                &lang,
+            );
+            let Ok(body) = serde_json::to_string(&payload) else {
+                continue;
+            };
+            let delivery = webhooks::Delivery::new(url.clone(), *kind, body, secret.as_deref());
+            let app = app.clone();
+            let client = client.clone();
+            tauri::async_runtime::spawn(async move {
+                let data = json!({
+                    "host": webhooks::display_host(&delivery.url),
+                    "event": delivery.event,
+                    "callId": payload.call_id,
+                    "delivery": delivery.id,
+                });
+                match webhooks::deliver(client, delivery).await {
+                    Ok(attempts) => log_app_event(
+                        &app,
+                        LogLevel::Debug,
+                        "webhooks",
+                        "webhook.delivered",
+                        None,
+                        Some(json!({ "attempts": attempts, "webhook": data })),
+                    ),
+                    Err(e) => log_app_event(
+                        &app,
+                        LogLevel::Warn,
+                        "webhooks",
+                        "webhook.failed",
+                        Some(e),
+                        Some(data),
+                    ),
+                }
+            });
+        }
+    }
+}
+
+/// Chat status for joined meeting `call_id`, ending with its calendar event
+fn presence_status(daemon: &DaemonState, call_id: &str) -> presence::MeetingStatus {
+    presence::MeetingStatus {
+        call_id: call_id.to_string(),
+        ends_at: daemon
+            .get_meetings()
+            .iter()
+            .find(|m| m.call_id == call_id)
+            .map(|m| m.end_time),
+    }
+}
+
+/// Show `call_id` on the chat status providers unless it already is
+fn start_presence(app: &AppHandle, call_id: &str) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    if !state.presence.lock_or_recover().start(call_id) {
+        return;
+    }
+    let status = presence_status(&state.daemon.lock_or_recover(), call_id);
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let _ = push_presence(&app, Some(&status)).await;
+    });
+}
+
+/// Clear the chat status if it shows `call_id`
+fn finish_presence(app: &AppHandle, call_id: &str) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    if !state.presence.lock_or_recover().finish(call_id) {
+        return;
+    }
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let _ = push_presence(&app, None).await;
+    });
+}
+
+/// Set `status` on every enabled chat status provider, or clear it when
+/// `None`. Returns how many providers were updated, or every failure.
+async fn push_presence(
+    app: &AppHandle,
+    status: Option<&presence::MeetingStatus>,
+) -> Result<usize, String> {
+    let settings = app.state::<AppState>().settings.lock_or_recover().clone();
+    let providers = match presence::providers(&settings, &Keychain) {
+        Ok(providers) => providers,
+        Err(e) => {
+            log_app_event(
+                app,
+                LogLevel::Warn,
+                "presence",
+                "presence.sync_failed",
+                Some(e.clone()),
+                None,
+            );
+            return Err(e);
+        }
+    };
+    let mut failures = Vec::new();
+    for provider in &providers {
+        let result = match status {
+            Some(status) => provider.set_in_meeting(status).await,
+            None => provider.clear().await,
+        };
+        let data = json!({
+            "provider": provider.name(),
+            "callId": status.map(|s| &s.call_id),
+        });
+        match result {
+            Ok(()) => log_app_event(
+                app,
+                LogLevel::Debug,
+                "presence",
+                "presence.synced",
+                None,
+                Some(data),
+            ),
+            Err(e) => {
+                log_app_event(
+                    app,
+                    LogLevel::Warn,
+                    "presence",
+                    "presence.sync_failed",
+                    Some(e.clone()),
+                    Some(data),
+                );
+                failures.push(format!("{}: {}", provider.name(), e));
+            }
+        }
+    }
+    if failures.is_empty() {
+        Ok(providers.len())
+    } else {
+        Err(failures.join("; "))
+    }
+}
+
+/// Set or clear the chat status to match the meeting joined right now, e.g.
+/// after the Slack token changed mid-meeting
+#[tauri::command]
+async fn sync_presence_now(app: AppHandle) -> Result<(), String> {
+    let status = {
+        let state = app.state::<AppState>();
+        let status = {
+            let daemon = state.daemon.lock_or_recover();
+            daemon
+                .joined_call_ids()
+                .first()
+                .map(|call_id| presence_status(&daemon, call_id))
+        };
+        let mut sync = state.presence.lock_or_recover();
+        match &status {
+            Some(status) => {
+                sync.start(&status.call_id);
+            }
+            None => {
+                sync.reset();
+            }
+        }
+        status
+    };
+    match push_presence(&app, status.as_ref()).await? {
+        0 => Err("No chat status provider is set up".to_string()),
+        _ => Ok(()),
+    }
+}
+
+/// Emit `meeting:lifecycle` for every lifecycle change since the last call
+fn emit_lifecycle_events(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let (events, meetings, demo) = {
+        let mut daemon = state.daemon.lock_or_recover();
+        (
+            daemon.drain_lifecycle_events(),
+            daemon.get_meetings(),
+            daemon.in_demo_mode(),
+        )
+    };
+    if !events.is_empty() {
+        refresh_mqtt_state(app);
+    }
+    // Sample meetings are kept out of the history and webhooks
+    if demo {
+        return;
+    }
+    send_webhooks(app, &events, &meetings);
+    let mut history = state.history.lock_or_recover();
+    history.observe(&meetings);
+    let mut ended = Vec::new();
+    for event in events {
+        log_app_event(
+            app,
+            LogLevel::Debug,
+            "lifecycle",
+            "lifecycle.changed",
+            None,
+            serde_json::to_value(&event).ok(),
+        );
+        let _ = app.emit(event::MEETING_LIFECYCLE, &event);
+        if let Some(entry) = history.record(&event) {
+            record_meeting_history(app, &entry);
+            if event.transition.to == MeetingPhase::Left && entry.outcome == HistoryOutcome::Joined
+            {
+                ended.push(entry);
+            }
+        }
+    }
+    drop(history);
+    persist_daemon_state(app);
+    for entry in &ended {
+        show_meeting_summary(app, entry);
+    }
+}
+
+/// Tell how long a meeting that was just left lasted, with an action that
+/// opens a note for its history entry
+fn show_meeting_summary(app: &AppHandle, entry: &HistoryEntry) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let settings = state.settings.lock_or_recover().clone();
+    let Some(tauri_settings) = settings.tauri.as_ref() else {
+        return;
+    };
+    if !tauri_settings.show_meeting_summary || tauri_settings.privacy_mode {
+        return;
+    }
+    let Some(duration_ms) = entry.duration_ms else {
+        return;
+    };
+    let minutes = ((duration_ms + 30_000) / 60_000).max(1) as u64;
+    let lang = i18n::Language::from_setting(&settings.language);
+    // Untitled meetings fall back to their code
+    let subject = format::meeting_subject(&lang, &entry.title, &entry.call_id, false);
+
+    let app_handle = app.clone();
+    let target = HistoryNoteTarget {
+        call_id: entry.call_id.clone(),
+        recorded_at_ms: entry.recorded_at_ms,
+        title: entry.title.clone(),
+    };
+    desktop_notify::show_with_action(
+        i18n::tr(&lang, i18n::keys::NOTIFY_MEETING_ENDED_TITLE),
+        &i18n::tr_meeting_summary_body(&lang, &subject, minutes),
+        i18n::tr(&lang, i18n::keys::NOTIFY_ADD_NOTE),
+        MEETING_SUMMARY_TIMEOUT,
+        move || {
+            let app = app_handle.clone();
+            let _ = app_handle.run_on_main_thread(move || {
+                if let Err(e) = request_history_note_dialog(&app, target) {
+                    log_app_event(
+                        &app,
+                        LogLevel::Warn,
+                        "history",
+                        "history.note_dialog_failed",
+                        Some(e),
+                        None,
+                    );
+                }
+            });
+        },
+    );
+    log_app_event(
+        app,
+        LogLevel::Debug,
+        "history",
+        "meeting.summary_shown",
+        None,
+        Some(json!({ "callId": entry.call_id, "minutes": minutes })),
+    );
+}
+
+fn record_meeting_history(app: &AppHandle, entry: &history::HistoryEntry) {
+    let result = meeting_history_path().and_then(|path| history::append(&path, entry));
+    match result {
+        Ok(()) => log_app_event(
+            app,
+            LogLevel::Debug,
+            "history",
+            "history.recorded",
+            None,
+            Some(json!({ "callId": entry.call_id, "outcome": entry.outcome })),
+        ),
+        Err(e) => log_app_event(
+            app,
+            LogLevel::Warn,
+            "history",
+            "history.write_failed",
+            Some(e),
+            Some(json!({ "callId": entry.call_id })),
+        ),
+    }
+}
+
+/// Meetings joined, suppressed, refused or skipped, by scheduled start
+#[tauri::command]
+fn get_meeting_history(range: Option<HistoryRange>) -> Result<MeetingHistory, String> {
+    let entries = history::read(&meeting_history_path()?, range.unwrap_or_default())?;
+    let summary = HistorySummary::of(&entries);
+    Ok(MeetingHistory { entries, summary })
+}
+
+/// Attach `note` to a history entry; a blank note removes it
+#[tauri::command]
+fn set_history_note(
+    app: AppHandle,
+    call_id: String,
+    recorded_at_ms: i64,
+    note: String,
+) -> Result<(), String> {
+    let found = history::set_note(&meeting_history_path()?, &call_id, recorded_at_ms, &note)?;
+    if !found {
+        return Err(format!("No history entry for {}", call_id));
+    }
+    log_app_event(
+        &app,
+        LogLevel::Info,
+        "history",
+        "history.note_saved",
+        None,
+        Some(json!({ "callId": call_id, "cleared": note.trim().is_empty() })),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+fn consume_history_note_request(state: State<AppState>) -> Option<HistoryNoteTarget> {
+    state.history_note_request.lock_or_recover().take()
+}
+
+/// Open the settings window with the note dialog for `target` showing
+fn request_history_note_dialog(app: &AppHandle, target: HistoryNoteTarget) -> Result<(), String> {
+    ensure_settings_window(app)?;
+    if let Some(state) = app.try_state::<AppState>() {
+        *state.history_note_request.lock_or_recover() = Some(target.clone());
+    }
+    let _ = app.emit(event::HISTORY_NOTE_OPEN_DIALOG, &target);
+    Ok(())
+}
+
+/// Whether the main window is currently showing any meeting page
+fn is_main_window_in_meeting(app: &AppHandle) -> bool {
+    app.get_webview_window("main")
+        .and_then(|window| window.url().ok())
+        .is_some_and(|url| {
+            url_norm::is_allowed_host(&url) && !matches!(url.path(), "" | "/" | "/landing")
+        })
+}
+
+/// Whether a join stays in the background until the webview reports the join
+/// button was clicked. Without auto-click nothing would bring the window
+/// forward later, so it is raised right away.
+fn joins_in_background(settings: &Settings, auto_click_join: Option<bool>) -> bool {
+    settings
+        .tauri
+        .as_ref()
+        .is_some_and(|t| t.join_in_background)
+        && auto_click_join.unwrap_or(settings.auto_click_join)
+}
+
+/// Show the window that joins `meeting` and return its label: the window of
+/// the meeting's profile, or the main window when the profile is gone.
+///
+/// With `raise` off the window is only prepared, so a hidden or minimized
+/// window stays where it is.
+fn show_meeting_window(app: &AppHandle, meeting: &Meeting, raise: bool) -> String {
+    let profile = meeting.profile.as_deref().and_then(|id| {
+        let state = app.try_state::<AppState>()?;
+        let settings = state.settings.lock_or_recover();
+        settings
+            .tauri
+            .as_ref()?
+            .profiles
+            .iter()
+            .find(|p| p.id == id)
+            .cloned()
+    });
+    if let Some(profile) = profile {
+        let opened = if raise {
+            profiles::open_window(app, &profile)
+        } else {
+            profiles::ensure_window(app, &profile)
+        };
+        match opened {
+            Ok(window) => return window.label().to_string(),
+            Err(e) => eprintln!("[MeetCat] Failed to open profile window: {}", e),
+        }
+    }
+    if raise {
+        raise_window(app, profiles::MAIN_WINDOW);
+    }
+    profiles::MAIN_WINDOW.to_string()
+}
+
+fn raise_window(app: &AppHandle, label: &str) {
+    if let Some(window) = app.get_webview_window(label) {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+}
+
+/// Record that MeetCat showed the window labelled `label` and why.
+///
+/// Shows the user did not ask for directly are also announced through
+/// `window:shown`, so the window can explain the surprise.
+pub(crate) fn record_window_shown(
+    app: &AppHandle,
+    label: &str,
+    cause: ShowCause,
+    call_id: Option<&str>,
+) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let (privacy_mode, notify) = {
+        let settings = state.settings.lock_or_recover();
+        let tauri_settings = settings.tauri_or_default();
+        (
+            tauri_settings.privacy_mode,
+            tauri_settings.show_window_cause && !cause.is_user_initiated(),
+        )
+    };
+    let title = match call_id {
+        Some(call_id) if !privacy_mode => state
+            .daemon
+            .lock_or_recover()
+            .get_meetings()
+            .iter()
+            .find(|m| m.call_id == call_id)
+            .map(|m| m.title.clone()),
+        _ => None,
+    };
+    let activity = WindowActivity {
+        at_ms: now_ms(),
+        label: label.to_string(),
+        cause,
+        call_id: call_id.map(str::to_string),
+        title,
+    };
+    state.logger.log(
+        LogLevel::Debug,
+        "window",
+        "window.shown",
+        None,
+        Some(json!({ "window": label, "cause": cause, "callId": call_id })),
+    );
+    state
+        .window_activity
+        .lock_or_recover()
+        .record(activity.clone());
+    if notify {
+        if let Err(e) = app.emit(event::WINDOW_SHOWN, &activity) {
+            eprintln!("[MeetCat] Failed to emit window:shown: {}", e);
+        }
+    }
+}
+
+/// Whether the window labelled `label` is currently showing `call_id`
+fn is_window_on_meeting(app: &AppHandle, label: &str, call_id: &str) -> bool {
+    app.get_webview_window(label)
+        .and_then(|window| window.url().ok())
+        .is_some_and(|url| {
+            url_norm::is_allowed_host(&url) && url.path().trim_matches('/') == call_id
+        })
+}
+
+/// Whether the main window is currently showing the Google Meet homepage
+fn is_main_window_on_meet_home(app: &AppHandle) -> bool {
+    app.get_webview_window("main")
+        .and_then(|window| window.url().ok())
+        .is_some_and(|url| {
+            url_norm::is_allowed_host(&url) && matches!(url.path(), "" | "/" | "/landing")
+        })
+}
+
+/// Act on joins whose page hasn't reached the call by the verification
+/// deadline: reload a page that never reported back, and report the rest as
+/// `join.verify_failed`
+fn check_join_verification(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let overdue = state
+        .daemon
+        .lock_or_recover()
+        .take_overdue_verifications(now_ms() as i64);
+    if overdue.is_empty() {
+        return;
+    }
+    for join in overdue {
+        if join.should_retry() && retry_join(app, &join.call_id) {
+            continue;
+        }
+        report_join_verify_failed(app, &join);
+    }
+    persist_daemon_state(app);
+}
+
+/// Navigate the window holding `call_id` to the meeting again; false when
+/// the meeting is no longer listed
+fn retry_join(app: &AppHandle, call_id: &str) -> bool {
+    let state = app.state::<AppState>();
+    let settings = state.settings.lock_or_recover().clone();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+    let Some(meeting) = state
+        .daemon
+        .lock_or_recover()
+        .get_meetings()
+        .iter()
+        .find(|m| m.call_id == call_id)
+        .cloned()
+    else {
+        return false;
+    };
+    let window = state
+        .arbiter
+        .lock_or_recover()
+        .active_calls()
+        .iter()
+        .find(|call| call.call_id == call_id)
+        .map(|call| call.window.clone());
+    let window = window.unwrap_or_else(|| show_meeting_window(app, &meeting, false));
+    let retried = state
+        .daemon
+        .lock_or_recover()
+        .retry_join(call_id, now_ms() as i64);
+    if retried.is_err() {
+        report_transition(app, retried);
+        return false;
+    }
+    emit_lifecycle_events(app);
+
+    let cmd = NavigateAndJoinCommand {
+        url: meeting.url.clone(),
+        settings: daemon::settings_for_meeting(&meeting, &settings, &title_filters),
+        auto_click_join: daemon::resolve_auto_click_join(&meeting, &settings, &title_filters),
+        join_id: state.logger.lock().begin_join(call_id),
+        window,
+    };
+    log_app_event(
+        app,
+        LogLevel::Warn,
+        "join",
+        "join.verify_retry",
+        Some("The meeting page never reported reaching the call; reloading it".to_string()),
+        Some(json!({
+            "callId": call_id,
+            "window": cmd.window,
+            "deadlineMs": lifecycle::VERIFY_DEADLINE_MS,
+        })),
+    );
+    let _ = app.emit(event::NAVIGATE_AND_JOIN, &cmd);
+    true
+}
+
+/// Report a join that didn't reach the call. A page in the green room stays
+/// joined, since the host may still let it in; one that never reported back
+/// is marked failed.
+fn report_join_verify_failed(app: &AppHandle, join: &OverdueJoin) {
+    eprintln!(
+        "[MeetCat] Join for {} not verified: {:?}",
+        join.call_id, join.verification
+    );
+    log_app_event(
+        app,
+        LogLevel::Warn,
+        "join",
+        diagnostics::JOIN_VERIFY_FAILED,
+        None,
+        Some(json!({
+            "callId": join.call_id,
+            "verification": join.verification,
+            "retries": join.retries,
+            "deadlineMs": lifecycle::VERIFY_DEADLINE_MS,
+        })),
+    );
+    if join.verification == JoinVerification::Unverified {
+        transition_meeting(app, &join.call_id, MeetingPhase::Failed);
+    }
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    state.logger.lock().end_join(&join.call_id);
+    let settings = state.settings.lock_or_recover().clone();
+    if diagnostics::captures_screenshot(&settings, diagnostics::JOIN_VERIFY_FAILED) {
+        let app_handle = app.clone();
+        tauri::async_runtime::spawn(async move {
+            let _ = capture_main_webview(&app_handle, diagnostics::JOIN_VERIFY_FAILED).await;
+        });
+    }
+}
+
+/// Run the self-healing sequence when an error budget is breached
+async fn check_error_budget(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let now = now_ms();
+    let breached = state.health.lock_or_recover().evaluate(now);
+    if breached.is_empty() {
+        return;
+    }
+    run_self_healing(app, breached, now).await;
+}
+
+/// Turn on debug logging, recover the main webview and write a report.
+///
+/// The webview is left alone while it shows a meeting so an active call is
+/// never interrupted.
+async fn run_self_healing(app: &AppHandle, breached: Vec<HealthBudget>, now: u64) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let (joins, parser) = {
+        let health = state.health.lock_or_recover();
+        (health.join_rate(), health.parser_rate())
+    };
+    eprintln!("[MeetCat] Error budget breached: {:?}", breached);
+
+    let debug_until_ms = now + health::DEBUG_BOOST_MS;
+    state.logger.lock().boost_debug_until(debug_until_ms);
+    let mut actions = vec!["debug_logging".to_string()];
+
+    if capture_main_webview(app, "self-heal").await.is_ok() {
+        actions.push("screenshot".to_string());
+    }
+
+    if is_main_window_in_meeting(app) {
+        actions.push("reload_skipped_in_meeting".to_string());
+    } else {
+        match navigate_to_meet_home_silent(app) {
+            Ok(()) => actions.push("reload_home".to_string()),
+            Err(e) => actions.push(format!("reload_home_failed: {}", e)),
+        }
+    }
+
+    schedule_join_trigger(&state);
+    actions.push("reschedule".to_string());
+
+    let record = HealingRecord {
+        at_ms: now,
+        breached,
+        joins,
+        parser,
+        actions,
+        debug_logging_until_ms: debug_until_ms,
+    };
+    log_app_event(
+        app,
+        LogLevel::Warn,
+        "health",
+        "self_heal.ran",
+        None,
+        serde_json::to_value(&record).ok(),
+    );
+    state.health.lock_or_recover().record_healing(record);
+
+    let report = state.health.lock_or_recover().report(now);
+    if let Err(e) = diagnostics::write_health_report(&report) {
+        eprintln!("[MeetCat] Failed to write health report: {}", e);
+    }
+}
+
+/// Current success rates and the most recent self-healing run
+#[tauri::command]
+fn get_health_report(state: State<AppState>) -> HealthReport {
+    state.health.lock_or_recover().report(now_ms())
+}
+
+/// Tell the user that auto-join stopped at the hourly cap.
+///
+/// The tray status item turns into a "click to resume" action and the main
+/// window is brought forward so the pause doesn't go unnoticed.
+fn notify_join_rate_capped(app: &AppHandle) {
+    refresh_tray_status(app);
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+        record_window_shown(app, "main", ShowCause::RateCapped, None);
+    }
+    let _ = app.emit(event::JOIN_RATE_CAPPED, ());
+}
+
+/// Clear the auto-join cap after the user confirmed and reschedule
+pub(crate) fn resume_auto_join(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let was_capped = {
+        let mut daemon = state.daemon.lock_or_recover();
+        let was_capped = daemon.is_join_rate_capped();
+        daemon.reset_join_rate_cap();
+        was_capped
+    };
+    if was_capped {
+        println!("[MeetCat] Auto-join resumed after rate cap");
+        log_app_event(app, LogLevel::Info, "join", "join.rate_cap_cleared", None, None);
+    }
+    schedule_join_trigger(&state);
+    refresh_tray_status(app);
+}
+
+/// Pause auto-join for an hour, or lift the pause if one is active.
+/// Returns whether auto-join is paused afterwards.
+pub(crate) fn toggle_auto_join_pause(app: &AppHandle) -> bool {
+    let Some(state) = app.try_state::<AppState>() else {
+        return false;
+    };
+    let now = chrono::Utc::now();
+    let paused_until = {
+        let mut daemon = state.daemon.lock_or_recover();
+        if daemon.resume(now) {
+            None
+        } else {
+            let until = now + chrono::Duration::minutes(TRAY_PAUSE_MINUTES);
+            daemon.pause_until(until);
+            Some(until)
+        }
+    };
+    match paused_until {
+        Some(until) => log_app_event(
+            app,
+            LogLevel::Info,
+            "daemon",
+            "pause.started",
+            None,
+            Some(json!({
+                "minutes": TRAY_PAUSE_MINUTES,
+                "untilMs": until.timestamp_millis(),
+                "source": "tray",
+            })),
+        ),
+        None => log_app_event(
+            app,
+            LogLevel::Info,
+            "daemon",
+            "pause.resumed",
+            None,
+            Some(json!({ "source": "tray" })),
+        ),
+    }
+    schedule_join_trigger(&state);
+    refresh_tray_status(app);
+    paused_until.is_some()
+}
+
+/// Postpone the next scheduled auto-join by `minutes` and reschedule
+pub(crate) fn snooze_next_join(app: &AppHandle, minutes: u32) -> Result<(), String> {
+    let Some(state) = app.try_state::<AppState>() else {
+        return Err("App state unavailable".to_string());
+    };
+    let settings = state.settings.lock_or_recover().clone();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+    let until_ms = now_ms() as i64 + (minutes as i64) * 60 * 1000;
+    let meeting = {
+        let mut daemon = state.daemon.lock_or_recover();
+        let trigger = daemon
+            .calculate_next_trigger(&settings, &title_filters)
+            .ok_or_else(|| "No join is scheduled".to_string())?;
+        daemon.snooze(&trigger.meeting.call_id, until_ms);
+        trigger.meeting
+    };
+    println!(
+        "[MeetCat] Snoozed join for \"{}\" by {} minutes",
+        meeting.title, minutes
+    );
+    log_app_event(
+        app,
+        LogLevel::Info,
+        "join",
+        "join.snoozed",
+        None,
+        Some(json!({
+            "callId": meeting.call_id,
+            "minutes": minutes,
+            "untilMs": until_ms,
+        })),
+    );
+    schedule_join_trigger(&state);
+    refresh_tray_status(app);
+    Ok(())
+}
+
+/// Join `call_id` right away, as its trigger would, e.g. from the tray's
+/// upcoming meetings
+pub(crate) fn join_meeting_now(app: &AppHandle, call_id: &str, source: &str) -> Result<(), String> {
+    let Some(state) = app.try_state::<AppState>() else {
+        return Err("App state unavailable".to_string());
+    };
+    if demo_mode_active(app) {
+        return Err(DEMO_MODE_ERROR.to_string());
+    }
+    let settings = state.settings.lock_or_recover().clone();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+    let meeting = state
+        .daemon
+        .lock_or_recover()
+        .get_meetings()
+        .iter()
+        .find(|m| m.call_id == call_id)
+        .cloned()
+        .ok_or_else(|| format!("Meeting {} is no longer listed", call_id))?;
+
+    // Keep the scheduled trigger from joining the same meeting again
+    let triggered = state.daemon.lock_or_recover().transition(
+        call_id,
+        MeetingPhase::Triggered,
+        now_ms() as i64,
+    );
+    report_transition(app, triggered);
+
+    let window = show_meeting_window(app, &meeting, true);
+    record_window_shown(app, &window, ShowCause::Tray, Some(call_id));
+    let cmd = NavigateAndJoinCommand {
+        url: meeting.url.clone(),
+        settings: daemon::settings_for_meeting(&meeting, &settings, &title_filters),
+        auto_click_join: daemon::resolve_auto_click_join(&meeting, &settings, &title_filters),
+        join_id: state.logger.lock().begin_join(call_id),
+        window,
+    };
+    app.emit(event::NAVIGATE_AND_JOIN, &cmd)
+        .map_err(|e| e.to_string())?;
+    transition_meeting(app, call_id, MeetingPhase::Navigating);
+    log_app_event(
+        app,
+        LogLevel::Info,
+        "join",
+        "join.manual",
+        None,
+        Some(json!({ "callId": call_id, "source": source, "window": cmd.window })),
+    );
+    schedule_join_trigger(&state);
+    refresh_tray_status(app);
+    Ok(())
+}
+
+/// Resume auto-join after it was stopped by the hourly cap
+#[tauri::command]
+fn confirm_resume_auto_join(app: AppHandle) {
+    resume_auto_join(&app);
+}
+
+/// Receive meetings from WebView
+#[tauri::command]
+fn meetings_updated(
+    app: AppHandle,
+    window: tauri::WebviewWindow,
+    state: State<AppState>,
+    meetings: Vec<Meeting>,
+) {
+    let meeting_count = meetings.len();
+    let first_meeting = meetings.first().map(|m| {
+        json!({
+            "callId": m.call_id,
+            "title": m.title,
+            "startsInMinutes": m.starts_in_minutes,
+        })
+    });
+    state
+        .health
+        .lock_or_recover()
+        .on_meetings_reported(now_ms());
+    // The page keeps reporting so its health checks pass; the list is unused
+    if demo_mode_active(&app) {
+        log_app_event(
+            &app,
+            LogLevel::Debug,
+            "meetings",
+            "meetings.ignored_demo",
+            None,
+            Some(json!({ "count": meeting_count })),
+        );
+        return;
+    }
+    let settings = state.settings.lock_or_recover().clone();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+    let tauri_settings = settings.tauri_or_default();
+    let (rejected_urls, out_of_office_change) = {
+        let mut daemon = state.daemon.lock_or_recover();
+        let priority = &tauri_settings.meeting_source_priority;
+        let rejected_urls = match profiles::profile_of_window(window.label()) {
+            Some(profile) if tauri_settings.profiles.iter().any(|p| p.id == profile) => {
+                daemon.update_profile_meetings(profile, meetings, priority)
+            }
+            Some(_) => Vec::new(),
+            None => daemon.update_meetings_from_source(
+                MeetingSource::Webview,
+                meetings,
+                now_ms() as i64,
+                priority,
+            ),
+        };
+        let out_of_office_change = daemon.refresh_out_of_office(
+            tauri_settings.pause_during_out_of_office,
+            chrono::Utc::now(),
+        );
+        (rejected_urls, out_of_office_change)
+    };
+    emit_lifecycle_events(&app);
+
+    if !rejected_urls.is_empty() {
+        log_app_event(
+            &app,
+            LogLevel::Warn,
+            "meetings",
+            "meetings.url_rejected",
+            None,
+            Some(json!({
+                "rejected": rejected_urls
+                    .iter()
+                    .map(|r| json!({ "callId": r.call_id, "reason": r.reason }))
+                    .collect::<Vec<_>>(),
+            })),
+        );
+    }
+
+    match out_of_office_change {
+        Some(OutOfOfficeChange::Paused { until }) => log_app_event(
+            &app,
+            LogLevel::Info,
+            "daemon",
+            "ooo.paused",
+            None,
+            Some(json!({ "untilMs": until.timestamp_millis() })),
+        ),
+        Some(OutOfOfficeChange::Resumed) => {
+            log_app_event(&app, LogLevel::Info, "daemon", "ooo.resumed", None, None)
+        }
+        None => {}
+    }
+
+    log_app_event(
+        &app,
+        LogLevel::Debug,
+        "meetings",
+        "meetings.updated",
+        None,
+        Some(json!({
+            "count": meeting_count,
+            "firstMeeting": first_meeting,
+        })),
+    );
+
+    // Announced first, so the trigger knows not to post its own notice
+    announce_last_minute_meetings(&app, &settings);
+    // Schedule precise join trigger (this will cancel any existing trigger)
+    schedule_join_trigger(&state);
+
+    // Update tray with next meeting info
+    let next_meeting = state
+        .daemon
+        .lock_or_recover()
+        .get_next_meeting(&settings, &title_filters);
+    tray::update_tray_status(&app, &settings, next_meeting.as_ref());
+}
+
+/// Mark a meeting as joined
+#[tauri::command]
+fn meeting_joined(app: AppHandle, window: WebviewWindow, state: State<AppState>, call_id: String) {
+    // The room of a breakout has its own call ID; the window stays in the
+    // meeting it was moved from
+    let breakout_parent = {
+        let breakout_calls = state.daemon.lock_or_recover().breakout_calls();
+        state
+            .arbiter
+            .lock_or_recover()
+            .active_calls()
+            .iter()
+            .find(|call| call.window == window.label() && breakout_calls.contains(&call.call_id))
+            .map(|call| call.call_id.clone())
+    };
+    if let Some(parent) = breakout_parent.filter(|parent| *parent != call_id) {
+        log_app_event(
+            &app,
+            LogLevel::Info,
+            "meetings",
+            "breakout.join_ignored",
+            None,
+            Some(json!({ "callId": call_id, "parentCallId": parent })),
+        );
+        return;
+    }
+    let joined = state
+        .daemon
+        .lock_or_recover()
+        .mark_joined(&call_id, now_ms() as i64);
+    report_transition(&app, joined);
+    state
+        .arbiter
+        .lock_or_recover()
+        .on_joined(window.label(), &call_id, now_ms());
+    state
+        .health
+        .lock_or_recover()
+        .on_join_verified(&call_id, now_ms());
+
+    log_app_event(
+        &app,
+        LogLevel::Info,
+        "meetings",
+        "meeting.joined",
+        None,
+        Some(json!({ "callId": call_id })),
+    );
+    state.logger.lock().end_join(&call_id);
+    start_presence(&app, &call_id);
+
+    // The page's own URL may carry MeetCat's auto-join parameter
+    let (url, title) = state
+        .daemon
+        .lock_or_recover()
+        .get_meetings()
+        .iter()
+        .find(|m| m.call_id == call_id)
+        .map(|m| (m.url.clone(), m.title.clone()))
+        .unwrap_or_else(|| {
+            (
+                format!("https://meet.google.com/{}", call_id),
+                String::new(),
+            )
+        });
+    tray::enter_meeting_mode(
+        &app,
+        &call_id,
+        window.label(),
+        &url,
+        &title,
+        now_ms() as i64,
+    );
+
+    // Re-schedule trigger for the next meeting
+    schedule_join_trigger(&state);
+    refresh_tray_status(&app);
+}
+
+/// Auto-click pressed the join button of `call_id`; bring the window to front
+/// if it joined in the background
+#[tauri::command]
+fn join_completed(app: AppHandle, state: State<AppState>, call_id: String) {
+    let pending = {
+        let mut background = state.background_join.lock_or_recover();
+        match background.as_ref() {
+            Some((pending_id, _)) if *pending_id == call_id => background.take(),
+            _ => None,
+        }
+    };
+    let Some((_, window)) = pending else {
+        return;
+    };
+    raise_window(&app, &window);
+    record_window_shown(&app, &window, ShowCause::BackgroundJoin, Some(&call_id));
+    log_app_event(
+        &app,
+        LogLevel::Info,
+        "join",
+        "join.background_raised",
+        None,
+        Some(json!({ "callId": call_id, "window": window })),
+    );
+}
+
+/// Record media and participant indicators the meeting page reported after
+/// joining, telling a page stuck in the green room from one in the call
+#[tauri::command]
+fn meeting_presence(
+    app: AppHandle,
+    state: State<AppState>,
+    call_id: String,
+    presence: CallPresence,
+) {
+    let verification =
+        state
+            .daemon
+            .lock_or_recover()
+            .record_call_presence(&call_id, presence, now_ms() as i64);
+    // Mute changes don't change the verification but still matter to MQTT
+    refresh_mqtt_state(&app);
+    let Some(verification) = verification else {
+        return;
+    };
+    state
+        .history
+        .lock_or_recover()
+        .verify(&call_id, verification);
+
+    log_app_event(
+        &app,
+        LogLevel::Info,
+        "meetings",
+        "meeting.verified",
+        None,
+        Some(json!({
+            "callId": call_id,
+            "verification": verification,
+            "localMediaLive": presence.local_media_live,
+            "participantCount": presence.participant_count,
+        })),
+    );
+    let _ = app.emit(
+        event::MEETING_VERIFIED,
+        MeetingVerified {
+            call_id,
+            verification,
+        },
+    );
+    persist_daemon_state(&app);
+}
+
+/// Record that Meet moved the page of joined meeting `call_id` to a breakout
+/// room; the meeting stays joined until the page leaves the call
+#[tauri::command]
+fn breakout_entered(
+    app: AppHandle,
+    state: State<AppState>,
+    call_id: String,
+    room_name: Option<String>,
+) {
+    let at_ms = now_ms() as i64;
+    let entered = state
+        .daemon
+        .lock_or_recover()
+        .enter_breakout(&call_id, room_name.clone(), at_ms);
+    if !entered {
+        return;
+    }
+    state
+        .history
+        .lock_or_recover()
+        .enter_breakout(&call_id, room_name.clone(), at_ms);
+    log_app_event(
+        &app,
+        LogLevel::Info,
+        "meetings",
+        "breakout.entered",
+        None,
+        Some(json!({ "callId": call_id, "roomName": room_name })),
+    );
+    persist_daemon_state(&app);
+}
+
+/// Record that the page of `call_id` returned from its breakout room to the
+/// main call
+#[tauri::command]
+fn breakout_exited(app: AppHandle, state: State<AppState>, call_id: String) {
+    let Some(room) = state.daemon.lock_or_recover().exit_breakout(&call_id) else {
+        return;
+    };
+    let at_ms = now_ms() as i64;
+    state
+        .history
+        .lock_or_recover()
+        .exit_breakout(&call_id, at_ms);
+    log_app_event(
+        &app,
+        LogLevel::Info,
+        "meetings",
+        "breakout.exited",
+        None,
+        Some(json!({
+            "callId": call_id,
+            "roomName": room.name,
+            "durationMs": at_ms - room.entered_at_ms,
+        })),
+    );
+    persist_daemon_state(&app);
+}
+
+/// Record that Google Meet refused to let the signed-in account join.
+///
+/// The call is never retried automatically; the user is told to join from a
+/// browser with an account that has access.
+#[tauri::command]
+fn meeting_join_blocked(
+    app: AppHandle,
+    state: State<AppState>,
+    call_id: String,
+    reason: JoinBlockedReason,
+    matched_text: Option<String>,
+) {
+    let settings = state.settings.lock_or_recover().clone();
+    let (first_time, meeting) = {
+        let mut daemon = state.daemon.lock_or_recover();
+        let first_time = daemon.mark_join_blocked(&call_id, reason, now_ms() as i64);
+        let meeting = daemon
+            .get_meetings()
+            .iter()
+            .find(|m| m.call_id == call_id)
+            .cloned();
+        (first_time, meeting)
+    };
+    emit_lifecycle_events(&app);
+    if !first_time {
+        return;
+    }
+
+    let url = meeting
+        .as_ref()
+        .map(|m| m.url.clone())
+        .or_else(|| {
+            url_norm::parse_target_path(&call_id)
+                .map(|target| url_norm::meeting_url(&target).to_string())
+        })
+        .unwrap_or_else(|| MEET_HOME_URL.to_string());
+    eprintln!("[MeetCat] Join blocked for {}: {:?}", call_id, reason);
+    log_app_event(
+        &app,
+        LogLevel::Warn,
+        "join",
+        "join.blocked",
+        None,
+        Some(json!({
+            "callId": call_id,
+            "reason": reason,
+            "matchedText": matched_text,
+        })),
+    );
+    state.logger.lock().end_join(&call_id);
+
+    let notice = JoinBlockedNotice {
+        call_id: call_id.clone(),
+        reason,
+        suggestion: JoinBlockedSuggestion::OpenInBrowser { url },
+    };
+    let _ = app.emit(event::JOIN_BLOCKED, &notice);
+
+    let lang = i18n::Language::from_setting(&settings.language);
+    let privacy_mode = settings.tauri.as_ref().is_some_and(|t| t.privacy_mode);
+    let subject = meeting
+        .as_ref()
+        .map(|m| format::meeting_subject(&lang, &m.title, &m.display_time, privacy_mode))
+        .unwrap_or_else(|| call_id.clone());
+    desktop_notify::show(
+        i18n::tr(&lang, i18n::keys::NOTIFY_JOIN_BLOCKED_TITLE),
+        &i18n::tr_join_blocked_body(&lang, &subject),
+    );
+
+    schedule_join_trigger(&state);
+    refresh_tray_status(&app);
+}
+
+/// Get call IDs Google Meet refused to join
+#[tauri::command]
+fn get_blocked_meetings(state: State<AppState>) -> Vec<String> {
+    state.daemon.lock_or_recover().get_blocked_meetings()
+}
+
+/// Open a meeting in the default browser
+#[tauri::command]
+fn open_meeting_in_browser(app: AppHandle, call_id: String) -> Result<(), String> {
+    let url = app
+        .try_state::<AppState>()
+        .and_then(|state| {
+            state
+                .daemon
+                .lock_or_recover()
+                .get_meetings()
+                .iter()
+                .find(|m| m.call_id == call_id)
+                .map(|m| m.url.clone())
+        })
+        .or_else(|| {
+            url_norm::parse_target_path(&call_id)
+                .map(|target| url_norm::meeting_url(&target).to_string())
+        })
+        .ok_or_else(|| format!("Invalid meeting id: {}", call_id))?;
+    app.opener()
+        .open_url(url, None::<&str>)
+        .map_err(|e| e.to_string())
+}
+
+/// Mark a meeting as closed
+#[tauri::command]
+fn meeting_closed(
+    app: AppHandle,
+    window: WebviewWindow,
+    state: State<AppState>,
+    call_id: String,
+    closed_at_ms: i64,
+) {
+    // Moving between breakout rooms closes the page but not the call
+    let in_breakout = state.daemon.lock_or_recover().in_breakout(&call_id);
+    if !in_breakout {
+        state
+            .arbiter
+            .lock_or_recover()
+            .on_left(window.label(), &call_id);
+    }
+    let settings = state.settings.lock_or_recover().clone();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+    let mut matched = false;
+    let mut trigger_at_ms: Option<i64> = None;
+    let mut closed = Ok(());
+    {
+        let mut daemon = state.daemon.lock_or_recover();
+        if let Some(meeting) = daemon.get_meetings().iter().find(|m| m.call_id == call_id) {
+            matched = true;
+            let computed_trigger_at_ms = meeting.begin_time.timestamp_millis()
+                - (daemon::join_before_minutes(meeting, &settings, &title_filters) as i64)
+                    * 60
+                    * 1000;
+            trigger_at_ms = Some(computed_trigger_at_ms);
+            closed = daemon.mark_closed(&call_id, closed_at_ms, computed_trigger_at_ms);
+        }
+    }
+    report_transition(&app, closed);
+
+    log_app_event(
+        &app,
+        LogLevel::Info,
+        "meetings",
+        "meeting.closed",
+        None,
+        Some(json!({
+            "callId": call_id,
+            "closedAtMs": closed_at_ms,
+            "matched": matched,
+            "triggerAtMs": trigger_at_ms,
+            "joinBeforeMinutes": settings.join_before_minutes,
+            "inBreakout": in_breakout,
+        })),
+    );
+
+    if !in_breakout {
+        tray::exit_meeting_mode(&app, &call_id);
+        finish_presence(&app, &call_id);
+    }
+
+    // Re-schedule trigger for the next meeting
+    schedule_join_trigger(&state);
+
+    let next_meeting = state
+        .daemon
+        .lock_or_recover()
+        .get_next_meeting(&settings, &title_filters);
+    tray::update_tray_status(&app, &settings, next_meeting.as_ref());
+
+    let return_home_after = settings
+        .tauri
+        .as_ref()
+        .and_then(|t| t.return_home_after_meeting)
+        .filter(|_| !in_breakout);
+    if let (Some(delay_seconds), "main") = (return_home_after, window.label()) {
+        schedule_return_home(&app, call_id, delay_seconds);
+    }
+}
+
+/// Send the main window back to the homepage once `delay_seconds` pass after
+/// `call_id` closed, so the parser resumes on fresh data.
+///
+/// Runs after the trigger was rescheduled, and only if the window still shows
+/// the closed meeting; a join or navigation in the meantime wins.
+fn schedule_return_home(app: &AppHandle, call_id: String, delay_seconds: u32) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if delay_seconds > 0 {
+            tokio::time::sleep(Duration::from_secs(delay_seconds as u64)).await;
+        }
+        let url = app
+            .get_webview_window("main")
+            .and_then(|window| window.url().ok());
+        let Some(url) = url.filter(|url| should_return_home(url, &call_id)) else {
+            log_app_event(
+                &app,
+                LogLevel::Debug,
+                "meetings",
+                "meeting.return_home_skipped",
+                None,
+                Some(json!({ "callId": call_id })),
+            );
+            return;
+        };
+        let result = navigate_to_meet_home_silent(&app);
+        log_app_event(
+            &app,
+            if result.is_ok() {
+                LogLevel::Info
+            } else {
+                LogLevel::Warn
+            },
+            "meetings",
+            "meeting.return_home",
+            result.err(),
+            Some(json!({
+                "callId": call_id,
+                "delaySeconds": delay_seconds,
+                "fromPath": url.path(),
+            })),
+        );
+    });
+}
+
+/// Whether the main window at `url` still shows the page of the closed
+/// meeting `call_id`, or Meet's page for a call that just ended
+fn should_return_home(url: &Url, call_id: &str) -> bool {
+    if !url_norm::is_allowed_host(url) || url.host_str() != Some("meet.google.com") {
+        return false;
+    }
+    let path = url.path().trim_matches('/');
+    path == call_id || path == "landing"
+}
+
+/// Calls currently active in MeetCat windows
+#[tauri::command]
+fn get_active_calls(state: State<AppState>) -> Vec<ActiveCall> {
+    state.arbiter.lock_or_recover().active_calls().to_vec()
+}
+
+/// Longest look-ahead accepted by `get_conflicts`
+const MAX_CONFLICT_RANGE_HOURS: u32 = 7 * 24;
+
+/// Overlapping meetings in the next `range_hours`, with the meeting the join
+/// engine will pick in each group
+#[tauri::command]
+fn get_conflicts(state: State<AppState>, range_hours: u32) -> Vec<daemon::ConflictGroup> {
+    let settings = state.settings.lock_or_recover().clone();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+    let range = chrono::Duration::hours(range_hours.min(MAX_CONFLICT_RANGE_HOURS) as i64);
+    state.daemon.lock_or_recover().find_conflicts_at(
+        &settings,
+        &title_filters,
+        chrono::Utc::now(),
+        range,
+    )
+}
+
+/// Today's and tomorrow's meetings, grouped around the current time with the
+/// join decision for each, so every surface lists them the same way
+#[tauri::command]
+fn get_agenda_view(state: State<AppState>) -> daemon::AgendaView {
+    let settings = state.settings.lock_or_recover().clone();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+    state.daemon.lock_or_recover().agenda_view_at(
+        &settings,
+        &title_filters,
+        chrono::Utc::now(),
+        &chrono::Local,
+    )
+}
+
+/// Busy blocks (Focus time, Lunch) reported by a calendar source
+#[tauri::command]
+fn busy_blocks_updated(
+    app: AppHandle,
+    state: State<AppState>,
+    source: MeetingSource,
+    blocks: Vec<daemon::BusyBlock>,
+) {
+    let count = blocks.len();
+    state
+        .daemon
+        .lock_or_recover()
+        .update_busy_blocks(source.clone(), blocks);
+    log_app_event(
+        &app,
+        LogLevel::Debug,
+        "meetings",
+        "busy_blocks.updated",
+        None,
+        Some(json!({ "source": source, "count": count })),
+    );
+    schedule_join_trigger(&state);
+}
+
+/// Why the join engine waits for, skips or plans a meeting
+#[tauri::command]
+fn explain_join(state: State<AppState>, call_id: String) -> Option<daemon::JoinExplanation> {
+    let settings = state.settings.lock_or_recover().clone();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+    state.daemon.lock_or_recover().explain_join_at(
+        &call_id,
+        &settings,
+        &title_filters,
+        chrono::Utc::now(),
+    )
+}
+
+/// The join the daemon triggers next, with the pauses, snoozes and
+/// suppressions holding other joins back
+#[tauri::command]
+fn get_next_trigger(state: State<AppState>) -> daemon::TriggerSchedule {
+    let settings = state.settings.lock_or_recover().clone();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+    state.daemon.lock_or_recover().trigger_schedule_at(
+        &settings,
+        &title_filters,
+        chrono::Utc::now(),
+    )
+}
+
+/// Outcome of each configured global shortcut
+#[tauri::command]
+fn get_shortcut_status(state: State<AppState>) -> Vec<ShortcutStatus> {
+    state.shortcuts.lock_or_recover().statuses().to_vec()
+}
+
+/// Camera and microphone permission status, without prompting
+#[tauri::command]
+fn check_media_permissions() -> permissions::MediaPermissions {
+    permissions::check()
+}
+
+/// Show the system prompt for camera and microphone access where it was
+/// never answered, and report the resulting status
+#[tauri::command]
+async fn request_media_permissions(app: AppHandle) -> permissions::MediaPermissions {
+    let result = permissions::request().await;
+    log_app_event(
+        &app,
+        LogLevel::Info,
+        "permissions",
+        "media.requested",
+        None,
+        serde_json::to_value(&result).ok(),
+    );
+    result
+}
+
+/// Open the System Settings pane that grants `device` to MeetCat
+#[tauri::command]
+fn open_media_permission_settings(
+    app: AppHandle,
+    device: permissions::MediaDevice,
+) -> Result<(), String> {
+    let url = permissions::settings_url(device)
+        .ok_or_else(|| "Media permissions are only managed on macOS".to_string())?;
+    app.opener()
+        .open_url(url, None::<&str>)
+        .map_err(|e| e.to_string())
+}
+
+/// Why MeetCat recently showed or focused its windows, newest first
+#[tauri::command]
+fn get_window_activity(state: State<AppState>, limit: Option<usize>) -> Vec<WindowActivity> {
+    state
+        .window_activity
+        .lock_or_recover()
+        .recent(limit.unwrap_or(DEFAULT_WINDOW_ACTIVITY_LIMIT))
+}
+
+/// Suppressed meetings with when and why each was suppressed
+#[tauri::command]
+fn get_suppressed_meetings(state: State<AppState>) -> Vec<Suppression> {
+    let daemon = state.daemon.lock_or_recover();
+    daemon.get_suppressed_meetings()
+}
+
+/// Let auto-join pick a suppressed meeting again; returns false when it
+/// wasn't suppressed
+#[tauri::command]
+fn clear_suppressed(app: AppHandle, state: State<AppState>, call_id: String) -> bool {
+    let cleared = state
+        .daemon
+        .lock_or_recover()
+        .clear_suppressed(&call_id, now_ms() as i64);
+    if !cleared {
+        return false;
+    }
+    emit_lifecycle_events(&app);
+    log_app_event(
+        &app,
+        LogLevel::Info,
+        "join",
+        "join.suppression_cleared",
+        None,
+        Some(json!({ "callId": call_id })),
+    );
+    schedule_join_trigger(&state);
+    refresh_tray_status(&app);
+    true
+}
+
+#[tauri::command]
+fn get_update_info(state: State<AppState>) -> Option<UpdateInfo> {
+    state.update_info.lock_or_recover().clone()
+}
+
+#[tauri::command]
+fn get_update_prompt_preference(state: State<AppState>) -> UpdatePromptPreference {
+    state.update_prompt_preference.lock_or_recover().clone()
+}
+
+#[tauri::command]
+fn set_update_prompt_preference(
+    app: AppHandle,
+    state: State<AppState>,
+    preference: UpdatePromptPreference,
+) -> Result<(), String> {
+    {
+        let mut current = state.update_prompt_preference.lock_or_recover();
+        *current = preference.clone();
+    }
+
+    save_update_prompt_preference(&preference)?;
+
+    app.emit(event::UPDATE_PREFERENCE_CHANGED, preference)
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[tauri::command]
+async fn check_for_update_manual(app: AppHandle) -> Result<Option<UpdateInfo>, String> {
+    check_for_update_with_source(app, "manual").await
+}
+
+#[tauri::command]
+async fn download_and_install_update(app: AppHandle, auto_restart: bool) -> Result<bool, String> {
+    let settings = app.state::<AppState>().settings.lock_or_recover().clone();
+    let updater = http_client::updater(&app, &settings)?;
 
     let update = updater.check().await.map_err(|e| e.to_string())?;
     if let Some(update) = update {
@@ -532,7 +4273,7 @@ async fn download_and_install_update(app: AppHandle, auto_restart: bool) -> Resu
                         }
                     });
                     let _ = app_progress.emit(
-                        "update:download-progress",
+                        event::UPDATE_DOWNLOAD_PROGRESS,
                         UpdateDownloadProgress {
                             downloaded,
                             total: total.map(|value| value as u64),
@@ -541,7 +4282,7 @@ async fn download_and_install_update(app: AppHandle, auto_restart: bool) -> Resu
                     );
                 },
                 move || {
-                    let _ = app_finish.emit("update:download-finish", ());
+                    let _ = app_finish.emit(event::UPDATE_DOWNLOAD_FINISH, ());
                 },
             )
             .await;
@@ -553,9 +4294,9 @@ async fn download_and_install_update(app: AppHandle, auto_restart: bool) -> Resu
 
         {
             let state = app.state::<AppState>();
-            *state.update_info.lock().unwrap() = None;
+            *state.update_info.lock_or_recover() = None;
         }
-        let _ = app.emit("update:available", Option::<UpdateInfo>::None);
+        let _ = app.emit(event::UPDATE_AVAILABLE, Option::<UpdateInfo>::None);
         refresh_tray_status(&app);
 
         log_app_event(
@@ -585,7 +4326,7 @@ fn open_update_dialog(app: AppHandle) -> Result<(), String> {
 
 #[tauri::command]
 fn consume_open_update_dialog_request(state: State<AppState>) -> bool {
-    let mut requested = state.update_dialog_requested.lock().unwrap();
+    let mut requested = state.update_dialog_requested.lock_or_recover();
     let value = *requested;
     *requested = false;
     value
@@ -593,7 +4334,7 @@ fn consume_open_update_dialog_request(state: State<AppState>) -> bool {
 
 #[tauri::command]
 fn consume_manual_update_check_request(state: State<AppState>) -> bool {
-    let mut requested = state.update_manual_check_requested.lock().unwrap();
+    let mut requested = state.update_manual_check_requested.lock_or_recover();
     let value = *requested;
     *requested = false;
     value
@@ -601,16 +4342,16 @@ fn consume_manual_update_check_request(state: State<AppState>) -> bool {
 
 pub(crate) fn request_open_update_dialog(app: &AppHandle) {
     if let Some(state) = app.try_state::<AppState>() {
-        *state.update_dialog_requested.lock().unwrap() = true;
+        *state.update_dialog_requested.lock_or_recover() = true;
     }
-    let _ = app.emit("update:open-dialog", ());
+    let _ = app.emit(event::UPDATE_OPEN_DIALOG, ());
 }
 
 pub(crate) fn request_manual_update_check(app: &AppHandle) {
     if let Some(state) = app.try_state::<AppState>() {
-        *state.update_manual_check_requested.lock().unwrap() = true;
+        *state.update_manual_check_requested.lock_or_recover() = true;
     }
-    let _ = app.emit("update:manual-check", ());
+    let _ = app.emit(event::UPDATE_MANUAL_CHECK, ());
 }
 
 async fn check_for_update_with_source(
@@ -619,15 +4360,16 @@ async fn check_for_update_with_source(
 ) -> Result<Option<UpdateInfo>, String> {
     let state = app.state::<AppState>();
     {
-        let mut checking = state.update_checking.lock().unwrap();
+        let mut checking = state.update_checking.lock_or_recover();
         if *checking {
-            return Ok(state.update_info.lock().unwrap().clone());
+            return Ok(state.update_info.lock_or_recover().clone());
         }
         *checking = true;
     }
 
     let result = async {
-        let updater = app.updater_builder().build().map_err(|e| e.to_string())?;
+        let settings = app.state::<AppState>().settings.lock_or_recover().clone();
+        let updater = http_client::updater(&app, &settings)?;
         let update = updater.check().await.map_err(|e| e.to_string())?;
 
         let info = update.map(|item| UpdateInfo {
@@ -637,9 +4379,9 @@ async fn check_for_update_with_source(
 
         {
             let state = app.state::<AppState>();
-            *state.update_info.lock().unwrap() = info.clone();
+            *state.update_info.lock_or_recover() = info.clone();
         }
-        let _ = app.emit("update:available", info.clone());
+        let _ = app.emit(event::UPDATE_AVAILABLE, info.clone());
         refresh_tray_status(&app);
 
         let context = match info.as_ref() {
@@ -668,50 +4410,424 @@ async fn check_for_update_with_source(
 
     {
         let state = app.state::<AppState>();
-        *state.update_checking.lock().unwrap() = false;
+        *state.update_checking.lock_or_recover() = false;
+    }
+
+    result
+}
+
+fn log_update_error(err: &impl StdError) {
+    eprintln!("[MeetCat] update install failed: {}", err);
+    let mut source = err.source();
+    let mut index = 0;
+    while let Some(cause) = source {
+        eprintln!("[MeetCat] caused by({}): {}", index, cause);
+        source = cause.source();
+        index += 1;
+    }
+}
+
+fn handle_deep_link_url(app: &AppHandle, url: &Url) {
+    suppress_reopen_focus(app);
+
+    let url_str = url.to_string();
+    let callback = url_scheme::parse_callback(url);
+    match url_scheme::parse(url) {
+        Some(action) if action.needs_confirmation() => {
+            confirm_deep_link_action(app, action, callback)
+        }
+        Some(action) => handle_deep_link_action(app, action, callback),
+        None => {
+            log_app_event(
+                app,
+                LogLevel::Warn,
+                "deep_link",
+                "parse.unknown",
+                None,
+                Some(json!({ "url": url_str })),
+            );
+            send_deep_link_callback(
+                app,
+                &callback,
+                Err(CallbackError::new("unknown_action", "Unrecognized MeetCat URL")),
+            );
+            focus_main_window(app);
+            record_window_shown(app, "main", ShowCause::DeepLink, None);
+        }
+    }
+}
+
+/// Ask the user before a link changes what MeetCat does, since any web page
+/// can open one. A declined or unanswered prompt reports `not_confirmed`.
+fn confirm_deep_link_action(app: &AppHandle, action: DeepLinkAction, callback: XCallback) {
+    if !desktop_notify::supports_actions() {
+        send_deep_link_callback(
+            app,
+            &callback,
+            Err(CallbackError::new(
+                "confirmation_unavailable",
+                "This action can't be confirmed on this platform",
+            )),
+        );
+        return;
+    }
+    let link = action.link();
+    log_app_event(
+        app,
+        LogLevel::Info,
+        "deep_link",
+        "action.confirm_requested",
+        None,
+        Some(json!({ "link": link })),
+    );
+    let lang = app
+        .try_state::<AppState>()
+        .map(|state| i18n::Language::from_setting(&state.settings.lock_or_recover().language))
+        .unwrap_or_else(i18n::Language::detect);
+    let app_handle = app.clone();
+    desktop_notify::ask(
+        i18n::tr(&lang, i18n::keys::NOTIFY_LINK_CONFIRM_TITLE),
+        &i18n::tr_link_confirm_body(&lang, &link),
+        i18n::tr(&lang, i18n::keys::NOTIFY_ALLOW),
+        DEEP_LINK_CONFIRM_TIMEOUT,
+        move |confirmed| {
+            if confirmed {
+                handle_deep_link_action(&app_handle, action, callback);
+                return;
+            }
+            log_app_event(
+                &app_handle,
+                LogLevel::Info,
+                "deep_link",
+                "action.not_confirmed",
+                None,
+                Some(json!({ "link": link })),
+            );
+            send_deep_link_callback(
+                &app_handle,
+                &callback,
+                Err(CallbackError::new(
+                    "not_confirmed",
+                    "The action wasn't allowed",
+                )),
+            );
+        },
+    );
+}
+
+fn handle_deep_link_action(app: &AppHandle, action: DeepLinkAction, callback: XCallback) {
+    preempt_daemon_for_join(app, &action);
+    if action.requires_main_window_navigation() && !is_main_first_load_done(app) {
+        queue_pending_deep_link(app, action, callback);
+        // Bring the main window forward so the user sees something
+        // happen while we wait for the initial load to finish.
+        focus_main_window(app);
+        record_window_shown(app, "main", ShowCause::DeepLink, None);
+    } else {
+        dispatch_deep_link(app, action, callback);
+    }
+}
+
+/// Handle `--join`, `--pause` and `--status` before anything is set up.
+///
+/// Returns the exit code once the command ran in the instance that is
+/// already running; `None` carries on with a normal launch, which applies a
+/// join or pause itself in [`apply_launch_cli_command`].
+/// Hand the links this process was launched with to the instance already
+/// running on the same data directory. Returns the exit code once they were
+/// forwarded, or `None` to start normally and handle them here.
+///
+/// macOS delivers links to the running app itself, so this is only needed on
+/// Windows and Linux.
+#[cfg(not(target_os = "macos"))]
+fn forward_launch_urls() -> Option<i32> {
+    let urls = url_scheme::launch_urls(std::env::args().skip(1));
+    let mut code = None;
+    for url in urls {
+        let command = cli::CliCommand::OpenUrl {
+            url: url.to_string(),
+        };
+        match cli::send(&command) {
+            Ok(_) => {
+                code.get_or_insert(0);
+            }
+            // Nothing to forward to; this process becomes the instance
+            Err(cli::SendError::NotRunning) if code.is_none() => return None,
+            Err(cli::SendError::NotRunning) => {
+                eprintln!("meetcat: MeetCat quit before {} was opened", url);
+                code = Some(1);
+            }
+            Err(cli::SendError::Failed(e)) => {
+                eprintln!("meetcat: {}", e);
+                code = Some(1);
+            }
+        }
+    }
+    code
+}
+
+fn run_cli_from_args() -> Option<i32> {
+    let invocation = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(Some(invocation)) => invocation,
+        Ok(None) => return None,
+        Err(e) => {
+            eprintln!("meetcat: {}", e);
+            return Some(2);
+        }
+    };
+    match cli::send(&invocation.command) {
+        Ok(result) => {
+            if invocation.json {
+                println!("{}", result);
+            } else {
+                println!(
+                    "{}",
+                    cli::format_result(&invocation.command, &result, chrono::Utc::now())
+                );
+            }
+            Some(0)
+        }
+        Err(cli::SendError::NotRunning) if invocation.command.deep_link_action().is_some() => None,
+        Err(cli::SendError::NotRunning) => {
+            eprintln!("meetcat: MeetCat is not running");
+            Some(1)
+        }
+        Err(cli::SendError::Failed(e)) => {
+            eprintln!("meetcat: {}", e);
+            Some(1)
+        }
+    }
+}
+
+/// Run a join or pause this process was launched with, now that it's up
+fn apply_launch_cli_command(app: &AppHandle) {
+    let action = cli::parse_args(std::env::args().skip(1))
+        .ok()
+        .flatten()
+        .and_then(|invocation| invocation.command.deep_link_action());
+    if let Some(action) = action {
+        handle_deep_link_action(app, action, XCallback::default());
+    }
+}
+
+/// Answer CLI commands sent by later `meetcat --join/--pause/--status` runs
+fn start_cli_listener(app: &AppHandle) {
+    let handle = app.clone();
+    let result = cli::listen(move |command| {
+        run_on_main_and_wait(&handle, move |app| run_cli_command(app, command))?
+    });
+    if let Err(e) = result {
+        log_app_event(app, LogLevel::Warn, "cli", "listen.failed", Some(e), None);
+    }
+}
+
+fn run_cli_command(app: &AppHandle, command: cli::CliCommand) -> Result<serde_json::Value, String> {
+    log_app_event(
+        app,
+        LogLevel::Info,
+        "cli",
+        "command.received",
+        None,
+        serde_json::to_value(&command).ok(),
+    );
+    if let cli::CliCommand::OpenUrl { url } = &command {
+        // Any app can open a link, so it goes through the same confirmation
+        // and callbacks as one opened in this process
+        let url = Url::parse(url).map_err(|e| e.to_string())?;
+        handle_deep_link_url(app, &url);
+        return Ok(json!({ "ok": true }));
     }
+    let Some(action) = command.deep_link_action() else {
+        let state = app.state::<AppState>();
+        return serde_json::to_value(app_status(&state)).map_err(|e| e.to_string());
+    };
+    run_remote_action(app, action).map_err(|e| e.message)
+}
 
-    result
+/// Run `f` on the main thread, where window and daemon updates belong, and
+/// wait for its result. Must not be called from the main thread itself.
+fn run_on_main_and_wait<T, F>(app: &AppHandle, f: F) -> Result<T, String>
+where
+    T: Send + 'static,
+    F: FnOnce(&AppHandle) -> T + Send + 'static,
+{
+    let (tx, rx) = std::sync::mpsc::channel();
+    let handle = app.clone();
+    app.run_on_main_thread(move || {
+        let _ = tx.send(f(&handle));
+    })
+    .map_err(|e| e.to_string())?;
+    rx.recv()
+        .map_err(|_| "MeetCat quit before answering".to_string())
 }
 
-fn log_update_error(err: &impl StdError) {
-    eprintln!("[MeetCat] update install failed: {}", err);
-    let mut source = err.source();
-    let mut index = 0;
-    while let Some(cause) = source {
-        eprintln!("[MeetCat] caused by({}): {}", index, cause);
-        source = cause.source();
-        index += 1;
+/// Run an action requested by another process and report its outcome.
+///
+/// Unlike a deep link there is no callback to answer later, so a join that
+/// has to wait for the main window's first load reports `queued` instead.
+fn run_remote_action(
+    app: &AppHandle,
+    action: DeepLinkAction,
+) -> Result<serde_json::Value, CallbackError> {
+    preempt_daemon_for_join(app, &action);
+    if action.requires_main_window_navigation() && !is_main_first_load_done(app) {
+        queue_pending_deep_link(app, action, XCallback::default());
+        return Ok(json!({ "ok": true, "queued": true }));
     }
+    run_deep_link_action(app, action)
 }
 
-fn handle_deep_link_url(app: &AppHandle, url: &Url) {
-    suppress_reopen_focus(app);
+/// Start, stop or re-key the local control API to match the settings
+fn apply_api_server(app: &AppHandle) {
+    let state = app.state::<AppState>();
+    let settings = state.settings.lock_or_recover().clone();
+    let tauri_settings = settings.tauri.clone().unwrap_or_default();
+    let mut server = state.api_server.lock_or_recover();
 
-    let url_str = url.to_string();
-    match url_scheme::parse(url) {
-        Some(action) => {
-            preempt_daemon_for_join(app, &action);
-            if action.requires_main_window_navigation() && !is_main_first_load_done(app) {
-                queue_pending_deep_link(app, action);
-                // Bring the main window forward so the user sees something
-                // happen while we wait for the initial load to finish.
-                focus_main_window(app);
-            } else {
-                dispatch_deep_link(app, action);
-            }
+    if !tauri_settings.api_server_enabled {
+        if server.take().is_some() {
+            log_app_event(
+                app,
+                LogLevel::Info,
+                "api_server",
+                "api_server.stopped",
+                None,
+                None,
+            );
         }
-        None => {
+        return;
+    }
+    let Some(token) = secrets::api_server_token(&settings, &Keychain) else {
+        server.take();
+        log_app_event(
+            app,
+            LogLevel::Warn,
+            "api_server",
+            "api_server.no_token",
+            Some("Enabled without a token; not listening".to_string()),
+            None,
+        );
+        return;
+    };
+    let port = tauri_settings.api_server_port;
+    if let Some(running) = server.as_ref().filter(|running| running.port() == port) {
+        if let Err(e) = running.set_token(&token) {
             log_app_event(
                 app,
                 LogLevel::Warn,
-                "deep_link",
-                "parse.unknown",
+                "api_server",
+                "api_server.token_rejected",
+                Some(e),
                 None,
-                Some(json!({ "url": url_str })),
             );
-            focus_main_window(app);
         }
+        return;
+    }
+
+    // Release the old port before binding the new one
+    server.take();
+    let handle = app.clone();
+    let handler: api_server::ApiHandler = Arc::new(move |route| {
+        run_on_main_and_wait(&handle, move |app| run_api_route(app, route))
+            .map_err(|e| api_server::ApiError::new(503, e))?
+    });
+    match api_server::start(port, &token, handler, state.api_events.clone()) {
+        Ok(started) => {
+            *server = Some(started);
+            log_app_event(
+                app,
+                LogLevel::Info,
+                "api_server",
+                "api_server.started",
+                None,
+                Some(json!({ "port": port })),
+            );
+        }
+        Err(e) => log_app_event(
+            app,
+            LogLevel::Warn,
+            "api_server",
+            "api_server.start_failed",
+            Some(e),
+            Some(json!({ "port": port })),
+        ),
+    }
+}
+
+fn run_api_route(
+    app: &AppHandle,
+    route: api_server::ApiRoute,
+) -> Result<serde_json::Value, api_server::ApiError> {
+    use api_server::{ApiError, ApiRoute};
+
+    log_app_event(
+        app,
+        LogLevel::Info,
+        "api_server",
+        "request.received",
+        Some(format!("{:?}", route)),
+        None,
+    );
+    let action = match route {
+        ApiRoute::Status => {
+            let state = app.state::<AppState>();
+            return serde_json::to_value(app_status(&state))
+                .map_err(|e| ApiError::new(500, e.to_string()));
+        }
+        ApiRoute::Join { code } => DeepLinkAction::JoinMeeting { code },
+        ApiRoute::Pause { minutes } => DeepLinkAction::Pause { minutes },
+        ApiRoute::Resume => DeepLinkAction::Resume,
+        // Streams are served by the server itself
+        ApiRoute::Events => return Err(ApiError::new(404, "Not found")),
+    };
+    run_remote_action(app, action).map_err(|e| {
+        let status = match e.code {
+            "invalid_url" => 400,
+            "no_meeting" => 404,
+            "unavailable" => 503,
+            _ => 500,
+        };
+        ApiError::new(status, e.message)
+    })
+}
+
+/// Pass the events the control API streams on to its WebSocket clients
+fn forward_api_events(app: &AppHandle) {
+    for &event in api_server::STREAMED_EVENTS {
+        let handle = app.clone();
+        app.listen(event, move |e| {
+            let state = handle.state::<AppState>();
+            // No subscribers is the common case and not an error
+            let _ = state
+                .api_events
+                .send(api_server::event_message(event, e.payload()));
+        });
+    }
+}
+
+/// Windows and Linux deliver links as launch arguments instead of
+/// `RunEvent::Opened`, so handle the ones this process started with.
+#[cfg(not(target_os = "macos"))]
+fn setup_launch_deep_links(app: &AppHandle) {
+    use tauri_plugin_deep_link::DeepLinkExt;
+
+    // Installed builds register the scheme at install time; this covers
+    // AppImage and development runs
+    if let Err(e) = app.deep_link().register_all() {
+        log_app_event(
+            app,
+            LogLevel::Warn,
+            "deep_link",
+            "scheme.register_failed",
+            Some(e.to_string()),
+            None,
+        );
+    }
+
+    // Reached only when no other instance took the links
+    // (see `forward_launch_urls`)
+    for url in url_scheme::launch_urls(std::env::args().skip(1)) {
+        handle_deep_link_url(app, &url);
     }
 }
 
@@ -730,10 +4846,12 @@ fn preempt_daemon_for_join(app: &AppHandle, action: &DeepLinkAction) {
         return;
     };
     let now = now_ms() as i64;
-    {
-        let mut daemon = state.daemon.lock().unwrap();
-        daemon.mark_suppressed(code, now);
-    }
+    let suppressed =
+        state
+            .daemon
+            .lock_or_recover()
+            .mark_suppressed(code, SuppressionReason::DeepLink, now);
+    report_transition(app, suppressed);
     log_app_event(
         app,
         LogLevel::Info,
@@ -750,11 +4868,11 @@ fn is_main_first_load_done(app: &AppHandle) -> bool {
         .unwrap_or(true)
 }
 
-fn queue_pending_deep_link(app: &AppHandle, action: DeepLinkAction) {
+fn queue_pending_deep_link(app: &AppHandle, action: DeepLinkAction, callback: XCallback) {
     let Some(state) = app.try_state::<AppState>() else {
         // No state means the app is in an unusual lifecycle stage; fall back
         // to immediate dispatch rather than dropping the action silently.
-        dispatch_deep_link(app, action);
+        dispatch_deep_link(app, action, callback);
         return;
     };
     log_app_event(
@@ -768,15 +4886,15 @@ fn queue_pending_deep_link(app: &AppHandle, action: DeepLinkAction) {
             "reason": "main_first_load_pending",
         })),
     );
-    *state.pending_deep_link.lock().unwrap() = Some(action);
+    *state.pending_deep_link.lock_or_recover() = Some((action, callback));
 }
 
 fn drain_pending_deep_link(app: &AppHandle) {
     let Some(state) = app.try_state::<AppState>() else {
         return;
     };
-    let action = state.pending_deep_link.lock().unwrap().take();
-    if let Some(action) = action {
+    let pending = state.pending_deep_link.lock_or_recover().take();
+    if let Some((action, callback)) = pending {
         log_app_event(
             app,
             LogLevel::Info,
@@ -785,75 +4903,500 @@ fn drain_pending_deep_link(app: &AppHandle) {
             None,
             Some(json!({ "action": format!("{:?}", action) })),
         );
-        dispatch_deep_link(app, action);
+        dispatch_deep_link(app, action, callback);
     }
 }
 
 fn suppress_reopen_focus(app: &AppHandle) {
     if let Some(state) = app.try_state::<AppState>() {
-        *state.suppress_reopen_focus_until_ms.lock().unwrap() = now_ms() + 3_000;
+        *state.suppress_reopen_focus_until_ms.lock_or_recover() = now_ms() + 3_000;
     }
 }
 
+#[cfg(target_os = "macos")]
 fn should_suppress_reopen_focus(app: &AppHandle) -> bool {
     app.try_state::<AppState>()
-        .map(|state| now_ms() <= *state.suppress_reopen_focus_until_ms.lock().unwrap())
+        .map(|state| now_ms() <= *state.suppress_reopen_focus_until_ms.lock_or_recover())
         .unwrap_or(false)
 }
 
+#[cfg(target_os = "macos")]
 fn focus_main_window_after_reopen(app: &AppHandle) {
     if !should_suppress_reopen_focus(app) {
         focus_main_window(app);
+        record_window_shown(app, "main", ShowCause::Reopen, None);
     }
 }
 
-fn setup_update_checker(app: &AppHandle) {
+/// Run a scheduled update check in the background
+fn run_update_check(app: &AppHandle, source: &'static str) {
     let app_handle = app.clone();
     tauri::async_runtime::spawn(async move {
-        tokio::time::sleep(Duration::from_secs(3)).await;
-        let _ = check_for_update_with_source(app_handle.clone(), "startup").await;
-
-        loop {
-            tokio::time::sleep(Duration::from_secs(UPDATE_CHECK_INTERVAL_SECONDS)).await;
-            let _ = check_for_update_with_source(app_handle.clone(), "polling").await;
-        }
+        let _ = check_for_update_with_source(app_handle, source).await;
     });
 }
 
+/// Path of a state file in the data directory, creating the directory
+fn state_file_path(name: &str) -> Result<PathBuf, String> {
+    let path =
+        paths::state_file(name).ok_or_else(|| "Failed to get config directory".to_string())?;
+    if let Some(app_dir) = path.parent() {
+        fs::create_dir_all(app_dir).map_err(|e| e.to_string())?;
+    }
+    Ok(path)
+}
+
 fn update_prompt_preference_path() -> Result<PathBuf, String> {
-    let config_dir =
-        dirs::config_dir().ok_or_else(|| "Failed to get config directory".to_string())?;
-    let app_dir = config_dir.join("meetcat");
-    fs::create_dir_all(&app_dir).map_err(|e| e.to_string())?;
-    Ok(app_dir.join(UPDATE_PROMPT_PREFERENCE_FILE))
+    state_file_path(UPDATE_PROMPT_PREFERENCE_FILE)
+}
+
+fn load_update_prompt_preference() -> LoadedState<UpdatePromptPreference> {
+    match update_prompt_preference_path() {
+        Ok(path) => persistence::load_versioned(&path, now_ms()),
+        Err(_) => LoadedState {
+            state: UpdatePromptPreference::default(),
+            migrated_from: None,
+            quarantined: None,
+        },
+    }
+}
+
+fn save_update_prompt_preference(preference: &UpdatePromptPreference) -> Result<(), String> {
+    let path = update_prompt_preference_path()?;
+    persistence::save_versioned(&path, preference)
+}
+
+fn recent_codes_path() -> Result<PathBuf, String> {
+    state_file_path(RECENT_CODES_FILE)
+}
+
+fn meeting_history_path() -> Result<PathBuf, String> {
+    state_file_path(MEETING_HISTORY_FILE)
+}
+
+/// Drop history entries past the retention period
+fn prune_meeting_history() {
+    let cutoff_ms = now_ms() as i64 - history::HISTORY_RETENTION_DAYS * 24 * 60 * 60 * 1000;
+    match meeting_history_path().and_then(|path| history::prune(&path, cutoff_ms)) {
+        Ok(0) => {}
+        Ok(dropped) => println!("[MeetCat] Dropped {} old meeting history entries", dropped),
+        Err(e) => eprintln!("[MeetCat] Failed to prune meeting history: {}", e),
+    }
+}
+
+fn load_recent_codes() -> LoadedState<RecentMeetingCodes> {
+    match recent_codes_path() {
+        Ok(path) => persistence::load_versioned(&path, now_ms()),
+        Err(_) => LoadedState {
+            state: RecentMeetingCodes::default(),
+            migrated_from: None,
+            quarantined: None,
+        },
+    }
+}
+
+fn daemon_state_path() -> Result<PathBuf, String> {
+    state_file_path(DAEMON_STATE_FILE)
+}
+
+fn load_daemon_state() -> LoadedState<PersistedDaemonState> {
+    match daemon_state_path() {
+        Ok(path) => persistence::load_versioned(&path, now_ms()),
+        Err(_) => LoadedState {
+            state: PersistedDaemonState::default(),
+            migrated_from: None,
+            quarantined: None,
+        },
+    }
+}
+
+/// Save the daemon's meetings and lifecycle records if they changed
+fn persist_daemon_state(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let Some(snapshot) = state
+        .daemon
+        .lock_or_recover()
+        .take_snapshot_if_dirty(now_ms() as i64)
+    else {
+        return;
+    };
+    let result = daemon_state_path().and_then(|path| persistence::save_versioned(&path, &snapshot));
+    if let Err(e) = result {
+        log_app_event(
+            app,
+            LogLevel::Warn,
+            "daemon",
+            "daemon.state_save_failed",
+            Some(e),
+            None,
+        );
+    }
+}
+
+/// Log and announce state files that were quarantined during startup
+fn report_quarantined_files(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let quarantined = std::mem::take(&mut *state.quarantined_files.lock_or_recover());
+    for file in quarantined {
+        log_app_event(
+            app,
+            LogLevel::Warn,
+            "persistence",
+            "file.quarantined",
+            Some(format!(
+                "Unreadable state file moved to {}; continuing with defaults",
+                file.moved_to.display()
+            )),
+            Some(json!({
+                "original": file.original,
+                "movedTo": file.moved_to,
+                "reason": file.reason,
+            })),
+        );
+        let _ = app.emit(event::PERSISTENCE_QUARANTINED, &file);
+    }
+}
+
+/// Log and announce that the settings were restored from their last good
+/// copy, once the app is running
+fn report_settings_recovery(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let Some(recovered) = state.settings_recovered.lock_or_recover().take() else {
+        return;
+    };
+    log_app_event(
+        app,
+        LogLevel::Warn,
+        "settings",
+        "settings.recovered",
+        Some(format!(
+            "Unreadable settings restored from {}",
+            recovered.restored_from.display()
+        )),
+        Some(json!({
+            "restoredFrom": recovered.restored_from,
+            "quarantinedTo": recovered.quarantined_to,
+            "reason": recovered.reason,
+        })),
+    );
+    let _ = app.emit(event::SETTINGS_RECOVERED, &recovered);
 }
 
-fn load_update_prompt_preference() -> UpdatePromptPreference {
-    let Ok(path) = update_prompt_preference_path() else {
-        return UpdatePromptPreference::default();
+/// Fold completed log days into weekly digests and prune old raw logs
+fn run_log_digest(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
     };
-    if !path.exists() {
-        return UpdatePromptPreference::default();
+    let run = state.logger.lock().run_digest(now_ms());
+    if !run.folded.is_empty() || run.pruned_digests > 0 {
+        log_app_event(
+            app,
+            LogLevel::Info,
+            "logging",
+            "digest.completed",
+            None,
+            Some(json!({
+                "folded": run.folded,
+                "weeks": run.weeks,
+                "prunedDigests": run.pruned_digests,
+            })),
+        );
     }
+    if !run.quarantined.is_empty() {
+        state
+            .quarantined_files
+            .lock_or_recover()
+            .extend(run.quarantined);
+        report_quarantined_files(app);
+    }
+}
 
-    let Ok(content) = fs::read_to_string(path) else {
-        return UpdatePromptPreference::default();
+/// Get the log digest for an ISO week such as `2026-W42` (default: the
+/// current week)
+#[tauri::command]
+fn get_log_digest(
+    state: State<AppState>,
+    week: Option<String>,
+) -> Result<Option<log_digest::LogDigest>, String> {
+    let monday = match week {
+        Some(week) => log_digest::parse_week_key(&week)
+            .ok_or_else(|| format!("Invalid week: {}", week))?,
+        None => chrono::Utc::now().date_naive(),
     };
+    let week = log_digest::week_key(monday);
+    Ok(state.logger.lock().digest(&week))
+}
 
-    serde_json::from_str::<UpdatePromptPreference>(&content).unwrap_or_default()
+/// Stored log entries matching `filter`, newest first, for the log viewer
+#[tauri::command]
+fn query_logs(state: State<AppState>, filter: LogQuery) -> Result<Vec<LogEntry>, String> {
+    Ok(state.logger.lock().query_logs(&filter))
 }
 
-fn save_update_prompt_preference(preference: &UpdatePromptPreference) -> Result<(), String> {
-    let path = update_prompt_preference_path()?;
-    let content = serde_json::to_string_pretty(preference).map_err(|e| e.to_string())?;
-    fs::write(path, content).map_err(|e| e.to_string())
+/// Bundle the retained logs, sanitized settings and system info into a zip
+/// for a bug report. Written to `directory` (default: Downloads); returns
+/// the path of the zip.
+#[tauri::command]
+fn export_logs(
+    app: AppHandle,
+    state: State<AppState>,
+    directory: Option<String>,
+) -> Result<String, String> {
+    let dest_dir = match directory {
+        Some(directory) => PathBuf::from(directory),
+        None => dirs::download_dir()
+            .or_else(dirs::home_dir)
+            .ok_or_else(|| "No download directory available".to_string())?,
+    };
+    let settings = state.settings.lock_or_recover().clone();
+    let info = logging::ExportInfo::current(&app.package_info().version.to_string());
+    let result = state.logger.lock().export(&settings, &info, &dest_dir);
+
+    match result {
+        Ok(path) => {
+            log_app_event(
+                &app,
+                LogLevel::Info,
+                "logs",
+                "logs.exported",
+                None,
+                Some(json!({ "path": path.display().to_string() })),
+            );
+            Ok(path.display().to_string())
+        }
+        Err(e) => {
+            log_app_event(
+                &app,
+                LogLevel::Warn,
+                "logs",
+                "logs.export_failed",
+                Some(e.clone()),
+                None,
+            );
+            Err(e)
+        }
+    }
+}
+
+pub(crate) fn refresh_tray_status(app: &AppHandle) {
+    if let Some(state) = app.try_state::<AppState>() {
+        let settings = state.settings.lock_or_recover().clone();
+        let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+        let next_meeting = state
+            .daemon
+            .lock_or_recover()
+            .get_next_meeting(&settings, &title_filters);
+        tray::update_tray_status(app, &settings, next_meeting.as_ref());
+        write_widget_data(app, &settings, next_meeting.as_ref());
+        publish_mqtt_state(app, &settings, next_meeting.as_ref());
+    }
+}
+
+/// Publish meeting and mute state right away instead of on the next tray tick
+fn refresh_mqtt_state(app: &AppHandle) {
+    if let Some(state) = app.try_state::<AppState>() {
+        let settings = state.settings.lock_or_recover().clone();
+        let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+        let next_meeting = state
+            .daemon
+            .lock_or_recover()
+            .get_next_meeting(&settings, &title_filters);
+        publish_mqtt_state(app, &settings, next_meeting.as_ref());
+    }
+}
+
+/// Hand the current state to the MQTT publisher when `mqttEnabled` is on
+fn publish_mqtt_state(app: &AppHandle, settings: &Settings, next_meeting: Option<&Meeting>) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let publisher = state.mqtt.lock_or_recover();
+    if let Some(publisher) = publisher.as_ref() {
+        publisher.publish(mqtt_snapshot(&state, settings, next_meeting));
+    }
+}
+
+fn mqtt_snapshot(
+    state: &AppState,
+    settings: &Settings,
+    next_meeting: Option<&Meeting>,
+) -> mqtt::MqttSnapshot {
+    let joined = state.daemon.lock_or_recover().joined_presences();
+    mqtt::MqttSnapshot::build(
+        next_meeting,
+        &joined,
+        settings.tauri_or_default().privacy_mode,
+        &i18n::Language::from_setting(&settings.language),
+        chrono::Utc::now(),
+    )
+}
+
+/// Start, stop or reconnect the MQTT publisher to match the settings
+fn apply_mqtt(app: &AppHandle) {
+    let state = app.state::<AppState>();
+    let settings = state.settings.lock_or_recover().clone();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+    let tauri_settings = settings.tauri_or_default();
+    let host = tauri_settings
+        .mqtt_host
+        .as_deref()
+        .map(str::trim)
+        .filter(|host| !host.is_empty());
+    let config = host
+        .filter(|_| tauri_settings.mqtt_enabled)
+        .map(|host| mqtt::MqttConfig {
+            host: host.to_string(),
+            port: tauri_settings.mqtt_port,
+            tls: tauri_settings.mqtt_tls,
+            username: tauri_settings.mqtt_username.clone(),
+            password: secrets::mqtt_password(&settings, &Keychain),
+            topic_prefix: tauri_settings.mqtt_topic_prefix.clone(),
+        });
+
+    let mut publisher = state.mqtt.lock_or_recover();
+    if publisher.as_ref().map(mqtt::MqttHandle::config) == config.as_ref() {
+        return;
+    }
+    if publisher.take().is_some() {
+        log_app_event(app, LogLevel::Info, "mqtt", "mqtt.stopped", None, None);
+    }
+    let Some(config) = config else {
+        if tauri_settings.mqtt_enabled {
+            log_app_event(
+                app,
+                LogLevel::Warn,
+                "mqtt",
+                "mqtt.no_host",
+                Some("Enabled without a broker host; not connecting".to_string()),
+                None,
+            );
+        }
+        return;
+    };
+
+    let next_meeting = state
+        .daemon
+        .lock_or_recover()
+        .get_next_meeting(&settings, &title_filters);
+    let snapshot = mqtt_snapshot(&state, &settings, next_meeting.as_ref());
+    log_app_event(
+        app,
+        LogLevel::Info,
+        "mqtt",
+        "mqtt.started",
+        None,
+        Some(json!({ "host": config.host, "port": config.port, "tls": config.tls })),
+    );
+    let handle = app.clone();
+    let report: mqtt::StatusReporter = Arc::new(move |status| match status {
+        mqtt::MqttStatus::Connected => log_app_event(
+            &handle,
+            LogLevel::Info,
+            "mqtt",
+            "mqtt.connected",
+            None,
+            None,
+        ),
+        mqtt::MqttStatus::Disconnected(e) => log_app_event(
+            &handle,
+            LogLevel::Warn,
+            "mqtt",
+            "mqtt.disconnected",
+            Some(e),
+            None,
+        ),
+    });
+    *publisher = Some(mqtt::start(config, snapshot, report));
+}
+
+/// Rewrite the widget data file when `widgetDataEnabled` is on
+fn write_widget_data(app: &AppHandle, settings: &Settings, next_meeting: Option<&Meeting>) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let tauri_settings = settings.tauri_or_default();
+    if !tauri_settings.widget_data_enabled {
+        return;
+    }
+    let now = chrono::Utc::now();
+    let data = {
+        let daemon = state.daemon.lock_or_recover();
+        let paused = daemon.is_join_rate_capped()
+            || daemon.paused_until(now).is_some()
+            || daemon.out_of_office_until(now).is_some();
+        widget::WidgetData::build(
+            next_meeting,
+            &daemon.upcoming_meetings_at(now),
+            paused,
+            tauri_settings.privacy_mode,
+            &i18n::Language::from_setting(&settings.language),
+            now,
+        )
+    };
+    let result = widget::resolve_path(tauri_settings.widget_data_path.as_deref())
+        .ok_or_else(|| "Data directory unavailable".to_string())
+        .and_then(|path| widget::write(&path, &data));
+
+    let mut last_error = state.widget_write_error.lock_or_recover();
+    match result {
+        Ok(()) => {
+            if last_error.take().is_some() {
+                log_app_event(
+                    app,
+                    LogLevel::Info,
+                    "widget",
+                    "widget.write_recovered",
+                    None,
+                    None,
+                );
+            }
+        }
+        Err(e) => {
+            if last_error.as_deref() != Some(e.as_str()) {
+                log_app_event(
+                    app,
+                    LogLevel::Warn,
+                    "widget",
+                    "widget.write_failed",
+                    Some(e.clone()),
+                    None,
+                );
+            }
+            *last_error = Some(e);
+        }
+    }
 }
 
-fn refresh_tray_status(app: &AppHandle) {
-    if let Some(state) = app.try_state::<AppState>() {
-        let settings = state.settings.lock().unwrap().clone();
-        let next_meeting = state.daemon.lock().unwrap().get_next_meeting(&settings);
-        tray::update_tray_status(app, next_meeting.as_ref());
+/// Remove the widget data file `before` wrote when it is turned off or moved
+fn remove_stale_widget_data(app: &AppHandle, before: &Settings, after: &Settings) {
+    let path_of = |settings: &Settings| {
+        let tauri = settings.tauri.clone().unwrap_or_default();
+        tauri
+            .widget_data_enabled
+            .then(|| widget::resolve_path(tauri.widget_data_path.as_deref()))
+            .flatten()
+    };
+    let Some(previous) = path_of(before) else {
+        return;
+    };
+    if path_of(after).as_ref() == Some(&previous) {
+        return;
+    }
+    if let Err(e) = widget::remove(&previous) {
+        log_app_event(
+            app,
+            LogLevel::Warn,
+            "widget",
+            "widget.remove_failed",
+            Some(e),
+            None,
+        );
     }
 }
 
@@ -861,7 +5404,9 @@ fn refresh_tray_status(app: &AppHandle) {
 #[tauri::command]
 fn navigate_home(app: AppHandle, focus: Option<bool>) -> Result<(), String> {
     if focus.unwrap_or(true) {
-        navigate_to_meet_home(&app)
+        navigate_to_meet_home(&app)?;
+        record_window_shown(&app, "main", ShowCause::NavigateHome, None);
+        Ok(())
     } else {
         navigate_to_meet_home_silent(&app)
     }
@@ -869,11 +5414,11 @@ fn navigate_home(app: AppHandle, focus: Option<bool>) -> Result<(), String> {
 
 /// Open the settings window
 #[tauri::command]
-fn open_settings_window(app: AppHandle) -> Result<(), String> {
+fn open_settings_window(app: AppHandle) -> Result<(), SettingsWindowError> {
     ensure_settings_window(&app)
 }
 
-pub(crate) fn ensure_settings_window(app: &AppHandle) -> Result<(), String> {
+pub(crate) fn ensure_settings_window(app: &AppHandle) -> Result<(), SettingsWindowError> {
     // Check if settings window already exists
     if let Some(window) = app.get_webview_window("settings") {
         let _ = window.show();
@@ -888,7 +5433,7 @@ pub(crate) fn ensure_settings_window(app: &AppHandle) -> Result<(), String> {
         .inner_size(420.0, 640.0)
         .resizable(false)
         .build()
-        .map_err(|e| e.to_string())?;
+        .map_err(|e| settings_window_failed(app, e.to_string()))?;
 
     let _ = window.show();
     let _ = window.set_focus();
@@ -896,6 +5441,47 @@ pub(crate) fn ensure_settings_window(app: &AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Classify a settings window failure, log it and fall back to revealing the
+/// settings file so it can be edited by hand
+fn settings_window_failed(app: &AppHandle, message: String) -> SettingsWindowError {
+    let mut error = SettingsWindowError::from_message(message);
+    eprintln!("[MeetCat] Failed to open settings window: {}", error);
+
+    let reveal_result = Settings::get_path()
+        .map_err(|e| e.to_string())
+        .and_then(|path| {
+            app.opener()
+                .reveal_item_in_dir(&path)
+                .map(|_| path)
+                .map_err(|e| e.to_string())
+        });
+    let reveal_error = match reveal_result {
+        Ok(path) => {
+            error.settings_path = Some(path);
+            None
+        }
+        Err(e) => Some(e),
+    };
+
+    log_app_event(
+        app,
+        LogLevel::Error,
+        "settings",
+        "window.open_failed",
+        Some(error.message.clone()),
+        Some(json!({
+            "kind": error.kind,
+            "os": std::env::consts::OS,
+            "hasDisplay": std::env::var_os("DISPLAY").is_some(),
+            "hasWaylandDisplay": std::env::var_os("WAYLAND_DISPLAY").is_some(),
+            "settingsRevealed": error.settings_path.is_some(),
+            "revealError": reveal_error,
+        })),
+    );
+    settings_window::notify_manual_editing(&error);
+    error
+}
+
 /// Force a window above any sibling window by briefly toggling always-on-top.
 ///
 /// Used by the deep-link Settings action so the Settings window surfaces above
@@ -915,25 +5501,6 @@ fn promote_window_to_front(window: &WebviewWindow) {
     });
 }
 
-// =============================================================================
-// Command payload types
-// =============================================================================
-
-#[derive(serde::Serialize, Clone)]
-#[serde(rename_all = "camelCase")]
-struct NavigateAndJoinCommand {
-    url: String,
-    settings: Settings,
-}
-
-#[derive(serde::Serialize, Clone)]
-#[serde(rename_all = "camelCase")]
-struct CheckMeetingsPayload {
-    check_id: u64,
-    interval_seconds: u32,
-    emitted_at_ms: u64,
-}
-
 fn log_app_event(
     app: &AppHandle,
     level: LogLevel,
@@ -943,9 +5510,7 @@ fn log_app_event(
     context: Option<serde_json::Value>,
 ) {
     if let Some(state) = app.try_state::<AppState>() {
-        if let Ok(mut logger) = state.logger.lock() {
-            logger.log_internal(level, module, event, message, context);
-        }
+        state.logger.log(level, module, event, message, context);
     }
 }
 
@@ -957,100 +5522,420 @@ fn build_settings_change_summary(
     let mut changes = serde_json::Map::new();
 
     add_change(
-        "checkIntervalSeconds",
-        before.check_interval_seconds,
-        after.check_interval_seconds,
+        "language",
+        before.language.clone(),
+        after.language.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "checkIntervalSeconds",
+        before.check_interval_seconds,
+        after.check_interval_seconds,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "joinBeforeMinutes",
+        before.join_before_minutes,
+        after.join_before_minutes,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "maxMinutesAfterStart",
+        before.max_minutes_after_start,
+        after.max_minutes_after_start,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "autoClickJoin",
+        before.auto_click_join,
+        after.auto_click_join,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "joinCountdownSeconds",
+        before.join_countdown_seconds,
+        after.join_countdown_seconds,
+        &mut changed_keys,
+        &mut changes,
+    );
+    if before.title_exclude_filters != after.title_exclude_filters {
+        changed_keys.push("titleExcludeFilters".to_string());
+        changes.insert(
+            "titleExcludeFilters".to_string(),
+            json!({
+                "fromCount": before.title_exclude_filters.len(),
+                "toCount": after.title_exclude_filters.len(),
+            }),
+        );
+    }
+    add_change(
+        "defaultMicState",
+        before.default_mic_state.clone(),
+        after.default_mic_state.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "defaultCameraState",
+        before.default_camera_state.clone(),
+        after.default_camera_state.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "showCountdownOverlay",
+        before.show_countdown_overlay,
+        after.show_countdown_overlay,
+        &mut changed_keys,
+        &mut changes,
+    );
+
+    let before_tauri = before.tauri.clone().unwrap_or_default();
+    let after_tauri = after.tauri.clone().unwrap_or_default();
+
+    add_change(
+        "tauri.startAtLogin",
+        before_tauri.start_at_login,
+        after_tauri.start_at_login,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.showTrayIcon",
+        before_tauri.show_tray_icon,
+        after_tauri.show_tray_icon,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.trayDisplayMode",
+        before_tauri.tray_display_mode,
+        after_tauri.tray_display_mode,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.trayShowMeetingTitle",
+        before_tauri.tray_show_meeting_title,
+        after_tauri.tray_show_meeting_title,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.trayIconTemplate",
+        before_tauri.tray_icon_template,
+        after_tauri.tray_icon_template,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.privacyMode",
+        before_tauri.privacy_mode,
+        after_tauri.privacy_mode,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.powerMode",
+        before_tauri.power_mode,
+        after_tauri.power_mode,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.allowConcurrentMeetings",
+        before_tauri.allow_concurrent_meetings,
+        after_tauri.allow_concurrent_meetings,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.deferWhileMicBusy",
+        before_tauri.defer_while_mic_busy,
+        after_tauri.defer_while_mic_busy,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.skipJoinWhenIdleMinutes",
+        before_tauri.skip_join_when_idle_minutes,
+        after_tauri.skip_join_when_idle_minutes,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.holdJoinsWhenOffline",
+        before_tauri.hold_joins_when_offline,
+        after_tauri.hold_joins_when_offline,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.skipJoinWhenStale",
+        before_tauri.skip_join_when_stale,
+        after_tauri.skip_join_when_stale,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.staleDataMinutes",
+        before_tauri.stale_data_minutes,
+        after_tauri.stale_data_minutes,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.startupUrl",
+        before_tauri.startup_url.clone(),
+        after_tauri.startup_url.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.meetingRules",
+        before_tauri.meeting_rules.clone(),
+        after_tauri.meeting_rules.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.excludeTitleFilters",
+        before_tauri.exclude_title_filters.clone(),
+        after_tauri.exclude_title_filters.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.includeTitleFilters",
+        before_tauri.include_title_filters.clone(),
+        after_tauri.include_title_filters.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.rememberRecentCodes",
+        before_tauri.remember_recent_codes,
+        after_tauri.remember_recent_codes,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.showNotifications",
+        before_tauri.show_notifications,
+        after_tauri.show_notifications,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.busyBlockRules",
+        before_tauri.busy_block_rules.clone(),
+        after_tauri.busy_block_rules.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.soakTest",
+        before_tauri.soak_test.clone(),
+        after_tauri.soak_test.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.returnHomeAfterMeeting",
+        before_tauri.return_home_after_meeting,
+        after_tauri.return_home_after_meeting,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.profiles",
+        before_tauri.profiles.clone(),
+        after_tauri.profiles.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.dndWindows",
+        before_tauri.dnd_windows.clone(),
+        after_tauri.dnd_windows.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.autoJoinHours",
+        before_tauri.auto_join_hours.clone(),
+        after_tauri.auto_join_hours.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.staleRefreshMinutes",
+        before_tauri.stale_refresh_minutes,
+        after_tauri.stale_refresh_minutes,
+        &mut changed_keys,
+        &mut changes,
+    );
+    if before_tauri.ics_subscriptions != after_tauri.ics_subscriptions {
+        // Subscription URLs often embed private tokens, so only counts are logged
+        changed_keys.push("tauri.icsSubscriptions".to_string());
+        changes.insert(
+            "tauri.icsSubscriptions".to_string(),
+            json!({
+                "fromCount": before_tauri.ics_subscriptions.len(),
+                "toCount": after_tauri.ics_subscriptions.len(),
+            }),
+        );
+    }
+    add_change(
+        "tauri.joinInBackground",
+        before_tauri.join_in_background,
+        after_tauri.join_in_background,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.conflictPolicy",
+        before_tauri.conflict_policy,
+        after_tauri.conflict_policy,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.showWindowCause",
+        before_tauri.show_window_cause,
+        after_tauri.show_window_cause,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.globalShortcuts",
+        before_tauri.global_shortcuts.clone(),
+        after_tauri.global_shortcuts.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.maintenanceWindow",
+        before_tauri.maintenance_window.clone(),
+        after_tauri.maintenance_window.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.lastMinuteMeetingMinutes",
+        before_tauri.last_minute_meeting_minutes,
+        after_tauri.last_minute_meeting_minutes,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.demoMode",
+        before_tauri.demo_mode,
+        after_tauri.demo_mode,
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.reminderMinutesBefore",
+        before_tauri.reminder_minutes_before,
+        after_tauri.reminder_minutes_before,
         &mut changed_keys,
         &mut changes,
     );
     add_change(
-        "joinBeforeMinutes",
-        before.join_before_minutes,
-        after.join_before_minutes,
+        "tauri.showMeetingSummary",
+        before_tauri.show_meeting_summary,
+        after_tauri.show_meeting_summary,
         &mut changed_keys,
         &mut changes,
     );
     add_change(
-        "maxMinutesAfterStart",
-        before.max_minutes_after_start,
-        after.max_minutes_after_start,
+        "tauri.widgetDataEnabled",
+        before_tauri.widget_data_enabled,
+        after_tauri.widget_data_enabled,
         &mut changed_keys,
         &mut changes,
     );
     add_change(
-        "autoClickJoin",
-        before.auto_click_join,
-        after.auto_click_join,
+        "tauri.widgetDataPath",
+        before_tauri.widget_data_path.clone(),
+        after_tauri.widget_data_path.clone(),
         &mut changed_keys,
         &mut changes,
     );
     add_change(
-        "joinCountdownSeconds",
-        before.join_countdown_seconds,
-        after.join_countdown_seconds,
+        "tauri.apiServerEnabled",
+        before_tauri.api_server_enabled,
+        after_tauri.api_server_enabled,
         &mut changed_keys,
         &mut changes,
     );
-    if before.title_exclude_filters != after.title_exclude_filters {
-        changed_keys.push("titleExcludeFilters".to_string());
-        changes.insert(
-            "titleExcludeFilters".to_string(),
-            json!({
-                "fromCount": before.title_exclude_filters.len(),
-                "toCount": after.title_exclude_filters.len(),
-            }),
-        );
-    }
     add_change(
-        "defaultMicState",
-        before.default_mic_state.clone(),
-        after.default_mic_state.clone(),
+        "tauri.apiServerPort",
+        before_tauri.api_server_port,
+        after_tauri.api_server_port,
         &mut changed_keys,
         &mut changes,
     );
     add_change(
-        "defaultCameraState",
-        before.default_camera_state.clone(),
-        after.default_camera_state.clone(),
+        "tauri.mqttEnabled",
+        before_tauri.mqtt_enabled,
+        after_tauri.mqtt_enabled,
         &mut changed_keys,
         &mut changes,
     );
     add_change(
-        "showCountdownOverlay",
-        before.show_countdown_overlay,
-        after.show_countdown_overlay,
+        "tauri.mqttHost",
+        before_tauri.mqtt_host.clone(),
+        after_tauri.mqtt_host.clone(),
         &mut changed_keys,
         &mut changes,
     );
-
-    let before_tauri = before.tauri.clone().unwrap_or_default();
-    let after_tauri = after.tauri.clone().unwrap_or_default();
-
     add_change(
-        "tauri.startAtLogin",
-        before_tauri.start_at_login,
-        after_tauri.start_at_login,
+        "tauri.mqttPort",
+        before_tauri.mqtt_port,
+        after_tauri.mqtt_port,
         &mut changed_keys,
         &mut changes,
     );
     add_change(
-        "tauri.showTrayIcon",
-        before_tauri.show_tray_icon,
-        after_tauri.show_tray_icon,
+        "tauri.mqttTls",
+        before_tauri.mqtt_tls,
+        after_tauri.mqtt_tls,
         &mut changed_keys,
         &mut changes,
     );
     add_change(
-        "tauri.trayDisplayMode",
-        before_tauri.tray_display_mode,
-        after_tauri.tray_display_mode,
+        "tauri.mqttUsername",
+        before_tauri.mqtt_username.clone(),
+        after_tauri.mqtt_username.clone(),
         &mut changed_keys,
         &mut changes,
     );
     add_change(
-        "tauri.trayShowMeetingTitle",
-        before_tauri.tray_show_meeting_title,
-        after_tauri.tray_show_meeting_title,
+        "tauri.mqttTopicPrefix",
+        before_tauri.mqtt_topic_prefix.clone(),
+        after_tauri.mqtt_topic_prefix.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    // URLs are left out of the summary since they often embed a token
+    add_change(
+        "tauri.webhookEvents",
+        before_tauri.webhook_events.clone(),
+        after_tauri.webhook_events.clone(),
+        &mut changed_keys,
+        &mut changes,
+    );
+    add_change(
+        "tauri.slackStatusEnabled",
+        before_tauri.slack_status_enabled,
+        after_tauri.slack_status_enabled,
         &mut changed_keys,
         &mut changes,
     );
@@ -1069,124 +5954,861 @@ fn build_settings_change_summary(
         &mut changes,
     );
 
-    (changed_keys, serde_json::Value::Object(changes))
+    (changed_keys, serde_json::Value::Object(changes))
+}
+
+fn add_change<T: Serialize + PartialEq>(
+    key: &str,
+    before: T,
+    after: T,
+    changed_keys: &mut Vec<String>,
+    changes: &mut serde_json::Map<String, serde_json::Value>,
+) {
+    if before == after {
+        return;
+    }
+    changed_keys.push(key.to_string());
+    changes.insert(key.to_string(), json!({ "from": before, "to": after }));
+}
+
+// =============================================================================
+// Application setup
+// =============================================================================
+
+/// Get the injectable script content
+fn get_inject_script() -> &'static str {
+    include_str!("../../../core/dist/meetcat-inject.global.js")
+}
+
+/// Match the profile windows to the configured profiles: open missing ones
+/// in the background, close removed ones and forget their meetings
+fn sync_profile_windows(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let tauri_settings = state
+        .settings
+        .lock_or_recover()
+        .tauri
+        .clone()
+        .unwrap_or_default();
+
+    profiles::close_removed_windows(app, &tauri_settings.profiles);
+    for profile in &tauri_settings.profiles {
+        if let Err(e) = profiles::ensure_window(app, profile) {
+            log_app_event(
+                app,
+                LogLevel::Error,
+                "profiles",
+                "profile.window_failed",
+                Some(e.to_string()),
+                Some(json!({ "profile": profile.id })),
+            );
+        }
+    }
+    let ids: Vec<String> = tauri_settings
+        .profiles
+        .iter()
+        .map(|p| p.id.clone())
+        .collect();
+    state
+        .daemon
+        .lock_or_recover()
+        .retain_profiles(&ids, &tauri_settings.meeting_source_priority);
+    tray::sync_profile_menu(app, &tauri_settings.profiles);
+}
+
+/// Set up script injection for the main window
+fn setup_script_injection(app: &AppHandle) {
+    let app_handle = app.clone();
+
+    // Listen for page load events to inject script
+    app.listen("tauri://webview-created", move |event| {
+        let app_handle = app_handle.clone();
+        let payload = event.payload();
+        // Only inject into main window (Google Meet)
+        if payload.contains("\"main\"") || payload.contains("main") {
+            if let Some(window) = app_handle.get_webview_window("main") {
+                let script = get_inject_script();
+                // Inject after a short delay to ensure page is ready
+                let window_clone = window.clone();
+                tauri::async_runtime::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(1000)).await;
+                    if let Err(e) = window_clone.eval(script) {
+                        eprintln!("Failed to inject script: {}", e);
+                        log_app_event(
+                            &app_handle,
+                            LogLevel::Error,
+                            "inject",
+                            "script.inject_failed",
+                            Some(e.to_string()),
+                            Some(json!({ "window": "main" })),
+                        );
+                    } else {
+                        log_app_event(
+                            &app_handle,
+                            LogLevel::Info,
+                            "inject",
+                            "script.injected",
+                            None,
+                            Some(json!({ "window": "main" })),
+                        );
+                    }
+                });
+            }
+        }
+    });
+}
+
+/// Set up the shared background timer that drives URL polling, meeting
+/// checks, tray refreshes, update checks, log digests and ICS refreshes
+fn setup_background_tasks(app: &AppHandle) {
+    let app_handle = app.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let Some(logger) = app_handle
+            .try_state::<AppState>()
+            .map(|state| state.logger.clone())
+        else {
+            return;
+        };
+        let mut check_id: u64 = 0;
+        let mut last_offset_check: Option<chrono::NaiveDate> = None;
+        let mut last_url = String::new();
+        let mut update_source = "startup";
+        loop {
+            let Some(state) = app_handle.try_state::<AppState>() else {
+                return;
+            };
+            refresh_maintenance_state(&app_handle);
+            let due = state.background.lock_or_recover().take_due(now_ms());
+            for task in &due.deferred {
+                log_app_event(
+                    &app_handle,
+                    LogLevel::Info,
+                    "background",
+                    "maintenance.deferred",
+                    None,
+                    Some(json!({
+                        "task": task,
+                        "state": state.background.lock_or_recover().maintenance(),
+                    })),
+                );
+            }
+            for task in due.run {
+                if task.is_maintenance() {
+                    log_app_event(
+                        &app_handle,
+                        LogLevel::Info,
+                        "background",
+                        "maintenance.ran",
+                        None,
+                        Some(json!({
+                            "task": task,
+                            "state": state.background.lock_or_recover().maintenance(),
+                            "waitedMs": due.waited_ms.get(&task).copied().unwrap_or_default(),
+                        })),
+                    );
+                }
+                match task {
+                    BackgroundTask::UrlPoll => poll_main_window_url(&app_handle, &mut last_url),
+                    BackgroundTask::MeetingCheck => {
+                        refresh_power_mode(&app_handle);
+                        run_meeting_check(
+                            &app_handle,
+                            &logger,
+                            &mut check_id,
+                            &mut last_offset_check,
+                        )
+                        .await;
+                    }
+                    BackgroundTask::TrayRefresh => refresh_tray_status(&app_handle),
+                    BackgroundTask::UpdateCheck => {
+                        run_update_check(&app_handle, update_source);
+                        update_source = "polling";
+                    }
+                    BackgroundTask::LogDigest => run_log_digest(&app_handle),
+                    BackgroundTask::HistoryPrune => prune_meeting_history(),
+                    BackgroundTask::ConnectivityCheck => {
+                        // A stalled probe must not hold up the URL poller
+                        let app_handle = app_handle.clone();
+                        tauri::async_runtime::spawn(async move {
+                            check_connectivity(&app_handle).await;
+                        });
+                    }
+                    BackgroundTask::IcsRefresh => {
+                        // Remote calendars can be slow; don't hold up the URL poller.
+                        // The jitter keeps clients from polling servers in lockstep.
+                        let delay_ms = source_quota::jitter_ms(
+                            &MeetingSource::Ics,
+                            source_quota::random_jitter(),
+                        );
+                        let app_handle = app_handle.clone();
+                        tauri::async_runtime::spawn(async move {
+                            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+                            refresh_ics_subscriptions(app_handle).await;
+                        });
+                    }
+                }
+            }
+
+            let next_wakeup_ms = state.background.lock_or_recover().next_wakeup_ms();
+            let sleep_ms = next_wakeup_ms
+                .map(|at| at.saturating_sub(now_ms()))
+                .unwrap_or(1000);
+            // Settings changes wake the timer early to apply new intervals
+            tokio::select! {
+                _ = tokio::time::sleep(Duration::from_millis(sleep_ms)) => {}
+                _ = state.background_wake.notified() => {}
+            }
+        }
+    });
+}
+
+/// Resolve the power mode from settings and the system, and apply it
+fn refresh_power_mode(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let (power_mode, interval_seconds) = {
+        let settings = state.settings.lock_or_recover();
+        (
+            settings.tauri.as_ref().map(|t| t.power_mode).unwrap_or_default(),
+            settings.check_interval_seconds.max(1),
+        )
+    };
+    let mode = match power_mode {
+        PowerMode::Normal => ActivePowerMode::Normal,
+        PowerMode::LowPower => ActivePowerMode::LowPower,
+        PowerMode::Auto if system_low_power_enabled() => ActivePowerMode::LowPower,
+        PowerMode::Auto => ActivePowerMode::Normal,
+    };
+    let changed = {
+        let mut background = state.background.lock_or_recover();
+        background.set_check_interval(interval_seconds, now_ms());
+        background.set_mode(mode, now_ms())
+    };
+    if changed {
+        println!("[MeetCat] Power mode: {:?}", mode);
+        log_app_event(
+            app,
+            LogLevel::Info,
+            "power",
+            "mode.changed",
+            None,
+            Some(json!({
+                "mode": mode,
+                "setting": power_mode,
+                "metrics": state.background.lock_or_recover().metrics(),
+            })),
+        );
+    }
+}
+
+/// Place the local clock relative to the maintenance window and auto-join
+/// hours, and apply it to the background timer
+fn refresh_maintenance_state(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let maintenance = {
+        let settings = state.settings.lock_or_recover();
+        let tauri = settings.tauri.as_ref();
+        MaintenanceState::resolve(
+            tauri.and_then(|t| t.maintenance_window.as_ref()),
+            tauri.and_then(|t| t.auto_join_hours.as_ref()),
+            chrono::Local::now().naive_local(),
+        )
+    };
+    if state
+        .background
+        .lock_or_recover()
+        .set_maintenance(maintenance)
+    {
+        log_app_event(
+            app,
+            LogLevel::Debug,
+            "background",
+            "maintenance.state",
+            None,
+            Some(json!({ "state": maintenance })),
+        );
+    }
+}
+
+/// Whether the system battery saver is on (macOS Low Power Mode)
+#[cfg(target_os = "macos")]
+fn system_low_power_enabled() -> bool {
+    objc2_foundation::NSProcessInfo::processInfo().isLowPowerModeEnabled()
+}
+
+#[cfg(not(target_os = "macos"))]
+fn system_low_power_enabled() -> bool {
+    false
+}
+
+/// Ask the webview for meetings and evaluate the error budget
+async fn run_meeting_check(
+    app: &AppHandle,
+    logger: &LoggerHandle,
+    check_id: &mut u64,
+    last_offset_check: &mut Option<chrono::NaiveDate>,
+) {
+    let today = chrono::Local::now().date_naive();
+    if *last_offset_check != Some(today) {
+        *last_offset_check = Some(today);
+        if let Some((from, to)) = daemon::offset_change_on(&chrono::Local, today) {
+            logger.log(
+                LogLevel::Info,
+                "time",
+                "time.dst_transition",
+                None,
+                Some(json!({
+                    "date": today.to_string(),
+                    "fromOffsetSeconds": from,
+                    "toOffsetSeconds": to,
+                })),
+            );
+        }
+    }
+
+    // Effective interval, widened in low-power mode
+    let interval_seconds = app
+        .try_state::<AppState>()
+        .and_then(|state| {
+            state
+                .background
+                .lock_or_recover()
+                .interval_ms(BackgroundTask::MeetingCheck)
+        })
+        .map(|ms| (ms / 1000) as u32)
+        .unwrap_or(TAURI_DEFAULT_CHECK_INTERVAL_SECONDS);
+
+    refresh_stale_hidden_page(app);
+    recover_stale_meeting_data(app);
+
+    *check_id += 1;
+    let payload = CheckMeetingsPayload {
+        check_id: *check_id,
+        interval_seconds,
+        emitted_at_ms: now_ms(),
+    };
+
+    // Emit check-meetings event to WebView
+    if let Err(e) = app.emit(event::CHECK_MEETINGS, payload.clone()) {
+        eprintln!("Failed to emit check-meetings: {}", e);
+        logger.log(
+            LogLevel::Error,
+            "daemon",
+            "check.emit_failed",
+            Some(e.to_string()),
+            Some(json!({
+                "checkId": payload.check_id,
+                "intervalSeconds": payload.interval_seconds,
+            })),
+        );
+    } else {
+        // Only the homepage answers with a meeting list
+        if is_main_window_on_meet_home(app) {
+            if let Some(state) = app.try_state::<AppState>() {
+                state.health.lock_or_recover().on_check_emitted(now_ms());
+            }
+        }
+        logger.log(
+            LogLevel::Debug,
+            "daemon",
+            "check.emitted",
+            None,
+            Some(json!({
+                "checkId": payload.check_id,
+                "intervalSeconds": payload.interval_seconds,
+                "emittedAtMs": payload.emitted_at_ms,
+            })),
+        );
+        ping_injected_script(app);
+    }
+
+    check_join_verification(app);
+    check_error_budget(app).await;
+}
+
+/// Re-read the subscribed ICS calendars and report their Meet events as the
+/// `ics` meeting source. A calendar that can't be read contributes its last
+/// good copy, so a flaky server doesn't drop meetings from the schedule.
+async fn refresh_ics_subscriptions(app: AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    if demo_mode_active(&app) {
+        return;
+    }
+    let settings = state.settings.lock_or_recover().clone();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+    let tauri_settings = settings.tauri.clone().unwrap_or_default();
+    let subscriptions = tauri_settings.ics_subscriptions;
+
+    let mut calendars = Vec::new();
+    let mut failed = 0;
+    let mut deferred = 0;
+    for (index, subscription) in subscriptions.iter().enumerate() {
+        let fetched = match ics::IcsLocation::parse(subscription) {
+            Ok(location) => ics::fetch(&location, &settings, &state.request_scheduler).await,
+            Err(message) => Err(ics::FetchError::Failed {
+                message,
+                backoff_until_ms: None,
+            }),
+        };
+        let mut cache = state.ics_calendars.lock_or_recover();
+        match fetched {
+            Ok(text) => {
+                cache.insert(subscription.clone(), text.clone());
+                calendars.push(text);
+                continue;
+            }
+            Err(ics::FetchError::Deferred(deferral)) => {
+                deferred += 1;
+                log_app_event(
+                    &app,
+                    LogLevel::Debug,
+                    "ics",
+                    "ics.fetch_deferred",
+                    None,
+                    Some(json!({
+                        "subscription": index,
+                        "deferral": deferral,
+                        "usingCachedCopy": cache.contains_key(subscription),
+                    })),
+                );
+            }
+            Err(ics::FetchError::Failed {
+                message,
+                backoff_until_ms,
+            }) => {
+                failed += 1;
+                log_app_event(
+                    &app,
+                    LogLevel::Warn,
+                    "ics",
+                    "ics.fetch_failed",
+                    Some(message),
+                    Some(json!({
+                        "subscription": index,
+                        "usingCachedCopy": cache.contains_key(subscription),
+                        "backoffUntilMs": backoff_until_ms,
+                    })),
+                );
+            }
+        }
+        calendars.extend(cache.get(subscription).cloned());
+    }
+    state
+        .ics_calendars
+        .lock_or_recover()
+        .retain(|subscription, _| subscriptions.contains(subscription));
+
+    // Subscriptions changed while fetching; the refresh that change scheduled wins
+    let current = state
+        .settings
+        .lock_or_recover()
+        .tauri
+        .as_ref()
+        .map(|t| t.ics_subscriptions.clone())
+        .unwrap_or_default();
+    if current != subscriptions {
+        return;
+    }
+
+    let now = chrono::Utc::now();
+    let mut meetings: Vec<Meeting> = calendars
+        .iter()
+        .flat_map(|calendar| ics::parse_meetings(calendar, now))
+        .collect();
+    meetings.sort_by(|a, b| {
+        a.begin_time
+            .cmp(&b.begin_time)
+            .then_with(|| a.call_id.cmp(&b.call_id))
+    });
+    // The same meeting can appear in several calendars; keep the earliest
+    let mut seen = std::collections::HashSet::new();
+    meetings.retain(|meeting| seen.insert(meeting.call_id.clone()));
+    let meeting_count = meetings.len();
+
+    let rejected_urls = state.daemon.lock_or_recover().update_meetings_from_source(
+        MeetingSource::Ics,
+        meetings,
+        now.timestamp_millis(),
+        &tauri_settings.meeting_source_priority,
+    );
+    emit_lifecycle_events(&app);
+    announce_last_minute_meetings(&app, &settings);
+    schedule_join_trigger(&state);
+    let next_meeting = state
+        .daemon
+        .lock_or_recover()
+        .get_next_meeting(&settings, &title_filters);
+    tray::update_tray_status(&app, &settings, next_meeting.as_ref());
+
+    log_app_event(
+        &app,
+        LogLevel::Debug,
+        "ics",
+        "ics.refreshed",
+        None,
+        Some(json!({
+            "subscriptions": subscriptions.len(),
+            "failed": failed,
+            "deferred": deferred,
+            "meetings": meeting_count,
+            "rejected": rejected_urls.len(),
+            "requests": state
+                .request_scheduler
+                .lock_or_recover()
+                .metrics(now_ms())
+                .get(&MeetingSource::Ics),
+        })),
+    );
 }
 
-fn add_change<T: Serialize + PartialEq>(
-    key: &str,
-    before: T,
-    after: T,
-    changed_keys: &mut Vec<String>,
-    changes: &mut serde_json::Map<String, serde_json::Value>,
-) {
-    if before == after {
+/// Reload Meet home in the main window once it has been hidden for
+/// `staleRefreshMinutes`, since Google stops refreshing a hidden page's
+/// meeting list. The reloaded page parses and reports meetings as soon as
+/// the script is injected.
+fn refresh_stale_hidden_page(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    let stale_refresh_minutes = state
+        .settings
+        .lock_or_recover()
+        .tauri
+        .as_ref()
+        .map(|t| t.stale_refresh_minutes)
+        .unwrap_or(0);
+    let visible = window.is_visible().unwrap_or(true);
+    let hidden_for_ms = state.main_hidden.lock_or_recover().observe(
+        visible,
+        now_ms(),
+        u64::from(stale_refresh_minutes) * 60_000,
+    );
+    let Some(hidden_for_ms) = hidden_for_ms else {
+        return;
+    };
+    // Leave meeting pages alone; the list is only shown on the homepage
+    if !is_main_window_on_meet_home(app) {
         return;
     }
-    changed_keys.push(key.to_string());
-    changes.insert(key.to_string(), json!({ "from": before, "to": after }));
+
+    let result = navigate_to_meet_home_silent(app);
+    log_app_event(
+        app,
+        if result.is_ok() {
+            LogLevel::Info
+        } else {
+            LogLevel::Warn
+        },
+        "webview",
+        "webview.stale_refresh",
+        result.err(),
+        Some(json!({
+            "hiddenForMs": hidden_for_ms,
+            "staleRefreshMinutes": stale_refresh_minutes,
+        })),
+    );
 }
 
-// =============================================================================
-// Application setup
-// =============================================================================
+/// How old the main window's meeting list may get, 0 when the check is off
+fn stale_data_ms(settings: &Settings) -> i64 {
+    i64::from(settings.tauri_or_default().stale_data_minutes) * 60_000
+}
 
-/// Get the injectable script content
-fn get_inject_script() -> &'static str {
-    include_str!("../../../core/dist/meetcat-inject.global.js")
+/// Reload Meet home when the main window has stopped reporting its meeting
+/// list for `staleDataMinutes`, at most once per that period. The tray and
+/// `get_status` show the list as stale until the page reports again.
+fn recover_stale_meeting_data(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let stale_ms = stale_data_ms(&state.settings.lock_or_recover());
+    let now_ms = now_ms() as i64;
+    let (recover, data_age_ms) = {
+        let mut daemon = state.daemon.lock_or_recover();
+        (
+            daemon.take_stale_recovery(now_ms, stale_ms),
+            daemon.data_age_ms(now_ms),
+        )
+    };
+    if !recover {
+        return;
+    }
+    refresh_tray_status(app);
+    // Meeting pages don't report the list; leave the call alone
+    if is_main_window_in_meeting(app) {
+        return;
+    }
+
+    let result = navigate_to_meet_home_silent(app);
+    log_app_event(
+        app,
+        if result.is_ok() {
+            LogLevel::Warn
+        } else {
+            LogLevel::Error
+        },
+        "webview",
+        "webview.stale_data_reload",
+        result.err(),
+        Some(json!({
+            "dataAgeMs": data_age_ms,
+            "staleDataMinutes": stale_ms / 60_000,
+        })),
+    );
 }
 
-/// Set up script injection for the main window
-fn setup_script_injection(app: &AppHandle) {
-    let app_handle = app.clone();
+/// Ping the script in the main window and act if it stays silent
+fn ping_injected_script(app: &AppHandle) {
+    let on_meet = app
+        .get_webview_window("main")
+        .and_then(|window| window.url().ok())
+        .is_some_and(|url| url_norm::is_allowed_host(&url));
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    if !on_meet {
+        return;
+    }
+    let ping_id = state
+        .script_watchdog
+        .lock_or_recover()
+        .on_ping_sent(now_ms());
+    if let Err(e) = app.emit(event::SCRIPT_PING, ScriptPing { ping_id }) {
+        eprintln!("Failed to emit script-ping: {}", e);
+        return;
+    }
 
-    // Listen for page load events to inject script
-    app.listen("tauri://webview-created", move |event| {
-        let app_handle = app_handle.clone();
-        let payload = event.payload();
-        // Only inject into main window (Google Meet)
-        if payload.contains("\"main\"") || payload.contains("main") {
-            if let Some(window) = app_handle.get_webview_window("main") {
-                let script = get_inject_script();
-                // Inject after a short delay to ensure page is ready
-                let window_clone = window.clone();
-                tauri::async_runtime::spawn(async move {
-                    tokio::time::sleep(Duration::from_millis(1000)).await;
-                    if let Err(e) = window_clone.eval(script) {
-                        eprintln!("Failed to inject script: {}", e);
-                        log_app_event(
-                            &app_handle,
-                            LogLevel::Error,
-                            "inject",
-                            "script.inject_failed",
-                            Some(e.to_string()),
-                            Some(json!({ "window": "main" })),
-                        );
-                    } else {
-                        log_app_event(
-                            &app_handle,
-                            LogLevel::Info,
-                            "inject",
-                            "script.injected",
-                            None,
-                            Some(json!({ "window": "main" })),
-                        );
-                    }
-                });
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(daemon::PING_REPLY_TIMEOUT_MS)).await;
+        let Some(state) = app_handle.try_state::<AppState>() else {
+            return;
+        };
+        let (action, misses) = {
+            let mut watchdog = state.script_watchdog.lock_or_recover();
+            let action = watchdog.check_timeout(ping_id, now_ms());
+            (action, watchdog.consecutive_misses())
+        };
+        match action {
+            WatchdogAction::None => {
+                if misses > 0 {
+                    log_app_event(
+                        &app_handle,
+                        LogLevel::Debug,
+                        "inject",
+                        "inject.ping_missed",
+                        None,
+                        Some(json!({ "pingId": ping_id, "misses": misses })),
+                    );
+                }
             }
+            WatchdogAction::Reinject => reinject_main_script(&app_handle, ping_id),
+            WatchdogAction::Reload => reload_unresponsive_main_window(&app_handle, ping_id),
+            WatchdogAction::Recreate => recreate_main_window(&app_handle, ping_id),
         }
     });
 }
 
-/// Set up the background daemon that triggers meeting checks
-fn setup_daemon(app: &AppHandle) {
-    let app_handle = app.clone();
+/// Inject the scripts into the main window again, replacing a stale instance
+fn reinject_main_script(app: &AppHandle, ping_id: u64) {
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    // Without this the init guard keeps the fresh script from starting
+    let reset =
+        "try { window.MeetCatInject && window.MeetCatInject.cleanup('reinject'); } catch (e) {} \
+         delete window.__meetcatInitialized;";
+    let result = window
+        .eval(reset)
+        .and_then(|_| window.eval(INTERCEPT_SCRIPT))
+        .and_then(|_| window.eval(get_inject_script()));
+    let url = window.url().map(|url| url.to_string()).unwrap_or_default();
+    log_app_event(
+        app,
+        LogLevel::Warn,
+        "inject",
+        "inject.watchdog_reinjected",
+        result.err().map(|e| e.to_string()),
+        Some(json!({
+            "pingId": ping_id,
+            "misses": daemon::PING_MISSES_BEFORE_RECOVERY,
+            "url": url,
+        })),
+    );
+}
 
-    tauri::async_runtime::spawn(async move {
-        let mut check_id: u64 = 0;
-        loop {
-            let interval_seconds = app_handle
-                .try_state::<AppState>()
-                .map(|state| state.settings.lock().unwrap().check_interval_seconds.max(1))
-                .unwrap_or(TAURI_DEFAULT_CHECK_INTERVAL_SECONDS);
-
-            check_id += 1;
-            let payload = CheckMeetingsPayload {
-                check_id,
-                interval_seconds,
-                emitted_at_ms: now_ms(),
-            };
+/// Reload the main window when re-injecting didn't bring the script back.
+///
+/// Skipped during a call so the meeting isn't dropped.
+fn reload_unresponsive_main_window(app: &AppHandle, ping_id: u64) {
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    let Ok(url) = window.url() else {
+        return;
+    };
+    let url_str = url.to_string();
+    let (level, event, error) = if is_main_window_in_meeting(app) {
+        (LogLevel::Warn, "inject.watchdog_reload_skipped", None)
+    } else {
+        match navigate_webview(app, &window, url) {
+            Ok(()) => (LogLevel::Warn, "inject.watchdog_reloaded", None),
+            Err(e) => (LogLevel::Error, "inject.watchdog_reload_failed", Some(e)),
+        }
+    };
+    log_app_event(
+        app,
+        level,
+        "inject",
+        event,
+        error,
+        Some(json!({ "pingId": ping_id, "url": url_str })),
+    );
+}
 
-            // Emit check-meetings event to WebView
-            if let Err(e) = app_handle.emit("check-meetings", payload.clone()) {
-                eprintln!("Failed to emit check-meetings: {}", e);
-                log_app_event(
-                    &app_handle,
-                    LogLevel::Error,
-                    "daemon",
-                    "check.emit_failed",
-                    Some(e.to_string()),
-                    Some(json!({
-                        "checkId": payload.check_id,
-                        "intervalSeconds": payload.interval_seconds,
-                    })),
-                );
+/// Create the main window from its config, opening the startup page when
+/// one is set and sending links that leave Meet to the browser
+fn build_main_window(app: &AppHandle, hidden: bool) -> Result<WebviewWindow, String> {
+    let mut main_config = app
+        .config()
+        .app
+        .windows
+        .iter()
+        .find(|w| w.label == "main")
+        .cloned()
+        .ok_or_else(|| "Missing main window config".to_string())?;
+    if let Some(state) = app.try_state::<AppState>() {
+        let settings = state.settings.lock_or_recover().clone();
+        if configured_startup_url(&settings).is_some() {
+            main_config.url = WebviewUrl::External(startup_url(app, &settings));
+        }
+    }
+
+    if hidden {
+        main_config.visible = false;
+    }
+
+    let app_handle = app.clone();
+    WebviewWindowBuilder::from_config(app, &main_config)
+        .map_err(|e| e.to_string())?
+        .on_new_window(move |url, features| {
+            let _ = features;
+            let current_url = app_handle
+                .get_webview_window("main")
+                .and_then(|window| window.url().ok())
+                .unwrap_or_else(|| Url::parse("https://meet.google.com/").unwrap());
+
+            if should_open_external(&current_url, &url) {
+                let _ = app_handle.opener().open_url(url.as_str(), None::<&str>);
+                return tauri::webview::NewWindowResponse::Deny;
+            }
+
+            if matches!(url.scheme(), "http" | "https") {
+                if let Some(window) = app_handle.get_webview_window("main") {
+                    let _ = window.navigate(url.clone());
+                }
             } else {
-                log_app_event(
-                    &app_handle,
-                    LogLevel::Debug,
-                    "daemon",
-                    "check.emitted",
-                    None,
-                    Some(json!({
-                        "checkId": payload.check_id,
-                        "intervalSeconds": payload.interval_seconds,
-                        "emittedAtMs": payload.emitted_at_ms,
-                    })),
-                );
+                let _ = app_handle.opener().open_url(url.as_str(), None::<&str>);
             }
+            tauri::webview::NewWindowResponse::Deny
+        })
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// Recreate the main window when reloading didn't bring the script back.
+///
+/// That is what a crashed webview content process looks like: the window
+/// stays blank and nothing reports meetings until the app restarts. The new
+/// window is built from config like at launch, gets the injection setup
+/// again, and a `check-meetings` round is replayed once it has loaded.
+/// Skipped during a call so the meeting isn't dropped.
+fn recreate_main_window(app: &AppHandle, ping_id: u64) {
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    let url = window.url().map(|url| url.to_string()).unwrap_or_default();
+    if is_main_window_in_meeting(app) {
+        log_app_event(
+            app,
+            LogLevel::Warn,
+            "webview",
+            "webview.recreate_skipped",
+            None,
+            Some(json!({ "pingId": ping_id, "url": url })),
+        );
+        return;
+    }
+    let visible = window.is_visible().unwrap_or(true);
+    let started_ms = now_ms();
+    if let Err(e) = window.destroy() {
+        log_app_event(
+            app,
+            LogLevel::Error,
+            "webview",
+            "webview.recreate_failed",
+            Some(e.to_string()),
+            Some(json!({ "pingId": ping_id, "url": url })),
+        );
+        return;
+    }
 
-            tokio::time::sleep(Duration::from_secs(interval_seconds as u64)).await;
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        // The label is only released once the event loop has torn the old
+        // window down
+        for _ in 0..WEBVIEW_RELEASE_POLLS {
+            if app_handle.get_webview_window("main").is_none() {
+                break;
+            }
+            tokio::time::sleep(WEBVIEW_RELEASE_POLL_INTERVAL).await;
+        }
+        if let Err(e) = build_main_window(&app_handle, !visible) {
+            log_app_event(
+                &app_handle,
+                LogLevel::Error,
+                "webview",
+                "webview.recreate_failed",
+                Some(e),
+                Some(json!({ "pingId": ping_id, "url": url })),
+            );
+            return;
         }
+        // The `tauri://webview-created` listener injects the script again
+        setup_window_lifecycle(&app_handle);
+        setup_new_window_handler(&app_handle);
+
+        tokio::time::sleep(WEBVIEW_RECOVERY_SETTLE).await;
+        if let Some(state) = app_handle.try_state::<AppState>() {
+            state
+                .background
+                .lock_or_recover()
+                .run_soon(BackgroundTask::MeetingCheck, now_ms());
+            state.background_wake.notify_one();
+        }
+        log_app_event(
+            &app_handle,
+            LogLevel::Warn,
+            "webview",
+            "webview.recovered",
+            None,
+            Some(json!({
+                "pingId": ping_id,
+                "url": url,
+                "visible": visible,
+                "durationMs": now_ms().saturating_sub(started_ms),
+            })),
+        );
     });
 }
 
@@ -1210,21 +6832,142 @@ pub(crate) fn navigate_to_meet_home(app: &AppHandle) -> Result<(), String> {
         .get_webview_window("main")
         .ok_or_else(|| "Main window not found".to_string())?;
     let url = Url::parse(MEET_HOME_URL).map_err(|e| e.to_string())?;
-    window.navigate(url).map_err(|e| e.to_string())?;
+    navigate_webview(app, &window, url)?;
+    let _ = window.show();
+    let _ = window.set_focus();
+    Ok(())
+}
+
+/// Navigate the main window to the configured startup page
+pub(crate) fn navigate_to_startup_page(app: &AppHandle) -> Result<(), String> {
+    let window = app
+        .get_webview_window("main")
+        .ok_or_else(|| "Main window not found".to_string())?;
+    let url = app
+        .try_state::<AppState>()
+        .map(|state| startup_url(app, &state.settings.lock_or_recover()))
+        .unwrap_or_else(|| Url::parse(MEET_HOME_URL).unwrap());
+    navigate_webview(app, &window, url)?;
     let _ = window.show();
     let _ = window.set_focus();
     Ok(())
 }
 
+/// Non-blank `startupUrl` setting, as configured
+fn configured_startup_url(settings: &Settings) -> Option<&str> {
+    settings
+        .tauri
+        .as_ref()
+        .and_then(|t| t.startup_url.as_deref())
+        .filter(|raw| !raw.trim().is_empty())
+}
+
+/// Page the main window opens at startup; Meet home unless a valid
+/// `startupUrl` is configured
+fn startup_url(app: &AppHandle, settings: &Settings) -> Url {
+    if let Some(raw) = configured_startup_url(settings) {
+        match url_norm::normalize_startup_url(raw) {
+            Ok(url) => return url,
+            Err(e) => log_app_event(
+                app,
+                LogLevel::Warn,
+                "startup",
+                "startup.url_rejected",
+                Some(e.to_string()),
+                Some(json!({ "reason": e })),
+            ),
+        }
+    }
+    Url::parse(MEET_HOME_URL).unwrap()
+}
+
 fn navigate_to_meet_home_silent(app: &AppHandle) -> Result<(), String> {
     let window = app
         .get_webview_window("main")
         .ok_or_else(|| "Main window not found".to_string())?;
     let url = Url::parse(MEET_HOME_URL).map_err(|e| e.to_string())?;
-    window.navigate(url).map_err(|e| e.to_string())?;
+    navigate_webview(app, &window, url)?;
     Ok(())
 }
 
+/// Navigate the main webview, recording failures for the load tracker
+fn navigate_webview(app: &AppHandle, window: &WebviewWindow, url: Url) -> Result<(), String> {
+    let url_str = url.to_string();
+    window.navigate(url).map_err(|e| {
+        let error = e.to_string();
+        if let Some(state) = app.try_state::<AppState>() {
+            let outcome = state.webview_health.lock_or_recover().on_navigate_failed();
+            log_page_load_outcome(app, &outcome, &url_str, Some(&error));
+        }
+        error
+    })
+}
+
+/// Feed main-window page-load events into the load tracker and log failures
+fn record_page_load(app: &AppHandle, event: PageLoadEvent, url: &Url) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    match event {
+        PageLoadEvent::Started => {
+            let load_id = state.webview_health.lock_or_recover().on_started(now_ms());
+            let app_handle = app.clone();
+            let url_str = url.to_string();
+            tauri::async_runtime::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(LOAD_TIMEOUT_MS)).await;
+                let Some(state) = app_handle.try_state::<AppState>() else {
+                    return;
+                };
+                let outcome = state
+                    .webview_health
+                    .lock_or_recover()
+                    .check_timeout(load_id, now_ms());
+                if let Some(outcome) = outcome {
+                    log_page_load_outcome(&app_handle, &outcome, &url_str, None);
+                }
+            });
+        }
+        PageLoadEvent::Finished => {
+            let outcome = state.webview_health.lock_or_recover().on_finished(url);
+            log_page_load_outcome(app, &outcome, url.as_str(), None);
+        }
+    }
+}
+
+fn log_page_load_outcome(app: &AppHandle, outcome: &LoadOutcome, url: &str, error: Option<&str>) {
+    match outcome {
+        LoadOutcome::Failed { code, consecutive } => {
+            eprintln!("[MeetCat] Main webview load failed ({:?}): {}", code, url);
+            log_app_event(
+                app,
+                LogLevel::Warn,
+                "webview",
+                "page.load_failed",
+                error.map(str::to_string),
+                Some(json!({
+                    "url": url,
+                    "errorCode": code,
+                    "consecutiveFailures": consecutive,
+                })),
+            );
+        }
+        LoadOutcome::Succeeded { recovered_after } if *recovered_after > 0 => {
+            log_app_event(
+                app,
+                LogLevel::Info,
+                "webview",
+                "page.load_recovered",
+                None,
+                Some(json!({
+                    "url": url,
+                    "failuresBefore": recovered_after,
+                })),
+            );
+        }
+        LoadOutcome::Succeeded { .. } => {}
+    }
+}
+
 fn focus_main_window(app: &AppHandle) {
     if let Some(window) = app.get_webview_window("main") {
         let _ = window.show();
@@ -1234,17 +6977,20 @@ fn focus_main_window(app: &AppHandle) {
 }
 
 fn navigate_main_window(app: &AppHandle, url: Url) -> Result<(), String> {
+    if demo_mode_active(app) {
+        return Err(DEMO_MODE_ERROR.to_string());
+    }
     let window = app
         .get_webview_window("main")
         .ok_or_else(|| "Main window not found".to_string())?;
-    window.navigate(url).map_err(|e| e.to_string())?;
+    navigate_webview(app, &window, url)?;
     let _ = window.show();
     let _ = window.unminimize();
     let _ = window.set_focus();
     Ok(())
 }
 
-fn dispatch_deep_link(app: &AppHandle, action: DeepLinkAction) {
+fn dispatch_deep_link(app: &AppHandle, action: DeepLinkAction, callback: XCallback) {
     log_app_event(
         app,
         LogLevel::Info,
@@ -1254,67 +7000,318 @@ fn dispatch_deep_link(app: &AppHandle, action: DeepLinkAction) {
         Some(json!({ "action": format!("{:?}", action) })),
     );
 
+    let shown_window = action.shown_window();
+    let result = run_deep_link_action(app, action);
+    match (&result, shown_window) {
+        (Err(e), _) => {
+            eprintln!(
+                "[MeetCat] deep_link action failed ({}): {}",
+                e.code, e.message
+            );
+        }
+        (Ok(_), Some(label)) => record_window_shown(app, label, ShowCause::DeepLink, None),
+        (Ok(_), None) => {}
+    }
+    send_deep_link_callback(app, &callback, result);
+}
+
+fn run_deep_link_action(app: &AppHandle, action: DeepLinkAction) -> Result<serde_json::Value, CallbackError> {
     match action {
         DeepLinkAction::Home => {
-            if let Err(e) = navigate_to_meet_home(app) {
-                eprintln!("[MeetCat] deep_link home failed: {}", e);
-            }
+            navigate_to_meet_home(app).map_err(|e| CallbackError::new("navigation_failed", e))?;
+            Ok(json!({ "ok": true }))
         }
         DeepLinkAction::Settings => {
-            if let Err(e) = ensure_settings_window(app) {
-                eprintln!("[MeetCat] deep_link settings failed: {}", e);
-            } else if let Some(window) = app.get_webview_window("settings") {
+            ensure_settings_window(app).map_err(|e| CallbackError::new("window_failed", e))?;
+            if let Some(window) = app.get_webview_window("settings") {
                 promote_window_to_front(&window);
             }
+            Ok(json!({ "ok": true }))
         }
         DeepLinkAction::NewMeeting => {
-            let url = match Url::parse("https://meet.google.com/new") {
-                Ok(u) => u,
-                Err(e) => {
-                    eprintln!("[MeetCat] deep_link new url parse failed: {}", e);
-                    return;
-                }
-            };
-            if let Err(e) = navigate_main_window(app, url) {
-                eprintln!("[MeetCat] deep_link new navigate failed: {}", e);
-            }
+            let url = Url::parse("https://meet.google.com/new")
+                .map_err(|e| CallbackError::new("invalid_url", e.to_string()))?;
+            navigate_main_window(app, url).map_err(|e| CallbackError::new("navigation_failed", e))?;
+            Ok(json!({ "ok": true }))
         }
         DeepLinkAction::CheckUpdate => {
-            if let Err(e) = ensure_settings_window(app) {
-                eprintln!("[MeetCat] deep_link check-update settings failed: {}", e);
-            } else if let Some(window) = app.get_webview_window("settings") {
+            ensure_settings_window(app).map_err(|e| CallbackError::new("window_failed", e))?;
+            if let Some(window) = app.get_webview_window("settings") {
                 promote_window_to_front(&window);
             }
             request_manual_update_check(app);
+            Ok(json!({ "ok": true }))
         }
         DeepLinkAction::JoinMeeting { code } => {
-            dispatch_join_meeting(app, &code);
+            dispatch_join_meeting(app, &code)?;
+            Ok(json!({ "ok": true }))
+        }
+        DeepLinkAction::QueryNextMeeting => {
+            let state = deep_link_state(app)?;
+            let settings = state.settings.lock_or_recover().clone();
+            let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+            let daemon = state.daemon.lock_or_recover();
+            let now = chrono::Utc::now();
+            let privacy_mode = settings.tauri_or_default().privacy_mode;
+            Ok(json!({
+                "ok": true,
+                "meeting": daemon
+                    .get_next_meeting(&settings, &title_filters)
+                    .map(|m| deep_link_meeting_json(&m, now, privacy_mode)),
+                "pausedUntil": daemon.paused_until(now).map(|t| t.to_rfc3339()),
+            }))
+        }
+        DeepLinkAction::JoinNext => dispatch_join_next(app),
+        DeepLinkAction::Pause { minutes } => {
+            let state = deep_link_state(app)?;
+            let until = chrono::Utc::now() + chrono::Duration::minutes(minutes as i64);
+            state.daemon.lock_or_recover().pause_until(until);
+            log_app_event(
+                app,
+                LogLevel::Info,
+                "daemon",
+                "pause.started",
+                None,
+                Some(json!({ "minutes": minutes, "untilMs": until.timestamp_millis() })),
+            );
+            schedule_join_trigger(&state);
+            refresh_tray_status(app);
+            Ok(json!({ "ok": true, "pausedUntil": until.to_rfc3339() }))
+        }
+        DeepLinkAction::Resume => {
+            let state = deep_link_state(app)?;
+            let was_paused = state.daemon.lock_or_recover().resume(chrono::Utc::now());
+            if was_paused {
+                log_app_event(app, LogLevel::Info, "daemon", "pause.resumed", None, None);
+            }
+            schedule_join_trigger(&state);
+            refresh_tray_status(app);
+            Ok(json!({ "ok": true, "wasPaused": was_paused }))
+        }
+        DeepLinkAction::PrivacyMode { enabled } => {
+            let state = deep_link_state(app)?;
+            let mut settings = state.settings.lock_or_recover().clone();
+            let mut tauri_settings = settings.tauri.clone().unwrap_or_default();
+            tauri_settings.privacy_mode = enabled.unwrap_or(!tauri_settings.privacy_mode);
+            let privacy_mode = tauri_settings.privacy_mode;
+            settings.tauri = Some(tauri_settings);
+            save_settings(app.clone(), state, settings).map_err(|errors| {
+                let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+                CallbackError::new("settings_failed", messages.join("; "))
+            })?;
+            Ok(json!({ "ok": true, "privacyMode": privacy_mode }))
         }
     }
 }
 
-fn dispatch_join_meeting(app: &AppHandle, code: &str) {
+fn deep_link_state(app: &AppHandle) -> Result<State<'_, AppState>, CallbackError> {
+    app.try_state::<AppState>()
+        .ok_or_else(|| CallbackError::new("unavailable", "MeetCat is not ready"))
+}
+
+/// Meeting shape returned to x-callback-url clients; privacy mode leaves out
+/// the title and anything that leads to the meeting
+fn deep_link_meeting_json(
+    meeting: &Meeting,
+    now: chrono::DateTime<chrono::Utc>,
+    privacy_mode: bool,
+) -> serde_json::Value {
+    let mut value = json!({
+        "displayTime": meeting.display_time,
+        "beginTime": meeting.begin_time.to_rfc3339(),
+        "endTime": meeting.end_time.to_rfc3339(),
+        "startsInMinutes": meeting.starts_in_minutes_at(now),
+    });
+    if !privacy_mode {
+        value["callId"] = json!(meeting.call_id);
+        value["title"] = json!(meeting.title);
+        value["url"] = json!(meeting.url);
+    }
+    value
+}
+
+/// Open the x-success / x-error callback for a finished deep-link action
+fn send_deep_link_callback(
+    app: &AppHandle,
+    callback: &XCallback,
+    result: Result<serde_json::Value, CallbackError>,
+) {
+    let target = match &result {
+        Ok(value) => callback.success_url(value),
+        Err(error) => callback.error_url(error),
+    };
+    let Some(target) = target else {
+        return;
+    };
+    log_app_event(
+        app,
+        LogLevel::Debug,
+        "deep_link",
+        "callback.open",
+        None,
+        Some(json!({ "ok": result.is_ok(), "scheme": target.scheme() })),
+    );
+    if let Err(e) = app.opener().open_url(target.as_str(), None::<&str>) {
+        eprintln!("[MeetCat] deep_link callback failed: {}", e);
+        log_app_event(
+            app,
+            LogLevel::Warn,
+            "deep_link",
+            "callback.failed",
+            None,
+            Some(json!({ "error": e.to_string() })),
+        );
+    }
+}
+
+fn dispatch_join_meeting(app: &AppHandle, code: &str) -> Result<(), CallbackError> {
     let auto_join = app
         .try_state::<AppState>()
-        .map(|state| state.settings.lock().unwrap().auto_click_join)
+        .map(|state| state.settings.lock_or_recover().auto_click_join)
         .unwrap_or(false);
 
-    let url = match build_join_meeting_url(code, auto_join) {
-        Ok(u) => u,
-        Err(e) => {
-            eprintln!("[MeetCat] deep_link join url parse failed: {}", e);
-            return;
+    let url = build_join_meeting_url(code, auto_join)
+        .map_err(|e| CallbackError::new("invalid_url", e))?;
+    navigate_main_window(app, url).map_err(|e| CallbackError::new("navigation_failed", e))?;
+    transition_meeting(app, code, MeetingPhase::Navigating);
+    Ok(())
+}
+
+/// Join a meeting typed into the "Join by code" dialog.
+/// Returns the meeting code in short form.
+#[tauri::command]
+fn join_by_code(app: AppHandle, state: State<AppState>, input: String) -> Result<String, String> {
+    let mut url = url_norm::meeting_input_url(&input)
+        .map_err(|e| format!("Invalid meeting code or link: {}", e))?;
+    let target = url_norm::parse_target_path(url.path())
+        .ok_or_else(|| "Invalid meeting code or link".to_string())?;
+    let code = url_norm::short_form(&url);
+
+    let settings = state.settings.lock_or_recover().clone();
+    if settings.auto_click_join {
+        url.query_pairs_mut()
+            .append_pair(MEETCAT_AUTO_JOIN_PARAM, "1");
+    }
+    navigate_main_window(&app, url)?;
+    transition_meeting(&app, &target.path(), MeetingPhase::Navigating);
+
+    log_app_event(
+        &app,
+        LogLevel::Info,
+        "join",
+        "join.by_code",
+        None,
+        Some(json!({ "callId": target.path() })),
+    );
+
+    if settings.tauri_or_default().remember_recent_codes {
+        let snapshot = {
+            let mut recent = state.recent_codes.lock_or_recover();
+            recent.remember(&code).then(|| recent.clone())
+        };
+        if let Some(snapshot) = snapshot {
+            save_recent_codes(&app, &snapshot);
         }
-    };
+    }
+    Ok(code)
+}
+
+/// Codes offered in the "Join by code" dialog, empty when remembering is off
+#[tauri::command]
+fn get_recent_meeting_codes(state: State<AppState>) -> Vec<String> {
+    if !state
+        .settings
+        .lock_or_recover()
+        .tauri_or_default()
+        .remember_recent_codes
+    {
+        return Vec::new();
+    }
+    state.recent_codes.lock_or_recover().codes.clone()
+}
+
+#[tauri::command]
+fn consume_join_by_code_dialog_request(state: State<AppState>) -> bool {
+    let mut requested = state.join_by_code_dialog_requested.lock_or_recover();
+    let value = *requested;
+    *requested = false;
+    value
+}
+
+/// Open the settings window with the "Join by code" dialog showing
+pub(crate) fn request_join_by_code_dialog(app: &AppHandle) -> Result<(), String> {
+    ensure_settings_window(app)?;
+    if let Some(state) = app.try_state::<AppState>() {
+        *state.join_by_code_dialog_requested.lock_or_recover() = true;
+    }
+    let _ = app.emit(event::JOIN_BY_CODE_OPEN_DIALOG, ());
+    Ok(())
+}
+
+fn save_recent_codes(app: &AppHandle, recent: &RecentMeetingCodes) {
+    let result = recent_codes_path().and_then(|path| persistence::save_versioned(&path, recent));
+    if let Err(e) = result {
+        log_app_event(
+            app,
+            LogLevel::Warn,
+            "join",
+            "recent_codes.save_failed",
+            None,
+            Some(json!({ "error": e })),
+        );
+    }
+}
 
-    if let Err(e) = navigate_main_window(app, url) {
-        eprintln!("[MeetCat] deep_link join navigate failed: {}", e);
+/// Join the daemon's next meeting right away, the same way a URL Scheme join would
+fn dispatch_join_next(app: &AppHandle) -> Result<serde_json::Value, CallbackError> {
+    let state = deep_link_state(app)?;
+    let settings = state.settings.lock_or_recover().clone();
+    let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+    let meeting = state
+        .daemon
+        .lock_or_recover()
+        .get_next_meeting(&settings, &title_filters)
+        .ok_or_else(|| CallbackError::new("no_meeting", "No upcoming meeting"))?;
+
+    let mut url = url_norm::normalize(&meeting.url)
+        .map_err(|e| CallbackError::new("invalid_url", e.to_string()))?;
+    if settings.auto_click_join {
+        url.query_pairs_mut()
+            .append_pair(MEETCAT_AUTO_JOIN_PARAM, "1");
     }
+
+    // Keep the scheduled trigger from joining the same meeting again
+    let triggered = state.daemon.lock_or_recover().transition(
+        &meeting.call_id,
+        MeetingPhase::Triggered,
+        now_ms() as i64,
+    );
+    report_transition(app, triggered);
+    log_app_event(
+        app,
+        LogLevel::Info,
+        "deep_link",
+        "join_next",
+        None,
+        Some(json!({ "callId": meeting.call_id, "title": meeting.title })),
+    );
+    navigate_main_window(app, url).map_err(|e| CallbackError::new("navigation_failed", e))?;
+    transition_meeting(app, &meeting.call_id, MeetingPhase::Navigating);
+
+    Ok(json!({
+        "ok": true,
+        "meeting": deep_link_meeting_json(
+            &meeting,
+            chrono::Utc::now(),
+            settings.tauri_or_default().privacy_mode,
+        ),
+    }))
 }
 
 fn build_join_meeting_url(code: &str, auto_join: bool) -> Result<Url, String> {
-    let target = format!("https://meet.google.com/{}", code);
-    let mut url = Url::parse(&target).map_err(|e| e.to_string())?;
+    let target = url_norm::parse_target_path(code)
+        .ok_or_else(|| format!("Invalid meeting code: {}", code))?;
+    let mut url = url_norm::meeting_url(&target);
     if auto_join {
         url.query_pairs_mut()
             .append_pair(MEETCAT_AUTO_JOIN_PARAM, "1");
@@ -1322,8 +7319,12 @@ fn build_join_meeting_url(code: &str, auto_join: bool) -> Result<Url, String> {
     Ok(url)
 }
 
-#[cfg(target_os = "macos")]
-fn apply_macos_menu(app: &AppHandle, refresh_enabled: bool) -> Result<(), String> {
+/// Build the app menu and install it.
+///
+/// macOS gets the usual app, Edit, View, Window and Help menus; Windows and
+/// Linux get a File, View and Help menu bar on the app's windows. The
+/// MeetCat items and their Cmd/Ctrl shortcuts are the same everywhere.
+fn apply_app_menu(app: &AppHandle, refresh_enabled: bool) -> Result<(), String> {
     let app_name = "MeetCat";
     let lang = i18n::Language::detect();
 
@@ -1339,7 +7340,7 @@ fn apply_macos_menu(app: &AppHandle, refresh_enabled: bool) -> Result<(), String
         "app-quit",
         i18n::tr(&lang, i18n::keys::QUIT_MEETCAT),
         true,
-        Some("Cmd+Q"),
+        Some("CmdOrCtrl+Q"),
     )
     .map_err(|e| e.to_string())?;
     let go_home_item = MenuItem::with_id(
@@ -1347,7 +7348,15 @@ fn apply_macos_menu(app: &AppHandle, refresh_enabled: bool) -> Result<(), String
         "app-go-home",
         i18n::tr(&lang, i18n::keys::BACK_TO_GOOGLE_MEET_HOME),
         true,
-        Some("Cmd+Shift+H"),
+        Some("CmdOrCtrl+Shift+H"),
+    )
+    .map_err(|e| e.to_string())?;
+    let go_startup_item = MenuItem::with_id(
+        app,
+        "app-go-startup",
+        i18n::tr(&lang, i18n::keys::BACK_TO_STARTUP_PAGE),
+        true,
+        None::<&str>,
     )
     .map_err(|e| e.to_string())?;
     let refresh_item = MenuItem::with_id(
@@ -1355,7 +7364,7 @@ fn apply_macos_menu(app: &AppHandle, refresh_enabled: bool) -> Result<(), String
         "app-refresh-home",
         i18n::tr(&lang, i18n::keys::MENU_REFRESH_HOME),
         refresh_enabled,
-        Some("Cmd+R"),
+        Some("CmdOrCtrl+R"),
     )
     .map_err(|e| e.to_string())?;
     let settings_item = MenuItem::with_id(
@@ -1363,53 +7372,10 @@ fn apply_macos_menu(app: &AppHandle, refresh_enabled: bool) -> Result<(), String
         "app-settings",
         i18n::tr(&lang, i18n::keys::SETTINGS),
         true,
-        Some("Cmd+,"),
+        Some("CmdOrCtrl+,"),
     )
     .map_err(|e| e.to_string())?;
 
-    let app_menu = SubmenuBuilder::with_id(app, "app", app_name)
-        .about_with_text(i18n::tr_about(&lang, app_name), Some(about_metadata))
-        .item(&settings_item)
-        .separator()
-        .item(&go_home_item)
-        .separator()
-        .services_with_text(i18n::tr(&lang, i18n::keys::MENU_SERVICES))
-        .separator()
-        .hide_with_text(i18n::tr_hide(&lang, app_name))
-        .hide_others_with_text(i18n::tr(&lang, i18n::keys::MENU_HIDE_OTHERS))
-        .show_all_with_text(i18n::tr(&lang, i18n::keys::MENU_SHOW_ALL))
-        .separator()
-        .item(&quit_item)
-        .build()
-        .map_err(|e| e.to_string())?;
-
-    let edit_menu = SubmenuBuilder::new(app, i18n::tr(&lang, i18n::keys::MENU_EDIT))
-        .undo_with_text(i18n::tr(&lang, i18n::keys::MENU_UNDO))
-        .redo_with_text(i18n::tr(&lang, i18n::keys::MENU_REDO))
-        .separator()
-        .cut_with_text(i18n::tr(&lang, i18n::keys::MENU_CUT))
-        .copy_with_text(i18n::tr(&lang, i18n::keys::MENU_COPY))
-        .paste_with_text(i18n::tr(&lang, i18n::keys::MENU_PASTE))
-        .separator()
-        .select_all_with_text(i18n::tr(&lang, i18n::keys::MENU_SELECT_ALL))
-        .build()
-        .map_err(|e| e.to_string())?;
-
-    let view_menu = SubmenuBuilder::new(app, i18n::tr(&lang, i18n::keys::MENU_VIEW))
-        .item(&refresh_item)
-        .separator()
-        .fullscreen_with_text(i18n::tr(&lang, i18n::keys::MENU_FULLSCREEN))
-        .build()
-        .map_err(|e| e.to_string())?;
-
-    let window_menu = SubmenuBuilder::new(app, i18n::tr(&lang, i18n::keys::MENU_WINDOW))
-        .minimize_with_text(i18n::tr(&lang, i18n::keys::MENU_MINIMIZE))
-        .maximize_with_text(i18n::tr(&lang, i18n::keys::MENU_ZOOM))
-        .separator()
-        .close_window_with_text(i18n::tr(&lang, i18n::keys::MENU_CLOSE_WINDOW))
-        .build()
-        .map_err(|e| e.to_string())?;
-
     let url_scheme_help_item = MenuItem::with_id(
         app,
         "app-help-url-scheme",
@@ -1419,19 +7385,100 @@ fn apply_macos_menu(app: &AppHandle, refresh_enabled: bool) -> Result<(), String
     )
     .map_err(|e| e.to_string())?;
 
-    let help_menu = SubmenuBuilder::new(app, i18n::tr(&lang, i18n::keys::MENU_HELP))
-        .item(&url_scheme_help_item)
-        .build()
-        .map_err(|e| e.to_string())?;
+    #[cfg(target_os = "macos")]
+    let menu = {
+        let app_menu = SubmenuBuilder::with_id(app, "app", app_name)
+            .about_with_text(i18n::tr_about(&lang, app_name), Some(about_metadata))
+            .item(&settings_item)
+            .separator()
+            .item(&go_home_item)
+            .item(&go_startup_item)
+            .separator()
+            .services_with_text(i18n::tr(&lang, i18n::keys::MENU_SERVICES))
+            .separator()
+            .hide_with_text(i18n::tr_hide(&lang, app_name))
+            .hide_others_with_text(i18n::tr(&lang, i18n::keys::MENU_HIDE_OTHERS))
+            .show_all_with_text(i18n::tr(&lang, i18n::keys::MENU_SHOW_ALL))
+            .separator()
+            .item(&quit_item)
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        let edit_menu = SubmenuBuilder::new(app, i18n::tr(&lang, i18n::keys::MENU_EDIT))
+            .undo_with_text(i18n::tr(&lang, i18n::keys::MENU_UNDO))
+            .redo_with_text(i18n::tr(&lang, i18n::keys::MENU_REDO))
+            .separator()
+            .cut_with_text(i18n::tr(&lang, i18n::keys::MENU_CUT))
+            .copy_with_text(i18n::tr(&lang, i18n::keys::MENU_COPY))
+            .paste_with_text(i18n::tr(&lang, i18n::keys::MENU_PASTE))
+            .separator()
+            .select_all_with_text(i18n::tr(&lang, i18n::keys::MENU_SELECT_ALL))
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        let view_menu = SubmenuBuilder::new(app, i18n::tr(&lang, i18n::keys::MENU_VIEW))
+            .item(&refresh_item)
+            .separator()
+            .fullscreen_with_text(i18n::tr(&lang, i18n::keys::MENU_FULLSCREEN))
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        let window_menu = SubmenuBuilder::new(app, i18n::tr(&lang, i18n::keys::MENU_WINDOW))
+            .minimize_with_text(i18n::tr(&lang, i18n::keys::MENU_MINIMIZE))
+            .maximize_with_text(i18n::tr(&lang, i18n::keys::MENU_ZOOM))
+            .separator()
+            .close_window_with_text(i18n::tr(&lang, i18n::keys::MENU_CLOSE_WINDOW))
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        let help_menu = SubmenuBuilder::new(app, i18n::tr(&lang, i18n::keys::MENU_HELP))
+            .item(&url_scheme_help_item)
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        MenuBuilder::new(app)
+            .item(&app_menu)
+            .item(&edit_menu)
+            .item(&view_menu)
+            .item(&window_menu)
+            .item(&help_menu)
+            .build()
+            .map_err(|e| e.to_string())?
+    };
 
-    let menu = MenuBuilder::new(app)
-        .item(&app_menu)
-        .item(&edit_menu)
-        .item(&view_menu)
-        .item(&window_menu)
-        .item(&help_menu)
-        .build()
-        .map_err(|e| e.to_string())?;
+    // No app menu outside macOS: the MeetCat items live under File, and the
+    // menu bar is attached to each window
+    #[cfg(not(target_os = "macos"))]
+    let menu = {
+        let file_menu = SubmenuBuilder::new(app, i18n::tr(&lang, i18n::keys::MENU_FILE))
+            .item(&settings_item)
+            .separator()
+            .item(&go_home_item)
+            .item(&go_startup_item)
+            .separator()
+            .item(&quit_item)
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        let view_menu = SubmenuBuilder::new(app, i18n::tr(&lang, i18n::keys::MENU_VIEW))
+            .item(&refresh_item)
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        let help_menu = SubmenuBuilder::new(app, i18n::tr(&lang, i18n::keys::MENU_HELP))
+            .item(&url_scheme_help_item)
+            .separator()
+            .about_with_text(i18n::tr_about(&lang, app_name), Some(about_metadata))
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        MenuBuilder::new(app)
+            .item(&file_menu)
+            .item(&view_menu)
+            .item(&help_menu)
+            .build()
+            .map_err(|e| e.to_string())?
+    };
 
     app.set_menu(menu).map_err(|e| e.to_string())?;
     Ok(())
@@ -1463,18 +7510,14 @@ fn open_url_scheme_help(app: &AppHandle) {
     }
 }
 
-#[cfg(target_os = "macos")]
 fn update_refresh_menu_state(app: &AppHandle, state: &State<AppState>, is_homepage: bool) {
-    let mut current = match state.homepage_active.lock() {
-        Ok(value) => value,
-        Err(poisoned) => poisoned.into_inner(),
-    };
+    let mut current = state.homepage_active.lock_or_recover();
     if current.as_ref() == Some(&is_homepage) {
         return;
     }
     *current = Some(is_homepage);
-    if let Err(e) = apply_macos_menu(app, is_homepage) {
-        eprintln!("Failed to update macOS menu: {}", e);
+    if let Err(e) = apply_app_menu(app, is_homepage) {
+        eprintln!("Failed to update app menu: {}", e);
     }
 }
 
@@ -1507,6 +7550,20 @@ fn setup_new_window_handler(app: &AppHandle) {
             // Wait for page to be ready
             tokio::time::sleep(Duration::from_millis(2000)).await;
 
+            // A denied permission can't be prompted for again; report it
+            // instead of letting the first auto-join fail silently
+            let media = permissions::check();
+            if !media.blocked_devices().is_empty() {
+                log_app_event(
+                    &app_handle,
+                    LogLevel::Warn,
+                    "permissions",
+                    "media.blocked",
+                    None,
+                    serde_json::to_value(&media).ok(),
+                );
+            }
+
             // Request media permissions
             if let Err(e) = window_clone.eval(REQUEST_MEDIA_SCRIPT) {
                 eprintln!("Failed to request media permissions: {}", e);
@@ -1654,82 +7711,78 @@ const INTERCEPT_SCRIPT: &str = r##"
 })();
 "##;
 
-/// Inject script when navigating to Google pages
-fn setup_navigation_injection(app: &AppHandle) {
-    let app_handle = app.clone();
+/// Re-inject scripts when the main window navigates to a Google Meet page.
+///
+/// Polled because Tauri 2.x navigation events may not fire reliably.
+fn poll_main_window_url(app: &AppHandle, last_url: &mut String) {
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    let Ok(url) = window.url() else {
+        return;
+    };
+    let url_str = url.to_string();
+
+    // Check if URL changed
+    if url_str == *last_url {
+        return;
+    }
+    println!("[MeetCat] URL changed: {} -> {}", last_url, url_str);
+    *last_url = url_str.clone();
 
-    // Use periodic URL check as Tauri 2.x navigation events may not fire reliably
+    // Re-inject scripts on meet.google.com
+    if !url_norm::is_allowed_host(&url) {
+        return;
+    }
+    let app_handle = app.clone();
     tauri::async_runtime::spawn(async move {
-        let mut last_url = String::new();
-        let mut interval = tokio::time::interval(Duration::from_millis(500));
+        // Wait for page to load
+        tokio::time::sleep(Duration::from_millis(1500)).await;
 
-        loop {
-            interval.tick().await;
+        // Inject intercept script
+        if let Err(e) = window.eval(INTERCEPT_SCRIPT) {
+            eprintln!("Failed to inject intercept script: {}", e);
+            log_app_event(
+                &app_handle,
+                LogLevel::Warn,
+                "inject",
+                "intercept.inject_failed",
+                Some(e.to_string()),
+                Some(json!({ "url": url_str })),
+            );
+        } else {
+            log_app_event(
+                &app_handle,
+                LogLevel::Debug,
+                "inject",
+                "intercept.injected",
+                None,
+                Some(json!({ "url": url_str })),
+            );
+        }
 
-            if let Some(window) = app_handle.get_webview_window("main") {
-                if let Ok(url) = window.url() {
-                    let url_str = url.to_string();
-
-                    // Check if URL changed
-                    if url_str != last_url {
-                        println!("[MeetCat] URL changed: {} -> {}", last_url, url_str);
-                        last_url = url_str.clone();
-
-                        // Re-inject scripts on meet.google.com
-                        if url.host_str().map_or(false, |h| h == "meet.google.com") {
-                            let window_clone = window.clone();
-                            // Wait for page to load
-                            tokio::time::sleep(Duration::from_millis(1500)).await;
-
-                            // Inject intercept script
-                            if let Err(e) = window_clone.eval(INTERCEPT_SCRIPT) {
-                                eprintln!("Failed to inject intercept script: {}", e);
-                                log_app_event(
-                                    &app_handle,
-                                    LogLevel::Warn,
-                                    "inject",
-                                    "intercept.inject_failed",
-                                    Some(e.to_string()),
-                                    Some(json!({ "url": url_str })),
-                                );
-                            } else {
-                                log_app_event(
-                                    &app_handle,
-                                    LogLevel::Debug,
-                                    "inject",
-                                    "intercept.injected",
-                                    None,
-                                    Some(json!({ "url": url_str })),
-                                );
-                            }
-
-                            // Inject MeetCat script
-                            let script = get_inject_script();
-                            if let Err(e) = window_clone.eval(script) {
-                                eprintln!("Failed to inject MeetCat script: {}", e);
-                                log_app_event(
-                                    &app_handle,
-                                    LogLevel::Warn,
-                                    "inject",
-                                    "script.inject_failed",
-                                    Some(e.to_string()),
-                                    Some(json!({ "url": url_str })),
-                                );
-                            } else {
-                                println!("[MeetCat] Script injected for: {}", url_str);
-                                log_app_event(
-                                    &app_handle,
-                                    LogLevel::Debug,
-                                    "inject",
-                                    "script.injected",
-                                    None,
-                                    Some(json!({ "url": url_str })),
-                                );
-                            }
-                        }
-                    }
-                }
-            }
+        // Inject MeetCat script
+        let script = get_inject_script();
+        if let Err(e) = window.eval(script) {
+            eprintln!("Failed to inject MeetCat script: {}", e);
+            log_app_event(
+                &app_handle,
+                LogLevel::Warn,
+                "inject",
+                "script.inject_failed",
+                Some(e.to_string()),
+                Some(json!({ "url": url_str })),
+            );
+        } else {
+            println!("[MeetCat] Script injected for: {}", url_str);
+            log_app_event(
+                &app_handle,
+                LogLevel::Debug,
+                "inject",
+                "script.injected",
+                None,
+                Some(json!({ "url": url_str })),
+            );
         }
     });
 }
@@ -1765,7 +7818,7 @@ fn is_meeting_path(path: &str) -> bool {
 }
 
 fn is_meeting_url(url: &Url) -> bool {
-    if url.host_str() != Some("meet.google.com") {
+    if !url_norm::is_allowed_host(url) {
         return false;
     }
     is_meeting_path(url.path())
@@ -1773,7 +7826,7 @@ fn is_meeting_url(url: &Url) -> bool {
 
 fn should_open_external(current_url: &Url, target_url: &Url) -> bool {
     if is_meeting_url(current_url) {
-        return target_url.host_str() != Some("meet.google.com");
+        return !url_norm::is_allowed_host(target_url);
     }
     false
 }
@@ -1781,10 +7834,23 @@ fn should_open_external(current_url: &Url, target_url: &Url) -> bool {
 #[cfg(test)]
 mod tests {
     use super::{
-        build_join_meeting_url, is_meeting_path, is_meeting_url, should_open_external,
+        build_join_meeting_url, build_settings_change_summary, deep_link_meeting_json,
+        is_meeting_path, is_meeting_url, should_open_external, should_return_home,
+        sleep_until_wall_clock,
     };
+    use crate::fixtures;
+    use crate::logging::now_ms;
+    use crate::settings::Settings;
     use tauri::Url;
 
+    #[tokio::test]
+    async fn test_sleep_until_wall_clock() {
+        let start = now_ms();
+        sleep_until_wall_clock(start.saturating_sub(1000)).await;
+        sleep_until_wall_clock(start + 50).await;
+        assert!(now_ms() >= start + 50);
+    }
+
     #[test]
     fn test_is_meeting_path_code() {
         assert!(is_meeting_path("/abc-defg-hij"));
@@ -1805,6 +7871,37 @@ mod tests {
         assert!(!is_meeting_path(""));
     }
 
+    #[test]
+    fn test_should_return_home() {
+        let on = |raw: &str| should_return_home(&Url::parse(raw).unwrap(), "abc-defg-hij");
+        assert!(on("https://meet.google.com/abc-defg-hij"));
+        assert!(on("https://meet.google.com/abc-defg-hij?authuser=1"));
+        assert!(on("https://meet.google.com/landing"));
+        // Already home, in the next meeting, or somewhere else entirely
+        assert!(!on("https://meet.google.com/"));
+        assert!(!on("https://meet.google.com/xyz-wxyz-xyz"));
+        assert!(!on("https://accounts.google.com/abc-defg-hij"));
+    }
+
+    #[test]
+    fn test_settings_change_summary() {
+        let before = Settings::default();
+        let (keys, changes) = build_settings_change_summary(&before, &before.clone());
+        assert!(keys.is_empty());
+        assert_eq!(changes, serde_json::json!({}));
+
+        let mut after = before.clone();
+        after.show_countdown_overlay = !before.show_countdown_overlay;
+        after.language = "ja".to_string();
+        let (keys, changes) = build_settings_change_summary(&before, &after);
+        assert_eq!(keys, vec!["language", "showCountdownOverlay"]);
+        assert_eq!(changes["language"]["to"], "ja");
+        assert_eq!(
+            changes["showCountdownOverlay"]["to"],
+            after.show_countdown_overlay
+        );
+    }
+
     #[test]
     fn test_is_meeting_url() {
         let url = Url::parse("https://meet.google.com/abc-defg-hij").unwrap();
@@ -1815,6 +7912,9 @@ mod tests {
 
         let other = Url::parse("https://example.com/abc-defg-hij").unwrap();
         assert!(!is_meeting_url(&other));
+
+        let lookalike = Url::parse("https://meet.google.com.example.com/abc-defg-hij").unwrap();
+        assert!(!is_meeting_url(&lookalike));
     }
 
     #[test]
@@ -1861,43 +7961,91 @@ mod tests {
             "https://meet.google.com/lookup/ab_cd-EF12?meetcatAuto=1"
         );
     }
+
+    #[test]
+    fn test_build_join_meeting_url_rejects_invalid_code() {
+        assert!(build_join_meeting_url("../evil", false).is_err());
+        assert!(build_join_meeting_url("lookup/a/b", false).is_err());
+    }
+
+    #[test]
+    fn test_deep_link_meeting_json_hides_meeting_in_privacy_mode() {
+        let now = chrono::Utc::now();
+        let meeting = fixtures::meeting_at("abc-defg-hij", "Board Review", now, 10, 30);
+
+        let shown = deep_link_meeting_json(&meeting, now, false);
+        assert_eq!(shown["title"], "Board Review");
+        assert_eq!(shown["callId"], "abc-defg-hij");
+
+        let hidden = deep_link_meeting_json(&meeting, now, true);
+        assert!(hidden.get("title").is_none());
+        assert!(hidden.get("callId").is_none());
+        assert!(hidden.get("url").is_none());
+        assert_eq!(hidden["startsInMinutes"], shown["startsInMinutes"]);
+    }
+}
+
+/// Login item that relaunches with the same data directory
+fn autostart_plugin<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
+    let builder = tauri_plugin_autostart::Builder::new().args(
+        paths::data_dir()
+            .map(|dir| dir.relaunch_args())
+            .unwrap_or_default(),
+    );
+    #[cfg(target_os = "macos")]
+    let builder = builder.macos_launcher(tauri_plugin_autostart::MacosLauncher::AppleScript);
+    builder.build()
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    if let Some(code) = run_cli_from_args() {
+        std::process::exit(code);
+    }
+    #[cfg(not(target_os = "macos"))]
+    if let Some(code) = forward_launch_urls() {
+        std::process::exit(code);
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_window_state::Builder::default().build())
         .plugin(tauri_plugin_opener::init())
-        .plugin(tauri_plugin_autostart::init(
-            tauri_plugin_autostart::MacosLauncher::AppleScript,
-            None,
-        ))
+        .plugin(autostart_plugin())
+        .plugin(shortcuts::plugin())
         .manage(AppState::default())
         .on_page_load(|webview, payload| {
-            if payload.event() != PageLoadEvent::Finished {
+            // Profile windows only get the scripts; page-load tracking and
+            // deep links belong to the main window
+            if !profiles::is_meet_window(webview.label()) {
                 return;
             }
+            let is_main = webview.label() == profiles::MAIN_WINDOW;
 
-            if webview.label() != "main" {
-                return;
+            if is_main {
+                record_page_load(webview.app_handle(), payload.event(), payload.url());
             }
 
-            let url = payload.url();
-            if url.host_str() != Some("meet.google.com") {
+            if payload.event() != PageLoadEvent::Finished {
                 return;
             }
 
-            // First time meet.google.com finishes loading on the main window:
-            // drain any deep-link action that was queued during cold start.
+            // First time the main window finishes loading, on Meet or on a
+            // configured startup page: drain any deep-link action that was
+            // queued during cold start.
             let app_handle = webview.app_handle().clone();
             if let Some(state) = app_handle.try_state::<AppState>() {
-                if !state.main_first_load_done.swap(true, Ordering::AcqRel) {
+                if is_main && !state.main_first_load_done.swap(true, Ordering::AcqRel) {
                     drain_pending_deep_link(&app_handle);
                 }
             }
 
+            let url = payload.url();
+            if !url_norm::is_allowed_host(url) {
+                return;
+            }
+
             let webview = webview.clone();
             let url_str = url.to_string();
 
@@ -1917,82 +8065,64 @@ pub fn run() {
             });
         })
         .setup(|app| {
+            locks::set_poison_logger(app.state::<AppState>().logger.clone());
+
+            if let Some(data_dir) = paths::data_dir() {
+                log_app_event(
+                    app.handle(),
+                    LogLevel::Info,
+                    "app",
+                    "paths.data_dir",
+                    None,
+                    serde_json::to_value(data_dir).ok(),
+                );
+            }
+
             // Set up system tray
             tray::setup_tray(app)?;
 
-            #[cfg(target_os = "macos")]
-            {
-                apply_macos_menu(&app.handle(), false)?;
-                if let Some(state) = app.try_state::<AppState>() {
-                    if let Ok(mut homepage) = state.homepage_active.lock() {
-                        *homepage = Some(false);
+            apply_global_shortcuts(app.handle());
+
+            apply_app_menu(app.handle(), false)?;
+            if let Some(state) = app.try_state::<AppState>() {
+                *state.homepage_active.lock_or_recover() = Some(false);
+            }
+            app.on_menu_event(|app, event| match event.id().as_ref() {
+                "app-quit" => app.exit(0),
+                "app-settings" => {
+                    if let Err(e) = ensure_settings_window(app) {
+                        eprintln!("Failed to open settings window: {}", e);
                     }
                 }
-                app.on_menu_event(|app, event| match event.id().as_ref() {
-                    "app-quit" => app.exit(0),
-                    "app-settings" => {
-                        if let Err(e) = ensure_settings_window(app) {
-                            eprintln!("Failed to open settings window: {}", e);
-                        }
-                    }
-                    "app-go-home" => {
-                        if let Err(e) = navigate_to_meet_home(app) {
-                            eprintln!("Failed to navigate to Google Meet home: {}", e);
-                        }
+                "app-go-home" => {
+                    if let Err(e) = navigate_to_meet_home(app) {
+                        eprintln!("Failed to navigate to Google Meet home: {}", e);
                     }
-                    "app-refresh-home" => {
-                        if let Err(e) = navigate_to_meet_home(app) {
-                            eprintln!("Failed to refresh homepage: {}", e);
-                        }
+                }
+                "app-go-startup" => {
+                    if let Err(e) = navigate_to_startup_page(app) {
+                        eprintln!("Failed to navigate to startup page: {}", e);
                     }
-                    "app-help-url-scheme" => {
-                        open_url_scheme_help(app);
+                }
+                "app-refresh-home" => {
+                    if let Err(e) = navigate_to_meet_home(app) {
+                        eprintln!("Failed to refresh homepage: {}", e);
                     }
-                    _ => {}
-                });
-            }
+                }
+                "app-help-url-scheme" => {
+                    open_url_scheme_help(app);
+                }
+                _ => {}
+            });
 
             // Set up script injection
             setup_script_injection(app.handle());
 
-            // Set up navigation injection
-            setup_navigation_injection(app.handle());
-
             // Create main window with a custom new-window handler
-            let main_config = app
-                .config()
-                .app
-                .windows
-                .iter()
-                .find(|w| w.label == "main")
-                .ok_or_else(|| {
-                    std::io::Error::new(std::io::ErrorKind::NotFound, "Missing main window config")
-                })?;
-
-            let app_handle = app.handle().clone();
-            WebviewWindowBuilder::from_config(app.handle(), main_config)?
-                .on_new_window(move |url, features| {
-                    let _ = features;
-                    let current_url = app_handle
-                        .get_webview_window("main")
-                        .and_then(|window| window.url().ok())
-                        .unwrap_or_else(|| Url::parse("https://meet.google.com/").unwrap());
-
-                    if should_open_external(&current_url, &url) {
-                        let _ = app_handle.opener().open_url(url.as_str(), None::<&str>);
-                        return tauri::webview::NewWindowResponse::Deny;
-                    }
+            build_main_window(app.handle(), false)?;
 
-                    if matches!(url.scheme(), "http" | "https") {
-                        if let Some(window) = app_handle.get_webview_window("main") {
-                            let _ = window.navigate(url.clone());
-                        }
-                    } else {
-                        let _ = app_handle.opener().open_url(url.as_str(), None::<&str>);
-                    }
-                    tauri::webview::NewWindowResponse::Deny
-                })
-                .build()?;
+            // Open the windows of extra account profiles in the background
+            sync_profile_windows(app.handle());
 
             // Set up window lifecycle
             setup_window_lifecycle(app.handle());
@@ -2000,15 +8130,34 @@ pub fn run() {
             // Set up new window handler
             setup_new_window_handler(app.handle());
 
-            // Set up background daemon
-            setup_daemon(app.handle());
+            // Reschedule requests sent before this wait in the channel
+            if let Some(inbox) = app.state::<AppState>().join_trigger.take_inbox() {
+                let trigger_handle = app.handle().clone();
+                tauri::async_runtime::spawn(
+                    inbox.run(move |aborted| schedule_next_join_trigger(&trigger_handle, aborted)),
+                );
+            }
+
+            // Set up background daemon, URL polling and update checks
+            setup_background_tasks(app.handle());
+
+            let power_handle = app.handle().clone();
+            power::watch(Arc::new(move |event| on_power_event(&power_handle, event)));
+
+            // Developer-only scheduler instrumentation
+            start_soak_test(app.handle());
+
+            apply_demo_mode(app.handle());
+
+            report_quarantined_files(app.handle());
+            report_settings_recovery(app.handle());
 
             // Start daemon by default
             {
                 let state = app.state::<AppState>();
-                let mut daemon = state.daemon.lock().unwrap();
+                let mut daemon = state.daemon.lock_or_recover();
                 daemon.start();
-                let mut logger = state.logger.lock().unwrap();
+                let mut logger = state.logger.lock();
                 logger.log_internal(
                     LogLevel::Info,
                     "daemon",
@@ -2018,20 +8167,43 @@ pub fn run() {
                 );
             }
 
-            setup_update_checker(app.handle());
+            #[cfg(not(target_os = "macos"))]
+            setup_launch_deep_links(app.handle());
+
+            start_cli_listener(app.handle());
+            apply_launch_cli_command(app.handle());
+
+            forward_api_events(app.handle());
+            apply_api_server(app.handle());
+            apply_mqtt(app.handle());
 
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
             get_status,
             get_joined_meetings,
+            get_meeting_lifecycles,
             get_suppressed_meetings,
+            clear_suppressed,
+            get_blocked_meetings,
+            meeting_join_blocked,
+            open_meeting_in_browser,
             get_settings,
+            flush_settings,
             save_settings,
+            test_title_filter,
+            set_secret,
+            clear_secret,
+            has_secret,
+            sync_presence_now,
             start_daemon,
             stop_daemon,
             meetings_updated,
             meeting_joined,
+            join_completed,
+            meeting_presence,
+            breakout_entered,
+            breakout_exited,
             meeting_closed,
             open_settings_window,
             navigate_home,
@@ -2043,20 +8215,62 @@ pub fn run() {
             open_update_dialog,
             consume_open_update_dialog_request,
             consume_manual_update_check_request,
+            join_by_code,
+            get_recent_meeting_codes,
+            get_meeting_history,
+            join_countdown_started,
+            consume_join_by_code_dialog_request,
+            set_history_note,
+            consume_history_note_request,
+            cancel_pending_join,
             log_event,
+            capture_webview_screenshot,
+            confirm_resume_auto_join,
+            get_health_report,
+            get_conflicts,
+            get_agenda_view,
+            busy_blocks_updated,
+            explain_join,
+            get_next_trigger,
+            get_window_activity,
+            get_shortcut_status,
+            check_media_permissions,
+            request_media_permissions,
+            open_media_permission_settings,
+            confirm_busy_join,
+            choose_conflict_meeting,
+            get_active_calls,
+            get_background_metrics,
+            get_ipc_schema,
+            get_source_request_metrics,
+            get_log_digest,
+            export_logs,
+            query_logs,
+            run_parser_selftest,
+            parser_selftest_result,
+            script_pong,
+            run_troubleshooter,
+            troubleshoot_probe_result,
         ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
         .run(|app_handle, event| match event {
+            // Launch arguments carry links elsewhere; see setup_launch_deep_links
+            #[cfg(target_os = "macos")]
             tauri::RunEvent::Opened { urls } => {
                 for url in urls {
                     handle_deep_link_url(app_handle, &url);
                 }
             }
+            #[cfg(target_os = "macos")]
             tauri::RunEvent::Reopen { .. } => {
                 focus_main_window_after_reopen(app_handle);
             }
-            tauri::RunEvent::ExitRequested { .. } => {}
+            tauri::RunEvent::ExitRequested { .. } => {
+                if let Err(e) = flush_pending_settings(app_handle) {
+                    eprintln!("[MeetCat] Failed to save settings on exit: {}", e);
+                }
+            }
             _ => {}
         });
 }
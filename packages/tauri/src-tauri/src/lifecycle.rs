@@ -0,0 +1,651 @@
+//! Per-meeting lifecycle state machine
+//!
+//! Every meeting the daemon tracks moves through explicit phases:
+//! `Scheduled → Triggered → Navigating → Joined → Left`, with `Suppressed`,
+//! `Failed` and `Expired` as exits. Transitions are validated against
+//! [`MeetingPhase::can_transition_to`] and timestamped, and each accepted
+//! change is queued as a [`LifecycleEvent`] for the app to publish.
+//!
+//! A joined meeting also keeps what the page last reported about the call
+//! ([`CallPresence`]), which tells a page stuck in the green room apart from
+//! one that is actually in the call, and the breakout room it was moved to,
+//! if any. A meeting stays `Joined` while its page is in a breakout room.
+//! A join whose page hasn't reached the call within [`VERIFY_DEADLINE_MS`]
+//! is handed back once as an [`OverdueJoin`], to be reloaded or reported.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// How long a joined page may take to reach the call; long enough for a host
+/// to let the user in from the green room
+pub const VERIFY_DEADLINE_MS: i64 = 3 * 60 * 1000;
+/// Reloads of a meeting whose page never reported back after joining
+pub const MAX_VERIFY_RETRIES: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeetingPhase {
+    /// Known and waiting for its trigger
+    Scheduled,
+    /// The join trigger fired
+    Triggered,
+    /// The main window is opening the meeting page
+    Navigating,
+    /// The webview reported the meeting as joined
+    Joined,
+    /// The meeting page was closed before the trigger time
+    Left,
+    /// Closed by the user after its trigger time; not joined again automatically
+    Suppressed,
+    /// Google Meet refused the join
+    Failed,
+    /// Ended or no longer reported; the record is dropped
+    Expired,
+}
+
+impl MeetingPhase {
+    pub fn can_transition_to(self, next: MeetingPhase) -> bool {
+        use MeetingPhase::*;
+        match (self, next) {
+            (Expired, _) => false,
+            (_, Expired) => true,
+            (Scheduled, Triggered | Navigating | Joined | Suppressed | Failed) => true,
+            (Triggered, Navigating | Joined | Left | Suppressed | Failed) => true,
+            (Navigating, Joined | Left | Suppressed | Failed) => true,
+            // Reloaded after the join couldn't be verified
+            (Joined, Navigating | Left | Suppressed | Failed) => true,
+            (Left, Triggered | Navigating | Joined | Suppressed | Failed) => true,
+            (Suppressed, Navigating | Joined | Failed) => true,
+            // Manual retry, e.g. after switching accounts
+            (Failed, Navigating | Joined) => true,
+            _ => false,
+        }
+    }
+}
+
+/// One accepted phase change
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PhaseTransition {
+    /// `None` when the record was created
+    pub from: Option<MeetingPhase>,
+    pub to: MeetingPhase,
+    pub at_ms: i64,
+}
+
+/// What the meeting page reported about the call after joining
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallPresence {
+    /// A local microphone or camera track is live. The green room preview
+    /// is live too, so this alone doesn't prove the call was entered.
+    pub local_media_live: bool,
+    /// Participant count shown by Meet, which it only shows inside a call
+    pub participant_count: Option<u32>,
+    /// Whether the mic button shows muted; `None` when it wasn't found
+    #[serde(default)]
+    pub mic_muted: Option<bool>,
+    /// Whether the camera button shows off; `None` when it wasn't found
+    #[serde(default)]
+    pub camera_muted: Option<bool>,
+}
+
+impl CallPresence {
+    pub fn verification(&self) -> JoinVerification {
+        if self.participant_count.is_some_and(|count| count > 0) {
+            JoinVerification::InCall
+        } else {
+            JoinVerification::GreenRoom
+        }
+    }
+}
+
+/// How far a joined meeting got, according to the page
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JoinVerification {
+    /// Nothing reported since the join
+    Unverified,
+    /// The page loaded but the call wasn't entered, e.g. waiting to be let in
+    GreenRoom,
+    /// In the call
+    InCall,
+}
+
+/// Breakout room a joined meeting's page was moved to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakoutRoom {
+    /// Room name shown by Meet, if the page could read it
+    pub name: Option<String>,
+    pub entered_at_ms: i64,
+}
+
+/// Current phase of a meeting and how it got there
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct MeetingLifecycle {
+    pub phase: MeetingPhase,
+    pub transitions: Vec<PhaseTransition>,
+    /// Latest page report since the meeting was last joined
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub presence: Option<CallPresence>,
+    /// When the page first reported being in the call since the last join
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_call_at_ms: Option<i64>,
+    /// Breakout room the page is in; cleared when the meeting leaves `Joined`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub breakout: Option<BreakoutRoom>,
+    /// When the last join missed the verification deadline
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verify_overdue_at_ms: Option<i64>,
+    /// Reloads spent on joins that missed the verification deadline
+    #[serde(default)]
+    pub verify_retries: u32,
+}
+
+impl MeetingLifecycle {
+    fn new(at_ms: i64) -> Self {
+        Self {
+            phase: MeetingPhase::Scheduled,
+            transitions: vec![PhaseTransition {
+                from: None,
+                to: MeetingPhase::Scheduled,
+                at_ms,
+            }],
+            presence: None,
+            in_call_at_ms: None,
+            breakout: None,
+            verify_overdue_at_ms: None,
+            verify_retries: 0,
+        }
+    }
+
+    /// Whether the last join reached the call. Once in the call, a later
+    /// report without a participant count doesn't undo it.
+    pub fn verification(&self) -> JoinVerification {
+        match self.presence {
+            None => JoinVerification::Unverified,
+            Some(_) if self.in_call_at_ms.is_some() => JoinVerification::InCall,
+            Some(presence) => presence.verification(),
+        }
+    }
+
+    /// Whether the meeting was ever in one of `phases`
+    pub fn has_entered(&self, phases: &[MeetingPhase]) -> bool {
+        self.transitions.iter().any(|t| phases.contains(&t.to))
+    }
+
+    /// When the meeting entered its current phase
+    fn phase_since_ms(&self) -> i64 {
+        self.transitions.last().map(|t| t.at_ms).unwrap_or_default()
+    }
+}
+
+/// A joined meeting whose page didn't reach the call by the deadline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverdueJoin {
+    pub call_id: String,
+    pub verification: JoinVerification,
+    /// Reloads already spent on the meeting
+    pub retries: u32,
+}
+
+impl OverdueJoin {
+    /// Only a page that never reported back is reloaded. One in the green
+    /// room may be waiting to be let in, and a reload would lose its place.
+    pub fn should_retry(&self) -> bool {
+        self.verification == JoinVerification::Unverified && self.retries < MAX_VERIFY_RETRIES
+    }
+}
+
+/// Payload of the `meeting:lifecycle` event
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct LifecycleEvent {
+    pub call_id: String,
+    #[serde(flatten)]
+    pub transition: PhaseTransition,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionError {
+    pub call_id: String,
+    pub from: MeetingPhase,
+    pub to: MeetingPhase,
+}
+
+impl fmt::Display for TransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid lifecycle transition for {}: {:?} -> {:?}",
+            self.call_id, self.from, self.to
+        )
+    }
+}
+
+impl std::error::Error for TransitionError {}
+
+#[derive(Debug, Default)]
+pub struct LifecycleTracker {
+    records: HashMap<String, MeetingLifecycle>,
+    pending_events: Vec<LifecycleEvent>,
+}
+
+impl LifecycleTracker {
+    /// Start tracking `call_id` as scheduled; no-op when already tracked
+    pub fn track(&mut self, call_id: &str, at_ms: i64) {
+        if self.records.contains_key(call_id) {
+            return;
+        }
+        let record = MeetingLifecycle::new(at_ms);
+        self.pending_events.push(LifecycleEvent {
+            call_id: call_id.to_string(),
+            transition: record.transitions[0].clone(),
+        });
+        self.records.insert(call_id.to_string(), record);
+    }
+
+    /// Move `call_id` to `to`, tracking it first if needed.
+    ///
+    /// Re-entering the current phase is accepted without recording anything.
+    pub fn transition(
+        &mut self,
+        call_id: &str,
+        to: MeetingPhase,
+        at_ms: i64,
+    ) -> Result<(), TransitionError> {
+        self.track(call_id, at_ms);
+        let record = self.records.get_mut(call_id).expect("tracked above");
+        let from = record.phase;
+        if from == to {
+            return Ok(());
+        }
+        if !from.can_transition_to(to) {
+            return Err(TransitionError {
+                call_id: call_id.to_string(),
+                from,
+                to,
+            });
+        }
+        let transition = PhaseTransition {
+            from: Some(from),
+            to,
+            at_ms,
+        };
+        record.phase = to;
+        record.breakout = None;
+        if to == MeetingPhase::Joined {
+            record.presence = None;
+            record.in_call_at_ms = None;
+            record.verify_overdue_at_ms = None;
+        }
+        record.transitions.push(transition.clone());
+        self.pending_events.push(LifecycleEvent {
+            call_id: call_id.to_string(),
+            transition,
+        });
+        Ok(())
+    }
+
+    /// Store a page report for a joined meeting and return its verification
+    /// when that changed. Reports for meetings not in `Joined` are ignored.
+    pub fn record_presence(
+        &mut self,
+        call_id: &str,
+        presence: CallPresence,
+        at_ms: i64,
+    ) -> Option<JoinVerification> {
+        let record = self
+            .records
+            .get_mut(call_id)
+            .filter(|r| r.phase == MeetingPhase::Joined)?;
+        let before = record.verification();
+        record.presence = Some(presence);
+        if record.in_call_at_ms.is_none() && presence.verification() == JoinVerification::InCall {
+            record.in_call_at_ms = Some(at_ms);
+        }
+        let after = record.verification();
+        (after != before).then_some(after)
+    }
+
+    /// Joined meetings whose page hasn't reached the call within
+    /// [`VERIFY_DEADLINE_MS`] of joining. Each join is returned once; a page
+    /// in a breakout room was in the call and is left alone.
+    pub fn take_overdue_verifications(&mut self, now_ms: i64) -> Vec<OverdueJoin> {
+        let mut overdue = Vec::new();
+        for (call_id, record) in &mut self.records {
+            let due = record.phase == MeetingPhase::Joined
+                && record.verify_overdue_at_ms.is_none()
+                && record.breakout.is_none()
+                && record.verification() != JoinVerification::InCall
+                && now_ms - record.phase_since_ms() >= VERIFY_DEADLINE_MS;
+            if !due {
+                continue;
+            }
+            record.verify_overdue_at_ms = Some(now_ms);
+            overdue.push(OverdueJoin {
+                call_id: call_id.clone(),
+                verification: record.verification(),
+                retries: record.verify_retries,
+            });
+        }
+        overdue
+    }
+
+    /// Reload overdue joined meeting `call_id`, counting the retry
+    pub fn retry_join(&mut self, call_id: &str, at_ms: i64) -> Result<(), TransitionError> {
+        self.transition(call_id, MeetingPhase::Navigating, at_ms)?;
+        if let Some(record) = self.records.get_mut(call_id) {
+            record.verify_retries += 1;
+        }
+        Ok(())
+    }
+
+    /// Record that the page of joined meeting `call_id` moved to a breakout
+    /// room; returns false when the meeting isn't joined or is already in
+    /// that room
+    pub fn enter_breakout(&mut self, call_id: &str, name: Option<String>, at_ms: i64) -> bool {
+        let Some(record) = self
+            .records
+            .get_mut(call_id)
+            .filter(|r| r.phase == MeetingPhase::Joined)
+        else {
+            return false;
+        };
+        if record
+            .breakout
+            .as_ref()
+            .is_some_and(|room| room.name == name)
+        {
+            return false;
+        }
+        record.breakout = Some(BreakoutRoom {
+            name,
+            entered_at_ms: at_ms,
+        });
+        true
+    }
+
+    /// Record that the page of `call_id` returned to the main call,
+    /// returning the room it left
+    pub fn exit_breakout(&mut self, call_id: &str) -> Option<BreakoutRoom> {
+        self.records.get_mut(call_id)?.breakout.take()
+    }
+
+    /// Whether the page of `call_id` is in a breakout room
+    pub fn in_breakout(&self, call_id: &str) -> bool {
+        self.records
+            .get(call_id)
+            .is_some_and(|r| r.breakout.is_some())
+    }
+
+    pub fn phase(&self, call_id: &str) -> Option<MeetingPhase> {
+        self.records.get(call_id).map(|r| r.phase)
+    }
+
+    pub fn get(&self, call_id: &str) -> Option<&MeetingLifecycle> {
+        self.records.get(call_id)
+    }
+
+    pub fn all(&self) -> &HashMap<String, MeetingLifecycle> {
+        &self.records
+    }
+
+    /// Whether `call_id` was ever in one of `phases`
+    pub fn has_entered(&self, call_id: &str, phases: &[MeetingPhase]) -> bool {
+        self.records
+            .get(call_id)
+            .is_some_and(|r| r.has_entered(phases))
+    }
+
+    /// Call IDs that were ever in one of `phases`
+    pub fn ids_entered(&self, phases: &[MeetingPhase]) -> Vec<String> {
+        self.records
+            .iter()
+            .filter(|(_, r)| r.has_entered(phases))
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    /// Expire and drop every record `keep` rejects
+    pub fn expire_unless(&mut self, keep: impl Fn(&str) -> bool, at_ms: i64) {
+        let expired: Vec<String> = self
+            .records
+            .keys()
+            .filter(|id| !keep(id))
+            .cloned()
+            .collect();
+        for call_id in expired {
+            if let Some(record) = self.records.remove(&call_id) {
+                self.pending_events.push(LifecycleEvent {
+                    call_id,
+                    transition: PhaseTransition {
+                        from: Some(record.phase),
+                        to: MeetingPhase::Expired,
+                        at_ms,
+                    },
+                });
+            }
+        }
+    }
+
+    /// Replace all records with previously saved ones, without queueing events
+    pub fn restore(&mut self, records: HashMap<String, MeetingLifecycle>) {
+        self.records = records;
+    }
+
+    /// Whether changes are waiting to be drained
+    pub fn has_pending_events(&self) -> bool {
+        !self.pending_events.is_empty()
+    }
+
+    /// Take the changes recorded since the last call
+    pub fn drain_events(&mut self) -> Vec<LifecycleEvent> {
+        std::mem::take(&mut self.pending_events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use MeetingPhase::*;
+
+    #[test]
+    fn test_transitions_are_validated_and_timestamped() {
+        let mut tracker = LifecycleTracker::default();
+        tracker.track("abc", 100);
+        tracker.transition("abc", Triggered, 200).unwrap();
+        tracker.transition("abc", Navigating, 210).unwrap();
+        tracker.transition("abc", Navigating, 220).unwrap();
+        tracker.transition("abc", Joined, 300).unwrap();
+
+        assert_eq!(
+            tracker.transition("abc", Triggered, 400),
+            Err(TransitionError {
+                call_id: "abc".to_string(),
+                from: Joined,
+                to: Triggered,
+            })
+        );
+        tracker.transition("abc", Suppressed, 500).unwrap();
+
+        let record = tracker.get("abc").unwrap();
+        assert_eq!(record.phase, Suppressed);
+        let steps: Vec<(Option<MeetingPhase>, MeetingPhase, i64)> = record
+            .transitions
+            .iter()
+            .map(|t| (t.from, t.to, t.at_ms))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                (None, Scheduled, 100),
+                (Some(Scheduled), Triggered, 200),
+                (Some(Triggered), Navigating, 210),
+                (Some(Navigating), Joined, 300),
+                (Some(Joined), Suppressed, 500),
+            ]
+        );
+        assert!(tracker.has_entered("abc", &[Joined]));
+        assert!(!tracker.has_entered("abc", &[Failed]));
+        assert_eq!(tracker.drain_events().len(), 5);
+        assert!(tracker.drain_events().is_empty());
+    }
+
+    #[test]
+    fn test_presence_tells_green_room_from_call() {
+        let green_room = CallPresence {
+            local_media_live: true,
+            participant_count: None,
+            mic_muted: Some(true),
+            camera_muted: Some(false),
+        };
+        let in_call = CallPresence {
+            local_media_live: false,
+            participant_count: Some(3),
+            mic_muted: None,
+            camera_muted: None,
+        };
+        let mut tracker = LifecycleTracker::default();
+        tracker.transition("abc", Navigating, 100).unwrap();
+        assert_eq!(tracker.record_presence("abc", in_call, 150), None);
+
+        tracker.transition("abc", Joined, 200).unwrap();
+        assert_eq!(
+            tracker.get("abc").unwrap().verification(),
+            JoinVerification::Unverified
+        );
+        assert_eq!(
+            tracker.record_presence("abc", green_room, 210),
+            Some(JoinVerification::GreenRoom)
+        );
+        assert_eq!(tracker.record_presence("abc", green_room, 220), None);
+        assert_eq!(
+            tracker.record_presence("abc", in_call, 300),
+            Some(JoinVerification::InCall)
+        );
+        // The participant badge can disappear briefly, e.g. while the people
+        // panel re-renders
+        assert_eq!(tracker.record_presence("abc", green_room, 310), None);
+        assert_eq!(tracker.get("abc").unwrap().in_call_at_ms, Some(300));
+
+        // Rejoining starts over
+        tracker.transition("abc", Left, 400).unwrap();
+        tracker.transition("abc", Joined, 500).unwrap();
+        let record = tracker.get("abc").unwrap();
+        assert_eq!(record.verification(), JoinVerification::Unverified);
+        assert_eq!(record.in_call_at_ms, None);
+    }
+
+    #[test]
+    fn test_joins_not_in_call_by_the_deadline_are_overdue() {
+        let green_room = CallPresence {
+            local_media_live: true,
+            participant_count: None,
+            mic_muted: Some(false),
+            camera_muted: Some(false),
+        };
+        let in_call = CallPresence {
+            participant_count: Some(4),
+            ..green_room
+        };
+        let mut tracker = LifecycleTracker::default();
+        for call_id in ["silent", "lobby", "admitted"] {
+            tracker.transition(call_id, Joined, 0).unwrap();
+        }
+        tracker.record_presence("lobby", green_room, 1_000);
+        tracker.record_presence("admitted", green_room, 1_000);
+        tracker.record_presence("admitted", in_call, 60_000);
+
+        assert!(tracker
+            .take_overdue_verifications(VERIFY_DEADLINE_MS - 1)
+            .is_empty());
+        let mut overdue = tracker.take_overdue_verifications(VERIFY_DEADLINE_MS);
+        overdue.sort_by(|a, b| a.call_id.cmp(&b.call_id));
+        assert_eq!(
+            overdue,
+            vec![
+                OverdueJoin {
+                    call_id: "lobby".to_string(),
+                    verification: JoinVerification::GreenRoom,
+                    retries: 0,
+                },
+                OverdueJoin {
+                    call_id: "silent".to_string(),
+                    verification: JoinVerification::Unverified,
+                    retries: 0,
+                },
+            ]
+        );
+        // A page in the green room isn't reloaded, one that never reported is
+        assert!(!overdue[0].should_retry());
+        assert!(overdue[1].should_retry());
+        // Each join is handed back once
+        assert!(tracker
+            .take_overdue_verifications(VERIFY_DEADLINE_MS * 2)
+            .is_empty());
+
+        // The reload joins again with a fresh deadline, and isn't retried twice
+        tracker.retry_join("silent", 200_000).unwrap();
+        assert_eq!(tracker.phase("silent"), Some(Navigating));
+        tracker.transition("silent", Joined, 210_000).unwrap();
+        let overdue = tracker.take_overdue_verifications(210_000 + VERIFY_DEADLINE_MS);
+        assert_eq!(overdue.len(), 1);
+        assert_eq!(overdue[0].retries, 1);
+        assert!(!overdue[0].should_retry());
+    }
+
+    #[test]
+    fn test_expired_is_terminal_and_drops_record() {
+        assert!(!Expired.can_transition_to(Scheduled));
+        assert!(Failed.can_transition_to(Expired));
+        assert!(!Failed.can_transition_to(Suppressed));
+
+        let mut tracker = LifecycleTracker::default();
+        tracker.transition("old", Failed, 100).unwrap();
+        tracker.track("new", 100);
+        tracker.drain_events();
+
+        tracker.expire_unless(|id| id == "new", 900);
+        assert_eq!(tracker.phase("old"), None);
+        assert_eq!(tracker.phase("new"), Some(Scheduled));
+        assert_eq!(
+            tracker.drain_events(),
+            vec![LifecycleEvent {
+                call_id: "old".to_string(),
+                transition: PhaseTransition {
+                    from: Some(Failed),
+                    to: Expired,
+                    at_ms: 900,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_breakout_keeps_meeting_joined() {
+        let mut tracker = LifecycleTracker::default();
+        assert!(!tracker.enter_breakout("abc", None, 100));
+        tracker.transition("abc", Joined, 100).unwrap();
+        tracker.drain_events();
+
+        assert!(tracker.enter_breakout("abc", Some("Room 1".to_string()), 200));
+        assert!(!tracker.enter_breakout("abc", Some("Room 1".to_string()), 250));
+        assert!(tracker.enter_breakout("abc", Some("Room 2".to_string()), 300));
+        assert!(tracker.in_breakout("abc"));
+        assert_eq!(tracker.phase("abc"), Some(Joined));
+        assert!(tracker.drain_events().is_empty());
+
+        let room = tracker.exit_breakout("abc").unwrap();
+        assert_eq!(room.name.as_deref(), Some("Room 2"));
+        assert_eq!(room.entered_at_ms, 300);
+        assert!(!tracker.in_breakout("abc"));
+
+        tracker.enter_breakout("abc", None, 400);
+        tracker.transition("abc", Suppressed, 500).unwrap();
+        assert!(!tracker.in_breakout("abc"));
+    }
+}
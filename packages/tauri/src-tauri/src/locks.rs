@@ -0,0 +1,109 @@
+//! Poisoned-lock recovery
+//!
+//! A panic while a lock is held poisons it, and `lock().unwrap()` would then
+//! panic in every command that touches the same state for the rest of the
+//! session. State behind these locks is plain data that stays usable after a
+//! panic cut an update short, so the lock is recovered instead: the poison is
+//! cleared, `state.lock_poisoned` is logged and the caller carries on.
+
+use crate::logging::LoggerHandle;
+use crate::settings::LogLevel;
+use serde_json::json;
+use std::panic::Location;
+use std::sync::{Mutex, MutexGuard, OnceLock, PoisonError};
+
+/// Where recovered locks are reported; set once the app state exists
+static POISON_LOGGER: OnceLock<LoggerHandle> = OnceLock::new();
+
+/// Report recovered locks through `logger`
+pub fn set_poison_logger(logger: LoggerHandle) {
+    let _ = POISON_LOGGER.set(logger);
+}
+
+pub trait LockExt<T> {
+    /// Lock, recovering the data if a panic poisoned the lock
+    fn lock_or_recover(&self) -> MutexGuard<'_, T>;
+}
+
+impl<T> LockExt<T> for Mutex<T> {
+    #[track_caller]
+    fn lock_or_recover(&self) -> MutexGuard<'_, T> {
+        match self.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                // Released before reporting, since the poisoned lock may be
+                // the logger's own
+                drop(poisoned.into_inner());
+                self.clear_poison();
+                report_poisoned(std::any::type_name::<T>(), Location::caller());
+                self.lock().unwrap_or_else(PoisonError::into_inner)
+            }
+        }
+    }
+}
+
+fn report_poisoned(lock: &str, location: &Location<'_>) {
+    eprintln!(
+        "[MeetCat] Recovered a lock poisoned by a panic: {} at {}",
+        lock, location
+    );
+    if let Some(logger) = POISON_LOGGER.get() {
+        logger.log(
+            LogLevel::Error,
+            "state",
+            "state.lock_poisoned",
+            None,
+            Some(json!({
+                "lock": lock,
+                "location": location.to_string(),
+            })),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::DaemonState;
+    use crate::fixtures;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_lock_recovers_after_panic_while_held() {
+        let state = Arc::new(Mutex::new(DaemonState::default()));
+        state
+            .lock_or_recover()
+            .update_meetings(vec![fixtures::meeting_at(
+                "abc-defg-hij",
+                "Standup",
+                chrono::Utc::now(),
+                10,
+                30,
+            )]);
+
+        // A command panics halfway through its update
+        let panicking = Arc::clone(&state);
+        let result = std::thread::spawn(move || {
+            let mut daemon = panicking.lock_or_recover();
+            daemon.update_meetings(Vec::new());
+            panic!("command failed while holding the daemon lock");
+        })
+        .join();
+        assert!(result.is_err());
+        assert!(state.is_poisoned());
+
+        // Later commands still get the state, and the poison is gone
+        assert!(state.lock_or_recover().get_meetings().is_empty());
+        assert!(!state.is_poisoned());
+        state
+            .lock_or_recover()
+            .update_meetings(vec![fixtures::meeting_at(
+                "xyz-wxyz-xyz",
+                "Planning",
+                chrono::Utc::now(),
+                20,
+                30,
+            )]);
+        assert_eq!(state.lock().unwrap().get_meetings().len(), 1);
+    }
+}
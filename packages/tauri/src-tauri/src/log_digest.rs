@@ -0,0 +1,379 @@
+//! Weekly summaries of the JSONL logs
+//!
+//! Raw logs are kept for a few days only. Before they are pruned, each
+//! completed day is folded into a compact digest for its ISO week (event
+//! counts, error highlights and the join success rate), stored under
+//! `logs/digests/`. The digest records which raw files it already contains,
+//! so folding is idempotent and can run on every cleanup.
+
+use crate::persistence::{self, VersionedState};
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Digests older than this are deleted
+const DIGEST_RETENTION_WEEKS: i64 = 52;
+/// Distinct error events kept per week
+const MAX_ERROR_HIGHLIGHTS: usize = 10;
+
+const DIGEST_DIR: &str = "digests";
+
+/// Summary of one ISO week of logs
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogDigest {
+    /// ISO week, e.g. `2026-W42`
+    pub week: String,
+    pub updated_at_ms: u64,
+    /// Raw log files folded into this digest
+    pub sources: Vec<String>,
+    pub total_entries: u64,
+    /// Entry count per level
+    pub levels: BTreeMap<String, u64>,
+    /// Entry count per `module.event`
+    pub events: BTreeMap<String, u64>,
+    /// Most frequent error events, most frequent first
+    pub errors: Vec<ErrorHighlight>,
+    pub joins: JoinSummary,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorHighlight {
+    pub module: String,
+    pub event: String,
+    /// Most recent message seen for this event
+    pub message: Option<String>,
+    pub count: u64,
+    pub last_ts_ms: u64,
+}
+
+/// Auto-join attempts and how many reached the meeting
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JoinSummary {
+    pub attempts: u64,
+    pub succeeded: u64,
+    pub success_rate: Option<f64>,
+}
+
+impl VersionedState for LogDigest {
+    const CURRENT_VERSION: u32 = 1;
+
+    fn migrate(from_version: u32, _data: Value) -> Result<Value, String> {
+        Err(format!(
+            "no digest format before version {}",
+            from_version + 1
+        ))
+    }
+}
+
+/// Outcome of a digest pass
+#[derive(Debug, Default)]
+pub struct DigestRun {
+    /// Raw files newly folded in
+    pub folded: Vec<String>,
+    /// Weeks whose digest was updated
+    pub weeks: Vec<String>,
+    pub pruned_digests: usize,
+    pub quarantined: Vec<persistence::QuarantinedFile>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawEntry {
+    ts_ms: u64,
+    level: String,
+    module: String,
+    event: String,
+    message: Option<String>,
+    join_id: Option<String>,
+}
+
+/// ISO week key for `date`, e.g. `2026-W42`
+pub fn week_key(date: NaiveDate) -> String {
+    let week = date.iso_week();
+    format!("{}-W{:02}", week.year(), week.week())
+}
+
+/// Parse a week key, returning its Monday
+pub fn parse_week_key(week: &str) -> Option<NaiveDate> {
+    let (year, number) = week.split_once("-W")?;
+    NaiveDate::from_isoywd_opt(
+        year.parse().ok()?,
+        number.parse().ok()?,
+        chrono::Weekday::Mon,
+    )
+}
+
+/// Load the stored digest for `week`
+pub fn load_digest(log_dir: &Path, week: &str, now_ms: u64) -> Option<LogDigest> {
+    let path = digest_path(log_dir, week);
+    if !path.exists() {
+        return None;
+    }
+    let loaded = persistence::load_versioned::<LogDigest>(&path, now_ms);
+    loaded.quarantined.is_none().then_some(loaded.state)
+}
+
+/// Fold raw files for days before `today` into their week's digest, then
+/// delete digests past retention
+pub fn fold_completed_days(log_dir: &Path, today: NaiveDate, now_ms: u64) -> DigestRun {
+    let mut run = DigestRun::default();
+    let mut by_week: BTreeMap<String, Vec<(String, PathBuf)>> = BTreeMap::new();
+    if let Ok(entries) = fs::read_dir(log_dir) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let Some(date) = raw_file_date(&name) else {
+                continue;
+            };
+            if date < today {
+                by_week
+                    .entry(week_key(date))
+                    .or_default()
+                    .push((name, entry.path()));
+            }
+        }
+    }
+
+    let digest_dir = log_dir.join(DIGEST_DIR);
+    for (week, mut files) in by_week {
+        let path = digest_path(log_dir, &week);
+        let loaded = persistence::load_versioned::<LogDigest>(&path, now_ms);
+        run.quarantined.extend(loaded.quarantined);
+        let mut digest = loaded.state;
+        digest.week = week.clone();
+
+        files.sort();
+        let mut changed = false;
+        for (name, file_path) in files {
+            if digest.sources.contains(&name) {
+                continue;
+            }
+            let Ok(content) = fs::read_to_string(&file_path) else {
+                continue;
+            };
+            fold_lines(&mut digest, content.lines());
+            digest.sources.push(name.clone());
+            run.folded.push(name);
+            changed = true;
+        }
+        if changed {
+            digest.updated_at_ms = now_ms;
+            if fs::create_dir_all(&digest_dir).is_ok()
+                && persistence::save_versioned(&path, &digest).is_ok()
+            {
+                run.weeks.push(week);
+            }
+        }
+    }
+
+    run.pruned_digests = prune_digests(&digest_dir, today);
+    run
+}
+
+/// Add JSONL `lines` to `digest`; unparseable lines are skipped
+fn fold_lines<'a>(digest: &mut LogDigest, lines: impl Iterator<Item = &'a str>) {
+    for line in lines {
+        let Ok(entry) = serde_json::from_str::<RawEntry>(line) else {
+            continue;
+        };
+        digest.total_entries += 1;
+        *digest.levels.entry(entry.level.clone()).or_default() += 1;
+        *digest
+            .events
+            .entry(format!("{}.{}", entry.module, entry.event))
+            .or_default() += 1;
+
+        if entry.join_id.is_some() {
+            match (entry.module.as_str(), entry.event.as_str()) {
+                ("join", "trigger.fired") => digest.joins.attempts += 1,
+                ("meetings", "meeting.joined") => digest.joins.succeeded += 1,
+                _ => {}
+            }
+        }
+
+        if entry.level == "error" {
+            add_error(&mut digest.errors, entry);
+        }
+    }
+
+    let joins = &mut digest.joins;
+    joins.success_rate =
+        (joins.attempts > 0).then(|| (joins.succeeded as f64 / joins.attempts as f64).min(1.0));
+    digest
+        .errors
+        .sort_by(|a, b| b.count.cmp(&a.count).then(b.last_ts_ms.cmp(&a.last_ts_ms)));
+    digest.errors.truncate(MAX_ERROR_HIGHLIGHTS);
+}
+
+fn add_error(errors: &mut Vec<ErrorHighlight>, entry: RawEntry) {
+    if let Some(existing) = errors
+        .iter_mut()
+        .find(|e| e.module == entry.module && e.event == entry.event)
+    {
+        existing.count += 1;
+        if entry.ts_ms >= existing.last_ts_ms {
+            existing.last_ts_ms = entry.ts_ms;
+            existing.message = entry.message.or(existing.message.take());
+        }
+        return;
+    }
+    errors.push(ErrorHighlight {
+        module: entry.module,
+        event: entry.event,
+        message: entry.message,
+        count: 1,
+        last_ts_ms: entry.ts_ms,
+    });
+}
+
+/// Date of a raw log file named `meetcat-YYYY-MM-DD.jsonl`
+fn raw_file_date(name: &str) -> Option<NaiveDate> {
+    let date = name.strip_prefix("meetcat-")?.strip_suffix(".jsonl")?;
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+fn digest_path(log_dir: &Path, week: &str) -> PathBuf {
+    log_dir
+        .join(DIGEST_DIR)
+        .join(format!("meetcat-digest-{}.json", week))
+}
+
+fn prune_digests(digest_dir: &Path, today: NaiveDate) -> usize {
+    let Ok(entries) = fs::read_dir(digest_dir) else {
+        return 0;
+    };
+    let cutoff = today - chrono::Duration::weeks(DIGEST_RETENTION_WEEKS);
+    let mut pruned = 0;
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let expired = name
+            .strip_prefix("meetcat-digest-")
+            .and_then(|rest| rest.strip_suffix(".json"))
+            .and_then(parse_week_key)
+            .is_some_and(|monday| monday < cutoff);
+        if expired && fs::remove_file(entry.path()).is_ok() {
+            pruned += 1;
+        }
+    }
+    pruned
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(level: &str, module: &str, event: &str, ts_ms: u64, join_id: Option<&str>) -> String {
+        serde_json::json!({
+            "tsMs": ts_ms,
+            "level": level,
+            "scope": "tauri",
+            "module": module,
+            "event": event,
+            "message": format!("{} at {}", event, ts_ms),
+            "sessionId": "1-0",
+            "joinId": join_id,
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_week_keys() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        assert_eq!(week_key(date), "2026-W42");
+        assert_eq!(
+            parse_week_key("2026-W42"),
+            NaiveDate::from_ymd_opt(2026, 10, 12)
+        );
+        // Early January can belong to the previous ISO year
+        assert_eq!(
+            week_key(NaiveDate::from_ymd_opt(2027, 1, 1).unwrap()),
+            "2026-W53"
+        );
+        assert_eq!(parse_week_key("2026-42"), None);
+    }
+
+    #[test]
+    fn test_fold_counts_errors_and_joins() {
+        let mut digest = LogDigest::default();
+        let lines = [
+            line("info", "join", "trigger.fired", 1, Some("j1")),
+            line("info", "meetings", "meeting.joined", 2, Some("j1")),
+            line("info", "join", "trigger.fired", 3, Some("j2")),
+            line("error", "webview", "inject.failed", 4, None),
+            line("error", "webview", "inject.failed", 6, None),
+            line("error", "updater", "check.failed", 5, None),
+            // Manual joins have no join id and aren't counted
+            line("info", "meetings", "meeting.joined", 7, None),
+            "not json".to_string(),
+        ];
+        fold_lines(&mut digest, lines.iter().map(String::as_str));
+
+        assert_eq!(digest.total_entries, 7);
+        assert_eq!(digest.levels["error"], 3);
+        assert_eq!(digest.events["meetings.meeting.joined"], 2);
+        assert_eq!(digest.joins.attempts, 2);
+        assert_eq!(digest.joins.succeeded, 1);
+        assert_eq!(digest.joins.success_rate, Some(0.5));
+        assert_eq!(digest.errors.len(), 2);
+        assert_eq!(digest.errors[0].event, "inject.failed");
+        assert_eq!(digest.errors[0].count, 2);
+        assert_eq!(
+            digest.errors[0].message.as_deref(),
+            Some("inject.failed at 6")
+        );
+    }
+
+    #[test]
+    fn test_fold_completed_days_is_idempotent() {
+        let dir = std::env::temp_dir().join(format!("meetcat-digest-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let write = |name: &str, lines: &[String]| {
+            fs::write(dir.join(name), lines.join("\n")).unwrap();
+        };
+        write(
+            "meetcat-2026-10-12.jsonl",
+            &[line("info", "join", "trigger.fired", 1, Some("j1"))],
+        );
+        write(
+            "meetcat-2026-10-13.jsonl",
+            &[line("info", "meetings", "meeting.joined", 2, Some("j1"))],
+        );
+        // Today's file is still being written
+        write(
+            "meetcat-2026-10-17.jsonl",
+            &[line("info", "app", "startup", 3, None)],
+        );
+        fs::create_dir_all(dir.join(DIGEST_DIR)).unwrap();
+        fs::write(
+            dir.join(DIGEST_DIR).join("meetcat-digest-2025-W01.json"),
+            "{}",
+        )
+        .unwrap();
+
+        let today = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        let run = fold_completed_days(&dir, today, 100);
+        assert_eq!(
+            run.folded,
+            vec!["meetcat-2026-10-12.jsonl", "meetcat-2026-10-13.jsonl"]
+        );
+        assert_eq!(run.weeks, vec!["2026-W42"]);
+        assert_eq!(run.pruned_digests, 1);
+
+        let again = fold_completed_days(&dir, today, 200);
+        assert!(again.folded.is_empty());
+
+        let digest = load_digest(&dir, "2026-W42", 300).unwrap();
+        assert_eq!(digest.total_entries, 2);
+        assert_eq!(digest.updated_at_ms, 100);
+        assert_eq!(digest.joins.success_rate, Some(1.0));
+        assert!(load_digest(&dir, "2026-W41", 300).is_none());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -1,22 +1,32 @@
 //! Log collection and persistence for MeetCat
 
-use crate::settings::{LogLevel, Settings};
+use crate::locks::LockExt;
+use crate::log_digest::{self, DigestRun, LogDigest};
+use crate::paths;
+use crate::settings::{LogLevel, Settings, UnknownLogLevel};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fs::{self, OpenOptions};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 const LOG_RETENTION_DAYS: u64 = 3;
 const CLEANUP_INTERVAL_MS: u64 = 6 * 60 * 60 * 1000;
+/// Join attempts tracked for correlation at once; the oldest is dropped first
+const MAX_ACTIVE_JOINS: usize = 16;
+/// Page size of `query_logs` when the query doesn't set one
+const DEFAULT_QUERY_LIMIT: usize = 100;
+const MAX_QUERY_LIMIT: usize = 1000;
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LogEventInput {
-    pub level: LogLevel,
+    /// Level name from the webview; unknown names are rejected
+    pub level: String,
     pub module: String,
     pub event: String,
     pub message: Option<String>,
@@ -25,9 +35,97 @@ pub struct LogEventInput {
     pub scope: Option<String>,
 }
 
+/// App and platform details written into an exported log bundle
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct LogEntry {
+pub struct ExportInfo {
+    pub app_version: String,
+    pub os: String,
+    pub os_version: Option<String>,
+    pub arch: String,
+    pub exported_at_ms: u64,
+}
+
+impl ExportInfo {
+    pub fn current(app_version: &str) -> Self {
+        Self {
+            app_version: app_version.to_string(),
+            os: std::env::consts::OS.to_string(),
+            os_version: os_version(),
+            arch: std::env::consts::ARCH.to_string(),
+            exported_at_ms: now_ms(),
+        }
+    }
+}
+
+/// Filter for `query_logs`. Every field is optional; entries come back
+/// newest first, `limit` at a time starting after `offset` matches.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LogQuery {
+    /// Most verbose level to include, e.g. `warn` for warnings and errors
+    pub level: Option<LogLevel>,
+    pub module: Option<String>,
+    /// Exact event name, or a prefix ending before a `.` such as `join`
+    pub event: Option<String>,
+    pub from_ms: Option<u64>,
+    pub to_ms: Option<u64>,
+    /// Case-insensitive text searched in the event, message and context
+    pub text: Option<String>,
+    pub offset: usize,
+    pub limit: Option<usize>,
+}
+
+impl LogQuery {
+    fn matches(&self, entry: &LogEntry) -> bool {
+        if self
+            .level
+            .as_ref()
+            .is_some_and(|level| !level_allowed(&entry.level, level))
+        {
+            return false;
+        }
+        if self.module.as_ref().is_some_and(|m| *m != entry.module) {
+            return false;
+        }
+        if let Some(event) = &self.event {
+            let prefix_match = entry
+                .event
+                .strip_prefix(event.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'));
+            if !prefix_match {
+                return false;
+            }
+        }
+        if self.from_ms.is_some_and(|from| entry.ts_ms < from)
+            || self.to_ms.is_some_and(|to| entry.ts_ms > to)
+        {
+            return false;
+        }
+        match self.text.as_deref().map(str::trim) {
+            Some(text) if !text.is_empty() => {
+                let needle = text.to_lowercase();
+                let context = entry
+                    .context
+                    .as_ref()
+                    .map(|c| c.to_string())
+                    .unwrap_or_default();
+                [
+                    entry.event.as_str(),
+                    entry.message.as_deref().unwrap_or(""),
+                    &context,
+                ]
+                .iter()
+                .any(|haystack| haystack.to_lowercase().contains(&needle))
+            }
+            _ => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntry {
     ts_ms: u64,
     level: LogLevel,
     scope: String,
@@ -35,7 +133,11 @@ struct LogEntry {
     event: String,
     message: Option<String>,
     context: Option<Value>,
+    #[serde(default)]
     session_id: String,
+    /// Correlation id of the join attempt this entry belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    join_id: Option<String>,
 }
 
 pub struct LogManager {
@@ -45,6 +147,10 @@ pub struct LogManager {
     log_dir: PathBuf,
     last_cleanup_ms: u64,
     rate_limits: HashMap<String, RateLimitState>,
+    /// Debug logging forced on until this time (set by self-healing)
+    debug_until_ms: u64,
+    /// Join attempts in flight as `(call_id, join_id)`, oldest first
+    active_joins: VecDeque<(String, String)>,
 }
 
 impl LogManager {
@@ -58,6 +164,8 @@ impl LogManager {
             log_dir,
             last_cleanup_ms: 0,
             rate_limits: HashMap::new(),
+            debug_until_ms: 0,
+            active_joins: VecDeque::new(),
         };
         manager.configure(settings);
         manager
@@ -76,10 +184,93 @@ impl LogManager {
         }
     }
 
-    pub fn log_from_input(&mut self, input: LogEventInput, default_scope: &str) {
+    /// Force debug-level logging on until `until_ms`, regardless of settings
+    pub fn boost_debug_until(&mut self, until_ms: u64) {
+        self.debug_until_ms = self.debug_until_ms.max(until_ms);
+        let _ = fs::create_dir_all(&self.log_dir);
+    }
+
+    fn is_debug_boosted(&self, now: u64) -> bool {
+        now < self.debug_until_ms
+    }
+
+    /// Start correlating entries for `call_id`, returning its join id.
+    ///
+    /// A meeting that already has a join in flight keeps its id, so
+    /// rescheduling the same trigger stays one attempt in the logs.
+    pub fn begin_join(&mut self, call_id: &str) -> String {
+        if let Some(join_id) = self.join_id_for(call_id) {
+            return join_id;
+        }
+        let join_id = uuid::Uuid::new_v4().to_string();
+        if self.active_joins.len() >= MAX_ACTIVE_JOINS {
+            self.active_joins.pop_front();
+        }
+        self.active_joins
+            .push_back((call_id.to_string(), join_id.clone()));
+        join_id
+    }
+
+    pub fn join_id_for(&self, call_id: &str) -> Option<String> {
+        self.active_joins
+            .iter()
+            .find(|(id, _)| id == call_id)
+            .map(|(_, join_id)| join_id.clone())
+    }
+
+    /// Stop correlating entries for `call_id` once its join attempt resolved
+    pub fn end_join(&mut self, call_id: &str) -> Option<String> {
+        let index = self.active_joins.iter().position(|(id, _)| id == call_id)?;
+        self.active_joins.remove(index).map(|(_, join_id)| join_id)
+    }
+
+    /// Log an entry explicitly tagged with `join_id`
+    pub fn log_for_join(
+        &mut self,
+        join_id: &str,
+        level: LogLevel,
+        module: &str,
+        event: &str,
+        message: Option<String>,
+        context: Option<Value>,
+    ) {
+        let entry = LogEntry {
+            ts_ms: now_ms(),
+            level,
+            scope: "rust".to_string(),
+            module: module.to_string(),
+            event: event.to_string(),
+            message,
+            context,
+            session_id: self.session_id.clone(),
+            join_id: Some(join_id.to_string()),
+        };
+        let _ = self.write_entry(entry);
+    }
+
+    /// Join id for an entry: an explicit `joinId` in the context, otherwise the
+    /// join in flight for the context's `callId`
+    fn correlate(&self, context: &Option<Value>) -> Option<String> {
+        let context = context.as_ref()?;
+        if let Some(join_id) = context.get("joinId").and_then(Value::as_str) {
+            return Some(join_id.to_string());
+        }
+        context
+            .get("callId")
+            .and_then(Value::as_str)
+            .and_then(|call_id| self.join_id_for(call_id))
+    }
+
+    pub fn log_from_input(
+        &mut self,
+        input: LogEventInput,
+        default_scope: &str,
+    ) -> Result<(), UnknownLogLevel> {
+        let level = input.level.parse()?;
+        let join_id = self.correlate(&input.context);
         let entry = LogEntry {
             ts_ms: input.ts_ms.unwrap_or_else(now_ms),
-            level: input.level,
+            level,
             scope: input
                 .scope
                 .filter(|s| !s.trim().is_empty())
@@ -89,8 +280,10 @@ impl LogManager {
             message: input.message,
             context: input.context,
             session_id: self.session_id.clone(),
+            join_id,
         };
         let _ = self.write_entry(entry);
+        Ok(())
     }
 
     pub fn log_internal(
@@ -101,6 +294,7 @@ impl LogManager {
         message: Option<String>,
         context: Option<Value>,
     ) {
+        let join_id = self.correlate(&context);
         let entry = LogEntry {
             ts_ms: now_ms(),
             level,
@@ -110,16 +304,19 @@ impl LogManager {
             message,
             context,
             session_id: self.session_id.clone(),
+            join_id,
         };
         let _ = self.write_entry(entry);
     }
 
     fn write_entry(&mut self, entry: LogEntry) -> std::io::Result<()> {
-        if !self.enabled {
+        let boosted = self.is_debug_boosted(entry.ts_ms);
+        if !self.enabled && !boosted {
             return Ok(());
         }
 
-        if !level_allowed(&entry.level, &self.level) {
+        let threshold = if boosted { &LogLevel::Debug } else { &self.level };
+        if !level_allowed(&entry.level, threshold) {
             return Ok(());
         }
 
@@ -187,10 +384,21 @@ impl LogManager {
         if now.saturating_sub(self.last_cleanup_ms) < CLEANUP_INTERVAL_MS {
             return;
         }
+        self.run_digest(now);
+    }
+
+    /// Fold completed days into weekly digests, then prune raw files past
+    /// retention. Digesting first means nothing is deleted unsummarized.
+    pub fn run_digest(&mut self, now: u64) -> DigestRun {
         self.last_cleanup_ms = now;
+        let run = log_digest::fold_completed_days(
+            &self.log_dir,
+            Utc::now().date_naive(),
+            now,
+        );
 
         let Ok(entries) = fs::read_dir(&self.log_dir) else {
-            return;
+            return run;
         };
 
         let max_age = Duration::from_secs(LOG_RETENTION_DAYS * 24 * 60 * 60);
@@ -209,12 +417,279 @@ impl LogManager {
                 let _ = fs::remove_file(path);
             }
         }
+        run
+    }
+
+    /// Stored digest for an ISO week such as `2026-W42`
+    pub fn digest(&self, week: &str) -> Option<LogDigest> {
+        log_digest::load_digest(&self.log_dir, week, now_ms())
+    }
+
+    /// Stored entries matching `query`, newest first
+    pub fn query_logs(&self, query: &LogQuery) -> Vec<LogEntry> {
+        query_log_dir(&self.log_dir, query)
+    }
+
+    /// Bundle the retained logs, sanitized settings and `info` into a zip in
+    /// `dest_dir` for a bug report. Returns the path of the zip.
+    pub fn export(
+        &self,
+        settings: &Settings,
+        info: &ExportInfo,
+        dest_dir: &Path,
+    ) -> Result<PathBuf, String> {
+        let files = export_files(&self.log_dir, settings, info)?;
+        fs::create_dir_all(dest_dir).map_err(|e| e.to_string())?;
+        let stamp = chrono::DateTime::from_timestamp_millis(info.exported_at_ms as i64)
+            .unwrap_or_else(Utc::now)
+            .format("%Y%m%d-%H%M%S");
+        let path = dest_dir.join(format!("meetcat-logs-{}.zip", stamp));
+        write_zip(&path, &files)?;
+        Ok(path)
+    }
+}
+
+fn query_log_dir(log_dir: &Path, query: &LogQuery) -> Vec<LogEntry> {
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT)
+        .min(MAX_QUERY_LIMIT);
+    let mut log_files: Vec<PathBuf> = fs::read_dir(log_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "jsonl"))
+                .collect()
+        })
+        .unwrap_or_default();
+    // Files are named by date, so the newest sorts last
+    log_files.sort();
+
+    let mut skipped = 0;
+    let mut found = Vec::new();
+    for path in log_files.iter().rev() {
+        let Ok(content) = fs::read_to_string(path) else {
+            continue;
+        };
+        for line in content.lines().rev() {
+            let Ok(entry) = serde_json::from_str::<LogEntry>(line) else {
+                continue;
+            };
+            if !query.matches(&entry) {
+                continue;
+            }
+            if skipped < query.offset {
+                skipped += 1;
+                continue;
+            }
+            if found.len() == limit {
+                return found;
+            }
+            found.push(entry);
+        }
+    }
+    found
+}
+
+/// Files of an exported bundle as `(name in zip, content)`
+fn export_files(
+    log_dir: &Path,
+    settings: &Settings,
+    info: &ExportInfo,
+) -> Result<Vec<(String, Vec<u8>)>, String> {
+    let mut files = vec![
+        (
+            "system.json".to_string(),
+            serde_json::to_vec_pretty(info).map_err(|e| e.to_string())?,
+        ),
+        (
+            "settings.json".to_string(),
+            serde_json::to_vec_pretty(&sanitized_settings(settings)).map_err(|e| e.to_string())?,
+        ),
+    ];
+
+    let mut log_files: Vec<PathBuf> = fs::read_dir(log_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "jsonl"))
+                .collect()
+        })
+        .unwrap_or_default();
+    log_files.sort();
+    for path in log_files {
+        let (Some(name), Ok(content)) = (path.file_name(), fs::read_to_string(&path)) else {
+            continue;
+        };
+        // Entries are masked when written; masking again covers files
+        // written before a key was added to the list
+        let mut sanitized = String::with_capacity(content.len());
+        for line in content.lines().filter_map(sanitize_log_line) {
+            sanitized.push_str(&line);
+            sanitized.push('\n');
+        }
+        files.push((
+            format!("logs/{}", name.to_string_lossy()),
+            sanitized.into_bytes(),
+        ));
+    }
+    Ok(files)
+}
+
+fn write_zip(path: &Path, files: &[(String, Vec<u8>)]) -> Result<(), String> {
+    let file = fs::File::create(path).map_err(|e| e.to_string())?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+    for (name, content) in files {
+        zip.start_file(name.as_str(), options)
+            .map_err(|e| e.to_string())?;
+        zip.write_all(content).map_err(|e| e.to_string())?;
+    }
+    zip.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Re-mask the context of one JSONL log line; unreadable lines are dropped
+fn sanitize_log_line(line: &str) -> Option<String> {
+    let mut value: Value = serde_json::from_str(line).ok()?;
+    if let Some(context) = value.get_mut("context") {
+        sanitize_value_in_place(context);
+    }
+    serde_json::to_string(&value).ok()
+}
+
+/// Settings as JSON with title patterns, URLs and proxy credentials masked
+fn sanitized_settings(settings: &Settings) -> Value {
+    let mut value = serde_json::to_value(settings).unwrap_or(Value::Null);
+    redact_settings_in_place(&mut value);
+    value
+}
+
+fn redact_settings_in_place(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, val) in map.iter_mut() {
+                match key.as_str() {
+                    "pattern" | "titlePattern" => *val = mask_value("title", val),
+                    "titleExcludeFilters" => {
+                        if let Value::Array(items) = val {
+                            for item in items.iter_mut() {
+                                *item = mask_value("title", item);
+                            }
+                        }
+                    }
+                    "startupUrl" if val.is_string() => *val = mask_value("url", val),
+                    // Private ICS links carry a token, and file paths a user name
+                    "icsSubscriptions" => {
+                        if let Value::Array(items) = val {
+                            for item in items.iter_mut() {
+                                *item = mask_value("url", item);
+                            }
+                        }
+                    }
+                    "host" | "username" | "password" | "mqttHost" | "mqttUsername"
+                    | "mqttPassword" | "webhookSecret"
+                        if !val.is_null() =>
+                    {
+                        *val = Value::String("[redacted]".to_string())
+                    }
+                    // Incoming-webhook URLs are credentials in themselves
+                    "webhookUrls" => {
+                        if let Value::Array(items) = val {
+                            for item in items.iter_mut() {
+                                *item = Value::String("[redacted]".to_string());
+                            }
+                        }
+                    }
+                    _ => redact_settings_in_place(val),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items.iter_mut() {
+                redact_settings_in_place(item);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn os_version() -> Option<String> {
+    let output = std::process::Command::new("sw_vers")
+        .arg("-productVersion")
+        .output()
+        .ok()?;
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!version.is_empty()).then(|| format!("macOS {}", version))
+}
+
+#[cfg(target_os = "linux")]
+fn os_version() -> Option<String> {
+    let release = fs::read_to_string("/etc/os-release").ok()?;
+    release
+        .lines()
+        .find_map(|line| line.strip_prefix("PRETTY_NAME="))
+        .map(|name| name.trim_matches('"').to_string())
+}
+
+#[cfg(target_os = "windows")]
+fn os_version() -> Option<String> {
+    let output = std::process::Command::new("cmd")
+        .args(["/C", "ver"])
+        .output()
+        .ok()?;
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!version.is_empty()).then_some(version)
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+fn os_version() -> Option<String> {
+    None
+}
+
+/// Cheap, clonable handle to the shared [`LogManager`].
+///
+/// Background tasks capture one when they're spawned instead of looking up
+/// `AppState` for every entry, and code without an `AppHandle` can log
+/// through it too.
+#[derive(Clone)]
+pub struct LoggerHandle {
+    manager: Arc<Mutex<LogManager>>,
+}
+
+impl LoggerHandle {
+    pub fn new(manager: LogManager) -> Self {
+        Self {
+            manager: Arc::new(Mutex::new(manager)),
+        }
+    }
+
+    /// Log an app-side entry
+    pub fn log(
+        &self,
+        level: LogLevel,
+        module: &str,
+        event: &str,
+        message: Option<String>,
+        context: Option<Value>,
+    ) {
+        self.lock()
+            .log_internal(level, module, event, message, context);
+    }
+
+    /// Lock the manager for configuration and join correlation
+    #[track_caller]
+    pub fn lock(&self) -> MutexGuard<'_, LogManager> {
+        self.manager.lock_or_recover()
     }
 }
 
 fn default_log_dir() -> PathBuf {
-    let base = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
-    base.join("meetcat").join("logs")
+    paths::logs_dir()
 }
 
 fn is_older_than(modified: SystemTime, max_age: Duration) -> bool {
@@ -325,6 +800,17 @@ fn mask_value(key: &str, value: &Value) -> Value {
         _ => return Value::String("[redacted]".to_string()),
     };
 
+    // Already masked, e.g. when re-sanitizing entries for an export
+    let masked = match key {
+        "title" => raw.starts_with("[redacted"),
+        "url" => raw.contains("…/…"),
+        "callId" | "eventId" => raw.starts_with("****"),
+        _ => false,
+    };
+    if masked {
+        return value.clone();
+    }
+
     match key {
         "title" => {
             let len = raw.chars().count();
@@ -377,3 +863,250 @@ fn mask_url(raw: &str) -> String {
     let suffix = tail_chars(last_segment, 6);
     format!("{}{}…/…{}", scheme, host, suffix)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_join_correlation() {
+        let mut logger = LogManager::new(&Settings::default());
+        let join_id = logger.begin_join("abc-defg-hij");
+        assert_eq!(logger.begin_join("abc-defg-hij"), join_id);
+
+        let by_call_id = Some(json!({ "callId": "abc-defg-hij" }));
+        assert_eq!(logger.correlate(&by_call_id), Some(join_id.clone()));
+        let explicit = Some(json!({ "callId": "other", "joinId": "j-1" }));
+        assert_eq!(logger.correlate(&explicit).as_deref(), Some("j-1"));
+        assert_eq!(logger.correlate(&None), None);
+
+        assert_eq!(logger.end_join("abc-defg-hij"), Some(join_id.clone()));
+        assert_eq!(logger.correlate(&by_call_id), None);
+        assert_ne!(logger.begin_join("abc-defg-hij"), join_id);
+    }
+
+    #[test]
+    fn test_log_from_input_rejects_unknown_level() {
+        let mut logger = LogManager::new(&Settings::default());
+        let input = |level: &str| LogEventInput {
+            level: level.to_string(),
+            module: "inject".to_string(),
+            event: "init.page_detected".to_string(),
+            message: None,
+            context: None,
+            ts_ms: None,
+            scope: None,
+        };
+        assert!(logger.log_from_input(input("warning"), "webview").is_ok());
+        let err = logger.log_from_input(input("loud"), "webview").unwrap_err();
+        assert_eq!(err.level, "loud");
+    }
+
+    #[test]
+    fn test_logger_handle_clones_share_manager() {
+        let handle = LoggerHandle::new(LogManager::new(&Settings::default()));
+        let task_handle = handle.clone();
+
+        let join_id = task_handle.lock().begin_join("abc-defg-hij");
+        task_handle.log(LogLevel::Info, "join", "trigger.fired", None, None);
+        assert_eq!(handle.lock().join_id_for("abc-defg-hij"), Some(join_id));
+    }
+
+    #[test]
+    fn test_export_files_are_sanitized() {
+        let log_dir = std::env::temp_dir().join(format!("meetcat-export-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&log_dir);
+        fs::create_dir_all(&log_dir).unwrap();
+        let masked = sanitize_log_line(
+            &json!({
+                "event": "trigger.fired",
+                "context": { "callId": "abc-defg-hij", "title": "Design Review" },
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let legacy = json!({
+            "event": "trigger.fired",
+            "context": { "url": "https://meet.google.com/abc-defg-hij", "title": "1:1 with Alex" },
+        });
+        fs::write(
+            log_dir.join("meetcat-2026-10-17.jsonl"),
+            format!("{}\n{}\nnot json\n", masked, legacy),
+        )
+        .unwrap();
+        fs::write(log_dir.join("notes.txt"), "skip me").unwrap();
+
+        let mut settings = Settings {
+            title_exclude_filters: vec!["Interview".to_string()],
+            ..Default::default()
+        };
+        let tauri = settings.tauri.get_or_insert_with(Default::default);
+        tauri.startup_url = Some("https://calendar.google.com/calendar/r/day".to_string());
+        tauri.proxy = Some(crate::settings::ProxySettings {
+            host: "proxy.corp.example".to_string(),
+            port: 8080,
+            username: Some("alex".to_string()),
+            password: Some("p@ss".to_string()),
+        });
+        tauri.mqtt_host = Some("broker.corp.example".to_string());
+        tauri.mqtt_username = Some("mqtt-user".to_string());
+        tauri.mqtt_password = Some("mqtt-pass".to_string());
+        tauri.ics_subscriptions = vec![
+            "https://calendar.example/private-abc123token/basic.ics".to_string(),
+            "/Users/alex-home/Calendars/work.ics".to_string(),
+        ];
+        tauri.webhook_urls =
+            vec!["https://hooks.slack.com/services/T000/B000/XXXXSECRET".to_string()];
+        tauri.webhook_secret = Some("hmac-secret".to_string());
+
+        let info = ExportInfo::current("1.2.3");
+        let files = export_files(&log_dir, &settings, &info).unwrap();
+        let names: Vec<&str> = files.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "system.json",
+                "settings.json",
+                "logs/meetcat-2026-10-17.jsonl"
+            ]
+        );
+
+        let everything: String = files
+            .iter()
+            .map(|(_, content)| String::from_utf8_lossy(content).to_string())
+            .collect();
+        for secret in [
+            "Design Review",
+            "1:1 with Alex",
+            "abc-defg-hij",
+            "Interview",
+            "calendar/r/day",
+            "proxy.corp.example",
+            "alex",
+            "p@ss",
+            "broker.corp.example",
+            "mqtt-user",
+            "mqtt-pass",
+            "private-abc123token",
+            "alex-home",
+            "hooks.slack.com",
+            "XXXXSECRET",
+            "hmac-secret",
+        ] {
+            assert!(!everything.contains(secret), "{} leaked", secret);
+        }
+        assert!(everything.contains("\"appVersion\": \"1.2.3\""));
+
+        // Masking twice leaves already-masked values alone
+        let log = String::from_utf8_lossy(&files[2].1).to_string();
+        assert!(log.contains(&masked));
+        assert_eq!(log.lines().count(), 2);
+    }
+
+    #[test]
+    fn test_query_logs_filters_and_pages() {
+        let log_dir = std::env::temp_dir().join(format!("meetcat-query-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&log_dir);
+        fs::create_dir_all(&log_dir).unwrap();
+        let line = |ts_ms: u64, level: &str, module: &str, event: &str, message: &str| {
+            json!({
+                "tsMs": ts_ms,
+                "level": level,
+                "scope": "rust",
+                "module": module,
+                "event": event,
+                "message": message,
+                "context": { "callId": "abc-defg-hij" },
+                "sessionId": "s-1",
+            })
+            .to_string()
+        };
+        fs::write(
+            log_dir.join("meetcat-2026-10-16.jsonl"),
+            [
+                line(1_000, "info", "join", "join.fired", "Trigger fired"),
+                line(2_000, "warn", "join", "join.blocked", "Refused by Meet"),
+            ]
+            .join("\n"),
+        )
+        .unwrap();
+        fs::write(
+            log_dir.join("meetcat-2026-10-17.jsonl"),
+            [
+                line(3_000, "debug", "meetings", "meetings.updated", "Parsed"),
+                "not json".to_string(),
+                line(4_000, "error", "join", "joined.twice", "Duplicate join"),
+            ]
+            .join("\n"),
+        )
+        .unwrap();
+
+        let events = |query: LogQuery| {
+            query_log_dir(&log_dir, &query)
+                .into_iter()
+                .map(|entry| entry.event)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            events(LogQuery::default()),
+            [
+                "joined.twice",
+                "meetings.updated",
+                "join.blocked",
+                "join.fired"
+            ]
+        );
+        assert_eq!(
+            events(LogQuery {
+                level: Some(LogLevel::Warn),
+                ..Default::default()
+            }),
+            ["joined.twice", "join.blocked"]
+        );
+        assert_eq!(
+            events(LogQuery {
+                event: Some("join".to_string()),
+                ..Default::default()
+            }),
+            ["join.blocked", "join.fired"]
+        );
+        assert_eq!(
+            events(LogQuery {
+                module: Some("join".to_string()),
+                from_ms: Some(1_500),
+                to_ms: Some(3_500),
+                ..Default::default()
+            }),
+            ["join.blocked"]
+        );
+        assert_eq!(
+            events(LogQuery {
+                text: Some("REFUSED".to_string()),
+                ..Default::default()
+            }),
+            ["join.blocked"]
+        );
+        assert_eq!(
+            events(LogQuery {
+                offset: 1,
+                limit: Some(2),
+                ..Default::default()
+            }),
+            ["meetings.updated", "join.blocked"]
+        );
+        let _ = fs::remove_dir_all(&log_dir);
+    }
+
+    #[test]
+    fn test_active_joins_are_capped() {
+        let mut logger = LogManager::new(&Settings::default());
+        for i in 0..=MAX_ACTIVE_JOINS {
+            logger.begin_join(&format!("m{}", i));
+        }
+        assert_eq!(logger.join_id_for("m0"), None);
+        assert!(logger
+            .join_id_for(&format!("m{}", MAX_ACTIVE_JOINS))
+            .is_some());
+    }
+}
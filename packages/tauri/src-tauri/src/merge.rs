@@ -0,0 +1,274 @@
+//! Merge policy for meetings reported by multiple sources
+//!
+//! The same calendar event can arrive from the webview parser, an ICS feed or an
+//! API with slightly different titles and times. Observations are grouped by
+//! `call_id` and each field is resolved independently:
+//! - times (`begin_time`, `end_time`, `display_time`): most accurate
+//!   observation wins, then the freshest
+//! - `title`: longest title wins
+//! - everything else (`url`, `event_id`): highest-priority source wins
+//!
+//! Ties always fall back to the configured source priority, so the output is
+//! deterministic for a given input set.
+
+use crate::daemon::Meeting;
+use crate::settings::MeetingSource;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+/// A meeting as reported by a single source
+#[derive(Debug, Clone)]
+pub struct SourcedMeeting {
+    pub meeting: Meeting,
+    pub source: MeetingSource,
+    /// When the source produced this observation
+    pub observed_at_ms: i64,
+}
+
+/// Which source won each merged field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldOrigins {
+    pub title: MeetingSource,
+    pub times: MeetingSource,
+    pub url: MeetingSource,
+    /// Every source that reported this meeting, in priority order
+    pub sources: Vec<MeetingSource>,
+}
+
+/// A merged meeting together with the provenance of its fields
+#[derive(Debug, Clone)]
+pub struct MergedMeeting {
+    pub meeting: Meeting,
+    pub origins: FieldOrigins,
+}
+
+/// Merge observations from all sources into one meeting per `call_id`.
+///
+/// Output is sorted by `begin_time`, then `call_id`.
+pub fn merge_meetings(inputs: &[SourcedMeeting], priority: &[MeetingSource]) -> Vec<MergedMeeting> {
+    let mut groups: BTreeMap<&str, Vec<&SourcedMeeting>> = BTreeMap::new();
+    for input in inputs {
+        groups
+            .entry(input.meeting.call_id.as_str())
+            .or_default()
+            .push(input);
+    }
+
+    let mut merged: Vec<MergedMeeting> = groups
+        .into_values()
+        .filter_map(|group| merge_group(&group, priority))
+        .collect();
+    merged.sort_by(|a, b| {
+        a.meeting
+            .begin_time
+            .cmp(&b.meeting.begin_time)
+            .then_with(|| a.meeting.call_id.cmp(&b.meeting.call_id))
+    });
+    merged
+}
+
+fn merge_group(group: &[&SourcedMeeting], priority: &[MeetingSource]) -> Option<MergedMeeting> {
+    let rank = |item: &&SourcedMeeting| source_rank(&item.source, priority);
+
+    let base = group
+        .iter()
+        .min_by_key(|item| (rank(item), Reverse(item.observed_at_ms)))?;
+    let times = group.iter().min_by_key(|item| {
+        (
+            item.meeting.accuracy,
+            Reverse(item.observed_at_ms),
+            rank(item),
+        )
+    })?;
+    let title = group.iter().min_by_key(|item| {
+        (
+            Reverse(item.meeting.title.trim().chars().count()),
+            rank(item),
+        )
+    })?;
+
+    let mut sources: Vec<MeetingSource> = group.iter().map(|item| item.source.clone()).collect();
+    sources.sort_by_key(|source| source_rank(source, priority));
+    sources.dedup();
+
+    let meeting = Meeting {
+        call_id: base.meeting.call_id.clone(),
+        url: base.meeting.url.clone(),
+        title: title.meeting.title.clone(),
+        display_time: times.meeting.display_time.clone(),
+        begin_time: times.meeting.begin_time,
+        end_time: times.meeting.end_time,
+        event_id: base
+            .meeting
+            .event_id
+            .clone()
+            .or_else(|| group.iter().find_map(|item| item.meeting.event_id.clone())),
+        starts_in_minutes: times.meeting.starts_in_minutes,
+        profile: base.meeting.profile.clone(),
+        accuracy: times.meeting.accuracy,
+    };
+
+    Some(MergedMeeting {
+        meeting,
+        origins: FieldOrigins {
+            title: title.source.clone(),
+            times: times.source.clone(),
+            url: base.source.clone(),
+            sources,
+        },
+    })
+}
+
+/// Position of `source` in the priority list; unlisted sources rank last in declaration order.
+fn source_rank(source: &MeetingSource, priority: &[MeetingSource]) -> usize {
+    priority
+        .iter()
+        .position(|s| s == source)
+        .unwrap_or(priority.len() + source.clone() as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::TimeAccuracy;
+    use crate::fixtures::meeting_at;
+    use chrono::{TimeZone, Utc};
+
+    fn observed(
+        source: MeetingSource,
+        call_id: &str,
+        title: &str,
+        starts_in_minutes: i64,
+        observed_at_ms: i64,
+    ) -> SourcedMeeting {
+        let now = Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap();
+        SourcedMeeting {
+            meeting: meeting_at(call_id, title, now, starts_in_minutes, 30),
+            source,
+            observed_at_ms,
+        }
+    }
+
+    fn default_priority() -> Vec<MeetingSource> {
+        vec![
+            MeetingSource::Webview,
+            MeetingSource::Api,
+            MeetingSource::Ics,
+        ]
+    }
+
+    #[test]
+    fn test_single_source_passthrough() {
+        let inputs = vec![observed(
+            MeetingSource::Webview,
+            "abc-defg-hij",
+            "Standup",
+            5,
+            1,
+        )];
+        let merged = merge_meetings(&inputs, &default_priority());
+
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].meeting.title, "Standup");
+        assert_eq!(merged[0].origins.sources, vec![MeetingSource::Webview]);
+    }
+
+    #[test]
+    fn test_exact_times_win_over_fresher_estimates() {
+        let ics = observed(MeetingSource::Ics, "abc-defg-hij", "Standup", 10, 100);
+        let mut webview = observed(MeetingSource::Webview, "abc-defg-hij", "Standup", 0, 200);
+        webview.meeting.accuracy = TimeAccuracy::Inferred;
+
+        let merged = merge_meetings(&[webview, ics.clone()], &default_priority());
+        assert_eq!(merged[0].meeting.begin_time, ics.meeting.begin_time);
+        assert_eq!(merged[0].meeting.accuracy, TimeAccuracy::Exact);
+        assert_eq!(merged[0].origins.times, MeetingSource::Ics);
+    }
+
+    #[test]
+    fn test_freshest_times_longest_title_priority_url() {
+        let mut ics = observed(
+            MeetingSource::Ics,
+            "abc-defg-hij",
+            "Weekly Standup (Team A)",
+            10,
+            200,
+        );
+        ics.meeting.url = "https://meet.google.com/abc-defg-hij?authuser=1".to_string();
+        let webview = observed(
+            MeetingSource::Webview,
+            "abc-defg-hij",
+            "Weekly Standup",
+            5,
+            100,
+        );
+
+        let merged = merge_meetings(&[webview.clone(), ics.clone()], &default_priority());
+        assert_eq!(merged.len(), 1);
+        let result = &merged[0];
+
+        assert_eq!(result.meeting.begin_time, ics.meeting.begin_time);
+        assert_eq!(result.meeting.title, "Weekly Standup (Team A)");
+        assert_eq!(result.meeting.url, webview.meeting.url);
+        assert_eq!(result.origins.times, MeetingSource::Ics);
+        assert_eq!(result.origins.title, MeetingSource::Ics);
+        assert_eq!(result.origins.url, MeetingSource::Webview);
+        assert_eq!(
+            result.origins.sources,
+            vec![MeetingSource::Webview, MeetingSource::Ics]
+        );
+    }
+
+    #[test]
+    fn test_ties_resolved_by_priority() {
+        let webview = observed(MeetingSource::Webview, "abc-defg-hij", "Sync A", 5, 100);
+        let api = observed(MeetingSource::Api, "abc-defg-hij", "Sync B", 6, 100);
+
+        let merged = merge_meetings(&[api.clone(), webview.clone()], &default_priority());
+        assert_eq!(merged[0].origins.title, MeetingSource::Webview);
+        assert_eq!(merged[0].origins.times, MeetingSource::Webview);
+
+        let api_first = vec![MeetingSource::Api, MeetingSource::Webview];
+        let merged = merge_meetings(&[webview, api], &api_first);
+        assert_eq!(merged[0].origins.title, MeetingSource::Api);
+        assert_eq!(merged[0].origins.url, MeetingSource::Api);
+    }
+
+    #[test]
+    fn test_output_is_deterministic_and_sorted() {
+        let inputs = vec![
+            observed(MeetingSource::Ics, "zzz-zzzz-zzz", "Later", 30, 1),
+            observed(MeetingSource::Webview, "bbb-bbbb-bbb", "Tie B", 10, 1),
+            observed(MeetingSource::Webview, "aaa-aaaa-aaa", "Tie A", 10, 1),
+        ];
+        let mut reversed = inputs.clone();
+        reversed.reverse();
+
+        let a: Vec<String> = merge_meetings(&inputs, &default_priority())
+            .into_iter()
+            .map(|m| m.meeting.call_id)
+            .collect();
+        let b: Vec<String> = merge_meetings(&reversed, &default_priority())
+            .into_iter()
+            .map(|m| m.meeting.call_id)
+            .collect();
+
+        assert_eq!(a, vec!["aaa-aaaa-aaa", "bbb-bbbb-bbb", "zzz-zzzz-zzz"]);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_unlisted_source_ranks_last() {
+        let ics = observed(MeetingSource::Ics, "abc-defg-hij", "Same", 5, 100);
+        let webview = observed(MeetingSource::Webview, "abc-defg-hij", "Same", 5, 100);
+
+        let merged = merge_meetings(&[ics, webview], &[MeetingSource::Webview]);
+        assert_eq!(merged[0].origins.url, MeetingSource::Webview);
+        assert_eq!(
+            merged[0].origins.sources,
+            vec![MeetingSource::Webview, MeetingSource::Ics]
+        );
+    }
+}
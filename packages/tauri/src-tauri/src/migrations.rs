@@ -0,0 +1,431 @@
+//! Settings file migrations
+//!
+//! The settings file records the `schemaVersion` it was written with; files
+//! from before versioning count as version 0. On load, every migration newer
+//! than the file runs in order on the raw JSON before it is parsed, so a
+//! renamed key, a changed unit or a split field carries the user's value over
+//! instead of silently falling back to the default.
+
+use serde_json::{Map, Value};
+
+/// Top-level key holding the version in the settings file
+pub const VERSION_KEY: &str = "schemaVersion";
+
+/// One upgrade step of the settings file
+pub struct Migration {
+    /// Version the file is at after this step
+    pub version: u32,
+    /// Logged when the step runs
+    pub name: &'static str,
+    pub apply: fn(&mut Map<String, Value>) -> Result<(), String>,
+}
+
+/// Every step, oldest first. Append new steps; never edit shipped ones.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "add_schema_version",
+        apply: add_schema_version,
+    },
+    Migration {
+        version: 2,
+        name: "raise_check_interval",
+        apply: raise_check_interval,
+    },
+    Migration {
+        version: 3,
+        name: "move_auto_click_rules",
+        apply: move_auto_click_rules,
+    },
+    Migration {
+        version: 4,
+        name: "move_countdown_rules",
+        apply: move_countdown_rules,
+    },
+];
+
+/// Version written by this release
+pub fn current_version() -> u32 {
+    MIGRATIONS.last().map_or(0, |migration| migration.version)
+}
+
+/// Outcome of [`migrate`]
+#[derive(Debug, PartialEq)]
+pub struct MigrationReport {
+    /// Version the file was written with
+    pub from_version: u32,
+    /// Names of the steps that ran, in order
+    pub ran: Vec<&'static str>,
+}
+
+/// Upgrade a parsed settings file in place and strip its version key.
+///
+/// A file from a newer release is left as it is; unknown keys are ignored
+/// when it's parsed.
+pub fn migrate(file: &mut Value, migrations: &[Migration]) -> Result<MigrationReport, String> {
+    let map = file
+        .as_object_mut()
+        .ok_or_else(|| "settings file is not a JSON object".to_string())?;
+    let from_version = match map.remove(VERSION_KEY) {
+        None => 0,
+        Some(value) => value
+            .as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| format!("invalid {}: {}", VERSION_KEY, value))?,
+    };
+
+    let mut ran = Vec::new();
+    for migration in migrations
+        .iter()
+        .filter(|migration| migration.version > from_version)
+    {
+        (migration.apply)(map).map_err(|e| {
+            format!(
+                "migration {} to version {} failed: {}",
+                migration.name, migration.version, e
+            )
+        })?;
+        ran.push(migration.name);
+    }
+    Ok(MigrationReport { from_version, ran })
+}
+
+/// Version 1 only starts recording the version
+fn add_schema_version(_: &mut Map<String, Value>) -> Result<(), String> {
+    Ok(())
+}
+
+/// Version 2 shares the 30-120 second check interval with the extension; the
+/// desktop app used to default to 5
+fn raise_check_interval(map: &mut Map<String, Value>) -> Result<(), String> {
+    convert(map, "checkIntervalSeconds", |value| {
+        let seconds = value.as_u64().ok_or("expected a number")?;
+        Ok(Value::from(seconds.max(30)))
+    })
+}
+
+/// Version 3 drops `tauri.autoClickRules` for an `autoClickJoin` override on
+/// the meeting rules
+fn move_auto_click_rules(map: &mut Map<String, Value>) -> Result<(), String> {
+    move_onto_meeting_rules(map, "tauri.autoClickRules", "autoClickJoin")
+}
+
+/// Version 4 drops `tauri.countdownRules` for a `countdownSeconds` override
+/// on the meeting rules
+fn move_countdown_rules(map: &mut Map<String, Value>) -> Result<(), String> {
+    move_onto_meeting_rules(map, "tauri.countdownRules", "countdownSeconds")
+}
+
+// Building blocks for migrations. Paths are dot-separated, e.g.
+// `tauri.logLevel`; a missing source key is not an error, since the file may
+// predate the setting entirely.
+
+/// Move the value at `from` to `to`, unless `to` is already set
+#[cfg_attr(not(test), allow(dead_code))]
+pub fn rename_key(map: &mut Map<String, Value>, from: &str, to: &str) -> Result<(), String> {
+    let Some(value) = take(map, from) else {
+        return Ok(());
+    };
+    if get(map, to).is_none() {
+        insert(map, to, value)?;
+    }
+    Ok(())
+}
+
+/// Replace the value at `path` with `convert(value)`, e.g. to change units
+pub fn convert<F>(map: &mut Map<String, Value>, path: &str, convert: F) -> Result<(), String>
+where
+    F: FnOnce(Value) -> Result<Value, String>,
+{
+    let Some(value) = take(map, path) else {
+        return Ok(());
+    };
+    let converted = convert(value).map_err(|e| format!("{}: {}", path, e))?;
+    insert(map, path, converted)
+}
+
+/// Replace the value at `from` with the `(path, value)` pairs `split` returns
+#[cfg_attr(not(test), allow(dead_code))]
+pub fn split_field<F>(map: &mut Map<String, Value>, from: &str, split: F) -> Result<(), String>
+where
+    F: FnOnce(Value) -> Result<Vec<(&'static str, Value)>, String>,
+{
+    let Some(value) = take(map, from) else {
+        return Ok(());
+    };
+    for (path, part) in split(value).map_err(|e| format!("{}: {}", from, e))? {
+        insert(map, path, part)?;
+    }
+    Ok(())
+}
+
+/// Move the `field` override of each `{ titlePattern, <field> }` rule at
+/// `from` onto `tauri.meetingRules`.
+///
+/// A substring meeting rule with the same pattern takes the override unless
+/// it already has one; other patterns are appended as new substring rules.
+/// Rules with an empty pattern never matched and are dropped.
+fn move_onto_meeting_rules(
+    map: &mut Map<String, Value>,
+    from: &str,
+    field: &str,
+) -> Result<(), String> {
+    let Some(rules) = take(map, from) else {
+        return Ok(());
+    };
+    let rules = match rules {
+        Value::Array(rules) => rules,
+        _ => return Err(format!("{}: expected an array", from)),
+    };
+    let mut meeting_rules = match take(map, "tauri.meetingRules") {
+        Some(Value::Array(meeting_rules)) => meeting_rules,
+        Some(_) => return Err("tauri.meetingRules: expected an array".to_string()),
+        None => Vec::new(),
+    };
+    for rule in rules {
+        let (Some(pattern), Some(value)) = (
+            rule.get("titlePattern").and_then(Value::as_str),
+            rule.get(field),
+        ) else {
+            continue;
+        };
+        if pattern.is_empty() {
+            continue;
+        }
+        let same_pattern = meeting_rules.iter_mut().find(|existing| {
+            existing.get("pattern").and_then(Value::as_str) == Some(pattern)
+                && existing
+                    .get("matchMode")
+                    .is_none_or(|mode| mode == "contains")
+        });
+        match same_pattern.and_then(Value::as_object_mut) {
+            Some(existing) => {
+                existing.entry(field).or_insert_with(|| value.clone());
+            }
+            None => {
+                let mut migrated = Map::new();
+                migrated.insert("pattern".to_string(), Value::from(pattern));
+                migrated.insert(field.to_string(), value.clone());
+                meeting_rules.push(Value::Object(migrated));
+            }
+        }
+    }
+    insert(map, "tauri.meetingRules", Value::Array(meeting_rules))
+}
+
+#[cfg_attr(not(test), allow(dead_code))]
+fn get<'a>(map: &'a Map<String, Value>, path: &str) -> Option<&'a Value> {
+    let (parents, key) = split_path(path);
+    let mut current = map;
+    for parent in parents {
+        current = current.get(parent)?.as_object()?;
+    }
+    current.get(key)
+}
+
+fn take(map: &mut Map<String, Value>, path: &str) -> Option<Value> {
+    let (parents, key) = split_path(path);
+    let mut current = map;
+    for parent in parents {
+        current = current.get_mut(parent)?.as_object_mut()?;
+    }
+    current.remove(key)
+}
+
+fn insert(map: &mut Map<String, Value>, path: &str, value: Value) -> Result<(), String> {
+    let (parents, key) = split_path(path);
+    let mut current = map;
+    for parent in parents {
+        current = current
+            .entry(parent)
+            .or_insert_with(|| Value::Object(Map::new()))
+            .as_object_mut()
+            .ok_or_else(|| format!("{} is not an object", parent))?;
+    }
+    current.insert(key.to_string(), value);
+    Ok(())
+}
+
+fn split_path(path: &str) -> (Vec<&str>, &str) {
+    let mut parts: Vec<&str> = path.split('.').collect();
+    let key = parts.pop().unwrap_or_default();
+    (parts, key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const TEST_MIGRATIONS: &[Migration] = &[
+        Migration {
+            version: 1,
+            name: "rename_interval",
+            apply: |map| rename_key(map, "interval", "checkIntervalSeconds"),
+        },
+        Migration {
+            version: 2,
+            name: "countdown_to_seconds",
+            apply: |map| {
+                convert(map, "tauri.countdownMs", |value| {
+                    let ms = value.as_u64().ok_or("expected a number")?;
+                    Ok(Value::from(ms / 1000))
+                })?;
+                rename_key(map, "tauri.countdownMs", "tauri.countdownSeconds")
+            },
+        },
+        Migration {
+            version: 3,
+            name: "split_media",
+            apply: |map| {
+                split_field(map, "mediaState", |value| {
+                    Ok(vec![
+                        ("defaultMicState", value.clone()),
+                        ("defaultCameraState", value),
+                    ])
+                })
+            },
+        },
+    ];
+
+    #[test]
+    fn test_current_version_is_last_migration() {
+        assert_eq!(current_version(), MIGRATIONS.last().unwrap().version);
+        let versions: Vec<u32> = MIGRATIONS.iter().map(|m| m.version).collect();
+        assert!(versions.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_runs_pending_migrations_in_order() {
+        let mut file = json!({
+            "interval": 10,
+            "tauri": { "countdownMs": 30000 },
+            "mediaState": "unmuted"
+        });
+        let report = migrate(&mut file, TEST_MIGRATIONS).unwrap();
+        assert_eq!(report.from_version, 0);
+        assert_eq!(
+            report.ran,
+            vec!["rename_interval", "countdown_to_seconds", "split_media"]
+        );
+        assert_eq!(
+            file,
+            json!({
+                "checkIntervalSeconds": 10,
+                "tauri": { "countdownSeconds": 30 },
+                "defaultMicState": "unmuted",
+                "defaultCameraState": "unmuted"
+            })
+        );
+    }
+
+    #[test]
+    fn test_skips_applied_migrations_and_strips_version() {
+        let mut file = json!({ "schemaVersion": 2, "interval": 10, "mediaState": "muted" });
+        let report = migrate(&mut file, TEST_MIGRATIONS).unwrap();
+        assert_eq!(report.from_version, 2);
+        assert_eq!(report.ran, vec!["split_media"]);
+        // Already renamed by version 1, so a stray old key is left alone
+        assert_eq!(file["interval"], 10);
+        assert!(file.get(VERSION_KEY).is_none());
+
+        let mut file = json!({ "schemaVersion": 9 });
+        let report = migrate(&mut file, TEST_MIGRATIONS).unwrap();
+        assert_eq!(report.from_version, 9);
+        assert!(report.ran.is_empty());
+    }
+
+    #[test]
+    fn test_rename_keeps_existing_target() {
+        let mut file = json!({ "interval": 10, "checkIntervalSeconds": 5 });
+        migrate(&mut file, &TEST_MIGRATIONS[..1]).unwrap();
+        assert_eq!(file, json!({ "checkIntervalSeconds": 5 }));
+    }
+
+    #[test]
+    fn test_raise_check_interval() {
+        let mut file = json!({ "schemaVersion": 1, "checkIntervalSeconds": 5 });
+        migrate(&mut file, MIGRATIONS).unwrap();
+        assert_eq!(file, json!({ "checkIntervalSeconds": 30 }));
+
+        let mut file = json!({ "schemaVersion": 1, "checkIntervalSeconds": 90 });
+        migrate(&mut file, MIGRATIONS).unwrap();
+        assert_eq!(file, json!({ "checkIntervalSeconds": 90 }));
+    }
+
+    #[test]
+    fn test_move_auto_click_rules() {
+        let mut file = json!({
+            "schemaVersion": 2,
+            "tauri": {
+                "autoClickRules": [
+                    { "titlePattern": "", "autoClickJoin": true },
+                    { "titlePattern": "Interview", "autoClickJoin": false },
+                    { "titlePattern": "Panel", "autoClickJoin": false },
+                    { "titlePattern": "Panel", "autoClickJoin": true }
+                ],
+                "meetingRules": [
+                    { "pattern": "Interview", "micState": "muted" },
+                    { "pattern": "Panel", "matchMode": "regex" }
+                ]
+            }
+        });
+        migrate(&mut file, MIGRATIONS).unwrap();
+        assert_eq!(
+            file,
+            json!({
+                "tauri": {
+                    "meetingRules": [
+                        { "pattern": "Interview", "micState": "muted", "autoClickJoin": false },
+                        { "pattern": "Panel", "matchMode": "regex" },
+                        { "pattern": "Panel", "autoClickJoin": false }
+                    ]
+                }
+            })
+        );
+
+        // Files without the old rules are left alone
+        let mut file = json!({ "schemaVersion": 2, "tauri": { "logLevel": "debug" } });
+        migrate(&mut file, MIGRATIONS).unwrap();
+        assert_eq!(file, json!({ "tauri": { "logLevel": "debug" } }));
+    }
+
+    #[test]
+    fn test_move_countdown_rules() {
+        let mut file = json!({
+            "schemaVersion": 2,
+            "tauri": {
+                "countdownRules": [
+                    { "titlePattern": "Standup", "countdownSeconds": 0 },
+                    { "titlePattern": "External", "countdownSeconds": 30 }
+                ],
+                "autoClickRules": [
+                    { "titlePattern": "External", "autoClickJoin": false }
+                ]
+            }
+        });
+        migrate(&mut file, MIGRATIONS).unwrap();
+        assert_eq!(
+            file,
+            json!({
+                "tauri": {
+                    "meetingRules": [
+                        { "pattern": "External", "autoClickJoin": false, "countdownSeconds": 30 },
+                        { "pattern": "Standup", "countdownSeconds": 0 }
+                    ]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_reports_failing_migration() {
+        let mut file = json!({ "tauri": { "countdownMs": "soon" } });
+        let err = migrate(&mut file, TEST_MIGRATIONS).unwrap_err();
+        assert!(err.contains("countdown_to_seconds"), "{}", err);
+
+        let mut file = json!({ "schemaVersion": "one" });
+        assert!(migrate(&mut file, TEST_MIGRATIONS).is_err());
+        assert!(migrate(&mut json!([]), TEST_MIGRATIONS).is_err());
+    }
+}
@@ -0,0 +1,550 @@
+//! Meeting and mute state published over MQTT for home automation
+//!
+//! When `mqttEnabled` is on, MeetCat keeps a connection to the configured
+//! broker and publishes retained messages under `mqttTopicPrefix`, so an
+//! "on air" light or a Home Assistant automation can follow along:
+//!
+//! - `<prefix>/state` — `idle`, `meeting-soon` or `in-meeting`
+//! - `<prefix>/next_meeting` — JSON of the next meeting, or `null`
+//! - `<prefix>/mic`, `<prefix>/camera` — `on`, `off` or `unknown`
+//! - `<prefix>/availability` — `online`, or `offline` once MeetCat quits or
+//!   drops off (sent by the broker as the will message)
+//!
+//! Only topics whose payload changed are published again, at QoS 0. The
+//! connection is MQTT 3.1.1 through rumqttc, over TLS when `mqttTls` is on;
+//! a user name and password are only sent over TLS or to this machine. The
+//! client reconnects with a growing delay when the broker goes away.
+
+use crate::daemon::Meeting;
+use crate::format;
+use crate::http_client;
+use crate::i18n::Language;
+use crate::lifecycle::CallPresence;
+use chrono::{DateTime, Utc};
+use rumqttc::{
+    AsyncClient, Event, EventLoop, LastWill, MqttOptions, Outgoing, Packet, QoS, TlsConfiguration,
+    Transport,
+};
+use rustls_platform_verifier::ConfigVerifierExt;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// A meeting starting within this many minutes makes the state `meeting-soon`
+pub const MEETING_SOON_MINUTES: i64 = 5;
+const KEEP_ALIVE: Duration = Duration::from_secs(60);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Requests queued for the event loop; a snapshot is five publishes
+const REQUEST_CAPACITY: usize = 16;
+const RECONNECT_MIN: Duration = Duration::from_secs(5);
+const RECONNECT_MAX: Duration = Duration::from_secs(300);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PresenceState {
+    Idle,
+    MeetingSoon,
+    InMeeting,
+}
+
+impl PresenceState {
+    fn as_str(self) -> &'static str {
+        match self {
+            PresenceState::Idle => "idle",
+            PresenceState::MeetingSoon => "meeting-soon",
+            PresenceState::InMeeting => "in-meeting",
+        }
+    }
+}
+
+/// Payload of `<prefix>/next_meeting`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NextMeeting {
+    /// Meeting title, or its display time in privacy mode
+    pub title: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    /// Meeting link; omitted in privacy mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Everything the publisher reports, built on each tray refresh and after
+/// meeting changes
+#[derive(Debug, Clone, PartialEq)]
+pub struct MqttSnapshot {
+    pub state: PresenceState,
+    pub next_meeting: Option<NextMeeting>,
+    /// Whether the mic is live; `None` when a joined page hasn't shown it
+    pub mic: Option<bool>,
+    /// Whether the camera is on; `None` when a joined page hasn't shown it
+    pub camera: Option<bool>,
+}
+
+impl MqttSnapshot {
+    /// `joined` holds the latest page report of each joined meeting
+    pub fn build(
+        next: Option<&Meeting>,
+        joined: &[Option<CallPresence>],
+        privacy_mode: bool,
+        lang: &Language,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let state = if !joined.is_empty() {
+            PresenceState::InMeeting
+        } else if next.is_some_and(|m| m.starts_in_minutes_at(now) < MEETING_SOON_MINUTES) {
+            PresenceState::MeetingSoon
+        } else {
+            PresenceState::Idle
+        };
+        Self {
+            state,
+            next_meeting: next.map(|m| NextMeeting {
+                title: format::meeting_subject(lang, &m.title, &m.display_time, privacy_mode),
+                starts_at: m.begin_time,
+                ends_at: m.end_time,
+                url: (!privacy_mode).then(|| m.url.clone()),
+            }),
+            mic: media_live(joined, |p| p.mic_muted),
+            camera: media_live(joined, |p| p.camera_muted),
+        }
+    }
+
+    /// Retained messages for this snapshot, by topic
+    pub fn messages(&self, prefix: &str) -> Vec<(String, String)> {
+        let next_meeting =
+            serde_json::to_string(&self.next_meeting).unwrap_or_else(|_| "null".to_string());
+        vec![
+            (topic(prefix, "state"), self.state.as_str().to_string()),
+            (topic(prefix, "next_meeting"), next_meeting),
+            (topic(prefix, "mic"), media_payload(self.mic).to_string()),
+            (
+                topic(prefix, "camera"),
+                media_payload(self.camera).to_string(),
+            ),
+        ]
+    }
+}
+
+/// Live when any joined page shows the device unmuted, off when every one
+/// shows it muted or nothing is joined
+fn media_live(
+    joined: &[Option<CallPresence>],
+    muted: impl Fn(&CallPresence) -> Option<bool>,
+) -> Option<bool> {
+    let mut all_known = true;
+    for presence in joined {
+        match presence.as_ref().and_then(&muted) {
+            Some(false) => return Some(true),
+            Some(true) => {}
+            None => all_known = false,
+        }
+    }
+    all_known.then_some(false)
+}
+
+fn media_payload(live: Option<bool>) -> &'static str {
+    match live {
+        Some(true) => "on",
+        Some(false) => "off",
+        None => "unknown",
+    }
+}
+
+fn topic(prefix: &str, name: &str) -> String {
+    format!("{}/{}", prefix, name)
+}
+
+/// Check the configured topic prefix before it's saved
+pub fn validate_topic_prefix(prefix: &str) -> Result<(), String> {
+    if prefix.is_empty() || prefix.starts_with('/') || prefix.ends_with('/') {
+        return Err(format!(
+            "MQTT topic prefix must not be empty or start or end with \"/\": \"{}\"",
+            prefix
+        ));
+    }
+    if prefix.contains(['+', '#', '\0']) {
+        return Err(format!(
+            "MQTT topic prefix must not contain wildcards: \"{}\"",
+            prefix
+        ));
+    }
+    Ok(())
+}
+
+/// Whether credentials may go to `host` without TLS: only when it is this
+/// machine
+pub fn validate_credentials_transport(host: &str, tls: bool) -> Result<(), String> {
+    let host = host.trim().trim_start_matches('[').trim_end_matches(']');
+    let loopback = host.eq_ignore_ascii_case("localhost")
+        || host.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback());
+    if tls || loopback {
+        return Ok(());
+    }
+    Err(format!(
+        "Turn on TLS to send an MQTT user name and password to {}",
+        host
+    ))
+}
+
+/// Broker connection settings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttConfig {
+    pub host: String,
+    pub port: u16,
+    pub tls: bool,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub topic_prefix: String,
+}
+
+/// Connection changes reported by the publisher
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MqttStatus {
+    Connected,
+    /// Lost the connection, or failed to connect after the last success
+    Disconnected(String),
+}
+
+pub type StatusReporter = Arc<dyn Fn(MqttStatus) + Send + Sync>;
+
+/// A running publisher; dropping it publishes `offline` and disconnects
+pub struct MqttHandle {
+    config: MqttConfig,
+    snapshot: watch::Sender<MqttSnapshot>,
+}
+
+impl MqttHandle {
+    pub fn config(&self) -> &MqttConfig {
+        &self.config
+    }
+
+    /// Publish the topics that differ from the last snapshot
+    pub fn publish(&self, snapshot: MqttSnapshot) {
+        self.snapshot.send_if_modified(|current| {
+            let changed = *current != snapshot;
+            *current = snapshot;
+            changed
+        });
+    }
+}
+
+/// Connect to the broker in the background and keep `initial` published
+pub fn start(config: MqttConfig, initial: MqttSnapshot, report: StatusReporter) -> MqttHandle {
+    let (snapshot, updates) = watch::channel(initial);
+    let client_id = format!(
+        "meetcat-{}",
+        &uuid::Uuid::new_v4().simple().to_string()[..12]
+    );
+    tauri::async_runtime::spawn(run(config.clone(), client_id, updates, report));
+    MqttHandle { config, snapshot }
+}
+
+/// Client options with a retained `offline` will and a clean session
+fn options(config: &MqttConfig, client_id: &str) -> Result<MqttOptions, String> {
+    let mut options = MqttOptions::new(client_id, config.host.as_str(), config.port);
+    options
+        .set_keep_alive(KEEP_ALIVE)
+        .set_last_will(LastWill::new(
+            topic(&config.topic_prefix, "availability"),
+            "offline",
+            QoS::AtMostOnce,
+            true,
+        ));
+    if let Some(username) = config.username.as_deref().filter(|u| !u.is_empty()) {
+        validate_credentials_transport(&config.host, config.tls)?;
+        options.set_credentials(username, config.password.clone().unwrap_or_default());
+    }
+    if config.tls {
+        http_client::install_crypto_provider();
+        let tls = rustls::ClientConfig::with_platform_verifier()
+            .map_err(|e| format!("Failed to set up TLS: {}", e))?;
+        options.set_transport(Transport::tls_with_config(TlsConfiguration::Rustls(
+            Arc::new(tls),
+        )));
+    }
+    Ok(options)
+}
+
+/// Keep the snapshot published until the handle is dropped. The event loop
+/// runs on its own task and reports each new session, so neither side is
+/// cancelled halfway through a packet.
+async fn run(
+    config: MqttConfig,
+    client_id: String,
+    mut updates: watch::Receiver<MqttSnapshot>,
+    report: StatusReporter,
+) {
+    let options = match options(&config, &client_id) {
+        Ok(options) => options,
+        Err(e) => {
+            report(MqttStatus::Disconnected(e));
+            return;
+        }
+    };
+    let (client, mut eventloop) = AsyncClient::new(options, REQUEST_CAPACITY);
+    eventloop
+        .network_options
+        .set_connection_timeout(CONNECT_TIMEOUT.as_secs());
+    let (session_tx, mut session) = watch::channel(None);
+    let mut driver = tauri::async_runtime::spawn(drive(eventloop, session_tx, report));
+
+    let availability = topic(&config.topic_prefix, "availability");
+    let mut published: HashMap<String, String> = HashMap::new();
+    loop {
+        tokio::select! {
+            changed = updates.changed() => {
+                if changed.is_err() {
+                    break;
+                }
+            }
+            changed = session.changed() => {
+                if changed.is_err() {
+                    return;
+                }
+                // A new session starts clean, so everything goes out again
+                published.clear();
+            }
+        }
+        if session.borrow_and_update().is_none() {
+            continue;
+        }
+        let mut pending = vec![(availability.clone(), "online".to_string())];
+        pending.extend(updates.borrow_and_update().messages(&config.topic_prefix));
+        for (topic, payload) in pending {
+            if published.get(&topic) == Some(&payload) {
+                continue;
+            }
+            if client
+                .try_publish(topic.as_str(), QoS::AtMostOnce, true, payload.as_bytes())
+                .is_ok()
+            {
+                published.insert(topic, payload);
+            }
+        }
+    }
+
+    // Say goodbye, giving the event loop a moment to send it
+    if session.borrow().is_some() {
+        let _ = client.try_publish(availability.as_str(), QoS::AtMostOnce, true, "offline");
+        let _ = client.try_disconnect();
+        let _ = tokio::time::timeout(CONNECT_TIMEOUT, &mut driver).await;
+    }
+    driver.abort();
+}
+
+/// Poll the event loop, reconnecting with a growing delay. `session` counts
+/// connections and is `None` while disconnected.
+async fn drive(
+    mut eventloop: EventLoop,
+    session: watch::Sender<Option<u64>>,
+    report: StatusReporter,
+) {
+    let mut delay = RECONNECT_MIN;
+    let mut reported_failure = false;
+    let mut sessions = 0;
+    loop {
+        match eventloop.poll().await {
+            Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                delay = RECONNECT_MIN;
+                reported_failure = false;
+                sessions += 1;
+                report(MqttStatus::Connected);
+                let _ = session.send(Some(sessions));
+            }
+            Ok(Event::Outgoing(Outgoing::Disconnect)) => return,
+            Ok(_) => {}
+            Err(e) => {
+                let _ = session.send(None);
+                // Log the first failure, not every retry against a dead broker
+                if !reported_failure {
+                    reported_failure = true;
+                    report(MqttStatus::Disconnected(e.to_string()));
+                }
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(RECONNECT_MAX);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    fn presence(mic_muted: Option<bool>, camera_muted: Option<bool>) -> Option<CallPresence> {
+        Some(CallPresence {
+            local_media_live: true,
+            participant_count: Some(3),
+            mic_muted,
+            camera_muted,
+        })
+    }
+
+    #[test]
+    fn test_snapshot_state_and_media() {
+        let now = Utc::now();
+        let lang = Language::En;
+        let soon = fixtures::meeting_at("abc-defg-hij", "Standup", now, 3, 30);
+        let later = fixtures::meeting_at("abc-defg-hij", "Standup", now, 20, 30);
+
+        let idle = MqttSnapshot::build(Some(&later), &[], false, &lang, now);
+        assert_eq!(idle.state, PresenceState::Idle);
+        assert_eq!((idle.mic, idle.camera), (Some(false), Some(false)));
+        assert_eq!(
+            MqttSnapshot::build(Some(&soon), &[], false, &lang, now).state,
+            PresenceState::MeetingSoon
+        );
+
+        let joined = [presence(Some(false), Some(true)), None];
+        let in_meeting = MqttSnapshot::build(None, &joined, false, &lang, now);
+        assert_eq!(in_meeting.state, PresenceState::InMeeting);
+        assert_eq!(in_meeting.mic, Some(true));
+        assert_eq!(in_meeting.camera, None);
+    }
+
+    #[test]
+    fn test_messages_respect_privacy_mode() {
+        let now = Utc::now();
+        let meeting = fixtures::meeting_at("abc-defg-hij", "Salary review", now, 3, 30);
+        let snapshot = MqttSnapshot::build(Some(&meeting), &[], true, &Language::En, now);
+        let messages: HashMap<_, _> = snapshot.messages("home/office").into_iter().collect();
+
+        assert_eq!(messages["home/office/state"], "meeting-soon");
+        assert_eq!(messages["home/office/mic"], "off");
+        let next: serde_json::Value =
+            serde_json::from_str(&messages["home/office/next_meeting"]).unwrap();
+        assert!(!next["title"].as_str().unwrap().contains("Salary"));
+        assert!(next.get("url").is_none());
+
+        let idle = MqttSnapshot::build(None, &[], false, &Language::En, now);
+        assert!(idle
+            .messages("meetcat")
+            .contains(&("meetcat/next_meeting".to_string(), "null".to_string())));
+    }
+
+    #[test]
+    fn test_validate_topic_prefix() {
+        assert!(validate_topic_prefix("meetcat").is_ok());
+        assert!(validate_topic_prefix("home/office").is_ok());
+        assert!(validate_topic_prefix("").is_err());
+        assert!(validate_topic_prefix("/meetcat").is_err());
+        assert!(validate_topic_prefix("meetcat/").is_err());
+        assert!(validate_topic_prefix("meetcat/#").is_err());
+    }
+
+    #[test]
+    fn test_credentials_need_tls_off_this_machine() {
+        assert!(validate_credentials_transport("localhost", false).is_ok());
+        assert!(validate_credentials_transport("127.0.0.1", false).is_ok());
+        assert!(validate_credentials_transport("[::1]", false).is_ok());
+        assert!(validate_credentials_transport("broker.local", false).is_err());
+        assert!(validate_credentials_transport("192.168.1.20", false).is_err());
+        assert!(validate_credentials_transport("broker.local", true).is_ok());
+
+        let config = MqttConfig {
+            host: "broker.local".to_string(),
+            port: 1883,
+            tls: false,
+            username: Some("user".to_string()),
+            password: Some("pw".to_string()),
+            topic_prefix: "meetcat".to_string(),
+        };
+        assert!(options(&config, "meetcat-1").is_err());
+        let anonymous = MqttConfig {
+            username: None,
+            ..config
+        };
+        assert!(options(&anonymous, "meetcat-1").is_ok());
+    }
+
+    /// Read one packet: its type nibble and body
+    async fn read_packet(broker: &mut TcpStream) -> (u8, Vec<u8>) {
+        let kind = broker.read_u8().await.unwrap() >> 4;
+        let (mut length, mut shift) = (0usize, 0);
+        loop {
+            let byte = broker.read_u8().await.unwrap();
+            length |= ((byte & 0x7F) as usize) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        let mut body = vec![0u8; length];
+        broker.read_exact(&mut body).await.unwrap();
+        (kind, body)
+    }
+
+    /// Topic and payload of the next packet, which must be a QoS 0 publish
+    async fn read_publish(broker: &mut TcpStream) -> (String, String) {
+        let (kind, body) = read_packet(broker).await;
+        assert_eq!(kind, 3);
+        let topic_len = u16::from_be_bytes([body[0], body[1]]) as usize;
+        let topic = String::from_utf8(body[2..2 + topic_len].to_vec()).unwrap();
+        let payload = String::from_utf8(body[2 + topic_len..].to_vec()).unwrap();
+        (topic, payload)
+    }
+
+    #[tokio::test]
+    async fn test_publishes_changes_and_will() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let config = MqttConfig {
+            host: "127.0.0.1".to_string(),
+            port,
+            tls: false,
+            username: Some("user".to_string()),
+            password: Some("pw".to_string()),
+            topic_prefix: "meetcat".to_string(),
+        };
+        let now = Utc::now();
+        let idle = MqttSnapshot::build(None, &[], false, &Language::En, now);
+        let (status_tx, mut status_rx) = tokio::sync::mpsc::unbounded_channel();
+        let handle = start(
+            config,
+            idle.clone(),
+            Arc::new(move |status| {
+                let _ = status_tx.send(status);
+            }),
+        );
+
+        let (mut broker, _) = listener.accept().await.unwrap();
+        let (kind, connect) = read_packet(&mut broker).await;
+        assert_eq!(kind, 1);
+        assert_eq!(&connect[..7], &[0, 4, b'M', b'Q', b'T', b'T', 4]);
+        // User name, password, retained will and clean session
+        assert_eq!(connect[7], 0x80 | 0x40 | 0x20 | 0x04 | 0x02);
+        broker.write_all(&[0x20, 0x02, 0, 0]).await.unwrap();
+        assert_eq!(status_rx.recv().await, Some(MqttStatus::Connected));
+
+        let mut expected = vec![("meetcat/availability".to_string(), "online".to_string())];
+        expected.extend(idle.messages("meetcat"));
+        for message in expected {
+            assert_eq!(read_publish(&mut broker).await, message);
+        }
+
+        // Only the state topic changed
+        handle.publish(MqttSnapshot {
+            state: PresenceState::InMeeting,
+            ..idle
+        });
+        assert_eq!(
+            read_publish(&mut broker).await,
+            ("meetcat/state".to_string(), "in-meeting".to_string())
+        );
+
+        drop(handle);
+        assert_eq!(
+            read_publish(&mut broker).await,
+            ("meetcat/availability".to_string(), "offline".to_string())
+        );
+        assert_eq!(read_packet(&mut broker).await, (14, Vec::new()));
+    }
+}
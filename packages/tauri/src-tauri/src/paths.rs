@@ -0,0 +1,248 @@
+//! Where MeetCat keeps its files
+//!
+//! Settings, logs, diagnostics and state files all live under one data
+//! directory, chosen once per process in this order:
+//! 1. `--data-dir=<path>` on the command line
+//! 2. the `MEETCAT_DATA_DIR` environment variable
+//! 3. portable mode (`--portable` or `MEETCAT_PORTABLE=1`): `meetcat-data`
+//!    next to the executable
+//! 4. `meetcat` inside the OS user's config directory
+//!
+//! The default is per OS user, so users sharing a machine never see each
+//! other's settings or logs. The overrides let a shared admin account or a
+//! synced folder hold the data instead.
+
+use serde::Serialize;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+pub const PORTABLE_FLAG: &str = "--portable";
+pub const DATA_DIR_FLAG: &str = "--data-dir";
+pub const DATA_DIR_ENV: &str = "MEETCAT_DATA_DIR";
+pub const PORTABLE_ENV: &str = "MEETCAT_PORTABLE";
+
+/// Folder created next to the executable in portable mode
+const PORTABLE_DIR_NAME: &str = "meetcat-data";
+const APP_DIR_NAME: &str = "meetcat";
+
+/// Why the data directory is where it is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DataDirSource {
+    Argument,
+    Environment,
+    Portable,
+    UserConfig,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataDir {
+    pub path: PathBuf,
+    pub source: DataDirSource,
+}
+
+impl DataDir {
+    /// Arguments that make a relaunch (e.g. at login) pick the same directory
+    pub fn relaunch_args(&self) -> Vec<String> {
+        match self.source {
+            DataDirSource::UserConfig => Vec::new(),
+            DataDirSource::Portable => vec![PORTABLE_FLAG.to_string()],
+            DataDirSource::Argument | DataDirSource::Environment => {
+                vec![format!("{}={}", DATA_DIR_FLAG, self.path.display())]
+            }
+        }
+    }
+}
+
+/// Data directory for this process, resolved on first use
+pub fn data_dir() -> Option<&'static DataDir> {
+    static DATA_DIR: OnceLock<Option<DataDir>> = OnceLock::new();
+    DATA_DIR
+        .get_or_init(|| {
+            let cwd = std::env::current_dir().ok();
+            resolve_data_dir(
+                std::env::args_os().skip(1),
+                |key| std::env::var_os(key),
+                std::env::current_exe().ok().as_deref(),
+                dirs::config_dir(),
+                cwd.as_deref(),
+            )
+        })
+        .as_ref()
+}
+
+/// `settings.json`
+pub fn settings_file() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.path.join("settings.json"))
+}
+
+/// A state file stored directly in the data directory
+pub fn state_file(name: &str) -> Option<PathBuf> {
+    data_dir().map(|dir| dir.path.join(name))
+}
+
+/// Log files and weekly digests
+pub fn logs_dir() -> PathBuf {
+    base_dir().join("logs")
+}
+
+/// Screenshots and health reports
+pub fn diagnostics_dir() -> PathBuf {
+    base_dir().join("diagnostics")
+}
+
+/// Webview session data of the extra account profiles, one folder each
+pub fn profiles_dir() -> PathBuf {
+    base_dir().join("profiles")
+}
+
+/// Data directory, or the working directory when none can be resolved
+fn base_dir() -> PathBuf {
+    data_dir()
+        .map(|dir| dir.path.clone())
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+fn resolve_data_dir(
+    args: impl IntoIterator<Item = OsString>,
+    env: impl Fn(&str) -> Option<OsString>,
+    exe: Option<&Path>,
+    config_dir: Option<PathBuf>,
+    cwd: Option<&Path>,
+) -> Option<DataDir> {
+    let absolute = |path: PathBuf| match cwd {
+        Some(cwd) if path.is_relative() => cwd.join(path),
+        _ => path,
+    };
+
+    let mut portable = false;
+    let mut from_arg = None;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let arg = arg.to_string_lossy().into_owned();
+        if arg == PORTABLE_FLAG {
+            portable = true;
+        } else if arg == DATA_DIR_FLAG {
+            from_arg = args.next().map(PathBuf::from);
+        } else if let Some(value) = arg
+            .strip_prefix(DATA_DIR_FLAG)
+            .and_then(|v| v.strip_prefix('='))
+        {
+            from_arg = Some(PathBuf::from(value));
+        }
+    }
+    let from_arg = from_arg.filter(|p| !p.as_os_str().is_empty());
+    if let Some(path) = from_arg {
+        return Some(DataDir {
+            path: absolute(path),
+            source: DataDirSource::Argument,
+        });
+    }
+
+    if let Some(path) = env(DATA_DIR_ENV).filter(|v| !v.is_empty()) {
+        return Some(DataDir {
+            path: absolute(PathBuf::from(path)),
+            source: DataDirSource::Environment,
+        });
+    }
+
+    portable |= env(PORTABLE_ENV).is_some_and(|v| is_truthy(&v.to_string_lossy()));
+    if portable {
+        if let Some(exe_dir) = exe.and_then(Path::parent) {
+            return Some(DataDir {
+                path: exe_dir.join(PORTABLE_DIR_NAME),
+                source: DataDirSource::Portable,
+            });
+        }
+    }
+
+    config_dir.map(|dir| DataDir {
+        path: dir.join(APP_DIR_NAME),
+        source: DataDirSource::UserConfig,
+    })
+}
+
+fn is_truthy(value: &str) -> bool {
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "1" | "true" | "yes" | "on"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn resolve(args: &[&str], env: &[(&str, &str)]) -> Option<DataDir> {
+        let env: HashMap<String, OsString> = env
+            .iter()
+            .map(|(k, v)| (k.to_string(), OsString::from(v)))
+            .collect();
+        resolve_data_dir(
+            args.iter().map(OsString::from),
+            |key| env.get(key).cloned(),
+            Some(Path::new("/opt/MeetCat/meetcat")),
+            Some(PathBuf::from("/home/alice/.config")),
+            Some(Path::new("/work")),
+        )
+    }
+
+    #[test]
+    fn test_defaults_to_user_config_dir() {
+        let dir = resolve(&[], &[]).unwrap();
+        assert_eq!(dir.path, PathBuf::from("/home/alice/.config/meetcat"));
+        assert_eq!(dir.source, DataDirSource::UserConfig);
+        assert!(dir.relaunch_args().is_empty());
+        assert_eq!(
+            resolve_data_dir(Vec::<OsString>::new(), |_| None, None, None, None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_portable_mode_uses_executable_dir() {
+        let expected = PathBuf::from("/opt/MeetCat/meetcat-data");
+        let dir = resolve(&["--portable"], &[]).unwrap();
+        assert_eq!(dir.path, expected);
+        assert_eq!(dir.source, DataDirSource::Portable);
+        assert_eq!(dir.relaunch_args(), vec!["--portable".to_string()]);
+
+        assert_eq!(
+            resolve(&[], &[(PORTABLE_ENV, "true")]).unwrap().path,
+            expected
+        );
+        assert_eq!(
+            resolve(&[], &[(PORTABLE_ENV, "0")]).unwrap().source,
+            DataDirSource::UserConfig
+        );
+    }
+
+    #[test]
+    fn test_explicit_data_dir_wins() {
+        let dir = resolve(
+            &["--portable", "--data-dir=sync/meetcat"],
+            &[(DATA_DIR_ENV, "/env/meetcat")],
+        )
+        .unwrap();
+        assert_eq!(dir.path, PathBuf::from("/work/sync/meetcat"));
+        assert_eq!(dir.source, DataDirSource::Argument);
+        assert_eq!(
+            dir.relaunch_args(),
+            vec!["--data-dir=/work/sync/meetcat".to_string()]
+        );
+
+        let dir = resolve(&["--data-dir", "/shared"], &[]).unwrap();
+        assert_eq!(dir.path, PathBuf::from("/shared"));
+
+        let dir = resolve(&["--portable"], &[(DATA_DIR_ENV, "/env/meetcat")]).unwrap();
+        assert_eq!(dir.path, PathBuf::from("/env/meetcat"));
+        assert_eq!(dir.source, DataDirSource::Environment);
+
+        // An empty override falls through
+        let dir = resolve(&["--data-dir="], &[(DATA_DIR_ENV, "")]).unwrap();
+        assert_eq!(dir.source, DataDirSource::UserConfig);
+    }
+}
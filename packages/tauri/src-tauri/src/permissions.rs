@@ -0,0 +1,240 @@
+//! Camera and microphone permission preflight
+//!
+//! Meet asks for media through the webview, and a denied macOS privacy
+//! permission only shows up as a failed getUserMedia once a call has started.
+//! The status is read from AVFoundation instead, so a denial can be surfaced
+//! with a link to the right System Settings pane before the first auto-join.
+//! Other platforms don't gate the webview's media access and report `Unknown`.
+
+use serde::{Deserialize, Serialize};
+
+/// Media device covered by a privacy permission
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MediaDevice {
+    Camera,
+    Microphone,
+}
+
+/// Privacy permission status of one media device
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PermissionStatus {
+    Authorized,
+    Denied,
+    /// Blocked by a device policy; the user can't grant it
+    Restricted,
+    /// Not asked yet; the next request shows the system prompt
+    Undetermined,
+    /// The platform has no per-app permission to check
+    Unknown,
+}
+
+impl PermissionStatus {
+    /// Map an `AVAuthorizationStatus` value
+    pub fn from_av_status(status: isize) -> Self {
+        match status {
+            0 => PermissionStatus::Undetermined,
+            1 => PermissionStatus::Restricted,
+            2 => PermissionStatus::Denied,
+            3 => PermissionStatus::Authorized,
+            _ => PermissionStatus::Unknown,
+        }
+    }
+
+    /// Whether only the user changing System Settings can grant access
+    pub fn needs_settings(self) -> bool {
+        matches!(
+            self,
+            PermissionStatus::Denied | PermissionStatus::Restricted
+        )
+    }
+}
+
+/// Permission status of the camera and microphone
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaPermissions {
+    pub camera: PermissionStatus,
+    pub microphone: PermissionStatus,
+}
+
+impl MediaPermissions {
+    /// Devices the user has to enable in System Settings
+    pub fn blocked_devices(&self) -> Vec<MediaDevice> {
+        let mut blocked = Vec::new();
+        if self.camera.needs_settings() {
+            blocked.push(MediaDevice::Camera);
+        }
+        if self.microphone.needs_settings() {
+            blocked.push(MediaDevice::Microphone);
+        }
+        blocked
+    }
+}
+
+/// System Settings pane where the permission for `device` is granted
+pub fn settings_url(device: MediaDevice) -> Option<&'static str> {
+    if !cfg!(target_os = "macos") {
+        return None;
+    }
+    Some(match device {
+        MediaDevice::Camera => {
+            "x-apple.systempreferences:com.apple.preference.security?Privacy_Camera"
+        }
+        MediaDevice::Microphone => {
+            "x-apple.systempreferences:com.apple.preference.security?Privacy_Microphone"
+        }
+    })
+}
+
+/// Current permission status, without prompting
+pub fn check() -> MediaPermissions {
+    MediaPermissions {
+        camera: status(MediaDevice::Camera),
+        microphone: status(MediaDevice::Microphone),
+    }
+}
+
+/// Prompt for every device still undetermined, then report the status.
+///
+/// Devices already decided are left alone, since macOS only prompts once.
+pub async fn request() -> MediaPermissions {
+    for device in [MediaDevice::Camera, MediaDevice::Microphone] {
+        if status(device) == PermissionStatus::Undetermined {
+            request_access(device).await;
+        }
+    }
+    check()
+}
+
+#[cfg(target_os = "macos")]
+fn status(device: MediaDevice) -> PermissionStatus {
+    PermissionStatus::from_av_status(avfoundation::authorization_status(device))
+}
+
+#[cfg(not(target_os = "macos"))]
+fn status(_device: MediaDevice) -> PermissionStatus {
+    PermissionStatus::Unknown
+}
+
+#[cfg(target_os = "macos")]
+async fn request_access(device: MediaDevice) {
+    let (tx, rx) = tokio::sync::oneshot::channel::<bool>();
+    avfoundation::request_access(device, tx);
+    let _ = rx.await;
+}
+
+#[cfg(not(target_os = "macos"))]
+async fn request_access(_device: MediaDevice) {}
+
+#[cfg(target_os = "macos")]
+mod avfoundation {
+    use super::MediaDevice;
+    use block2::RcBlock;
+    use objc2::runtime::Bool;
+    use objc2::{class, msg_send};
+    use objc2_foundation::NSString;
+    use std::sync::Mutex;
+
+    #[link(name = "AVFoundation", kind = "framework")]
+    extern "C" {
+        static AVMediaTypeVideo: &'static NSString;
+        static AVMediaTypeAudio: &'static NSString;
+    }
+
+    fn media_type(device: MediaDevice) -> &'static NSString {
+        // SAFETY: both are immutable NSString constants exported by
+        // AVFoundation, which the link attribute above loads.
+        unsafe {
+            match device {
+                MediaDevice::Camera => AVMediaTypeVideo,
+                MediaDevice::Microphone => AVMediaTypeAudio,
+            }
+        }
+    }
+
+    pub fn authorization_status(device: MediaDevice) -> isize {
+        // SAFETY: `authorizationStatusForMediaType:` takes an AVMediaType and
+        // returns an NSInteger-backed AVAuthorizationStatus.
+        unsafe {
+            msg_send![
+                class!(AVCaptureDevice),
+                authorizationStatusForMediaType: media_type(device)
+            ]
+        }
+    }
+
+    /// Ask for access; `tx` gets the answer from AVFoundation's callback queue
+    pub fn request_access(device: MediaDevice, tx: tokio::sync::oneshot::Sender<bool>) {
+        let tx = Mutex::new(Some(tx));
+        let handler = RcBlock::new(move |granted: Bool| {
+            if let Some(tx) = tx.lock().ok().and_then(|mut tx| tx.take()) {
+                let _ = tx.send(granted.as_bool());
+            }
+        });
+        // SAFETY: the block matches the `void (^)(BOOL granted)` completion
+        // handler and is retained by AVFoundation until it runs.
+        unsafe {
+            let _: () = msg_send![
+                class!(AVCaptureDevice),
+                requestAccessForMediaType: media_type(device),
+                completionHandler: &*handler
+            ];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_av_status() {
+        assert_eq!(
+            PermissionStatus::from_av_status(0),
+            PermissionStatus::Undetermined
+        );
+        assert_eq!(
+            PermissionStatus::from_av_status(1),
+            PermissionStatus::Restricted
+        );
+        assert_eq!(
+            PermissionStatus::from_av_status(2),
+            PermissionStatus::Denied
+        );
+        assert_eq!(
+            PermissionStatus::from_av_status(3),
+            PermissionStatus::Authorized
+        );
+        assert_eq!(
+            PermissionStatus::from_av_status(7),
+            PermissionStatus::Unknown
+        );
+    }
+
+    #[test]
+    fn test_blocked_devices() {
+        let permissions = MediaPermissions {
+            camera: PermissionStatus::Denied,
+            microphone: PermissionStatus::Undetermined,
+        };
+        assert_eq!(permissions.blocked_devices(), vec![MediaDevice::Camera]);
+
+        let permissions = MediaPermissions {
+            camera: PermissionStatus::Authorized,
+            microphone: PermissionStatus::Restricted,
+        };
+        assert_eq!(permissions.blocked_devices(), vec![MediaDevice::Microphone]);
+
+        let permissions = MediaPermissions {
+            camera: PermissionStatus::Unknown,
+            microphone: PermissionStatus::Unknown,
+        };
+        assert!(permissions.blocked_devices().is_empty());
+        assert_eq!(
+            serde_json::to_value(&permissions).unwrap(),
+            serde_json::json!({ "camera": "unknown", "microphone": "unknown" })
+        );
+    }
+}
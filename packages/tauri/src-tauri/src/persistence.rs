@@ -0,0 +1,284 @@
+//! Versioned state files with migrations and quarantine
+//!
+//! State files are written as `{ "version": N, "data": ... }`. On load, older
+//! versions are upgraded one step at a time through the type's `migrate`
+//! function; files written before the envelope existed count as version 0.
+//! A file that cannot be read, parsed or migrated is renamed to
+//! `<name>.corrupt-<ts>` and the caller continues with default state, so a
+//! format change never blocks startup.
+//!
+//! Every state file is replaced through [`write_atomically`], so a crash
+//! mid-write leaves the previous file rather than a torn one that would be
+//! quarantined on the next start.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// State that is persisted in a versioned envelope
+pub trait VersionedState: Serialize + DeserializeOwned + Default {
+    /// Version written by this release
+    const CURRENT_VERSION: u32;
+
+    /// Upgrade `data` from `from_version` to `from_version + 1`
+    fn migrate(from_version: u32, data: Value) -> Result<Value, String>;
+}
+
+#[derive(Debug, Serialize)]
+struct Envelope<T> {
+    version: u32,
+    data: T,
+}
+
+/// A state file moved aside because it could not be loaded
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuarantinedFile {
+    pub original: PathBuf,
+    pub moved_to: PathBuf,
+    pub reason: String,
+}
+
+/// Result of loading a versioned state file
+#[derive(Debug)]
+pub struct LoadedState<T> {
+    pub state: T,
+    /// Version the file was upgraded from, when a migration ran
+    pub migrated_from: Option<u32>,
+    pub quarantined: Option<QuarantinedFile>,
+}
+
+/// Load `path`, migrating older versions and quarantining unreadable files.
+///
+/// A missing file yields default state without a quarantine.
+pub fn load_versioned<T: VersionedState>(path: &Path, now_ms: u64) -> LoadedState<T> {
+    let decoded = match fs::read_to_string(path) {
+        Ok(content) => decode::<T>(&content),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return LoadedState {
+                state: T::default(),
+                migrated_from: None,
+                quarantined: None,
+            };
+        }
+        Err(e) => Err(e.to_string()),
+    };
+
+    match decoded {
+        Ok((state, migrated_from)) => {
+            if migrated_from.is_some() {
+                // Rewrite right away so the upgrade only runs once
+                let _ = save_versioned(path, &state);
+            }
+            LoadedState {
+                state,
+                migrated_from,
+                quarantined: None,
+            }
+        }
+        Err(reason) => LoadedState {
+            state: T::default(),
+            migrated_from: None,
+            quarantined: Some(quarantine(path, now_ms, reason)),
+        },
+    }
+}
+
+/// Write `state` wrapped in an envelope with the current version
+pub fn save_versioned<T: VersionedState>(path: &Path, state: &T) -> Result<(), String> {
+    let envelope = Envelope {
+        version: T::CURRENT_VERSION,
+        data: state,
+    };
+    let content = serde_json::to_string_pretty(&envelope).map_err(|e| e.to_string())?;
+    write_atomically(path, &content).map_err(|e| e.to_string())
+}
+
+/// Replace `path` with `content` through a synced temporary file next to it
+pub fn write_atomically(path: &Path, content: &str) -> std::io::Result<()> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let temp = path.with_file_name(format!("{}.tmp", file_name));
+    let result = fs::File::create(&temp)
+        .and_then(|mut file| {
+            file.write_all(content.as_bytes())?;
+            file.sync_all()
+        })
+        .and_then(|()| fs::rename(&temp, path));
+    if let Err(e) = result {
+        let _ = fs::remove_file(&temp);
+        return Err(e);
+    }
+    #[cfg(unix)]
+    if let Some(dir) = path.parent() {
+        // Persist the rename itself
+        if let Ok(dir) = fs::File::open(dir) {
+            let _ = dir.sync_all();
+        }
+    }
+    Ok(())
+}
+
+/// Parse file content, returning the state and the version it was migrated from
+fn decode<T: VersionedState>(content: &str) -> Result<(T, Option<u32>), String> {
+    let raw: Value = serde_json::from_str(content).map_err(|e| e.to_string())?;
+    let (version, mut data) = match raw {
+        Value::Object(mut map) if map.contains_key("version") && map.contains_key("data") => {
+            let version = map
+                .get("version")
+                .and_then(Value::as_u64)
+                .ok_or_else(|| "invalid version field".to_string())?;
+            let version = u32::try_from(version).map_err(|e| e.to_string())?;
+            (version, map.remove("data").unwrap_or(Value::Null))
+        }
+        legacy => (0, legacy),
+    };
+
+    if version > T::CURRENT_VERSION {
+        return Err(format!(
+            "written by a newer release (version {}, supported {})",
+            version,
+            T::CURRENT_VERSION
+        ));
+    }
+    for from_version in version..T::CURRENT_VERSION {
+        data = T::migrate(from_version, data)
+            .map_err(|e| format!("migration from version {} failed: {}", from_version, e))?;
+    }
+
+    let state = serde_json::from_value(data).map_err(|e| e.to_string())?;
+    let migrated_from = (version < T::CURRENT_VERSION).then_some(version);
+    Ok((state, migrated_from))
+}
+
+/// Rename an unreadable file to `<name>.corrupt-<ts>`
+pub fn quarantine(path: &Path, now_ms: u64, reason: String) -> QuarantinedFile {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let moved_to = path.with_file_name(format!("{}.corrupt-{}", file_name, now_ms));
+    let reason = match fs::rename(path, &moved_to) {
+        Ok(()) => reason,
+        Err(e) => format!("{} (rename failed: {})", reason, e),
+    };
+    QuarantinedFile {
+        original: path.to_path_buf(),
+        moved_to,
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct TestState {
+        ids: Vec<String>,
+        limit: u32,
+    }
+
+    impl VersionedState for TestState {
+        const CURRENT_VERSION: u32 = 2;
+
+        fn migrate(from_version: u32, data: Value) -> Result<Value, String> {
+            match from_version {
+                // v0 stored a bare list of ids
+                0 => Ok(serde_json::json!({ "ids": data })),
+                // v2 added `limit`
+                1 => {
+                    let mut map = data.as_object().cloned().ok_or("expected object")?;
+                    map.insert("limit".to_string(), Value::from(5));
+                    Ok(Value::Object(map))
+                }
+                _ => Err(format!("unknown version {}", from_version)),
+            }
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "meetcat-persistence-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir.join("state.json")
+    }
+
+    #[test]
+    fn test_round_trip_and_missing_file() {
+        let path = temp_path("roundtrip");
+        let outcome = load_versioned::<TestState>(&path, 0);
+        assert_eq!(outcome.state, TestState::default());
+        assert!(outcome.quarantined.is_none());
+
+        let state = TestState {
+            ids: vec!["abc".to_string()],
+            limit: 3,
+        };
+        save_versioned(&path, &state).unwrap();
+        let outcome = load_versioned::<TestState>(&path, 0);
+        assert_eq!(outcome.state, state);
+        assert_eq!(outcome.migrated_from, None);
+    }
+
+    #[test]
+    fn test_write_atomically_replaces_without_leftovers() {
+        let path = temp_path("atomic");
+        fs::write(&path, "old").unwrap();
+        write_atomically(&path, "new").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert!(!path.with_file_name("state.json.tmp").exists());
+
+        // A failed write leaves the old file alone
+        let missing_dir = path.with_file_name("missing").join("state.json");
+        assert!(write_atomically(&missing_dir, "new").is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+    }
+
+    #[test]
+    fn test_legacy_file_migrates_through_each_version() {
+        let path = temp_path("legacy");
+        fs::write(&path, r#"["abc", "def"]"#).unwrap();
+
+        let outcome = load_versioned::<TestState>(&path, 0);
+        assert_eq!(outcome.migrated_from, Some(0));
+        assert_eq!(outcome.state.ids, vec!["abc", "def"]);
+        assert_eq!(outcome.state.limit, 5);
+
+        // The upgraded file was written back in the current format
+        let raw: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(raw["version"], 2);
+    }
+
+    #[test]
+    fn test_unreadable_files_are_quarantined() {
+        for (name, content) in [
+            ("garbage", "{ not json"),
+            ("newer", r#"{ "version": 9, "data": {} }"#),
+            ("bad-data", r#"{ "version": 2, "data": { "ids": 1 } }"#),
+        ] {
+            let path = temp_path(name);
+            fs::write(&path, content).unwrap();
+
+            let outcome = load_versioned::<TestState>(&path, 1_234);
+            assert_eq!(outcome.state, TestState::default(), "{}", name);
+            let quarantined = outcome.quarantined.expect(name);
+            assert_eq!(
+                quarantined.moved_to.file_name().unwrap(),
+                "state.json.corrupt-1234"
+            );
+            assert!(!path.exists());
+            assert_eq!(fs::read_to_string(&quarantined.moved_to).unwrap(), content);
+        }
+    }
+}
@@ -0,0 +1,548 @@
+//! Screen lock, system sleep and display sleep
+//!
+//! Auto-joins are held while the screen is locked or the system or display
+//! sleeps, and the join trigger is re-evaluated as soon as that ends, so a
+//! meeting that started meanwhile still joins if it is within
+//! `maxMinutesAfterStart`.
+//!
+//! Every platform reports changes as they happen:
+//!
+//! - macOS through NSWorkspace and the distributed screen lock notifications
+//! - Windows through a hidden window registered for session changes
+//!   (`WM_WTSSESSION_CHANGE`) and power broadcasts (`WM_POWERBROADCAST`)
+//! - Linux through logind's D-Bus signals: the session's `Lock` / `Unlock`
+//!   and `LockedHint` changes, and the manager's `PrepareForSleep`
+//!
+//! Where none of these is available, e.g. without a system bus, a poller
+//! reads the session's lock state where [`crate::idle`] can tell, and treats
+//! a jump in the wall clock between polls as the system waking up.
+
+use serde::Serialize;
+use std::sync::Arc;
+
+/// Called with every change, on whichever thread observed it
+pub type Handler = Arc<dyn Fn(PowerEvent) + Send + Sync>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PowerEvent {
+    Locked,
+    Unlocked,
+    Sleep,
+    Wake,
+    DisplaySleep,
+    DisplayWake,
+}
+
+/// What currently keeps the user from a join
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PowerState {
+    pub locked: bool,
+    pub asleep: bool,
+    pub display_asleep: bool,
+}
+
+impl PowerState {
+    /// Whether auto-joins should wait
+    pub fn holds_joins(&self) -> bool {
+        self.locked || self.asleep || self.display_asleep
+    }
+
+    /// Apply `event`; returns true when the join trigger should be
+    /// re-evaluated, i.e. joins were held and no longer are, or the system
+    /// just woke up
+    pub fn apply(&mut self, event: PowerEvent) -> bool {
+        let held = self.holds_joins();
+        match event {
+            PowerEvent::Locked => self.locked = true,
+            PowerEvent::Unlocked => self.locked = false,
+            PowerEvent::Sleep => self.asleep = true,
+            PowerEvent::Wake => self.asleep = false,
+            PowerEvent::DisplaySleep => self.display_asleep = true,
+            PowerEvent::DisplayWake => self.display_asleep = false,
+        }
+        event == PowerEvent::Wake || (held && !self.holds_joins())
+    }
+}
+
+/// Start reporting changes to `handler` for the life of the app
+pub fn watch(handler: Handler) {
+    #[cfg(target_os = "macos")]
+    {
+        workspace::observe(handler);
+    }
+    #[cfg(target_os = "windows")]
+    {
+        wts::observe(handler);
+    }
+    #[cfg(target_os = "linux")]
+    {
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = logind::observe(handler.clone()).await {
+                eprintln!("[MeetCat] logind unavailable, polling instead: {}", e);
+                poll(handler);
+            }
+        });
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    {
+        poll(handler);
+    }
+}
+
+/// Whether the screen is locked, as last reported by the session
+#[cfg(target_os = "windows")]
+pub fn screen_locked() -> bool {
+    wts::LOCKED.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+/// The event for a lock state just read, if it differs from `locked`
+#[cfg_attr(target_os = "macos", allow(dead_code))]
+fn lock_change(locked: &mut bool, now_locked: bool) -> Option<PowerEvent> {
+    if *locked == now_locked {
+        return None;
+    }
+    *locked = now_locked;
+    Some(if now_locked {
+        PowerEvent::Locked
+    } else {
+        PowerEvent::Unlocked
+    })
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn poll(handler: Handler) {
+    use std::time::SystemTime;
+
+    std::thread::spawn(move || {
+        let mut locked = false;
+        let mut last_poll = SystemTime::now();
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+            let now = SystemTime::now();
+            if slept_between(POLL_INTERVAL, now.duration_since(last_poll).ok()) {
+                handler(PowerEvent::Wake);
+            }
+            last_poll = now;
+
+            let now_locked = crate::idle::current().is_some_and(|idle| idle.locked);
+            if let Some(event) = lock_change(&mut locked, now_locked) {
+                handler(event);
+            }
+        }
+    });
+}
+
+/// Whether a poll meant to run `interval` after the last one came so late
+/// that the system must have been asleep; `elapsed` is `None` when the wall
+/// clock went backwards
+#[cfg_attr(any(target_os = "macos", target_os = "windows"), allow(dead_code))]
+fn slept_between(interval: std::time::Duration, elapsed: Option<std::time::Duration>) -> bool {
+    const SLACK: std::time::Duration = std::time::Duration::from_secs(30);
+    elapsed.is_some_and(|elapsed| elapsed > interval + SLACK)
+}
+
+#[cfg(target_os = "macos")]
+mod workspace {
+    use super::{Handler, PowerEvent};
+    use block2::RcBlock;
+    use objc2::rc::Retained;
+    use objc2::runtime::AnyObject;
+    use objc2::{class, msg_send};
+    use objc2_foundation::NSString;
+    use std::ptr::NonNull;
+
+    #[link(name = "AppKit", kind = "framework")]
+    extern "C" {
+        static NSWorkspaceWillSleepNotification: &'static NSString;
+        static NSWorkspaceDidWakeNotification: &'static NSString;
+        static NSWorkspaceScreensDidSleepNotification: &'static NSString;
+        static NSWorkspaceScreensDidWakeNotification: &'static NSString;
+    }
+
+    pub fn observe(handler: Handler) {
+        // SAFETY: both centers are shared singletons, and the notification
+        // names are immutable NSString constants exported by AppKit.
+        unsafe {
+            let workspace: Retained<AnyObject> = msg_send![class!(NSWorkspace), sharedWorkspace];
+            let center: Retained<AnyObject> = msg_send![&workspace, notificationCenter];
+            add_observer(
+                &center,
+                NSWorkspaceWillSleepNotification,
+                PowerEvent::Sleep,
+                &handler,
+            );
+            add_observer(
+                &center,
+                NSWorkspaceDidWakeNotification,
+                PowerEvent::Wake,
+                &handler,
+            );
+            add_observer(
+                &center,
+                NSWorkspaceScreensDidSleepNotification,
+                PowerEvent::DisplaySleep,
+                &handler,
+            );
+            add_observer(
+                &center,
+                NSWorkspaceScreensDidWakeNotification,
+                PowerEvent::DisplayWake,
+                &handler,
+            );
+
+            let distributed: Retained<AnyObject> =
+                msg_send![class!(NSDistributedNotificationCenter), defaultCenter];
+            let locked = NSString::from_str("com.apple.screenIsLocked");
+            let unlocked = NSString::from_str("com.apple.screenIsUnlocked");
+            add_observer(&distributed, &locked, PowerEvent::Locked, &handler);
+            add_observer(&distributed, &unlocked, PowerEvent::Unlocked, &handler);
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `center` must be an NSNotificationCenter.
+    unsafe fn add_observer(
+        center: &AnyObject,
+        name: &NSString,
+        event: PowerEvent,
+        handler: &Handler,
+    ) {
+        let handler = handler.clone();
+        let block = RcBlock::new(move |_notification: NonNull<AnyObject>| handler(event));
+        let observer: Retained<AnyObject> = msg_send![
+            center,
+            addObserverForName: name,
+            object: std::ptr::null::<AnyObject>(),
+            queue: std::ptr::null::<AnyObject>(),
+            usingBlock: &*block
+        ];
+        // Observers stay registered for the life of the app
+        std::mem::forget(observer);
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod wts {
+    //! A hidden top-level window: session changes are sent to windows
+    //! registered for them, and power broadcasts only reach top-level
+    //! windows, not message-only ones.
+
+    use super::{lock_change, Handler, PowerEvent};
+    use std::ffi::c_void;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::OnceLock;
+
+    type Hwnd = *mut c_void;
+    type WndProc = unsafe extern "system" fn(Hwnd, u32, usize, isize) -> isize;
+
+    #[repr(C)]
+    struct WndClassW {
+        style: u32,
+        wnd_proc: Option<WndProc>,
+        cls_extra: i32,
+        wnd_extra: i32,
+        instance: *mut c_void,
+        icon: *mut c_void,
+        cursor: *mut c_void,
+        background: *mut c_void,
+        menu_name: *const u16,
+        class_name: *const u16,
+    }
+
+    #[repr(C)]
+    struct Msg {
+        hwnd: Hwnd,
+        message: u32,
+        wparam: usize,
+        lparam: isize,
+        time: u32,
+        pt_x: i32,
+        pt_y: i32,
+    }
+
+    #[link(name = "user32")]
+    extern "system" {
+        fn RegisterClassW(class: *const WndClassW) -> u16;
+        fn CreateWindowExW(
+            ex_style: u32,
+            class_name: *const u16,
+            window_name: *const u16,
+            style: u32,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+            parent: Hwnd,
+            menu: *mut c_void,
+            instance: *mut c_void,
+            param: *const c_void,
+        ) -> Hwnd;
+        fn DefWindowProcW(hwnd: Hwnd, message: u32, wparam: usize, lparam: isize) -> isize;
+        fn GetMessageW(msg: *mut Msg, hwnd: Hwnd, min: u32, max: u32) -> i32;
+        fn DispatchMessageW(msg: *const Msg) -> isize;
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetModuleHandleW(name: *const u16) -> *mut c_void;
+    }
+
+    #[link(name = "wtsapi32")]
+    extern "system" {
+        fn WTSRegisterSessionNotification(hwnd: Hwnd, flags: u32) -> i32;
+    }
+
+    const WM_WTSSESSION_CHANGE: u32 = 0x02B1;
+    const WTS_SESSION_LOCK: usize = 0x7;
+    const WTS_SESSION_UNLOCK: usize = 0x8;
+    const NOTIFY_FOR_THIS_SESSION: u32 = 0;
+    const WM_POWERBROADCAST: u32 = 0x0218;
+    const PBT_APMSUSPEND: usize = 0x4;
+    const PBT_APMRESUMEAUTOMATIC: usize = 0x12;
+
+    /// Lock state of this session, as last reported
+    pub static LOCKED: AtomicBool = AtomicBool::new(false);
+    static HANDLER: OnceLock<Handler> = OnceLock::new();
+
+    pub fn observe(handler: Handler) {
+        if HANDLER.set(handler).is_err() {
+            return;
+        }
+        // The window belongs to the thread that created it and only gets
+        // messages while that thread pumps them
+        std::thread::spawn(|| {
+            let class_name: Vec<u16> = "MeetCatPowerWatcher\0".encode_utf16().collect();
+            // SAFETY: the class name outlives the window, `wnd_proc` matches
+            // the WNDPROC signature, and `msg` outlives each call using it.
+            unsafe {
+                let instance = GetModuleHandleW(std::ptr::null());
+                let class = WndClassW {
+                    style: 0,
+                    wnd_proc: Some(wnd_proc),
+                    cls_extra: 0,
+                    wnd_extra: 0,
+                    instance,
+                    icon: std::ptr::null_mut(),
+                    cursor: std::ptr::null_mut(),
+                    background: std::ptr::null_mut(),
+                    menu_name: std::ptr::null(),
+                    class_name: class_name.as_ptr(),
+                };
+                if RegisterClassW(&class) == 0 {
+                    eprintln!("[MeetCat] Failed to register the power watcher window");
+                    return;
+                }
+                // Never shown: no WS_VISIBLE
+                let hwnd = CreateWindowExW(
+                    0,
+                    class_name.as_ptr(),
+                    class_name.as_ptr(),
+                    0,
+                    0,
+                    0,
+                    0,
+                    0,
+                    std::ptr::null_mut(),
+                    std::ptr::null_mut(),
+                    instance,
+                    std::ptr::null(),
+                );
+                if hwnd.is_null() {
+                    eprintln!("[MeetCat] Failed to create the power watcher window");
+                    return;
+                }
+                if WTSRegisterSessionNotification(hwnd, NOTIFY_FOR_THIS_SESSION) == 0 {
+                    eprintln!("[MeetCat] Failed to register for session lock changes");
+                }
+                let mut msg: Msg = std::mem::zeroed();
+                while GetMessageW(&mut msg, std::ptr::null_mut(), 0, 0) > 0 {
+                    DispatchMessageW(&msg);
+                }
+            }
+        });
+    }
+
+    unsafe extern "system" fn wnd_proc(
+        hwnd: Hwnd,
+        message: u32,
+        wparam: usize,
+        lparam: isize,
+    ) -> isize {
+        let event = match (message, wparam) {
+            (WM_WTSSESSION_CHANGE, WTS_SESSION_LOCK) => lock_event(true),
+            (WM_WTSSESSION_CHANGE, WTS_SESSION_UNLOCK) => lock_event(false),
+            (WM_POWERBROADCAST, PBT_APMSUSPEND) => Some(PowerEvent::Sleep),
+            (WM_POWERBROADCAST, PBT_APMRESUMEAUTOMATIC) => Some(PowerEvent::Wake),
+            _ => None,
+        };
+        if let (Some(event), Some(handler)) = (event, HANDLER.get()) {
+            handler(event);
+        }
+        DefWindowProcW(hwnd, message, wparam, lparam)
+    }
+
+    fn lock_event(now_locked: bool) -> Option<PowerEvent> {
+        let mut locked = LOCKED.load(Ordering::Relaxed);
+        let event = lock_change(&mut locked, now_locked);
+        LOCKED.store(locked, Ordering::Relaxed);
+        event
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod logind {
+    use super::{lock_change, Handler, PowerEvent};
+    use futures_util::StreamExt;
+    use std::collections::HashMap;
+    use zbus::message::Type;
+    use zbus::zvariant::{OwnedObjectPath, OwnedValue};
+    use zbus::{Connection, MatchRule, Message, MessageStream};
+
+    const LOGIND: &str = "org.freedesktop.login1";
+    const MANAGER_PATH: &str = "/org/freedesktop/login1";
+    const SESSION_INTERFACE: &str = "org.freedesktop.login1.Session";
+
+    /// Report logind's signals until the bus connection closes
+    pub async fn observe(handler: Handler) -> zbus::Result<()> {
+        let connection = Connection::system().await?;
+        // Same session `crate::idle` asks loginctl about
+        let session_id = std::env::var("XDG_SESSION_ID").unwrap_or_else(|_| "auto".to_string());
+        let session: OwnedObjectPath = connection
+            .call_method(
+                Some(LOGIND),
+                MANAGER_PATH,
+                Some("org.freedesktop.login1.Manager"),
+                "GetSession",
+                &(session_id,),
+            )
+            .await?
+            .body()
+            .deserialize()?;
+
+        let rule = MatchRule::builder()
+            .msg_type(Type::Signal)
+            .sender(LOGIND)?
+            .build();
+        let mut stream = MessageStream::for_match_rule(rule, &connection, None).await?;
+
+        let mut locked = false;
+        if locked_hint(&connection, &session).await? {
+            if let Some(event) = lock_change(&mut locked, true) {
+                handler(event);
+            }
+        }
+        while let Some(message) = stream.next().await {
+            let Ok(message) = message else {
+                continue;
+            };
+            let event = match signal_event(&message, &session) {
+                Some(Signal::Power(event)) => Some(event),
+                Some(Signal::Lock(now_locked)) => lock_change(&mut locked, now_locked),
+                None => None,
+            };
+            if let Some(event) = event {
+                handler(event);
+            }
+        }
+        Ok(())
+    }
+
+    /// Interface, changed values and invalidated names
+    type PropertiesChanged = (String, HashMap<String, OwnedValue>, Vec<String>);
+
+    enum Signal {
+        Power(PowerEvent),
+        Lock(bool),
+    }
+
+    /// `Lock` / `Unlock` ask the session's screen locker to act, and
+    /// `LockedHint` follows once it did; both are taken, as not every
+    /// desktop sets the hint
+    fn signal_event(message: &Message, session: &OwnedObjectPath) -> Option<Signal> {
+        let header = message.header();
+        let on_session = header.path().is_some_and(|path| *path == session.as_ref());
+        match header.member()?.as_str() {
+            "PrepareForSleep" => {
+                let sleeping: bool = message.body().deserialize().ok()?;
+                Some(Signal::Power(if sleeping {
+                    PowerEvent::Sleep
+                } else {
+                    PowerEvent::Wake
+                }))
+            }
+            "Lock" if on_session => Some(Signal::Lock(true)),
+            "Unlock" if on_session => Some(Signal::Lock(false)),
+            "PropertiesChanged" if on_session => {
+                let (interface, mut changed, _): PropertiesChanged =
+                    message.body().deserialize().ok()?;
+                if interface != SESSION_INTERFACE {
+                    return None;
+                }
+                let locked = bool::try_from(changed.remove("LockedHint")?).ok()?;
+                Some(Signal::Lock(locked))
+            }
+            _ => None,
+        }
+    }
+
+    async fn locked_hint(connection: &Connection, session: &OwnedObjectPath) -> zbus::Result<bool> {
+        let value: OwnedValue = connection
+            .call_method(
+                Some(LOGIND),
+                session,
+                Some("org.freedesktop.DBus.Properties"),
+                "Get",
+                &(SESSION_INTERFACE, "LockedHint"),
+            )
+            .await?
+            .body()
+            .deserialize()?;
+        Ok(bool::try_from(value)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_apply_resumes_when_nothing_holds() {
+        let mut state = PowerState::default();
+        assert!(!state.apply(PowerEvent::Locked));
+        assert!(!state.apply(PowerEvent::DisplaySleep));
+        assert!(state.holds_joins());
+
+        // Still held by the sleeping display
+        assert!(!state.apply(PowerEvent::Unlocked));
+        assert!(state.apply(PowerEvent::DisplayWake));
+        assert!(!state.holds_joins());
+
+        // A wake is re-evaluated even if the sleep went unnoticed
+        assert!(state.apply(PowerEvent::Wake));
+    }
+
+    #[test]
+    fn test_lock_change_reports_only_flips() {
+        let mut locked = false;
+        assert_eq!(lock_change(&mut locked, false), None);
+        assert_eq!(lock_change(&mut locked, true), Some(PowerEvent::Locked));
+        // `Lock` followed by the `LockedHint` change is one lock
+        assert_eq!(lock_change(&mut locked, true), None);
+        assert_eq!(lock_change(&mut locked, false), Some(PowerEvent::Unlocked));
+    }
+
+    #[test]
+    fn test_slept_between() {
+        let interval = Duration::from_secs(5);
+        assert!(!slept_between(interval, Some(Duration::from_secs(6))));
+        assert!(slept_between(interval, Some(Duration::from_secs(600))));
+        assert!(!slept_between(interval, None));
+    }
+}
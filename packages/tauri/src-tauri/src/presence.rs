@@ -0,0 +1,238 @@
+//! In-meeting status on chat services
+//!
+//! While a meeting is joined, every enabled [`PresenceProvider`] shows it on
+//! the user's profile ("In a meeting until 10:30"), and the status is cleared
+//! when the meeting page closes. Slack is the only provider so far; another
+//! service needs an implementation of the trait and an entry in [`providers`].
+
+use crate::http_client;
+use crate::i18n::{self, Language};
+use crate::secrets::{self, SecretStore};
+use crate::settings::Settings;
+use chrono::{DateTime, Local, Utc};
+use serde_json::{json, Value};
+use std::future::Future;
+use std::pin::Pin;
+use tauri::Url;
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+const SLACK_PROFILE_SET: &str = "https://slack.com/api/users.profile.set";
+/// Slack's name for 🗓️
+const SLACK_STATUS_EMOJI: &str = ":spiral_calendar_pad:";
+
+/// What a provider shows while in a meeting
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeetingStatus {
+    pub call_id: String,
+    /// End of the meeting, when it's in the meeting list
+    pub ends_at: Option<DateTime<Utc>>,
+}
+
+impl MeetingStatus {
+    /// Status line, with the end time in local time
+    pub fn text(&self, lang: &Language) -> String {
+        let until = self
+            .ends_at
+            .map(|ends_at| ends_at.with_timezone(&Local).format("%H:%M").to_string());
+        i18n::tr_presence_in_meeting(lang, until.as_deref())
+    }
+}
+
+/// A service that can show the user as in a meeting
+pub trait PresenceProvider: Send + Sync {
+    /// Short name used in logs
+    fn name(&self) -> &'static str;
+
+    fn set_in_meeting<'a>(&'a self, status: &'a MeetingStatus)
+        -> BoxFuture<'a, Result<(), String>>;
+
+    fn clear(&self) -> BoxFuture<'_, Result<(), String>>;
+}
+
+/// Providers enabled in `settings` that have what they need to run
+pub fn providers(
+    settings: &Settings,
+    store: &impl SecretStore,
+) -> Result<Vec<Box<dyn PresenceProvider>>, String> {
+    let mut providers: Vec<Box<dyn PresenceProvider>> = Vec::new();
+    if settings.tauri_or_default().slack_status_enabled {
+        if let Some(token) = secrets::slack_token(settings, store) {
+            let lang = Language::from_setting(&settings.language);
+            providers.push(Box::new(SlackStatus::new(settings, token, lang)?));
+        }
+    }
+    Ok(providers)
+}
+
+/// Which meeting, if any, the providers currently show
+///
+/// Only a status MeetCat set is cleared, so a custom status the user picked
+/// outside meetings survives.
+#[derive(Debug, Default)]
+pub struct PresenceSync {
+    active: Option<String>,
+}
+
+impl PresenceSync {
+    /// Record that `call_id` is shown; `false` if it already was
+    pub fn start(&mut self, call_id: &str) -> bool {
+        if self.active.as_deref() == Some(call_id) {
+            return false;
+        }
+        self.active = Some(call_id.to_string());
+        true
+    }
+
+    /// Forget `call_id`; `true` if it was the meeting shown, so the status
+    /// should be cleared
+    pub fn finish(&mut self, call_id: &str) -> bool {
+        if self.active.as_deref() != Some(call_id) {
+            return false;
+        }
+        self.active = None;
+        true
+    }
+
+    /// Forget whatever is shown; `true` if something was
+    pub fn reset(&mut self) -> bool {
+        self.active.take().is_some()
+    }
+}
+
+/// Slack profile status, set through `users.profile.set` with a user token
+pub struct SlackStatus {
+    client: reqwest::Client,
+    token: String,
+    lang: Language,
+}
+
+impl SlackStatus {
+    pub fn new(settings: &Settings, token: String, lang: Language) -> Result<Self, String> {
+        let endpoint = Url::parse(SLACK_PROFILE_SET).map_err(|e| e.to_string())?;
+        Ok(Self {
+            client: http_client::client(settings, &endpoint)?,
+            token,
+            lang,
+        })
+    }
+
+    async fn set_profile(&self, profile: Value) -> Result<(), String> {
+        let response = self
+            .client
+            .post(SLACK_PROFILE_SET)
+            .bearer_auth(&self.token)
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                "application/json; charset=utf-8",
+            )
+            .body(json!({ "profile": profile }).to_string())
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let status = response.status();
+        let body = response.text().await.map_err(|e| e.to_string())?;
+        if !status.is_success() {
+            return Err(format!("Slack answered HTTP {}", status.as_u16()));
+        }
+        parse_slack_response(&body)
+    }
+}
+
+impl PresenceProvider for SlackStatus {
+    fn name(&self) -> &'static str {
+        "slack"
+    }
+
+    fn set_in_meeting<'a>(
+        &'a self,
+        status: &'a MeetingStatus,
+    ) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(self.set_profile(slack_profile(status, &self.lang)))
+    }
+
+    fn clear(&self) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(self.set_profile(json!({
+            "status_text": "",
+            "status_emoji": "",
+            "status_expiration": 0,
+        })))
+    }
+}
+
+/// Profile fields of the in-meeting status. Slack drops it by itself at the
+/// end time, in case MeetCat quits before the meeting closes.
+fn slack_profile(status: &MeetingStatus, lang: &Language) -> Value {
+    json!({
+        "status_text": status.text(lang),
+        "status_emoji": SLACK_STATUS_EMOJI,
+        "status_expiration": status.ends_at.map(|t| t.timestamp()).unwrap_or(0),
+    })
+}
+
+/// Slack answers 200 with `{"ok": false, "error": "..."}` on failure
+fn parse_slack_response(body: &str) -> Result<(), String> {
+    let value: Value =
+        serde_json::from_str(body).map_err(|e| format!("Unexpected Slack response: {}", e))?;
+    if value.get("ok").and_then(Value::as_bool) == Some(true) {
+        return Ok(());
+    }
+    let error = value
+        .get("error")
+        .and_then(Value::as_str)
+        .unwrap_or("unknown_error");
+    Err(format!("Slack error: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_slack_profile_expires_at_meeting_end() {
+        let ends_at = Utc.with_ymd_and_hms(2026, 3, 2, 10, 0, 0).unwrap();
+        let status = MeetingStatus {
+            call_id: "abc-defg-hij".to_string(),
+            ends_at: Some(ends_at),
+        };
+        let local = ends_at.with_timezone(&Local).format("%H:%M").to_string();
+
+        let profile = slack_profile(&status, &Language::En);
+        assert_eq!(
+            profile["status_text"],
+            format!("In a meeting until {}", local)
+        );
+        assert_eq!(profile["status_emoji"], SLACK_STATUS_EMOJI);
+        assert_eq!(profile["status_expiration"], ends_at.timestamp());
+
+        let open_ended = MeetingStatus {
+            ends_at: None,
+            ..status
+        };
+        let profile = slack_profile(&open_ended, &Language::En);
+        assert_eq!(profile["status_text"], "In a meeting");
+        assert_eq!(profile["status_expiration"], 0);
+    }
+
+    #[test]
+    fn test_parse_slack_response() {
+        assert_eq!(parse_slack_response(r#"{"ok":true,"profile":{}}"#), Ok(()));
+        assert_eq!(
+            parse_slack_response(r#"{"ok":false,"error":"missing_scope"}"#),
+            Err("Slack error: missing_scope".to_string())
+        );
+        assert!(parse_slack_response("<html>").is_err());
+    }
+
+    #[test]
+    fn test_presence_sync_clears_only_its_own_meeting() {
+        let mut sync = PresenceSync::default();
+        assert!(!sync.finish("abc-defg-hij"));
+        assert!(sync.start("abc-defg-hij"));
+        assert!(!sync.start("abc-defg-hij"));
+        assert!(!sync.finish("xyz-abcd-efg"));
+        assert!(sync.finish("abc-defg-hij"));
+        assert!(!sync.reset());
+    }
+}
@@ -0,0 +1,143 @@
+//! Extra Google accounts, each in its own window
+//!
+//! The main window keeps the default account. Every configured profile gets
+//! a window labelled `profile-<id>` whose webview stores cookies and site
+//! data apart from the main window, so several accounts stay signed in at
+//! once. Meetings reported by a profile window are joined in that window.
+
+use crate::paths;
+use crate::settings::Profile;
+use tauri::{AppHandle, Manager, Url, WebviewUrl, WebviewWindow, WebviewWindowBuilder};
+use tauri_plugin_opener::OpenerExt;
+
+pub const MAIN_WINDOW: &str = "main";
+const PROFILE_WINDOW_PREFIX: &str = "profile-";
+
+/// Window that shows meetings of `profile`, the main window for none
+pub fn window_label(profile: Option<&str>) -> String {
+    match profile {
+        Some(id) => format!("{}{}", PROFILE_WINDOW_PREFIX, id),
+        None => MAIN_WINDOW.to_string(),
+    }
+}
+
+/// Profile shown in the window labelled `label`, if it is a profile window
+pub fn profile_of_window(label: &str) -> Option<&str> {
+    label.strip_prefix(PROFILE_WINDOW_PREFIX)
+}
+
+/// Whether the window shows Google Meet for some account
+pub fn is_meet_window(label: &str) -> bool {
+    label == MAIN_WINDOW || profile_of_window(label).is_some()
+}
+
+/// Stable identifier of a profile's WebKit data store.
+///
+/// macOS ignores the data directory and separates sessions by this
+/// identifier instead, so it must not change between runs.
+fn session_identifier(id: &str) -> [u8; 16] {
+    // Two FNV-1a passes with different offsets, one per half
+    let fnv = |offset: u64| {
+        id.bytes().fold(offset, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+    };
+    let mut identifier = [0u8; 16];
+    identifier[..8].copy_from_slice(&fnv(0xcbf2_9ce4_8422_2325).to_be_bytes());
+    identifier[8..].copy_from_slice(&fnv(0x6c62_272e_07bb_0142).to_be_bytes());
+    identifier
+}
+
+/// Show the window of `profile`, creating it first if needed
+pub fn open_window(app: &AppHandle, profile: &Profile) -> tauri::Result<WebviewWindow> {
+    let window = ensure_window(app, profile)?;
+    let _ = window.show();
+    let _ = window.unminimize();
+    let _ = window.set_focus();
+    Ok(window)
+}
+
+/// Window of `profile`, created hidden on Meet home when missing.
+///
+/// Like the main window, closing only hides it, so the injected script keeps
+/// reporting the profile's meetings and can join them.
+pub fn ensure_window(app: &AppHandle, profile: &Profile) -> tauri::Result<WebviewWindow> {
+    let label = window_label(Some(&profile.id));
+    if let Some(window) = app.get_webview_window(&label) {
+        return Ok(window);
+    }
+
+    let home = Url::parse(super::MEET_HOME_URL).expect("valid Meet home URL");
+    let app_handle = app.clone();
+    let window_label = label.clone();
+    let window = WebviewWindowBuilder::new(app, &label, WebviewUrl::External(home))
+        .title(format!("MeetCat – {}", profile.name))
+        .inner_size(1200.0, 800.0)
+        .visible(false)
+        .data_directory(paths::profiles_dir().join(&profile.id))
+        .data_store_identifier(session_identifier(&profile.id))
+        .on_new_window(move |url, _features| {
+            let current_url = app_handle
+                .get_webview_window(&window_label)
+                .and_then(|window| window.url().ok())
+                .unwrap_or_else(|| Url::parse(super::MEET_HOME_URL).unwrap());
+            if matches!(url.scheme(), "http" | "https")
+                && !super::should_open_external(&current_url, &url)
+            {
+                if let Some(window) = app_handle.get_webview_window(&window_label) {
+                    let _ = window.navigate(url);
+                }
+            } else {
+                let _ = app_handle.opener().open_url(url.as_str(), None::<&str>);
+            }
+            tauri::webview::NewWindowResponse::Deny
+        })
+        .build()?;
+
+    let window_clone = window.clone();
+    window.on_window_event(move |event| {
+        if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+            api.prevent_close();
+            let _ = window_clone.hide();
+        }
+    });
+    Ok(window)
+}
+
+/// Close windows of profiles no longer in `profiles`
+pub fn close_removed_windows(app: &AppHandle, profiles: &[Profile]) {
+    for (label, window) in app.webview_windows() {
+        let Some(id) = profile_of_window(&label) else {
+            continue;
+        };
+        if !profiles.iter().any(|p| p.id == id) {
+            let _ = window.destroy();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_labels() {
+        assert_eq!(window_label(None), "main");
+        assert_eq!(window_label(Some("work")), "profile-work");
+        assert_eq!(profile_of_window("profile-work"), Some("work"));
+        assert_eq!(profile_of_window("main"), None);
+        assert!(is_meet_window("main"));
+        assert!(is_meet_window("profile-work"));
+        assert!(!is_meet_window("settings"));
+    }
+
+    #[test]
+    fn test_session_identifier_is_stable_and_distinct() {
+        assert_eq!(session_identifier("work"), session_identifier("work"));
+        assert_ne!(session_identifier("work"), session_identifier("home"));
+        assert_ne!(
+            session_identifier("work")[..8],
+            session_identifier("work")[8..]
+        );
+    }
+}
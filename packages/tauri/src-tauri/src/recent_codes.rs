@@ -0,0 +1,61 @@
+//! Meetings recently joined by code from the tray
+//!
+//! Entries are stored in short form (`abc-defg-hij`, optionally with an
+//! `authuser` query), most recent first.
+
+use crate::persistence::VersionedState;
+use serde::{Deserialize, Serialize};
+
+/// How many codes are kept
+pub const MAX_RECENT_CODES: usize = 5;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentMeetingCodes {
+    #[serde(default)]
+    pub codes: Vec<String>,
+}
+
+impl VersionedState for RecentMeetingCodes {
+    const CURRENT_VERSION: u32 = 1;
+
+    fn migrate(from_version: u32, _data: serde_json::Value) -> Result<serde_json::Value, String> {
+        Err(format!(
+            "no recent codes format before version {}",
+            from_version + 1
+        ))
+    }
+}
+
+impl RecentMeetingCodes {
+    /// Move `code` to the front. Returns whether the list changed.
+    pub fn remember(&mut self, code: &str) -> bool {
+        if self.codes.first().map(String::as_str) == Some(code) {
+            return false;
+        }
+        self.codes.retain(|existing| existing != code);
+        self.codes.insert(0, code.to_string());
+        self.codes.truncate(MAX_RECENT_CODES);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remember_dedupes_and_caps() {
+        let mut recent = RecentMeetingCodes::default();
+        for i in 0..MAX_RECENT_CODES + 2 {
+            assert!(recent.remember(&format!("code-{}", i)));
+        }
+        assert_eq!(recent.codes.len(), MAX_RECENT_CODES);
+        assert_eq!(recent.codes[0], "code-6");
+
+        assert!(!recent.remember("code-6"));
+        assert!(recent.remember("code-4"));
+        assert_eq!(recent.codes[..3], ["code-4", "code-6", "code-5"]);
+        assert_eq!(recent.codes.len(), MAX_RECENT_CODES);
+    }
+}
@@ -0,0 +1,349 @@
+//! Secrets kept in the OS credential store
+//!
+//! Passwords and tokens live in the macOS Keychain, the Windows Credential
+//! Manager or the Secret Service on Linux, never in `settings.json`. Secrets
+//! found in plaintext settings (written by older versions, or sent by the
+//! settings window) are moved to the store; when no store is available they
+//! stay where they are so the feature keeps working.
+
+use crate::settings::{ProxySettings, Settings};
+use serde::{Deserialize, Serialize};
+
+/// Service name the entries are filed under
+const SERVICE: &str = "MeetCat";
+
+/// A secret MeetCat knows how to store
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SecretName {
+    ProxyPassword,
+    ApiServerToken,
+    MqttPassword,
+    WebhookSecret,
+    SlackToken,
+}
+
+impl SecretName {
+    pub const ALL: [SecretName; 5] = [
+        SecretName::ProxyPassword,
+        SecretName::ApiServerToken,
+        SecretName::MqttPassword,
+        SecretName::WebhookSecret,
+        SecretName::SlackToken,
+    ];
+
+    /// Account name of the credential store entry
+    fn key(self) -> &'static str {
+        match self {
+            SecretName::ProxyPassword => "proxy-password",
+            SecretName::ApiServerToken => "api-server-token",
+            SecretName::MqttPassword => "mqtt-password",
+            SecretName::WebhookSecret => "webhook-secret",
+            SecretName::SlackToken => "slack-token",
+        }
+    }
+}
+
+pub trait SecretStore {
+    fn get(&self, name: SecretName) -> Result<Option<String>, String>;
+    fn set(&self, name: SecretName, value: &str) -> Result<(), String>;
+    fn clear(&self, name: SecretName) -> Result<(), String>;
+}
+
+/// The platform credential store
+pub struct Keychain;
+
+impl Keychain {
+    fn entry(name: SecretName) -> Result<keyring::Entry, String> {
+        keyring::Entry::new(SERVICE, name.key()).map_err(|e| e.to_string())
+    }
+}
+
+impl SecretStore for Keychain {
+    fn get(&self, name: SecretName) -> Result<Option<String>, String> {
+        match Self::entry(name)?.get_password() {
+            Ok(value) => Ok(Some(value)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn set(&self, name: SecretName, value: &str) -> Result<(), String> {
+        Self::entry(name)?
+            .set_password(value)
+            .map_err(|e| e.to_string())
+    }
+
+    fn clear(&self, name: SecretName) -> Result<(), String> {
+        match Self::entry(name)?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// Outcome of moving plaintext secrets out of settings
+#[derive(Debug, Default)]
+pub struct Migration {
+    pub migrated: Vec<SecretName>,
+    /// Secrets left in settings because the store refused them
+    pub failed: Vec<(SecretName, String)>,
+}
+
+/// Move every plaintext secret in `settings` into `store`
+pub fn migrate_plaintext(settings: &mut Settings, store: &impl SecretStore) -> Migration {
+    let mut migration = Migration::default();
+    for name in SecretName::ALL {
+        let Some(slot) = plaintext_slot(settings, name) else {
+            continue;
+        };
+        let Some(value) = slot.take() else {
+            continue;
+        };
+        if value.is_empty() {
+            continue;
+        }
+        match store.set(name, &value) {
+            Ok(()) => migration.migrated.push(name),
+            Err(e) => {
+                *slot = Some(value);
+                migration.failed.push((name, e));
+            }
+        }
+    }
+    migration
+}
+
+/// `proxy` with its password read from `store` when settings don't hold one
+pub fn with_proxy_password(proxy: &ProxySettings, store: &impl SecretStore) -> ProxySettings {
+    let mut proxy = proxy.clone();
+    let has_username = proxy.username.as_deref().is_some_and(|u| !u.is_empty());
+    if has_username && proxy.password.is_none() {
+        proxy.password = store.get(SecretName::ProxyPassword).ok().flatten();
+    }
+    proxy
+}
+
+/// Token of the local control API, from settings or else from `store`
+pub fn api_server_token(settings: &Settings, store: &impl SecretStore) -> Option<String> {
+    let plaintext = settings
+        .tauri
+        .as_ref()
+        .and_then(|t| t.api_server_token.clone());
+    plaintext_or_stored(plaintext, SecretName::ApiServerToken, store)
+}
+
+/// Password of the MQTT broker, from settings or else from `store`
+pub fn mqtt_password(settings: &Settings, store: &impl SecretStore) -> Option<String> {
+    let plaintext = settings
+        .tauri
+        .as_ref()
+        .and_then(|t| t.mqtt_password.clone());
+    plaintext_or_stored(plaintext, SecretName::MqttPassword, store)
+}
+
+/// Signing key of webhook requests, from settings or else from `store`
+pub fn webhook_secret(settings: &Settings, store: &impl SecretStore) -> Option<String> {
+    let plaintext = settings
+        .tauri
+        .as_ref()
+        .and_then(|t| t.webhook_secret.clone());
+    plaintext_or_stored(plaintext, SecretName::WebhookSecret, store)
+}
+
+/// Slack token of the in-meeting status, from settings or else from `store`
+pub fn slack_token(settings: &Settings, store: &impl SecretStore) -> Option<String> {
+    let plaintext = settings.tauri.as_ref().and_then(|t| t.slack_token.clone());
+    plaintext_or_stored(plaintext, SecretName::SlackToken, store)
+}
+
+fn plaintext_or_stored(
+    plaintext: Option<String>,
+    name: SecretName,
+    store: &impl SecretStore,
+) -> Option<String> {
+    plaintext
+        .or_else(|| store.get(name).ok().flatten())
+        .filter(|value| !value.is_empty())
+}
+
+/// Settings field a secret used to be stored in
+fn plaintext_slot(settings: &mut Settings, name: SecretName) -> Option<&mut Option<String>> {
+    match name {
+        SecretName::ProxyPassword => settings
+            .tauri
+            .as_mut()
+            .and_then(|t| t.proxy.as_mut())
+            .map(|proxy| &mut proxy.password),
+        SecretName::ApiServerToken => settings.tauri.as_mut().map(|t| &mut t.api_server_token),
+        SecretName::MqttPassword => settings.tauri.as_mut().map(|t| &mut t.mqtt_password),
+        SecretName::WebhookSecret => settings.tauri.as_mut().map(|t| &mut t.webhook_secret),
+        SecretName::SlackToken => settings.tauri.as_mut().map(|t| &mut t.slack_token),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::TauriSettings;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct MemoryStore {
+        values: RefCell<HashMap<SecretName, String>>,
+        fail: bool,
+    }
+
+    impl SecretStore for MemoryStore {
+        fn get(&self, name: SecretName) -> Result<Option<String>, String> {
+            Ok(self.values.borrow().get(&name).cloned())
+        }
+
+        fn set(&self, name: SecretName, value: &str) -> Result<(), String> {
+            if self.fail {
+                return Err("no secret service".to_string());
+            }
+            self.values.borrow_mut().insert(name, value.to_string());
+            Ok(())
+        }
+
+        fn clear(&self, name: SecretName) -> Result<(), String> {
+            self.values.borrow_mut().remove(&name);
+            Ok(())
+        }
+    }
+
+    fn settings_with_proxy_password(password: Option<&str>) -> Settings {
+        Settings {
+            tauri: Some(TauriSettings {
+                proxy: Some(ProxySettings {
+                    host: "proxy.example.com".to_string(),
+                    port: 8080,
+                    username: Some("user".to_string()),
+                    password: password.map(str::to_string),
+                }),
+                ..TauriSettings::default()
+            }),
+            ..Settings::default()
+        }
+    }
+
+    fn proxy(settings: &Settings) -> &ProxySettings {
+        settings.tauri.as_ref().unwrap().proxy.as_ref().unwrap()
+    }
+
+    #[test]
+    fn test_migrate_moves_plaintext_password_to_store() {
+        let store = MemoryStore::default();
+        let mut settings = settings_with_proxy_password(Some("p@ss"));
+
+        let migration = migrate_plaintext(&mut settings, &store);
+        assert_eq!(migration.migrated, vec![SecretName::ProxyPassword]);
+        assert!(proxy(&settings).password.is_none());
+        assert!(!serde_json::to_string(&settings).unwrap().contains("p@ss"));
+
+        let resolved = with_proxy_password(proxy(&settings), &store);
+        assert_eq!(resolved.password.as_deref(), Some("p@ss"));
+
+        // Nothing left to move
+        let migration = migrate_plaintext(&mut settings, &store);
+        assert!(migration.migrated.is_empty());
+        assert!(migration.failed.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_keeps_plaintext_when_store_fails() {
+        let store = MemoryStore {
+            fail: true,
+            ..MemoryStore::default()
+        };
+        let mut settings = settings_with_proxy_password(Some("p@ss"));
+
+        let migration = migrate_plaintext(&mut settings, &store);
+        assert!(migration.migrated.is_empty());
+        assert_eq!(migration.failed.len(), 1);
+        assert_eq!(proxy(&settings).password.as_deref(), Some("p@ss"));
+        assert_eq!(
+            with_proxy_password(proxy(&settings), &store)
+                .password
+                .as_deref(),
+            Some("p@ss")
+        );
+    }
+
+    #[test]
+    fn test_api_server_token_moves_to_store() {
+        let store = MemoryStore::default();
+        let mut settings = Settings {
+            tauri: Some(TauriSettings {
+                api_server_token: Some("0123456789abcdef".to_string()),
+                ..TauriSettings::default()
+            }),
+            ..Settings::default()
+        };
+
+        let migration = migrate_plaintext(&mut settings, &store);
+        assert_eq!(migration.migrated, vec![SecretName::ApiServerToken]);
+        assert!(settings.tauri.as_ref().unwrap().api_server_token.is_none());
+        assert_eq!(
+            api_server_token(&settings, &store).as_deref(),
+            Some("0123456789abcdef")
+        );
+
+        store.clear(SecretName::ApiServerToken).unwrap();
+        assert_eq!(api_server_token(&settings, &store), None);
+    }
+
+    #[test]
+    fn test_mqtt_password_moves_to_store() {
+        let store = MemoryStore::default();
+        let mut settings = Settings {
+            tauri: Some(TauriSettings {
+                mqtt_password: Some("broker-secret".to_string()),
+                ..TauriSettings::default()
+            }),
+            ..Settings::default()
+        };
+
+        let migration = migrate_plaintext(&mut settings, &store);
+        assert_eq!(migration.migrated, vec![SecretName::MqttPassword]);
+        assert!(settings.tauri.as_ref().unwrap().mqtt_password.is_none());
+        assert_eq!(
+            mqtt_password(&settings, &store).as_deref(),
+            Some("broker-secret")
+        );
+    }
+
+    #[test]
+    fn test_slack_token_falls_back_to_store() {
+        let store = MemoryStore::default();
+        let settings = Settings {
+            tauri: Some(TauriSettings::default()),
+            ..Settings::default()
+        };
+        assert_eq!(slack_token(&settings, &store), None);
+
+        store.set(SecretName::SlackToken, "xoxp-123").unwrap();
+        assert_eq!(slack_token(&settings, &store).as_deref(), Some("xoxp-123"));
+    }
+
+    #[test]
+    fn test_proxy_without_username_skips_store() {
+        let store = MemoryStore::default();
+        store.set(SecretName::ProxyPassword, "p@ss").unwrap();
+        let mut settings = settings_with_proxy_password(None);
+        settings
+            .tauri
+            .as_mut()
+            .unwrap()
+            .proxy
+            .as_mut()
+            .unwrap()
+            .username = None;
+
+        assert!(with_proxy_password(proxy(&settings), &store)
+            .password
+            .is_none());
+    }
+}
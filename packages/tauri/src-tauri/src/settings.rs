@@ -1,12 +1,20 @@
 //! Settings management for MeetCat
 
+use crate::filters::TitleFilter;
+use crate::persistence::{self, QuarantinedFile};
+use crate::{logging, migrations, paths};
+use chrono::{Datelike, NaiveDateTime, NaiveTime, Weekday};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt;
 use std::fs;
-use std::path::PathBuf;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::OnceLock;
 use thiserror::Error;
 
-pub const TAURI_DEFAULT_CHECK_INTERVAL_SECONDS: u32 = 5;
+pub const TAURI_DEFAULT_CHECK_INTERVAL_SECONDS: u32 = 30;
 
 #[derive(Error, Debug)]
 pub enum SettingsError {
@@ -39,9 +47,22 @@ pub enum TrayDisplayMode {
     IconWithCountdown,
 }
 
-/// Log level options
+/// Power mode options
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum PowerMode {
+    /// Follow the system battery saver (macOS Low Power Mode)
+    #[default]
+    Auto,
+    Normal,
+    LowPower,
+}
+
+/// Log level options.
+///
+/// Serialized in lowercase; parsing ignores case and also accepts `warning`.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase", try_from = "String")]
 pub enum LogLevel {
     Error,
     Warn,
@@ -51,6 +72,479 @@ pub enum LogLevel {
     Trace,
 }
 
+impl LogLevel {
+    pub const ALL: [LogLevel; 5] = [
+        LogLevel::Error,
+        LogLevel::Warn,
+        LogLevel::Info,
+        LogLevel::Debug,
+        LogLevel::Trace,
+    ];
+
+    /// Canonical name, as written to settings and log files
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for LogLevel {
+    type Err = UnknownLogLevel;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let level = raw.trim().to_ascii_lowercase();
+        if level == "warning" {
+            return Ok(LogLevel::Warn);
+        }
+        LogLevel::ALL
+            .into_iter()
+            .find(|candidate| candidate.as_str() == level)
+            .ok_or_else(|| UnknownLogLevel {
+                level: raw.to_string(),
+                expected: LogLevel::ALL.map(|level| level.as_str()).to_vec(),
+            })
+    }
+}
+
+impl TryFrom<String> for LogLevel {
+    type Error = UnknownLogLevel;
+
+    fn try_from(raw: String) -> Result<Self, UnknownLogLevel> {
+        raw.parse()
+    }
+}
+
+/// Structured error for a string that names no log level
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnknownLogLevel {
+    pub level: String,
+    /// Canonical level names
+    pub expected: Vec<&'static str>,
+}
+
+impl fmt::Display for UnknownLogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unknown log level \"{}\", expected one of {}",
+            self.level,
+            self.expected.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for UnknownLogLevel {}
+
+/// Meeting source options, used to rank sources when merging
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum MeetingSource {
+    Webview,
+    Ics,
+    Api,
+}
+
+/// How a meeting rule's pattern is matched against titles
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum TitleMatchMode {
+    #[default]
+    Contains,
+    Regex,
+}
+
+impl TitleMatchMode {
+    /// The case-sensitive title filter matching `pattern` in this mode
+    pub fn filter(self, pattern: &str) -> TitleFilter {
+        let pattern = pattern.to_string();
+        match self {
+            TitleMatchMode::Contains => TitleFilter::Contains {
+                pattern,
+                case_sensitive: true,
+            },
+            TitleMatchMode::Regex => TitleFilter::Regex {
+                pattern,
+                case_sensitive: true,
+            },
+        }
+    }
+
+    /// Check that a regex pattern compiles
+    pub fn validate(self, pattern: &str) -> Result<(), String> {
+        match self {
+            TitleMatchMode::Contains => Ok(()),
+            TitleMatchMode::Regex => regex::Regex::new(pattern)
+                .map(|_| ())
+                .map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// Per-meeting overrides for meetings whose title matches `pattern`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingRule {
+    pub pattern: String,
+    #[serde(default)]
+    pub match_mode: TitleMatchMode,
+    /// `Some(false)` never auto-joins; `Some(true)` auto-joins even when a
+    /// title exclude filter matches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_join: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub join_before_minutes: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mic_state: Option<MediaState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub camera_state: Option<MediaState>,
+    /// `Some(false)` only opens the meeting and leaves clicking Join to the
+    /// user, whatever the global `autoClickJoin`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_click_join: Option<bool>,
+    /// Replaces `joinCountdownSeconds`, e.g. 0 for standups
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub countdown_seconds: Option<u32>,
+}
+
+impl MeetingRule {
+    /// Check that a regex pattern compiles
+    pub fn validate(&self) -> Result<(), String> {
+        self.match_mode
+            .validate(&self.pattern)
+            .map_err(|e| format!("Invalid meeting rule pattern \"{}\": {}", self.pattern, e))
+    }
+}
+
+/// What to do when a busy block overlaps a meeting's join trigger
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum BusyBlockPolicy {
+    /// Wait until the block ends, skipping the meeting if that is too late
+    #[default]
+    Delay,
+    /// Ask before joining
+    Confirm,
+}
+
+/// Which meeting to auto-join when overlapping meetings trigger together
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ConflictPolicy {
+    /// The meeting whose join triggers first
+    #[default]
+    FirstScheduled,
+    /// The shortest of the overlapping meetings
+    Shortest,
+    /// Hold the joins and let the user pick one
+    PromptUser,
+}
+
+/// Meeting lifecycle event that can be sent to webhooks
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum WebhookEvent {
+    /// The daemon started tracking the meeting
+    Scheduled,
+    /// The auto-join trigger fired
+    TriggerFired,
+    Joined,
+    /// The meeting page was closed
+    Closed,
+    /// Closed after its trigger time; it won't be joined again automatically
+    Suppressed,
+}
+
+/// Policy for non-Meet calendar blocks (Focus time, Lunch) whose title
+/// matches `pattern`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BusyBlockRule {
+    pub pattern: String,
+    #[serde(default)]
+    pub match_mode: TitleMatchMode,
+    #[serde(default)]
+    pub policy: BusyBlockPolicy,
+}
+
+impl BusyBlockRule {
+    /// Check that a regex pattern compiles
+    pub fn validate(&self) -> Result<(), String> {
+        self.match_mode
+            .validate(&self.pattern)
+            .map_err(|e| format!("Invalid busy block pattern \"{}\": {}", self.pattern, e))
+    }
+}
+
+/// Weekly Do-Not-Disturb window in local time, e.g. Friday 16:00-18:00.
+///
+/// A window whose `end` is not after its `start` runs past midnight into the
+/// next day.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DndWindow {
+    pub weekday: Weekday,
+    /// `HH:MM`
+    pub start: String,
+    /// `HH:MM`
+    pub end: String,
+}
+
+impl DndWindow {
+    pub fn contains(&self, local: NaiveDateTime) -> bool {
+        let (Some(start), Some(end)) = (parse_hh_mm(&self.start), parse_hh_mm(&self.end)) else {
+            return false;
+        };
+        let (day, time) = (local.weekday(), local.time());
+        if start < end {
+            day == self.weekday && start <= time && time < end
+        } else {
+            (day == self.weekday && time >= start) || (day == self.weekday.succ() && time < end)
+        }
+    }
+
+    /// Check that both times parse
+    pub fn validate(&self) -> Result<(), String> {
+        for time in [&self.start, &self.end] {
+            if parse_hh_mm(time).is_none() {
+                return Err(format!(
+                    "Invalid Do-Not-Disturb time \"{}\", expected HH:MM",
+                    time
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_hh_mm(raw: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(raw.trim(), "%H:%M").ok()
+}
+
+/// Whether `local` falls inside any of `windows`
+pub fn in_dnd_window(windows: &[DndWindow], local: NaiveDateTime) -> bool {
+    windows.iter().any(|window| window.contains(local))
+}
+
+/// Local hours and weekdays in which meetings may start to be auto-joined,
+/// e.g. 09:00-18:00 Monday to Friday.
+///
+/// An `end` that is not after `start` runs past midnight into the next day.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoJoinHours {
+    /// `HH:MM`
+    pub start: String,
+    /// `HH:MM`
+    pub end: String,
+    /// Days the hours start on
+    pub weekdays: Vec<Weekday>,
+}
+
+impl AutoJoinHours {
+    pub fn contains(&self, local: NaiveDateTime) -> bool {
+        let (Some(start), Some(end)) = (parse_hh_mm(&self.start), parse_hh_mm(&self.end)) else {
+            // Unparseable hours never hold back a join
+            return true;
+        };
+        let (day, time) = (local.weekday(), local.time());
+        if start < end {
+            self.weekdays.contains(&day) && start <= time && time < end
+        } else {
+            (self.weekdays.contains(&day) && time >= start)
+                || (self.weekdays.contains(&day.pred()) && time < end)
+        }
+    }
+
+    /// Check that both times parse and at least one weekday is set
+    pub fn validate(&self) -> Result<(), String> {
+        for time in [&self.start, &self.end] {
+            if parse_hh_mm(time).is_none() {
+                return Err(format!(
+                    "Invalid auto-join hours time \"{}\", expected HH:MM",
+                    time
+                ));
+            }
+        }
+        if self.weekdays.is_empty() {
+            return Err("Auto-join hours need at least one weekday".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Local time range for heavier background work such as update checks, log
+/// digests and history pruning, e.g. 02:00-04:00 every day.
+///
+/// An `end` that is not after `start` runs past midnight.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintenanceWindow {
+    /// `HH:MM`
+    pub start: String,
+    /// `HH:MM`
+    pub end: String,
+}
+
+impl MaintenanceWindow {
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        let (Some(start), Some(end)) = (parse_hh_mm(&self.start), parse_hh_mm(&self.end)) else {
+            return false;
+        };
+        if start < end {
+            start <= time && time < end
+        } else {
+            time >= start || time < end
+        }
+    }
+
+    /// Check that both times parse
+    pub fn validate(&self) -> Result<(), String> {
+        for time in [&self.start, &self.end] {
+            if parse_hh_mm(time).is_none() {
+                return Err(format!(
+                    "Invalid maintenance window time \"{}\", expected HH:MM",
+                    time
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An extra Google account opened in its own window with a separate session
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    /// Names the profile's window and session data; lowercase letters,
+    /// digits and `-`
+    pub id: String,
+    pub name: String,
+}
+
+impl Profile {
+    /// Check that the id can name a window and a directory
+    pub fn validate(&self) -> Result<(), String> {
+        let valid = !self.id.is_empty()
+            && self.id.len() <= 32
+            && self
+                .id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+        if !valid {
+            return Err(format!(
+                "Invalid profile id \"{}\", expected up to 32 lowercase letters, digits or '-'",
+                self.id
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Explicit HTTP proxy for network features, overriding system settings
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxySettings {
+    pub host: String,
+    pub port: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Only set before the password is moved to the OS keychain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+/// Developer-only soak-test instrumentation
+///
+/// Drives a shadow copy of the join engine with synthetic meetings on an
+/// accelerated clock and checks scheduler invariants. Never shown in the
+/// settings window; set `tauri.soakTest` in settings.json to enable.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SoakTestSettings {
+    /// Virtual seconds that pass per real second (1-3600)
+    #[serde(default = "default_soak_clock_factor")]
+    pub clock_factor: u32,
+    /// Virtual minutes between injected synthetic meetings (at least 5)
+    #[serde(default = "default_soak_meeting_interval_minutes")]
+    pub meeting_interval_minutes: u32,
+}
+
+fn default_soak_clock_factor() -> u32 {
+    60
+}
+
+fn default_soak_meeting_interval_minutes() -> u32 {
+    15
+}
+
+impl SoakTestSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(1..=3600).contains(&self.clock_factor) {
+            return Err(format!(
+                "Soak test clock factor must be between 1 and 3600, got {}",
+                self.clock_factor
+            ));
+        }
+        if self.meeting_interval_minutes < 5 {
+            return Err(format!(
+                "Soak test meeting interval must be at least 5 minutes, got {}",
+                self.meeting_interval_minutes
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// System-wide keyboard shortcuts, as accelerators like
+/// `CommandOrControl+Shift+J`; `None` leaves an action unbound
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GlobalShortcuts {
+    /// Join the next meeting right away
+    #[serde(default = "default_join_next_shortcut")]
+    pub join_next: Option<String>,
+    /// Cancel the pending auto-join
+    #[serde(default = "default_cancel_join_shortcut")]
+    pub cancel_join: Option<String>,
+    /// Mute or unmute the microphone of the current call
+    #[serde(default = "default_toggle_mic_shortcut")]
+    pub toggle_mic: Option<String>,
+}
+
+fn default_join_next_shortcut() -> Option<String> {
+    Some("CommandOrControl+Shift+J".to_string())
+}
+
+fn default_cancel_join_shortcut() -> Option<String> {
+    Some("CommandOrControl+Shift+K".to_string())
+}
+
+fn default_toggle_mic_shortcut() -> Option<String> {
+    Some("CommandOrControl+Shift+M".to_string())
+}
+
+impl Default for GlobalShortcuts {
+    fn default() -> Self {
+        Self {
+            join_next: default_join_next_shortcut(),
+            cancel_join: default_cancel_join_shortcut(),
+            toggle_mic: default_toggle_mic_shortcut(),
+        }
+    }
+}
+
 /// Tauri-specific settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -72,6 +566,190 @@ pub struct TauriSettings {
 
     #[serde(default = "default_log_level")]
     pub log_level: LogLevel,
+
+    #[serde(default = "default_meeting_source_priority")]
+    pub meeting_source_priority: Vec<MeetingSource>,
+
+    #[serde(default = "default_capture_screenshot_on_join_failure")]
+    pub capture_screenshot_on_join_failure: bool,
+
+    #[serde(default = "default_pause_during_out_of_office")]
+    pub pause_during_out_of_office: bool,
+
+    #[serde(default = "default_max_auto_joins_per_hour")]
+    pub max_auto_joins_per_hour: u32,
+
+    #[serde(default = "default_privacy_mode")]
+    pub privacy_mode: bool,
+
+    #[serde(default = "default_green_room_minutes")]
+    pub green_room_minutes: u32,
+
+    #[serde(default = "default_power_mode")]
+    pub power_mode: PowerMode,
+
+    #[serde(default = "default_proxy")]
+    pub proxy: Option<ProxySettings>,
+
+    #[serde(default = "default_allow_concurrent_meetings")]
+    pub allow_concurrent_meetings: bool,
+
+    #[serde(default = "default_defer_while_mic_busy")]
+    pub defer_while_mic_busy: bool,
+
+    #[serde(default = "default_startup_url")]
+    pub startup_url: Option<String>,
+
+    #[serde(default = "default_meeting_rules")]
+    pub meeting_rules: Vec<MeetingRule>,
+
+    #[serde(default = "default_remember_recent_codes")]
+    pub remember_recent_codes: bool,
+
+    #[serde(default = "default_show_notifications")]
+    pub show_notifications: bool,
+
+    #[serde(default = "default_busy_block_rules")]
+    pub busy_block_rules: Vec<BusyBlockRule>,
+
+    #[serde(default = "default_soak_test")]
+    pub soak_test: Option<SoakTestSettings>,
+
+    #[serde(default = "default_accessibility_announcements")]
+    pub accessibility_announcements: bool,
+
+    #[serde(default = "default_return_home_after_meeting")]
+    pub return_home_after_meeting: Option<u32>,
+
+    #[serde(default = "default_dnd_windows")]
+    pub dnd_windows: Vec<DndWindow>,
+
+    #[serde(default = "default_profiles")]
+    pub profiles: Vec<Profile>,
+
+    #[serde(default = "default_stale_refresh_minutes")]
+    pub stale_refresh_minutes: u32,
+
+    #[serde(default = "default_ics_subscriptions")]
+    pub ics_subscriptions: Vec<String>,
+
+    #[serde(default = "default_show_meeting_summary")]
+    pub show_meeting_summary: bool,
+
+    #[serde(default = "default_widget_data_enabled")]
+    pub widget_data_enabled: bool,
+
+    #[serde(default = "default_widget_data_path")]
+    pub widget_data_path: Option<String>,
+
+    #[serde(default = "default_auto_join_hours")]
+    pub auto_join_hours: Option<AutoJoinHours>,
+
+    #[serde(default = "default_reminder_minutes_before")]
+    pub reminder_minutes_before: Option<u32>,
+
+    #[serde(default = "default_join_in_background")]
+    pub join_in_background: bool,
+
+    #[serde(default = "default_conflict_policy")]
+    pub conflict_policy: ConflictPolicy,
+
+    #[serde(default = "default_show_window_cause")]
+    pub show_window_cause: bool,
+
+    #[serde(default = "default_global_shortcuts")]
+    pub global_shortcuts: Option<GlobalShortcuts>,
+
+    #[serde(default = "default_maintenance_window")]
+    pub maintenance_window: Option<MaintenanceWindow>,
+
+    #[serde(default = "default_last_minute_meeting_minutes")]
+    pub last_minute_meeting_minutes: u32,
+
+    #[serde(default = "default_demo_mode")]
+    pub demo_mode: bool,
+
+    #[serde(default = "default_tray_icon_template")]
+    pub tray_icon_template: bool,
+
+    #[serde(default = "default_api_server_enabled")]
+    pub api_server_enabled: bool,
+
+    #[serde(default = "default_api_server_port")]
+    pub api_server_port: u16,
+
+    /// Only set before the token is moved to the OS keychain
+    #[serde(
+        default = "default_api_server_token",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub api_server_token: Option<String>,
+
+    #[serde(default = "default_mqtt_enabled")]
+    pub mqtt_enabled: bool,
+
+    #[serde(default = "default_mqtt_host")]
+    pub mqtt_host: Option<String>,
+
+    #[serde(default = "default_mqtt_port")]
+    pub mqtt_port: u16,
+
+    #[serde(default = "default_mqtt_username")]
+    pub mqtt_username: Option<String>,
+
+    /// Only set before the password is moved to the OS keychain
+    #[serde(
+        default = "default_mqtt_password",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub mqtt_password: Option<String>,
+
+    #[serde(default = "default_mqtt_topic_prefix")]
+    pub mqtt_topic_prefix: String,
+
+    #[serde(default = "default_webhook_urls")]
+    pub webhook_urls: Vec<String>,
+
+    #[serde(default = "default_webhook_events")]
+    pub webhook_events: Vec<WebhookEvent>,
+
+    /// Only set before the secret is moved to the OS keychain
+    #[serde(
+        default = "default_webhook_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub webhook_secret: Option<String>,
+
+    #[serde(default = "default_slack_status_enabled")]
+    pub slack_status_enabled: bool,
+
+    /// Only set before the token is moved to the OS keychain
+    #[serde(
+        default = "default_slack_token",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub slack_token: Option<String>,
+
+    #[serde(default = "default_skip_join_when_idle_minutes")]
+    pub skip_join_when_idle_minutes: Option<u32>,
+
+    #[serde(default = "default_stale_data_minutes")]
+    pub stale_data_minutes: u32,
+
+    #[serde(default = "default_skip_join_when_stale")]
+    pub skip_join_when_stale: bool,
+
+    #[serde(default = "default_exclude_title_filters")]
+    pub exclude_title_filters: Vec<TitleFilter>,
+
+    #[serde(default = "default_include_title_filters")]
+    pub include_title_filters: Vec<TitleFilter>,
+
+    #[serde(default = "default_mqtt_tls")]
+    pub mqtt_tls: bool,
+
+    #[serde(default = "default_hold_joins_when_offline")]
+    pub hold_joins_when_offline: bool,
 }
 
 impl Default for TauriSettings {
@@ -84,6 +762,62 @@ impl Default for TauriSettings {
             tray_show_meeting_title: defaults.tauri.tray_show_meeting_title,
             log_collection_enabled: defaults.tauri.log_collection_enabled,
             log_level: defaults.tauri.log_level.clone(),
+            meeting_source_priority: defaults.tauri.meeting_source_priority.clone(),
+            capture_screenshot_on_join_failure: defaults.tauri.capture_screenshot_on_join_failure,
+            pause_during_out_of_office: defaults.tauri.pause_during_out_of_office,
+            max_auto_joins_per_hour: defaults.tauri.max_auto_joins_per_hour,
+            privacy_mode: defaults.tauri.privacy_mode,
+            green_room_minutes: defaults.tauri.green_room_minutes,
+            power_mode: defaults.tauri.power_mode,
+            proxy: defaults.tauri.proxy.clone(),
+            allow_concurrent_meetings: defaults.tauri.allow_concurrent_meetings,
+            defer_while_mic_busy: defaults.tauri.defer_while_mic_busy,
+            startup_url: defaults.tauri.startup_url.clone(),
+            meeting_rules: defaults.tauri.meeting_rules.clone(),
+            remember_recent_codes: defaults.tauri.remember_recent_codes,
+            show_notifications: defaults.tauri.show_notifications,
+            busy_block_rules: defaults.tauri.busy_block_rules.clone(),
+            soak_test: defaults.tauri.soak_test.clone(),
+            accessibility_announcements: defaults.tauri.accessibility_announcements,
+            return_home_after_meeting: defaults.tauri.return_home_after_meeting,
+            dnd_windows: defaults.tauri.dnd_windows.clone(),
+            profiles: defaults.tauri.profiles.clone(),
+            stale_refresh_minutes: defaults.tauri.stale_refresh_minutes,
+            ics_subscriptions: defaults.tauri.ics_subscriptions.clone(),
+            show_meeting_summary: defaults.tauri.show_meeting_summary,
+            widget_data_enabled: defaults.tauri.widget_data_enabled,
+            widget_data_path: defaults.tauri.widget_data_path.clone(),
+            auto_join_hours: defaults.tauri.auto_join_hours.clone(),
+            reminder_minutes_before: defaults.tauri.reminder_minutes_before,
+            join_in_background: defaults.tauri.join_in_background,
+            conflict_policy: defaults.tauri.conflict_policy,
+            show_window_cause: defaults.tauri.show_window_cause,
+            global_shortcuts: defaults.tauri.global_shortcuts.clone(),
+            maintenance_window: defaults.tauri.maintenance_window.clone(),
+            last_minute_meeting_minutes: defaults.tauri.last_minute_meeting_minutes,
+            demo_mode: defaults.tauri.demo_mode,
+            tray_icon_template: defaults.tauri.tray_icon_template,
+            api_server_enabled: defaults.tauri.api_server_enabled,
+            api_server_port: defaults.tauri.api_server_port,
+            api_server_token: defaults.tauri.api_server_token.clone(),
+            mqtt_enabled: defaults.tauri.mqtt_enabled,
+            mqtt_host: defaults.tauri.mqtt_host.clone(),
+            mqtt_port: defaults.tauri.mqtt_port,
+            mqtt_username: defaults.tauri.mqtt_username.clone(),
+            mqtt_password: defaults.tauri.mqtt_password.clone(),
+            mqtt_topic_prefix: defaults.tauri.mqtt_topic_prefix.clone(),
+            webhook_urls: defaults.tauri.webhook_urls.clone(),
+            webhook_events: defaults.tauri.webhook_events.clone(),
+            webhook_secret: defaults.tauri.webhook_secret.clone(),
+            slack_status_enabled: defaults.tauri.slack_status_enabled,
+            slack_token: defaults.tauri.slack_token.clone(),
+            skip_join_when_idle_minutes: defaults.tauri.skip_join_when_idle_minutes,
+            stale_data_minutes: defaults.tauri.stale_data_minutes,
+            skip_join_when_stale: defaults.tauri.skip_join_when_stale,
+            exclude_title_filters: defaults.tauri.exclude_title_filters.clone(),
+            include_title_filters: defaults.tauri.include_title_filters.clone(),
+            mqtt_tls: defaults.tauri.mqtt_tls,
+            hold_joins_when_offline: defaults.tauri.hold_joins_when_offline,
         }
     }
 }
@@ -141,6 +875,62 @@ struct DefaultsTauriSettings {
     tray_show_meeting_title: bool,
     log_collection_enabled: bool,
     log_level: LogLevel,
+    meeting_source_priority: Vec<MeetingSource>,
+    capture_screenshot_on_join_failure: bool,
+    pause_during_out_of_office: bool,
+    max_auto_joins_per_hour: u32,
+    privacy_mode: bool,
+    green_room_minutes: u32,
+    power_mode: PowerMode,
+    proxy: Option<ProxySettings>,
+    allow_concurrent_meetings: bool,
+    defer_while_mic_busy: bool,
+    startup_url: Option<String>,
+    meeting_rules: Vec<MeetingRule>,
+    remember_recent_codes: bool,
+    show_notifications: bool,
+    busy_block_rules: Vec<BusyBlockRule>,
+    soak_test: Option<SoakTestSettings>,
+    accessibility_announcements: bool,
+    return_home_after_meeting: Option<u32>,
+    dnd_windows: Vec<DndWindow>,
+    profiles: Vec<Profile>,
+    stale_refresh_minutes: u32,
+    ics_subscriptions: Vec<String>,
+    show_meeting_summary: bool,
+    widget_data_enabled: bool,
+    widget_data_path: Option<String>,
+    auto_join_hours: Option<AutoJoinHours>,
+    reminder_minutes_before: Option<u32>,
+    join_in_background: bool,
+    conflict_policy: ConflictPolicy,
+    show_window_cause: bool,
+    global_shortcuts: Option<GlobalShortcuts>,
+    maintenance_window: Option<MaintenanceWindow>,
+    last_minute_meeting_minutes: u32,
+    demo_mode: bool,
+    tray_icon_template: bool,
+    api_server_enabled: bool,
+    api_server_port: u16,
+    api_server_token: Option<String>,
+    mqtt_enabled: bool,
+    mqtt_host: Option<String>,
+    mqtt_port: u16,
+    mqtt_username: Option<String>,
+    mqtt_password: Option<String>,
+    mqtt_topic_prefix: String,
+    webhook_urls: Vec<String>,
+    webhook_events: Vec<WebhookEvent>,
+    webhook_secret: Option<String>,
+    slack_status_enabled: bool,
+    slack_token: Option<String>,
+    skip_join_when_idle_minutes: Option<u32>,
+    stale_data_minutes: u32,
+    skip_join_when_stale: bool,
+    exclude_title_filters: Vec<TitleFilter>,
+    include_title_filters: Vec<TitleFilter>,
+    mqtt_tls: bool,
+    hold_joins_when_offline: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -230,6 +1020,230 @@ fn default_log_level() -> LogLevel {
     defaults().tauri.log_level.clone()
 }
 
+fn default_meeting_source_priority() -> Vec<MeetingSource> {
+    defaults().tauri.meeting_source_priority.clone()
+}
+
+fn default_capture_screenshot_on_join_failure() -> bool {
+    defaults().tauri.capture_screenshot_on_join_failure
+}
+
+fn default_pause_during_out_of_office() -> bool {
+    defaults().tauri.pause_during_out_of_office
+}
+
+fn default_max_auto_joins_per_hour() -> u32 {
+    defaults().tauri.max_auto_joins_per_hour
+}
+
+fn default_privacy_mode() -> bool {
+    defaults().tauri.privacy_mode
+}
+
+fn default_green_room_minutes() -> u32 {
+    defaults().tauri.green_room_minutes
+}
+
+fn default_power_mode() -> PowerMode {
+    defaults().tauri.power_mode
+}
+
+fn default_proxy() -> Option<ProxySettings> {
+    defaults().tauri.proxy.clone()
+}
+
+fn default_allow_concurrent_meetings() -> bool {
+    defaults().tauri.allow_concurrent_meetings
+}
+
+fn default_defer_while_mic_busy() -> bool {
+    defaults().tauri.defer_while_mic_busy
+}
+
+fn default_startup_url() -> Option<String> {
+    defaults().tauri.startup_url.clone()
+}
+
+fn default_meeting_rules() -> Vec<MeetingRule> {
+    defaults().tauri.meeting_rules.clone()
+}
+
+fn default_remember_recent_codes() -> bool {
+    defaults().tauri.remember_recent_codes
+}
+
+fn default_show_notifications() -> bool {
+    defaults().tauri.show_notifications
+}
+
+fn default_busy_block_rules() -> Vec<BusyBlockRule> {
+    defaults().tauri.busy_block_rules.clone()
+}
+
+fn default_soak_test() -> Option<SoakTestSettings> {
+    defaults().tauri.soak_test.clone()
+}
+
+fn default_accessibility_announcements() -> bool {
+    defaults().tauri.accessibility_announcements
+}
+
+fn default_return_home_after_meeting() -> Option<u32> {
+    defaults().tauri.return_home_after_meeting
+}
+
+fn default_dnd_windows() -> Vec<DndWindow> {
+    defaults().tauri.dnd_windows.clone()
+}
+
+fn default_profiles() -> Vec<Profile> {
+    defaults().tauri.profiles.clone()
+}
+
+fn default_stale_refresh_minutes() -> u32 {
+    defaults().tauri.stale_refresh_minutes
+}
+
+fn default_ics_subscriptions() -> Vec<String> {
+    defaults().tauri.ics_subscriptions.clone()
+}
+
+fn default_show_meeting_summary() -> bool {
+    defaults().tauri.show_meeting_summary
+}
+
+fn default_widget_data_enabled() -> bool {
+    defaults().tauri.widget_data_enabled
+}
+
+fn default_widget_data_path() -> Option<String> {
+    defaults().tauri.widget_data_path.clone()
+}
+
+fn default_auto_join_hours() -> Option<AutoJoinHours> {
+    defaults().tauri.auto_join_hours.clone()
+}
+
+fn default_reminder_minutes_before() -> Option<u32> {
+    defaults().tauri.reminder_minutes_before
+}
+
+fn default_join_in_background() -> bool {
+    defaults().tauri.join_in_background
+}
+
+fn default_conflict_policy() -> ConflictPolicy {
+    defaults().tauri.conflict_policy
+}
+
+fn default_show_window_cause() -> bool {
+    defaults().tauri.show_window_cause
+}
+
+fn default_global_shortcuts() -> Option<GlobalShortcuts> {
+    defaults().tauri.global_shortcuts.clone()
+}
+
+fn default_maintenance_window() -> Option<MaintenanceWindow> {
+    defaults().tauri.maintenance_window.clone()
+}
+
+fn default_last_minute_meeting_minutes() -> u32 {
+    defaults().tauri.last_minute_meeting_minutes
+}
+
+fn default_demo_mode() -> bool {
+    defaults().tauri.demo_mode
+}
+
+fn default_tray_icon_template() -> bool {
+    defaults().tauri.tray_icon_template
+}
+
+fn default_api_server_enabled() -> bool {
+    defaults().tauri.api_server_enabled
+}
+
+fn default_api_server_port() -> u16 {
+    defaults().tauri.api_server_port
+}
+
+fn default_api_server_token() -> Option<String> {
+    defaults().tauri.api_server_token.clone()
+}
+
+fn default_mqtt_enabled() -> bool {
+    defaults().tauri.mqtt_enabled
+}
+
+fn default_mqtt_host() -> Option<String> {
+    defaults().tauri.mqtt_host.clone()
+}
+
+fn default_mqtt_port() -> u16 {
+    defaults().tauri.mqtt_port
+}
+
+fn default_mqtt_username() -> Option<String> {
+    defaults().tauri.mqtt_username.clone()
+}
+
+fn default_mqtt_password() -> Option<String> {
+    defaults().tauri.mqtt_password.clone()
+}
+
+fn default_mqtt_topic_prefix() -> String {
+    defaults().tauri.mqtt_topic_prefix.clone()
+}
+
+fn default_webhook_urls() -> Vec<String> {
+    defaults().tauri.webhook_urls.clone()
+}
+
+fn default_webhook_events() -> Vec<WebhookEvent> {
+    defaults().tauri.webhook_events.clone()
+}
+
+fn default_webhook_secret() -> Option<String> {
+    defaults().tauri.webhook_secret.clone()
+}
+
+fn default_slack_status_enabled() -> bool {
+    defaults().tauri.slack_status_enabled
+}
+
+fn default_slack_token() -> Option<String> {
+    defaults().tauri.slack_token.clone()
+}
+
+fn default_skip_join_when_idle_minutes() -> Option<u32> {
+    defaults().tauri.skip_join_when_idle_minutes
+}
+
+fn default_stale_data_minutes() -> u32 {
+    defaults().tauri.stale_data_minutes
+}
+
+fn default_skip_join_when_stale() -> bool {
+    defaults().tauri.skip_join_when_stale
+}
+
+fn default_exclude_title_filters() -> Vec<TitleFilter> {
+    defaults().tauri.exclude_title_filters.clone()
+}
+
+fn default_include_title_filters() -> Vec<TitleFilter> {
+    defaults().tauri.include_title_filters.clone()
+}
+
+fn default_mqtt_tls() -> bool {
+    defaults().tauri.mqtt_tls
+}
+
+fn default_hold_joins_when_offline() -> bool {
+    defaults().tauri.hold_joins_when_offline
+}
+
 impl Default for Settings {
     fn default() -> Self {
         let defaults = defaults();
@@ -249,45 +1263,345 @@ impl Default for Settings {
     }
 }
 
+/// Settings read at startup, with what loading had to do to them
+#[derive(Debug)]
+pub struct LoadedSettings {
+    pub settings: Settings,
+    /// Schema version the file was upgraded from, when migrations ran
+    pub migrated_from: Option<u32>,
+    /// Names of the migrations that ran, in order
+    pub migrations: Vec<&'static str>,
+    /// Copy of the file from before the migrations
+    pub backup: Option<PathBuf>,
+    pub quarantined: Option<QuarantinedFile>,
+    /// Last good copy the settings were restored from, after the file
+    /// itself was quarantined
+    pub recovered_from: Option<PathBuf>,
+}
+
+/// `settings.json.<suffix>`, next to the settings file
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!("{}.{}", file_name, suffix))
+}
+
+/// Copy of the file from before a migration
+fn backup_path(path: &Path, version: u32, now_ms: u64) -> PathBuf {
+    sibling_path(path, &format!("v{}-{}.bak", version, now_ms))
+}
+
+/// Copy of the latest successful save
+fn last_good_path(path: &Path) -> PathBuf {
+    sibling_path(path, "bak")
+}
+
+/// Read, migrate and parse a settings file
+fn decode_file(path: &Path) -> Result<(Settings, migrations::MigrationReport), String> {
+    let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let mut file: serde_json::Value = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+    let report = migrations::migrate(&mut file, migrations::MIGRATIONS)?;
+    let settings = serde_json::from_value(file).map_err(|e| e.to_string())?;
+    Ok((settings, report))
+}
+
 impl Settings {
     /// Get the settings file path
-    fn get_path() -> Result<PathBuf, SettingsError> {
-        let config_dir = dirs::config_dir().ok_or(SettingsError::ConfigDirError)?;
-        let app_dir = config_dir.join("meetcat");
-        fs::create_dir_all(&app_dir)?;
-        Ok(app_dir.join("settings.json"))
+    pub fn get_path() -> Result<PathBuf, SettingsError> {
+        let path = paths::settings_file().ok_or(SettingsError::ConfigDirError)?;
+        if let Some(app_dir) = path.parent() {
+            fs::create_dir_all(app_dir)?;
+        }
+        Ok(path)
     }
 
-    /// Load settings from file
-    pub fn load() -> Result<Self, SettingsError> {
+    /// Load settings from file, upgrading it to the current schema.
+    ///
+    /// A file that can't be parsed or migrated is quarantined and defaults
+    /// are used instead.
+    pub fn load() -> Result<LoadedSettings, SettingsError> {
         let path = Self::get_path()?;
+        Ok(Self::load_from(&path, logging::now_ms()))
+    }
 
+    fn load_from(path: &Path, now_ms: u64) -> LoadedSettings {
+        let mut loaded = LoadedSettings {
+            settings: Self::default(),
+            migrated_from: None,
+            migrations: Vec::new(),
+            backup: None,
+            quarantined: None,
+            recovered_from: None,
+        };
         if !path.exists() {
-            return Ok(Self::default());
+            return loaded;
         }
 
-        let content = fs::read_to_string(&path)?;
-        let settings: Settings = serde_json::from_str(&content)?;
-        Ok(settings)
+        let (settings, report) = match decode_file(path) {
+            Ok(decoded) => decoded,
+            Err(reason) => {
+                loaded.quarantined = Some(persistence::quarantine(path, now_ms, reason));
+                // Fall back to the copy kept by the last successful save
+                let last_good = last_good_path(path);
+                if last_good.exists() {
+                    match decode_file(&last_good) {
+                        Ok((settings, _)) => {
+                            if let Err(e) = settings.save_to(path) {
+                                eprintln!("[MeetCat] Failed to restore settings: {}", e);
+                            }
+                            loaded.settings = settings;
+                            loaded.recovered_from = Some(last_good);
+                        }
+                        Err(e) => eprintln!("[MeetCat] Settings backup is unreadable: {}", e),
+                    }
+                }
+                return loaded;
+            }
+        };
+        loaded.settings = settings;
+        if report.from_version > migrations::current_version() {
+            eprintln!(
+                "[MeetCat] Settings were written by a newer release (schema {}, supported {})",
+                report.from_version,
+                migrations::current_version()
+            );
+        }
+        if !report.ran.is_empty() {
+            // Keep the file as it was, then rewrite it so migrations run once
+            let backup = backup_path(path, report.from_version, now_ms);
+            match fs::copy(path, &backup) {
+                Ok(_) => {
+                    if let Err(e) = loaded.settings.save_to(path) {
+                        eprintln!("[MeetCat] Failed to save migrated settings: {}", e);
+                    }
+                    loaded.backup = Some(backup);
+                }
+                Err(e) => eprintln!(
+                    "[MeetCat] Failed to back up settings, leaving the file unmigrated: {}",
+                    e
+                ),
+            }
+            loaded.migrated_from = Some(report.from_version);
+            loaded.migrations = report.ran;
+        }
+        loaded
     }
 
     /// Save settings to file
     pub fn save(&self) -> Result<(), SettingsError> {
-        let path = Self::get_path()?;
-        let content = serde_json::to_string_pretty(self)?;
-        fs::write(&path, content)?;
+        self.save_to(&Self::get_path()?)
+    }
+
+    /// Write to a temporary file and rename it over the settings, so a crash
+    /// mid-write leaves either the old or the new file, never a torn one
+    fn save_to(&self, path: &Path) -> Result<(), SettingsError> {
+        let mut file = serde_json::to_value(self)?;
+        if let Some(map) = file.as_object_mut() {
+            map.insert(
+                migrations::VERSION_KEY.to_string(),
+                serde_json::Value::from(migrations::current_version()),
+            );
+        }
+        let content = serde_json::to_string_pretty(&file)?;
+        persistence::write_atomically(path, &content)?;
+
+        // Keep what was just saved for recovery, once it is safely in place
+        if let Err(e) = persistence::write_atomically(&last_good_path(path), &content) {
+            eprintln!("[MeetCat] Failed to back up settings: {}", e);
+        }
         Ok(())
     }
+
+    /// Desktop settings, borrowed unless the section is missing
+    pub fn tauri_or_default(&self) -> Cow<'_, TauriSettings> {
+        match &self.tauri {
+            Some(tauri) => Cow::Borrowed(tauri),
+            None => Cow::Owned(TauriSettings::default()),
+        }
+    }
+
+    /// Check ranges and formats that serde can't express, reporting every
+    /// offending field rather than stopping at the first
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = FieldErrors::default();
+        errors.range(
+            "checkIntervalSeconds",
+            self.check_interval_seconds,
+            30..=120,
+        );
+        errors.range("joinBeforeMinutes", self.join_before_minutes, 0..=30);
+        errors.range("maxMinutesAfterStart", self.max_minutes_after_start, 0..=30);
+        errors.range("joinCountdownSeconds", self.join_countdown_seconds, 0..=60);
+        for (i, filter) in self.title_exclude_filters.iter().enumerate() {
+            errors.non_empty(&format!("titleExcludeFilters.{}", i), filter);
+        }
+        if let Some(tauri) = &self.tauri {
+            tauri.validate(&mut errors);
+        }
+        errors.0
+    }
+}
+
+impl TauriSettings {
+    fn validate(&self, errors: &mut FieldErrors) {
+        errors.range(
+            "tauri.maxAutoJoinsPerHour",
+            self.max_auto_joins_per_hour,
+            0..=60,
+        );
+        errors.range("tauri.greenRoomMinutes", self.green_room_minutes, 0..=30);
+        if let Some(proxy) = &self.proxy {
+            errors.non_empty("tauri.proxy.host", &proxy.host);
+            errors.range("tauri.proxy.port", u32::from(proxy.port), 1..=65535);
+        }
+        for (i, rule) in self.meeting_rules.iter().enumerate() {
+            let field = format!("tauri.meetingRules.{}", i);
+            errors.non_empty(&format!("{}.pattern", field), &rule.pattern);
+            errors.check(&format!("{}.pattern", field), rule.validate());
+            if let Some(minutes) = rule.join_before_minutes {
+                errors.range(&format!("{}.joinBeforeMinutes", field), minutes, 0..=30);
+            }
+            if let Some(seconds) = rule.countdown_seconds {
+                errors.range(&format!("{}.countdownSeconds", field), seconds, 0..=60);
+            }
+        }
+        for (key, filters) in [
+            ("excludeTitleFilters", &self.exclude_title_filters),
+            ("includeTitleFilters", &self.include_title_filters),
+        ] {
+            for (i, filter) in filters.iter().enumerate() {
+                errors.check(&format!("tauri.{}.{}", key, i), filter.validate());
+            }
+        }
+        for (i, rule) in self.busy_block_rules.iter().enumerate() {
+            let field = format!("tauri.busyBlockRules.{}.pattern", i);
+            errors.non_empty(&field, &rule.pattern);
+            errors.check(&field, rule.validate());
+        }
+        if let Some(soak_test) = &self.soak_test {
+            errors.check("tauri.soakTest", soak_test.validate());
+        }
+        if let Some(seconds) = self.return_home_after_meeting {
+            errors.range("tauri.returnHomeAfterMeeting", seconds, 0..=600);
+        }
+        for (i, window) in self.dnd_windows.iter().enumerate() {
+            errors.check(&format!("tauri.dndWindows.{}", i), window.validate());
+        }
+        for (i, profile) in self.profiles.iter().enumerate() {
+            let field = format!("tauri.profiles.{}", i);
+            errors.check(&format!("{}.id", field), profile.validate());
+            if self.profiles[..i].iter().any(|p| p.id == profile.id) {
+                errors.push(
+                    &format!("{}.id", field),
+                    format!("Duplicate profile id \"{}\"", profile.id),
+                );
+            }
+            errors.non_empty(&format!("{}.name", field), &profile.name);
+        }
+        errors.range(
+            "tauri.staleRefreshMinutes",
+            self.stale_refresh_minutes,
+            0..=720,
+        );
+        if let Some(hours) = &self.auto_join_hours {
+            errors.check("tauri.autoJoinHours", hours.validate());
+        }
+        if let Some(minutes) = self.reminder_minutes_before {
+            errors.range("tauri.reminderMinutesBefore", minutes, 1..=60);
+        }
+        if let Some(shortcuts) = &self.global_shortcuts {
+            for (key, accelerator) in [
+                ("joinNext", &shortcuts.join_next),
+                ("cancelJoin", &shortcuts.cancel_join),
+                ("toggleMic", &shortcuts.toggle_mic),
+            ] {
+                if let Some(accelerator) = accelerator {
+                    errors.non_empty(&format!("tauri.globalShortcuts.{}", key), accelerator);
+                }
+            }
+        }
+        if let Some(window) = &self.maintenance_window {
+            errors.check("tauri.maintenanceWindow", window.validate());
+        }
+        errors.range(
+            "tauri.lastMinuteMeetingMinutes",
+            self.last_minute_meeting_minutes,
+            0..=60,
+        );
+        errors.range("tauri.mqttPort", u32::from(self.mqtt_port), 1..=65535);
+        if let Some(minutes) = self.skip_join_when_idle_minutes {
+            errors.range("tauri.skipJoinWhenIdleMinutes", minutes, 1..=240);
+        }
+        errors.range("tauri.staleDataMinutes", self.stale_data_minutes, 0..=720);
+    }
+}
+
+/// A setting that failed validation, for the settings window to highlight
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FieldError {
+    /// Path of the field as the settings window names it, e.g.
+    /// `tauri.dndWindows.0` or `joinBeforeMinutes`
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Collects [`FieldError`]s during validation
+#[derive(Default)]
+pub struct FieldErrors(pub Vec<FieldError>);
+
+impl FieldErrors {
+    pub fn push(&mut self, field: &str, message: String) {
+        self.0.push(FieldError {
+            field: field.to_string(),
+            message,
+        });
+    }
+
+    /// Record the error of a check that reports one
+    pub fn check(&mut self, field: &str, result: Result<(), String>) {
+        if let Err(message) = result {
+            self.push(field, message);
+        }
+    }
+
+    fn range(&mut self, field: &str, value: u32, range: RangeInclusive<u32>) {
+        if !range.contains(&value) {
+            self.push(
+                field,
+                format!(
+                    "Must be between {} and {}, got {}",
+                    range.start(),
+                    range.end(),
+                    value
+                ),
+            );
+        }
+    }
+
+    fn non_empty(&mut self, field: &str, value: &str) {
+        if value.trim().is_empty() {
+            self.push(field, "Must not be empty".to_string());
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::filters::TitleFilters;
 
     #[test]
     fn test_default_settings() {
         let settings = Settings::default();
-        assert_eq!(settings.check_interval_seconds, 5);
+        assert_eq!(settings.check_interval_seconds, 30);
         assert_eq!(settings.join_before_minutes, 1);
         assert_eq!(settings.max_minutes_after_start, 10);
         assert!(settings.auto_click_join);
@@ -302,7 +1616,10 @@ mod tests {
         let settings = Settings::default();
         let json = serde_json::to_string(&settings).unwrap();
         let parsed: Settings = serde_json::from_str(&json).unwrap();
-        assert_eq!(parsed.check_interval_seconds, settings.check_interval_seconds);
+        assert_eq!(
+            parsed.check_interval_seconds,
+            settings.check_interval_seconds
+        );
     }
 
     #[test]
@@ -311,9 +1628,78 @@ mod tests {
         assert!(!tauri_settings.start_at_login);
         assert!(tauri_settings.show_tray_icon);
         assert_eq!(tauri_settings.tray_display_mode, TrayDisplayMode::IconOnly);
+        assert_eq!(tauri_settings.power_mode, PowerMode::Auto);
         assert!(!tauri_settings.tray_show_meeting_title);
         assert!(!tauri_settings.log_collection_enabled);
         assert_eq!(tauri_settings.log_level, LogLevel::Info);
+        assert_eq!(
+            tauri_settings.meeting_source_priority,
+            vec![
+                MeetingSource::Webview,
+                MeetingSource::Api,
+                MeetingSource::Ics
+            ]
+        );
+        assert!(!tauri_settings.capture_screenshot_on_join_failure);
+        assert!(!tauri_settings.pause_during_out_of_office);
+        assert_eq!(tauri_settings.max_auto_joins_per_hour, 6);
+        assert!(!tauri_settings.privacy_mode);
+        assert_eq!(tauri_settings.green_room_minutes, 0);
+        assert!(tauri_settings.proxy.is_none());
+        assert!(!tauri_settings.allow_concurrent_meetings);
+        assert!(!tauri_settings.defer_while_mic_busy);
+        assert!(tauri_settings.startup_url.is_none());
+        assert!(tauri_settings.meeting_rules.is_empty());
+        assert!(tauri_settings.remember_recent_codes);
+        assert!(tauri_settings.show_notifications);
+        assert!(tauri_settings.busy_block_rules.is_empty());
+        assert!(tauri_settings.soak_test.is_none());
+        assert!(tauri_settings.accessibility_announcements);
+        assert!(tauri_settings.return_home_after_meeting.is_none());
+        assert!(tauri_settings.dnd_windows.is_empty());
+        assert!(tauri_settings.profiles.is_empty());
+        assert_eq!(tauri_settings.stale_refresh_minutes, 30);
+        assert!(tauri_settings.ics_subscriptions.is_empty());
+        assert!(!tauri_settings.show_meeting_summary);
+        assert!(!tauri_settings.widget_data_enabled);
+        assert!(tauri_settings.widget_data_path.is_none());
+        assert!(tauri_settings.auto_join_hours.is_none());
+        assert!(tauri_settings.reminder_minutes_before.is_none());
+        assert!(!tauri_settings.join_in_background);
+        assert_eq!(
+            tauri_settings.conflict_policy,
+            ConflictPolicy::FirstScheduled
+        );
+        assert!(tauri_settings.show_window_cause);
+        assert_eq!(tauri_settings.global_shortcuts, None);
+        assert!(tauri_settings.maintenance_window.is_none());
+        assert_eq!(tauri_settings.last_minute_meeting_minutes, 5);
+        assert!(!tauri_settings.demo_mode);
+        assert!(!tauri_settings.tray_icon_template);
+        assert!(!tauri_settings.api_server_enabled);
+        assert_eq!(tauri_settings.api_server_port, 47625);
+        assert!(tauri_settings.api_server_token.is_none());
+        assert!(!tauri_settings.mqtt_enabled);
+        assert!(tauri_settings.mqtt_host.is_none());
+        assert_eq!(tauri_settings.mqtt_port, 1883);
+        assert!(tauri_settings.mqtt_username.is_none());
+        assert!(tauri_settings.mqtt_password.is_none());
+        assert_eq!(tauri_settings.mqtt_topic_prefix, "meetcat");
+        assert!(tauri_settings.webhook_urls.is_empty());
+        assert_eq!(
+            tauri_settings.webhook_events,
+            vec![WebhookEvent::Joined, WebhookEvent::Closed]
+        );
+        assert!(tauri_settings.webhook_secret.is_none());
+        assert!(!tauri_settings.slack_status_enabled);
+        assert!(tauri_settings.slack_token.is_none());
+        assert!(tauri_settings.skip_join_when_idle_minutes.is_none());
+        assert_eq!(tauri_settings.stale_data_minutes, 15);
+        assert!(!tauri_settings.skip_join_when_stale);
+        assert!(tauri_settings.exclude_title_filters.is_empty());
+        assert!(tauri_settings.include_title_filters.is_empty());
+        assert!(!tauri_settings.mqtt_tls);
+        assert!(tauri_settings.hold_joins_when_offline);
     }
 
     #[test]
@@ -347,7 +1733,7 @@ mod tests {
 
         assert_eq!(settings.join_before_minutes, 5);
         // Other fields should use defaults
-        assert_eq!(settings.check_interval_seconds, 5);
+        assert_eq!(settings.check_interval_seconds, 30);
         assert_eq!(settings.max_minutes_after_start, 10);
         assert!(settings.auto_click_join);
         assert_eq!(settings.default_mic_state, MediaState::Muted);
@@ -360,7 +1746,9 @@ mod tests {
 
         assert_eq!(settings.title_exclude_filters.len(), 3);
         assert!(settings.title_exclude_filters.contains(&"1:1".to_string()));
-        assert!(settings.title_exclude_filters.contains(&"Optional".to_string()));
+        assert!(settings
+            .title_exclude_filters
+            .contains(&"Optional".to_string()));
     }
 
     #[test]
@@ -430,6 +1818,106 @@ mod tests {
                 tray_show_meeting_title: true,
                 log_collection_enabled: true,
                 log_level: LogLevel::Debug,
+                meeting_source_priority: vec![MeetingSource::Ics, MeetingSource::Webview],
+                capture_screenshot_on_join_failure: true,
+                pause_during_out_of_office: true,
+                max_auto_joins_per_hour: 3,
+                privacy_mode: true,
+                green_room_minutes: 5,
+                power_mode: PowerMode::LowPower,
+                proxy: Some(ProxySettings {
+                    host: "proxy.local".to_string(),
+                    port: 8080,
+                    username: Some("user".to_string()),
+                    password: None,
+                }),
+                allow_concurrent_meetings: true,
+                defer_while_mic_busy: true,
+                startup_url: Some("https://calendar.google.com/calendar/r/day".to_string()),
+                meeting_rules: vec![MeetingRule {
+                    pattern: "^Standup".to_string(),
+                    match_mode: TitleMatchMode::Regex,
+                    auto_join: Some(true),
+                    join_before_minutes: Some(2),
+                    mic_state: Some(MediaState::Unmuted),
+                    camera_state: None,
+                    auto_click_join: Some(false),
+                    countdown_seconds: Some(0),
+                }],
+                remember_recent_codes: false,
+                show_notifications: false,
+                busy_block_rules: vec![BusyBlockRule {
+                    pattern: "^Lunch".to_string(),
+                    match_mode: TitleMatchMode::Regex,
+                    policy: BusyBlockPolicy::Confirm,
+                }],
+                soak_test: Some(SoakTestSettings {
+                    clock_factor: 120,
+                    meeting_interval_minutes: 5,
+                }),
+                accessibility_announcements: false,
+                return_home_after_meeting: Some(30),
+                dnd_windows: vec![DndWindow {
+                    weekday: Weekday::Fri,
+                    start: "16:00".to_string(),
+                    end: "18:00".to_string(),
+                }],
+                profiles: vec![Profile {
+                    id: "work".to_string(),
+                    name: "Work".to_string(),
+                }],
+                stale_refresh_minutes: 45,
+                ics_subscriptions: vec!["https://calendar.example.com/team.ics".to_string()],
+                show_meeting_summary: true,
+                widget_data_enabled: true,
+                widget_data_path: Some("/tmp/meetcat-widget.json".to_string()),
+                auto_join_hours: Some(AutoJoinHours {
+                    start: "09:00".to_string(),
+                    end: "18:00".to_string(),
+                    weekdays: vec![Weekday::Mon, Weekday::Fri],
+                }),
+                reminder_minutes_before: Some(5),
+                join_in_background: true,
+                conflict_policy: ConflictPolicy::PromptUser,
+                show_window_cause: false,
+                global_shortcuts: Some(GlobalShortcuts {
+                    toggle_mic: None,
+                    ..Default::default()
+                }),
+                maintenance_window: Some(MaintenanceWindow {
+                    start: "02:00".to_string(),
+                    end: "04:00".to_string(),
+                }),
+                last_minute_meeting_minutes: 10,
+                demo_mode: true,
+                tray_icon_template: true,
+                api_server_enabled: true,
+                api_server_port: 48000,
+                api_server_token: None,
+                mqtt_enabled: true,
+                mqtt_host: Some("broker.local".to_string()),
+                mqtt_port: 1884,
+                mqtt_username: Some("meetcat".to_string()),
+                mqtt_password: None,
+                mqtt_topic_prefix: "home/office".to_string(),
+                webhook_urls: vec!["https://hooks.example.com/meetcat".to_string()],
+                webhook_events: vec![WebhookEvent::TriggerFired],
+                webhook_secret: None,
+                slack_status_enabled: true,
+                slack_token: None,
+                skip_join_when_idle_minutes: Some(10),
+                stale_data_minutes: 20,
+                skip_join_when_stale: true,
+                exclude_title_filters: vec![TitleFilter::Exact {
+                    pattern: "Lunch".to_string(),
+                    case_sensitive: true,
+                }],
+                include_title_filters: vec![TitleFilter::Regex {
+                    pattern: "sync$".to_string(),
+                    case_sensitive: false,
+                }],
+                mqtt_tls: true,
+                hold_joins_when_offline: false,
             }),
         };
 
@@ -450,8 +1938,443 @@ mod tests {
         assert!(tauri.start_at_login);
         assert!(!tauri.show_tray_icon);
         assert_eq!(tauri.tray_display_mode, TrayDisplayMode::IconWithTime);
+        assert_eq!(tauri.power_mode, PowerMode::LowPower);
         assert!(tauri.tray_show_meeting_title);
         assert!(tauri.log_collection_enabled);
         assert_eq!(tauri.log_level, LogLevel::Debug);
+        assert_eq!(
+            tauri.meeting_source_priority,
+            vec![MeetingSource::Ics, MeetingSource::Webview]
+        );
+        let proxy = tauri.proxy.unwrap();
+        assert_eq!(proxy.port, 8080);
+        assert_eq!(proxy.username.as_deref(), Some("user"));
+        assert!(tauri.allow_concurrent_meetings);
+        assert!(tauri.defer_while_mic_busy);
+        assert_eq!(
+            tauri.startup_url.as_deref(),
+            Some("https://calendar.google.com/calendar/r/day")
+        );
+        assert_eq!(tauri.meeting_rules.len(), 1);
+        assert_eq!(tauri.meeting_rules[0].match_mode, TitleMatchMode::Regex);
+        assert_eq!(tauri.meeting_rules[0].mic_state, Some(MediaState::Unmuted));
+        assert_eq!(tauri.meeting_rules[0].auto_click_join, Some(false));
+        assert_eq!(tauri.meeting_rules[0].countdown_seconds, Some(0));
+        assert!(!tauri.remember_recent_codes);
+        assert!(!tauri.show_notifications);
+        assert_eq!(tauri.busy_block_rules[0].policy, BusyBlockPolicy::Confirm);
+        assert!(tauri.busy_block_rules[0]
+            .match_mode
+            .filter(&tauri.busy_block_rules[0].pattern)
+            .matches("Lunch with Bob"));
+        assert_eq!(tauri.soak_test.unwrap().clock_factor, 120);
+        assert!(!tauri.accessibility_announcements);
+        assert_eq!(tauri.return_home_after_meeting, Some(30));
+        assert_eq!(tauri.dnd_windows[0].weekday, Weekday::Fri);
+        assert_eq!(tauri.profiles[0].id, "work");
+        assert_eq!(tauri.stale_refresh_minutes, 45);
+        assert_eq!(
+            tauri.ics_subscriptions,
+            vec!["https://calendar.example.com/team.ics".to_string()]
+        );
+        assert!(tauri.show_meeting_summary);
+        assert!(tauri.widget_data_enabled);
+        assert_eq!(
+            tauri.widget_data_path.as_deref(),
+            Some("/tmp/meetcat-widget.json")
+        );
+        assert_eq!(
+            tauri.auto_join_hours.unwrap().weekdays,
+            [Weekday::Mon, Weekday::Fri]
+        );
+        assert_eq!(tauri.reminder_minutes_before, Some(5));
+        assert!(tauri.join_in_background);
+        assert_eq!(tauri.conflict_policy, ConflictPolicy::PromptUser);
+        assert!(!tauri.show_window_cause);
+        assert_eq!(tauri.global_shortcuts.unwrap().toggle_mic, None);
+        assert_eq!(tauri.maintenance_window.unwrap().end, "04:00");
+        assert_eq!(tauri.last_minute_meeting_minutes, 10);
+        assert!(tauri.demo_mode);
+        assert!(tauri.tray_icon_template);
+        assert!(tauri.api_server_enabled);
+        assert_eq!(tauri.api_server_port, 48000);
+        assert!(tauri.api_server_token.is_none());
+        assert!(tauri.mqtt_enabled);
+        assert_eq!(tauri.mqtt_host.as_deref(), Some("broker.local"));
+        assert_eq!(tauri.mqtt_port, 1884);
+        assert_eq!(tauri.mqtt_username.as_deref(), Some("meetcat"));
+        assert!(tauri.mqtt_password.is_none());
+        assert_eq!(tauri.mqtt_topic_prefix, "home/office");
+        assert_eq!(
+            tauri.webhook_urls,
+            vec!["https://hooks.example.com/meetcat".to_string()]
+        );
+        assert_eq!(tauri.webhook_events, vec![WebhookEvent::TriggerFired]);
+        assert!(tauri.webhook_secret.is_none());
+        assert!(tauri.slack_status_enabled);
+        assert!(tauri.slack_token.is_none());
+        assert_eq!(tauri.skip_join_when_idle_minutes, Some(10));
+        assert_eq!(tauri.stale_data_minutes, 20);
+        assert!(tauri.skip_join_when_stale);
+        assert_eq!(tauri.exclude_title_filters.len(), 1);
+        assert_eq!(tauri.include_title_filters.len(), 1);
+        assert!(tauri.mqtt_tls);
+        assert!(!tauri.hold_joins_when_offline);
+    }
+
+    #[test]
+    fn test_meeting_rules_deserialize_and_match() {
+        let json = r#"{
+            "tauri": {
+                "meetingRules": [
+                    { "pattern": "1:1", "autoJoin": false },
+                    { "pattern": "^(Daily )?Standup$", "matchMode": "regex", "countdownSeconds": 0 },
+                    { "pattern": "([", "matchMode": "regex" }
+                ]
+            }
+        }"#;
+        let settings: Settings = serde_json::from_str(json).unwrap();
+        let title_filters = TitleFilters::from_settings(&settings);
+        assert_eq!(title_filters.meeting_rule("Weekly 1:1 with Sam"), Some(0));
+        assert_eq!(title_filters.meeting_rule("Daily Standup"), Some(1));
+        assert_eq!(title_filters.meeting_rule("Standup notes review"), None);
+        assert_eq!(title_filters.meeting_rule("(["), None);
+
+        let rules = settings.tauri.unwrap().meeting_rules;
+        assert_eq!(rules[0].match_mode, TitleMatchMode::Contains);
+        assert_eq!(rules[0].auto_join, Some(false));
+        assert_eq!(rules[1].countdown_seconds, Some(0));
+        assert!(rules[1].validate().is_ok());
+        assert!(rules[2].validate().is_err());
+    }
+
+    #[test]
+    fn test_profile_validate() {
+        let profile = |id: &str| Profile {
+            id: id.to_string(),
+            name: "Work".to_string(),
+        };
+        assert!(profile("work").validate().is_ok());
+        assert!(profile("client-2").validate().is_ok());
+        assert!(profile("").validate().is_err());
+        assert!(profile("Work").validate().is_err());
+        assert!(profile("../work").validate().is_err());
+    }
+
+    #[test]
+    fn test_log_level_parsing() {
+        for level in LogLevel::ALL {
+            let json = serde_json::to_string(&level).unwrap();
+            assert_eq!(json, format!("\"{}\"", level));
+            assert_eq!(serde_json::from_str::<LogLevel>(&json).unwrap(), level);
+        }
+        assert_eq!("WARNING".parse::<LogLevel>(), Ok(LogLevel::Warn));
+        assert_eq!(" Debug ".parse::<LogLevel>(), Ok(LogLevel::Debug));
+
+        let err = "loud".parse::<LogLevel>().unwrap_err();
+        assert_eq!(err.level, "loud");
+        assert_eq!(err.expected, ["error", "warn", "info", "debug", "trace"]);
+        assert!(serde_json::from_str::<LogLevel>("\"loud\"").is_err());
+    }
+
+    #[test]
+    fn test_auto_join_hours() {
+        let at = |day: u32, time: &str| {
+            // 2026-10-12 is a Monday
+            chrono::NaiveDate::from_ymd_opt(2026, 10, day)
+                .unwrap()
+                .and_time(NaiveTime::parse_from_str(time, "%H:%M").unwrap())
+        };
+        let hours: AutoJoinHours = serde_json::from_str(
+            r#"{
+                "start": "09:00",
+                "end": "18:00",
+                "weekdays": ["Mon", "Tue", "Wed", "Thu", "Fri"]
+            }"#,
+        )
+        .unwrap();
+        assert!(hours.validate().is_ok());
+        assert!(!hours.contains(at(12, "07:00")));
+        assert!(hours.contains(at(12, "09:00")));
+        assert!(!hours.contains(at(12, "18:00")));
+        // Saturday
+        assert!(!hours.contains(at(17, "10:00")));
+
+        let overnight = AutoJoinHours {
+            start: "22:00".to_string(),
+            end: "06:00".to_string(),
+            weekdays: vec![Weekday::Fri],
+        };
+        assert!(overnight.contains(at(16, "23:00")));
+        assert!(overnight.contains(at(17, "05:59")));
+        assert!(!overnight.contains(at(17, "22:30")));
+
+        let no_days = AutoJoinHours {
+            weekdays: Vec::new(),
+            ..hours.clone()
+        };
+        assert!(no_days.validate().is_err());
+        let broken = AutoJoinHours {
+            start: "9am".to_string(),
+            ..hours
+        };
+        assert!(broken.validate().is_err());
+    }
+
+    #[test]
+    fn test_maintenance_window() {
+        let at = |time: &str| NaiveTime::parse_from_str(time, "%H:%M").unwrap();
+        let window = MaintenanceWindow {
+            start: "02:00".to_string(),
+            end: "04:00".to_string(),
+        };
+        assert!(window.validate().is_ok());
+        assert!(window.contains(at("02:00")));
+        assert!(!window.contains(at("04:00")));
+
+        let overnight = MaintenanceWindow {
+            start: "23:30".to_string(),
+            end: "01:00".to_string(),
+        };
+        assert!(overnight.contains(at("23:45")));
+        assert!(overnight.contains(at("00:30")));
+        assert!(!overnight.contains(at("12:00")));
+
+        let broken = MaintenanceWindow {
+            start: "2am".to_string(),
+            ..window
+        };
+        assert!(broken.validate().is_err());
+        assert!(!broken.contains(at("02:30")));
+    }
+
+    #[test]
+    fn test_dnd_windows() {
+        let at = |day: u32, time: &str| {
+            // 2026-10-12 is a Monday
+            chrono::NaiveDate::from_ymd_opt(2026, 10, day)
+                .unwrap()
+                .and_time(NaiveTime::parse_from_str(time, "%H:%M").unwrap())
+        };
+        let windows: Vec<DndWindow> = serde_json::from_str(
+            r#"[
+                { "weekday": "Mon", "start": "12:00", "end": "13:00" },
+                { "weekday": "Fri", "start": "22:00", "end": "07:00" }
+            ]"#,
+        )
+        .unwrap();
+        assert!(in_dnd_window(&windows, at(12, "12:00")));
+        assert!(!in_dnd_window(&windows, at(12, "13:00")));
+        assert!(!in_dnd_window(&windows, at(13, "12:30")));
+        // Overnight from Friday into Saturday
+        assert!(in_dnd_window(&windows, at(16, "23:30")));
+        assert!(in_dnd_window(&windows, at(17, "06:59")));
+        assert!(!in_dnd_window(&windows, at(17, "07:00")));
+
+        let broken = DndWindow {
+            weekday: Weekday::Mon,
+            start: "9am".to_string(),
+            end: "10:00".to_string(),
+        };
+        assert!(broken.validate().is_err());
+        assert!(!broken.contains(at(12, "09:30")));
+        assert!(windows.iter().all(|w| w.validate().is_ok()));
+    }
+
+    fn temp_settings_path(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("meetcat-settings-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir.join("settings.json")
+    }
+
+    #[test]
+    fn test_load_migrates_unversioned_file_with_backup() {
+        let path = temp_settings_path("migrate");
+        let legacy = r#"{ "joinBeforeMinutes": 3 }"#;
+        fs::write(&path, legacy).unwrap();
+
+        let loaded = Settings::load_from(&path, 42);
+        assert_eq!(loaded.settings.join_before_minutes, 3);
+        assert_eq!(loaded.migrated_from, Some(0));
+        assert_eq!(
+            loaded.migrations,
+            vec![
+                "add_schema_version",
+                "raise_check_interval",
+                "move_auto_click_rules",
+                "move_countdown_rules"
+            ]
+        );
+        let backup = loaded.backup.expect("backup");
+        assert!(backup.ends_with("settings.json.v0-42.bak"));
+        assert_eq!(fs::read_to_string(&backup).unwrap(), legacy);
+
+        let saved: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(
+            saved[migrations::VERSION_KEY],
+            migrations::current_version()
+        );
+
+        // Already current, so nothing runs the second time
+        let loaded = Settings::load_from(&path, 43);
+        assert_eq!(loaded.settings.join_before_minutes, 3);
+        assert_eq!(loaded.migrated_from, None);
+        assert!(loaded.backup.is_none());
+    }
+
+    #[test]
+    fn test_load_quarantines_unparseable_file() {
+        let path = temp_settings_path("corrupt");
+        assert!(Settings::load_from(&path, 0).quarantined.is_none());
+
+        fs::write(&path, "{ not json").unwrap();
+        let loaded = Settings::load_from(&path, 7);
+        assert_eq!(loaded.settings.join_before_minutes, 1);
+        let quarantined = loaded.quarantined.expect("quarantined");
+        assert!(!path.exists());
+        assert!(quarantined.moved_to.exists());
+    }
+
+    #[test]
+    fn test_save_is_atomic_and_recovers_last_good_copy() {
+        let path = temp_settings_path("recover");
+        let mut settings = Settings {
+            join_before_minutes: 4,
+            ..Settings::default()
+        };
+        settings.save_to(&path).unwrap();
+        assert!(!sibling_path(&path, "tmp").exists());
+        assert!(!sibling_path(&last_good_path(&path), "tmp").exists());
+        assert_eq!(
+            fs::read_to_string(last_good_path(&path)).unwrap(),
+            fs::read_to_string(&path).unwrap()
+        );
+
+        settings.join_before_minutes = 5;
+        settings.save_to(&path).unwrap();
+        fs::write(&path, r#"{ "joinBeforeMinutes": "#).unwrap();
+
+        // The latest save comes back, not the one before it
+        let loaded = Settings::load_from(&path, 9);
+        assert!(loaded.quarantined.is_some());
+        assert_eq!(loaded.recovered_from, Some(last_good_path(&path)));
+        assert_eq!(loaded.settings.join_before_minutes, 5);
+        // Restored to disk, so the next start loads it directly
+        let loaded = Settings::load_from(&path, 10);
+        assert!(loaded.quarantined.is_none());
+        assert_eq!(loaded.settings.join_before_minutes, 5);
+    }
+
+    #[test]
+    fn test_validate_reports_every_offending_field() {
+        assert!(Settings::default().validate().is_empty());
+
+        let settings = Settings {
+            check_interval_seconds: 0,
+            join_before_minutes: 10_000,
+            title_exclude_filters: vec!["Standup".to_string(), " ".to_string()],
+            tauri: Some(TauriSettings {
+                stale_data_minutes: 721,
+                reminder_minutes_before: Some(0),
+                dnd_windows: vec![DndWindow {
+                    weekday: Weekday::Mon,
+                    start: "9am".to_string(),
+                    end: "10:00".to_string(),
+                }],
+                ..TauriSettings::default()
+            }),
+            ..Settings::default()
+        };
+
+        let fields: Vec<String> = settings
+            .validate()
+            .into_iter()
+            .map(|error| error.field)
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                "checkIntervalSeconds",
+                "joinBeforeMinutes",
+                "titleExcludeFilters.1",
+                "tauri.dndWindows.0",
+                "tauri.reminderMinutesBefore",
+                "tauri.staleDataMinutes",
+            ]
+        );
+
+        let error = FieldError {
+            field: "joinBeforeMinutes".to_string(),
+            message: "Must be between 0 and 30, got 10000".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            serde_json::json!({
+                "field": "joinBeforeMinutes",
+                "message": "Must be between 0 and 30, got 10000"
+            })
+        );
+    }
+
+    /// `min`/`max` bounds of `key` in the zod schema, searched from `section`
+    fn schema_bounds(section: &str, key: &str) -> (u32, u32) {
+        let schema = include_str!("../../../settings/src/schema.ts");
+        let rest = &schema[schema.find(section).expect(section)..];
+        let rest = &rest[rest
+            .find(&format!("  {}: z", key))
+            .unwrap_or_else(|| panic!("{} is not in the schema", key))..];
+        let field = &rest[..rest.find(".default(").expect("default")];
+        let bound = |name: &str| {
+            let value = &field[field.find(name).expect(name) + name.len()..];
+            value[..value.find(')').unwrap()].parse::<u32>().unwrap()
+        };
+        (bound(".min("), bound(".max("))
+    }
+
+    /// Whether `validate` reports `path` once it is set to `value`; a value
+    /// that doesn't even parse counts as rejected
+    fn rejects(path: &str, value: u32) -> bool {
+        let mut json = serde_json::to_value(Settings::default()).unwrap();
+        *json
+            .pointer_mut(&format!("/{}", path.replace('.', "/")))
+            .unwrap() = value.into();
+        serde_json::from_value::<Settings>(json).map_or(true, |settings| {
+            settings.validate().iter().any(|error| error.field == path)
+        })
+    }
+
+    #[test]
+    fn test_validate_ranges_match_schema() {
+        let top = "export const SettingsSchema";
+        let tauri = "export const TauriSettingsSchema";
+        for (section, path) in [
+            (top, "checkIntervalSeconds"),
+            (top, "joinBeforeMinutes"),
+            (top, "maxMinutesAfterStart"),
+            (top, "joinCountdownSeconds"),
+            (tauri, "tauri.maxAutoJoinsPerHour"),
+            (tauri, "tauri.greenRoomMinutes"),
+            (tauri, "tauri.returnHomeAfterMeeting"),
+            (tauri, "tauri.staleRefreshMinutes"),
+            (tauri, "tauri.reminderMinutesBefore"),
+            (tauri, "tauri.lastMinuteMeetingMinutes"),
+            (tauri, "tauri.mqttPort"),
+            (tauri, "tauri.skipJoinWhenIdleMinutes"),
+            (tauri, "tauri.staleDataMinutes"),
+        ] {
+            let key = path.trim_start_matches("tauri.");
+            let (min, max) = schema_bounds(section, key);
+            assert!(!rejects(path, min), "{} rejects its minimum {}", path, min);
+            assert!(!rejects(path, max), "{} rejects its maximum {}", path, max);
+            assert!(rejects(path, max + 1), "{} accepts {}", path, max + 1);
+            if min > 0 {
+                assert!(rejects(path, min - 1), "{} accepts {}", path, min - 1);
+            }
+        }
+        // The shared defaults fall inside every range
+        assert!(Settings::default().validate().is_empty());
     }
 }
@@ -0,0 +1,73 @@
+//! Debounced persistence of settings
+//!
+//! `save_settings` updates the in-memory settings right away but only writes
+//! the file, emits `settings_changed` and refreshes the tray once the UI has
+//! been quiet for [`SAVE_DEBOUNCE_MS`]. The settings from before the first
+//! pending change are kept so one change summary covers the whole burst.
+
+use crate::settings::Settings;
+
+/// Quiet period before pending settings are persisted
+pub const SAVE_DEBOUNCE_MS: u64 = 500;
+
+#[derive(Debug, Default)]
+pub struct SaveDebouncer {
+    /// Settings as last persisted, while a save is pending
+    baseline: Option<Settings>,
+    /// Bumped on every change so stale timers can tell they were superseded
+    generation: u64,
+}
+
+impl SaveDebouncer {
+    /// Record a change away from `previous`, returning the generation the
+    /// debounce timer should flush
+    pub fn record(&mut self, previous: &Settings) -> u64 {
+        if self.baseline.is_none() {
+            self.baseline = Some(previous.clone());
+        }
+        self.generation += 1;
+        self.generation
+    }
+
+    /// Whether no change arrived after the one that started `generation`
+    pub fn is_latest(&self, generation: u64) -> bool {
+        self.generation == generation
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.baseline.is_some()
+    }
+
+    /// Take the baseline for the change summary, ending the pending save
+    pub fn take(&mut self) -> Option<Settings> {
+        self.baseline.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burst_coalesces_to_first_baseline() {
+        let mut debouncer = SaveDebouncer::default();
+        assert!(debouncer.take().is_none());
+
+        let original = Settings::default();
+        let first = debouncer.record(&original);
+        let changed = Settings {
+            join_before_minutes: original.join_before_minutes + 1,
+            ..original.clone()
+        };
+        let second = debouncer.record(&changed);
+
+        assert!(!debouncer.is_latest(first));
+        assert!(debouncer.is_latest(second));
+        assert!(debouncer.is_pending());
+        assert_eq!(
+            debouncer.take().map(|s| s.join_before_minutes),
+            Some(original.join_before_minutes)
+        );
+        assert!(!debouncer.is_pending());
+    }
+}
@@ -0,0 +1,135 @@
+//! Classification of settings window failures
+//!
+//! Creating the settings webview can fail when the platform webview runtime is
+//! missing (WebView2 on Windows, WebKitGTK on Linux) or when no display server
+//! is reachable. Failures are classified so the UI and logs can say what went
+//! wrong, and the caller falls back to revealing the settings file for manual
+//! editing.
+
+use crate::desktop_notify;
+use serde::Serialize;
+use std::fmt;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SettingsWindowErrorKind {
+    /// WebView2 or WebKitGTK is not installed or failed to load
+    WebviewRuntimeMissing,
+    /// No X11/Wayland display or the windowing system refused the window
+    DisplayUnavailable,
+    Other,
+}
+
+/// Structured error returned when the settings window cannot be opened
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsWindowError {
+    pub kind: SettingsWindowErrorKind,
+    pub message: String,
+    /// What the user can do instead
+    pub hint: String,
+    /// Settings file revealed as a fallback, when that succeeded
+    pub settings_path: Option<PathBuf>,
+}
+
+impl SettingsWindowError {
+    pub fn from_message(message: String) -> Self {
+        let kind = classify_window_error(&message);
+        Self {
+            kind,
+            hint: hint_for(kind).to_string(),
+            message,
+            settings_path: None,
+        }
+    }
+}
+
+impl fmt::Display for SettingsWindowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.kind, self.message)
+    }
+}
+
+impl From<SettingsWindowError> for String {
+    fn from(error: SettingsWindowError) -> Self {
+        error.to_string()
+    }
+}
+
+/// Classify a window creation error message
+pub fn classify_window_error(message: &str) -> SettingsWindowErrorKind {
+    let lower = message.to_lowercase();
+    const RUNTIME_MARKERS: &[&str] = &["webview2", "webkit", "libwebkit2gtk", "javascriptcore"];
+    const DISPLAY_MARKERS: &[&str] = &[
+        "cannot open display",
+        "failed to initialize gtk",
+        "gtk has not been initialized",
+        "wayland",
+        "x11",
+        "$display",
+        "no display",
+    ];
+    if RUNTIME_MARKERS.iter().any(|marker| lower.contains(marker)) {
+        SettingsWindowErrorKind::WebviewRuntimeMissing
+    } else if DISPLAY_MARKERS.iter().any(|marker| lower.contains(marker)) {
+        SettingsWindowErrorKind::DisplayUnavailable
+    } else {
+        SettingsWindowErrorKind::Other
+    }
+}
+
+fn hint_for(kind: SettingsWindowErrorKind) -> &'static str {
+    match kind {
+        SettingsWindowErrorKind::WebviewRuntimeMissing => {
+            "Install or repair the system webview (WebView2 on Windows, WebKitGTK on Linux), or edit settings.json directly and restart MeetCat."
+        }
+        SettingsWindowErrorKind::DisplayUnavailable => {
+            "MeetCat could not reach the display server. Edit settings.json directly and restart MeetCat."
+        }
+        SettingsWindowErrorKind::Other => {
+            "The settings window could not be opened. Edit settings.json directly and restart MeetCat."
+        }
+    }
+}
+
+/// Show a desktop notification explaining the manual-edit fallback
+pub fn notify_manual_editing(error: &SettingsWindowError) {
+    desktop_notify::show("MeetCat settings unavailable", &error.hint);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_window_error() {
+        assert_eq!(
+            classify_window_error("WebView2 runtime not found (0x80070002)"),
+            SettingsWindowErrorKind::WebviewRuntimeMissing
+        );
+        assert_eq!(
+            classify_window_error("failed to load libwebkit2gtk-4.1.so.0"),
+            SettingsWindowErrorKind::WebviewRuntimeMissing
+        );
+        assert_eq!(
+            classify_window_error("Gtk: cannot open display: :0"),
+            SettingsWindowErrorKind::DisplayUnavailable
+        );
+        assert_eq!(
+            classify_window_error("a window with label `settings` already exists"),
+            SettingsWindowErrorKind::Other
+        );
+    }
+
+    #[test]
+    fn test_error_converts_for_string_callers() {
+        let error = SettingsWindowError::from_message("Failed to initialize GTK".to_string());
+        assert_eq!(error.kind, SettingsWindowErrorKind::DisplayUnavailable);
+        assert!(error.hint.contains("settings.json"));
+        assert_eq!(
+            String::from(error),
+            "DisplayUnavailable: Failed to initialize GTK"
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//! System-wide keyboard shortcuts
+//!
+//! Bindings come from `tauri.globalShortcuts` and are registered again
+//! whenever that setting changes. The outcome of every binding is kept, so
+//! invalid accelerators, two actions on the same keys, and keys the system
+//! refused (usually because another app owns them) can be reported back.
+
+use crate::locks::LockExt;
+use crate::settings::GlobalShortcuts;
+use crate::AppState;
+use serde::Serialize;
+use std::str::FromStr;
+use tauri::plugin::TauriPlugin;
+use tauri::{AppHandle, Manager, Runtime, Wry};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+/// What a global shortcut does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortcutAction {
+    /// Join the next meeting right away
+    JoinNext,
+    /// Cancel the pending auto-join
+    CancelJoin,
+    /// Mute or unmute the microphone of the current call
+    ToggleMic,
+}
+
+impl ShortcutAction {
+    pub const ALL: [ShortcutAction; 3] = [
+        ShortcutAction::JoinNext,
+        ShortcutAction::CancelJoin,
+        ShortcutAction::ToggleMic,
+    ];
+
+    fn accelerator(self, bindings: &GlobalShortcuts) -> Option<&str> {
+        match self {
+            ShortcutAction::JoinNext => bindings.join_next.as_deref(),
+            ShortcutAction::CancelJoin => bindings.cancel_join.as_deref(),
+            ShortcutAction::ToggleMic => bindings.toggle_mic.as_deref(),
+        }
+    }
+}
+
+/// Outcome of one configured binding
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShortcutStatus {
+    pub action: ShortcutAction,
+    pub accelerator: String,
+    pub registered: bool,
+    /// Why the binding is not active
+    pub error: Option<String>,
+}
+
+/// Configured bindings split into usable shortcuts and rejected ones
+#[derive(Debug, Default)]
+pub struct ShortcutPlan {
+    pub shortcuts: Vec<(ShortcutAction, String, Shortcut)>,
+    /// Invalid accelerators and ones already bound to an earlier action
+    pub rejected: Vec<ShortcutStatus>,
+}
+
+/// Parse the bound accelerators, rejecting invalid and duplicate ones
+pub fn plan(bindings: &GlobalShortcuts) -> ShortcutPlan {
+    let mut plan = ShortcutPlan::default();
+    for action in ShortcutAction::ALL {
+        let Some(accelerator) = action.accelerator(bindings) else {
+            continue;
+        };
+        let error = match Shortcut::from_str(accelerator) {
+            Err(e) => format!("Invalid shortcut: {}", e),
+            Ok(shortcut) => match plan.shortcuts.iter().find(|(_, _, s)| *s == shortcut) {
+                Some((other, _, _)) => format!("Already bound to {:?}", other),
+                None => {
+                    plan.shortcuts
+                        .push((action, accelerator.to_string(), shortcut));
+                    continue;
+                }
+            },
+        };
+        plan.rejected.push(ShortcutStatus {
+            action,
+            accelerator: accelerator.to_string(),
+            registered: false,
+            error: Some(error),
+        });
+    }
+    plan
+}
+
+/// Registered shortcuts and the status of every configured binding
+#[derive(Debug, Default)]
+pub struct ShortcutRegistry {
+    active: Vec<(ShortcutAction, Shortcut)>,
+    statuses: Vec<ShortcutStatus>,
+}
+
+impl ShortcutRegistry {
+    pub fn action_for(&self, shortcut: &Shortcut) -> Option<ShortcutAction> {
+        self.active
+            .iter()
+            .find(|(_, s)| s == shortcut)
+            .map(|(action, _)| *action)
+    }
+
+    /// One entry per bound action, in action order
+    pub fn statuses(&self) -> &[ShortcutStatus] {
+        &self.statuses
+    }
+}
+
+/// Drop the previous bindings and register `bindings`; `None` turns global
+/// shortcuts off
+pub fn apply<R: Runtime>(
+    app: &AppHandle<R>,
+    bindings: Option<&GlobalShortcuts>,
+) -> ShortcutRegistry {
+    let manager = app.global_shortcut();
+    if let Err(e) = manager.unregister_all() {
+        eprintln!("[MeetCat] Failed to unregister global shortcuts: {}", e);
+    }
+    let Some(bindings) = bindings else {
+        return ShortcutRegistry::default();
+    };
+
+    let plan = plan(bindings);
+    let mut registry = ShortcutRegistry {
+        active: Vec::new(),
+        statuses: plan.rejected,
+    };
+    for (action, accelerator, shortcut) in plan.shortcuts {
+        let error = manager.register(shortcut).err().map(|e| e.to_string());
+        if error.is_none() {
+            registry.active.push((action, shortcut));
+        }
+        registry.statuses.push(ShortcutStatus {
+            action,
+            accelerator,
+            registered: error.is_none(),
+            error,
+        });
+    }
+    registry.statuses.sort_by_key(|status| status.action);
+    registry
+}
+
+/// Global shortcut plugin that runs the bound action on key press
+pub fn plugin() -> TauriPlugin<Wry> {
+    tauri_plugin_global_shortcut::Builder::new()
+        .with_handler(|app, shortcut, event| {
+            if event.state != ShortcutState::Pressed {
+                return;
+            }
+            let action = app
+                .try_state::<AppState>()
+                .and_then(|state| state.shortcuts.lock_or_recover().action_for(shortcut));
+            if let Some(action) = action {
+                crate::run_shortcut_action(app, action);
+            }
+        })
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_rejects_invalid_and_duplicate_bindings() {
+        let bindings = GlobalShortcuts {
+            join_next: Some("CommandOrControl+Shift+J".to_string()),
+            cancel_join: Some("Shift+CommandOrControl+J".to_string()),
+            toggle_mic: Some("Ctrl+Nope".to_string()),
+        };
+        let plan = plan(&bindings);
+
+        assert_eq!(plan.shortcuts.len(), 1);
+        assert_eq!(plan.shortcuts[0].0, ShortcutAction::JoinNext);
+        let rejected: Vec<_> = plan.rejected.iter().map(|s| s.action).collect();
+        assert_eq!(
+            rejected,
+            [ShortcutAction::CancelJoin, ShortcutAction::ToggleMic]
+        );
+        assert!(plan.rejected[0]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("JoinNext"));
+    }
+
+    #[test]
+    fn test_plan_skips_unbound_actions() {
+        let bindings = GlobalShortcuts {
+            toggle_mic: None,
+            ..Default::default()
+        };
+        let plan = plan(&bindings);
+
+        let actions: Vec<_> = plan.shortcuts.iter().map(|(a, _, _)| *a).collect();
+        assert_eq!(
+            actions,
+            [ShortcutAction::JoinNext, ShortcutAction::CancelJoin]
+        );
+        assert!(plan.rejected.is_empty());
+    }
+}
@@ -0,0 +1,362 @@
+//! Soak-test instrumentation
+//!
+//! A shadow copy of the join engine runs on a virtual clock that moves
+//! `clock_factor` times faster than real time. Synthetic meetings are
+//! injected on a fixed schedule, and every join the engine decides on is
+//! checked against scheduler invariants: each meeting is joined once, on
+//! time, and none leaves its join window unjoined. The real daemon, windows
+//! and settings are never touched, so a run can be left going overnight.
+
+use crate::daemon::{DaemonState, Meeting};
+use crate::filters::TitleFilters;
+use crate::fixtures;
+use crate::lifecycle::MeetingPhase;
+use crate::settings::{Settings, SoakTestSettings};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// Largest virtual step the engine is evaluated at; also the lateness a
+/// trigger is allowed
+const SIM_STEP_MS: i64 = 15_000;
+/// How long injected meetings stay in the schedule after they end
+const RETAIN_ENDED_MINUTES: i64 = 60;
+/// Guard against an engine that keeps asking for the same join
+const MAX_ACTIONS_PER_STEP: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ViolationKind {
+    /// The engine joined a meeting it had already joined
+    DoubleJoin,
+    /// The join fired after the meeting's trigger time
+    LateTrigger,
+    /// The join window closed without the meeting being joined
+    MissedTrigger,
+    /// The lifecycle refused a phase change the app would make
+    IllegalTransition,
+}
+
+/// A broken scheduler invariant, at virtual time `at_ms`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Violation {
+    pub kind: ViolationKind,
+    pub call_id: String,
+    pub at_ms: i64,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SoakStats {
+    pub injected: u64,
+    pub joined: u64,
+    pub violations: u64,
+    pub virtual_elapsed_ms: i64,
+}
+
+/// The meeting the simulated user is sitting in
+struct ActiveMeeting {
+    call_id: String,
+    trigger_at_ms: i64,
+    ends_at: DateTime<Utc>,
+}
+
+pub struct SoakRun {
+    config: SoakTestSettings,
+    settings: Settings,
+    title_filters: TitleFilters,
+    daemon: DaemonState,
+    real_origin: DateTime<Utc>,
+    virtual_origin: DateTime<Utc>,
+    virtual_now: DateTime<Utc>,
+    next_inject_at: DateTime<Utc>,
+    sequence: usize,
+    meetings: Vec<Meeting>,
+    /// Virtual time each meeting was joined at
+    joins: HashMap<String, i64>,
+    /// Meetings already reported as missed
+    missed: HashSet<String>,
+    active: Option<ActiveMeeting>,
+    stats: SoakStats,
+}
+
+impl SoakRun {
+    /// Start a run whose virtual clock matches `started_at`
+    pub fn new(config: SoakTestSettings, started_at: DateTime<Utc>) -> Self {
+        let mut daemon = DaemonState::default();
+        daemon.start();
+        let settings = Settings::default();
+        Self {
+            config,
+            title_filters: TitleFilters::from_settings(&settings),
+            settings,
+            daemon,
+            real_origin: started_at,
+            virtual_origin: started_at,
+            virtual_now: started_at,
+            next_inject_at: started_at,
+            sequence: 0,
+            meetings: Vec::new(),
+            joins: HashMap::new(),
+            missed: HashSet::new(),
+            active: None,
+            stats: SoakStats::default(),
+        }
+    }
+
+    pub fn config(&self) -> &SoakTestSettings {
+        &self.config
+    }
+
+    pub fn stats(&self) -> &SoakStats {
+        &self.stats
+    }
+
+    /// Virtual time corresponding to the real instant `real_now`
+    pub fn virtual_time_at(&self, real_now: DateTime<Utc>) -> DateTime<Utc> {
+        let elapsed_ms = (real_now - self.real_origin).num_milliseconds().max(0);
+        self.virtual_origin
+            + Duration::milliseconds(elapsed_ms.saturating_mul(self.config.clock_factor as i64))
+    }
+
+    /// Run the engine up to the virtual time of `real_now`, returning the
+    /// violations found on the way
+    pub fn advance_to(&mut self, real_now: DateTime<Utc>) -> Vec<Violation> {
+        let target = self.virtual_time_at(real_now);
+        self.run_until(target)
+    }
+
+    fn run_until(&mut self, target: DateTime<Utc>) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        while self.virtual_now < target {
+            let step_end = (self.virtual_now + Duration::milliseconds(SIM_STEP_MS)).min(target);
+            self.step(step_end, &mut violations);
+        }
+        self.stats.violations += violations.len() as u64;
+        self.stats.virtual_elapsed_ms = (self.virtual_now - self.virtual_origin).num_milliseconds();
+        violations
+    }
+
+    /// Play everything the app would do between `virtual_now` and `until`
+    fn step(&mut self, until: DateTime<Utc>, violations: &mut Vec<Violation>) {
+        self.inject_due(until);
+
+        for _ in 0..MAX_ACTIONS_PER_STEP {
+            if let Some(active) = &self.active {
+                if active.ends_at > until {
+                    break;
+                }
+                let left_at = active.ends_at.max(self.virtual_now);
+                self.leave(left_at, violations);
+                self.virtual_now = left_at;
+                continue;
+            }
+
+            let now = self.virtual_now;
+            let Some(trigger) =
+                self.daemon
+                    .calculate_next_trigger_at(&self.settings, &self.title_filters, now)
+            else {
+                break;
+            };
+            let fire_at = now + Duration::milliseconds(trigger.delay_ms as i64);
+            if fire_at > until {
+                break;
+            }
+            self.fire(&trigger.meeting, fire_at, violations);
+            self.virtual_now = fire_at;
+        }
+
+        self.check_missed(until, violations);
+        self.virtual_now = until;
+    }
+
+    /// Add the synthetic meetings scheduled up to `until`. Each one is known
+    /// an interval ahead of its start, like a real calendar entry.
+    fn inject_due(&mut self, until: DateTime<Utc>) {
+        let interval = self.config.meeting_interval_minutes as i64;
+        let mut changed = false;
+        while self.next_inject_at <= until {
+            let call_id = fixtures::synthetic_call_id(self.sequence);
+            let title = format!("Soak Test #{}", self.sequence);
+            self.meetings.push(fixtures::meeting_at(
+                &call_id,
+                &title,
+                self.next_inject_at,
+                interval,
+                (interval / 2).max(1),
+            ));
+            self.sequence += 1;
+            self.stats.injected += 1;
+            self.next_inject_at += Duration::minutes(interval);
+            changed = true;
+        }
+        if !changed {
+            return;
+        }
+
+        let retain_after = until - Duration::minutes(RETAIN_ENDED_MINUTES);
+        self.meetings.retain(|m| m.end_time > retain_after);
+        let ids: HashSet<&str> = self.meetings.iter().map(|m| m.call_id.as_str()).collect();
+        self.joins.retain(|id, _| ids.contains(id.as_str()));
+        self.missed.retain(|id| ids.contains(id.as_str()));
+        self.daemon.update_meetings(self.meetings.clone());
+    }
+
+    /// Join `meeting` at `at`, the way the trigger task does
+    fn fire(&mut self, meeting: &Meeting, at: DateTime<Utc>, violations: &mut Vec<Violation>) {
+        let at_ms = at.timestamp_millis();
+        let trigger_at_ms = self.trigger_at_ms(meeting);
+
+        if let Some(joined_at_ms) = self.joins.get(&meeting.call_id) {
+            violations.push(Violation {
+                kind: ViolationKind::DoubleJoin,
+                call_id: meeting.call_id.clone(),
+                at_ms,
+                detail: format!("already joined at {}", joined_at_ms),
+            });
+        }
+        let late_ms = at_ms - trigger_at_ms;
+        if late_ms > SIM_STEP_MS {
+            violations.push(Violation {
+                kind: ViolationKind::LateTrigger,
+                call_id: meeting.call_id.clone(),
+                at_ms,
+                detail: format!("fired {}ms after its trigger time", late_ms),
+            });
+        }
+
+        for phase in [MeetingPhase::Triggered, MeetingPhase::Joined] {
+            if let Err(e) = self.daemon.transition(&meeting.call_id, phase, at_ms) {
+                violations.push(Violation {
+                    kind: ViolationKind::IllegalTransition,
+                    call_id: meeting.call_id.clone(),
+                    at_ms,
+                    detail: e.to_string(),
+                });
+            }
+        }
+
+        self.joins.insert(meeting.call_id.clone(), at_ms);
+        self.stats.joined += 1;
+        self.active = Some(ActiveMeeting {
+            call_id: meeting.call_id.clone(),
+            trigger_at_ms,
+            ends_at: meeting.end_time,
+        });
+    }
+
+    /// Close the active meeting page at `at`
+    fn leave(&mut self, at: DateTime<Utc>, violations: &mut Vec<Violation>) {
+        let Some(active) = self.active.take() else {
+            return;
+        };
+        let at_ms = at.timestamp_millis();
+        if let Err(e) = self
+            .daemon
+            .mark_closed(&active.call_id, at_ms, active.trigger_at_ms)
+        {
+            violations.push(Violation {
+                kind: ViolationKind::IllegalTransition,
+                call_id: active.call_id,
+                at_ms,
+                detail: e.to_string(),
+            });
+        }
+    }
+
+    /// Report meetings whose join window closed before `now` without a join
+    fn check_missed(&mut self, now: DateTime<Utc>, violations: &mut Vec<Violation>) {
+        let max_after_start = Duration::minutes(self.settings.max_minutes_after_start as i64);
+        for meeting in &self.meetings {
+            let window_end = meeting.begin_time + max_after_start;
+            if window_end > now
+                || self.joins.contains_key(&meeting.call_id)
+                || !self.missed.insert(meeting.call_id.clone())
+            {
+                continue;
+            }
+            violations.push(Violation {
+                kind: ViolationKind::MissedTrigger,
+                call_id: meeting.call_id.clone(),
+                at_ms: now.timestamp_millis(),
+                detail: format!("join window closed at {}", window_end.to_rfc3339()),
+            });
+        }
+    }
+
+    fn trigger_at_ms(&self, meeting: &Meeting) -> i64 {
+        meeting.begin_time.timestamp_millis()
+            - (self.settings.join_before_minutes as i64) * 60 * 1000
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(clock_factor: u32) -> SoakTestSettings {
+        SoakTestSettings {
+            clock_factor,
+            meeting_interval_minutes: 15,
+        }
+    }
+
+    #[test]
+    fn test_clean_run_joins_every_meeting_once() {
+        let start = Utc::now();
+        let mut run = SoakRun::new(config(3600), start);
+
+        // 24 virtual hours, advanced in uneven real-time ticks
+        let mut violations = Vec::new();
+        for tick_ms in (0..24_000).step_by(700).chain([24_000]) {
+            violations.extend(run.advance_to(start + Duration::milliseconds(tick_ms)));
+        }
+        assert!(violations.is_empty(), "{:?}", violations);
+
+        let stats = run.stats();
+        assert_eq!(stats.injected, 24 * 4 + 1);
+        // Meetings starting within the last interval haven't reached their trigger
+        assert_eq!(stats.joined, 24 * 4);
+        assert_eq!(stats.violations, 0);
+    }
+
+    #[test]
+    fn test_paused_engine_reports_missed_triggers() {
+        let start = Utc::now();
+        let mut run = SoakRun::new(config(60), start);
+        run.daemon.pause_until(start + Duration::hours(2));
+
+        // One virtual hour: meetings at +15, +30 and +45 minutes are skipped
+        let violations = run.advance_to(start + Duration::seconds(60));
+        let missed: Vec<_> = violations
+            .iter()
+            .filter(|v| v.kind == ViolationKind::MissedTrigger)
+            .collect();
+        assert_eq!(missed.len(), 3);
+        assert_eq!(violations.len(), 3);
+
+        // Each meeting is reported once
+        assert!(run.advance_to(start + Duration::seconds(61)).is_empty());
+    }
+
+    #[test]
+    fn test_repeated_join_is_a_violation() {
+        let start = Utc::now();
+        let mut run = SoakRun::new(config(60), start);
+        run.advance_to(start + Duration::seconds(1));
+        let meeting = run.meetings[0].clone();
+        let trigger_at = start + Duration::minutes(14);
+
+        let mut violations = Vec::new();
+        run.fire(&meeting, trigger_at, &mut violations);
+        assert!(violations.is_empty(), "{:?}", violations);
+
+        run.fire(&meeting, trigger_at + Duration::minutes(1), &mut violations);
+        let kinds: Vec<_> = violations.iter().map(|v| v.kind).collect();
+        assert!(kinds.contains(&ViolationKind::DoubleJoin));
+        assert!(kinds.contains(&ViolationKind::LateTrigger));
+    }
+}
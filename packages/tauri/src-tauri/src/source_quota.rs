@@ -0,0 +1,379 @@
+//! Request budgets for meeting sources that poll a remote service
+//!
+//! Remote ICS feeds, and the Calendar API once it reports meetings, go through
+//! a [`RequestScheduler`] so that many subscriptions can't exhaust a
+//! provider's quota:
+//! - a token bucket per source caps the sustained request rate while still
+//!   allowing a burst right after launch
+//! - 429 and 5xx responses back the whole source off exponentially with
+//!   jitter, honouring `Retry-After` when it asks for longer
+//! - refreshes are spread with [`jitter_ms`] so clients don't poll in lockstep
+//!
+//! Request, deferral and backoff counts are kept per source for diagnostics.
+
+use crate::settings::MeetingSource;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// First backoff after a throttled or failed request
+const BACKOFF_BASE_MS: u64 = 30_000;
+/// Longest backoff, however many requests failed in a row
+const BACKOFF_MAX_MS: u64 = 30 * 60 * 1000;
+
+/// Request budget of one source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceQuota {
+    /// Requests that may be made back to back
+    pub burst: u32,
+    /// Time to earn back one request
+    pub refill_interval_ms: u64,
+    /// Upper bound of the random delay before a scheduled refresh
+    pub max_jitter_ms: u64,
+}
+
+impl SourceQuota {
+    /// Budget for `source`; `None` for sources that make no requests
+    pub fn for_source(source: &MeetingSource) -> Option<Self> {
+        match source {
+            MeetingSource::Webview => None,
+            MeetingSource::Ics => Some(SourceQuota {
+                burst: 10,
+                refill_interval_ms: 30_000,
+                max_jitter_ms: 15_000,
+            }),
+            // Well inside the Calendar API's per-user quota, even with many calendars
+            MeetingSource::Api => Some(SourceQuota {
+                burst: 5,
+                refill_interval_ms: 10_000,
+                max_jitter_ms: 30_000,
+            }),
+        }
+    }
+}
+
+/// How a request to a source ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestOutcome {
+    Success,
+    /// 429 Too Many Requests
+    Throttled {
+        retry_after_ms: Option<u64>,
+    },
+    /// 5xx
+    ServerError,
+    /// Other statuses and transport errors; retrying sooner wouldn't help
+    Failed,
+}
+
+impl RequestOutcome {
+    /// Classify an HTTP status. `Retry-After` is honoured in its seconds form.
+    pub fn from_status(status: u16, retry_after: Option<&str>) -> Self {
+        match status {
+            200..=399 => RequestOutcome::Success,
+            429 => RequestOutcome::Throttled {
+                retry_after_ms: retry_after
+                    .and_then(|value| value.trim().parse::<u64>().ok())
+                    .map(|seconds| seconds.saturating_mul(1000)),
+            },
+            500..=599 => RequestOutcome::ServerError,
+            _ => RequestOutcome::Failed,
+        }
+    }
+}
+
+/// Why a request may not be made yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "reason", rename_all = "camelCase")]
+pub enum Deferral {
+    /// The token bucket is empty
+    #[serde(rename_all = "camelCase")]
+    RateLimited { retry_at_ms: u64 },
+    /// The source was throttled or failing
+    #[serde(rename_all = "camelCase")]
+    BackingOff { until_ms: u64 },
+}
+
+/// Request counters of one source
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceRequestMetrics {
+    /// Requests allowed through
+    pub requests: u64,
+    pub succeeded: u64,
+    pub throttled: u64,
+    pub server_errors: u64,
+    pub failed: u64,
+    /// Requests refused by the bucket or an active backoff
+    pub deferred: u64,
+    /// Backoffs started
+    pub backoffs: u64,
+    pub consecutive_failures: u32,
+    pub backoff_until_ms: Option<u64>,
+}
+
+#[derive(Debug)]
+struct SourceState {
+    quota: SourceQuota,
+    tokens: f64,
+    refilled_at_ms: u64,
+    backoff_until_ms: u64,
+    metrics: SourceRequestMetrics,
+}
+
+impl SourceState {
+    fn new(quota: SourceQuota, now_ms: u64) -> Self {
+        Self {
+            quota,
+            tokens: f64::from(quota.burst),
+            refilled_at_ms: now_ms,
+            backoff_until_ms: 0,
+            metrics: SourceRequestMetrics::default(),
+        }
+    }
+
+    fn refill(&mut self, now_ms: u64) {
+        let elapsed = now_ms.saturating_sub(self.refilled_at_ms);
+        let earned = elapsed as f64 / self.quota.refill_interval_ms.max(1) as f64;
+        self.tokens = (self.tokens + earned).min(f64::from(self.quota.burst));
+        self.refilled_at_ms = self.refilled_at_ms.max(now_ms);
+    }
+}
+
+/// Token buckets and backoff state for every remote source
+#[derive(Debug, Default)]
+pub struct RequestScheduler {
+    sources: BTreeMap<MeetingSource, SourceState>,
+}
+
+impl RequestScheduler {
+    /// Take one request from `source`'s budget.
+    ///
+    /// Sources without a quota are always allowed and not counted.
+    pub fn acquire(&mut self, source: &MeetingSource, now_ms: u64) -> Result<(), Deferral> {
+        let Some(state) = self.state(source, now_ms) else {
+            return Ok(());
+        };
+        state.refill(now_ms);
+        if state.backoff_until_ms > now_ms {
+            state.metrics.deferred += 1;
+            return Err(Deferral::BackingOff {
+                until_ms: state.backoff_until_ms,
+            });
+        }
+        if state.tokens < 1.0 {
+            state.metrics.deferred += 1;
+            let missing = (1.0 - state.tokens) * state.quota.refill_interval_ms as f64;
+            return Err(Deferral::RateLimited {
+                retry_at_ms: now_ms + missing.ceil() as u64,
+            });
+        }
+        state.tokens -= 1.0;
+        state.metrics.requests += 1;
+        Ok(())
+    }
+
+    /// Record how an acquired request ended.
+    ///
+    /// Returns when the source may be asked again if this started a backoff.
+    /// `jitter` in `[0, 1)` spreads the backoff so clients don't retry together.
+    pub fn record(
+        &mut self,
+        source: &MeetingSource,
+        outcome: RequestOutcome,
+        now_ms: u64,
+        jitter: f64,
+    ) -> Option<u64> {
+        let state = self.state(source, now_ms)?;
+        let retry_after_ms = match outcome {
+            RequestOutcome::Success => {
+                state.metrics.succeeded += 1;
+                state.metrics.consecutive_failures = 0;
+                return None;
+            }
+            RequestOutcome::Failed => {
+                state.metrics.failed += 1;
+                return None;
+            }
+            RequestOutcome::Throttled { retry_after_ms } => {
+                state.metrics.throttled += 1;
+                // The provider says the budget is spent, whatever the bucket thinks
+                state.tokens = 0.0;
+                retry_after_ms
+            }
+            RequestOutcome::ServerError => {
+                state.metrics.server_errors += 1;
+                None
+            }
+        };
+        state.metrics.consecutive_failures += 1;
+        let delay = backoff_delay_ms(state.metrics.consecutive_failures, jitter)
+            .max(retry_after_ms.unwrap_or(0));
+        state.backoff_until_ms = state.backoff_until_ms.max(now_ms + delay);
+        state.metrics.backoffs += 1;
+        Some(state.backoff_until_ms)
+    }
+
+    /// Counters of every source that has made or been refused a request
+    pub fn metrics(&self, now_ms: u64) -> BTreeMap<MeetingSource, SourceRequestMetrics> {
+        self.sources
+            .iter()
+            .map(|(source, state)| {
+                let mut metrics = state.metrics.clone();
+                metrics.backoff_until_ms =
+                    (state.backoff_until_ms > now_ms).then_some(state.backoff_until_ms);
+                (source.clone(), metrics)
+            })
+            .collect()
+    }
+
+    fn state(&mut self, source: &MeetingSource, now_ms: u64) -> Option<&mut SourceState> {
+        let quota = SourceQuota::for_source(source)?;
+        Some(
+            self.sources
+                .entry(source.clone())
+                .or_insert_with(|| SourceState::new(quota, now_ms)),
+        )
+    }
+}
+
+/// Exponential backoff with equal jitter: half the step is fixed, half random
+pub fn backoff_delay_ms(consecutive_failures: u32, jitter: f64) -> u64 {
+    let exponent = consecutive_failures.saturating_sub(1).min(16);
+    let step = BACKOFF_BASE_MS
+        .saturating_mul(1 << exponent)
+        .min(BACKOFF_MAX_MS);
+    step / 2 + ((step / 2) as f64 * jitter.clamp(0.0, 1.0)) as u64
+}
+
+/// Random delay before a scheduled refresh of `source`
+pub fn jitter_ms(source: &MeetingSource, jitter: f64) -> u64 {
+    SourceQuota::for_source(source)
+        .map(|quota| (quota.max_jitter_ms as f64 * jitter.clamp(0.0, 1.0)) as u64)
+        .unwrap_or(0)
+}
+
+/// A random value in `[0, 1)` for [`jitter_ms`] and [`RequestScheduler::record`]
+pub fn random_jitter() -> f64 {
+    (uuid::Uuid::new_v4().as_u128() % 1_000_000) as f64 / 1_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_bucket_limits_and_refills() {
+        let mut scheduler = RequestScheduler::default();
+        for _ in 0..10 {
+            assert!(scheduler.acquire(&MeetingSource::Ics, 0).is_ok());
+        }
+        assert_eq!(
+            scheduler.acquire(&MeetingSource::Ics, 0),
+            Err(Deferral::RateLimited {
+                retry_at_ms: 30_000
+            })
+        );
+        assert_eq!(
+            scheduler.acquire(&MeetingSource::Ics, 15_000),
+            Err(Deferral::RateLimited {
+                retry_at_ms: 30_000
+            })
+        );
+        assert!(scheduler.acquire(&MeetingSource::Ics, 30_000).is_ok());
+
+        let metrics = &scheduler.metrics(30_000)[&MeetingSource::Ics];
+        assert_eq!(metrics.requests, 11);
+        assert_eq!(metrics.deferred, 2);
+    }
+
+    #[test]
+    fn test_webview_is_not_limited() {
+        let mut scheduler = RequestScheduler::default();
+        for _ in 0..100 {
+            assert!(scheduler.acquire(&MeetingSource::Webview, 0).is_ok());
+        }
+        assert!(scheduler.metrics(0).is_empty());
+    }
+
+    #[test]
+    fn test_backoff_grows_and_resets() {
+        let mut scheduler = RequestScheduler::default();
+        let source = MeetingSource::Api;
+
+        scheduler.acquire(&source, 0).unwrap();
+        assert_eq!(
+            scheduler.record(&source, RequestOutcome::ServerError, 0, 0.0),
+            Some(15_000)
+        );
+        assert_eq!(
+            scheduler.acquire(&source, 10_000),
+            Err(Deferral::BackingOff { until_ms: 15_000 })
+        );
+
+        scheduler.acquire(&source, 15_000).unwrap();
+        assert_eq!(
+            scheduler.record(&source, RequestOutcome::ServerError, 15_000, 0.0),
+            Some(45_000)
+        );
+
+        scheduler.acquire(&source, 45_000).unwrap();
+        assert_eq!(
+            scheduler.record(&source, RequestOutcome::Success, 45_000, 0.0),
+            None
+        );
+        let metrics = &scheduler.metrics(45_000)[&source];
+        assert_eq!(metrics.consecutive_failures, 0);
+        assert_eq!(metrics.backoffs, 2);
+        assert_eq!(metrics.server_errors, 2);
+        assert_eq!(metrics.backoff_until_ms, None);
+    }
+
+    #[test]
+    fn test_throttle_honours_retry_after_and_drains_bucket() {
+        let mut scheduler = RequestScheduler::default();
+        let source = MeetingSource::Ics;
+        scheduler.acquire(&source, 0).unwrap();
+
+        let outcome = RequestOutcome::from_status(429, Some("120"));
+        assert_eq!(scheduler.record(&source, outcome, 0, 0.5), Some(120_000));
+        assert!(scheduler.acquire(&source, 60_000).is_err());
+        // The bucket was drained, so only what refilled during the backoff is left
+        for _ in 0..4 {
+            assert!(scheduler.acquire(&source, 120_000).is_ok());
+        }
+        assert!(scheduler.acquire(&source, 120_000).is_err());
+        assert_eq!(scheduler.metrics(0)[&source].throttled, 1);
+    }
+
+    #[test]
+    fn test_backoff_delay_is_capped_and_jittered() {
+        assert_eq!(backoff_delay_ms(1, 0.0), 15_000);
+        assert_eq!(backoff_delay_ms(1, 1.0), 30_000);
+        assert_eq!(backoff_delay_ms(3, 0.5), 90_000);
+        assert_eq!(backoff_delay_ms(40, 1.0), BACKOFF_MAX_MS);
+        assert_eq!(jitter_ms(&MeetingSource::Ics, 0.5), 7_500);
+        assert_eq!(jitter_ms(&MeetingSource::Webview, 0.5), 0);
+    }
+
+    #[test]
+    fn test_outcome_from_status() {
+        assert_eq!(
+            RequestOutcome::from_status(200, None),
+            RequestOutcome::Success
+        );
+        assert_eq!(
+            RequestOutcome::from_status(429, Some("Wed, 21 Oct 2026 07:28:00 GMT")),
+            RequestOutcome::Throttled {
+                retry_after_ms: None
+            }
+        );
+        assert_eq!(
+            RequestOutcome::from_status(503, None),
+            RequestOutcome::ServerError
+        );
+        assert_eq!(
+            RequestOutcome::from_status(404, None),
+            RequestOutcome::Failed
+        );
+    }
+}
@@ -1,24 +1,70 @@
 //! System tray functionality
 
-use crate::daemon::Meeting;
+use crate::daemon::{outside_auto_join_hours, Meeting};
+use crate::format;
 use crate::i18n::{self, keys, Language};
-use crate::settings::{LogLevel, TauriSettings, TrayDisplayMode};
+use crate::ipc::{event, CopyMeetingLinkCommand, LeaveMeetingCommand};
+use crate::lifecycle::MeetingPhase;
+use crate::locks::LockExt;
+use crate::merge::FieldOrigins;
+use crate::profiles;
+use crate::settings::{LogLevel, MeetingSource, Profile, Settings, TauriSettings, TrayDisplayMode};
+use crate::window_activity::ShowCause;
 use crate::{
-    ensure_settings_window, navigate_to_meet_home, request_manual_update_check,
-    request_open_update_dialog, AppState,
+    cancel_next_join, ensure_settings_window, join_meeting_now, navigate_to_meet_home,
+    navigate_to_startup_page, record_window_shown, refresh_tray_status,
+    request_join_by_code_dialog, request_manual_update_check, request_open_update_dialog,
+    resume_auto_join, snooze_next_join, toggle_auto_join_pause, AppState,
 };
+use chrono::{DateTime, Utc};
 use serde_json::json;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Mutex;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use tauri::{
-    menu::{MenuBuilder, MenuItem, PredefinedMenuItem},
+    menu::{Menu, MenuBuilder, MenuItem, PredefinedMenuItem, Submenu},
     tray::TrayIconBuilder,
-    App, AppHandle, Manager,
+    App, AppHandle, Emitter, Manager,
 };
 
 /// Tray icon ID
 const TRAY_ID: &str = "meetcat-tray";
 
+/// Maximum number of meetings listed in the tray tooltip
+const TOOLTIP_MAX_MEETINGS: usize = 3;
+
+/// The countdown title shows seconds when the meeting starts within this
+/// many seconds
+const TRAY_SECONDS_COUNTDOWN: i64 = 120;
+
+/// The tray icon turns to its "imminent" variant when the next meeting
+/// starts within this many minutes
+const TRAY_IMMINENT_MINUTES: i64 = 5;
+
+/// Shown before the tray title while the network is offline
+const TRAY_OFFLINE_BADGE: &str = "⚠︎";
+
+/// Maximum number of upcoming meetings listed in the tray menu
+const MENU_MAX_MEETINGS: usize = 5;
+
+/// Menu ID prefixes of an upcoming meeting's "Join now" item and its entry in
+/// the "Skip" submenu, followed by the meeting's slot
+const JOIN_MEETING_ID_PREFIX: &str = "join-meeting:";
+const SKIP_MEETING_ID_PREFIX: &str = "skip-meeting:";
+
+/// Choices in the "Snooze Next Join" submenu, in minutes
+const SNOOZE_MINUTES: [u32; 3] = [5, 10, 15];
+
+/// Menu ID prefix of the snooze choices, followed by the minutes
+const SNOOZE_ID_PREFIX: &str = "snooze-";
+
+/// Menu ID of the main window in the account switcher
+const MAIN_ACCOUNT_ID: &str = "account-main";
+
+/// Menu ID prefix of the profiles in the account switcher, followed by the
+/// profile id
+const PROFILE_ID_PREFIX: &str = "account:";
+
 /// Persistent menu items stored in Tauri managed state.
 ///
 /// On macOS, NSMenuItem retains a reference to Rust-side data via muda's callback
@@ -28,18 +74,123 @@ const TRAY_ID: &str = "meetcat-tray";
 /// app's lifetime, we guarantee the backing data remains valid.
 struct TrayMenuItems {
     status: MenuItem<tauri::Wry>,
+    /// "Join now" items of the upcoming meetings, one per slot
+    join_meetings: Vec<MenuItem<tauri::Wry>>,
+    /// Lists the upcoming meetings again so a join can be skipped
+    skip: Submenu<tauri::Wry>,
+    skip_meetings: Vec<MenuItem<tauri::Wry>>,
+    /// Call IDs of the meetings in the slots, in order
+    meeting_slots: Mutex<Vec<String>>,
+    snooze: Submenu<tauri::Wry>,
+    snooze_choices: Vec<MenuItem<tauri::Wry>>,
+    cancel_next_join: MenuItem<tauri::Wry>,
+    /// Pauses auto-join for an hour, or resumes it while paused
+    pause: MenuItem<tauri::Wry>,
+    /// Listed only while in a meeting
+    leave_meeting: MenuItem<tauri::Wry>,
+    copy_meeting_link: MenuItem<tauri::Wry>,
+    /// Whether the tray follows the upcoming meetings or a meeting in progress
+    mode: Mutex<TrayMode>,
+    /// Icon variant and template flag last applied, so the per-second
+    /// refresh doesn't reload the image
+    icon: Mutex<Option<(TrayIconState, bool)>>,
     show: MenuItem<tauri::Wry>,
+    /// Account switcher, listed only while profiles are configured
+    accounts: Submenu<tauri::Wry>,
+    main_account: MenuItem<tauri::Wry>,
+    /// Items of every profile seen this run, kept even after the profile is
+    /// removed for the reason above
+    profile_items: Mutex<HashMap<String, MenuItem<tauri::Wry>>>,
     go_home: MenuItem<tauri::Wry>,
+    go_startup: MenuItem<tauri::Wry>,
+    join_by_code: MenuItem<tauri::Wry>,
     settings_item: MenuItem<tauri::Wry>,
     check_update: MenuItem<tauri::Wry>,
     install_update: MenuItem<tauri::Wry>,
     quit: MenuItem<tauri::Wry>,
-    /// Whether the install_update item is currently included in the menu
-    update_in_menu: AtomicBool,
+    /// Which optional parts the menu currently includes
+    layout: Mutex<MenuLayout>,
     /// Tracks the current language to avoid redundant set_text calls
     current_lang: Mutex<Language>,
 }
 
+/// Which optional parts the tray menu includes.
+///
+/// The menu is only rebuilt when this changes; everything else is updated
+/// in place through the persistent items.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct MenuLayout {
+    /// Upcoming meetings listed, at most `MENU_MAX_MEETINGS`
+    meetings: usize,
+    accounts: bool,
+    update: bool,
+    /// "Leave meeting" and "Copy meeting link" listed
+    in_meeting: bool,
+}
+
+/// What the tray follows.
+///
+/// Switched to `InMeeting` by `meeting_joined` and back by `meeting_closed`;
+/// while in a meeting the title shows the time elapsed in it instead of the
+/// countdown to the next one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TrayMode {
+    #[default]
+    Idle,
+    InMeeting {
+        call_id: String,
+        /// Label of the window the meeting is in
+        window: String,
+        url: String,
+        /// Empty when the meeting isn't in the list
+        title: String,
+        joined_at_ms: i64,
+    },
+}
+
+/// Tray icon variant reflecting the app state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayIconState {
+    Normal,
+    /// The next meeting starts within `TRAY_IMMINENT_MINUTES`
+    Imminent,
+    InMeeting,
+    /// Auto-join is paused, capped or the daemon is stopped
+    Paused,
+    /// The network is offline, so joins wait
+    Offline,
+}
+
+impl TrayIconState {
+    /// PNG of this variant, in its monochrome form for `template`
+    fn image_bytes(self, template: bool) -> &'static [u8] {
+        macro_rules! icon {
+            ($name:literal) => {
+                include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/icons/", $name)).as_slice()
+            };
+        }
+        match (self, template) {
+            (Self::Normal, false) => icon!("tray-icon.png"),
+            (Self::Normal, true) => icon!("tray-icon-template.png"),
+            (Self::Imminent, false) => icon!("tray-icon-imminent.png"),
+            (Self::Imminent, true) => icon!("tray-icon-imminent-template.png"),
+            (Self::InMeeting, false) => icon!("tray-icon-meeting.png"),
+            (Self::InMeeting, true) => icon!("tray-icon-meeting-template.png"),
+            // Joins are on hold either way; the title badge tells them apart
+            (Self::Paused | Self::Offline, false) => icon!("tray-icon-paused.png"),
+            (Self::Paused | Self::Offline, true) => icon!("tray-icon-paused-template.png"),
+        }
+    }
+}
+
+/// An upcoming meeting listed in the tray menu
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeetingMenuEntry {
+    pub call_id: String,
+    /// Time and title, like "10:30 AM Design Sync"
+    pub label: String,
+}
+
 /// Resolve the current Language from app state settings
 fn resolve_language(app: &AppHandle) -> Language {
     app.try_state::<AppState>()
@@ -58,9 +209,65 @@ pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
     let lang = Language::from_setting("auto");
 
     // Create all menu items once - they will be stored and reused forever
+    let snooze_choices = SNOOZE_MINUTES
+        .iter()
+        .map(|minutes| {
+            MenuItem::with_id(
+                app,
+                format!("{}{}", SNOOZE_ID_PREFIX, minutes),
+                i18n::tr_snooze_minutes(&lang, *minutes),
+                true,
+                None::<&str>,
+            )
+        })
+        .collect::<tauri::Result<Vec<_>>>()?;
+    let snooze = Submenu::with_id(app, "snooze", i18n::tr(&lang, keys::SNOOZE_NEXT_JOIN), false)?;
+    for choice in &snooze_choices {
+        snooze.append(choice)?;
+    }
+    let meeting_slots = |prefix: &str| {
+        (0..MENU_MAX_MEETINGS)
+            .map(|slot| {
+                MenuItem::with_id(app, format!("{}{}", prefix, slot), "", true, None::<&str>)
+            })
+            .collect::<tauri::Result<Vec<_>>>()
+    };
+    let join_meetings = meeting_slots(JOIN_MEETING_ID_PREFIX)?;
+    let skip_meetings = meeting_slots(SKIP_MEETING_ID_PREFIX)?;
+    let skip = Submenu::with_id(app, "skip-meeting", i18n::tr(&lang, keys::SKIP_MEETING), true)?;
+    let main_account = MenuItem::with_id(app, MAIN_ACCOUNT_ID, i18n::tr(&lang, keys::MAIN_ACCOUNT), true, None::<&str>)?;
+    let accounts = Submenu::with_id(app, "accounts", i18n::tr(&lang, keys::ACCOUNTS), true)?;
+    accounts.append(&main_account)?;
     let items = TrayMenuItems {
         status: MenuItem::with_id(app, "status", i18n::tr(&lang, keys::NO_UPCOMING_MEETINGS), false, None::<&str>)?,
+        join_meetings,
+        skip,
+        skip_meetings,
+        meeting_slots: Mutex::new(Vec::new()),
+        snooze,
+        snooze_choices,
+        cancel_next_join: MenuItem::with_id(app, "cancel-next-join", i18n::tr(&lang, keys::CANCEL_NEXT_JOIN), false, None::<&str>)?,
+        pause: MenuItem::with_id(app, "pause-auto-join", i18n::tr(&lang, keys::PAUSE_FOR_HOUR), true, None::<&str>)?,
+        leave_meeting: MenuItem::with_id(
+            app,
+            "leave-meeting",
+            i18n::tr(&lang, keys::LEAVE_MEETING),
+            true,
+            None::<&str>,
+        )?,
+        copy_meeting_link: MenuItem::with_id(
+            app,
+            "copy-meeting-link",
+            i18n::tr(&lang, keys::COPY_MEETING_LINK),
+            true,
+            None::<&str>,
+        )?,
+        mode: Mutex::new(TrayMode::Idle),
+        icon: Mutex::new(None),
         show: MenuItem::with_id(app, "show", i18n::tr(&lang, keys::SHOW_WINDOW), true, None::<&str>)?,
+        accounts,
+        main_account,
+        profile_items: Mutex::new(HashMap::new()),
         go_home: MenuItem::with_id(
             app,
             "go-home",
@@ -68,6 +275,20 @@ pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
             true,
             None::<&str>,
         )?,
+        go_startup: MenuItem::with_id(
+            app,
+            "go-startup",
+            i18n::tr(&lang, keys::BACK_TO_STARTUP_PAGE),
+            true,
+            None::<&str>,
+        )?,
+        join_by_code: MenuItem::with_id(
+            app,
+            "join-by-code",
+            i18n::tr(&lang, keys::JOIN_BY_CODE),
+            true,
+            None::<&str>,
+        )?,
         settings_item: MenuItem::with_id(app, "settings", i18n::tr(&lang, keys::SETTINGS), true, None::<&str>)?,
         check_update: MenuItem::with_id(
             app,
@@ -78,45 +299,98 @@ pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
         )?,
         install_update: MenuItem::with_id(app, "install-update", "", false, None::<&str>)?,
         quit: MenuItem::with_id(app, "quit", i18n::tr(&lang, keys::QUIT_MEETCAT), true, None::<&str>)?,
-        update_in_menu: AtomicBool::new(false),
+        layout: Mutex::new(MenuLayout::default()),
         current_lang: Mutex::new(lang.clone()),
     };
 
     // If an update is already available at startup, prepare the install_update item
-    let has_update = available_update_version(app.handle());
-    if let Some(ref version) = has_update {
+    if let Some(ref version) = available_update_version(app.handle()) {
         let _ = items.install_update.set_text(&i18n::tr_update_available(&lang, version));
         let _ = items.install_update.set_enabled(true);
-        items.update_in_menu.store(true, Ordering::Relaxed);
+        items.layout.lock_or_recover().update = true;
     }
 
-    // Build initial menu
-    let sep1 = PredefinedMenuItem::separator(app)?;
-    let sep2 = PredefinedMenuItem::separator(app)?;
-    let mut menu_builder = MenuBuilder::new(app)
-        .item(&items.status)
-        .item(&sep1)
-        .item(&items.show)
-        .item(&items.go_home)
-        .item(&items.settings_item)
-        .item(&items.check_update);
-    if has_update.is_some() {
-        menu_builder = menu_builder.item(&items.install_update);
+    // Store items in Tauri managed state so they survive for the app's lifetime,
+    // including while the tray icon itself is hidden
+    app.manage(items);
+
+    let profiles = app
+        .try_state::<AppState>()
+        .and_then(|state| state.settings.lock().ok().and_then(|s| s.tauri.clone()))
+        .unwrap_or_default()
+        .profiles;
+    sync_profile_menu(app.handle(), &profiles);
+
+    if show_tray_icon_setting(app.handle()) {
+        build_tray_icon(app.handle())?;
     }
-    let menu = menu_builder
-        .item(&sep2)
-        .item(&items.quit)
-        .build()?;
 
-    let tray_icon_bytes = include_bytes!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/icons/tray-icon.png"
-    ));
-    let tray_icon = tauri::image::Image::from_bytes(tray_icon_bytes)?;
+    Ok(())
+}
+
+/// Show or remove the tray icon to match the `showTrayIcon` setting.
+///
+/// The icon is torn down completely when hidden and rebuilt from the
+/// persistent menu items when shown again, with the current status applied.
+pub fn set_tray_visible(app: &AppHandle, visible: bool) {
+    let present = app.tray_by_id(TRAY_ID).is_some();
+    if visible && !present {
+        match build_tray_icon(app) {
+            Ok(()) => log_tray_event(app, LogLevel::Info, "icon.shown", None),
+            Err(e) => {
+                eprintln!("Failed to create tray icon: {}", e);
+                log_tray_event(
+                    app,
+                    LogLevel::Error,
+                    "icon.show_failed",
+                    Some(json!({ "error": e.to_string() })),
+                );
+            }
+        }
+    } else if !visible && present {
+        if let Some(tray) = app.remove_tray_by_id(TRAY_ID) {
+            let _ = tray.set_visible(false);
+        }
+        log_tray_event(app, LogLevel::Info, "icon.hidden", None);
+    }
+}
+
+fn show_tray_icon_setting(app: &AppHandle) -> bool {
+    app.try_state::<AppState>()
+        .and_then(|state| state.settings.lock().ok().and_then(|s| s.tauri.clone()))
+        .unwrap_or_default()
+        .show_tray_icon
+}
+
+/// Create the tray icon from the persistent menu items.
+///
+/// Menu and icon event handlers are registered per tray ID, so rebuilding
+/// under the same ID replaces the previous handlers instead of stacking them.
+fn build_tray_icon(app: &AppHandle) -> tauri::Result<()> {
+    let Some(items) = app.try_state::<TrayMenuItems>() else {
+        return Ok(());
+    };
+    let layout = *items.layout.lock_or_recover();
+    let menu = build_menu_from_items(app, &items, layout)?;
+    let lang = items.current_lang.lock_or_recover().clone();
+
+    // Starts out normal; the status refresh below picks the state's variant
+    let template = app
+        .try_state::<AppState>()
+        .and_then(|state| {
+            state
+                .settings
+                .lock()
+                .ok()
+                .map(|s| s.tauri_or_default().tray_icon_template)
+        })
+        .unwrap_or(false);
+    let tray_icon = tauri::image::Image::from_bytes(TrayIconState::Normal.image_bytes(template))?;
+    *items.icon.lock_or_recover() = Some((TrayIconState::Normal, template));
 
     let _tray = TrayIconBuilder::with_id(TRAY_ID)
         .icon(tray_icon)
-        .icon_as_template(false)
+        .icon_as_template(template)
         .menu(&menu)
         .tooltip(i18n::tr(&lang, keys::TOOLTIP))
         .on_menu_event(|app, event| match event.id.as_ref() {
@@ -130,6 +404,7 @@ pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
                     ok = window.show().is_ok() && window.set_focus().is_ok();
                 }
                 if ok {
+                    record_window_shown(app, "main", ShowCause::Tray, None);
                     log_tray_event(app, LogLevel::Info, "menu.show", Some(json!({ "window": "main" })));
                 } else {
                     log_tray_event(
@@ -153,6 +428,32 @@ pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
                     log_tray_event(app, LogLevel::Info, "menu.go_home", None);
                 }
             }
+            "go-startup" => {
+                if let Err(e) = navigate_to_startup_page(app) {
+                    eprintln!("Failed to navigate to startup page: {}", e);
+                    log_tray_event(
+                        app,
+                        LogLevel::Error,
+                        "menu.go_startup_failed",
+                        Some(json!({ "error": e })),
+                    );
+                } else {
+                    log_tray_event(app, LogLevel::Info, "menu.go_startup", None);
+                }
+            }
+            "join-by-code" => {
+                if let Err(e) = request_join_by_code_dialog(app) {
+                    eprintln!("Failed to open join by code dialog: {}", e);
+                    log_tray_event(
+                        app,
+                        LogLevel::Error,
+                        "menu.join_by_code_failed",
+                        Some(json!({ "error": e })),
+                    );
+                } else {
+                    log_tray_event(app, LogLevel::Info, "menu.join_by_code", None);
+                }
+            }
             "settings" => {
                 if let Err(e) = open_settings(app) {
                     eprintln!("Failed to open settings: {}", e);
@@ -181,6 +482,11 @@ pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
                     log_tray_event(app, LogLevel::Info, "menu.check_update", None);
                 }
             }
+            "status" => {
+                // Only enabled while auto-join is stopped by the hourly cap
+                resume_auto_join(app);
+                log_tray_event(app, LogLevel::Info, "menu.resume_auto_join", None);
+            }
             "install-update" => {
                 if let Err(e) = open_settings(app) {
                     eprintln!("Failed to open settings: {}", e);
@@ -195,7 +501,155 @@ pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
                     log_tray_event(app, LogLevel::Info, "menu.install_update", None);
                 }
             }
-            _ => {}
+            "pause-auto-join" => {
+                let paused = toggle_auto_join_pause(app);
+                log_tray_event(app, LogLevel::Info, "menu.pause_auto_join", Some(json!({ "paused": paused })));
+            }
+            "leave-meeting" => {
+                let Some((call_id, window, _)) = current_meeting(app) else {
+                    return;
+                };
+                let command = LeaveMeetingCommand {
+                    window: window.clone(),
+                };
+                match app.emit(event::LEAVE_MEETING, command) {
+                    Ok(()) => log_tray_event(
+                        app,
+                        LogLevel::Info,
+                        "menu.leave_meeting",
+                        Some(json!({ "callId": call_id, "window": window })),
+                    ),
+                    Err(e) => log_tray_event(
+                        app,
+                        LogLevel::Warn,
+                        "menu.leave_meeting_failed",
+                        Some(json!({ "callId": call_id, "error": e.to_string() })),
+                    ),
+                }
+            }
+            "copy-meeting-link" => {
+                let Some((call_id, window, url)) = current_meeting(app) else {
+                    return;
+                };
+                // The page writes the clipboard; the app has no access of its own
+                match app.emit(event::COPY_MEETING_LINK, CopyMeetingLinkCommand { window, url }) {
+                    Ok(()) => log_tray_event(
+                        app,
+                        LogLevel::Info,
+                        "menu.copy_meeting_link",
+                        Some(json!({ "callId": call_id })),
+                    ),
+                    Err(e) => log_tray_event(
+                        app,
+                        LogLevel::Warn,
+                        "menu.copy_meeting_link_failed",
+                        Some(json!({ "callId": call_id, "error": e.to_string() })),
+                    ),
+                }
+            }
+            "cancel-next-join" => {
+                match cancel_next_join(app, None, "tray") {
+                    Ok(call_id) => log_tray_event(app, LogLevel::Info, "menu.cancel_next_join", Some(json!({ "callId": call_id }))),
+                    Err(e) => log_tray_event(app, LogLevel::Warn, "menu.cancel_next_join_failed", Some(json!({ "error": e }))),
+                }
+            }
+            id if id.starts_with(JOIN_MEETING_ID_PREFIX) => {
+                let Some(call_id) = meeting_in_slot(app, &id[JOIN_MEETING_ID_PREFIX.len()..])
+                else {
+                    return;
+                };
+                match join_meeting_now(app, &call_id, "tray") {
+                    Ok(()) => log_tray_event(
+                        app,
+                        LogLevel::Info,
+                        "menu.join_meeting",
+                        Some(json!({ "callId": call_id })),
+                    ),
+                    Err(e) => log_tray_event(
+                        app,
+                        LogLevel::Warn,
+                        "menu.join_meeting_failed",
+                        Some(json!({ "callId": call_id, "error": e })),
+                    ),
+                }
+            }
+            id if id.starts_with(SKIP_MEETING_ID_PREFIX) => {
+                let Some(call_id) = meeting_in_slot(app, &id[SKIP_MEETING_ID_PREFIX.len()..])
+                else {
+                    return;
+                };
+                match cancel_next_join(app, Some(call_id.clone()), "tray") {
+                    Ok(_) => log_tray_event(
+                        app,
+                        LogLevel::Info,
+                        "menu.skip_meeting",
+                        Some(json!({ "callId": call_id })),
+                    ),
+                    Err(e) => log_tray_event(
+                        app,
+                        LogLevel::Warn,
+                        "menu.skip_meeting_failed",
+                        Some(json!({ "callId": call_id, "error": e })),
+                    ),
+                }
+            }
+            MAIN_ACCOUNT_ID => {
+                if let Some(window) = app.get_webview_window(profiles::MAIN_WINDOW) {
+                    let _ = window.show();
+                    let _ = window.unminimize();
+                    let _ = window.set_focus();
+                    record_window_shown(app, profiles::MAIN_WINDOW, ShowCause::Tray, None);
+                }
+                log_tray_event(app, LogLevel::Info, "menu.account", Some(json!({ "window": "main" })));
+            }
+            id if id.starts_with(PROFILE_ID_PREFIX) => {
+                let profile_id = &id[PROFILE_ID_PREFIX.len()..];
+                let profile = app
+                    .try_state::<AppState>()
+                    .and_then(|state| state.settings.lock().ok().and_then(|s| s.tauri.clone()))
+                    .unwrap_or_default()
+                    .profiles
+                    .into_iter()
+                    .find(|p| p.id == profile_id);
+                let Some(profile) = profile else {
+                    return;
+                };
+                match profiles::open_window(app, &profile) {
+                    Ok(window) => {
+                        record_window_shown(app, window.label(), ShowCause::Tray, None);
+                        log_tray_event(
+                            app,
+                            LogLevel::Info,
+                            "menu.account",
+                            Some(json!({ "profile": profile.id })),
+                        );
+                    }
+                    Err(e) => log_tray_event(
+                        app,
+                        LogLevel::Error,
+                        "menu.account_failed",
+                        Some(json!({ "profile": profile.id, "error": e.to_string() })),
+                    ),
+                }
+            }
+            id => {
+                let Some(minutes) = id
+                    .strip_prefix(SNOOZE_ID_PREFIX)
+                    .and_then(|m| m.parse::<u32>().ok())
+                else {
+                    return;
+                };
+                if let Err(e) = snooze_next_join(app, minutes) {
+                    log_tray_event(
+                        app,
+                        LogLevel::Warn,
+                        "menu.snooze_failed",
+                        Some(json!({ "error": e, "minutes": minutes })),
+                    );
+                } else {
+                    log_tray_event(app, LogLevel::Info, "menu.snooze", Some(json!({ "minutes": minutes })));
+                }
+            }
         })
         .on_tray_icon_event(|tray, event| {
             if let tauri::tray::TrayIconEvent::Click {
@@ -207,6 +661,7 @@ pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
                 if let Some(window) = tray.app_handle().get_webview_window("main") {
                     let _ = window.show();
                     let _ = window.set_focus();
+                    record_window_shown(tray.app_handle(), "main", ShowCause::Tray, None);
                 }
                 log_tray_event(
                     tray.app_handle(),
@@ -218,57 +673,186 @@ pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
         })
         .build(app)?;
 
-    // Store items in Tauri managed state so they survive for the app's lifetime
-    app.manage(items);
+    refresh_tray_status(app);
 
     Ok(())
 }
 
 /// Open settings window
 fn open_settings(app: &AppHandle) -> Result<(), String> {
-    ensure_settings_window(app)
+    ensure_settings_window(app).map_err(String::from)
 }
 
 /// Update tray status with next meeting info.
 ///
 /// Uses `set_text()` on existing menu items instead of recreating them,
-/// preventing the use-after-free crash on macOS.
-pub fn update_tray_status(app: &AppHandle, meeting: Option<&Meeting>) {
+/// preventing the use-after-free crash on macOS. Runs on every tray tick, so
+/// the daemon is locked once and its meetings are only borrowed.
+pub fn update_tray_status(app: &AppHandle, settings: &Settings, meeting: Option<&Meeting>) {
     let Some(tray) = app.tray_by_id(TRAY_ID) else {
         return;
     };
 
     let lang = resolve_language(app);
-
-    // Update tooltip
-    let tooltip = match meeting {
-        Some(m) => {
-            let status = i18n::tr_time_status(&lang, m.starts_in_minutes);
-            i18n::tr_tooltip_with_meeting(&lang, &m.title, &status)
-        }
-        None => i18n::tr_tooltip_no_meetings(&lang),
-    };
+    let now = chrono::Utc::now();
+    let tray_settings = settings.tauri_or_default();
+    let offline = app
+        .try_state::<AppState>()
+        .is_some_and(|state| state.connectivity.lock_or_recover().is_offline());
+    let (rate_capped, paused, paused_key, can_snooze, tooltip, menu_entries, daemon_running) = app
+        .try_state::<AppState>()
+        .and_then(|state| {
+            let title_filters = Arc::clone(&state.title_filters.lock_or_recover());
+            let d = state.daemon.lock().ok()?;
+            let upcoming = d.upcoming_meetings_at(now);
+            let rate_capped = d.is_join_rate_capped();
+            let paused = d.paused_until(now).is_some();
+            let paused_key = if rate_capped {
+                Some(keys::JOIN_RATE_CAPPED)
+            } else if paused {
+                Some(keys::PAUSED)
+            } else if d.out_of_office_until(now).is_some() {
+                Some(keys::PAUSED_OUT_OF_OFFICE)
+            } else {
+                None
+            };
+            let tooltip = match paused_key {
+                Some(key) => format!("MeetCat - {}", i18n::tr(&lang, key)),
+                None => {
+                    let outside_hours: Vec<String> = upcoming
+                        .iter()
+                        .filter(|m| outside_auto_join_hours(m, settings))
+                        .map(|m| m.call_id.clone())
+                        .collect();
+                    build_tray_tooltip(
+                        &upcoming,
+                        &d.get_blocked_meetings(),
+                        &outside_hours,
+                        d.meeting_origins(),
+                        &tray_settings,
+                        &lang,
+                    )
+                }
+            };
+            // Screenshots taken in demo mode must not pass for the real schedule
+            let tooltip = if d.in_demo_mode() {
+                i18n::tr_demo_tooltip(&lang, &tooltip)
+            } else {
+                tooltip
+            };
+            let stale_ms = i64::from(tray_settings.stale_data_minutes) * 60_000;
+            let tooltip = if d.is_data_stale(now.timestamp_millis(), stale_ms) {
+                format!("{}\n{}", i18n::tr(&lang, keys::DATA_STALE), tooltip)
+            } else {
+                tooltip
+            };
+            let tooltip = if offline {
+                format!("{}\n{}", i18n::tr(&lang, keys::OFFLINE), tooltip)
+            } else {
+                tooltip
+            };
+            // Snoozing and cancelling need a scheduled join
+            let can_snooze = d
+                .calculate_next_trigger_at(settings, &title_filters, now)
+                .is_some();
+            // Meetings already joining or skipped are left out of the menu
+            let listed: Vec<&Meeting> = upcoming
+                .into_iter()
+                .filter(|m| {
+                    !d.is_join_cancelled(&m.call_id)
+                        && !matches!(
+                            d.meeting_phase(&m.call_id),
+                            Some(
+                                MeetingPhase::Triggered
+                                    | MeetingPhase::Navigating
+                                    | MeetingPhase::Joined
+                            )
+                        )
+                })
+                .collect();
+            let menu_entries = build_meeting_menu_entries(&listed, &tray_settings, &lang);
+            Some((
+                rate_capped,
+                paused,
+                paused_key,
+                can_snooze,
+                tooltip,
+                menu_entries,
+                d.is_running(),
+            ))
+        })
+        .unwrap_or_else(|| {
+            (
+                false,
+                false,
+                None,
+                false,
+                i18n::tr_tooltip_no_meetings(&lang),
+                Vec::new(),
+                true,
+            )
+        });
 
     let _ = tray.set_tooltip(Some(&tooltip));
 
-    // Update tray title based on settings
-    let tray_settings = app
-        .try_state::<AppState>()
-        .and_then(|state| state.settings.lock().ok().and_then(|s| s.tauri.clone()))
-        .unwrap_or_default();
-    let title = build_tray_title(meeting, &tray_settings, &lang);
-    let _ = tray.set_title(Some(&title));
-
     let Some(items) = app.try_state::<TrayMenuItems>() else {
         return;
     };
+    let mode = items.mode.lock_or_recover().clone();
+
+    // Update tray title based on settings
+    let title = match &mode {
+        TrayMode::Idle => build_tray_title(meeting, &tray_settings, &lang, now),
+        TrayMode::InMeeting { joined_at_ms, .. } => {
+            build_meeting_elapsed_title(*joined_at_ms, &tray_settings, now)
+        }
+    };
+    let title = if offline {
+        format!("{} {}", TRAY_OFFLINE_BADGE, title)
+            .trim_end()
+            .to_string()
+    } else {
+        title
+    };
+    let _ = tray.set_title(Some(&title));
+
+    let icon_state = tray_icon_state(
+        mode != TrayMode::Idle,
+        paused_key.is_some() || !daemon_running,
+        offline,
+        meeting,
+        now,
+    );
+    let template = tray_settings.tray_icon_template;
+    {
+        let mut applied = items.icon.lock_or_recover();
+        if *applied != Some((icon_state, template)) {
+            if let Ok(image) = tauri::image::Image::from_bytes(icon_state.image_bytes(template)) {
+                let _ = tray.set_icon(Some(image));
+                let _ = tray.set_icon_as_template(template);
+                *applied = Some((icon_state, template));
+            }
+        }
+    }
 
     // Update all item texts when language changes
     {
-        let mut current = items.current_lang.lock().unwrap();
+        let mut current = items.current_lang.lock_or_recover();
         if *current != lang {
+            let _ = items.skip.set_text(i18n::tr(&lang, keys::SKIP_MEETING));
+            let _ = items.snooze.set_text(i18n::tr(&lang, keys::SNOOZE_NEXT_JOIN));
+            let _ = items.cancel_next_join.set_text(i18n::tr(&lang, keys::CANCEL_NEXT_JOIN));
+            let _ = items.leave_meeting.set_text(i18n::tr(&lang, keys::LEAVE_MEETING));
+            let _ = items.copy_meeting_link.set_text(i18n::tr(&lang, keys::COPY_MEETING_LINK));
+            for (choice, minutes) in items.snooze_choices.iter().zip(SNOOZE_MINUTES) {
+                let _ = choice.set_text(i18n::tr_snooze_minutes(&lang, minutes));
+            }
             let _ = items.show.set_text(i18n::tr(&lang, keys::SHOW_WINDOW));
+            let _ = items.accounts.set_text(i18n::tr(&lang, keys::ACCOUNTS));
+            let _ = items.main_account.set_text(i18n::tr(&lang, keys::MAIN_ACCOUNT));
             let _ = items.go_home.set_text(i18n::tr(&lang, keys::BACK_TO_GOOGLE_MEET_HOME));
+            let _ = items.go_startup.set_text(i18n::tr(&lang, keys::BACK_TO_STARTUP_PAGE));
+            let _ = items.join_by_code.set_text(i18n::tr(&lang, keys::JOIN_BY_CODE));
             let _ = items.settings_item.set_text(i18n::tr(&lang, keys::SETTINGS));
             let _ = items.check_update.set_text(i18n::tr(&lang, keys::CHECK_FOR_UPDATES));
             let _ = items.quit.set_text(i18n::tr(&lang, keys::QUIT_MEETCAT));
@@ -277,39 +861,168 @@ pub fn update_tray_status(app: &AppHandle, meeting: Option<&Meeting>) {
     }
 
     // Update status text
-    let status_text = match meeting {
-        Some(m) => {
-            let time_str = i18n::tr_time_status(&lang, m.starts_in_minutes);
-            i18n::tr_next_meeting(&lang, &truncate_title(&m.title, 25), &time_str)
+    let status_text = match (&mode, paused_key, meeting) {
+        (TrayMode::InMeeting { title, .. }, _, _) => {
+            let title = if tray_settings.privacy_mode {
+                ""
+            } else {
+                title.trim()
+            };
+            i18n::tr_in_meeting(&lang, &truncate_title(title, 25))
         }
-        None => i18n::tr(&lang, keys::NO_UPCOMING_MEETINGS).to_string(),
+        (_, Some(key), _) => i18n::tr(&lang, key).to_string(),
+        (_, None, _) if offline => i18n::tr(&lang, keys::OFFLINE).to_string(),
+        (_, None, Some(m)) => {
+            let time_str = format::relative_time(&lang, m.starts_in_minutes);
+            let subject = format::meeting_subject(
+                &lang,
+                &m.title,
+                &m.display_time,
+                tray_settings.privacy_mode,
+            );
+            // Screen readers stumble over a cut-off title; give them the whole one
+            let subject = if tray_settings.accessibility_announcements { subject } else { truncate_title(&subject, 25) };
+            i18n::tr_next_meeting(&lang, &subject, &time_str)
+        }
+        (_, None, None) => i18n::tr(&lang, keys::NO_UPCOMING_MEETINGS).to_string(),
     };
     let _ = items.status.set_text(&status_text);
+    let _ = items.status.set_enabled(rate_capped);
+    let _ = items.snooze.set_enabled(can_snooze);
+    let _ = items.cancel_next_join.set_enabled(can_snooze);
+    let pause_key = if paused {
+        keys::RESUME_AUTO_JOIN
+    } else {
+        keys::PAUSE_FOR_HOUR
+    };
+    let _ = items.pause.set_text(i18n::tr(&lang, pause_key));
 
-    // Sync update item: rebuild menu only when update availability changes
-    let has_update = available_update_version(app);
-    let was_in_menu = items.update_in_menu.load(Ordering::Relaxed);
+    sync_meeting_menu(&items, &menu_entries, &lang);
 
-    match (&has_update, was_in_menu) {
-        (Some(version), false) => {
-            // Update became available: enable item and rebuild menu to include it
+    // Sync update item: shown only while an update is available
+    let has_update = available_update_version(app);
+    match &has_update {
+        Some(version) => {
             let _ = items.install_update.set_text(&i18n::tr_update_available(&lang, version));
             let _ = items.install_update.set_enabled(true);
-            items.update_in_menu.store(true, Ordering::Relaxed);
-            rebuild_menu_from_items(app, &items, true);
         }
-        (None, true) => {
-            // Update no longer available: rebuild menu to exclude it
+        None => {
             let _ = items.install_update.set_enabled(false);
-            items.update_in_menu.store(false, Ordering::Relaxed);
-            rebuild_menu_from_items(app, &items, false);
         }
-        (Some(version), true) => {
-            // Update still available, refresh text (language may have changed)
-            let _ = items.install_update.set_text(&i18n::tr_update_available(&lang, version));
+    }
+
+    // Rebuild the menu only when its layout changed
+    update_layout(app, &items, |layout| {
+        layout.meetings = menu_entries.len();
+        layout.update = has_update.is_some();
+        layout.in_meeting = mode != TrayMode::Idle;
+    });
+}
+
+/// Switch the tray to follow the meeting `call_id` joined in `window`
+pub fn enter_meeting_mode(
+    app: &AppHandle,
+    call_id: &str,
+    window: &str,
+    url: &str,
+    title: &str,
+    joined_at_ms: i64,
+) {
+    let Some(items) = app.try_state::<TrayMenuItems>() else {
+        return;
+    };
+    *items.mode.lock_or_recover() = TrayMode::InMeeting {
+        call_id: call_id.to_string(),
+        window: window.to_string(),
+        url: url.to_string(),
+        title: title.to_string(),
+        joined_at_ms,
+    };
+    log_tray_event(
+        app,
+        LogLevel::Debug,
+        "mode.in_meeting",
+        Some(json!({ "callId": call_id, "window": window })),
+    );
+}
+
+/// Switch the tray back to the upcoming meetings once `call_id` closed.
+///
+/// Leaves the tray alone when it follows another meeting.
+pub fn exit_meeting_mode(app: &AppHandle, call_id: &str) {
+    let Some(items) = app.try_state::<TrayMenuItems>() else {
+        return;
+    };
+    let mut mode = items.mode.lock_or_recover();
+    if !matches!(&*mode, TrayMode::InMeeting { call_id: current, .. } if current == call_id) {
+        return;
+    }
+    *mode = TrayMode::Idle;
+    drop(mode);
+    log_tray_event(
+        app,
+        LogLevel::Debug,
+        "mode.idle",
+        Some(json!({ "callId": call_id })),
+    );
+}
+
+/// Call ID, window and link of the meeting the tray follows
+fn current_meeting(app: &AppHandle) -> Option<(String, String, String)> {
+    let items = app.try_state::<TrayMenuItems>()?;
+    let mode = items.mode.lock_or_recover();
+    match &*mode {
+        TrayMode::InMeeting {
+            call_id,
+            window,
+            url,
+            ..
+        } => Some((call_id.clone(), window.clone(), url.clone())),
+        TrayMode::Idle => None,
+    }
+}
+
+/// Show `entries` in the upcoming meeting slots and the "Skip" submenu
+fn sync_meeting_menu(items: &TrayMenuItems, entries: &[MeetingMenuEntry], lang: &Language) {
+    let mut slots = items.meeting_slots.lock_or_recover();
+    for ((join, skip), entry) in items
+        .join_meetings
+        .iter()
+        .zip(&items.skip_meetings)
+        .zip(entries)
+    {
+        let _ = join.set_text(i18n::tr_join_meeting_now(lang, &entry.label));
+        let _ = skip.set_text(&entry.label);
+    }
+    if slots.len() != entries.len() {
+        while let Ok(Some(_)) = items.skip.remove_at(0) {}
+        for skip in items.skip_meetings.iter().take(entries.len()) {
+            let _ = items.skip.append(skip);
         }
-        _ => {}
     }
+    *slots = entries.iter().map(|entry| entry.call_id.clone()).collect();
+}
+
+/// Call ID of the meeting listed in `slot` of the menu
+fn meeting_in_slot(app: &AppHandle, slot: &str) -> Option<String> {
+    let slot = slot.parse::<usize>().ok()?;
+    let items = app.try_state::<TrayMenuItems>()?;
+    let slots = items.meeting_slots.lock_or_recover();
+    slots.get(slot).cloned()
+}
+
+/// Change the menu layout, rebuilding the menu when it differs
+fn update_layout(app: &AppHandle, items: &TrayMenuItems, change: impl FnOnce(&mut MenuLayout)) {
+    let layout = {
+        let mut layout = items.layout.lock_or_recover();
+        let before = *layout;
+        change(&mut layout);
+        if *layout == before {
+            return;
+        }
+        *layout
+    };
+    rebuild_menu_from_items(app, items, layout);
 }
 
 /// Rebuild the tray menu using the stored (persistent) items.
@@ -317,33 +1030,98 @@ pub fn update_tray_status(app: &AppHandle, meeting: Option<&Meeting>) {
 /// This creates a new `Menu` structure but reuses the existing `MenuItem` objects.
 /// Since items are Arc-based, both the new menu and `TrayMenuItems` hold references,
 /// so items survive even after the old menu is dropped.
-fn rebuild_menu_from_items(app: &AppHandle, items: &TrayMenuItems, include_update: bool) {
+fn rebuild_menu_from_items(app: &AppHandle, items: &TrayMenuItems, layout: MenuLayout) {
     let Some(tray) = app.tray_by_id(TRAY_ID) else {
         return;
     };
 
-    let Ok(sep1) = PredefinedMenuItem::separator(app) else {
-        return;
-    };
-    let Ok(sep2) = PredefinedMenuItem::separator(app) else {
+    if let Ok(menu) = build_menu_from_items(app, items, layout) {
+        let _ = tray.set_menu(Some(menu));
+    }
+}
+
+/// List the main window and `profiles` in the account switcher, showing it
+/// only while profiles are configured
+pub fn sync_profile_menu(app: &AppHandle, profiles: &[Profile]) {
+    let Some(items) = app.try_state::<TrayMenuItems>() else {
         return;
     };
 
-    let mut builder = MenuBuilder::new(app)
-        .item(&items.status)
+    while let Ok(Some(_)) = items.accounts.remove_at(1) {}
+    {
+        let mut profile_items = items.profile_items.lock_or_recover();
+        for profile in profiles {
+            let item = match profile_items.get(&profile.id) {
+                Some(item) => {
+                    let _ = item.set_text(&profile.name);
+                    item.clone()
+                }
+                None => {
+                    let Ok(item) = MenuItem::with_id(
+                        app,
+                        format!("{}{}", PROFILE_ID_PREFIX, profile.id),
+                        &profile.name,
+                        true,
+                        None::<&str>,
+                    ) else {
+                        continue;
+                    };
+                    profile_items.insert(profile.id.clone(), item.clone());
+                    item
+                }
+            };
+            let _ = items.accounts.append(&item);
+        }
+    }
+
+    update_layout(app, &items, |layout| layout.accounts = !profiles.is_empty());
+}
+
+/// Assemble the tray menu from the persistent items in `layout`
+fn build_menu_from_items(
+    app: &AppHandle,
+    items: &TrayMenuItems,
+    layout: MenuLayout,
+) -> tauri::Result<Menu<tauri::Wry>> {
+    let sep1 = PredefinedMenuItem::separator(app)?;
+    let sep2 = PredefinedMenuItem::separator(app)?;
+
+    let mut builder = MenuBuilder::new(app).item(&items.status);
+    if layout.in_meeting {
+        builder = builder
+            .item(&items.leave_meeting)
+            .item(&items.copy_meeting_link);
+    }
+    for join in items.join_meetings.iter().take(layout.meetings) {
+        builder = builder.item(join);
+    }
+    if layout.meetings > 0 {
+        builder = builder.item(&items.skip);
+    }
+
+    builder = builder
+        .item(&items.snooze)
+        .item(&items.cancel_next_join)
+        .item(&items.pause)
         .item(&sep1)
-        .item(&items.show)
+        .item(&items.show);
+
+    if layout.accounts {
+        builder = builder.item(&items.accounts);
+    }
+
+    builder = builder
         .item(&items.go_home)
+        .item(&items.go_startup)
+        .item(&items.join_by_code)
         .item(&items.settings_item)
         .item(&items.check_update);
 
-    if include_update {
+    if layout.update {
         builder = builder.item(&items.install_update);
     }
 
-    if let Ok(menu) = builder.item(&sep2).item(&items.quit).build() {
-        let _ = tray.set_menu(Some(menu));
-    }
+    builder.item(&sep2).item(&items.quit).build()
 }
 
 fn available_update_version(app: &AppHandle) -> Option<String> {
@@ -383,17 +1161,31 @@ fn log_tray_event(
     context: Option<serde_json::Value>,
 ) {
     if let Some(state) = app.try_state::<AppState>() {
-        if let Ok(mut logger) = state.logger.lock() {
-            logger.log_internal(level, "tray", event, None, context);
-        }
+        state.logger.log(level, "tray", event, None, context);
     }
 }
 
 fn format_countdown(lang: &Language, starts_in_minutes: i64) -> String {
-    i18n::tr_countdown_short(lang, starts_in_minutes)
+    format::relative_time_short(lang, starts_in_minutes)
 }
 
-fn build_tray_title(meeting: Option<&Meeting>, settings: &TauriSettings, lang: &Language) -> String {
+/// Countdown for the tray title, counting seconds in the last minutes so the
+/// per-second tray refresh near a meeting shows up
+fn tray_countdown(meeting: &Meeting, lang: &Language, now: DateTime<Utc>) -> String {
+    let starts_in_seconds = (meeting.begin_time - now).num_seconds();
+    if (1..TRAY_SECONDS_COUNTDOWN).contains(&starts_in_seconds) {
+        return format::countdown_seconds_short(lang, starts_in_seconds);
+    }
+    format_countdown(lang, meeting.starts_in_minutes_at(now))
+}
+
+/// Build the tray title text shown next to the icon for the given display settings
+pub fn build_tray_title(
+    meeting: Option<&Meeting>,
+    settings: &TauriSettings,
+    lang: &Language,
+    now: DateTime<Utc>,
+) -> String {
     if matches!(settings.tray_display_mode, TrayDisplayMode::IconOnly) {
         return String::new();
     }
@@ -404,11 +1196,11 @@ fn build_tray_title(meeting: Option<&Meeting>, settings: &TauriSettings, lang: &
 
     let base = match settings.tray_display_mode {
         TrayDisplayMode::IconWithTime => meeting.display_time.clone(),
-        TrayDisplayMode::IconWithCountdown => format_countdown(lang, meeting.starts_in_minutes),
+        TrayDisplayMode::IconWithCountdown => tray_countdown(meeting, lang, now),
         TrayDisplayMode::IconOnly => return String::new(),
     };
 
-    if settings.tray_show_meeting_title {
+    if settings.tray_show_meeting_title && !settings.privacy_mode {
         let truncated = truncate_title(&meeting.title, 24);
         if truncated.is_empty() {
             return base;
@@ -419,9 +1211,167 @@ fn build_tray_title(meeting: Option<&Meeting>, settings: &TauriSettings, lang: &
     base
 }
 
+/// Pick the tray icon variant: being in a meeting wins over a pause, which
+/// wins over being offline, which wins over the next meeting starting soon
+pub fn tray_icon_state(
+    in_meeting: bool,
+    paused: bool,
+    offline: bool,
+    next_meeting: Option<&Meeting>,
+    now: DateTime<Utc>,
+) -> TrayIconState {
+    if in_meeting {
+        return TrayIconState::InMeeting;
+    }
+    if paused {
+        return TrayIconState::Paused;
+    }
+    if offline {
+        return TrayIconState::Offline;
+    }
+    let imminent = next_meeting.is_some_and(|meeting| {
+        (0..TRAY_IMMINENT_MINUTES * 60).contains(&(meeting.begin_time - now).num_seconds())
+    });
+    if imminent {
+        TrayIconState::Imminent
+    } else {
+        TrayIconState::Normal
+    }
+}
+
+/// Build the tray title shown while in a meeting: the time since joining,
+/// like "● 23m" or "● 1h 05m"
+pub fn build_meeting_elapsed_title(
+    joined_at_ms: i64,
+    settings: &TauriSettings,
+    now: DateTime<Utc>,
+) -> String {
+    if matches!(settings.tray_display_mode, TrayDisplayMode::IconOnly) {
+        return String::new();
+    }
+
+    let minutes = (now.timestamp_millis() - joined_at_ms).max(0) / 60_000;
+    if minutes < 60 {
+        return format!("● {}m", minutes);
+    }
+    format!("● {}h {:02}m", minutes / 60, minutes % 60)
+}
+
+/// Menu entries for the first `MENU_MAX_MEETINGS` of `meetings`.
+///
+/// Each is labelled with its time and title like the tooltip; titles are
+/// omitted in privacy mode.
+pub fn build_meeting_menu_entries<M: Borrow<Meeting>>(
+    meetings: &[M],
+    settings: &TauriSettings,
+    lang: &Language,
+) -> Vec<MeetingMenuEntry> {
+    meetings
+        .iter()
+        .take(MENU_MAX_MEETINGS)
+        .map(|meeting| {
+            let meeting = meeting.borrow();
+            let time = meeting.display_time.trim();
+            let title = if settings.privacy_mode {
+                String::new()
+            } else {
+                truncate_title(meeting.title.trim(), 24)
+            };
+            let label = match (time.is_empty(), title.is_empty()) {
+                (false, false) => format!("{} {}", time, title),
+                (false, true) => time.to_string(),
+                (true, false) => title,
+                (true, true) => i18n::tr(lang, keys::NEXT_MEETING).to_string(),
+            };
+            MeetingMenuEntry {
+                call_id: meeting.call_id.clone(),
+                label,
+            }
+        })
+        .collect()
+}
+
+/// Build the multi-line tray tooltip listing the next few meetings.
+///
+/// Shows up to `TOOLTIP_MAX_MEETINGS` meetings with their time and status,
+/// followed by a "+N more" line. Meetings in `blocked` show a "can't join"
+/// status instead of their start time, and meetings in `outside_hours` an
+/// "Outside auto-join hours" one. Meetings the Meet page didn't list
+/// are tagged with the sources that reported them. Titles are omitted in
+/// privacy mode.
+pub fn build_tray_tooltip<M: Borrow<Meeting>>(
+    meetings: &[M],
+    blocked: &[String],
+    outside_hours: &[String],
+    origins: &HashMap<String, FieldOrigins>,
+    settings: &TauriSettings,
+    lang: &Language,
+) -> String {
+    if meetings.is_empty() {
+        return i18n::tr_tooltip_no_meetings(lang);
+    }
+
+    let mut lines = vec!["MeetCat".to_string()];
+    for meeting in meetings.iter().take(TOOLTIP_MAX_MEETINGS) {
+        let meeting = meeting.borrow();
+        let status = if blocked.contains(&meeting.call_id) {
+            i18n::tr(lang, keys::JOIN_BLOCKED).to_string()
+        } else if outside_hours.contains(&meeting.call_id) {
+            i18n::tr(lang, keys::OUTSIDE_AUTO_JOIN_HOURS).to_string()
+        } else {
+            format::relative_time(lang, meeting.starts_in_minutes)
+        };
+        let mut label = meeting.display_time.trim().to_string();
+        if !settings.privacy_mode {
+            let title = truncate_title(meeting.title.trim(), 24);
+            if !title.is_empty() {
+                if !label.is_empty() {
+                    label.push(' ');
+                }
+                label.push_str(&title);
+            }
+        }
+        let mut line = if label.is_empty() {
+            status
+        } else {
+            i18n::tr_meeting_line(lang, &label, &status)
+        };
+        if let Some(tag) = origins.get(&meeting.call_id).and_then(source_tag) {
+            line.push_str(" · ");
+            line.push_str(&tag);
+        }
+        lines.push(line);
+    }
+
+    let remaining = meetings.len().saturating_sub(TOOLTIP_MAX_MEETINGS);
+    if remaining > 0 {
+        lines.push(i18n::tr_more_meetings(lang, remaining));
+    }
+
+    lines.join("\n")
+}
+
+/// Short label of the sources behind a meeting not seen on the Meet page
+fn source_tag(origins: &FieldOrigins) -> Option<String> {
+    if origins.sources.is_empty() || origins.sources.contains(&MeetingSource::Webview) {
+        return None;
+    }
+    let labels: Vec<&str> = origins
+        .sources
+        .iter()
+        .map(|source| match source {
+            MeetingSource::Webview => "Meet",
+            MeetingSource::Ics => "ICS",
+            MeetingSource::Api => "API",
+        })
+        .collect();
+    Some(labels.join("+"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::daemon::TimeAccuracy;
 
     #[test]
     fn test_truncate_title_short() {
@@ -476,7 +1426,10 @@ mod tests {
             ..TauriSettings::default()
         };
 
-        assert_eq!(build_tray_title(Some(&meeting), &settings, &lang), "");
+        assert_eq!(
+            build_tray_title(Some(&meeting), &settings, &lang, test_now()),
+            ""
+        );
     }
 
     #[test]
@@ -490,7 +1443,7 @@ mod tests {
         };
 
         assert_eq!(
-            build_tray_title(Some(&meeting), &settings, &lang),
+            build_tray_title(Some(&meeting), &settings, &lang, test_now()),
             "10:30 AM - Design Sync"
         );
     }
@@ -505,7 +1458,32 @@ mod tests {
             ..TauriSettings::default()
         };
 
-        assert_eq!(build_tray_title(Some(&meeting), &settings, &lang), "2m ago");
+        assert_eq!(
+            build_tray_title(Some(&meeting), &settings, &lang, test_now()),
+            "2m ago"
+        );
+    }
+
+    #[test]
+    fn test_build_tray_title_counts_seconds_near_start() {
+        let meeting = create_test_meeting("Design Sync", "10:30 AM", 5);
+        let lang = Language::En;
+        let settings = TauriSettings {
+            tray_display_mode: TrayDisplayMode::IconWithCountdown,
+            tray_show_meeting_title: false,
+            ..TauriSettings::default()
+        };
+        let title = |seconds_before: i64| {
+            let now = meeting.begin_time - chrono::Duration::seconds(seconds_before);
+            build_tray_title(Some(&meeting), &settings, &lang, now)
+        };
+
+        // Derived from the start time rather than the cached minutes
+        assert_eq!(title(180), "in 3m");
+        assert_eq!(title(120), "in 2m");
+        assert_eq!(title(119), "in 1:59");
+        assert_eq!(title(7), "in 0:07");
+        assert_eq!(title(0), "now");
     }
 
     #[test]
@@ -517,7 +1495,260 @@ mod tests {
             ..TauriSettings::default()
         };
 
-        assert_eq!(build_tray_title(None, &settings, &lang), "");
+        assert_eq!(build_tray_title(None, &settings, &lang, test_now()), "");
+    }
+
+    #[test]
+    fn test_tray_icon_state() {
+        let now = test_now();
+        let soon = create_test_meeting("Design Sync", "10:03 AM", 3);
+        let later = create_test_meeting("Planning", "11:00 AM", 60);
+
+        assert_eq!(
+            tray_icon_state(false, false, false, None, now),
+            TrayIconState::Normal
+        );
+        assert_eq!(
+            tray_icon_state(false, false, false, Some(&later), now),
+            TrayIconState::Normal
+        );
+        assert_eq!(
+            tray_icon_state(false, false, false, Some(&soon), now),
+            TrayIconState::Imminent
+        );
+        assert_eq!(
+            tray_icon_state(false, false, true, Some(&soon), now),
+            TrayIconState::Offline
+        );
+        assert_eq!(
+            tray_icon_state(false, true, true, Some(&soon), now),
+            TrayIconState::Paused
+        );
+        assert_eq!(
+            tray_icon_state(true, true, true, Some(&soon), now),
+            TrayIconState::InMeeting
+        );
+    }
+
+    #[test]
+    fn test_build_meeting_elapsed_title() {
+        let settings = TauriSettings::default();
+        let now = test_now();
+        let joined_at = |minutes: i64| now.timestamp_millis() - minutes * 60_000 - 30_000;
+
+        assert_eq!(
+            build_meeting_elapsed_title(joined_at(0), &settings, now),
+            "● 0m"
+        );
+        assert_eq!(
+            build_meeting_elapsed_title(joined_at(23), &settings, now),
+            "● 23m"
+        );
+        assert_eq!(
+            build_meeting_elapsed_title(joined_at(65), &settings, now),
+            "● 1h 05m"
+        );
+        // A join stamped slightly ahead of the tray's clock
+        assert_eq!(
+            build_meeting_elapsed_title(now.timestamp_millis() + 1_000, &settings, now),
+            "● 0m"
+        );
+
+        let icon_only = TauriSettings {
+            tray_display_mode: TrayDisplayMode::IconOnly,
+            ..TauriSettings::default()
+        };
+        assert_eq!(
+            build_meeting_elapsed_title(joined_at(23), &icon_only, now),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_build_tray_tooltip_lists_next_meetings() {
+        let meetings = vec![
+            create_test_meeting("Standup", "10:00 AM", 5),
+            create_test_meeting("Design Sync", "10:30 AM", 35),
+            create_test_meeting("This Is A Very Long Meeting Title", "11:00 AM", 65),
+            create_test_meeting("1:1", "2:00 PM", 245),
+            create_test_meeting("Retro", "4:00 PM", 365),
+        ];
+        let settings = TauriSettings::default();
+
+        assert_eq!(
+            build_tray_tooltip(
+                &meetings,
+                &[],
+                &[],
+                &HashMap::new(),
+                &settings,
+                &Language::En
+            ),
+            "MeetCat\n\
+             10:00 AM Standup (in 5 min)\n\
+             10:30 AM Design Sync (in 35 min)\n\
+             11:00 AM This Is A Very Long M... (in 65 min)\n\
+             +2 more"
+        );
+    }
+
+    #[test]
+    fn test_build_meeting_menu_entries() {
+        let meetings: Vec<Meeting> = (0..7)
+            .map(|i| create_test_meeting(&format!("Meeting {}", i), "10:30 AM", i * 30))
+            .collect();
+        let settings = TauriSettings::default();
+
+        let entries = build_meeting_menu_entries(&meetings, &settings, &Language::En);
+        assert_eq!(entries.len(), MENU_MAX_MEETINGS);
+        assert_eq!(entries[0].label, "10:30 AM Meeting 0");
+        assert_eq!(
+            i18n::tr_join_meeting_now(&Language::En, &entries[0].label),
+            "10:30 AM Meeting 0 — Join now"
+        );
+
+        let mut untimed = create_test_meeting("This Is A Very Long Meeting Title", "", 5);
+        untimed.call_id = "untimed".to_string();
+        let private = TauriSettings {
+            privacy_mode: true,
+            ..TauriSettings::default()
+        };
+        let entries =
+            build_meeting_menu_entries(&[&meetings[0], &untimed], &private, &Language::En);
+        assert_eq!(entries[0].label, "10:30 AM");
+        assert_eq!(entries[1].label, "Next meeting");
+        assert_eq!(entries[1].call_id, "untimed");
+        assert_eq!(
+            build_meeting_menu_entries(&[&untimed], &settings, &Language::En)[0].label,
+            "This Is A Very Long M..."
+        );
+    }
+
+    #[test]
+    fn test_build_tray_tooltip_privacy_mode() {
+        let meetings = vec![
+            create_test_meeting("Secret Project", "10:00 AM", 0),
+            create_test_meeting("Interview", "", 30),
+        ];
+        let settings = TauriSettings {
+            privacy_mode: true,
+            ..TauriSettings::default()
+        };
+
+        let tooltip = build_tray_tooltip(
+            &meetings,
+            &[],
+            &[],
+            &HashMap::new(),
+            &settings,
+            &Language::En,
+        );
+        assert_eq!(tooltip, "MeetCat\n10:00 AM (now)\nin 30 min");
+        assert_eq!(
+            build_tray_title(
+                meetings.first(),
+                &TauriSettings {
+                    tray_display_mode: TrayDisplayMode::IconWithTime,
+                    tray_show_meeting_title: true,
+                    ..settings
+                },
+                &Language::En,
+                test_now()
+            ),
+            "10:00 AM"
+        );
+    }
+
+    #[test]
+    fn test_build_tray_tooltip_marks_blocked_meetings() {
+        let mut partner = create_test_meeting("Partner Sync", "10:00 AM", 5);
+        partner.call_id = "blocked-call".to_string();
+        let meetings = vec![partner, create_test_meeting("Standup", "10:30 AM", 35)];
+
+        assert_eq!(
+            build_tray_tooltip(
+                &meetings,
+                &["blocked-call".to_string()],
+                &[],
+                &HashMap::new(),
+                &TauriSettings::default(),
+                &Language::En
+            ),
+            "MeetCat\n10:00 AM Partner Sync (can't join)\n10:30 AM Standup (in 35 min)"
+        );
+    }
+
+    #[test]
+    fn test_build_tray_tooltip_marks_meetings_outside_auto_join_hours() {
+        let mut early = create_test_meeting("Early Sync", "7:00 AM", 5);
+        early.call_id = "early-call".to_string();
+        let meetings = vec![early, create_test_meeting("Standup", "9:30 AM", 35)];
+
+        assert_eq!(
+            build_tray_tooltip(
+                &meetings,
+                &[],
+                &["early-call".to_string()],
+                &HashMap::new(),
+                &TauriSettings::default(),
+                &Language::En
+            ),
+            "MeetCat\n7:00 AM Early Sync (Outside auto-join hours)\n9:30 AM Standup (in 35 min)"
+        );
+    }
+
+    #[test]
+    fn test_build_tray_tooltip_tags_calendar_only_meetings() {
+        let mut vendor = create_test_meeting("Vendor Call", "10:00 AM", 5);
+        vendor.call_id = "ics-only-mtg".to_string();
+        let meetings = vec![vendor, create_test_meeting("Standup", "10:30 AM", 35)];
+        let origins = HashMap::from([
+            (
+                "ics-only-mtg".to_string(),
+                FieldOrigins {
+                    title: MeetingSource::Ics,
+                    times: MeetingSource::Ics,
+                    url: MeetingSource::Ics,
+                    sources: vec![MeetingSource::Api, MeetingSource::Ics],
+                },
+            ),
+            (
+                "abc123".to_string(),
+                FieldOrigins {
+                    title: MeetingSource::Ics,
+                    times: MeetingSource::Webview,
+                    url: MeetingSource::Webview,
+                    sources: vec![MeetingSource::Webview, MeetingSource::Ics],
+                },
+            ),
+        ]);
+
+        assert_eq!(
+            build_tray_tooltip(
+                &meetings,
+                &[],
+                &[],
+                &origins,
+                &TauriSettings::default(),
+                &Language::En
+            ),
+            "MeetCat\n10:00 AM Vendor Call (in 5 min) · API+ICS\n10:30 AM Standup (in 35 min)"
+        );
+    }
+
+    #[test]
+    fn test_build_tray_tooltip_no_meetings() {
+        assert_eq!(
+            build_tray_tooltip(
+                &[] as &[Meeting],
+                &[],
+                &[],
+                &HashMap::new(),
+                &TauriSettings::default(),
+                &Language::En
+            ),
+            "MeetCat - No upcoming meetings"
+        );
     }
 
     fn create_test_meeting(title: &str, display_time: &str, starts_in_minutes: i64) -> Meeting {
@@ -526,10 +1757,18 @@ mod tests {
             url: "https://meet.google.com/abc123".to_string(),
             title: title.to_string(),
             display_time: display_time.to_string(),
-            begin_time: chrono::Utc::now(),
-            end_time: chrono::Utc::now(),
+            begin_time: test_now() + chrono::Duration::minutes(starts_in_minutes),
+            end_time: test_now() + chrono::Duration::minutes(starts_in_minutes + 30),
             event_id: None,
             starts_in_minutes,
+            profile: None,
+            accuracy: TimeAccuracy::Exact,
         }
     }
+
+    fn test_now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-03-11T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
 }
@@ -0,0 +1,135 @@
+//! Join trigger scheduling, serialized through one task
+//!
+//! Commands, notifications and the trigger tasks themselves all ask for the
+//! join trigger to be rescheduled. Doing that in place let two callers
+//! interleave between aborting the pending trigger and storing its
+//! replacement, leaving a trigger running that nothing could cancel. Instead
+//! every request is sent to a single actor that owns the pending tasks: it
+//! aborts them and schedules the next trigger from the current state one
+//! request at a time. Requests queued while it was busy are folded into one
+//! reschedule.
+//!
+//! The actor owns the trigger tasks only. Meetings and settings are not
+//! behind it yet: they stay in their `AppState` mutexes, which the actor
+//! locks like any command when it schedules, holding `settings` while it
+//! reads the daemon so both come from the same moment. Moving them into a
+//! single actor that answers typed requests is still to be done.
+
+use crate::locks::LockExt;
+use std::sync::Mutex;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::AbortHandle;
+
+/// Ask the actor to abort the pending trigger and schedule the next one
+struct Reschedule {
+    /// Answered once the new trigger is scheduled
+    done: oneshot::Sender<()>,
+}
+
+/// Sending side, kept in the app state
+pub struct TriggerActor {
+    tx: mpsc::UnboundedSender<Reschedule>,
+    /// Taken when the actor starts; requests sent before then wait in the
+    /// channel
+    inbox: Mutex<Option<TriggerInbox>>,
+}
+
+impl Default for TriggerActor {
+    fn default() -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        Self {
+            tx,
+            inbox: Mutex::new(Some(TriggerInbox { rx })),
+        }
+    }
+}
+
+impl TriggerActor {
+    /// Request a reschedule; the receiver resolves once it is done and may
+    /// be dropped by callers that don't need to wait
+    pub fn reschedule(&self) -> oneshot::Receiver<()> {
+        let (done, rx) = oneshot::channel();
+        // Only fails after the actor stopped, when the app is exiting
+        let _ = self.tx.send(Reschedule { done });
+        rx
+    }
+
+    /// The receiving side, the first time this is called
+    pub fn take_inbox(&self) -> Option<TriggerInbox> {
+        self.inbox.lock_or_recover().take()
+    }
+}
+
+/// Receiving side, run by the actor task
+pub struct TriggerInbox {
+    rx: mpsc::UnboundedReceiver<Reschedule>,
+}
+
+impl TriggerInbox {
+    /// Handle requests until every sender is gone.
+    ///
+    /// `schedule` is told whether a trigger was still pending when it got
+    /// aborted, and returns the tasks it spawned for the next one.
+    pub async fn run<F>(mut self, mut schedule: F)
+    where
+        F: FnMut(bool) -> Vec<AbortHandle>,
+    {
+        let mut pending: Vec<AbortHandle> = Vec::new();
+        while let Some(request) = self.rx.recv().await {
+            let mut waiting = vec![request.done];
+            while let Ok(request) = self.rx.try_recv() {
+                waiting.push(request.done);
+            }
+            let aborted = pending.iter().any(|task| !task.is_finished());
+            for task in pending.drain(..) {
+                task.abort();
+            }
+            pending = schedule(aborted);
+            for done in waiting {
+                let _ = done.send(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    /// A task that runs until aborted, and a receiver that errors once it is
+    fn pending_task() -> (AbortHandle, oneshot::Receiver<()>) {
+        let (alive, dropped) = oneshot::channel::<()>();
+        let task = tokio::spawn(async move {
+            let _alive = alive;
+            std::future::pending::<()>().await;
+        });
+        (task.abort_handle(), dropped)
+    }
+
+    #[tokio::test]
+    async fn test_folds_queued_requests_and_aborts_pending() {
+        let actor = TriggerActor::default();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let (first, first_dropped) = pending_task();
+
+        // Sent before the actor starts, so both are handled as one
+        let queued = actor.reschedule();
+        drop(actor.reschedule());
+
+        let inbox = actor.take_inbox().expect("inbox");
+        assert!(actor.take_inbox().is_none());
+        let recorded = Arc::clone(&calls);
+        let mut first = Some(first);
+        tokio::spawn(inbox.run(move |aborted| {
+            recorded.lock_or_recover().push(aborted);
+            first.take().into_iter().collect()
+        }));
+        queued.await.expect("rescheduled");
+        assert_eq!(*calls.lock_or_recover(), vec![false]);
+
+        actor.reschedule().await.expect("rescheduled");
+        assert_eq!(*calls.lock_or_recover(), vec![false, true]);
+        assert!(first_dropped.await.is_err());
+    }
+}
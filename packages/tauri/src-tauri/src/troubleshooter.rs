@@ -0,0 +1,439 @@
+//! Guided troubleshooting
+//!
+//! Runs the individual diagnostics in a fixed order and turns each result
+//! into a step of the troubleshooting wizard in the settings window:
+//! 1. injection: the MeetCat script is loaded in the main window
+//! 2. parser: the parser self-test passes against the sample cards
+//! 3. permissions: camera and microphone are not blocked for Meet
+//! 4. reachability: meet.google.com answers from the main window
+//! 5. clock: the system clock agrees with Google's `Date` header
+//! 6. logging: log collection is on, so problems can be reported
+//!
+//! Steps that depend on the main window showing Google Meet are skipped when
+//! it doesn't.
+
+use crate::settings::{LogLevel, Settings};
+use serde::{Deserialize, Serialize};
+
+/// Clock offset from Google's servers above which joins may fire off time
+pub const CLOCK_SKEW_WARN_MS: i64 = 2 * 60 * 1000;
+
+/// Script evaluated in the main window to probe the page; it reports back
+/// through `troubleshoot_probe_result` with the request id substituted for
+/// `__REQUEST_ID__`
+pub const PROBE_SCRIPT: &str = r#"
+(async function() {
+    const requestId = __REQUEST_ID__;
+    const report = {
+        injected: typeof window.MeetCatInject !== 'undefined'
+            && window.__meetcatInitialized === location.pathname,
+    };
+    async function permission(name) {
+        try {
+            return (await navigator.permissions.query({ name })).state;
+        } catch (e) {
+            return null;
+        }
+    }
+    report.camera = await permission('camera');
+    report.microphone = await permission('microphone');
+    try {
+        const response = await fetch('https://meet.google.com/', {
+            method: 'HEAD',
+            cache: 'no-store',
+            credentials: 'include',
+        });
+        report.meetStatus = response.status;
+        const date = Date.parse(response.headers.get('date') || '');
+        if (!Number.isNaN(date)) {
+            report.serverDateMs = date;
+        }
+    } catch (e) {
+        report.meetError = String(e);
+    }
+    report.localMs = Date.now();
+    window.__TAURI__.core.invoke('troubleshoot_probe_result', { requestId, report });
+})();
+"#;
+
+/// What the probe script found in the main window
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageProbe {
+    pub injected: bool,
+    /// `granted`, `prompt` or `denied`; `None` when the browser can't tell
+    pub camera: Option<String>,
+    pub microphone: Option<String>,
+    pub meet_status: Option<u16>,
+    pub meet_error: Option<String>,
+    /// Google's `Date` header; only second precision
+    pub server_date_ms: Option<i64>,
+    pub local_ms: i64,
+}
+
+/// Results gathered by `run_troubleshooter` before the report is built
+#[derive(Debug, Clone)]
+pub struct TroubleshootInput {
+    /// Whether the main window shows a Google Meet page
+    pub on_meet: bool,
+    /// `None` when the page didn't answer the probe in time
+    pub probe: Option<PageProbe>,
+    /// Parser self-test report, or why it couldn't run
+    pub parser: Result<serde_json::Value, String>,
+    pub log_collection_enabled: bool,
+    pub log_level: LogLevel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepId {
+    Injection,
+    Parser,
+    Permissions,
+    Reachability,
+    Clock,
+    Logging,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepStatus {
+    Pass,
+    Warn,
+    Fail,
+    /// Couldn't run, e.g. Google Meet isn't open
+    Skipped,
+}
+
+/// Suggested fix, translated by the settings window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuggestedFix {
+    /// Go back to Google Meet home in the main window
+    OpenMeetHome,
+    /// Google changed the page; a newer MeetCat may handle it
+    CheckForUpdates,
+    /// Allow camera and microphone for meet.google.com
+    AllowMediaAccess,
+    /// Check the network connection or proxy settings
+    CheckNetwork,
+    /// Turn on automatic date and time in system settings
+    SyncClock,
+    /// Turn on log collection in the developer settings
+    EnableLogging,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TroubleshootStep {
+    pub id: StepId,
+    pub status: StepStatus,
+    /// Untranslated technical detail, e.g. an error message
+    pub detail: Option<String>,
+    pub fix: Option<SuggestedFix>,
+}
+
+impl TroubleshootStep {
+    fn new(id: StepId, status: StepStatus) -> Self {
+        Self {
+            id,
+            status,
+            detail: None,
+            fix: None,
+        }
+    }
+
+    fn detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    fn fix(mut self, fix: SuggestedFix) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TroubleshootReport {
+    pub ran_at_ms: u64,
+    /// One entry per check, in wizard order
+    pub steps: Vec<TroubleshootStep>,
+    /// Whether no step failed
+    pub passed: bool,
+}
+
+impl TroubleshootInput {
+    pub fn from_settings(
+        settings: &Settings,
+        on_meet: bool,
+        probe: Option<PageProbe>,
+        parser: Result<serde_json::Value, String>,
+    ) -> Self {
+        let (log_collection_enabled, log_level) = settings
+            .tauri
+            .as_ref()
+            .map(|t| (t.log_collection_enabled, t.log_level.clone()))
+            .unwrap_or((false, LogLevel::default()));
+        Self {
+            on_meet,
+            probe,
+            parser,
+            log_collection_enabled,
+            log_level,
+        }
+    }
+}
+
+/// Turn the gathered results into wizard steps
+pub fn build_report(input: &TroubleshootInput, now_ms: u64) -> TroubleshootReport {
+    let steps = vec![
+        injection_step(input),
+        parser_step(input),
+        permissions_step(input),
+        reachability_step(input),
+        clock_step(input),
+        logging_step(input),
+    ];
+    let passed = steps.iter().all(|step| step.status != StepStatus::Fail);
+    TroubleshootReport {
+        ran_at_ms: now_ms,
+        steps,
+        passed,
+    }
+}
+
+/// Step for a check that needs the probe when the page didn't answer it
+fn skipped_without_probe(id: StepId) -> TroubleshootStep {
+    TroubleshootStep::new(id, StepStatus::Skipped).fix(SuggestedFix::OpenMeetHome)
+}
+
+fn injection_step(input: &TroubleshootInput) -> TroubleshootStep {
+    let step = TroubleshootStep::new(StepId::Injection, StepStatus::Fail);
+    if !input.on_meet {
+        return step
+            .detail("The main window is not showing Google Meet")
+            .fix(SuggestedFix::OpenMeetHome);
+    }
+    match &input.probe {
+        Some(probe) if probe.injected => TroubleshootStep::new(StepId::Injection, StepStatus::Pass),
+        Some(_) => step
+            .detail("The MeetCat script is not running on the page")
+            .fix(SuggestedFix::OpenMeetHome),
+        None => step
+            .detail("The page did not respond")
+            .fix(SuggestedFix::OpenMeetHome),
+    }
+}
+
+fn parser_step(input: &TroubleshootInput) -> TroubleshootStep {
+    match &input.parser {
+        Ok(report) if report.get("passed").and_then(|v| v.as_bool()) == Some(true) => {
+            TroubleshootStep::new(StepId::Parser, StepStatus::Pass)
+        }
+        Ok(report) => {
+            let failed = report.get("failed").and_then(|v| v.as_u64()).unwrap_or(0);
+            let total = report.get("total").and_then(|v| v.as_u64()).unwrap_or(0);
+            TroubleshootStep::new(StepId::Parser, StepStatus::Fail)
+                .detail(format!("{} of {} sample meetings failed", failed, total))
+                .fix(SuggestedFix::CheckForUpdates)
+        }
+        Err(_) if !input.on_meet => TroubleshootStep::new(StepId::Parser, StepStatus::Skipped)
+            .fix(SuggestedFix::OpenMeetHome),
+        Err(e) => TroubleshootStep::new(StepId::Parser, StepStatus::Fail)
+            .detail(e.clone())
+            .fix(SuggestedFix::OpenMeetHome),
+    }
+}
+
+fn permissions_step(input: &TroubleshootInput) -> TroubleshootStep {
+    let Some(probe) = &input.probe else {
+        return skipped_without_probe(StepId::Permissions);
+    };
+    let denied: Vec<&str> = [("camera", &probe.camera), ("microphone", &probe.microphone)]
+        .into_iter()
+        .filter(|(_, state)| state.as_deref() == Some("denied"))
+        .map(|(name, _)| name)
+        .collect();
+    if denied.is_empty() {
+        return TroubleshootStep::new(StepId::Permissions, StepStatus::Pass);
+    }
+    TroubleshootStep::new(StepId::Permissions, StepStatus::Warn)
+        .detail(format!("Blocked: {}", denied.join(", ")))
+        .fix(SuggestedFix::AllowMediaAccess)
+}
+
+fn reachability_step(input: &TroubleshootInput) -> TroubleshootStep {
+    let Some(probe) = &input.probe else {
+        return skipped_without_probe(StepId::Reachability);
+    };
+    match (probe.meet_status, &probe.meet_error) {
+        (Some(status), _) if status < 500 => {
+            TroubleshootStep::new(StepId::Reachability, StepStatus::Pass)
+        }
+        (Some(status), _) => TroubleshootStep::new(StepId::Reachability, StepStatus::Fail)
+            .detail(format!("HTTP {}", status))
+            .fix(SuggestedFix::CheckNetwork),
+        (None, error) => TroubleshootStep::new(StepId::Reachability, StepStatus::Fail)
+            .detail(error.clone().unwrap_or_else(|| "No response".to_string()))
+            .fix(SuggestedFix::CheckNetwork),
+    }
+}
+
+fn clock_step(input: &TroubleshootInput) -> TroubleshootStep {
+    let Some(probe) = &input.probe else {
+        return skipped_without_probe(StepId::Clock);
+    };
+    let Some(server_ms) = probe.server_date_ms else {
+        return TroubleshootStep::new(StepId::Clock, StepStatus::Skipped);
+    };
+    let skew_ms = probe.local_ms - server_ms;
+    if skew_ms.abs() < CLOCK_SKEW_WARN_MS {
+        return TroubleshootStep::new(StepId::Clock, StepStatus::Pass);
+    }
+    TroubleshootStep::new(StepId::Clock, StepStatus::Warn)
+        .detail(format!("System clock is off by {} s", skew_ms / 1000))
+        .fix(SuggestedFix::SyncClock)
+}
+
+fn logging_step(input: &TroubleshootInput) -> TroubleshootStep {
+    if !input.log_collection_enabled {
+        return TroubleshootStep::new(StepId::Logging, StepStatus::Warn)
+            .detail("Log collection is off")
+            .fix(SuggestedFix::EnableLogging);
+    }
+    let level = serde_json::to_value(&input.log_level)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+    TroubleshootStep::new(StepId::Logging, StepStatus::Pass).detail(format!("Level: {}", level))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn healthy() -> TroubleshootInput {
+        TroubleshootInput {
+            on_meet: true,
+            probe: Some(PageProbe {
+                injected: true,
+                camera: Some("granted".to_string()),
+                microphone: Some("prompt".to_string()),
+                meet_status: Some(200),
+                meet_error: None,
+                server_date_ms: Some(1_000_000),
+                local_ms: 1_000_500,
+            }),
+            parser: Ok(json!({ "passed": true, "failed": 0, "total": 4 })),
+            log_collection_enabled: true,
+            log_level: LogLevel::Info,
+        }
+    }
+
+    fn step(report: &TroubleshootReport, id: StepId) -> &TroubleshootStep {
+        report.steps.iter().find(|step| step.id == id).unwrap()
+    }
+
+    #[test]
+    fn test_healthy_setup_passes_every_step() {
+        let report = build_report(&healthy(), 42);
+        assert!(report.passed);
+        assert_eq!(report.ran_at_ms, 42);
+        assert_eq!(report.steps.len(), 6);
+        assert!(report
+            .steps
+            .iter()
+            .all(|step| step.status == StepStatus::Pass && step.fix.is_none()));
+    }
+
+    #[test]
+    fn test_problems_suggest_fixes() {
+        let mut input = healthy();
+        let probe = input.probe.as_mut().unwrap();
+        probe.injected = false;
+        probe.camera = Some("denied".to_string());
+        probe.meet_status = Some(503);
+        probe.local_ms = probe.server_date_ms.unwrap() + 5 * 60 * 1000;
+        input.parser = Ok(json!({ "passed": false, "failed": 2, "total": 4 }));
+        input.log_collection_enabled = false;
+
+        let report = build_report(&input, 0);
+        assert!(!report.passed);
+        let expected = [
+            (
+                StepId::Injection,
+                StepStatus::Fail,
+                SuggestedFix::OpenMeetHome,
+            ),
+            (
+                StepId::Parser,
+                StepStatus::Fail,
+                SuggestedFix::CheckForUpdates,
+            ),
+            (
+                StepId::Permissions,
+                StepStatus::Warn,
+                SuggestedFix::AllowMediaAccess,
+            ),
+            (
+                StepId::Reachability,
+                StepStatus::Fail,
+                SuggestedFix::CheckNetwork,
+            ),
+            (StepId::Clock, StepStatus::Warn, SuggestedFix::SyncClock),
+            (
+                StepId::Logging,
+                StepStatus::Warn,
+                SuggestedFix::EnableLogging,
+            ),
+        ];
+        for (id, status, fix) in expected {
+            assert_eq!(step(&report, id).status, status, "{:?}", id);
+            assert_eq!(step(&report, id).fix, Some(fix), "{:?}", id);
+        }
+        assert_eq!(
+            step(&report, StepId::Parser).detail.as_deref(),
+            Some("2 of 4 sample meetings failed")
+        );
+    }
+
+    #[test]
+    fn test_page_checks_skipped_away_from_meet() {
+        let mut input = healthy();
+        input.on_meet = false;
+        input.probe = None;
+        input.parser = Err("Main window not found".to_string());
+
+        let report = build_report(&input, 0);
+        assert_eq!(step(&report, StepId::Injection).status, StepStatus::Fail);
+        for id in [
+            StepId::Parser,
+            StepId::Permissions,
+            StepId::Reachability,
+            StepId::Clock,
+        ] {
+            assert_eq!(step(&report, id).status, StepStatus::Skipped, "{:?}", id);
+        }
+        assert_eq!(step(&report, StepId::Logging).status, StepStatus::Pass);
+    }
+
+    #[test]
+    fn test_probe_report_deserializes() {
+        let probe: PageProbe = serde_json::from_value(json!({
+            "injected": true,
+            "camera": null,
+            "microphone": "denied",
+            "meetStatus": 200,
+            "serverDateMs": 1000,
+            "localMs": 2000,
+        }))
+        .unwrap();
+        assert!(probe.injected);
+        assert_eq!(probe.microphone.as_deref(), Some("denied"));
+        assert_eq!(probe.meet_error, None);
+        assert_eq!(probe.server_date_ms, Some(1000));
+    }
+}
@@ -0,0 +1,409 @@
+//! Normalization and validation of Google Meet URLs
+//!
+//! Meeting URLs arrive from the WebView parser, calendar feeds and deep links,
+//! and may carry tracking parameters, plain `http`, mixed-case codes or a
+//! lookalike host. Everything the app schedules or navigates to goes through
+//! [`normalize`] first, which either returns the canonical
+//! `https://meet.google.com/<code>` form or says why the URL was rejected.
+
+use serde::Serialize;
+use std::fmt;
+use tauri::Url;
+
+pub const MEET_HOST: &str = "meet.google.com";
+
+/// Hosts meeting URLs may point at
+const ALLOWED_HOSTS: &[&str] = &[MEET_HOST];
+
+/// Hosts the main window may open at startup
+const STARTUP_HOSTS: &[&str] = &[MEET_HOST, "calendar.google.com"];
+
+/// Query parameters kept on a normalized URL; everything else (`utm_*`,
+/// `hs`, `pli`, ...) is dropped
+const KEPT_PARAMS: &[&str] = &["authuser"];
+
+/// What a meeting URL points at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MeetTarget {
+    /// `xxx-xxxx-xxx` meeting code, lowercased
+    Code(String),
+    /// `lookup/<id>` calendar lookup; the id is case-sensitive
+    Lookup(String),
+}
+
+impl MeetTarget {
+    /// Path appended to `https://meet.google.com/`
+    pub fn path(&self) -> String {
+        match self {
+            MeetTarget::Code(code) => code.clone(),
+            MeetTarget::Lookup(id) => format!("lookup/{}", id),
+        }
+    }
+}
+
+/// Why a meeting URL was refused
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UrlRejection {
+    Malformed,
+    Scheme,
+    Credentials,
+    Port,
+    Host,
+    Path,
+}
+
+impl fmt::Display for UrlRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            UrlRejection::Malformed => "not a valid URL",
+            UrlRejection::Scheme => "only http and https meeting URLs are allowed",
+            UrlRejection::Credentials => "meeting URLs must not contain credentials",
+            UrlRejection::Port => "meeting URLs must not set a port",
+            UrlRejection::Host => "host is not a Google Meet host",
+            UrlRejection::Path => "not a meeting code or lookup path",
+        };
+        f.write_str(message)
+    }
+}
+
+/// Whether `url` is on an allowed Meet host, over http(s) and the default port
+pub fn is_allowed_host(url: &Url) -> bool {
+    is_on_hosts(url, ALLOWED_HOSTS)
+}
+
+fn is_on_hosts(url: &Url, hosts: &[&str]) -> bool {
+    matches!(url.scheme(), "http" | "https")
+        && url.port().is_none()
+        && url
+            .host_str()
+            .is_some_and(|host| hosts.contains(&host.trim_end_matches('.')))
+}
+
+/// Parse a Meet path such as `/abc-defg-hij`, `abcdefghij` or `lookup/<id>`
+pub fn parse_target_path(path: &str) -> Option<MeetTarget> {
+    let trimmed = path.trim_matches('/');
+    if let Some(id) = trimmed.strip_prefix("lookup/") {
+        return is_safe_path_segment(id).then(|| MeetTarget::Lookup(id.to_string()));
+    }
+    canonical_code(trimmed).map(MeetTarget::Code)
+}
+
+/// Canonical URL for `target`
+pub fn meeting_url(target: &MeetTarget) -> Url {
+    let mut url = Url::parse("https://meet.google.com/").expect("static URL");
+    url.set_path(&target.path());
+    url
+}
+
+/// Normalize a meeting URL given as text.
+///
+/// A missing scheme is tolerated (`meet.google.com/abc-defg-hij`).
+pub fn normalize(raw: &str) -> Result<Url, UrlRejection> {
+    normalize_url(&parse_lenient(raw)?)
+}
+
+/// Normalize an already parsed meeting URL
+pub fn normalize_url(url: &Url) -> Result<Url, UrlRejection> {
+    check_origin(url, ALLOWED_HOSTS)?;
+    let target = parse_target_path(url.path()).ok_or(UrlRejection::Path)?;
+
+    let mut normalized = meeting_url(&target);
+    let kept: Vec<(String, String)> = KEPT_PARAMS
+        .iter()
+        .filter_map(|name| {
+            url.query_pairs()
+                .find(|(key, value)| key == name && !value.trim().is_empty())
+                .map(|(key, value)| (key.into_owned(), value.trim().to_string()))
+        })
+        .collect();
+    if !kept.is_empty() {
+        normalized.query_pairs_mut().extend_pairs(kept);
+    }
+    Ok(normalized)
+}
+
+/// Meeting URL for a code or link typed by the user.
+///
+/// Accepts full links, links without a scheme, bare codes with or without
+/// dashes, and `lookup/<id>` paths, optionally followed by a query.
+pub fn meeting_input_url(input: &str) -> Result<Url, UrlRejection> {
+    let trimmed = input.trim();
+    let looks_like_url = trimmed.contains("://")
+        || trimmed
+            .split(['/', '?'])
+            .next()
+            .is_some_and(|head| head.contains('.'));
+    if looks_like_url {
+        normalize(trimmed)
+    } else {
+        normalize(&format!(
+            "https://{}/{}",
+            MEET_HOST,
+            trimmed.trim_start_matches('/')
+        ))
+    }
+}
+
+/// `url` without the Meet origin, e.g. `abc-defg-hij?authuser=1`
+pub fn short_form(url: &Url) -> String {
+    let path = url.path().trim_start_matches('/');
+    match url.query() {
+        Some(query) => format!("{}?{}", path, query),
+        None => path.to_string(),
+    }
+}
+
+/// Normalize a configured startup page.
+///
+/// Any path is allowed, but only on Google Meet or Google Calendar, and
+/// always over https.
+pub fn normalize_startup_url(raw: &str) -> Result<Url, UrlRejection> {
+    let mut url = parse_lenient(raw)?;
+    check_origin(&url, STARTUP_HOSTS)?;
+    let host = url
+        .host_str()
+        .unwrap_or_default()
+        .trim_end_matches('.')
+        .to_string();
+    url.set_host(Some(&host))
+        .map_err(|_| UrlRejection::Malformed)?;
+    url.set_scheme("https").map_err(|_| UrlRejection::Scheme)?;
+    Ok(url)
+}
+
+/// Parse `raw`, assuming https when the scheme is missing
+fn parse_lenient(raw: &str) -> Result<Url, UrlRejection> {
+    let trimmed = raw.trim();
+    let parsed = if trimmed.contains("://") {
+        Url::parse(trimmed)
+    } else {
+        Url::parse(&format!("https://{}", trimmed))
+    };
+    parsed.map_err(|_| UrlRejection::Malformed)
+}
+
+/// Scheme, credential, port and host checks shared by all URL kinds
+fn check_origin(url: &Url, hosts: &[&str]) -> Result<(), UrlRejection> {
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(UrlRejection::Scheme);
+    }
+    // `https://meet.google.com@evil.example/` reads like a Meet link
+    if !url.username().is_empty() || url.password().is_some() {
+        return Err(UrlRejection::Credentials);
+    }
+    if url.port().is_some() {
+        return Err(UrlRejection::Port);
+    }
+    if !is_on_hosts(url, hosts) {
+        return Err(UrlRejection::Host);
+    }
+    Ok(())
+}
+
+/// `xxx-xxxx-xxx` (3-4-3 alphanumeric), or the same ten letters without dashes
+fn canonical_code(code: &str) -> Option<String> {
+    let bytes = code.as_bytes();
+    let dashed = bytes.len() == 12
+        && bytes.iter().enumerate().all(|(idx, b)| match idx {
+            3 | 8 => *b == b'-',
+            _ => b.is_ascii_alphanumeric(),
+        });
+    if dashed {
+        return Some(code.to_ascii_lowercase());
+    }
+    let bare = bytes.len() == 10 && bytes.iter().all(u8::is_ascii_alphabetic);
+    bare.then(|| {
+        let code = code.to_ascii_lowercase();
+        format!("{}-{}-{}", &code[..3], &code[3..7], &code[7..])
+    })
+}
+
+fn is_safe_path_segment(s: &str) -> bool {
+    !s.is_empty()
+        && s.bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn norm(raw: &str) -> Result<String, UrlRejection> {
+        normalize(raw).map(|url| url.to_string())
+    }
+
+    #[test]
+    fn test_normalize_canonical_forms() {
+        let canonical = Ok("https://meet.google.com/abc-defg-hij".to_string());
+        assert_eq!(norm("https://meet.google.com/abc-defg-hij"), canonical);
+        assert_eq!(norm("http://meet.google.com/abc-defg-hij/"), canonical);
+        assert_eq!(norm("  HTTPS://Meet.Google.com/ABC-DEFG-HIJ  "), canonical);
+        assert_eq!(norm("meet.google.com/abcdefghij"), canonical);
+        assert_eq!(norm("https://meet.google.com./abc-defg-hij"), canonical);
+        assert_eq!(norm("https://meet.google.com:443/abc-defg-hij"), canonical);
+        assert_eq!(
+            norm("https://meet.google.com/lookup/Ab_cD-12"),
+            Ok("https://meet.google.com/lookup/Ab_cD-12".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_strips_tracking_params() {
+        assert_eq!(
+            norm("https://meet.google.com/abc-defg-hij?hs=122&utm_source=cal&pli=1#x"),
+            Ok("https://meet.google.com/abc-defg-hij".to_string())
+        );
+        assert_eq!(
+            norm("https://meet.google.com/abc-defg-hij?utm_source=cal&authuser=2"),
+            Ok("https://meet.google.com/abc-defg-hij?authuser=2".to_string())
+        );
+        assert_eq!(
+            norm("https://meet.google.com/abc-defg-hij?authuser="),
+            Ok("https://meet.google.com/abc-defg-hij".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_rejects_spoofed_hosts() {
+        assert_eq!(
+            norm("https://meet.google.com.evil.example/abc-defg-hij"),
+            Err(UrlRejection::Host)
+        );
+        assert_eq!(
+            norm("https://evil.example/meet.google.com/abc-defg-hij"),
+            Err(UrlRejection::Host)
+        );
+        assert_eq!(
+            norm("https://meet.google.com@evil.example/abc-defg-hij"),
+            Err(UrlRejection::Credentials)
+        );
+        assert_eq!(
+            norm("https://user:pw@meet.google.com/abc-defg-hij"),
+            Err(UrlRejection::Credentials)
+        );
+        assert_eq!(
+            norm("https://xn--meet-google-com.example/abc-defg-hij"),
+            Err(UrlRejection::Host)
+        );
+        assert_eq!(
+            norm("https://meet.google.com:8443/abc-defg-hij"),
+            Err(UrlRejection::Port)
+        );
+        assert_eq!(norm("https://zoom.us/j/123456789"), Err(UrlRejection::Host));
+    }
+
+    #[test]
+    fn test_normalize_rejects_malformed() {
+        assert_eq!(norm(""), Err(UrlRejection::Malformed));
+        assert_eq!(norm("https://"), Err(UrlRejection::Malformed));
+        assert_eq!(
+            norm("javascript://meet.google.com/abc-defg-hij"),
+            Err(UrlRejection::Scheme)
+        );
+        assert_eq!(
+            norm("file:///meet.google.com/abc-defg-hij"),
+            Err(UrlRejection::Scheme)
+        );
+        assert_eq!(norm("https://meet.google.com/"), Err(UrlRejection::Path));
+        assert_eq!(
+            norm("https://meet.google.com/landing"),
+            Err(UrlRejection::Path)
+        );
+        assert_eq!(
+            norm("https://meet.google.com/ab-cdef-ghi"),
+            Err(UrlRejection::Path)
+        );
+        assert_eq!(
+            norm("https://meet.google.com/abc-defg-hij/extra"),
+            Err(UrlRejection::Path)
+        );
+        assert_eq!(
+            norm("https://meet.google.com/lookup/"),
+            Err(UrlRejection::Path)
+        );
+        assert_eq!(
+            norm("https://meet.google.com/lookup/a%2Fb"),
+            Err(UrlRejection::Path)
+        );
+        assert_eq!(
+            norm("https://meet.google.com/abcdefghi1"),
+            Err(UrlRejection::Path)
+        );
+    }
+
+    #[test]
+    fn test_normalize_startup_url() {
+        let day_view = normalize_startup_url("http://calendar.google.com/calendar/r/day?pli=1");
+        assert_eq!(
+            day_view.map(|url| url.to_string()),
+            Ok("https://calendar.google.com/calendar/r/day?pli=1".to_string())
+        );
+        assert_eq!(
+            normalize_startup_url("meet.google.com./landing").map(|url| url.to_string()),
+            Ok("https://meet.google.com/landing".to_string())
+        );
+        assert_eq!(
+            normalize_startup_url("https://calendar.google.com.evil.example/"),
+            Err(UrlRejection::Host)
+        );
+        assert_eq!(
+            normalize_startup_url("https://mail.google.com/"),
+            Err(UrlRejection::Host)
+        );
+        // Meeting URLs stay limited to Meet
+        assert_eq!(
+            norm("https://calendar.google.com/abc-defg-hij"),
+            Err(UrlRejection::Host)
+        );
+    }
+
+    #[test]
+    fn test_parse_target_path() {
+        assert_eq!(
+            parse_target_path("/ABC-defg-HIJ/"),
+            Some(MeetTarget::Code("abc-defg-hij".to_string()))
+        );
+        assert_eq!(
+            parse_target_path("lookup/xyz"),
+            Some(MeetTarget::Lookup("xyz".to_string()))
+        );
+        assert_eq!(parse_target_path("new"), None);
+        assert_eq!(
+            meeting_url(&MeetTarget::Lookup("xyz".to_string())).as_str(),
+            "https://meet.google.com/lookup/xyz"
+        );
+    }
+
+    #[test]
+    fn test_meeting_input_url() {
+        for input in [
+            "abc-defg-hij",
+            " ABCDEFGHIJ ",
+            "/abc-defg-hij",
+            "meet.google.com/abc-defg-hij",
+            "https://meet.google.com/abc-defg-hij?hs=122",
+        ] {
+            let url = meeting_input_url(input).unwrap();
+            assert_eq!(
+                url.as_str(),
+                "https://meet.google.com/abc-defg-hij",
+                "{}",
+                input
+            );
+            assert_eq!(short_form(&url), "abc-defg-hij");
+        }
+
+        let url = meeting_input_url("abc-defg-hij?authuser=1").unwrap();
+        assert_eq!(short_form(&url), "abc-defg-hij?authuser=1");
+        let url = meeting_input_url("lookup/ab_cd-EF12").unwrap();
+        assert_eq!(short_form(&url), "lookup/ab_cd-EF12");
+
+        assert_eq!(meeting_input_url(""), Err(UrlRejection::Path));
+        assert_eq!(meeting_input_url("standup"), Err(UrlRejection::Path));
+        assert_eq!(
+            meeting_input_url("evil.example/abc-defg-hij"),
+            Err(UrlRejection::Host)
+        );
+    }
+}
@@ -9,9 +9,34 @@
 //! - `meetcat://settings`                     — open settings window
 //! - `meetcat://new`                          — start a new instant meeting
 //! - `meetcat://check-update`                 — trigger manual update check
+//! - `meetcat://query/next-meeting`           — report the next meeting as JSON
+//! - `meetcat://join-next`                    — join the next meeting now
+//! - `meetcat://pause?minutes=<n>`            — pause auto-join for `n` minutes
+//! - `meetcat://resume`                       — resume auto-join
+//! - `meetcat://privacy-mode?enabled=<on|off>` — set or toggle privacy mode
+//!
+//! Any of them may carry `x-success` / `x-error` callback URLs following the
+//! x-callback-url convention, see [`XCallback`]. Any web page can open a
+//! `meetcat://` link, so callbacks only go to apps in [`CALLBACK_SCHEMES`]
+//! and actions that change MeetCat's state are confirmed by the user first,
+//! see [`DeepLinkAction::needs_confirmation`].
+//!
+//! Plain `https://meet.google.com/<code>` links handed to the app (e.g. "Open
+//! With MeetCat") join the meeting like the Meet mirror form, without
+//! callbacks.
 
+use crate::url_norm::{self, MeetTarget};
+use serde_json::Value;
 use tauri::Url;
 
+/// Longest pause accepted by `meetcat://pause`, in minutes
+pub const MAX_PAUSE_MINUTES: u32 = 24 * 60;
+
+/// Schemes a callback may open: launchers that run x-callback-url actions.
+/// Web URLs are refused, since the page that opened the link would receive
+/// the result.
+pub const CALLBACK_SCHEMES: [&str; 3] = ["shortcuts", "raycast", "alfred"];
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DeepLinkAction {
     /// Join a Google Meet meeting. `code` is the path segment appended to
@@ -23,6 +48,19 @@ pub enum DeepLinkAction {
     Settings,
     NewMeeting,
     CheckUpdate,
+    /// Report the next meeting through the success callback
+    QueryNextMeeting,
+    /// Join the daemon's next meeting immediately
+    JoinNext,
+    /// Stop auto-join for the given number of minutes
+    Pause {
+        minutes: u32,
+    },
+    Resume,
+    /// Set privacy mode, or toggle it when `enabled` is `None`
+    PrivacyMode {
+        enabled: Option<bool>,
+    },
 }
 
 impl DeepLinkAction {
@@ -36,11 +74,130 @@ impl DeepLinkAction {
             DeepLinkAction::JoinMeeting { .. }
                 | DeepLinkAction::Home
                 | DeepLinkAction::NewMeeting
+                | DeepLinkAction::JoinNext
         )
     }
+
+    /// Whether a link must be confirmed before this action runs, since it
+    /// changes what MeetCat does on its own
+    pub fn needs_confirmation(&self) -> bool {
+        matches!(
+            self,
+            DeepLinkAction::JoinNext
+                | DeepLinkAction::Pause { .. }
+                | DeepLinkAction::Resume
+                | DeepLinkAction::PrivacyMode { .. }
+        )
+    }
+
+    /// The link for this action without callbacks, shown when asking to
+    /// confirm it
+    pub fn link(&self) -> String {
+        match self {
+            DeepLinkAction::JoinMeeting { code } => format!("meetcat://meet.google.com/{}", code),
+            DeepLinkAction::Home => "meetcat://home".to_string(),
+            DeepLinkAction::Settings => "meetcat://settings".to_string(),
+            DeepLinkAction::NewMeeting => "meetcat://new".to_string(),
+            DeepLinkAction::CheckUpdate => "meetcat://check-update".to_string(),
+            DeepLinkAction::QueryNextMeeting => "meetcat://query/next-meeting".to_string(),
+            DeepLinkAction::JoinNext => "meetcat://join-next".to_string(),
+            DeepLinkAction::Pause { minutes } => format!("meetcat://pause?minutes={}", minutes),
+            DeepLinkAction::Resume => "meetcat://resume".to_string(),
+            DeepLinkAction::PrivacyMode { enabled } => match enabled {
+                None => "meetcat://privacy-mode".to_string(),
+                Some(true) => "meetcat://privacy-mode?enabled=on".to_string(),
+                Some(false) => "meetcat://privacy-mode?enabled=off".to_string(),
+            },
+        }
+    }
+
+    /// Label of the window this action brings forward, if any
+    pub fn shown_window(&self) -> Option<&'static str> {
+        match self {
+            DeepLinkAction::Settings | DeepLinkAction::CheckUpdate => Some("settings"),
+            _ if self.requires_main_window_navigation() => Some("main"),
+            _ => None,
+        }
+    }
+}
+
+/// Error reported to the `x-error` callback
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallbackError {
+    /// Stable machine-readable code, e.g. `"no_meeting"`
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl CallbackError {
+    pub fn new(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// x-callback-url targets attached to a command URL.
+///
+/// On success MeetCat opens `x-success` with a `result` query parameter that
+/// holds a JSON document; on failure it opens `x-error` with `errorCode` and
+/// `errorMessage`. Callbacks outside [`CALLBACK_SCHEMES`] are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct XCallback {
+    pub success: Option<Url>,
+    pub error: Option<Url>,
+}
+
+impl XCallback {
+    /// Callback URL for a successful action with its JSON result
+    pub fn success_url(&self, result: &Value) -> Option<Url> {
+        let mut url = self.success.clone()?;
+        url.query_pairs_mut()
+            .append_pair("result", &result.to_string());
+        Some(url)
+    }
+
+    /// Callback URL for a failed action
+    pub fn error_url(&self, error: &CallbackError) -> Option<Url> {
+        let mut url = self.error.clone()?;
+        url.query_pairs_mut()
+            .append_pair("errorCode", error.code)
+            .append_pair("errorMessage", &error.message);
+        Some(url)
+    }
+}
+
+/// Extract `x-success` / `x-error` from a `meetcat://` URL
+pub fn parse_callback(url: &Url) -> XCallback {
+    let mut callback = XCallback::default();
+    if url.scheme() != "meetcat" {
+        return callback;
+    }
+    for (key, value) in url.query_pairs() {
+        let target = match key.as_ref() {
+            "x-success" => &mut callback.success,
+            "x-error" => &mut callback.error,
+            _ => continue,
+        };
+        if let Ok(parsed) = Url::parse(value.trim()) {
+            if is_allowed_callback(&parsed) {
+                *target = Some(parsed);
+            }
+        }
+    }
+    callback
+}
+
+fn is_allowed_callback(url: &Url) -> bool {
+    CALLBACK_SCHEMES.contains(&url.scheme())
 }
 
 pub fn parse(url: &Url) -> Option<DeepLinkAction> {
+    if url_norm::is_allowed_host(url) {
+        let code = code_from_meet_path(url.path().trim_matches('/'))?;
+        return Some(DeepLinkAction::JoinMeeting { code });
+    }
     if url.scheme() != "meetcat" {
         return None;
     }
@@ -53,6 +210,27 @@ pub fn parse(url: &Url) -> Option<DeepLinkAction> {
         "settings" => Some(DeepLinkAction::Settings),
         "new" => Some(DeepLinkAction::NewMeeting),
         "check-update" => Some(DeepLinkAction::CheckUpdate),
+        "query" => match trimmed_path {
+            "next-meeting" => Some(DeepLinkAction::QueryNextMeeting),
+            _ => None,
+        },
+        "join-next" => Some(DeepLinkAction::JoinNext),
+        "pause" => {
+            let minutes = query_value(url, "minutes")?.parse::<u32>().ok()?;
+            (1..=MAX_PAUSE_MINUTES)
+                .contains(&minutes)
+                .then_some(DeepLinkAction::Pause { minutes })
+        }
+        "resume" => Some(DeepLinkAction::Resume),
+        "privacy-mode" => {
+            let enabled = match query_value(url, "enabled").as_deref() {
+                None | Some("toggle") => None,
+                Some("on" | "true" | "1") => Some(true),
+                Some("off" | "false" | "0") => Some(false),
+                Some(_) => return None,
+            };
+            Some(DeepLinkAction::PrivacyMode { enabled })
+        }
         "join" => {
             let code = code_from_join(url, trimmed_path)?;
             Some(DeepLinkAction::JoinMeeting { code })
@@ -65,57 +243,45 @@ pub fn parse(url: &Url) -> Option<DeepLinkAction> {
     }
 }
 
+/// URLs among the app's launch arguments that [`parse`] may handle.
+///
+/// Windows and Linux open `meetcat://` links (and Meet links the user chose
+/// to open with MeetCat) by launching the app with the URL as an argument.
+pub fn launch_urls<I>(args: I) -> Vec<Url>
+where
+    I: IntoIterator<Item = String>,
+{
+    args.into_iter()
+        .filter_map(|arg| Url::parse(arg.trim()).ok())
+        .filter(|url| url.scheme() == "meetcat" || url_norm::is_allowed_host(url))
+        .collect()
+}
+
+fn query_value(url: &Url, name: &str) -> Option<String> {
+    url.query_pairs()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.trim().to_ascii_lowercase())
+}
+
 fn code_from_join(url: &Url, trimmed_path: &str) -> Option<String> {
     if !trimmed_path.is_empty() {
-        return is_meeting_code(trimmed_path).then(|| trimmed_path.to_string());
+        return meeting_code(trimmed_path);
     }
-    for (key, value) in url.query_pairs() {
-        if key.eq_ignore_ascii_case("id") {
-            let v = value.trim();
-            if is_meeting_code(v) {
-                return Some(v.to_string());
-            }
-        }
-    }
-    None
+    url.query_pairs()
+        .filter(|(key, _)| key.eq_ignore_ascii_case("id"))
+        .find_map(|(_, value)| meeting_code(value.trim()))
 }
 
 fn code_from_meet_path(trimmed_path: &str) -> Option<String> {
-    if let Some(rest) = trimmed_path.strip_prefix("lookup/") {
-        if !rest.is_empty() && is_safe_path_segment(rest) {
-            return Some(format!("lookup/{}", rest));
-        }
-        return None;
-    }
-    is_meeting_code(trimmed_path).then(|| trimmed_path.to_string())
+    url_norm::parse_target_path(trimmed_path).map(|target| target.path())
 }
 
-/// `xxx-xxxx-xxx` (3-4-3 alphanumeric).
-fn is_meeting_code(code: &str) -> bool {
-    if code.len() != 12 {
-        return false;
-    }
-    for (idx, b) in code.as_bytes().iter().enumerate() {
-        match idx {
-            3 | 8 => {
-                if *b != b'-' {
-                    return false;
-                }
-            }
-            _ => {
-                if !b.is_ascii_alphanumeric() {
-                    return false;
-                }
-            }
-        }
+/// A plain meeting code, canonicalized; lookup paths aren't accepted here
+fn meeting_code(code: &str) -> Option<String> {
+    match url_norm::parse_target_path(code)? {
+        MeetTarget::Code(code) => Some(code),
+        MeetTarget::Lookup(_) => None,
     }
-    true
-}
-
-fn is_safe_path_segment(s: &str) -> bool {
-    !s.is_empty()
-        && s.bytes()
-            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_'))
 }
 
 #[cfg(test)]
@@ -249,12 +415,63 @@ mod tests {
         assert_eq!(parse_str("meetcat://checkupdate"), None);
     }
 
+    #[test]
+    fn join_meet_web_link() {
+        assert_eq!(
+            parse_str("https://meet.google.com/XRS-DPXG-HSW?authuser=1&hs=122"),
+            Some(DeepLinkAction::JoinMeeting {
+                code: "xrs-dpxg-hsw".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_str("https://meet.google.com/lookup/ab_cd-EF12"),
+            Some(DeepLinkAction::JoinMeeting {
+                code: "lookup/ab_cd-EF12".to_string(),
+            })
+        );
+        assert_eq!(parse_str("https://meet.google.com/"), None);
+        assert_eq!(parse_str("https://meet.google.com:8443/xrs-dpxg-hsw"), None);
+        // Callbacks only come with meetcat:// commands
+        let url =
+            Url::parse("https://meet.google.com/xrs-dpxg-hsw?x-success=shortcuts%3A%2F%2Fdone")
+                .unwrap();
+        assert_eq!(parse_callback(&url), XCallback::default());
+    }
+
     #[test]
     fn unknown_scheme_and_host() {
-        assert_eq!(parse_str("https://meet.google.com/xrs-dpxg-hsw"), None);
+        assert_eq!(
+            parse_str("https://meet.google.com.evil.com/xrs-dpxg-hsw"),
+            None
+        );
+        assert_eq!(parse_str("https://example.com/xrs-dpxg-hsw"), None);
+        assert_eq!(parse_str("zoommtg://meet.google.com/xrs-dpxg-hsw"), None);
         assert_eq!(parse_str("meetcat://unknown"), None);
     }
 
+    #[test]
+    fn launch_urls_keep_links_only() {
+        let args = [
+            "--data-dir",
+            "/tmp/meetcat",
+            "C:\\Users\\me\\MeetCat",
+            "meetcat://join/xrs-dpxg-hsw",
+            "https://meet.google.com/abc-defg-hij",
+            "https://example.com/",
+        ]
+        .map(String::from);
+        assert_eq!(
+            launch_urls(args)
+                .iter()
+                .map(Url::as_str)
+                .collect::<Vec<_>>(),
+            vec![
+                "meetcat://join/xrs-dpxg-hsw",
+                "https://meet.google.com/abc-defg-hij"
+            ]
+        );
+    }
+
     #[test]
     fn requires_main_window_navigation_classification() {
         assert!(DeepLinkAction::Home.requires_main_window_navigation());
@@ -268,5 +485,144 @@ mod tests {
         // to wait for the main window's first load.
         assert!(!DeepLinkAction::Settings.requires_main_window_navigation());
         assert!(!DeepLinkAction::CheckUpdate.requires_main_window_navigation());
+        assert!(DeepLinkAction::JoinNext.requires_main_window_navigation());
+        assert!(!DeepLinkAction::QueryNextMeeting.requires_main_window_navigation());
+    }
+
+    #[test]
+    fn shown_window_classification() {
+        assert_eq!(DeepLinkAction::Home.shown_window(), Some("main"));
+        assert_eq!(DeepLinkAction::JoinNext.shown_window(), Some("main"));
+        assert_eq!(DeepLinkAction::CheckUpdate.shown_window(), Some("settings"));
+        assert_eq!(DeepLinkAction::Resume.shown_window(), None);
+        assert_eq!(DeepLinkAction::QueryNextMeeting.shown_window(), None);
+    }
+
+    #[test]
+    fn shortcut_commands() {
+        assert_eq!(
+            parse_str("meetcat://query/next-meeting"),
+            Some(DeepLinkAction::QueryNextMeeting)
+        );
+        assert_eq!(parse_str("meetcat://query/unknown"), None);
+        assert_eq!(
+            parse_str("meetcat://join-next"),
+            Some(DeepLinkAction::JoinNext)
+        );
+        assert_eq!(parse_str("meetcat://resume"), Some(DeepLinkAction::Resume));
+        assert_eq!(
+            parse_str("meetcat://privacy-mode"),
+            Some(DeepLinkAction::PrivacyMode { enabled: None })
+        );
+        assert_eq!(
+            parse_str("meetcat://privacy-mode?enabled=on"),
+            Some(DeepLinkAction::PrivacyMode {
+                enabled: Some(true)
+            })
+        );
+        assert_eq!(
+            parse_str("meetcat://privacy-mode?enabled=OFF"),
+            Some(DeepLinkAction::PrivacyMode {
+                enabled: Some(false)
+            })
+        );
+        assert_eq!(parse_str("meetcat://privacy-mode?enabled=maybe"), None);
+    }
+
+    #[test]
+    fn pause_requires_valid_minutes() {
+        assert_eq!(
+            parse_str("meetcat://pause?minutes=30"),
+            Some(DeepLinkAction::Pause { minutes: 30 })
+        );
+        assert_eq!(parse_str("meetcat://pause"), None);
+        assert_eq!(parse_str("meetcat://pause?minutes=0"), None);
+        assert_eq!(parse_str("meetcat://pause?minutes=-5"), None);
+        assert_eq!(parse_str("meetcat://pause?minutes=1441"), None);
+    }
+
+    #[test]
+    fn x_callback_urls() {
+        let url = Url::parse(
+            "meetcat://query/next-meeting?x-success=shortcuts%3A%2F%2Fx-callback-url%2Fdone\
+             &x-error=shortcuts%3A%2F%2Fx-callback-url%2Ferror",
+        )
+        .unwrap();
+        let callback = parse_callback(&url);
+
+        let success = callback
+            .success_url(&serde_json::json!({ "ok": true }))
+            .unwrap();
+        assert_eq!(
+            success.as_str(),
+            "shortcuts://x-callback-url/done?result=%7B%22ok%22%3Atrue%7D"
+        );
+
+        let error = callback
+            .error_url(&CallbackError::new("no_meeting", "No upcoming meeting"))
+            .unwrap();
+        assert_eq!(
+            error.as_str(),
+            "shortcuts://x-callback-url/error?errorCode=no_meeting&errorMessage=No+upcoming+meeting"
+        );
+    }
+
+    #[test]
+    fn x_callback_rejects_unsafe_targets() {
+        let url = Url::parse(
+            "meetcat://resume?x-success=meetcat%3A%2F%2Fresume&x-error=javascript%3Aalert(1)",
+        )
+        .unwrap();
+        assert_eq!(parse_callback(&url), XCallback::default());
+        assert_eq!(
+            parse_callback(&Url::parse("meetcat://resume").unwrap()).success_url(&Value::Null),
+            None
+        );
+
+        // A web page must not receive the result
+        for target in [
+            "https%3A%2F%2Fattacker.example%2F",
+            "http%3A%2F%2F127.0.0.1%3A8080%2F",
+            "HTTPS%3A%2F%2Fattacker.example%2F",
+            "ftp%3A%2F%2Fattacker.example%2F",
+        ] {
+            let url = Url::parse(&format!(
+                "meetcat://query/next-meeting?x-success={}",
+                target
+            ))
+            .unwrap();
+            assert_eq!(parse_callback(&url), XCallback::default(), "{}", target);
+        }
+        let url =
+            Url::parse("meetcat://query/next-meeting?x-success=raycast%3A%2F%2Fdone").unwrap();
+        assert!(parse_callback(&url).success.is_some());
+    }
+
+    #[test]
+    fn state_changes_need_confirmation() {
+        for link in [
+            "meetcat://join-next",
+            "meetcat://pause?minutes=30",
+            "meetcat://resume",
+            "meetcat://privacy-mode?enabled=off",
+        ] {
+            let action = parse_str(link).unwrap();
+            assert!(action.needs_confirmation(), "{}", link);
+            assert_eq!(action.link(), link);
+        }
+        for link in [
+            "meetcat://home",
+            "meetcat://settings",
+            "meetcat://query/next-meeting",
+            "meetcat://join?id=xrs-dpxg-hsw",
+        ] {
+            assert!(!parse_str(link).unwrap().needs_confirmation(), "{}", link);
+        }
+        assert_eq!(
+            parse_str("meetcat://privacy-mode?enabled=toggle")
+                .unwrap()
+                .link(),
+            "meetcat://privacy-mode"
+        );
     }
 }
@@ -0,0 +1,314 @@
+//! Webhook notifications for meeting lifecycle events
+//!
+//! Each lifecycle change listed in `webhookEvents` is POSTed as JSON to every
+//! URL in `webhookUrls`, e.g. to pipe joins into Slack or a time tracker.
+//! With `webhookSecret` set, the body is signed with HMAC-SHA256 and the
+//! hex digest sent as `X-MeetCat-Signature: sha256=<digest>`, so a receiver
+//! can check the request came from this MeetCat.
+//!
+//! Deliveries run in the background. Transport errors, 429 and 5xx answers
+//! are retried with exponential backoff; any other answer ends the delivery.
+
+use crate::daemon::Meeting;
+use crate::format;
+use crate::i18n::Language;
+use crate::lifecycle::{LifecycleEvent, MeetingPhase};
+use crate::settings::WebhookEvent;
+use crate::source_quota::RequestOutcome;
+use chrono::{DateTime, TimeZone, Utc};
+use ring::hmac;
+use serde::Serialize;
+use std::time::Duration;
+use tauri::Url;
+
+pub const SIGNATURE_HEADER: &str = "X-MeetCat-Signature";
+pub const EVENT_HEADER: &str = "X-MeetCat-Event";
+pub const DELIVERY_HEADER: &str = "X-MeetCat-Delivery";
+/// Attempts per delivery, including the first
+const MAX_ATTEMPTS: u32 = 4;
+const RETRY_BASE: Duration = Duration::from_secs(2);
+/// Longest `Retry-After` honoured before giving up on a delivery
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(300);
+
+/// The webhook event a lifecycle change is sent as, if any
+pub fn event_for(lifecycle: &LifecycleEvent) -> Option<WebhookEvent> {
+    match lifecycle.transition.to {
+        MeetingPhase::Scheduled => Some(WebhookEvent::Scheduled),
+        MeetingPhase::Triggered => Some(WebhookEvent::TriggerFired),
+        MeetingPhase::Joined => Some(WebhookEvent::Joined),
+        MeetingPhase::Left => Some(WebhookEvent::Closed),
+        MeetingPhase::Suppressed => Some(WebhookEvent::Suppressed),
+        MeetingPhase::Navigating | MeetingPhase::Failed | MeetingPhase::Expired => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookMeeting {
+    /// Meeting title, or its display time in privacy mode
+    pub title: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    /// Meeting link; omitted in privacy mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// JSON body of a webhook request
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookPayload {
+    pub event: WebhookEvent,
+    pub call_id: String,
+    /// Phase the meeting left; `None` when it was just scheduled
+    pub from: Option<MeetingPhase>,
+    pub at: DateTime<Utc>,
+    /// `None` once the meeting is no longer in the meeting list
+    pub meeting: Option<WebhookMeeting>,
+}
+
+impl WebhookPayload {
+    pub fn build(
+        event: WebhookEvent,
+        lifecycle: &LifecycleEvent,
+        meeting: Option<&Meeting>,
+        privacy_mode: bool,
+        lang: &Language,
+    ) -> Self {
+        Self {
+            event,
+            call_id: lifecycle.call_id.clone(),
+            from: lifecycle.transition.from,
+            at: Utc
+                .timestamp_millis_opt(lifecycle.transition.at_ms)
+                .single()
+                .unwrap_or_default(),
+            meeting: meeting.map(|m| WebhookMeeting {
+                title: format::meeting_subject(lang, &m.title, &m.display_time, privacy_mode),
+                starts_at: m.begin_time,
+                ends_at: m.end_time,
+                url: (!privacy_mode).then(|| m.url.clone()),
+            }),
+        }
+    }
+}
+
+/// Reject URLs that can't receive a webhook before they're saved
+pub fn validate_url(url: &str) -> Result<(), String> {
+    let parsed = Url::parse(url.trim()).map_err(|e| format!("Invalid webhook URL: {}", e))?;
+    if !matches!(parsed.scheme(), "http" | "https") || parsed.host_str().is_none() {
+        return Err(format!("Webhook URL must be http or https: {}", url));
+    }
+    Ok(())
+}
+
+/// Host of `url` for logs; the rest of a webhook URL is often a credential
+pub fn display_host(url: &Url) -> String {
+    url.host_str().unwrap_or_default().to_string()
+}
+
+/// Value of the [`SIGNATURE_HEADER`] for `body`
+pub fn signature(secret: &str, body: &[u8]) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let tag = hmac::sign(&key, body);
+    let hex: String = tag.as_ref().iter().map(|b| format!("{:02x}", b)).collect();
+    format!("sha256={}", hex)
+}
+
+/// A request to make, with its retries
+#[derive(Debug, Clone)]
+pub struct Delivery {
+    pub id: String,
+    pub url: Url,
+    pub event: WebhookEvent,
+    pub body: String,
+    pub signature: Option<String>,
+}
+
+impl Delivery {
+    pub fn new(url: Url, event: WebhookEvent, body: String, secret: Option<&str>) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            signature: secret.map(|secret| signature(secret, body.as_bytes())),
+            url,
+            event,
+            body,
+        }
+    }
+}
+
+/// Send `delivery`, retrying while the receiver may still accept it.
+/// Returns the number of attempts made, or the last error.
+pub async fn deliver(client: reqwest::Client, delivery: Delivery) -> Result<u32, String> {
+    let event = serde_json::to_value(delivery.event)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+    let mut attempt = 1;
+    loop {
+        let mut request = client
+            .post(delivery.url.clone())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, &event)
+            .header(DELIVERY_HEADER, &delivery.id)
+            .body(delivery.body.clone());
+        if let Some(signature) = &delivery.signature {
+            request = request.header(SIGNATURE_HEADER, signature);
+        }
+        // Errors drop the URL, which may embed a token
+        let (error, retry) = match request.send().await {
+            Ok(response) => {
+                let status = response.status();
+                if status.is_success() {
+                    return Ok(attempt);
+                }
+                let retry_after = response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok());
+                let retry = retry_delay(Some(status.as_u16()), retry_after, attempt);
+                (format!("HTTP {}", status), retry)
+            }
+            Err(e) => (
+                e.without_url().to_string(),
+                retry_delay(None, None, attempt),
+            ),
+        };
+        let Some(delay) = retry else {
+            return Err(error);
+        };
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+/// How long to wait after failed attempt number `attempt`, or `None` to give
+/// up. `status` is `None` for transport errors.
+fn retry_delay(status: Option<u16>, retry_after: Option<&str>, attempt: u32) -> Option<Duration> {
+    if attempt >= MAX_ATTEMPTS {
+        return None;
+    }
+    let backoff = RETRY_BASE * 2u32.pow(attempt - 1);
+    match status.map(|status| RequestOutcome::from_status(status, retry_after)) {
+        None | Some(RequestOutcome::ServerError) => Some(backoff),
+        Some(RequestOutcome::Throttled { retry_after_ms }) => {
+            let requested = Duration::from_millis(retry_after_ms.unwrap_or_default());
+            (requested <= MAX_RETRY_AFTER).then(|| requested.max(backoff))
+        }
+        Some(RequestOutcome::Success | RequestOutcome::Failed) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::lifecycle::PhaseTransition;
+
+    fn lifecycle(from: Option<MeetingPhase>, to: MeetingPhase) -> LifecycleEvent {
+        LifecycleEvent {
+            call_id: "abc-defg-hij".to_string(),
+            transition: PhaseTransition {
+                from,
+                to,
+                at_ms: 1_772_443_800_000,
+            },
+        }
+    }
+
+    #[test]
+    fn test_event_for_phase() {
+        use MeetingPhase::*;
+        assert_eq!(
+            event_for(&lifecycle(None, Scheduled)),
+            Some(WebhookEvent::Scheduled)
+        );
+        assert_eq!(
+            event_for(&lifecycle(Some(Scheduled), Triggered)),
+            Some(WebhookEvent::TriggerFired)
+        );
+        assert_eq!(
+            event_for(&lifecycle(Some(Navigating), Joined)),
+            Some(WebhookEvent::Joined)
+        );
+        assert_eq!(
+            event_for(&lifecycle(Some(Joined), Left)),
+            Some(WebhookEvent::Closed)
+        );
+        assert_eq!(event_for(&lifecycle(Some(Triggered), Navigating)), None);
+        assert_eq!(event_for(&lifecycle(Some(Left), Expired)), None);
+    }
+
+    #[test]
+    fn test_payload() {
+        let meeting = fixtures::meeting_at("abc-defg-hij", "1:1 with Sam", Utc::now(), 0, 30);
+        let event = lifecycle(Some(MeetingPhase::Navigating), MeetingPhase::Joined);
+
+        let payload = WebhookPayload::build(
+            WebhookEvent::Joined,
+            &event,
+            Some(&meeting),
+            false,
+            &Language::En,
+        );
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["event"], "joined");
+        assert_eq!(json["callId"], "abc-defg-hij");
+        assert_eq!(json["from"], "navigating");
+        assert_eq!(json["at"], "2026-03-02T09:30:00Z");
+        assert_eq!(json["meeting"]["title"], "1:1 with Sam");
+        assert_eq!(
+            json["meeting"]["url"],
+            "https://meet.google.com/abc-defg-hij"
+        );
+
+        let private = WebhookPayload::build(
+            WebhookEvent::Joined,
+            &event,
+            Some(&meeting),
+            true,
+            &Language::En,
+        );
+        let json = serde_json::to_value(&private).unwrap();
+        assert_ne!(json["meeting"]["title"], "1:1 with Sam");
+        assert!(json["meeting"].get("url").is_none());
+    }
+
+    #[test]
+    fn test_signature() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            signature("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        let long_key = "k".repeat(100);
+        assert_ne!(
+            signature(&long_key, b"body"),
+            signature(&long_key[..64], b"body")
+        );
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        assert_eq!(retry_delay(None, None, 1), Some(Duration::from_secs(2)));
+        assert_eq!(
+            retry_delay(Some(503), None, 2),
+            Some(Duration::from_secs(4))
+        );
+        assert_eq!(
+            retry_delay(Some(429), Some("30"), 1),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(retry_delay(Some(429), Some("3600"), 1), None);
+        assert_eq!(retry_delay(Some(404), None, 1), None);
+        assert_eq!(retry_delay(Some(500), None, MAX_ATTEMPTS), None);
+    }
+
+    #[test]
+    fn test_validate_url() {
+        assert!(validate_url("https://hooks.slack.com/services/T/B/x").is_ok());
+        assert!(validate_url("http://localhost:8080/meetcat").is_ok());
+        assert!(validate_url("ftp://example.com/hook").is_err());
+        assert!(validate_url("not a url").is_err());
+    }
+}
@@ -0,0 +1,220 @@
+//! Page-load failure tracking for the main webview
+//!
+//! Tauri only reports `Started` / `Finished` page-load events, so failures are
+//! inferred: a load that finishes on a non-HTTP(S) error page, a load that never
+//! finishes within `LOAD_TIMEOUT_MS`, or a `navigate()` call that errors out.
+//! Consecutive failures are counted until a load succeeds again.
+//!
+//! Google Meet stops refreshing its meeting list while the page is hidden, so
+//! `HiddenTracker` also measures how long the main window has been hidden to
+//! reload the page before the list goes stale.
+
+use serde::Serialize;
+use tauri::Url;
+
+/// How long a page load may stay pending before it counts as failed
+pub const LOAD_TIMEOUT_MS: u64 = 30_000;
+
+/// Why a page load was considered failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadErrorCode {
+    /// Finished on a browser error page (TLS, DNS, offline, ...)
+    ErrorPage,
+    /// Started but never finished in time
+    Timeout,
+    /// `navigate()` returned an error
+    NavigateFailed,
+}
+
+/// Result of recording a finished load
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadOutcome {
+    Succeeded {
+        /// Failures that preceded this success (0 when nothing was wrong)
+        recovered_after: u32,
+    },
+    Failed {
+        code: LoadErrorCode,
+        consecutive: u32,
+    },
+}
+
+#[derive(Debug, Default)]
+pub struct LoadTracker {
+    /// Id and start time of the load currently in flight
+    pending: Option<(u64, u64)>,
+    next_load_id: u64,
+    consecutive_failures: u32,
+}
+
+impl LoadTracker {
+    /// Record a load start and return its id for the timeout check
+    pub fn on_started(&mut self, now_ms: u64) -> u64 {
+        self.next_load_id += 1;
+        self.pending = Some((self.next_load_id, now_ms));
+        self.next_load_id
+    }
+
+    /// Record a finished load
+    pub fn on_finished(&mut self, url: &Url) -> LoadOutcome {
+        self.pending = None;
+        if is_error_page(url) {
+            self.record_failure(LoadErrorCode::ErrorPage)
+        } else {
+            let recovered_after = self.consecutive_failures;
+            self.consecutive_failures = 0;
+            LoadOutcome::Succeeded { recovered_after }
+        }
+    }
+
+    /// Mark load `load_id` as timed out if it is still pending
+    pub fn check_timeout(&mut self, load_id: u64, now_ms: u64) -> Option<LoadOutcome> {
+        let (pending_id, started_ms) = self.pending?;
+        if pending_id != load_id || now_ms.saturating_sub(started_ms) < LOAD_TIMEOUT_MS {
+            return None;
+        }
+        self.pending = None;
+        Some(self.record_failure(LoadErrorCode::Timeout))
+    }
+
+    /// Record a failed `navigate()` call
+    pub fn on_navigate_failed(&mut self) -> LoadOutcome {
+        self.record_failure(LoadErrorCode::NavigateFailed)
+    }
+
+    /// Failures since the last successful load
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    fn record_failure(&mut self, code: LoadErrorCode) -> LoadOutcome {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        LoadOutcome::Failed {
+            code,
+            consecutive: self.consecutive_failures,
+        }
+    }
+}
+
+/// Browser engines surface network/TLS errors on internal, non-HTTP(S) pages
+fn is_error_page(url: &Url) -> bool {
+    !matches!(url.scheme(), "http" | "https") || url.host_str().unwrap_or("").is_empty()
+}
+
+/// How long the main window has been hidden
+#[derive(Debug, Default)]
+pub struct HiddenTracker {
+    /// Start of the current hidden stretch, or of the last refresh during it
+    hidden_since_ms: Option<u64>,
+}
+
+impl HiddenTracker {
+    /// Record the window's visibility. Returns how long the page has gone
+    /// without a refresh once that reaches `stale_after_ms`, restarting the
+    /// count so the next refresh follows a full period later.
+    pub fn observe(&mut self, visible: bool, now_ms: u64, stale_after_ms: u64) -> Option<u64> {
+        if visible {
+            self.hidden_since_ms = None;
+            return None;
+        }
+        let since = *self.hidden_since_ms.get_or_insert(now_ms);
+        let hidden_for_ms = now_ms.saturating_sub(since);
+        if stale_after_ms == 0 || hidden_for_ms < stale_after_ms {
+            return None;
+        }
+        self.hidden_since_ms = Some(now_ms);
+        Some(hidden_for_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(s: &str) -> Url {
+        Url::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_success_resets_failures() {
+        let mut tracker = LoadTracker::default();
+        tracker.on_started(0);
+        assert_eq!(
+            tracker.on_finished(&url("chrome-error://chromewebdata/")),
+            LoadOutcome::Failed {
+                code: LoadErrorCode::ErrorPage,
+                consecutive: 1,
+            }
+        );
+        assert_eq!(
+            tracker.on_navigate_failed(),
+            LoadOutcome::Failed {
+                code: LoadErrorCode::NavigateFailed,
+                consecutive: 2,
+            }
+        );
+
+        tracker.on_started(10);
+        assert_eq!(
+            tracker.on_finished(&url("https://meet.google.com/")),
+            LoadOutcome::Succeeded { recovered_after: 2 }
+        );
+        assert_eq!(tracker.consecutive_failures(), 0);
+    }
+
+    #[test]
+    fn test_timeout_only_for_pending_load() {
+        let mut tracker = LoadTracker::default();
+        let first = tracker.on_started(0);
+        assert_eq!(tracker.check_timeout(first, LOAD_TIMEOUT_MS - 1), None);
+
+        let second = tracker.on_started(5);
+        assert_eq!(tracker.check_timeout(first, LOAD_TIMEOUT_MS + 10), None);
+        assert_eq!(
+            tracker.check_timeout(second, LOAD_TIMEOUT_MS + 10),
+            Some(LoadOutcome::Failed {
+                code: LoadErrorCode::Timeout,
+                consecutive: 1,
+            })
+        );
+
+        // Already resolved, so a second check does nothing
+        assert_eq!(tracker.check_timeout(second, LOAD_TIMEOUT_MS + 20), None);
+    }
+
+    #[test]
+    fn test_finished_load_is_not_timed_out() {
+        let mut tracker = LoadTracker::default();
+        let id = tracker.on_started(0);
+        tracker.on_finished(&url("https://meet.google.com/"));
+        assert_eq!(tracker.check_timeout(id, LOAD_TIMEOUT_MS * 2), None);
+    }
+
+    #[test]
+    fn test_error_page_detection() {
+        assert!(is_error_page(&url("about:blank")));
+        assert!(is_error_page(&url("data:text/html,oops")));
+        assert!(!is_error_page(&url("https://accounts.google.com/signin")));
+    }
+
+    #[test]
+    fn test_hidden_window_refreshes_once_per_period() {
+        let mut tracker = HiddenTracker::default();
+        let minute = 60_000;
+        let period = 30 * minute;
+        assert_eq!(tracker.observe(false, 0, period), None);
+        assert_eq!(tracker.observe(false, 29 * minute, period), None);
+        assert_eq!(tracker.observe(false, 30 * minute, period), Some(period));
+        assert_eq!(tracker.observe(false, 31 * minute, period), None);
+        assert_eq!(tracker.observe(false, 60 * minute, period), Some(period));
+
+        // Showing the window restarts the count
+        assert_eq!(tracker.observe(true, 70 * minute, period), None);
+        assert_eq!(tracker.observe(false, 80 * minute, period), None);
+        assert_eq!(tracker.observe(false, 100 * minute, period), None);
+
+        // 0 disables the refresh
+        assert_eq!(tracker.observe(false, 500 * minute, 0), None);
+    }
+}
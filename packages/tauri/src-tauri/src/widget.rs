@@ -0,0 +1,232 @@
+//! Next-meeting data for desktop widgets
+//!
+//! When `widgetDataEnabled` is on, the tray ticker writes a small JSON file
+//! that widget tools like Übersicht or SwiftBar can poll. The schema is
+//! described in `doc/WIDGET_DATA.md`; bump [`WIDGET_DATA_VERSION`] on any
+//! incompatible change. The file is replaced atomically, so a reader sees
+//! either the previous or the new content, never a partial write.
+
+use crate::daemon::Meeting;
+use crate::format;
+use crate::i18n::Language;
+use crate::persistence;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const WIDGET_DATA_VERSION: u32 = 1;
+/// Written to the data directory when no path is configured
+pub const WIDGET_DATA_FILE: &str = "widget.json";
+/// Meetings listed after the next one
+const MAX_UPCOMING: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WidgetStatus {
+    /// A meeting is coming up and auto-join is active
+    Upcoming,
+    /// Auto-join is paused, snoozed for out-of-office or rate-capped
+    Paused,
+    /// Nothing left to join
+    Idle,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetMeeting {
+    /// Meeting title, or its display time in privacy mode
+    pub title: String,
+    pub display_time: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub starts_in_minutes: i64,
+    /// One-line text ready for a menu bar, e.g. "Standup · in 5 min"
+    pub label: String,
+    /// Meeting link; omitted in privacy mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetData {
+    pub version: u32,
+    pub updated_at: DateTime<Utc>,
+    pub status: WidgetStatus,
+    /// Titles and links are masked
+    pub privacy_mode: bool,
+    /// Meeting auto-join will open next
+    pub next: Option<WidgetMeeting>,
+    /// Meetings that haven't ended, by start time
+    pub upcoming: Vec<WidgetMeeting>,
+}
+
+impl WidgetData {
+    pub fn build(
+        next: Option<&Meeting>,
+        upcoming: &[&Meeting],
+        paused: bool,
+        privacy_mode: bool,
+        lang: &Language,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let meeting = |m: &Meeting| widget_meeting(m, privacy_mode, lang, now);
+        let status = if paused {
+            WidgetStatus::Paused
+        } else if next.is_some() {
+            WidgetStatus::Upcoming
+        } else {
+            WidgetStatus::Idle
+        };
+        Self {
+            version: WIDGET_DATA_VERSION,
+            updated_at: now,
+            status,
+            privacy_mode,
+            next: next.map(meeting),
+            upcoming: upcoming
+                .iter()
+                .copied()
+                .take(MAX_UPCOMING)
+                .map(meeting)
+                .collect(),
+        }
+    }
+}
+
+fn widget_meeting(
+    meeting: &Meeting,
+    privacy_mode: bool,
+    lang: &Language,
+    now: DateTime<Utc>,
+) -> WidgetMeeting {
+    let title = format::meeting_subject(lang, &meeting.title, &meeting.display_time, privacy_mode);
+    let starts_in_minutes = meeting.starts_in_minutes_at(now);
+    WidgetMeeting {
+        label: format!(
+            "{} · {}",
+            title,
+            format::relative_time(lang, starts_in_minutes)
+        ),
+        title,
+        display_time: meeting.display_time.clone(),
+        starts_at: meeting.begin_time,
+        ends_at: meeting.end_time,
+        starts_in_minutes,
+        url: (!privacy_mode).then(|| meeting.url.clone()),
+    }
+}
+
+/// The configured file, or `widget.json` in the data directory
+pub fn resolve_path(configured: Option<&str>) -> Option<PathBuf> {
+    match configured.map(str::trim).filter(|p| !p.is_empty()) {
+        Some(path) => Some(PathBuf::from(path)),
+        None => crate::paths::state_file(WIDGET_DATA_FILE),
+    }
+}
+
+/// Reject paths that would land somewhere depending on the working directory
+pub fn validate_path(path: &str) -> Result<(), String> {
+    if Path::new(path.trim()).is_absolute() {
+        Ok(())
+    } else {
+        Err(format!("Widget data path must be absolute: {}", path))
+    }
+}
+
+/// Replace `path` with `data`, so widgets never read a half-written file
+pub fn write(path: &Path, data: &WidgetData) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(data).map_err(|e| e.to_string())?;
+    if path.file_name().is_none() {
+        return Err(format!("Not a file path: {}", path.display()));
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    persistence::write_atomically(path, &content).map_err(|e| e.to_string())
+}
+
+/// Remove the file so widgets stop showing stale data; a missing file is fine
+pub fn remove(path: &Path) -> Result<(), String> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.to_string()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 2, 9, 20, 0).unwrap()
+    }
+
+    #[test]
+    fn test_build_upcoming() {
+        let next = fixtures::meeting_at("abc-defg-hij", "Standup", now(), 10, 30);
+        let data = WidgetData::build(Some(&next), &[&next], false, false, &Language::En, now());
+        assert_eq!(data.status, WidgetStatus::Upcoming);
+        let widget_next = data.next.unwrap();
+        assert_eq!(widget_next.title, "Standup");
+        assert_eq!(widget_next.label, "Standup · in 10 min");
+        assert_eq!(widget_next.starts_in_minutes, 10);
+        assert_eq!(
+            widget_next.url.as_deref(),
+            Some("https://meet.google.com/abc-defg-hij")
+        );
+        assert_eq!(data.upcoming.len(), 1);
+    }
+
+    #[test]
+    fn test_build_masks_titles_and_links_in_privacy_mode() {
+        let next = fixtures::meeting_at("abc-defg-hij", "Salary review", now(), 10, 30);
+        let data = WidgetData::build(Some(&next), &[&next], false, true, &Language::En, now());
+        let json = serde_json::to_string(&data).unwrap();
+        assert!(!json.contains("Salary review"));
+        assert!(!json.contains("abc-defg-hij"));
+        assert_eq!(data.next.unwrap().title, "09:30");
+    }
+
+    #[test]
+    fn test_build_paused_and_idle() {
+        let later = fixtures::meeting_at("abc-defg-hij", "Standup", now(), 10, 30);
+        let paused = WidgetData::build(Some(&later), &[&later], true, false, &Language::En, now());
+        assert_eq!(paused.status, WidgetStatus::Paused);
+
+        let idle = WidgetData::build(None, &[], false, false, &Language::En, now());
+        assert_eq!(idle.status, WidgetStatus::Idle);
+        assert!(idle.next.is_none());
+    }
+
+    #[test]
+    fn test_write_replaces_file_without_leftovers() {
+        let dir = std::env::temp_dir().join(format!("meetcat-widget-{}", std::process::id()));
+        let path = dir.join("nested").join(WIDGET_DATA_FILE);
+        let idle = WidgetData::build(None, &[], false, false, &Language::En, now());
+        write(&path, &idle).unwrap();
+        write(&path, &idle).unwrap();
+
+        let written: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written["version"], WIDGET_DATA_VERSION);
+        assert_eq!(written["status"], "idle");
+        let entries = fs::read_dir(path.parent().unwrap()).unwrap().count();
+        assert_eq!(entries, 1);
+
+        remove(&path).unwrap();
+        remove(&path).unwrap();
+        assert!(!path.exists());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_validate_path_requires_absolute() {
+        assert!(validate_path("widget.json").is_err());
+        let absolute = std::env::temp_dir().join(WIDGET_DATA_FILE);
+        assert!(validate_path(&absolute.to_string_lossy()).is_ok());
+    }
+}
@@ -0,0 +1,124 @@
+//! Why MeetCat last showed or focused one of its windows
+//!
+//! Every programmatic show is recorded with its cause, so a window that
+//! appears on its own can be traced back to the trigger, tray click or deep
+//! link that raised it.
+
+use serde::Serialize;
+use std::collections::VecDeque;
+
+/// How many entries are kept
+pub const MAX_WINDOW_ACTIVITY: usize = 50;
+
+/// What made MeetCat show a window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShowCause {
+    /// A join trigger fired and navigated to the meeting
+    AutoJoin,
+    /// The green room opened the meeting ahead of its trigger
+    GreenRoom,
+    /// A join opened in the background finished and raised its window
+    BackgroundJoin,
+    /// A join was held back by the rate cap and needs attention
+    RateCapped,
+    /// A tray icon click or menu item
+    Tray,
+    /// The app was reopened from the Dock or launcher
+    Reopen,
+    /// A global keyboard shortcut
+    Shortcut,
+    /// A `meetcat://` link
+    DeepLink,
+    /// The homepage was requested with focus
+    NavigateHome,
+}
+
+impl ShowCause {
+    /// Whether the user asked for the window directly, so no notice is needed
+    pub fn is_user_initiated(self) -> bool {
+        matches!(
+            self,
+            ShowCause::Tray | ShowCause::Reopen | ShowCause::Shortcut
+        )
+    }
+}
+
+/// One recorded show of a window
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowActivity {
+    pub at_ms: u64,
+    /// Label of the window that was shown
+    pub label: String,
+    pub cause: ShowCause,
+    pub call_id: Option<String>,
+    /// Meeting title, left out in privacy mode
+    pub title: Option<String>,
+}
+
+/// Most recent window shows, oldest first
+#[derive(Debug, Default)]
+pub struct WindowActivityLog {
+    entries: VecDeque<WindowActivity>,
+}
+
+impl WindowActivityLog {
+    pub fn record(&mut self, activity: WindowActivity) {
+        if self.entries.len() == MAX_WINDOW_ACTIVITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(activity);
+    }
+
+    /// Up to `limit` entries, newest first
+    pub fn recent(&self, limit: usize) -> Vec<WindowActivity> {
+        self.entries.iter().rev().take(limit).cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activity(at_ms: u64, cause: ShowCause) -> WindowActivity {
+        WindowActivity {
+            at_ms,
+            label: "main".to_string(),
+            cause,
+            call_id: None,
+            title: None,
+        }
+    }
+
+    #[test]
+    fn test_recent_is_newest_first_and_capped() {
+        let mut log = WindowActivityLog::default();
+        for i in 0..MAX_WINDOW_ACTIVITY as u64 + 3 {
+            log.record(activity(i, ShowCause::AutoJoin));
+        }
+        log.record(activity(1_000, ShowCause::Tray));
+
+        let recent = log.recent(2);
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0].cause, ShowCause::Tray);
+        assert_eq!(recent[1].at_ms, MAX_WINDOW_ACTIVITY as u64 + 2);
+
+        let all = log.recent(usize::MAX);
+        assert_eq!(all.len(), MAX_WINDOW_ACTIVITY);
+        assert_eq!(all.last().unwrap().at_ms, 4);
+    }
+
+    #[test]
+    fn test_tray_reopen_and_shortcut_are_user_initiated() {
+        assert!(ShowCause::Tray.is_user_initiated());
+        assert!(ShowCause::Reopen.is_user_initiated());
+        assert!(ShowCause::Shortcut.is_user_initiated());
+        assert!(!ShowCause::AutoJoin.is_user_initiated());
+        assert!(!ShowCause::DeepLink.is_user_initiated());
+        assert_eq!(
+            serde_json::to_value(ShowCause::BackgroundJoin).unwrap(),
+            "background_join"
+        );
+    }
+}
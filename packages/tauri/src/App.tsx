@@ -9,7 +9,11 @@ import {
 } from "@tauri-apps/plugin-autostart";
 import type { Settings } from "@meetcat/settings";
 import { DEFAULT_TAURI_SETTINGS, getTauriDefaults } from "@meetcat/settings";
-import { SettingsContainer, type SettingsAdapter } from "@meetcat/settings-ui";
+import {
+  SettingsContainer,
+  type SettingsAdapter,
+  type TitleFilterPreview,
+} from "@meetcat/settings-ui";
 import { initI18n, type LanguageSetting } from "@meetcat/i18n";
 import { I18nProvider, useTranslation } from "@meetcat/i18n/react";
 import "./App.css";
@@ -32,6 +36,43 @@ type UpdatePreference = {
 };
 
 const REMIND_LATER_MS = 24 * 60 * 60 * 1000;
+const WEEK_MS = 7 * 24 * 60 * 60 * 1000;
+
+type MeetingHistory = {
+  summary: {
+    joined: number;
+    suppressed: number;
+    failed: number;
+    skipped: number;
+    inMeetingMs: number;
+  };
+};
+
+type HistoryNoteTarget = {
+  callId: string;
+  recordedAtMs: number;
+  title: string;
+};
+
+type TroubleshootStep = {
+  id: "injection" | "parser" | "permissions" | "reachability" | "clock" | "logging";
+  status: "pass" | "warn" | "fail" | "skipped";
+  detail?: string | null;
+  fix?:
+    | "open_meet_home"
+    | "check_for_updates"
+    | "allow_media_access"
+    | "check_network"
+    | "sync_clock"
+    | "enable_logging"
+    | null;
+};
+
+type TroubleshootReport = {
+  ranAtMs: number;
+  steps: TroubleshootStep[];
+  passed: boolean;
+};
 
 const defaultSettings = getTauriDefaults();
 
@@ -52,6 +93,7 @@ const adapter: SettingsAdapter = {
     tray: true,
     showSavingIndicator: true,
     developer: true,
+    calendarSubscriptions: true,
   },
   getDefaultSettings: () => resolveSettings(null),
   resolveSettings,
@@ -85,6 +127,7 @@ const adapter: SettingsAdapter = {
   saveSettings: async (settings) => {
     await invoke("save_settings", { settings });
   },
+  testTitleFilter: (title) => invoke<TitleFilterPreview>("test_title_filter", { title }),
   subscribe: (handler) => {
     const unlisten = listen<Settings>("settings_changed", (event) => {
       handler(event.payload);
@@ -259,6 +302,24 @@ function AppContent() {
   const [updateErrorText, setUpdateErrorText] = useState<string | null>(null);
   const [downloadProgress, setDownloadProgress] = useState<UpdateDownloadProgress | null>(null);
   const [updatePreference, setUpdatePreference] = useState<UpdatePreference>({});
+  const [isJoinByCodeDialogOpen, setIsJoinByCodeDialogOpen] = useState(false);
+  const [joinCodeInput, setJoinCodeInput] = useState("");
+  const [recentMeetingCodes, setRecentMeetingCodes] = useState<string[]>([]);
+  const [isJoiningByCode, setIsJoiningByCode] = useState(false);
+  const [joinByCodeErrorText, setJoinByCodeErrorText] = useState<string | null>(null);
+  const [historyNoteTarget, setHistoryNoteTarget] = useState<HistoryNoteTarget | null>(null);
+  const [historyNoteInput, setHistoryNoteInput] = useState("");
+  const [isSavingHistoryNote, setIsSavingHistoryNote] = useState(false);
+  const [historyNoteErrorText, setHistoryNoteErrorText] = useState<string | null>(null);
+  const [joinedThisWeek, setJoinedThisWeek] = useState<number | null>(null);
+  const [isTroubleshooterOpen, setIsTroubleshooterOpen] = useState(false);
+  const [isTroubleshooting, setIsTroubleshooting] = useState(false);
+  const [troubleshootReport, setTroubleshootReport] = useState<TroubleshootReport | null>(null);
+  const [troubleshootStepIndex, setTroubleshootStepIndex] = useState(0);
+  const [troubleshootErrorText, setTroubleshootErrorText] = useState<string | null>(null);
+  const [slackStatusEnabled, setSlackStatusEnabled] = useState(false);
+  const [isSyncingPresence, setIsSyncingPresence] = useState(false);
+  const [presenceSyncText, setPresenceSyncText] = useState<string | null>(null);
 
   const bannerUpdate = useMemo(() => {
     if (!updateInfo) return null;
@@ -444,6 +505,45 @@ function AppContent() {
       });
       cleanupTasks.push(unlistenOpenDialog);
 
+      try {
+        const shouldOpenJoinByCode = await invoke<boolean>(
+          "consume_join_by_code_dialog_request"
+        );
+        if (shouldOpenJoinByCode) {
+          setIsJoinByCodeDialogOpen(true);
+        }
+      } catch (error) {
+        console.error("Failed to consume join by code dialog request:", error);
+      }
+
+      const unlistenJoinByCode = await listen("join-by-code:open-dialog", () => {
+        if (!disposed) {
+          setIsJoinByCodeDialogOpen(true);
+        }
+      });
+      cleanupTasks.push(unlistenJoinByCode);
+
+      try {
+        const noteTarget = await invoke<HistoryNoteTarget | null>(
+          "consume_history_note_request"
+        );
+        if (noteTarget) {
+          setHistoryNoteTarget(noteTarget);
+        }
+      } catch (error) {
+        console.error("Failed to consume history note request:", error);
+      }
+
+      const unlistenHistoryNote = await listen<HistoryNoteTarget>(
+        "history-note:open-dialog",
+        (event) => {
+          if (!disposed) {
+            setHistoryNoteTarget(event.payload);
+          }
+        }
+      );
+      cleanupTasks.push(unlistenHistoryNote);
+
       const unlistenManualCheck = await listen("update:manual-check", () => {
         if (!disposed) {
           void checkForUpdates(true);
@@ -480,6 +580,119 @@ function AppContent() {
     };
   }, [checkForUpdates, t]);
 
+  useEffect(() => {
+    invoke<MeetingHistory>("get_meeting_history", {
+      range: { fromMs: Date.now() - WEEK_MS },
+    })
+      .then((history) => setJoinedThisWeek(history.summary.joined))
+      .catch((error) => {
+        console.error("Failed to load meeting history:", error);
+      });
+  }, []);
+
+  useEffect(() => {
+    invoke<Settings>("get_settings")
+      .then((settings) => setSlackStatusEnabled(settings?.tauri?.slackStatusEnabled ?? false))
+      .catch((error) => {
+        console.error("Failed to load settings:", error);
+      });
+  }, []);
+
+  useEffect(() => {
+    if (!isJoinByCodeDialogOpen) return;
+    setJoinCodeInput("");
+    setJoinByCodeErrorText(null);
+    invoke<string[]>("get_recent_meeting_codes")
+      .then((codes) => setRecentMeetingCodes(codes ?? []))
+      .catch((error) => {
+        console.error("Failed to load recent meeting codes:", error);
+      });
+  }, [isJoinByCodeDialogOpen]);
+
+  const joinByCode = async (input: string) => {
+    if (!input.trim() || isJoiningByCode) return;
+    setIsJoiningByCode(true);
+    setJoinByCodeErrorText(null);
+    try {
+      await invoke<string>("join_by_code", { input });
+      setIsJoinByCodeDialogOpen(false);
+    } catch (error) {
+      setJoinByCodeErrorText(String(error));
+    } finally {
+      setIsJoiningByCode(false);
+    }
+  };
+
+  useEffect(() => {
+    setHistoryNoteInput("");
+    setHistoryNoteErrorText(null);
+  }, [historyNoteTarget]);
+
+  const saveHistoryNote = async () => {
+    if (!historyNoteTarget || isSavingHistoryNote) return;
+    setIsSavingHistoryNote(true);
+    setHistoryNoteErrorText(null);
+    try {
+      await invoke("set_history_note", {
+        callId: historyNoteTarget.callId,
+        recordedAtMs: historyNoteTarget.recordedAtMs,
+        note: historyNoteInput,
+      });
+      setHistoryNoteTarget(null);
+    } catch (error) {
+      setHistoryNoteErrorText(String(error));
+    } finally {
+      setIsSavingHistoryNote(false);
+    }
+  };
+
+  const runTroubleshooter = async () => {
+    if (isTroubleshooting) return;
+    setIsTroubleshooterOpen(true);
+    setIsTroubleshooting(true);
+    setTroubleshootReport(null);
+    setTroubleshootStepIndex(0);
+    setTroubleshootErrorText(null);
+    try {
+      const report = await invoke<TroubleshootReport>("run_troubleshooter");
+      setTroubleshootReport(report);
+    } catch (error) {
+      setTroubleshootErrorText(String(error));
+    } finally {
+      setIsTroubleshooting(false);
+    }
+  };
+
+  const syncPresenceNow = async () => {
+    if (isSyncingPresence) return;
+    setIsSyncingPresence(true);
+    setPresenceSyncText(null);
+    try {
+      await invoke("sync_presence_now");
+      setPresenceSyncText(t("presence.synced"));
+    } catch (error) {
+      setPresenceSyncText(t("presence.syncFailed", { error: String(error) }));
+    } finally {
+      setIsSyncingPresence(false);
+    }
+  };
+
+  const applyTroubleshootFix = (fix: TroubleshootStep["fix"]) => {
+    if (fix === "open_meet_home") {
+      invoke("navigate_home").catch((error) => {
+        console.error("Failed to navigate home:", error);
+      });
+    } else if (fix === "check_for_updates") {
+      setIsTroubleshooterOpen(false);
+      void checkForUpdates(true);
+    }
+  };
+
+  const troubleshootStep =
+    troubleshootReport && troubleshootStepIndex < troubleshootReport.steps.length
+      ? troubleshootReport.steps[troubleshootStepIndex]
+      : null;
+
   return (
     <div className="tauri-settings-shell">
       {bannerUpdate && (
@@ -504,6 +717,172 @@ function AppContent() {
         appName="MeetCat"
       />
 
+      {joinedThisWeek !== null && joinedThisWeek > 0 && (
+        <p className="meeting-history-summary">
+          {t("history.weekSummary", { count: joinedThisWeek })}
+        </p>
+      )}
+
+      <div className="troubleshooter-entry">
+        <button
+          type="button"
+          className="btn btn-secondary"
+          disabled={isTroubleshooting}
+          onClick={() => {
+            void runTroubleshooter();
+          }}
+        >
+          {t("troubleshooter.run")}
+        </button>
+      </div>
+
+      {slackStatusEnabled && (
+        <div className="troubleshooter-entry">
+          <button
+            type="button"
+            className="btn btn-secondary"
+            disabled={isSyncingPresence}
+            onClick={() => {
+              void syncPresenceNow();
+            }}
+          >
+            {isSyncingPresence ? t("presence.syncing") : t("presence.syncNow")}
+          </button>
+          {presenceSyncText && <p className="meeting-history-summary">{presenceSyncText}</p>}
+        </div>
+      )}
+
+      {isTroubleshooterOpen && (
+        <div
+          className="update-dialog-backdrop"
+          role="presentation"
+          onClick={() => {
+            if (isTroubleshooting) return;
+            setIsTroubleshooterOpen(false);
+          }}
+        >
+          <div
+            className="update-dialog"
+            role="dialog"
+            aria-modal="true"
+            aria-labelledby="troubleshooter-dialog-title"
+            onClick={(event) => event.stopPropagation()}
+          >
+            <div className="update-dialog-header">
+              <h2 id="troubleshooter-dialog-title">{t("troubleshooter.title")}</h2>
+              <button
+                type="button"
+                className="update-dialog-close"
+                aria-label={t("troubleshooter.closeDialog")}
+                disabled={isTroubleshooting}
+                onClick={() => setIsTroubleshooterOpen(false)}
+              >
+                ×
+              </button>
+            </div>
+
+            {isTroubleshooting && (
+              <p className="update-dialog-status">{t("troubleshooter.running")}</p>
+            )}
+            {troubleshootErrorText && (
+              <p className="update-dialog-error">{troubleshootErrorText}</p>
+            )}
+
+            {troubleshootReport && troubleshootStep && (
+              <div className="troubleshooter-step">
+                <p className="troubleshooter-progress">
+                  {t("troubleshooter.progress", {
+                    current: troubleshootStepIndex + 1,
+                    total: troubleshootReport.steps.length,
+                  })}
+                </p>
+                <h3>{t(`troubleshooter.steps.${troubleshootStep.id}`)}</h3>
+                <p
+                  className={`troubleshooter-status troubleshooter-status-${troubleshootStep.status}`}
+                >
+                  {t(`troubleshooter.status.${troubleshootStep.status}`)}
+                </p>
+                {troubleshootStep.detail && (
+                  <p className="update-dialog-description">
+                    <code>{troubleshootStep.detail}</code>
+                  </p>
+                )}
+                {troubleshootStep.fix && (
+                  <p className="update-dialog-description">
+                    {t(`troubleshooter.fixes.${troubleshootStep.fix}`)}
+                  </p>
+                )}
+              </div>
+            )}
+
+            {troubleshootReport && !troubleshootStep && (
+              <div className="troubleshooter-step">
+                <p className="update-dialog-description">
+                  {troubleshootReport.passed
+                    ? t("troubleshooter.allPassed")
+                    : t("troubleshooter.someFailed")}
+                </p>
+                <ul className="troubleshooter-summary">
+                  {troubleshootReport.steps.map((step) => (
+                    <li key={step.id}>
+                      {t(`troubleshooter.steps.${step.id}`)}:{" "}
+                      <span className={`troubleshooter-status-${step.status}`}>
+                        {t(`troubleshooter.status.${step.status}`)}
+                      </span>
+                    </li>
+                  ))}
+                </ul>
+              </div>
+            )}
+
+            {troubleshootReport && (
+              <div className="update-dialog-actions">
+                <div className="update-dialog-minor-actions">
+                  {troubleshootStepIndex > 0 && (
+                    <button
+                      type="button"
+                      className="btn btn-secondary"
+                      onClick={() => setTroubleshootStepIndex((index) => index - 1)}
+                    >
+                      {t("troubleshooter.back")}
+                    </button>
+                  )}
+                  {(troubleshootStep?.fix === "open_meet_home" ||
+                    troubleshootStep?.fix === "check_for_updates") && (
+                    <button
+                      type="button"
+                      className="btn btn-secondary"
+                      onClick={() => applyTroubleshootFix(troubleshootStep.fix)}
+                    >
+                      {t(`troubleshooter.actions.${troubleshootStep.fix}`)}
+                    </button>
+                  )}
+                </div>
+                {troubleshootStep ? (
+                  <button
+                    type="button"
+                    className="btn btn-primary"
+                    onClick={() => setTroubleshootStepIndex((index) => index + 1)}
+                  >
+                    {t("troubleshooter.next")}
+                  </button>
+                ) : (
+                  <button
+                    type="button"
+                    className="btn btn-primary"
+                    onClick={() => {
+                      void runTroubleshooter();
+                    }}
+                  >
+                    {t("troubleshooter.runAgain")}
+                  </button>
+                )}
+              </div>
+            )}
+          </div>
+        </div>
+      )}
+
       {isUpdateDialogOpen && (
         <div
           className="update-dialog-backdrop"
@@ -626,6 +1005,158 @@ function AppContent() {
           </div>
         </div>
       )}
+
+      {isJoinByCodeDialogOpen && (
+        <div
+          className="update-dialog-backdrop"
+          role="presentation"
+          onClick={() => {
+            if (isJoiningByCode) return;
+            setIsJoinByCodeDialogOpen(false);
+          }}
+        >
+          <form
+            className="update-dialog"
+            role="dialog"
+            aria-modal="true"
+            aria-labelledby="join-by-code-dialog-title"
+            onClick={(event) => event.stopPropagation()}
+            onSubmit={(event) => {
+              event.preventDefault();
+              void joinByCode(joinCodeInput);
+            }}
+          >
+            <div className="update-dialog-header">
+              <h2 id="join-by-code-dialog-title">{t("joinByCode.title")}</h2>
+              <button
+                type="button"
+                className="update-dialog-close"
+                aria-label={t("joinByCode.closeDialog")}
+                disabled={isJoiningByCode}
+                onClick={() => setIsJoinByCodeDialogOpen(false)}
+              >
+                ×
+              </button>
+            </div>
+
+            <label className="join-by-code-label" htmlFor="join-by-code-input">
+              {t("joinByCode.inputLabel")}
+            </label>
+            <input
+              id="join-by-code-input"
+              className="join-by-code-input"
+              type="text"
+              autoFocus
+              autoComplete="off"
+              spellCheck={false}
+              placeholder={t("joinByCode.placeholder")}
+              value={joinCodeInput}
+              disabled={isJoiningByCode}
+              onChange={(event) => setJoinCodeInput(event.target.value)}
+            />
+
+            {recentMeetingCodes.length > 0 && (
+              <div className="join-by-code-recent">
+                <h3>{t("joinByCode.recent")}</h3>
+                <div className="join-by-code-recent-list">
+                  {recentMeetingCodes.map((code) => (
+                    <button
+                      key={code}
+                      type="button"
+                      className="btn btn-secondary"
+                      disabled={isJoiningByCode}
+                      onClick={() => {
+                        setJoinCodeInput(code);
+                        void joinByCode(code);
+                      }}
+                    >
+                      {code}
+                    </button>
+                  ))}
+                </div>
+              </div>
+            )}
+
+            {joinByCodeErrorText && (
+              <p className="update-dialog-error">{joinByCodeErrorText}</p>
+            )}
+
+            <div className="update-dialog-actions">
+              <button
+                type="submit"
+                className="btn btn-primary"
+                disabled={!joinCodeInput.trim() || isJoiningByCode}
+              >
+                {isJoiningByCode ? t("joinByCode.joining") : t("joinByCode.join")}
+              </button>
+            </div>
+          </form>
+        </div>
+      )}
+
+      {historyNoteTarget && (
+        <div
+          className="update-dialog-backdrop"
+          role="presentation"
+          onClick={() => {
+            if (isSavingHistoryNote) return;
+            setHistoryNoteTarget(null);
+          }}
+        >
+          <form
+            className="update-dialog"
+            role="dialog"
+            aria-modal="true"
+            aria-labelledby="history-note-dialog-title"
+            onClick={(event) => event.stopPropagation()}
+            onSubmit={(event) => {
+              event.preventDefault();
+              void saveHistoryNote();
+            }}
+          >
+            <div className="update-dialog-header">
+              <h2 id="history-note-dialog-title">{t("history.noteTitle")}</h2>
+              <button
+                type="button"
+                className="update-dialog-close"
+                aria-label={t("history.closeNoteDialog")}
+                disabled={isSavingHistoryNote}
+                onClick={() => setHistoryNoteTarget(null)}
+              >
+                ×
+              </button>
+            </div>
+
+            <label className="history-note-label" htmlFor="history-note-input">
+              {historyNoteTarget.title || historyNoteTarget.callId}
+            </label>
+            <textarea
+              id="history-note-input"
+              className="history-note-input"
+              rows={4}
+              autoFocus
+              placeholder={t("history.notePlaceholder")}
+              value={historyNoteInput}
+              disabled={isSavingHistoryNote}
+              onChange={(event) => setHistoryNoteInput(event.target.value)}
+            />
+
+            {historyNoteErrorText && (
+              <p className="update-dialog-error">{historyNoteErrorText}</p>
+            )}
+
+            <div className="update-dialog-actions">
+              <button
+                type="submit"
+                className="btn btn-primary"
+                disabled={!historyNoteInput.trim() || isSavingHistoryNote}
+              >
+                {isSavingHistoryNote ? t("history.savingNote") : t("history.saveNote")}
+              </button>
+            </div>
+          </form>
+        </div>
+      )}
     </div>
   );
 }